@@ -0,0 +1,87 @@
+/**
+ * Audit Trail Test Suite
+ *
+ * Tests the audit subsystem:
+ * 1. AuditLogger fills the event from the request context and isolates failing sinks
+ * 2. auditTrail records mutating requests with the resolved resource and outcome
+ * 3. setAuditEntry completes the event from the handler
+ *
+ * @module __tests__/audit/audit-trail
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Hono } from 'hono';
+import { auditTrail, setAuditEntry } from '@/components/audit';
+import {
+  AuditLogger,
+  AuditOutcomes,
+  getError,
+  MemoryAuditSink,
+  RequestContextStorage,
+} from '@venizia/ignis-helpers';
+
+describe('Audit', () => {
+  test('TC-001: fills event defaults and keeps writing when a sink fails', async () => {
+    const sink = new MemoryAuditSink();
+    const auditLogger = new AuditLogger({
+      sinks: [
+        {
+          name: 'broken',
+          write: () => {
+            throw new Error('Sink down');
+          },
+        },
+        sink,
+      ],
+    });
+
+    const event = await RequestContextStorage.run({
+      context: { requestId: 'req-1', userId: 42, tenantId: 'acme' },
+      task: () => auditLogger.record({ action: 'order.cancel', resource: { type: 'order' } }),
+    });
+
+    expect(sink.events).toEqual([event]);
+    expect(event.outcome).toBe(AuditOutcomes.SUCCESS);
+    expect(event.actor.id).toBe('42');
+    expect(event.correlationId).toBe('req-1');
+    expect(event.tenantId).toBe('acme');
+  });
+
+  describe('auditTrail', () => {
+    const sink = new MemoryAuditSink();
+    const app = new Hono();
+    app.onError((_error, c) => c.json({}, 409));
+    app.use('*', auditTrail({ auditLogger: new AuditLogger({ sinks: [sink] }) }));
+    app.get('/orders/:id', c => c.json({}));
+    app.patch('/orders/:id', c => {
+      setAuditEntry(c, { before: { status: 'open' }, after: { status: 'closed' } });
+      return c.json({});
+    });
+    app.delete('/orders/:id/items/:itemId', () => {
+      throw getError({ statusCode: 409, message: 'Order is closed' });
+    });
+
+    test('TC-002: records mutating requests only with the route resource', async () => {
+      sink.clear();
+      await app.request('/orders/7');
+      await app.request('/orders/7', { method: 'PATCH' });
+
+      expect(sink.events).toHaveLength(1);
+      expect(sink.events[0].action).toBe('orders.update');
+      expect(sink.events[0].resource).toEqual({ type: 'orders', id: '7' });
+      expect(sink.events[0].before).toEqual({ status: 'open' });
+      expect(sink.events[0].after).toEqual({ status: 'closed' });
+      expect(sink.events[0].metadata?.statusCode).toBe(200);
+    });
+
+    test('TC-003: records failed requests with the failure outcome', async () => {
+      sink.clear();
+      await app.request('/orders/7/items/3', { method: 'DELETE' });
+
+      expect(sink.events).toHaveLength(1);
+      expect(sink.events[0].action).toBe('items.delete');
+      expect(sink.events[0].resource).toEqual({ type: 'items', id: '3' });
+      expect(sink.events[0].outcome).toBe(AuditOutcomes.FAILURE);
+    });
+  });
+});
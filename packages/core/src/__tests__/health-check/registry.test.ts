@@ -0,0 +1,80 @@
+/**
+ * Health Check Registry Test Suite
+ *
+ * Tests HealthCheckRegistry:
+ * 1. Reports per checker status and latency
+ * 2. Critical failures mark the report down, non critical ones degrade it
+ * 3. Checkers are filtered by probe and bounded by their timeout
+ *
+ * @module __tests__/health-check/registry
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { HealthCheckProbes, HealthCheckRegistry, HealthCheckStatuses } from '@/components';
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe('HealthCheckRegistry', () => {
+  test('TC-001: reports up when every checker passes', async () => {
+    const registry = new HealthCheckRegistry();
+    registry.register({ checker: { name: 'postgres', check: () => ({ pool: 10 }) } });
+
+    const report = await registry.check({ probe: HealthCheckProbes.READINESS });
+
+    expect(report.status).toBe(HealthCheckStatuses.UP);
+    expect(report.checks).toHaveLength(1);
+    expect(report.checks[0].name).toBe('postgres');
+    expect(report.checks[0].details).toEqual({ pool: 10 });
+    expect(report.checks[0].latency).toBeGreaterThanOrEqual(0);
+  });
+
+  test('TC-002: critical failures mark the report down, others degrade it', async () => {
+    const registry = new HealthCheckRegistry();
+    registry.register({
+      checker: {
+        name: 'search',
+        isCritical: false,
+        check: () => {
+          throw new Error('Connection refused');
+        },
+      },
+    });
+
+    const degraded = await registry.check();
+    expect(degraded.status).toBe(HealthCheckStatuses.DEGRADED);
+    expect(degraded.checks[0].error).toBe('Connection refused');
+
+    registry.register({
+      checker: {
+        name: 'postgres',
+        check: async () => {
+          throw new Error('Pool exhausted');
+        },
+      },
+    });
+
+    const down = await registry.check();
+    expect(down.status).toBe(HealthCheckStatuses.DOWN);
+  });
+
+  test('TC-003: filters checkers by probe and applies timeouts', async () => {
+    const registry = new HealthCheckRegistry();
+    registry.register({ checker: { name: 'postgres', check: () => {} } });
+    registry.register({
+      checker: {
+        name: 'event-loop',
+        probes: [HealthCheckProbes.LIVENESS, HealthCheckProbes.READINESS],
+        timeout: 20,
+        check: () => sleep(200),
+      },
+    });
+
+    const liveness = await registry.check({ probe: HealthCheckProbes.LIVENESS });
+    expect(liveness.checks.map(rs => rs.name)).toEqual(['event-loop']);
+    expect(liveness.status).toBe(HealthCheckStatuses.DOWN);
+    expect(liveness.checks[0].error).toContain('timed out');
+
+    const readiness = await registry.check({ probe: HealthCheckProbes.READINESS });
+    expect(readiness.checks).toHaveLength(2);
+  });
+});
@@ -0,0 +1,122 @@
+/**
+ * Mail Templates Test Suite
+ *
+ * Tests the mail template engines and the delivery error mapping:
+ * 1. Templates resolve through the locale fallback chain
+ * 2. The default engine renders placeholders and validates the template data
+ * 3. The handlebars engine renders conditionals, loops and partials with html escaping
+ * 4. Transport failures are mapped to mail error codes and status codes
+ *
+ * @module __tests__/mail/templates
+ */
+
+import { describe, test, expect } from 'bun:test';
+import {
+  HandlebarsTemplateEngineService,
+  MailErrorCodes,
+  normalizeMailError,
+  TemplateEngineService,
+} from '@/components/mail';
+
+const catchError = (fn: () => unknown) => {
+  try {
+    fn();
+  } catch (error) {
+    return error;
+  }
+
+  throw new Error('Expected the render to throw');
+};
+
+describe('Mail Templates', () => {
+  test('TC-001: resolves templates through the locale fallback chain', () => {
+    const engine = new TemplateEngineService({ defaultLocale: 'en' });
+    engine.registerTemplate({ name: 'welcome', locale: 'vi', content: 'Xin chào {{name}}' });
+    engine.registerTemplate({ name: 'welcome', locale: 'en', content: 'Hello {{name}}' });
+    engine.registerTemplate({ name: 'welcome', locale: 'pt-BR', content: 'Olá {{name}}' });
+    engine.registerTemplate({ name: 'receipt', content: 'Receipt {{id}}' });
+
+    expect(engine.getTemplate('welcome', 'vi-VN')?.locale).toBe('vi');
+    expect(engine.getTemplate('welcome', 'pt-BR')?.locale).toBe('pt-BR');
+    expect(engine.getTemplate('welcome', 'fr-FR')?.locale).toBe('en');
+    expect(engine.getTemplate('welcome')?.locale).toBe('en');
+    expect(engine.getTemplate('receipt', 'vi')?.content).toBe('Receipt {{id}}');
+    expect(engine.hasTemplate('invoice', 'vi')).toBe(false);
+
+    expect(engine.render({ templateName: 'welcome', locale: 'vi-VN', data: { name: 'An' } })).toBe(
+      'Xin chào An',
+    );
+    expect(catchError(() => engine.render({ templateName: 'invoice', data: {} }))).toMatchObject({
+      statusCode: 404,
+      messageCode: MailErrorCodes.TEMPLATE_NOT_FOUND,
+    });
+  });
+
+  test('TC-002: renders placeholders and validates the template data', () => {
+    const engine = new TemplateEngineService();
+    const template = 'Order {{order.id}} for {{ customer.name }}: {{total}}';
+
+    expect(
+      engine.render({
+        templateData: template,
+        data: { order: { id: 7 }, customer: { name: 'Mai' }, total: 0 },
+      }),
+    ).toBe('Order 7 for Mai: 0');
+
+    // Missing values are left as is unless validated
+    expect(engine.render({ templateData: template, data: { order: { id: 7 } } })).toBe(
+      'Order 7 for {{ customer.name }}: {{total}}',
+    );
+    const render = () =>
+      engine.render({ templateData: template, data: { order: { id: 7 } }, requireValidate: true });
+    expect(catchError(render)).toMatchObject({
+      statusCode: 400,
+      messageCode: MailErrorCodes.INVALID_CONFIGURATION,
+    });
+    expect(engine.validateTemplateData({ template, data: { total: 1 } })).toEqual({
+      isValid: false,
+      missingKeys: ['order.id', 'customer.name'],
+      allKeys: ['order.id', 'customer.name', 'total'],
+    });
+  });
+
+  test('TC-003: renders handlebars templates with html escaping', () => {
+    const engine = new HandlebarsTemplateEngineService({ defaultLocale: 'en' });
+    engine.registerPartial({ name: 'footer', content: '-- {{team}}' });
+    engine.registerHelper({ name: 'upper', fn: (value: string) => value.toUpperCase() });
+    engine.registerTemplate({
+      name: 'digest',
+      locale: 'en',
+      content:
+        '{{upper name}}:{{#each items}} {{this}}{{/each}}{{#if isVip}} (vip){{/if}} {{> footer}}',
+    });
+
+    const html = engine.render({
+      templateName: 'digest',
+      locale: 'en-GB',
+      data: { name: 'linh', items: ['<b>a</b>', 'b'], isVip: true, team: 'Ops' },
+    });
+    expect(html).toBe('LINH: &lt;b&gt;a&lt;/b&gt; b (vip) -- Ops');
+    expect(
+      engine.render({ templateName: 'digest', data: { name: 'an', items: [], team: 'Ops' } }),
+    ).toBe('AN: -- Ops');
+  });
+
+  test('TC-004: maps transport failures to mail error codes', () => {
+    const cases: Array<[object, string, number]> = [
+      [{ code: 'EAUTH' }, MailErrorCodes.AUTHENTICATION_FAILED, 500],
+      [{ responseCode: 535 }, MailErrorCodes.AUTHENTICATION_FAILED, 500],
+      [{ code: 'EENVELOPE' }, MailErrorCodes.INVALID_RECIPIENT, 422],
+      [{ responseCode: 550 }, MailErrorCodes.INVALID_RECIPIENT, 422],
+      [{ responseCode: 554 }, MailErrorCodes.MESSAGE_REJECTED, 422],
+      [{ responseCode: 421 }, MailErrorCodes.TEMPORARY_FAILURE, 503],
+      [{ code: 'ECONNREFUSED' }, MailErrorCodes.CONNECTION_FAILED, 503],
+      [{ code: 'ETIMEDOUT' }, MailErrorCodes.CONNECTION_FAILED, 503],
+      [new Error('Unexpected'), MailErrorCodes.SEND_FAILED, 500],
+    ];
+
+    for (const [error, errorCode, statusCode] of cases) {
+      expect(normalizeMailError(error)).toEqual({ errorCode, statusCode });
+    }
+  });
+});
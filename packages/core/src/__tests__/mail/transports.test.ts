@@ -0,0 +1,305 @@
+/**
+ * Mail API Transports Test Suite
+ *
+ * Tests the SES v2 and SendGrid transports with a stubbed fetcher:
+ * 1. Requests are signed with AWS Signature Version 4 (aws-sig-v4-test-suite vectors)
+ * 2. SES sends a signed outbound email request and maps its error types
+ * 3. SendGrid sends a mail send request and maps its field errors
+ *
+ * @module __tests__/mail/transports
+ */
+
+import { describe, test, expect } from 'bun:test';
+import C from 'node:crypto';
+import {
+  MailErrorCodes,
+  SendGridTransportHelper,
+  SesTransportHelper,
+  signAwsRequest,
+} from '@/components/mail';
+
+interface IStubRequest {
+  url: string;
+  method: string;
+  headers: Record<string, string>;
+  body?: string;
+}
+
+// Stands in for the NodeFetchNetworkRequest of the transports, answers from a queue
+class StubFetcher {
+  requests: Array<IStubRequest> = [];
+  responses: Array<{ status: number; headers?: Record<string, string>; body?: unknown }> = [];
+
+  getNetworkService() {
+    return {
+      send: async (opts: IStubRequest) => {
+        this.requests.push(opts);
+
+        const { status, headers, body } = this.responses.shift() ?? { status: 200 };
+        return new Response(body === undefined ? null : JSON.stringify(body), { status, headers });
+      },
+    };
+  }
+}
+
+const withFetcher = <T extends object>(transport: T) => {
+  const fetcher = new StubFetcher();
+  Object.assign(transport, { network: fetcher });
+  return { transport, fetcher };
+};
+
+const CREDENTIALS = {
+  accessKeyId: 'AKIDEXAMPLE',
+  secretAccessKey: 'wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY',
+};
+
+describe('Mail API Transports', () => {
+  test('TC-001: signs requests with the aws-sig-v4-test-suite vectors', () => {
+    const vector = {
+      path: '/',
+      headers: { Host: 'example.amazonaws.com' },
+      body: '',
+      region: 'us-east-1',
+      service: 'service',
+      credentials: CREDENTIALS,
+      now: new Date('2015-08-30T12:36:00Z'),
+    };
+    const scope = 'Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request';
+
+    // get-vanilla
+    expect(signAwsRequest({ ...vector, method: 'GET' })).toEqual({
+      ['host']: 'example.amazonaws.com',
+      ['x-amz-date']: '20150830T123600Z',
+      ['authorization']:
+        `AWS4-HMAC-SHA256 ${scope}, SignedHeaders=host;x-amz-date, ` +
+        'Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31',
+    });
+
+    // post-vanilla
+    expect(signAwsRequest({ ...vector, method: 'POST' }).authorization).toBe(
+      `AWS4-HMAC-SHA256 ${scope}, SignedHeaders=host;x-amz-date, ` +
+        'Signature=5da7c1a2acd57cee7505fc6676e4e544621c30862966e37dddb68e92efbe5d6b',
+    );
+
+    // Temporary credentials sign their session token
+    const signed = signAwsRequest({
+      ...vector,
+      method: 'GET',
+      credentials: { ...CREDENTIALS, sessionToken: 'session-token' },
+    });
+    expect(signed['x-amz-security-token']).toBe('session-token');
+    expect(signed.authorization).toContain('SignedHeaders=host;x-amz-date;x-amz-security-token,');
+  });
+
+  test('TC-002: sends signed SES v2 outbound email requests', async () => {
+    const { transport, fetcher } = withFetcher(
+      new SesTransportHelper({
+        region: 'eu-west-1',
+        ...CREDENTIALS,
+        sessionToken: 'session-token',
+        configurationSetName: 'transactional',
+      }),
+    );
+
+    fetcher.responses.push({ status: 200, body: { MessageId: 'ses-1' } });
+    const result = await transport.send({
+      from: 'Shop <no-reply@shop.example.com>',
+      to: ['"Linh Tran" <linh@example.com>', 'an@example.com'],
+      bcc: 'audit@shop.example.com',
+      subject: 'Order confirmed',
+      text: 'Thanks',
+      html: '<p>Thanks</p>',
+      headers: { 'x-order-id': '7' },
+      attachments: [{ filename: 'receipt.txt', content: 'paid', contentType: 'text/plain' }],
+    });
+    expect(result).toMatchObject({ success: true, messageId: 'ses-1' });
+
+    const [request] = fetcher.requests;
+    expect(request.url).toBe('https://email.eu-west-1.amazonaws.com/v2/email/outbound-emails');
+    expect(request.method).toBe('POST');
+    expect(JSON.parse(request.body!)).toEqual({
+      FromEmailAddress: 'Shop <no-reply@shop.example.com>',
+      Destination: {
+        ToAddresses: ['"Linh Tran" <linh@example.com>', 'an@example.com'],
+        CcAddresses: [],
+        BccAddresses: ['audit@shop.example.com'],
+      },
+      ReplyToAddresses: [],
+      ConfigurationSetName: 'transactional',
+      Content: {
+        Simple: {
+          Subject: { Data: 'Order confirmed', Charset: 'UTF-8' },
+          Body: {
+            Text: { Data: 'Thanks', Charset: 'UTF-8' },
+            Html: { Data: '<p>Thanks</p>', Charset: 'UTF-8' },
+          },
+          Headers: [{ Name: 'x-order-id', Value: '7' }],
+          Attachments: [
+            {
+              FileName: 'receipt.txt',
+              ContentType: 'text/plain',
+              ContentDisposition: 'ATTACHMENT',
+              RawContent: Buffer.from('paid').toString('base64'),
+            },
+          ],
+        },
+      },
+    });
+
+    // fetch sets the host header, it is still part of the signature
+    expect(request.headers.host).toBeUndefined();
+    expect(request.headers['x-amz-content-sha256']).toBe(
+      C.createHash('sha256').update(request.body!).digest('hex'),
+    );
+    const amzDate = request.headers['x-amz-date'];
+    const now = new Date(
+      amzDate.replace(/^(\d{4})(\d{2})(\d{2})T(\d{2})(\d{2})(\d{2})Z$/, '$1-$2-$3T$4:$5:$6Z'),
+    );
+    const expected = signAwsRequest({
+      method: 'POST',
+      path: '/v2/email/outbound-emails',
+      headers: {
+        ['content-type']: 'application/json',
+        ['host']: 'email.eu-west-1.amazonaws.com',
+        ['x-amz-content-sha256']: request.headers['x-amz-content-sha256'],
+      },
+      body: request.body!,
+      region: 'eu-west-1',
+      service: 'ses',
+      credentials: { ...CREDENTIALS, sessionToken: 'session-token' },
+      now,
+    });
+    delete expected.host;
+    expect(request.headers).toEqual(expected);
+    expect(request.headers.authorization).toContain(
+      'SignedHeaders=content-type;host;x-amz-content-sha256;x-amz-date;x-amz-security-token,',
+    );
+
+    fetcher.responses.push({
+      status: 400,
+      headers: { ['x-amzn-errortype']: 'MessageRejected:http://internal.amazon.com/' },
+      body: { message: 'Email address is not verified.' },
+    });
+    fetcher.responses.push({ status: 403, body: { __type: 'UnrecognizedClientException' } });
+    fetcher.responses.push({ status: 429, body: { message: 'Maximum sending rate exceeded.' } });
+
+    const message = { to: 'an@example.com', subject: 'Hi', text: 'Hi' };
+    expect(await transport.send(message)).toMatchObject({
+      success: false,
+      error: 'Email address is not verified.',
+      errorCode: MailErrorCodes.MESSAGE_REJECTED,
+      statusCode: 422,
+    });
+    expect(await transport.send(message)).toMatchObject({
+      errorCode: MailErrorCodes.AUTHENTICATION_FAILED,
+      statusCode: 500,
+    });
+    expect(await transport.send(message)).toMatchObject({
+      errorCode: MailErrorCodes.TEMPORARY_FAILURE,
+      statusCode: 503,
+    });
+
+    fetcher.responses.push({ status: 200, body: { SendingEnabled: true } });
+    expect(await transport.verify()).toBe(true);
+    expect(fetcher.requests.at(-1)).toMatchObject({
+      url: 'https://email.eu-west-1.amazonaws.com/v2/email/account',
+      method: 'GET',
+      body: undefined,
+    });
+  });
+
+  test('TC-003: sends SendGrid v3 mail send requests', async () => {
+    const { transport, fetcher } = withFetcher(
+      new SendGridTransportHelper({
+        apiKey: 'SG.key',
+        baseUrl: 'https://api.eu.sendgrid.com/',
+        sandboxMode: true,
+      }),
+    );
+
+    fetcher.responses.push({ status: 202, headers: { ['x-message-id']: 'sg-1' } });
+    const result = await transport.send({
+      from: 'Shop <no-reply@shop.example.com>',
+      to: ['"Linh Tran" <linh@example.com>'],
+      cc: 'an@example.com',
+      replyTo: 'support@shop.example.com',
+      subject: 'Order confirmed',
+      html: '<p>Thanks</p>',
+      text: 'Thanks',
+      attachments: [
+        { filename: 'logo.png', content: 'png', contentType: 'image/png', cid: 'logo' },
+      ],
+    });
+    expect(result).toMatchObject({ success: true, messageId: 'sg-1' });
+
+    const [request] = fetcher.requests;
+    expect(request).toMatchObject({
+      url: 'https://api.eu.sendgrid.com/v3/mail/send',
+      method: 'POST',
+      headers: { ['authorization']: 'Bearer SG.key', ['content-type']: 'application/json' },
+    });
+    expect(JSON.parse(request.body!)).toEqual({
+      personalizations: [
+        {
+          to: [{ email: 'linh@example.com', name: 'Linh Tran' }],
+          cc: [{ email: 'an@example.com' }],
+        },
+      ],
+      from: { email: 'no-reply@shop.example.com', name: 'Shop' },
+      reply_to: { email: 'support@shop.example.com' },
+      subject: 'Order confirmed',
+      content: [
+        { type: 'text/plain', value: 'Thanks' },
+        { type: 'text/html', value: '<p>Thanks</p>' },
+      ],
+      attachments: [
+        {
+          filename: 'logo.png',
+          type: 'image/png',
+          content_id: 'logo',
+          disposition: 'inline',
+          content: Buffer.from('png').toString('base64'),
+        },
+      ],
+      mail_settings: { sandbox_mode: { enable: true } },
+    });
+
+    fetcher.responses.push({
+      status: 400,
+      body: {
+        errors: [
+          { field: 'personalizations.0.to.0.email', message: 'Invalid email' },
+          { field: 'subject', message: 'Subject is required' },
+        ],
+      },
+    });
+    fetcher.responses.push({ status: 400, body: { errors: [{ field: 'from', message: 'Bad' }] } });
+    fetcher.responses.push({ status: 401, body: { errors: [{ message: 'Unauthorized' }] } });
+
+    const message = { to: 'an@example.com', subject: 'Hi', text: 'Hi' };
+    expect(await transport.send(message)).toMatchObject({
+      success: false,
+      error: 'Invalid email; Subject is required',
+      errorCode: MailErrorCodes.INVALID_RECIPIENT,
+      statusCode: 422,
+    });
+    expect(await transport.send(message)).toMatchObject({
+      errorCode: MailErrorCodes.MESSAGE_REJECTED,
+      statusCode: 422,
+    });
+    expect(await transport.send(message)).toMatchObject({
+      errorCode: MailErrorCodes.AUTHENTICATION_FAILED,
+      statusCode: 500,
+    });
+
+    // Keys without the mail.send scope are not usable
+    fetcher.responses.push({ status: 200, body: { scopes: ['mail.send', 'stats.read'] } });
+    fetcher.responses.push({ status: 200, body: { scopes: ['stats.read'] } });
+    expect(await transport.verify()).toBe(true);
+    expect(await transport.verify()).toBe(false);
+    expect(fetcher.requests.at(-1)).toMatchObject({
+      url: 'https://api.eu.sendgrid.com/v3/scopes',
+      method: 'GET',
+    });
+  });
+});
@@ -0,0 +1,102 @@
+/**
+ * Application Error Handler Test Suite
+ *
+ * Tests appErrorHandler:
+ * 1. ApplicationError keeps its status and message code
+ * 2. Hono HTTPException uses its status
+ * 3. Non-error throws and invalid status codes fall back to 500
+ * 4. Reporters are notified without affecting the response
+ *
+ * @module __tests__/middlewares/app-error
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Hono } from 'hono';
+import { HTTPException } from 'hono/http-exception';
+import { appErrorHandler, IErrorReport } from '@/base/middlewares';
+import { AppErrorCodes } from '@/common/constants';
+import { getError, LoggerFactory } from '@venizia/ignis-helpers';
+
+describe('appErrorHandler', () => {
+  const app = new Hono();
+  app.onError(appErrorHandler({ logger: LoggerFactory.getLogger(['AppErrorTest']) }));
+  app.get('/application', () => {
+    throw getError({ statusCode: 404, messageCode: 'USER_NOT_FOUND', message: 'User not found' });
+  });
+  app.get('/http-exception', () => {
+    throw new HTTPException(403, { message: 'Forbidden' });
+  });
+  app.get('/string', () => {
+    throw 'Something broke';
+  });
+  app.get('/invalid-status', () => {
+    throw getError({ statusCode: 200, message: 'Not an error status' });
+  });
+
+  test('TC-001: returns ApplicationError status and message code', async () => {
+    const rs = await app.request('/application');
+    const body = await rs.json();
+
+    expect(rs.status).toBe(404);
+    expect(body.statusCode).toBe(404);
+    expect(body.messageCode).toBe('USER_NOT_FOUND');
+    expect(body.message).toBe('User not found');
+    expect(body.details.path).toBe('/application');
+  });
+
+  test('TC-002: returns HTTPException status', async () => {
+    const rs = await app.request('/http-exception');
+    const body = await rs.json();
+
+    expect(rs.status).toBe(403);
+    expect(body.statusCode).toBe(403);
+    expect(body.message).toBe('Forbidden');
+  });
+
+  test('TC-003: wraps non-error throws as 500', async () => {
+    const rs = await app.request('/string');
+    const body = await rs.json();
+
+    expect(rs.status).toBe(500);
+    expect(body.messageCode).toBe(AppErrorCodes.INTERNAL_ERROR);
+    expect(body.message).toBe('Something broke');
+  });
+
+  test('TC-004: falls back to 500 for non error status codes', async () => {
+    const rs = await app.request('/invalid-status');
+    const body = await rs.json();
+
+    expect(rs.status).toBe(500);
+    expect(body.statusCode).toBe(500);
+  });
+
+  test('TC-005: notifies reporters and ignores reporter failures', async () => {
+    const reports: Array<IErrorReport> = [];
+    const reportedApp = new Hono();
+    reportedApp.onError(
+      appErrorHandler({
+        logger: LoggerFactory.getLogger(['AppErrorTest']),
+        reporters: [
+          report => {
+            reports.push(report);
+          },
+          () => {
+            throw new Error('Reporter down');
+          },
+        ],
+      }),
+    );
+    reportedApp.get('/application', () => {
+      throw getError({ statusCode: 502, messageCode: 'UPSTREAM_FAILED', message: 'Bad gateway' });
+    });
+
+    const rs = await reportedApp.request('/application');
+    await new Promise(resolve => setTimeout(resolve, 0));
+
+    expect(rs.status).toBe(502);
+    expect(reports).toHaveLength(1);
+    expect(reports[0].statusCode).toBe(502);
+    expect(reports[0].messageCode).toBe('UPSTREAM_FAILED');
+    expect(reports[0].context.req.path).toBe('/application');
+  });
+});
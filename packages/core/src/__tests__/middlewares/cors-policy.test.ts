@@ -0,0 +1,67 @@
+/**
+ * CORS Policy Middleware Test Suite
+ *
+ * Tests corsPolicy:
+ * 1. Exact and wildcard origins are echoed back, unknown origins get no CORS headers
+ * 2. Per path overrides replace the default policy
+ * 3. Invalid policies are rejected when the middleware is built
+ *
+ * @module __tests__/middlewares/cors-policy
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Hono } from 'hono';
+import { corsPolicy } from '@/base/middlewares';
+
+describe('corsPolicy', () => {
+  const app = new Hono();
+  app.use(
+    corsPolicy({
+      origins: 'https://app.example.com, https://*.example.com',
+      credentials: true,
+      maxAge: 600,
+      overrides: [{ path: '/public/*', policy: { origins: '*', credentials: false } }],
+    }),
+  );
+  app.get('/users', c => c.json({ ok: true }));
+  app.get('/public/assets', c => c.json({ ok: true }));
+
+  const request = (path: string, origin: string) => {
+    return app.request(path, { headers: { origin } });
+  };
+
+  test('TC-001: echoes allowed origins and ignores unknown ones', async () => {
+    const exact = await request('/users', 'https://app.example.com');
+    expect(exact.headers.get('access-control-allow-origin')).toBe('https://app.example.com');
+    expect(exact.headers.get('access-control-allow-credentials')).toBe('true');
+
+    const wildcard = await request('/users', 'https://admin.eu.example.com');
+    expect(wildcard.headers.get('access-control-allow-origin')).toBe(
+      'https://admin.eu.example.com',
+    );
+
+    const unknown = await request('/users', 'https://example.com.evil.io');
+    expect(unknown.headers.get('access-control-allow-origin')).toBeNull();
+  });
+
+  test('TC-002: applies path overrides', async () => {
+    const rs = await request('/public/assets', 'https://any.site');
+    expect(rs.headers.get('access-control-allow-origin')).toBe('*');
+    expect(rs.headers.get('access-control-allow-credentials')).toBeNull();
+  });
+
+  test('TC-003: answers preflight requests with max age', async () => {
+    const rs = await app.request('/users', {
+      method: 'OPTIONS',
+      headers: { origin: 'https://app.example.com', 'access-control-request-method': 'POST' },
+    });
+
+    expect(rs.status).toBe(204);
+    expect(rs.headers.get('access-control-max-age')).toBe('600');
+  });
+
+  test('TC-004: rejects wildcard origins with credentials', () => {
+    expect(() => corsPolicy({ origins: '*', credentials: true })).toThrow();
+    expect(() => corsPolicy({ origins: ' , ' })).toThrow();
+  });
+});
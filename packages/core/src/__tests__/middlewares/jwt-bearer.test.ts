@@ -0,0 +1,52 @@
+/**
+ * JWT Bearer Middleware Test Suite
+ *
+ * Tests jwtBearerAuth:
+ * 1. Valid tokens expose typed claims and the current user
+ * 2. Missing, malformed and expired tokens fail with 401
+ *
+ * @module __tests__/middlewares/jwt-bearer
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Hono } from 'hono';
+import { appErrorHandler } from '@/base/middlewares';
+import { Authentication, getJWTClaims, jwtBearerAuth } from '@/components/auth/authenticate';
+import { JWTAlgorithms, JWTHelper, LoggerFactory } from '@venizia/ignis-helpers';
+
+const jwt = new JWTHelper({ algorithm: JWTAlgorithms.HS256, secret: 'secret', issuer: 'idp' });
+
+const app = new Hono();
+app.onError(appErrorHandler({ logger: LoggerFactory.getLogger(['JWTBearerTest']) }));
+app.use(jwtBearerAuth<{ scope: string }>({ jwt }));
+app.get('/me', c => {
+  return c.json({
+    scope: getJWTClaims<{ scope: string }>(c)?.scope,
+    userId: c.get(Authentication.CURRENT_USER)?.userId,
+  });
+});
+
+const request = (authorization?: string) => {
+  return app.request('/me', { headers: authorization ? { authorization } : {} });
+};
+
+describe('jwtBearerAuth', () => {
+  test('TC-001: injects verified claims', async () => {
+    const token = jwt.sign({ subject: '42', claims: { scope: 'orders:read' } });
+    const rs = await request(`Bearer ${token}`);
+
+    expect(rs.status).toBe(200);
+    expect(await rs.json()).toEqual({ scope: 'orders:read', userId: '42' });
+  });
+
+  test('TC-002: rejects missing, malformed and expired tokens', async () => {
+    const expired = jwt.sign({ now: 1_000, expiresIn: 60 });
+
+    expect((await request()).status).toBe(401);
+    expect((await request('Basic abc')).status).toBe(401);
+
+    const rs = await request(`Bearer ${expired}`);
+    expect(rs.status).toBe(401);
+    expect((await rs.json()).message).toContain('expired');
+  });
+});
@@ -0,0 +1,62 @@
+/**
+ * Rate Limit Middleware Test Suite
+ *
+ * Tests rateLimit:
+ * 1. Requests within the limit pass with rate limit headers
+ * 2. Requests over the limit fail with 429 and Retry-After
+ * 3. Clients are counted separately
+ *
+ * @module __tests__/middlewares/rate-limit
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Hono } from 'hono';
+import { appErrorHandler, rateLimit } from '@/base/middlewares';
+import { AppErrorCodes } from '@/common/constants';
+import { LoggerFactory, MemoryRateLimiter } from '@venizia/ignis-helpers';
+
+describe('rateLimit', () => {
+  const consumed: Array<string> = [];
+
+  const app = new Hono();
+  app.onError(appErrorHandler({ logger: LoggerFactory.getLogger(['RateLimitTest']) }));
+  app.use(
+    '/api/*',
+    rateLimit({
+      limiter: new MemoryRateLimiter(),
+      prefix: 'api',
+      limit: 2,
+      window: 60_000,
+      onConsumed: ({ key }) => consumed.push(key),
+    }),
+  );
+  app.get('/api/users', c => c.json({ ok: true }));
+
+  const request = (ip: string) => {
+    return app.request('/api/users', { headers: { 'x-forwarded-for': ip } });
+  };
+
+  test('TC-001: passes requests within the limit', async () => {
+    const rs = await request('10.0.0.1');
+
+    expect(rs.status).toBe(200);
+    expect(rs.headers.get('ratelimit-limit')).toBe('2');
+    expect(rs.headers.get('ratelimit-remaining')).toBe('1');
+    expect(consumed).toEqual(['api:ip:10.0.0.1']);
+  });
+
+  test('TC-002: rejects requests over the limit', async () => {
+    await request('10.0.0.1');
+    const rs = await request('10.0.0.1');
+    const body = await rs.json();
+
+    expect(rs.status).toBe(429);
+    expect(Number(rs.headers.get('retry-after'))).toBeGreaterThan(0);
+    expect(body.messageCode).toBe(AppErrorCodes.RATE_LIMITED);
+  });
+
+  test('TC-003: counts clients separately', async () => {
+    const rs = await request('10.0.0.2');
+    expect(rs.status).toBe(200);
+  });
+});
@@ -0,0 +1,37 @@
+/**
+ * Request Context Middleware Test Suite
+ *
+ * Tests requestContext:
+ * 1. The deadline of the scope is the earliest of the caller deadline and the server timeout
+ *
+ * @module __tests__/middlewares/request-context
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Hono } from 'hono';
+import { requestContext } from '@/base/middlewares';
+import { RequestContextHeaders, RequestContextStorage } from '@venizia/ignis-helpers';
+
+describe('requestContext', () => {
+  const app = new Hono();
+  app.use(requestContext({ requestTimeout: 1_000 }));
+  app.get('/budget', c => c.json({ remaining: RequestContextStorage.getRemainingBudget() }));
+
+  test('TC-001: caps the deadline of the caller by the server timeout', async () => {
+    const own = await (await app.request('/budget')).json();
+    expect(own.remaining).toBeGreaterThan(900);
+    expect(own.remaining).toBeLessThanOrEqual(1_000);
+
+    const caller = await (
+      await app.request('/budget', {
+        headers: { [RequestContextHeaders.DEADLINE]: String(Date.now() + 200) },
+      })
+    ).json();
+    expect(caller.remaining).toBeLessThanOrEqual(200);
+
+    const patient = await (
+      await app.request('/budget', { headers: { [RequestContextHeaders.GRPC_TIMEOUT]: '5S' } })
+    ).json();
+    expect(patient.remaining).toBeLessThanOrEqual(1_000);
+  });
+});
@@ -0,0 +1,40 @@
+/**
+ * Request Timeout Middleware Test Suite
+ *
+ * Tests requestTimeout:
+ * 1. Fast handlers are passed through
+ * 2. Slow handlers fail with 504 through the application error handler
+ *
+ * @module __tests__/middlewares/request-timeout
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Hono } from 'hono';
+import { appErrorHandler, requestTimeout } from '@/base/middlewares';
+import { LoggerFactory } from '@venizia/ignis-helpers';
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe('requestTimeout', () => {
+  const app = new Hono();
+  app.onError(appErrorHandler({ logger: LoggerFactory.getLogger(['RequestTimeoutTest']) }));
+  app.use(requestTimeout({ duration: 50 }));
+  app.get('/fast', c => c.json({ ok: true }));
+  app.get('/slow', async c => {
+    await sleep(200);
+    return c.json({ ok: true });
+  });
+
+  test('TC-001: passes fast requests through', async () => {
+    const rs = await app.request('/fast');
+    expect(rs.status).toBe(200);
+  });
+
+  test('TC-002: fails slow requests with 504', async () => {
+    const rs = await app.request('/slow');
+    const body = await rs.json();
+
+    expect(rs.status).toBe(504);
+    expect(body.statusCode).toBe(504);
+  });
+});
@@ -0,0 +1,59 @@
+/**
+ * Request Validator Middleware Test Suite
+ *
+ * Tests requestValidator:
+ * 1. Valid values are parsed and passed to the handler
+ * 2. Invalid values are returned as 422 with field level errors
+ *
+ * @module __tests__/middlewares/request-validator
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { z } from '@hono/zod-openapi';
+import { Hono } from 'hono';
+import { appErrorHandler, requestValidator } from '@/base/middlewares';
+import { LoggerFactory, ValidationErrorCodes } from '@venizia/ignis-helpers';
+
+describe('requestValidator', () => {
+  const app = new Hono();
+  app.onError(appErrorHandler({ logger: LoggerFactory.getLogger(['RequestValidatorTest']) }));
+  app.post(
+    '/orders',
+    requestValidator({
+      target: 'json',
+      schema: z.object({
+        email: z.email(),
+        items: z.array(z.object({ quantity: z.number().int().positive() })).min(1),
+      }),
+    }),
+    c => c.json(c.req.valid('json')),
+  );
+
+  const post = (body: unknown) => {
+    return app.request('/orders', {
+      method: 'POST',
+      headers: { 'content-type': 'application/json' },
+      body: JSON.stringify(body),
+    });
+  };
+
+  test('TC-001: passes parsed values to the handler', async () => {
+    const rs = await post({ email: 'a@b.co', items: [{ quantity: 2 }] });
+
+    expect(rs.status).toBe(200);
+    expect(await rs.json()).toEqual({ email: 'a@b.co', items: [{ quantity: 2 }] });
+  });
+
+  test('TC-002: returns field level errors', async () => {
+    const rs = await post({ email: 'invalid', items: [{ quantity: 0 }] });
+    const body = await rs.json();
+
+    expect(rs.status).toBe(422);
+    expect(body.messageCode).toBe(ValidationErrorCodes.VALIDATION_ERROR);
+    expect(body.details.target).toBe('json');
+    expect(body.details.cause.map((field: { path: string }) => field.path)).toEqual([
+      'email',
+      'items.0.quantity',
+    ]);
+  });
+});
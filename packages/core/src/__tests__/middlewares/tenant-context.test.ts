@@ -0,0 +1,42 @@
+/**
+ * Tenant Context Middleware Test Suite
+ *
+ * Tests tenantContext with requestContext:
+ * 1. A spoofed `x-tenant-id` header is ignored, only the resolved tenant is bound
+ *
+ * @module __tests__/middlewares/tenant-context
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Hono } from 'hono';
+import { requestContext, tenantContext, TenantResolvers } from '@/base/middlewares';
+import { Authentication } from '@/components/auth/authenticate/common/constants';
+import { RequestContextHeaders, TenantContext } from '@venizia/ignis-helpers';
+
+describe('tenantContext', () => {
+  const app = new Hono();
+  app.use(requestContext());
+  app.use('/claims/*', async (c, next) => {
+    c.set(Authentication.JWT_CLAIMS, { tenantId: 'acme' });
+    await next();
+  });
+  for (const path of ['/claims/*', '/anonymous/*']) {
+    app.use(path, tenantContext({ resolvers: [TenantResolvers.claim()], isRequired: false }));
+  }
+
+  for (const path of ['/claims/me', '/anonymous/me', '/public/me']) {
+    app.get(path, c => c.json({ tenantId: TenantContext.get() ?? null }));
+  }
+
+  const getTenant = async (path: string) => {
+    const headers = { [RequestContextHeaders.TENANT_ID]: 'globex' };
+    const rs = await app.request(path, { headers });
+    return (await rs.json()).tenantId;
+  };
+
+  test('TC-001: ignores a spoofed tenant header', async () => {
+    expect(await getTenant('/claims/me')).toBe('acme');
+    expect(await getTenant('/anonymous/me')).toBeNull();
+    expect(await getTenant('/public/me')).toBeNull();
+  });
+});
@@ -0,0 +1,42 @@
+/**
+ * OpenAPI Response Schemas Test Suite
+ *
+ * Tests jsonResponse / paginatedJsonResponse:
+ * 1. Error responses reference the shared ErrorResponse component
+ * 2. Paginated responses reference the shared PaginationMeta component
+ *
+ * @module __tests__/openapi/response-schemas
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { createRoute, OpenAPIHono, z } from '@hono/zod-openapi';
+import { paginatedJsonResponse } from '@/base/models';
+
+describe('paginatedJsonResponse', () => {
+  const app = new OpenAPIHono();
+  app.openapi(
+    createRoute({
+      method: 'get',
+      path: '/users',
+      responses: paginatedJsonResponse({ schema: z.object({ id: z.number() }) }),
+    }),
+    c => c.json({ data: [], meta: {} } as never),
+  );
+
+  const document = app.getOpenAPIDocument({
+    openapi: '3.0.0',
+    info: { title: 'Test', version: '1.0.0' },
+  });
+
+  test('TC-001: references the error envelope', () => {
+    const errorResponse = document.paths['/users']?.get?.responses?.['4xx | 5xx'];
+
+    expect(document.components?.schemas?.ErrorResponse).toBeDefined();
+    expect(JSON.stringify(errorResponse)).toContain('#/components/schemas/ErrorResponse');
+  });
+
+  test('TC-002: references the pagination meta', () => {
+    expect(document.components?.schemas?.PaginationMeta).toBeDefined();
+    expect(document.components?.schemas?.ResponseMeta).toBeDefined();
+  });
+});
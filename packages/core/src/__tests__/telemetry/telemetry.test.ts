@@ -0,0 +1,38 @@
+/**
+ * Telemetry Setup Test Suite
+ *
+ * Tests the OpenTelemetry setup helper:
+ * 1. parseOtlpHeaders - `OTEL_EXPORTER_OTLP_HEADERS` parsing
+ * 2. initTelemetry - Option validation and idempotent shutdown
+ *
+ * @module __tests__/telemetry/telemetry
+ */
+
+import { describe, expect, test } from 'bun:test';
+import { initTelemetry, parseOtlpHeaders } from '@/components/telemetry';
+
+describe('Telemetry', () => {
+  test('TC-001: should parse OTLP headers with url encoded values', () => {
+    expect(parseOtlpHeaders('api-key=abc%3D%3D, x-team = core,broken,=x')).toEqual({
+      'api-key': 'abc==',
+      'x-team': 'core',
+    });
+    expect(parseOtlpHeaders(undefined)).toEqual({});
+  });
+
+  test('TC-002: should reject sampling ratios outside [0, 1]', () => {
+    expect(() => initTelemetry({ samplingRatio: 1.5 })).toThrow(/Invalid sampling ratio/);
+  });
+
+  test('TC-003: should share one shutdown between calls', async () => {
+    const guard = initTelemetry({
+      service: { name: 'telemetry-test' },
+      traces: { isEnabled: false },
+      metrics: { isEnabled: false },
+    });
+
+    const first = guard.shutdown();
+    expect(guard.shutdown()).toBe(first);
+    await first;
+  });
+});
@@ -5,7 +5,12 @@ import {
   applicationEnvironment,
   getError,
   int,
+  IShutdownHook,
+  IShutdownReport,
   RuntimeModules,
+  ShutdownDefaults,
+  ShutdownOrchestrator,
+  ShutdownPhases,
   toBoolean,
   ValueOrPromise,
 } from '@venizia/ignis-helpers';
@@ -13,6 +18,7 @@ import { Env, Schema } from 'hono';
 import { showRoutes as showApplicationRoutes } from 'hono/dev';
 import isEmpty from 'lodash/isEmpty';
 import path from 'node:path';
+import { TErrorReporter } from '../middlewares';
 import {
   IApplication,
   IApplicationConfigs,
@@ -47,6 +53,9 @@ export abstract class AbstractApplication<
   protected projectRoot: string;
 
   private postStartHooks: Array<{ identifier: string; hook: () => ValueOrPromise<void> }> = [];
+  private shutdownOrchestrator = new ShutdownOrchestrator({ scope: 'ApplicationShutdown' });
+  private stopPromise?: Promise<IShutdownReport>;
+  protected errorReporters: Array<TErrorReporter> = [];
 
   // ------------------------------------------------------------------------------
   constructor(opts: { scope: string; config: IApplicationConfigs }) {
@@ -152,6 +161,60 @@ export abstract class AbstractApplication<
     }
   }
 
+  /**
+   * Register a hook executed on `stop`. Hooks run phase by phase (`ShutdownPhases`), the
+   * default `resources` phase starts once in-flight requests and consumers were drained,
+   * e.g. to close datasources or redis connections.
+   */
+  registerShutdownHook(opts: IShutdownHook) {
+    this.shutdownOrchestrator.register(opts);
+  }
+
+  /**
+   * Register a reporter notified of every error handled by the application error handler,
+   * e.g. to forward server errors to an error tracking service.
+   */
+  registerErrorReporter(reporter: TErrorReporter) {
+    this.errorReporters.push(reporter);
+  }
+
+  isShuttingDown(): boolean {
+    return this.shutdownOrchestrator.isShuttingDown();
+  }
+
+  protected bindShutdownSignals() {
+    const { enable = false, signals = ['SIGINT', 'SIGTERM'], shouldExitProcess = true } =
+      this.configs.server?.shutdown ?? {};
+
+    if (!enable) {
+      return;
+    }
+
+    for (const signal of signals) {
+      process.once(signal, () => {
+        this.logger
+          .for(this.bindShutdownSignals.name)
+          .info('Received %s | Shutting down gracefully...', signal);
+
+        Promise.resolve(this.stop())
+          .then(report => {
+            if (shouldExitProcess) {
+              process.exit(report.isCompleted && !report.failed.length ? 0 : 1);
+            }
+          })
+          .catch(error => {
+            this.logger
+              .for(this.bindShutdownSignals.name)
+              .error('Failed to shut down gracefully | error: %s', error);
+
+            if (shouldExitProcess) {
+              process.exit(1);
+            }
+          });
+      });
+    }
+  }
+
   // ------------------------------------------------------------------------------
   protected registerCoreBindings() {
     this.bind<typeof this>({
@@ -326,18 +389,33 @@ export abstract class AbstractApplication<
     }
 
     await this.executePostStartHooks();
+    this.bindShutdownSignals();
   }
 
-  stop() {
-    this.logger.for(this.stop.name).info('Server STOPPED');
+  // ------------------------------------------------------------------------------
+  protected closeServerInstance(opts: { force: boolean }): Promise<void> {
+    const { force } = opts;
+
     switch (this.server.runtime) {
       case RuntimeModules.BUN: {
-        this.server.instance?.stop();
-        break;
+        return Promise.resolve(this.server.instance?.stop(force));
       }
       case RuntimeModules.NODE: {
-        this.server.instance?.close();
-        break;
+        const instance = this.server.instance;
+        if (!instance) {
+          return Promise.resolve();
+        }
+
+        if (force) {
+          instance.closeAllConnections?.();
+          return Promise.resolve();
+        }
+
+        return new Promise(resolve => {
+          // Resolve on errors too, e.g. ERR_SERVER_NOT_RUNNING
+          instance.close(() => resolve());
+          instance.closeIdleConnections?.();
+        });
       }
       default: {
         throw getError({
@@ -346,4 +424,70 @@ export abstract class AbstractApplication<
       }
     }
   }
+
+  private async drainServerInstance(opts: { timeout: number }) {
+    let timer: ReturnType<typeof setTimeout> | undefined;
+    const isDrained = await Promise.race([
+      this.closeServerInstance({ force: false }).then(() => true),
+      new Promise<boolean>(resolve => {
+        timer = setTimeout(() => resolve(false), opts.timeout);
+      }),
+    ]);
+    clearTimeout(timer);
+
+    if (!isDrained) {
+      this.logger
+        .for(this.drainServerInstance.name)
+        .warn('Server drain TIMED OUT | Closing remaining connections');
+      await this.closeServerInstance({ force: true });
+    }
+  }
+
+  /**
+   * Shut the application down within `server.shutdown.deadline`:
+   * 1. `pre-stop`: wait `preStopDelay` so load balancers notice the failing readiness probe
+   * 2. `http`: stop accepting requests, wait for in-flight ones up to `server.shutdown.timeout`
+   * 3. `consumers`, `flush` and `resources`: the registered shutdown hooks
+   *
+   * Calling it again returns the same report.
+   */
+  stop(): Promise<IShutdownReport> {
+    if (this.stopPromise) {
+      return this.stopPromise;
+    }
+
+    const {
+      timeout = 10_000,
+      deadline = ShutdownDefaults.DEADLINE,
+      preStopDelay = 0,
+    } = this.configs.server?.shutdown ?? {};
+    const logger = this.logger.for(this.stop.name);
+
+    if (preStopDelay > 0) {
+      this.shutdownOrchestrator.register({
+        identifier: 'pre-stop-delay',
+        phase: ShutdownPhases.PRE_STOP,
+        hook: () => new Promise(resolve => setTimeout(resolve, preStopDelay)),
+      });
+    }
+
+    this.shutdownOrchestrator.register({
+      identifier: 'http-server',
+      phase: ShutdownPhases.HTTP,
+      hook: () => this.drainServerInstance({ timeout }),
+    });
+
+    logger.info('Server STOPPING | timeout: %s (ms) | deadline: %s (ms)', timeout, deadline);
+    this.stopPromise = this.shutdownOrchestrator.execute({ deadline }).then(report => {
+      logger.info(
+        'Server STOPPED | Took: %s (ms) | completed: %s | failed: %j',
+        report.took,
+        report.isCompleted,
+        report.failed,
+      );
+      return report;
+    });
+
+    return this.stopPromise;
+  }
 }
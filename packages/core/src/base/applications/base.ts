@@ -1,4 +1,5 @@
 import { BindingNamespaces, TBindingNamespace } from '@/common/bindings';
+import { AppErrorCodes } from '@/common/constants';
 import { RequestTrackerComponent } from '@/components';
 import {
   Binding,
@@ -26,12 +27,19 @@ import {
   RuntimeModules,
   TClass,
 } from '@venizia/ignis-helpers';
+import { bodyLimit } from 'hono/body-limit';
 import { contextStorage } from 'hono/context-storage';
 import isEmpty from 'lodash/isEmpty';
 import { BaseComponent } from '../components';
 import { BaseController } from '../controllers';
 import { IDataSource } from '../datasources';
-import { appErrorHandler, emojiFavicon, notFoundHandler } from '../middlewares';
+import {
+  appErrorHandler,
+  corsPolicy,
+  emojiFavicon,
+  notFoundHandler,
+  requestTimeout,
+} from '../middlewares';
 import { TMixinOpts } from '../mixins';
 import { TTableSchemaWithId } from '../models/common';
 import { IRepository } from '../repositories';
@@ -379,6 +387,7 @@ export abstract class BaseApplication
           appErrorHandler({
             logger: this.logger,
             rootKey: this.configs.error?.rootKey ?? undefined,
+            reporters: this.errorReporters,
           }),
         );
 
@@ -388,6 +397,31 @@ export abstract class BaseApplication
 
         server.notFound(notFoundHandler({ logger: this.logger }));
 
+        // Registered before the request tracker, which already reads the body
+        const { requestTimeout: duration, bodyLimit: maxSize, cors } = this.configs.server ?? {};
+        if (cors) {
+          server.use(corsPolicy(cors));
+        }
+
+        if (duration) {
+          server.use(requestTimeout({ duration }));
+        }
+
+        if (maxSize) {
+          server.use(
+            bodyLimit({
+              maxSize,
+              onError: context => {
+                throw getError({
+                  statusCode: HTTP.ResultCodes.RS_4.ContentTooLarge,
+                  messageCode: AppErrorCodes.PAYLOAD_TOO_LARGE,
+                  message: `Request body exceeds ${maxSize} bytes | path: ${context.req.path}`,
+                });
+              },
+            }),
+          );
+        }
+
         // Assign requestId for every single request from client
         // NOTE: RequestTrackerComponent includes RequestSpyMiddleware which parses request body
         // This also works around Bun + Hono body parsing bug: https://github.com/honojs/middleware/issues/81
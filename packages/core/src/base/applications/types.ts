@@ -10,6 +10,7 @@ import {
 } from '../mixins/types';
 import { ValueOrPromise } from '@venizia/ignis-helpers';
 import { IBootOptions } from '@venizia/ignis-boot';
+import type { ICORSPolicyOptions } from '../middlewares';
 
 // ------------------------------------------------------------------------------
 // Common Middleware Options
@@ -76,6 +77,34 @@ export interface IBodyLimitOptions extends IBaseMiddlewareOptions {
 // ------------------------------------------------------------------------------
 export interface IRequestIdOptions extends IBaseMiddlewareOptions {}
 
+// ------------------------------------------------------------------------------
+// Server Options
+// ------------------------------------------------------------------------------
+export interface IGracefulShutdownOptions {
+  enable: boolean;
+  // Defaults to SIGINT and SIGTERM
+  signals?: Array<NodeJS.Signals>;
+  // Milliseconds to wait for in-flight requests before closing connections forcefully
+  timeout?: number;
+  // Milliseconds for the whole shutdown, hooks still running afterwards are abandoned
+  deadline?: number;
+  // Milliseconds to keep serving after readiness started failing, lets load balancers catch up
+  preStopDelay?: number;
+  // Exit the process once the application stopped, defaults to true
+  shouldExitProcess?: boolean;
+}
+
+export interface IServerOptions {
+  // Milliseconds, requests running longer fail with 504. Fetchers called by a request are capped
+  // by the time it has left, see `requestContext`
+  requestTimeout?: number;
+  // Bytes, larger request bodies are rejected with 413
+  bodyLimit?: number;
+  shutdown?: IGracefulShutdownOptions;
+  // Applied before the other default middlewares, preflight requests are answered early
+  cors?: ICORSPolicyOptions;
+}
+
 // ------------------------------------------------------------------------------
 // Application
 // ------------------------------------------------------------------------------
@@ -103,6 +132,7 @@ export interface IApplicationConfigs {
   asyncContext?: { enable: boolean };
   bootOptions?: IBootOptions;
   debug?: { shouldShowRoutes?: boolean };
+  server?: IServerOptions;
   [key: string]: any;
 }
 
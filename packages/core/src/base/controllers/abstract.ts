@@ -2,7 +2,7 @@ import { authenticate as authenticateFn, AuthenticationModes } from '@/component
 import { MetadataRegistry } from '@/helpers/inversion';
 import { htmlResponse } from '@/utilities/jsx.utility';
 import { createRoute, Hook, OpenAPIHono } from '@hono/zod-openapi';
-import { BaseHelper, getError, ValueOrPromise } from '@venizia/ignis-helpers';
+import { BaseHelper, getError, ValidationError, ValueOrPromise } from '@venizia/ignis-helpers';
 import { Env, Schema } from 'hono';
 import {
   IController,
@@ -109,7 +109,7 @@ export abstract class AbstractController<
       strict: isStrict,
       defaultHook: (result, _context) => {
         if (!result.success) {
-          throw result.error;
+          throw ValidationError.fromZodError({ error: result.error, target: result.target });
         }
       },
     });
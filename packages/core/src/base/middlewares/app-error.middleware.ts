@@ -1,5 +1,9 @@
-import { Logger, Environment, HTTP } from '@venizia/ignis-helpers';
-import { ErrorHandler, HTTPResponseError } from 'hono/types';
+import { AppErrorCodes } from '@/common/constants';
+import { z } from '@hono/zod-openapi';
+import { Logger, Environment, HTTP, ValidationError, ValueOrPromise } from '@venizia/ignis-helpers';
+import { Context } from 'hono';
+import { HTTPException } from 'hono/http-exception';
+import { ErrorHandler } from 'hono/types';
 import { RequestSpyMiddleware } from './request-spy.middleware';
 
 /**
@@ -81,62 +85,138 @@ const isDatabaseClientError = (opts: {
   return { isClientError: false };
 };
 
-const formatZodError = (opts: {
+/**
+ * Converts `ZodError`s thrown by handlers (e.g. `schema.parse`) into a `ValidationError`.
+ */
+const toValidationError = (error: Error): ValidationError | null => {
+  if (ValidationError.isValidationError(error)) {
+    return error;
+  }
+
+  if (error.name === 'ZodError' && Array.isArray((error as z.ZodError).issues)) {
+    return ValidationError.fromZodError({ error: error as z.ZodError });
+  }
+
+  return null;
+};
+
+const formatValidationError = (opts: {
   isProduction: boolean;
   requestId: string;
   url: string;
   path: string;
-  error: Error | HTTPResponseError;
+  error: ValidationError;
 }) => {
   const { isProduction, requestId, url, path, error } = opts;
-  const statusCode = HTTP.ResultCodes.RS_4.UnprocessableEntity;
-
-  let validationErrors = error;
-  try {
-    validationErrors = JSON.parse(error.message);
-  } catch (_) {
-    validationErrors = error;
-  }
 
   return {
-    statusCode,
+    statusCode: error.statusCode,
     response: {
-      message: 'ValidationError',
-      statusCode,
+      message: error.message,
+      statusCode: error.statusCode,
+      messageCode: error.messageCode,
       requestId,
       details: {
         url,
         path,
+        target: error.target,
         stack: !isProduction ? error.stack : undefined,
-        cause: Array.isArray(validationErrors)
-          ? validationErrors.map(el => ({
-              path: el.path.join('.') || 'root',
-              message: el.message,
-              code: el.code,
-              expected: el.expected,
-              received: el.received,
-            }))
-          : validationErrors,
+        cause: error.fields,
       },
     },
   };
 };
 
+/**
+ * Normalizes anything thrown by a handler into an `Error`.
+ * Strings, plain objects and other non-error values are wrapped, keeping the original as `cause`.
+ */
+const toError = (thrown: unknown): Error => {
+  if (thrown instanceof Error) {
+    return thrown;
+  }
+
+  const message =
+    typeof thrown === 'string'
+      ? thrown
+      : ((thrown as { message?: string })?.message ?? 'Internal Server Error');
+
+  return new Error(message, { cause: thrown });
+};
+
+/**
+ * Resolves the HTTP status of an error, falling back to 500 for anything outside 4xx / 5xx.
+ */
+const resolveStatusCode = (error: Error): number => {
+  let statusCode: unknown = HTTP.ResultCodes.RS_5.InternalServerError;
+
+  if (error instanceof HTTPException) {
+    statusCode = error.status;
+  } else if ('statusCode' in error) {
+    statusCode = error.statusCode;
+  }
+
+  if (typeof statusCode !== 'number' || statusCode < 400 || statusCode > 599) {
+    return HTTP.ResultCodes.RS_5.InternalServerError;
+  }
+
+  return statusCode;
+};
+
+export interface IErrorReport {
+  error: Error;
+  context: Context;
+  statusCode: number;
+  messageCode?: string;
+  requestId: string;
+}
+
+// Error tracking integrations (Sentry, ...), called for every handled error
+export type TErrorReporter = (report: IErrorReport) => ValueOrPromise<void>;
+
 /**
  * Creates an error handling middleware for the application.
  * This middleware catches errors, logs them, and formats the response for the client.
- * It also handles `ZodError` specifically for validation errors.
+ *
+ * Every error is returned with the same envelope:
+ * `{ message, statusCode, messageCode, requestId, details: { url, path, stack, cause } }`
+ * - `ApplicationError` (`getError`) keeps its `statusCode` and `messageCode`.
+ * - Hono `HTTPException` uses its `status`.
+ * - `ValidationError` and `ZodError` are returned as 422 with the field errors in `details.cause`.
+ * - Database constraint violations are returned as 400.
+ * - Anything else, including non-error throws, is returned as 500.
  *
  * @param opts - Options for the error handler.
  * @param opts.logger - The application logger instance. Defaults to `console`.
  * @param opts.rootKey - Optional: A key to wrap the error response in.
+ * @param opts.reporters - Optional: Reporters notified of every error, failures are only logged.
  * @returns An `ErrorHandler` middleware function.
  */
-export const appErrorHandler = (opts: { logger: Logger; rootKey?: string }) => {
-  const { logger = console, rootKey = null } = opts;
+export const appErrorHandler = (opts: {
+  logger: Logger;
+  rootKey?: string;
+  reporters?: Array<TErrorReporter>;
+}) => {
+  const { logger = console, rootKey = null, reporters = [] } = opts;
+
+  // Reporting never delays nor changes the response
+  const report = (payload: IErrorReport) => {
+    for (const reporter of reporters) {
+      Promise.resolve()
+        .then(() => reporter(payload))
+        .catch(error => {
+          logger.error(
+            '[onError][%s] Failed to report error | Error: %s',
+            payload.requestId,
+            error,
+          );
+        });
+    }
+  };
 
-  const mw: ErrorHandler = async (error, context) => {
+  const mw: ErrorHandler = async (thrown, context) => {
     const requestId = context.get(RequestSpyMiddleware.REQUEST_ID_KEY);
+    const error = toError(thrown);
 
     logger.error(
       '[onError][%s] REQUEST ERROR | path: %s | url: %s | Error: %j',
@@ -149,32 +229,50 @@ export const appErrorHandler = (opts: { logger: Logger; rootKey?: string }) => {
     const env = context.env?.NODE_ENV || process.env.NODE_ENV;
     const isProduction = env?.toLowerCase() === Environment.PRODUCTION;
 
-    const statusCode =
-      'statusCode' in error ? error.statusCode : HTTP.ResultCodes.RS_5.InternalServerError;
-
-    if (error.name === 'ZodError') {
-      const rs = formatZodError({
+    const validationError = toValidationError(error);
+    if (validationError) {
+      const rs = formatValidationError({
         isProduction,
         requestId,
         url: context.req.url,
         path: context.req.path,
-        error,
+        error: validationError,
+      });
+      report({
+        error: validationError,
+        context,
+        statusCode: rs.statusCode,
+        messageCode: validationError.messageCode,
+        requestId,
       });
 
-      return context.json(rs.response, rs.statusCode);
+      return context.json(
+        rootKey ? { [rootKey]: rs.response } : rs.response,
+        rs.statusCode as Parameters<typeof context.json>[1],
+      );
     }
 
     // Determine if this is a database client error (should be 400, not 500)
     const dbError = isDatabaseClientError({ error });
-    const resolvedStatusCode = dbError.isClientError
+    const statusCode = dbError.isClientError
       ? HTTP.ResultCodes.RS_4.BadRequest
-      : statusCode;
-    const resolvedMessage =
-      dbError.isClientError && dbError.message ? dbError.message : error.message;
+      : resolveStatusCode(error);
+    const message = dbError.isClientError && dbError.message ? dbError.message : error.message;
+
+    let messageCode: string | undefined =
+      'messageCode' in error ? (error.messageCode as string) : undefined;
+    if (!messageCode && dbError.isClientError) {
+      messageCode = AppErrorCodes.DATABASE_CONSTRAINT;
+    } else if (!messageCode && statusCode === HTTP.ResultCodes.RS_5.InternalServerError) {
+      messageCode = AppErrorCodes.INTERNAL_ERROR;
+    }
+
+    report({ error, context, statusCode, messageCode, requestId });
 
     const rs = {
-      message: resolvedMessage,
-      statusCode: resolvedStatusCode,
+      message,
+      statusCode,
+      messageCode,
       requestId,
       details: {
         url: context.req.url,
@@ -186,7 +284,7 @@ export const appErrorHandler = (opts: { logger: Logger; rootKey?: string }) => {
 
     return context.json(
       rootKey ? { [rootKey]: rs } : rs,
-      resolvedStatusCode as Parameters<typeof context.json>[1],
+      statusCode as Parameters<typeof context.json>[1],
     );
   };
 
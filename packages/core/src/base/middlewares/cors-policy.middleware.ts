@@ -0,0 +1,143 @@
+import { getError, HTTP } from '@venizia/ignis-helpers';
+import { MiddlewareHandler } from 'hono';
+import { cors } from 'hono/cors';
+import { createMiddleware } from 'hono/factory';
+
+export interface ICORSPolicy {
+  /**
+   * Allowed origins, either exact (`https://app.example.com`), with a subdomain wildcard
+   * (`https://*.example.com`) or `*`. A comma separated string is accepted for env values.
+   */
+  origins: string | Array<string>;
+  allowMethods?: Array<string>;
+  allowHeaders?: Array<string>;
+  exposeHeaders?: Array<string>;
+  credentials?: boolean;
+  // Seconds browsers may cache a preflight response
+  maxAge?: number;
+}
+
+export interface ICORSPolicyOptions extends ICORSPolicy {
+  // First matching path wins, e.g. `/public/*` or `/webhooks/stripe`
+  overrides?: Array<{ path: string; policy: Partial<ICORSPolicy> }>;
+}
+
+const DEFAULT_ALLOW_METHODS = ['GET', 'HEAD', 'PUT', 'PATCH', 'POST', 'DELETE', 'OPTIONS'];
+
+const WILDCARD_PATTERN = '[a-z0-9-]+(\\.[a-z0-9-]+)*';
+
+const escapeRegExp = (input: string) => input.replace(/[.+?^${}()|[\]\\]/g, '\\$&');
+
+/**
+ * Normalize configured origins, dropping blanks and trailing slashes.
+ */
+export const parseCORSOrigins = (origins: ICORSPolicy['origins']): Array<string> => {
+  const list = Array.isArray(origins) ? origins : `${origins ?? ''}`.split(',');
+  return list.map(origin => origin.trim().replace(/\/+$/, '')).filter(origin => !!origin);
+};
+
+/**
+ * Build a matcher for a list of origins, `*` in a host only matches whole subdomain labels.
+ */
+export const buildCORSOriginMatcher = (origins: ICORSPolicy['origins']) => {
+  const list = parseCORSOrigins(origins);
+  if (list.includes('*')) {
+    return (_origin: string) => true;
+  }
+
+  const exact = new Set(list.filter(origin => !origin.includes('*')));
+  const patterns = list
+    .filter(origin => origin.includes('*'))
+    .map(origin => {
+      return new RegExp(`^${escapeRegExp(origin).replace(/\*/g, WILDCARD_PATTERN)}$`, 'i');
+    });
+
+  return (origin: string) => {
+    return exact.has(origin) || patterns.some(pattern => pattern.test(origin));
+  };
+};
+
+const buildCORSHandler = (policy: ICORSPolicy): MiddlewareHandler => {
+  const {
+    origins,
+    allowMethods = DEFAULT_ALLOW_METHODS,
+    allowHeaders = [],
+    exposeHeaders = [],
+    credentials = false,
+    maxAge,
+  } = policy;
+
+  const list = parseCORSOrigins(origins);
+  if (!list.length) {
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+      message: '[corsPolicy] Invalid CORS policy, at least one origin is required!',
+    });
+  }
+
+  // Browsers reject credentialed responses with `Access-Control-Allow-Origin: *`
+  if (credentials && list.includes('*')) {
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+      message: '[corsPolicy] Invalid CORS policy, origin "*" can not be used with credentials!',
+    });
+  }
+
+  const isAllowed = buildCORSOriginMatcher(list);
+  return cors({
+    origin: list.includes('*') ? '*' : origin => (isAllowed(origin) ? origin : null),
+    allowMethods,
+    allowHeaders,
+    exposeHeaders,
+    credentials,
+    maxAge,
+  });
+};
+
+const isPathMatched = (opts: { pattern: string; path: string }) => {
+  const { pattern, path } = opts;
+  if (pattern.endsWith('*')) {
+    return path.startsWith(pattern.slice(0, -1));
+  }
+
+  return path === pattern;
+};
+
+/**
+ * Creates a CORS middleware from a config driven policy.
+ * Origins are matched exactly or by subdomain wildcard and echoed back, unknown origins get
+ * no CORS headers. Invalid policies throw when the middleware is built, not per request.
+ *
+ * @example
+ * ```typescript
+ * server.use(
+ *   corsPolicy({
+ *     origins: ['https://app.example.com', 'https://*.example.com'],
+ *     credentials: true,
+ *     maxAge: 600,
+ *     overrides: [{ path: '/public/*', policy: { origins: '*', credentials: false } }],
+ *   }),
+ * );
+ * ```
+ *
+ * @param opts - Default policy and per path overrides.
+ * @returns A `MiddlewareHandler` function.
+ */
+export const corsPolicy = (opts: ICORSPolicyOptions) => {
+  const { overrides = [], ...policy } = opts;
+
+  const defaultHandler = buildCORSHandler(policy);
+  const overrideHandlers = overrides.map(override => ({
+    path: override.path,
+    handler: buildCORSHandler({ ...policy, ...override.policy }),
+  }));
+
+  return createMiddleware(async (context, next) => {
+    const path = context.req.path;
+    const matched = overrideHandlers.find(override => {
+      return isPathMatched({ pattern: override.path, path });
+    });
+
+    return (matched?.handler ?? defaultHandler)(context, next);
+  });
+};
@@ -1,4 +1,10 @@
 export * from './app-error.middleware';
+export * from './cors-policy.middleware';
 export * from './emoji-favicon.middleware';
 export * from './not-found.middleware';
+export * from './rate-limit.middleware';
+export * from './request-context.middleware';
 export * from './request-spy.middleware';
+export * from './request-timeout.middleware';
+export * from './request-validator.middleware';
+export * from './tenant.middleware';
@@ -0,0 +1,119 @@
+import { AppErrorCodes } from '@/common/constants';
+import { getError, HTTP, IRateLimiter, IRateLimitResult } from '@venizia/ignis-helpers';
+import { Context } from 'hono';
+import { createMiddleware } from 'hono/factory';
+import { routePath } from 'hono/route';
+import C from 'node:crypto';
+
+export type TRateLimitKeyResolver = (context: Context) => string | null | undefined;
+
+export interface IRateLimitOptions {
+  limiter: IRateLimiter;
+  limit: number;
+  // Milliseconds
+  window: number;
+  // Namespace of the counters, use one per route group, e.g. `auth`, `public-api`
+  prefix?: string;
+  // Defaults to the API key, then the client IP. Returning nothing skips the request
+  keyResolver?: TRateLimitKeyResolver;
+  // Count per matched route instead of per group
+  isPerRoute?: boolean;
+  // Hook for metrics, called for every counted request
+  onConsumed?: (opts: { context: Context; key: string; result: IRateLimitResult }) => void;
+}
+
+/**
+ * Client IP from proxy headers, falling back to the Bun socket address.
+ * NOTE: `x-forwarded-for` can be spoofed unless the application runs behind a trusted proxy.
+ */
+export const getClientIp = (context: Context): string | undefined => {
+  const forwarded = context.req.header('x-forwarded-for')?.split(',')[0]?.trim();
+  if (forwarded) {
+    return forwarded;
+  }
+
+  const realIp = context.req.header('x-real-ip');
+  if (realIp) {
+    return realIp;
+  }
+
+  return context.env?.requestIP?.(context.req.raw)?.address;
+};
+
+export const RateLimitKeyResolvers = {
+  ip: (context: Context) => {
+    const ip = getClientIp(context);
+    return ip ? `ip:${ip}` : undefined;
+  },
+  apiKey: (context: Context) => {
+    // Keep raw keys out of the counter names
+    const apiKey = context.req.header('x-api-key');
+    return apiKey ? `key:${C.createHash('sha256').update(apiKey).digest('hex')}` : undefined;
+  },
+  apiKeyOrIp: (context: Context) => {
+    return RateLimitKeyResolvers.apiKey(context) ?? RateLimitKeyResolvers.ip(context);
+  },
+};
+
+/**
+ * Creates a middleware limiting requests per client within a fixed window.
+ *
+ * Every response carries `RateLimit-Limit`, `RateLimit-Remaining` and `RateLimit-Reset`,
+ * rejected requests fail with 429 and `Retry-After` through the application error handler.
+ * Use a `RedisRateLimiter` to share the counters between instances.
+ *
+ * @example
+ * ```typescript
+ * const limiter = new RedisRateLimiter({ redis });
+ *
+ * server.use('/auth/*', rateLimit({ limiter, prefix: 'auth', limit: 10, window: 60_000 }));
+ * server.use(
+ *   '/api/*',
+ *   rateLimit({ limiter, prefix: 'api', limit: 1_000, window: 60_000, isPerRoute: true }),
+ * );
+ * ```
+ *
+ * @returns A `MiddlewareHandler` function.
+ */
+export const rateLimit = (opts: IRateLimitOptions) => {
+  const {
+    limiter,
+    limit,
+    window,
+    prefix = 'default',
+    keyResolver = RateLimitKeyResolvers.apiKeyOrIp,
+    isPerRoute = false,
+    onConsumed,
+  } = opts;
+
+  return createMiddleware(async (context, next) => {
+    const client = keyResolver(context);
+    if (!client) {
+      return next();
+    }
+
+    const segments = [prefix, client];
+    if (isPerRoute) {
+      segments.push(`${context.req.method}:${routePath(context, -1)}`);
+    }
+
+    const key = segments.join(':');
+    const result = await limiter.consume({ key, limit, window });
+    onConsumed?.({ context, key, result });
+
+    context.header('RateLimit-Limit', `${result.limit}`);
+    context.header('RateLimit-Remaining', `${result.remaining}`);
+    context.header('RateLimit-Reset', `${Math.ceil((result.resetAt - Date.now()) / 1000)}`);
+
+    if (!result.isAllowed) {
+      context.header('Retry-After', `${Math.ceil(result.retryAfter / 1000)}`);
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.TooManyRequests,
+        messageCode: AppErrorCodes.RATE_LIMITED,
+        message: `Too many requests, retry after ${Math.ceil(result.retryAfter / 1000)}s`,
+      });
+    }
+
+    return next();
+  });
+};
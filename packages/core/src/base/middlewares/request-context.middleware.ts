@@ -0,0 +1,59 @@
+import {
+  IRequestContext,
+  RequestContextHeaders,
+  RequestContextStorage,
+  RequestDeadlines,
+} from '@venizia/ignis-helpers';
+import { createMiddleware } from 'hono/factory';
+import { RequestSpyMiddleware } from './request-spy.middleware';
+
+/**
+ * Creates a middleware that opens a `RequestContextStorage` scope for every request.
+ * The context is seeded with the request id (set by `hono/request-id`), the authentication
+ * middleware adds the user id once a strategy succeeded. The tenant is only bound by
+ * `tenantContext`, a client sending `x-tenant-id` does not select it.
+ *
+ * The deadline of the caller (`x-request-deadline` or `grpc-timeout`) is kept in the scope too,
+ * capped by `requestTimeout` of the server, with the signal of the request which aborts once the
+ * client disconnects.
+ *
+ * Fetchers read the scope to forward `x-request-id` / `x-tenant-id` / `x-request-deadline` to
+ * downstream services, and refuse the calls the remaining budget can not cover.
+ *
+ * @param opts.requestTimeout - Milliseconds given to a request, see `server.requestTimeout`.
+ * @returns A `MiddlewareHandler` function.
+ */
+export const requestContext = (opts: { requestTimeout?: number } = {}) => {
+  const { requestTimeout } = opts;
+
+  return createMiddleware(async (context, next) => {
+    const requestId =
+      context.get(RequestSpyMiddleware.REQUEST_ID_KEY) ??
+      context.req.header(RequestContextHeaders.REQUEST_ID) ??
+      crypto.randomUUID();
+
+    return RequestContextStorage.run({
+      context: {
+        requestId,
+        deadline: RequestDeadlines.earliest([
+          RequestDeadlines.parse({
+            deadline: context.req.header(RequestContextHeaders.DEADLINE),
+            grpcTimeout: context.req.header(RequestContextHeaders.GRPC_TIMEOUT),
+          }),
+          requestTimeout ? Date.now() + requestTimeout : undefined,
+        ]),
+        signal: context.req.raw.signal,
+      },
+      task: () => next(),
+    });
+  });
+};
+
+/**
+ * Read the context of the request being handled, e.g. from a service or repository.
+ */
+export const getRequestContext = <
+  TExtra extends Record<string, any> = Record<string, any>,
+>(): IRequestContext<TExtra> | undefined => {
+  return RequestContextStorage.get<TExtra>();
+};
@@ -0,0 +1,39 @@
+import { AppErrorCodes } from '@/common/constants';
+import { getError, HTTP } from '@venizia/ignis-helpers';
+import { createMiddleware } from 'hono/factory';
+
+/**
+ * Creates a middleware that fails requests taking longer than `duration` with a 504.
+ * The error is thrown through the application error handler, so the response keeps the
+ * usual error shape and request id.
+ *
+ * NOTE: The handler itself is not cancelled, long running work should watch `c.req.raw.signal`.
+ *
+ * @param opts.duration - Timeout in milliseconds.
+ * @returns A `MiddlewareHandler` function.
+ */
+export const requestTimeout = (opts: { duration: number }) => {
+  const { duration } = opts;
+
+  return createMiddleware(async (context, next) => {
+    let timer: ReturnType<typeof setTimeout> | undefined;
+
+    const timeout = new Promise<never>((_resolve, reject) => {
+      timer = setTimeout(() => {
+        reject(
+          getError({
+            statusCode: HTTP.ResultCodes.RS_5.GatewayTimeout,
+            messageCode: AppErrorCodes.REQUEST_TIMEOUT,
+            message: `Request timed out after ${duration}ms | path: ${context.req.path}`,
+          }),
+        );
+      }, duration);
+    });
+
+    try {
+      await Promise.race([next(), timeout]);
+    } finally {
+      clearTimeout(timer);
+    }
+  });
+};
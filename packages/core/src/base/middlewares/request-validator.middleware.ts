@@ -0,0 +1,42 @@
+import { z } from '@hono/zod-openapi';
+import { ValidationError } from '@venizia/ignis-helpers';
+import { ValidationTargets } from 'hono';
+import { validator } from 'hono/validator';
+
+/**
+ * Creates a middleware validating one part of the request against a zod schema, for routes
+ * that are not declared through OpenAPI route configs (those are validated automatically).
+ *
+ * Failures are thrown as `ValidationError`, the application error handler returns them as 422
+ * with the field errors in `details.cause`. The parsed value is read with `context.req.valid`.
+ *
+ * @example
+ * ```typescript
+ * router.get('/search', requestValidator({ target: 'query', schema: SearchQuerySchema }), c => {
+ *   const { keyword } = c.req.valid('query');
+ *   return c.json(await searchService.find({ keyword }));
+ * });
+ * ```
+ *
+ * @param opts.target - Part of the request to validate, e.g. `json`, `query`, `param`, `header`.
+ * @param opts.schema - Zod schema of the value.
+ * @returns A `MiddlewareHandler` function.
+ */
+export const requestValidator = <
+  Target extends keyof ValidationTargets,
+  Schema extends z.ZodType,
+>(opts: {
+  target: Target;
+  schema: Schema;
+}) => {
+  const { target, schema } = opts;
+
+  return validator(target, async value => {
+    const rs = await schema.safeParseAsync(value);
+    if (!rs.success) {
+      throw ValidationError.fromZodError({ error: rs.error, target });
+    }
+
+    return rs.data as z.output<Schema>;
+  });
+};
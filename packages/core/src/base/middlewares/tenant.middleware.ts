@@ -0,0 +1,127 @@
+import { Authentication } from '@/components/auth/authenticate/common/constants';
+import {
+  getError,
+  HTTP,
+  RequestContextHeaders,
+  RequestContextStorage,
+  TenantErrorCodes,
+  ValueOrPromise,
+} from '@venizia/ignis-helpers';
+import { Context } from 'hono';
+import { createMiddleware } from 'hono/factory';
+
+export type TTenantResolver = (context: Context) => ValueOrPromise<string | null | undefined>;
+
+export interface ITenantContextOptions {
+  // Tried in order, the first non empty value wins
+  resolvers: Array<TTenantResolver>;
+  // Reject requests without a tenant with 400, defaults to true
+  isRequired?: boolean;
+  // Hook once the tenant is resolved, e.g. reject unknown tenants or select the tenant schema
+  onResolved?: (opts: { context: Context; tenantId: string }) => ValueOrPromise<void>;
+}
+
+export class TenantContextKeys {
+  static readonly TENANT_ID = 'tenant.id';
+}
+
+declare module 'hono' {
+  // eslint-disable-next-line @typescript-eslint/naming-convention
+  interface ContextVariableMap {
+    [TenantContextKeys.TENANT_ID]: string;
+  }
+}
+
+export const TenantResolvers = {
+  header: (opts: { name?: string } = {}): TTenantResolver => {
+    const { name = RequestContextHeaders.TENANT_ID } = opts;
+    return context => context.req.header(name);
+  },
+  /**
+   * `acme.api.example.com` resolves `acme` with `baseDomain: 'api.example.com'`.
+   */
+  subdomain: (opts: { baseDomain: string }): TTenantResolver => {
+    const suffix = `.${opts.baseDomain.toLowerCase()}`;
+
+    return context => {
+      const host = (context.req.header('host') ?? '').split(':')[0].toLowerCase();
+      if (!host.endsWith(suffix)) {
+        return undefined;
+      }
+
+      const subdomain = host.slice(0, -suffix.length);
+      return subdomain && !subdomain.includes('.') ? subdomain : undefined;
+    };
+  },
+  /**
+   * Read a claim of the verified JWT, mount the tenant middleware after `jwtBearerAuth`.
+   */
+  claim: (opts: { name?: string } = {}): TTenantResolver => {
+    const { name = 'tenantId' } = opts;
+
+    return context => {
+      const claims = context.get(Authentication.JWT_CLAIMS) as Record<string, unknown> | undefined;
+      const value = claims?.[name];
+      return value === undefined || value === null ? undefined : `${value}`;
+    };
+  },
+};
+
+/**
+ * Creates a middleware resolving the tenant of every request and binding it to the
+ * request context. Services read it with `TenantContext.get()` and fetchers forward it
+ * as `x-tenant-id` to downstream services.
+ *
+ * NOTE: Requires the `RequestTrackerComponent` (registered by default) for the context scope.
+ *
+ * @example
+ * ```typescript
+ * server.use(
+ *   '/api/*',
+ *   tenantContext({
+ *     resolvers: [
+ *       TenantResolvers.claim(),
+ *       TenantResolvers.subdomain({ baseDomain: 'api.example.com' }),
+ *     ],
+ *     onResolved: ({ tenantId }) => tenantService.assertActive({ tenantId }),
+ *   }),
+ * );
+ * ```
+ *
+ * @returns A `MiddlewareHandler` function.
+ */
+export const tenantContext = (opts: ITenantContextOptions) => {
+  const { resolvers, isRequired = true, onResolved } = opts;
+
+  return createMiddleware(async (context, next) => {
+    let tenantId: string | undefined;
+    for (const resolver of resolvers) {
+      const value = (await resolver(context))?.trim();
+      if (value) {
+        tenantId = value;
+        break;
+      }
+    }
+
+    if (!tenantId) {
+      // Nothing the client sent stands for the tenant
+      RequestContextStorage.update({ tenantId: undefined });
+
+      if (isRequired) {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+          messageCode: TenantErrorCodes.TENANT_REQUIRED,
+          message: 'Unable to resolve the tenant of the request!',
+        });
+      }
+
+      return next();
+    }
+
+    context.set(TenantContextKeys.TENANT_ID, tenantId);
+    RequestContextStorage.update({ tenantId });
+    await onResolved?.({ context, tenantId });
+
+    return next();
+  });
+};
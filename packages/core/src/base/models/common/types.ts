@@ -1,10 +1,11 @@
 import { TRelationConfig } from '@/base/repositories';
 import { z } from '@hono/zod-openapi';
 import {
-  ErrorSchema,
+  ErrorResponseSchema,
   getError,
   HTTP,
   keysToCamel,
+  paginatedResponseSchema,
   toCamel,
   TValueOrResolver,
 } from '@venizia/ignis-helpers';
@@ -130,10 +131,23 @@ export const jsonResponse = <
 
   return {
     [HTTP.ResultCodes.RS_2.Ok]: successResponse,
-    ['4xx | 5xx']: jsonContent({ description: 'Error Response', schema: ErrorSchema }),
+    ['4xx | 5xx']: jsonContent({ description: 'Error Response', schema: ErrorResponseSchema }),
   };
 };
 
+/**
+ * Same as `jsonResponse`, with the content wrapped in the paginated `{ data, meta }` envelope
+ * built by `ApiResponses.paginated`.
+ */
+export const paginatedJsonResponse = <
+  ItemSchema extends z.ZodType,
+  HeaderSchema extends TResponseHeaders | undefined = undefined,
+>(
+  opts: TJsonResponseOpts<ItemSchema, HeaderSchema>,
+) => {
+  return jsonResponse({ ...opts, schema: paginatedResponseSchema({ schema: opts.schema }) });
+};
+
 // -------------------------------------------------------------------------
 type TSnakeToCamelCase<S extends string> = S extends `${infer T}_${infer U}`
   ? `${T}${Capitalize<TSnakeToCamelCase<U>>}`
@@ -12,3 +12,15 @@ export class App {
   static readonly DS_MEMORY = 'memory';
   static readonly DS_REDIS = 'redis';
 }
+
+// ------------------------------------------------------------------------------
+/**
+ * Message codes set by the application error handler and the default middlewares.
+ */
+export class AppErrorCodes {
+  static readonly INTERNAL_ERROR = 'INTERNAL_ERROR';
+  static readonly DATABASE_CONSTRAINT = 'DATABASE_CONSTRAINT';
+  static readonly REQUEST_TIMEOUT = 'REQUEST_TIMEOUT';
+  static readonly PAYLOAD_TOO_LARGE = 'PAYLOAD_TOO_LARGE';
+  static readonly RATE_LIMITED = 'RATE_LIMITED';
+}
@@ -0,0 +1,2 @@
+export * from './keys';
+export * from './types';
@@ -0,0 +1,4 @@
+export class AuditBindingKeys {
+  static readonly AUDIT_OPTIONS = '@app/audit/options';
+  static readonly AUDIT_LOGGER = '@app/audit/logger';
+}
@@ -0,0 +1,7 @@
+import { IAuditSink } from '@venizia/ignis-helpers';
+
+export interface IAuditOptions {
+  sinks: Array<IAuditSink>;
+  // Fail the operation when a sink fails, defaults to false (failures are logged)
+  isStrict?: boolean;
+}
@@ -0,0 +1,49 @@
+import { BaseApplication } from '@/base/applications';
+import { BaseComponent } from '@/base/components';
+import { inject } from '@/base/metadata';
+import { CoreBindings } from '@/common/bindings';
+import { Binding } from '@/helpers/inversion';
+import { AuditLogger, getError, ValueOrPromise } from '@venizia/ignis-helpers';
+import { AuditBindingKeys, IAuditOptions } from './common';
+
+/**
+ * Binds the application `AuditLogger` at `AuditBindingKeys.AUDIT_LOGGER` from the configured
+ * sinks, services inject it to record domain events and `auditTrail` uses it for HTTP
+ * operations.
+ *
+ * @example
+ * ```typescript
+ * this.bind<IAuditOptions>({ key: AuditBindingKeys.AUDIT_OPTIONS }).toValue({
+ *   sinks: [new DrizzleAuditSink({ connector: postgresDataSource.getConnector() })],
+ * });
+ * this.component(AuditComponent);
+ * ```
+ */
+export class AuditComponent extends BaseComponent {
+  constructor(
+    @inject({ key: CoreBindings.APPLICATION_INSTANCE }) private application: BaseApplication,
+  ) {
+    super({
+      scope: AuditComponent.name,
+      initDefault: { enable: true, container: application },
+      bindings: {},
+    });
+  }
+
+  override binding(): ValueOrPromise<void> {
+    const options = this.application.get<IAuditOptions>({
+      key: AuditBindingKeys.AUDIT_OPTIONS,
+      isOptional: true,
+    });
+
+    if (!options?.sinks?.length) {
+      throw getError({
+        message: '[AuditComponent][binding] Invalid audit options, at least one sink is required!',
+      });
+    }
+
+    this.application
+      .bind<AuditLogger>({ key: AuditBindingKeys.AUDIT_LOGGER })
+      .toValue(new AuditLogger({ sinks: options.sinks, isStrict: options.isStrict }));
+  }
+}
@@ -0,0 +1,5 @@
+export * from './common';
+export * from './component';
+export * from './middleware';
+export * from './models';
+export * from './sink';
@@ -0,0 +1,129 @@
+import { getClientIp } from '@/base/middlewares';
+import {
+  AuditLogger,
+  AuditOutcomes,
+  IAuditResource,
+  TAuditEventInput,
+} from '@venizia/ignis-helpers';
+import { Context } from 'hono';
+import { createMiddleware } from 'hono/factory';
+import { routePath } from 'hono/route';
+
+export class AuditContextKeys {
+  static readonly ENTRY = 'audit.entry';
+}
+
+declare module 'hono' {
+  // eslint-disable-next-line @typescript-eslint/naming-convention
+  interface ContextVariableMap {
+    [AuditContextKeys.ENTRY]: Partial<TAuditEventInput>;
+  }
+}
+
+const MUTATING_METHODS = ['POST', 'PUT', 'PATCH', 'DELETE'];
+
+const ACTION_VERBS: Record<string, string> = {
+  POST: 'create',
+  PUT: 'update',
+  PATCH: 'update',
+  DELETE: 'delete',
+};
+
+export interface IAuditTrailOptions {
+  auditLogger: AuditLogger;
+  // Defaults to POST, PUT, PATCH and DELETE
+  methods?: Array<string>;
+  // Defaults to the last static segment of the route and its last parameter
+  resolveResource?: (context: Context) => IAuditResource;
+  // Defaults to `<resource type>.<create|update|delete>`
+  resolveAction?: (opts: { context: Context; resource: IAuditResource }) => string;
+  skip?: (context: Context) => boolean;
+}
+
+/**
+ * Complete the audit event of the current request from the handler, e.g. with the state of
+ * the resource before and after the operation. Later calls are merged into earlier ones.
+ */
+export const setAuditEntry = (context: Context, entry: Partial<TAuditEventInput>) => {
+  context.set(AuditContextKeys.ENTRY, { ...context.get(AuditContextKeys.ENTRY), ...entry });
+};
+
+/**
+ * `/api/orders/:id/items/:itemId` resolves `{ type: 'items', id: <itemId> }`.
+ */
+export const resolveRouteResource = (context: Context): IAuditResource => {
+  const route = routePath(context, -1);
+  const segments = route.split('/').filter(segment => !!segment && segment !== '*');
+
+  const staticSegments = segments.filter(segment => !segment.startsWith(':'));
+  const params = segments.filter(segment => segment.startsWith(':'));
+  const lastParam = params[params.length - 1]?.slice(1).replace(/\{.*\}$/, '');
+
+  return {
+    type: staticSegments[staticSegments.length - 1] ?? 'unknown',
+    id: lastParam ? context.req.param(lastParam) : undefined,
+  };
+};
+
+/**
+ * Creates a middleware recording an audit event for every mutating request once it was
+ * handled, failed requests (status >= 400) are recorded with the `failure` outcome.
+ *
+ * Handlers add the resource state with `setAuditEntry`, anything set there overrides the
+ * resolved values.
+ *
+ * @example
+ * ```typescript
+ * server.use('/api/*', auditTrail({ auditLogger }));
+ *
+ * router.patch('/orders/:id', async c => {
+ *   const before = await orderService.findById({ id: c.req.param('id') });
+ *   const after = await orderService.update({ id: before.id, data: await c.req.json() });
+ *   setAuditEntry(c, { action: 'order.update', before, after });
+ *   return c.json(after);
+ * });
+ * ```
+ *
+ * @returns A `MiddlewareHandler` function.
+ */
+export const auditTrail = (opts: IAuditTrailOptions) => {
+  const {
+    auditLogger,
+    methods = MUTATING_METHODS,
+    resolveResource = resolveRouteResource,
+    resolveAction = ({ context, resource }) => {
+      const verb = ACTION_VERBS[context.req.method] ?? context.req.method.toLowerCase();
+      return `${resource.type}.${verb}`;
+    },
+    skip,
+  } = opts;
+  const auditedMethods = new Set(methods.map(method => method.toUpperCase()));
+
+  return createMiddleware(async (context, next) => {
+    if (!auditedMethods.has(context.req.method) || skip?.(context)) {
+      return next();
+    }
+
+    await next();
+
+    const entry = context.get(AuditContextKeys.ENTRY) ?? {};
+    const resource = entry.resource ?? resolveResource(context);
+    const statusCode = context.res.status;
+
+    await auditLogger.record({
+      ...entry,
+      action: entry.action ?? resolveAction({ context, resource }),
+      resource,
+      outcome:
+        entry.outcome ??
+        (context.error || statusCode >= 400 ? AuditOutcomes.FAILURE : AuditOutcomes.SUCCESS),
+      actor: { ip: getClientIp(context), ...entry.actor },
+      metadata: {
+        method: context.req.method,
+        path: context.req.path,
+        statusCode,
+        ...entry.metadata,
+      },
+    });
+  });
+};
@@ -0,0 +1,45 @@
+import { model } from '@/base/metadata';
+import { BaseEntity, generateIdColumnDefs, TTableObject } from '@/base/models';
+import { index, jsonb, pgTable, text, timestamp } from 'drizzle-orm/pg-core';
+
+// ================================================================================
+/**
+ * BaseAuditEventModel using static schema pattern.
+ *
+ * Append only table of the audit events written by `DrizzleAuditSink`.
+ */
+@model({ type: 'entity', skipMigrate: true })
+export class BaseAuditEventModel extends BaseEntity<typeof BaseAuditEventModel.schema> {
+  static override schema = pgTable(
+    'AuditEvent',
+    {
+      ...generateIdColumnDefs({ id: { dataType: 'string' } }),
+      action: text().notNull(),
+      outcome: text().notNull(),
+      actorId: text('actor_id'),
+      actorType: text('actor_type'),
+      actorIp: text('actor_ip'),
+      resourceType: text('resource_type').notNull(),
+      resourceId: text('resource_id'),
+      before: jsonb().$type<Record<string, any>>(),
+      after: jsonb().$type<Record<string, any>>(),
+      correlationId: text('correlation_id'),
+      tenantId: text('tenant_id'),
+      metadata: jsonb().$type<Record<string, any>>(),
+      occurredAt: timestamp('occurred_at', { mode: 'date', withTimezone: true }).notNull(),
+    },
+    def => [
+      index(`IDX_AuditEvent_resource`).on(def.resourceType, def.resourceId),
+      index(`IDX_AuditEvent_actorId`).on(def.actorId),
+      index(`IDX_AuditEvent_correlationId`).on(def.correlationId),
+      index(`IDX_AuditEvent_occurredAt`).on(def.occurredAt),
+    ],
+  );
+
+  static override relations = () => [];
+}
+
+// ================================================================================
+// Type exports
+export type TAuditEventSchema = typeof BaseAuditEventModel.schema;
+export type TAuditEventRecord = TTableObject<TAuditEventSchema>;
@@ -0,0 +1 @@
+export * from './audit-event.model';
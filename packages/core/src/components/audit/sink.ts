@@ -0,0 +1,43 @@
+import { TAnyConnector } from '@/base/datasources';
+import { IAuditEvent, IAuditSink } from '@venizia/ignis-helpers';
+import { BaseAuditEventModel, TAuditEventSchema } from './models';
+
+/**
+ * Writes audit events to the `AuditEvent` table, one multi row insert per batch.
+ *
+ * @example
+ * ```typescript
+ * new DrizzleAuditSink({ connector: postgresDataSource.getConnector() });
+ * ```
+ */
+export class DrizzleAuditSink implements IAuditSink {
+  readonly name = 'postgres';
+  private connector: TAnyConnector;
+  private table: TAuditEventSchema;
+
+  constructor(opts: { connector: TAnyConnector; table?: TAuditEventSchema }) {
+    this.connector = opts.connector;
+    this.table = opts.table ?? BaseAuditEventModel.schema;
+  }
+
+  async write(opts: { events: Array<IAuditEvent> }) {
+    const rows = opts.events.map(event => ({
+      id: event.id,
+      action: event.action,
+      outcome: event.outcome,
+      actorId: event.actor.id ?? null,
+      actorType: event.actor.type ?? null,
+      actorIp: event.actor.ip ?? null,
+      resourceType: event.resource.type,
+      resourceId: event.resource.id ?? null,
+      before: event.before ?? null,
+      after: event.after ?? null,
+      correlationId: event.correlationId ?? null,
+      tenantId: event.tenantId ?? null,
+      metadata: event.metadata ?? null,
+      occurredAt: event.occurredAt,
+    }));
+
+    await this.connector.insert(this.table).values(rows);
+  }
+}
@@ -33,6 +33,8 @@ export class Authentication {
 
   static readonly CURRENT_USER = 'auth.current.user';
   static readonly AUDIT_USER_ID = 'audit.user.id';
+  // Verified claims set by `jwtBearerAuth`
+  static readonly JWT_CLAIMS = 'auth.jwt.claims';
 }
 
 // --------------------------------------------------------------------------------------------------------
@@ -1,7 +1,12 @@
 import { IdType } from '@/base/models';
 import { TAnyObjectSchema } from '@/utilities/schema.utility';
 import { TContext } from '@/base/controllers';
-import { AESAlgorithmType, AnyObject, ValueOrPromise } from '@venizia/ignis-helpers';
+import {
+  AESAlgorithmType,
+  AnyObject,
+  IJWTClaims,
+  ValueOrPromise,
+} from '@venizia/ignis-helpers';
 import { Env } from 'hono';
 import { JWTPayload } from 'jose';
 import { TChangePasswordRequest, TSignInRequest, TSignUpRequest } from '../../models/requests';
@@ -13,6 +18,7 @@ declare module 'hono' {
   interface ContextVariableMap<User extends IAuthUser = IAuthUser> {
     [Authentication.CURRENT_USER]: User;
     [Authentication.AUDIT_USER_ID]: IdType;
+    [Authentication.JWT_CLAIMS]: IJWTClaims;
   }
 }
 
@@ -1,5 +1,6 @@
 export * from './common';
 export * from './component';
 export * from './controllers';
+export * from './middlewares';
 export * from './services';
 export * from './strategies';
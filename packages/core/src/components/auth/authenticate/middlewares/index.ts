@@ -0,0 +1 @@
+export * from './jwt-bearer.middleware';
@@ -0,0 +1,106 @@
+import {
+  getError,
+  HTTP,
+  IJWTClaims,
+  IVerifyJWTOptions,
+  JWTHelper,
+  RequestContextStorage,
+  ValueOrPromise,
+} from '@venizia/ignis-helpers';
+import { Context } from 'hono';
+import { createMiddleware } from 'hono/factory';
+import { Authentication, IAuthUser } from '../common';
+
+export interface IJWTBearerAuthOptions<TClaims extends IJWTClaims = IJWTClaims>
+  extends IVerifyJWTOptions {
+  // e.g. `new JWTHelper({ algorithm: 'RS256', keyResolver: jwks.getKeyResolver() })`
+  jwt: JWTHelper;
+  // Map verified claims to the authenticated user, defaults to `{ userId: claims.sub, ...claims }`
+  getUser?: (opts: { claims: TClaims; context: Context }) => ValueOrPromise<IAuthUser>;
+  // Let requests without a bearer token through unauthenticated
+  isOptional?: boolean;
+}
+
+/**
+ * Read the bearer token of the `Authorization` header, `null` when absent.
+ */
+export const extractBearerToken = (context: Context): string | null => {
+  const value = context.req.header('Authorization');
+  if (!value) {
+    return null;
+  }
+
+  const [type, token] = value.trim().split(/\s+/);
+  if (type?.toLowerCase() !== Authentication.TYPE_BEARER.toLowerCase() || !token) {
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+      message: `Unauthorized user! Authorization header must be "${Authentication.TYPE_BEARER} <token>"`,
+    });
+  }
+
+  return token;
+};
+
+/**
+ * Creates a middleware validating bearer tokens with a `JWTHelper`, for tokens issued by an
+ * identity provider (JWKS) or by another service, independently of `JWTTokenService`.
+ *
+ * Verification failures are thrown as `JWTError` (an `ApplicationError` with status 401 and a
+ * `JWTErrorCodes` message code), so they go through the application error handler.
+ * On success the verified claims are available via `getJWTClaims` and the user via
+ * `Authentication.CURRENT_USER`.
+ *
+ * @example
+ * ```typescript
+ * const jwks = new JWKSHelper({ url: 'https://auth.example.com/.well-known/jwks.json' });
+ * const jwt = new JWTHelper({ algorithm: 'RS256', keyResolver: jwks.getKeyResolver() });
+ *
+ * router.use('/orders/*', jwtBearerAuth<{ sub: string; scope: string }>({
+ *   jwt,
+ *   issuer: 'https://auth.example.com',
+ *   audience: 'orders-api',
+ * }));
+ * ```
+ */
+export const jwtBearerAuth = <TClaims extends IJWTClaims = IJWTClaims>(
+  opts: IJWTBearerAuthOptions<TClaims>,
+) => {
+  const { jwt, getUser, isOptional = false, ...verifyOptions } = opts;
+
+  return createMiddleware(async (context, next) => {
+    const token = extractBearerToken(context);
+    if (!token) {
+      if (isOptional) {
+        return next();
+      }
+
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+        message: 'Unauthorized user! Missing authorization header',
+      });
+    }
+
+    const { payload } = await jwt.verify<TClaims>({ token, ...verifyOptions });
+    const user = getUser
+      ? await getUser({ claims: payload, context })
+      : { userId: payload.sub!, ...payload };
+
+    context.set(Authentication.JWT_CLAIMS, payload);
+    context.set(Authentication.CURRENT_USER, user);
+    if (user?.userId) {
+      context.set(Authentication.AUDIT_USER_ID, user.userId);
+      RequestContextStorage.update({ userId: user.userId });
+    }
+
+    return next();
+  });
+};
+
+/**
+ * Typed access to the claims verified by `jwtBearerAuth`.
+ */
+export const getJWTClaims = <TClaims extends IJWTClaims = IJWTClaims>(
+  context: Context,
+): TClaims | undefined => {
+  return context.get(Authentication.JWT_CLAIMS) as TClaims | undefined;
+};
@@ -1,5 +1,11 @@
 import { BindingScopes, Container } from '@/helpers/inversion';
-import { BaseHelper, getError, HTTP, TClass } from '@venizia/ignis-helpers';
+import {
+  BaseHelper,
+  getError,
+  HTTP,
+  RequestContextStorage,
+  TClass,
+} from '@venizia/ignis-helpers';
 import { Context, Env, MiddlewareHandler } from 'hono';
 import { createMiddleware } from 'hono/factory';
 import isEmpty from 'lodash/isEmpty';
@@ -102,6 +108,7 @@ export class AuthenticationStrategyRegistry<E extends Env = Env> extends BaseHel
               context.set(Authentication.CURRENT_USER, user);
               if (user?.userId) {
                 context.set(Authentication.AUDIT_USER_ID, user.userId);
+                RequestContextStorage.update({ userId: user.userId });
               }
 
               await next();
@@ -131,6 +138,7 @@ export class AuthenticationStrategyRegistry<E extends Env = Env> extends BaseHel
           if (authUser?.userId) {
             context.set(Authentication.CURRENT_USER, authUser);
             context.set(Authentication.AUDIT_USER_ID, authUser.userId);
+            RequestContextStorage.update({ userId: authUser.userId });
           } else {
             this.logger
               .for(this.authenticate.name)
@@ -0,0 +1,58 @@
+import { IDataSource } from '@/base/datasources';
+import type { DefaultRedisHelper } from '@venizia/ignis-helpers';
+import { sql } from 'drizzle-orm';
+import { IHealthChecker } from './common';
+
+type TCheckerOptions = Partial<Omit<IHealthChecker, 'check'>>;
+
+// --------------------------------------------------------------------------------------------------------
+export const dataSourceHealthChecker = (
+  opts: TCheckerOptions & { dataSource: IDataSource },
+): IHealthChecker => {
+  const { dataSource, ...rest } = opts;
+
+  return {
+    name: dataSource.name,
+    ...rest,
+    check: async () => {
+      await dataSource.getConnector().execute(sql`select 1`);
+    },
+  };
+};
+
+// --------------------------------------------------------------------------------------------------------
+export const redisHealthChecker = (
+  opts: TCheckerOptions & { redis: DefaultRedisHelper },
+): IHealthChecker => {
+  const { redis, ...rest } = opts;
+
+  return {
+    name: redis.name,
+    ...rest,
+    check: async () => {
+      await redis.ping();
+    },
+  };
+};
+
+// --------------------------------------------------------------------------------------------------------
+/**
+ * Checks a downstream service, any non 2xx response marks it as down.
+ */
+export const networkHealthChecker = (
+  opts: TCheckerOptions & { name: string; url: string; headers?: Record<string, string> },
+): IHealthChecker => {
+  const { url, headers, ...rest } = opts;
+
+  return {
+    ...rest,
+    check: async () => {
+      const response = await fetch(url, { method: 'GET', headers });
+      if (!response.ok) {
+        throw new Error(`Unexpected response status | url: ${url} | status: ${response.status}`);
+      }
+
+      return { statusCode: response.status };
+    },
+  };
+};
@@ -0,0 +1,35 @@
+import { TConstValue } from '@/helpers';
+
+// --------------------------------------------------------------------------------------------------------
+export class HealthCheckStatuses {
+  static readonly UP = 'up';
+  static readonly DEGRADED = 'degraded';
+  static readonly DOWN = 'down';
+
+  static readonly SCHEME_SET = new Set([this.UP, this.DEGRADED, this.DOWN]);
+
+  static isValid(input: string): boolean {
+    return this.SCHEME_SET.has(input);
+  }
+}
+export type THealthCheckStatus = TConstValue<typeof HealthCheckStatuses>;
+
+// --------------------------------------------------------------------------------------------------------
+export class HealthCheckProbes {
+  // Process is alive, a failure makes the orchestrator restart it
+  static readonly LIVENESS = 'liveness';
+  // Dependencies are reachable, a failure takes the instance out of load balancing
+  static readonly READINESS = 'readiness';
+
+  static readonly SCHEME_SET = new Set([this.LIVENESS, this.READINESS]);
+
+  static isValid(input: string): boolean {
+    return this.SCHEME_SET.has(input);
+  }
+}
+export type THealthCheckProbe = TConstValue<typeof HealthCheckProbes>;
+
+// --------------------------------------------------------------------------------------------------------
+export class HealthCheckDefaults {
+  static readonly TIMEOUT = 3_000;
+}
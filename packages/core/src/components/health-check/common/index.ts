@@ -1,3 +1,4 @@
+export * from './constants';
 export * from './keys';
 export * from './rest-paths';
 export * from './types';
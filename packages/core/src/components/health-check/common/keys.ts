@@ -1,3 +1,4 @@
 export class HealthCheckBindingKeys {
   static readonly HEALTH_CHECK_OPTIONS = '@app/health-check/options';
+  static readonly HEALTH_CHECK_REGISTRY = '@app/health-check/registry';
 }
@@ -1,4 +1,7 @@
 export class HealthCheckRestPaths {
   static readonly ROOT = '/';
   static readonly PING = '/ping';
+
+  static readonly LIVENESS = '/healthz';
+  static readonly READINESS = '/readyz';
 }
@@ -1,3 +1,35 @@
+import { AnyObject, ValueOrPromise } from '@/helpers';
+import { THealthCheckProbe, THealthCheckStatus } from './constants';
+
+export interface IHealthChecker {
+  name: string;
+  check: () => ValueOrPromise<AnyObject | void>;
+
+  // Probes running this checker, defaults to readiness only
+  probes?: Array<THealthCheckProbe>;
+  // Non critical failures degrade the report instead of failing it
+  isCritical?: boolean;
+  timeout?: number;
+}
+
+export interface IHealthCheckResult {
+  name: string;
+  status: THealthCheckStatus;
+  // Milliseconds
+  latency: number;
+  details?: AnyObject;
+  error?: string;
+}
+
+export interface IHealthReport {
+  status: THealthCheckStatus;
+  timestamp: string;
+  checks: Array<IHealthCheckResult>;
+}
+
 export interface IHealthCheckOptions {
   restOptions: { path: string };
+
+  // Kubernetes style probes mounted on the root router, `false` disables them
+  probeOptions?: { livenessPath?: string; readinessPath?: string } | false;
 }
@@ -2,13 +2,25 @@ import { BaseApplication } from '@/base/applications';
 import { BaseComponent } from '@/base/components';
 import { controller, inject } from '@/base/metadata';
 import { CoreBindings } from '@/common/bindings';
-import { ValueOrPromise } from '@venizia/ignis-helpers';
-import { HealthCheckBindingKeys, IHealthCheckOptions } from './common';
+import { HTTP, ShutdownPhases, ValueOrPromise } from '@venizia/ignis-helpers';
+import {
+  HealthCheckBindingKeys,
+  HealthCheckProbes,
+  HealthCheckRestPaths,
+  HealthCheckStatuses,
+  IHealthCheckOptions,
+  THealthCheckProbe,
+} from './common';
 import { HealthCheckController } from './controller';
+import { HealthCheckRegistry } from './registry';
 import { Binding } from '@/helpers/inversion';
 
 const DEFAULT_OPTIONS: IHealthCheckOptions = {
   restOptions: { path: '/health' },
+  probeOptions: {
+    livenessPath: HealthCheckRestPaths.LIVENESS,
+    readinessPath: HealthCheckRestPaths.READINESS,
+  },
 };
 
 export class HealthCheckComponent extends BaseComponent {
@@ -22,6 +34,9 @@ export class HealthCheckComponent extends BaseComponent {
         [HealthCheckBindingKeys.HEALTH_CHECK_OPTIONS]: Binding.bind<IHealthCheckOptions>({
           key: HealthCheckBindingKeys.HEALTH_CHECK_OPTIONS,
         }).toValue(DEFAULT_OPTIONS),
+        [HealthCheckBindingKeys.HEALTH_CHECK_REGISTRY]: Binding.bind<HealthCheckRegistry>({
+          key: HealthCheckBindingKeys.HEALTH_CHECK_REGISTRY,
+        }).toValue(HealthCheckRegistry.getInstance()),
       },
     });
   }
@@ -35,5 +50,41 @@ export class HealthCheckComponent extends BaseComponent {
 
     Reflect.decorate([controller({ path: healthOptions.restOptions.path })], HealthCheckController);
     this.application.controller(HealthCheckController);
+    this.application.registerShutdownHook({
+      identifier: HealthCheckRegistry.name,
+      phase: ShutdownPhases.PRE_STOP,
+      hook: () => {
+        HealthCheckRegistry.getInstance().markShuttingDown();
+      },
+    });
+
+    if (healthOptions.probeOptions === false) {
+      return;
+    }
+
+    const {
+      livenessPath = HealthCheckRestPaths.LIVENESS,
+      readinessPath = HealthCheckRestPaths.READINESS,
+    } = healthOptions.probeOptions ?? {};
+
+    this.bindProbe({ path: livenessPath, probe: HealthCheckProbes.LIVENESS });
+    this.bindProbe({ path: readinessPath, probe: HealthCheckProbes.READINESS });
+  }
+
+  // Probes stay out of the OpenAPI document, they are meant for orchestrators and load balancers
+  private bindProbe(opts: { path: string; probe: THealthCheckProbe }) {
+    const { path, probe } = opts;
+    const registry = HealthCheckRegistry.getInstance();
+
+    this.application.getRootRouter().get(path, async context => {
+      const report = await registry.check({ probe });
+
+      return context.json(
+        report,
+        report.status === HealthCheckStatuses.DOWN
+          ? HTTP.ResultCodes.RS_5.ServiceUnavailable
+          : HTTP.ResultCodes.RS_2.Ok,
+      );
+    });
   }
 }
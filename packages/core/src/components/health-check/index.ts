@@ -1,3 +1,5 @@
+export * from './checkers';
 export * from './common';
 export * from './component';
 export * from './controller';
+export * from './registry';
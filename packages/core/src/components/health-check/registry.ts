@@ -0,0 +1,160 @@
+import { BaseHelper, getError, HTTP } from '@venizia/ignis-helpers';
+import {
+  HealthCheckDefaults,
+  HealthCheckProbes,
+  HealthCheckStatuses,
+  IHealthChecker,
+  IHealthCheckResult,
+  IHealthReport,
+  THealthCheckProbe,
+} from './common';
+
+/**
+ * Collects the dependency checkers of an application and runs them for health probes.
+ *
+ * @example
+ * ```typescript
+ * HealthCheckRegistry.getInstance().register({
+ *   checker: {
+ *     name: 'postgres',
+ *     check: () => dataSource.getConnector().execute(sql`select 1`),
+ *   },
+ * });
+ * ```
+ */
+export class HealthCheckRegistry extends BaseHelper {
+  private static instance: HealthCheckRegistry;
+
+  private checkers: Map<string, IHealthChecker>;
+  private isShuttingDown = false;
+
+  // ------------------------------------------------------------------------------
+  constructor() {
+    super({ scope: HealthCheckRegistry.name });
+    this.checkers = new Map();
+  }
+
+  static getInstance() {
+    if (!HealthCheckRegistry.instance) {
+      HealthCheckRegistry.instance = new HealthCheckRegistry();
+    }
+
+    return HealthCheckRegistry.instance;
+  }
+
+  // ------------------------------------------------------------------------------
+  register(opts: { checker: IHealthChecker }) {
+    const { checker } = opts;
+
+    if (!checker?.name) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: '[register] Invalid health checker, name is required!',
+      });
+    }
+
+    if (this.checkers.has(checker.name)) {
+      this.logger
+        .for(this.register.name)
+        .warn('Overriding registered health checker | name: %s', checker.name);
+    }
+
+    this.checkers.set(checker.name, checker);
+    return this;
+  }
+
+  unregister(opts: { name: string }) {
+    this.checkers.delete(opts.name);
+    return this;
+  }
+
+  /**
+   * Fail readiness from now on, so load balancers stop routing traffic before the server closes.
+   */
+  markShuttingDown() {
+    this.isShuttingDown = true;
+    return this;
+  }
+
+  getCheckers(opts: { probe?: THealthCheckProbe } = {}): Array<IHealthChecker> {
+    const { probe } = opts;
+    const checkers = [...this.checkers.values()];
+
+    if (!probe) {
+      return checkers;
+    }
+
+    return checkers.filter(checker => {
+      return (checker.probes ?? [HealthCheckProbes.READINESS]).includes(probe);
+    });
+  }
+
+  // ------------------------------------------------------------------------------
+  private async runChecker(checker: IHealthChecker): Promise<IHealthCheckResult> {
+    const timeout = checker.timeout ?? HealthCheckDefaults.TIMEOUT;
+    const startedAt = performance.now();
+    let timer: ReturnType<typeof setTimeout> | undefined;
+
+    try {
+      const details = await Promise.race([
+        Promise.resolve().then(() => checker.check()),
+        new Promise<never>((_resolve, reject) => {
+          timer = setTimeout(() => {
+            reject(new Error(`Health check timed out after ${timeout}ms`));
+          }, timeout);
+        }),
+      ]);
+
+      return {
+        name: checker.name,
+        status: HealthCheckStatuses.UP,
+        latency: Math.round(performance.now() - startedAt),
+        details: details ?? undefined,
+      };
+    } catch (error) {
+      this.logger
+        .for(this.runChecker.name)
+        .error('Health check failed | name: %s | error: %s', checker.name, error);
+
+      return {
+        name: checker.name,
+        status: HealthCheckStatuses.DOWN,
+        latency: Math.round(performance.now() - startedAt),
+        error: (error as Error)?.message ?? `${error}`,
+      };
+    } finally {
+      clearTimeout(timer);
+    }
+  }
+
+  /**
+   * Run the checkers of a probe concurrently. The report is `down` when a critical checker
+   * fails and `degraded` when only non critical ones do.
+   */
+  async check(opts: { probe?: THealthCheckProbe } = {}): Promise<IHealthReport> {
+    const checkers = this.getCheckers(opts);
+    const checks = await Promise.all(checkers.map(checker => this.runChecker(checker)));
+
+    if (this.isShuttingDown && opts.probe === HealthCheckProbes.READINESS) {
+      checks.push({ name: 'shutdown', status: HealthCheckStatuses.DOWN, latency: 0 });
+    }
+
+    let status: IHealthReport['status'] = HealthCheckStatuses.UP;
+    checks.forEach((rs, index) => {
+      if (rs.status !== HealthCheckStatuses.DOWN) {
+        return;
+      }
+
+      if (checkers[index]?.isCritical ?? true) {
+        status = HealthCheckStatuses.DOWN;
+        return;
+      }
+
+      if (status === HealthCheckStatuses.UP) {
+        status = HealthCheckStatuses.DEGRADED;
+      }
+    });
+
+    return { status, timestamp: new Date().toISOString(), checks };
+  }
+}
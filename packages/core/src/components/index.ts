@@ -1,10 +1,16 @@
+export * from './audit';
 export * from './auth';
 export * from './health-check';
 // Excluded from barrel — import from @venizia/ignis/mail directly
 // export * from './mail';
+export * from './metrics';
 export * from './request-tracker';
+// Excluded from barrel — import from @venizia/ignis/sentry directly
+// export * from './sentry';
 // Excluded from barrel — import from @venizia/ignis/socket-io directly
 // export * from './socket-io';
 export * from './static-asset';
 export * from './swagger';
+// Excluded from barrel — import from @venizia/ignis/telemetry directly
+// export * from './telemetry';
 export * from './websocket';
@@ -1,5 +1,6 @@
 export class MailDefaults {
   static readonly BATCH_CONCURRENCY = 5;
+  static readonly LOCALE = 'en';
 }
 
 export class MailErrorCodes {
@@ -9,6 +10,10 @@ export class MailErrorCodes {
   static readonly INVALID_RECIPIENT = 'MAIL_INVALID_RECIPIENT';
   static readonly BATCH_SEND_FAILED = 'MAIL_BATCH_SEND_FAILED';
   static readonly TEMPLATE_NOT_FOUND = 'TEMPLATE_NOT_FOUND';
+  static readonly AUTHENTICATION_FAILED = 'MAIL_AUTHENTICATION_FAILED';
+  static readonly CONNECTION_FAILED = 'MAIL_CONNECTION_FAILED';
+  static readonly TEMPORARY_FAILURE = 'MAIL_TEMPORARY_FAILURE';
+  static readonly MESSAGE_REJECTED = 'MAIL_MESSAGE_REJECTED';
 }
 
 export class MailQueueExecutorTypes {
@@ -7,6 +7,8 @@ import { IBullMQMailExecutorOpts } from '../helpers';
 export class MailProviders {
   static readonly NODEMAILER = 'nodemailer';
   static readonly MAILGUN = 'mailgun';
+  static readonly SES = 'ses';
+  static readonly SENDGRID = 'sendgrid';
   static readonly CUSTOM = 'custom';
 }
 
@@ -27,6 +29,16 @@ export interface IMailgunMailOptions extends IBaseMailOptions {
   config: TMailgunConfig;
 }
 
+export interface ISesMailOptions extends IBaseMailOptions {
+  provider: 'ses';
+  config: TSesConfig;
+}
+
+export interface ISendGridMailOptions extends IBaseMailOptions {
+  provider: 'sendgrid';
+  config: TSendGridConfig;
+}
+
 export interface ICustomMailOptions extends IBaseMailOptions {
   provider: 'custom';
   config: IMailTransport;
@@ -40,6 +52,8 @@ export interface IGenericMailOptions extends IBaseMailOptions {
 export type TMailOptions =
   | INodemailerMailOptions
   | IMailgunMailOptions
+  | ISesMailOptions
+  | ISendGridMailOptions
   | ICustomMailOptions
   | IGenericMailOptions;
 
@@ -48,6 +62,29 @@ export type TNodemailerConfig = SMTPTransport | SMTPTransport.Options | string;
 // MailgunClientOptions & {domain: string}
 export type TMailgunConfig = AnyType & { domain: string };
 
+export interface IApiMailTransportConfig {
+  // Request timeout in milliseconds
+  timeout?: number;
+}
+
+// Amazon SES v2 HTTP API, requests are signed with AWS Signature Version 4
+export type TSesConfig = IApiMailTransportConfig & {
+  region: string;
+  accessKeyId: string;
+  secretAccessKey: string;
+  sessionToken?: string;
+  endpoint?: string;
+  configurationSetName?: string;
+};
+
+// SendGrid v3 Mail Send API
+export type TSendGridConfig = IApiMailTransportConfig & {
+  apiKey: string;
+  // Use https://api.eu.sendgrid.com for EU regional subusers
+  baseUrl?: string;
+  sandboxMode?: boolean;
+};
+
 export interface IMailAttachment {
   filename?: string;
   contentType?: string;
@@ -77,6 +114,9 @@ export interface IMailSendResult {
   messageId?: string;
   response?: any;
   error?: string;
+  // Normalized failure details, see MailErrorCodes
+  errorCode?: string;
+  statusCode?: number;
 }
 
 export interface IMailTransport {
@@ -97,6 +137,7 @@ export interface IMailService {
     templateName: string;
     data: Record<string, any>;
     recipients: string | string[];
+    locale?: string;
     options?: Partial<IMailMessage>;
   }): Promise<IMailSendResult>;
   verify(): Promise<boolean>;
@@ -104,6 +145,7 @@ export interface IMailService {
 
 export interface ITemplate {
   name: string;
+  locale?: string;
   content?: string;
   render?: (data: Record<string, AnyType>) => string;
   subject?: string;
@@ -114,19 +156,20 @@ export interface IMailTemplateEngine {
   render(opts: {
     templateData?: string;
     templateName?: string;
+    locale?: string;
     data: Record<string, any>;
     requireValidate?: boolean;
   }): string;
-  registerTemplate(opts: { name: string; content: string }): void;
+  registerTemplate(opts: { name: string; content: string; locale?: string }): void;
   validateTemplateData(opts: { template: string; data: Record<string, any> }): {
     isValid: boolean;
     missingKeys: string[];
     allKeys: string[];
   };
-  getTemplate(name: string): ITemplate | undefined;
+  getTemplate(name: string, locale?: string): ITemplate | undefined;
   listTemplates(): ITemplate[];
-  hasTemplate(name: string): boolean;
-  removeTemplate(name: string): boolean;
+  hasTemplate(name: string, locale?: string): boolean;
+  removeTemplate(name: string, locale?: string): boolean;
 }
 
 export interface IVerificationGenerationOptions {
@@ -0,0 +1,71 @@
+import { AnyType, BaseHelper, NodeFetchNetworkRequest } from '@/helpers';
+import { IMailMessage, IMailSendResult, IMailTransport, MailErrorCodes } from '../../common';
+import { normalizeMailError } from '../../utilities';
+
+export interface IApiMailResponse {
+  status: number;
+  headers: Headers;
+  data: AnyType;
+}
+
+/**
+ * Base for mail transports which talk to a provider HTTP API instead of SMTP.
+ */
+export abstract class AbstractApiMailTransportHelper extends BaseHelper implements IMailTransport {
+  static readonly DEFAULT_TIMEOUT = 30_000;
+
+  protected network: NodeFetchNetworkRequest;
+  protected timeout: number;
+
+  constructor(opts: { scope: string; timeout?: number }) {
+    super({ scope: opts.scope });
+
+    this.timeout = opts.timeout ?? AbstractApiMailTransportHelper.DEFAULT_TIMEOUT;
+    this.network = new NodeFetchNetworkRequest({ name: opts.scope, networkOptions: {} });
+  }
+
+  abstract send(message: IMailMessage): Promise<IMailSendResult>;
+  abstract verify(): Promise<boolean>;
+
+  // ---------------------------------------------------------------------
+  protected async request(opts: {
+    url: string;
+    method: string;
+    headers: Record<string, string>;
+    body?: string;
+  }): Promise<IApiMailResponse> {
+    const { url, method, headers, body } = opts;
+
+    // Logger is intentionally not forwarded, the fetcher would log the authorization header
+    const response = await this.network
+      .getNetworkService()
+      .send({ url, method, headers, body, timeout: this.timeout });
+
+    const text = await response.text();
+    let data: AnyType = text;
+    try {
+      data = text ? JSON.parse(text) : null;
+    } catch {
+      // Keep the raw body for non JSON responses
+    }
+
+    return { status: response.status, headers: response.headers, data };
+  }
+
+  /**
+   * The message could not be prepared (e.g. unreadable attachment) or the request never reached
+   * the provider (DNS, refused connection, timeout...).
+   */
+  protected getRequestFailure(error: unknown): IMailSendResult {
+    const isRequestError =
+      error instanceof Error && (error.name === 'AbortError' || error.message === 'fetch failed');
+
+    return {
+      success: false,
+      error: error instanceof Error ? error.message : 'Unknown error',
+      ...(isRequestError
+        ? { errorCode: MailErrorCodes.CONNECTION_FAILED, statusCode: 503 }
+        : normalizeMailError(error)),
+    };
+  }
+}
@@ -1,2 +1,5 @@
+export * from './api-transporter.helper';
 export * from './nodemail-transporter.helper';
 export * from './mailgun-transporter.helper';
+export * from './ses-transporter.helper';
+export * from './sendgrid-transporter.helper';
@@ -1,5 +1,6 @@
 import { AnyType, BaseHelper, validateModule } from '@/helpers';
 import { IMailMessage, IMailSendResult, IMailTransport, TNodemailerConfig } from '../../common';
+import { normalizeMailError } from '../../utilities';
 
 export class NodemailerTransportHelper extends BaseHelper implements IMailTransport {
   private transporter: AnyType;
@@ -48,6 +49,7 @@ export class NodemailerTransportHelper extends BaseHelper implements IMailTransp
       return {
         success: false,
         error: error instanceof Error ? error.message : 'Unknown error',
+        ...normalizeMailError(error),
       };
     }
   }
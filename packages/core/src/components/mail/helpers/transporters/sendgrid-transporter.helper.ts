@@ -0,0 +1,152 @@
+import { AnyType } from '@/helpers';
+import { IMailMessage, IMailSendResult, TSendGridConfig } from '../../common';
+import {
+  normalizeSendGridError,
+  parseMailAddress,
+  readAttachmentContent,
+  toMailAddresses,
+} from '../../utilities';
+import { AbstractApiMailTransportHelper } from './api-transporter.helper';
+
+export class SendGridTransportHelper extends AbstractApiMailTransportHelper {
+  static readonly DEFAULT_BASE_URL = 'https://api.sendgrid.com';
+
+  private config: TSendGridConfig;
+  private baseUrl: string;
+
+  constructor(config: TSendGridConfig) {
+    super({ scope: SendGridTransportHelper.name, timeout: config.timeout });
+
+    this.config = config;
+    this.baseUrl = (config.baseUrl ?? SendGridTransportHelper.DEFAULT_BASE_URL).replace(/\/$/, '');
+  }
+
+  private getHeaders() {
+    return {
+      ['authorization']: `Bearer ${this.config.apiKey}`,
+      ['content-type']: 'application/json',
+    };
+  }
+
+  // ---------------------------------------------------------------------
+  private async buildPayload(message: IMailMessage) {
+    const personalization: AnyType = { to: toMailAddresses(message.to) };
+
+    const cc = toMailAddresses(message.cc);
+    if (cc.length > 0) {
+      personalization.cc = cc;
+    }
+
+    const bcc = toMailAddresses(message.bcc);
+    if (bcc.length > 0) {
+      personalization.bcc = bcc;
+    }
+
+    // SendGrid requires text/plain to come before text/html
+    const content: Array<{ type: string; value: string }> = [];
+    if (message.text) {
+      content.push({ type: 'text/plain', value: message.text });
+    }
+
+    if (message.html) {
+      content.push({ type: 'text/html', value: message.html });
+    }
+
+    const payload: AnyType = {
+      personalizations: [personalization],
+      from: message.from ? parseMailAddress(message.from) : undefined,
+      reply_to: message.replyTo ? parseMailAddress(message.replyTo) : undefined,
+      subject: message.subject,
+      content,
+      headers: message.headers,
+    };
+
+    if (message.attachments && message.attachments.length > 0) {
+      payload.attachments = await Promise.all(
+        message.attachments.map(async att => ({
+          filename: att.filename ?? 'attachment',
+          type: att.contentType,
+          content_id: att.cid,
+          disposition: att.cid ? 'inline' : 'attachment',
+          content: (await readAttachmentContent(att)).toString('base64'),
+        })),
+      );
+    }
+
+    if (this.config.sandboxMode) {
+      payload.mail_settings = { sandbox_mode: { enable: true } };
+    }
+
+    return payload;
+  }
+
+  async send(message: IMailMessage): Promise<IMailSendResult> {
+    let response;
+    try {
+      const payload = await this.buildPayload(message);
+
+      this.logger.for(this.send.name).debug('Sending email with SendGrid to: %s', message.to);
+      response = await this.request({
+        url: `${this.baseUrl}/v3/mail/send`,
+        method: 'POST',
+        headers: this.getHeaders(),
+        body: JSON.stringify(payload),
+      });
+    } catch (error) {
+      this.logger.for(this.send.name).error('SendGrid request failed: %s', error);
+      return this.getRequestFailure(error);
+    }
+
+    const { status, headers, data } = response;
+    if (status >= 200 && status < 300) {
+      // Mail send answers 202 with an empty body, the message id is only exposed as a header
+      return { success: true, messageId: headers.get('x-message-id') ?? undefined, response: data };
+    }
+
+    const errors: Array<{ field?: string | null; message?: string }> = data?.errors ?? [];
+    this.logger
+      .for(this.send.name)
+      .error('SendGrid send failed | status: %d | errors: %j', status, errors);
+
+    return {
+      success: false,
+      error:
+        errors.map(error => error.message).join('; ') ||
+        `SendGrid responded with status ${status}`,
+      response: data,
+      ...normalizeSendGridError({ statusCode: status, errors }),
+    };
+  }
+
+  async verify(): Promise<boolean> {
+    try {
+      this.logger.for(this.verify.name).info('Verifying SendGrid API key');
+      const { status, data } = await this.request({
+        url: `${this.baseUrl}/v3/scopes`,
+        method: 'GET',
+        headers: this.getHeaders(),
+      });
+
+      if (status !== 200) {
+        this.logger
+          .for(this.verify.name)
+          .error('SendGrid API verification failed | status: %d | response: %j', status, data);
+        return false;
+      }
+
+      const scopes: Array<string> = data?.scopes ?? [];
+      if (!scopes.includes('mail.send')) {
+        this.logger
+          .for(this.verify.name)
+          .error('SendGrid API key is missing the mail.send scope | scopes: %j', scopes);
+        return false;
+      }
+
+      this.logger.for(this.verify.name).info('SendGrid API key verified successfully');
+      return true;
+    } catch (error) {
+      this.logger.for(this.verify.name).error('SendGrid API verification failed: %s', error);
+      return false;
+    }
+  }
+}
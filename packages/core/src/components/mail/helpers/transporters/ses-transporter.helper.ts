@@ -0,0 +1,167 @@
+import { AnyType } from '@/helpers';
+import C from 'node:crypto';
+import { IMailMessage, IMailSendResult, TSesConfig } from '../../common';
+import {
+  normalizeSesError,
+  readAttachmentContent,
+  signAwsRequest,
+  toMailAddresses,
+} from '../../utilities';
+import { AbstractApiMailTransportHelper } from './api-transporter.helper';
+
+const SES_SERVICE = 'ses';
+
+export class SesTransportHelper extends AbstractApiMailTransportHelper {
+  private config: TSesConfig;
+  private endpoint: string;
+
+  constructor(config: TSesConfig) {
+    super({ scope: SesTransportHelper.name, timeout: config.timeout });
+
+    this.config = config;
+    this.endpoint = (config.endpoint ?? `https://email.${config.region}.amazonaws.com`).replace(
+      /\/$/,
+      '',
+    );
+  }
+
+  // ---------------------------------------------------------------------
+  private signRequest(opts: { method: string; path: string; body: string; now?: Date }) {
+    const { method, path, body, now } = opts;
+    const { region, accessKeyId, secretAccessKey, sessionToken } = this.config;
+
+    const headers = signAwsRequest({
+      method,
+      path,
+      body,
+      headers: {
+        ['content-type']: 'application/json',
+        ['host']: new URL(this.endpoint).host,
+        ['x-amz-content-sha256']: C.createHash('sha256').update(body).digest('hex'),
+      },
+      region,
+      service: SES_SERVICE,
+      credentials: { accessKeyId, secretAccessKey, sessionToken },
+      now,
+    });
+
+    // fetch sets the host header itself
+    delete headers['host'];
+    return headers;
+  }
+
+  private async sendRequest(opts: { method: string; path: string; body?: AnyType }) {
+    const { method, path } = opts;
+    const body = opts.body ? JSON.stringify(opts.body) : '';
+
+    return this.request({
+      url: `${this.endpoint}${path}`,
+      method,
+      headers: this.signRequest({ method, path, body }),
+      body: body || undefined,
+    });
+  }
+
+  // ---------------------------------------------------------------------
+  private async buildContent(message: IMailMessage) {
+    const content: AnyType = {
+      Subject: { Data: message.subject, Charset: 'UTF-8' },
+      Body: {},
+    };
+
+    if (message.text) {
+      content.Body.Text = { Data: message.text, Charset: 'UTF-8' };
+    }
+
+    if (message.html) {
+      content.Body.Html = { Data: message.html, Charset: 'UTF-8' };
+    }
+
+    if (message.headers) {
+      content.Headers = Object.entries(message.headers).map(([Name, Value]) => ({ Name, Value }));
+    }
+
+    if (message.attachments && message.attachments.length > 0) {
+      content.Attachments = await Promise.all(
+        message.attachments.map(async att => ({
+          FileName: att.filename ?? 'attachment',
+          ContentType: att.contentType,
+          ContentId: att.cid,
+          ContentDisposition: att.cid ? 'INLINE' : 'ATTACHMENT',
+          RawContent: (await readAttachmentContent(att)).toString('base64'),
+        })),
+      );
+    }
+
+    return content;
+  }
+
+  async send(message: IMailMessage): Promise<IMailSendResult> {
+    const toAddresses = (addresses?: string | string[]) =>
+      toMailAddresses(addresses).map(({ email, name }) => (name ? `"${name}" <${email}>` : email));
+
+    let response;
+    try {
+      const body = {
+        FromEmailAddress: message.from,
+        Destination: {
+          ToAddresses: toAddresses(message.to),
+          CcAddresses: toAddresses(message.cc),
+          BccAddresses: toAddresses(message.bcc),
+        },
+        ReplyToAddresses: toAddresses(message.replyTo),
+        ConfigurationSetName: this.config.configurationSetName,
+        Content: { Simple: await this.buildContent(message) },
+      };
+
+      this.logger.for(this.send.name).debug('Sending email with SES to: %s', message.to);
+      response = await this.sendRequest({
+        method: 'POST',
+        path: '/v2/email/outbound-emails',
+        body,
+      });
+    } catch (error) {
+      this.logger.for(this.send.name).error('SES request failed: %s', error);
+      return this.getRequestFailure(error);
+    }
+
+    const { status, headers, data } = response;
+    if (status >= 200 && status < 300) {
+      return { success: true, messageId: data?.MessageId, response: data };
+    }
+
+    const errorType = headers.get('x-amzn-errortype') ?? data?.__type;
+    this.logger
+      .for(this.send.name)
+      .error('SES send failed | status: %d | type: %s | response: %j', status, errorType, data);
+
+    return {
+      success: false,
+      error: data?.message ?? data?.Message ?? `SES responded with status ${status}`,
+      response: data,
+      ...normalizeSesError({ statusCode: status, errorType }),
+    };
+  }
+
+  async verify(): Promise<boolean> {
+    try {
+      this.logger.for(this.verify.name).info('Verifying SES API credentials');
+      const { status, data } = await this.sendRequest({ method: 'GET', path: '/v2/email/account' });
+
+      if (status !== 200) {
+        this.logger
+          .for(this.verify.name)
+          .error('SES API verification failed | status: %d | response: %j', status, data);
+        return false;
+      }
+
+      this.logger
+        .for(this.verify.name)
+        .info('SES API verified | sendingEnabled: %s', data?.SendingEnabled);
+      return true;
+    } catch (error) {
+      this.logger.for(this.verify.name).error('SES API verification failed: %s', error);
+      return false;
+    }
+  }
+}
@@ -4,11 +4,18 @@ import {
   IMailgunMailOptions,
   IMailTransport,
   INodemailerMailOptions,
+  ISendGridMailOptions,
+  ISesMailOptions,
   MailErrorCodes,
   MailProviders,
   TMailOptions,
 } from '../common';
-import { MailgunTransportHelper, NodemailerTransportHelper } from '../helpers';
+import {
+  MailgunTransportHelper,
+  NodemailerTransportHelper,
+  SendGridTransportHelper,
+  SesTransportHelper,
+} from '../helpers';
 import { Container, getError } from '@/helpers';
 import { isMailTransport } from '../utilities';
 
@@ -34,6 +41,14 @@ export class MailTransportProvider extends BaseProvider<TGetMailTransportFn> {
           return this.createMailgunTransport(options);
         }
 
+        case MailProviders.SES: {
+          return this.createSesTransport(options);
+        }
+
+        case MailProviders.SENDGRID: {
+          return this.createSendGridTransport(options);
+        }
+
         case MailProviders.CUSTOM: {
           return this.createCustomTransport(options);
         }
@@ -77,6 +92,32 @@ export class MailTransportProvider extends BaseProvider<TGetMailTransportFn> {
     });
   }
 
+  private createSesTransport(options: TMailOptions): SesTransportHelper {
+    if (this.isSesOptions(options)) {
+      this.logger.for(this.createSesTransport.name).info('Initializing SES transport');
+      return new SesTransportHelper(options.config);
+    }
+
+    throw getError({
+      statusCode: 500,
+      messageCode: MailErrorCodes.INVALID_CONFIGURATION,
+      message: 'Invalid SES configuration',
+    });
+  }
+
+  private createSendGridTransport(options: TMailOptions): SendGridTransportHelper {
+    if (this.isSendGridOptions(options)) {
+      this.logger.for(this.createSendGridTransport.name).info('Initializing SendGrid transport');
+      return new SendGridTransportHelper(options.config);
+    }
+
+    throw getError({
+      statusCode: 500,
+      messageCode: MailErrorCodes.INVALID_CONFIGURATION,
+      message: 'Invalid SendGrid configuration',
+    });
+  }
+
   private createCustomTransport(options: TMailOptions): IMailTransport {
     if (!this.isCustomOptions(options)) {
       throw getError({
@@ -115,6 +156,14 @@ export class MailTransportProvider extends BaseProvider<TGetMailTransportFn> {
     return options.provider === MailProviders.MAILGUN && 'config' in options;
   }
 
+  private isSesOptions(options: TMailOptions): options is ISesMailOptions {
+    return options.provider === MailProviders.SES && 'config' in options;
+  }
+
+  private isSendGridOptions(options: TMailOptions): options is ISendGridMailOptions {
+    return options.provider === MailProviders.SENDGRID && 'config' in options;
+  }
+
   private isCustomOptions(options: TMailOptions): options is ICustomMailOptions {
     return options.provider === MailProviders.CUSTOM && 'config' in options;
   }
@@ -0,0 +1,62 @@
+import { AnyType, getError, validateModule } from '@/helpers';
+import { MailErrorCodes } from '../common';
+import { TemplateEngineService } from './template.service';
+
+/**
+ * Template engine backed by `handlebars` (conditionals, loops, partials, helpers and html
+ * escaping). Locale resolution is inherited from {@link TemplateEngineService}.
+ *
+ * Bind it in place of the default engine after adding `MailComponent`:
+ * ```typescript
+ * this.bind({ key: MailKeys.MAIL_TEMPLATE_ENGINE }).toClass(HandlebarsTemplateEngineService);
+ * ```
+ */
+export class HandlebarsTemplateEngineService extends TemplateEngineService {
+  private handlebars: AnyType;
+  // Compiled templates are cached by their source
+  private compiled: Map<string, (data: Record<string, AnyType>) => string> = new Map();
+
+  constructor(opts?: { defaultLocale?: string }) {
+    super({ scope: HandlebarsTemplateEngineService.name, defaultLocale: opts?.defaultLocale });
+
+    validateModule({
+      scope: HandlebarsTemplateEngineService.name,
+      modules: ['handlebars'],
+    });
+
+    this.handlebars = require('handlebars').create();
+  }
+
+  registerPartial(opts: { name: string; content: string }) {
+    this.handlebars.registerPartial(opts.name, opts.content);
+  }
+
+  registerHelper(opts: { name: string; fn: (...args: AnyType[]) => AnyType }) {
+    this.handlebars.registerHelper(opts.name, opts.fn);
+  }
+
+  override renderSimpleTemplate(
+    template: string,
+    data: Record<string, AnyType>,
+    opts?: { requireValidate?: boolean },
+  ): string {
+    if (opts?.requireValidate) {
+      const validation = this.validateTemplateData({ template, data });
+      if (!validation.isValid) {
+        throw getError({
+          statusCode: 400,
+          messageCode: MailErrorCodes.INVALID_CONFIGURATION,
+          message: `Missing template data for keys: ${validation.missingKeys.join(', ')}`,
+        });
+      }
+    }
+
+    let render = this.compiled.get(template);
+    if (!render) {
+      render = this.handlebars.compile(template) as (data: Record<string, AnyType>) => string;
+      this.compiled.set(template, render);
+    }
+
+    return render(data);
+  }
+}
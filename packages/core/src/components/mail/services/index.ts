@@ -1,3 +1,4 @@
 export * from './generator.service';
+export * from './handlebars-template.service';
 export * from './mail.service';
 export * from './template.service';
@@ -12,6 +12,7 @@ import {
 } from '../common';
 import { inject } from '@/base/metadata';
 import { AnyType, executePromiseWithLimit, getError } from '@/helpers';
+import { getMailDeliveryError } from '../utilities';
 
 export class MailService extends BaseService implements IMailService {
   constructor(
@@ -59,6 +60,19 @@ export class MailService extends BaseService implements IMailService {
     }
   }
 
+  /**
+   * Same as {@link send} but a failed delivery is raised as an `ApplicationError`
+   * carrying the normalized `MailErrorCodes` message code.
+   */
+  async sendOrThrow(message: IMailMessage): Promise<IMailSendResult> {
+    const result = await this.send(message);
+    if (!result.success) {
+      throw getMailDeliveryError({ result });
+    }
+
+    return result;
+  }
+
   async sendBatch(
     messages: IMailMessage[],
     options?: { concurrency?: number },
@@ -109,9 +123,10 @@ export class MailService extends BaseService implements IMailService {
     templateName: string;
     data: Record<string, AnyType>;
     recipients: string | string[];
+    locale?: string;
     options?: Partial<IMailMessage>;
   }): Promise<IMailSendResult> {
-    const { templateName, data, recipients, options } = opts;
+    const { templateName, data, recipients, locale, options } = opts;
 
     try {
       if (!this.templateEngine) {
@@ -125,11 +140,12 @@ export class MailService extends BaseService implements IMailService {
       this.logger.for(this.sendTemplate.name).debug('Rendering template: %s', templateName);
       const html = this.templateEngine.render({
         templateName,
+        locale,
         data,
         requireValidate: options?.requireValidate,
       });
 
-      const templateData = this.templateEngine.getTemplate(templateName);
+      const templateData = this.templateEngine.getTemplate(templateName, locale);
 
       const message: IMailMessage = {
         to: recipients,
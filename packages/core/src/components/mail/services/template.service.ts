@@ -1,36 +1,70 @@
 import { BaseService } from '@/base/services';
-import { IMailTemplateEngine, ITemplate, MailErrorCodes } from '../common';
+import { IMailTemplateEngine, ITemplate, MailDefaults, MailErrorCodes } from '../common';
 import { AnyType, getError } from '@/helpers';
 
 export class TemplateEngineService extends BaseService implements IMailTemplateEngine {
   private templates: Map<string, ITemplate> = new Map();
+  protected defaultLocale: string;
 
-  constructor() {
-    super({ scope: TemplateEngineService.name });
+  constructor(opts?: { scope?: string; defaultLocale?: string }) {
+    super({ scope: opts?.scope ?? TemplateEngineService.name });
+    this.defaultLocale = opts?.defaultLocale ?? MailDefaults.LOCALE;
     this.logger.for(this.constructor.name).info('Template engine initialized');
   }
 
-  registerTemplate(opts: { name: string; content: string; options?: Partial<ITemplate> }): void {
-    const { name, content, options } = opts;
-    this.logger.for(this.registerTemplate.name).info('Registering template: %s', name);
+  protected getTemplateKey(name: string, locale?: string) {
+    return locale ? `${name}:${locale.toLowerCase()}` : name;
+  }
+
+  /**
+   * Locale fallback chain, e.g. `vi-VN` -> `vi` -> default locale -> template without locale
+   */
+  protected getLocaleCandidates(locale?: string): Array<string | undefined> {
+    const candidates: Array<string | undefined> = [];
+
+    if (locale) {
+      candidates.push(locale);
+
+      const language = locale.split(/[-_]/)[0];
+      if (language !== locale) {
+        candidates.push(language);
+      }
+    }
+
+    candidates.push(this.defaultLocale, undefined);
+    return candidates;
+  }
+
+  registerTemplate(opts: {
+    name: string;
+    content: string;
+    locale?: string;
+    options?: Partial<ITemplate>;
+  }): void {
+    const { name, content, locale, options } = opts;
+    this.logger
+      .for(this.registerTemplate.name)
+      .info('Registering template: %s | locale: %s', name, locale ?? '-');
 
     const template: ITemplate = {
       name,
+      locale,
       content,
       subject: options?.subject,
       description: options?.description,
     };
 
-    this.templates.set(name, template);
+    this.templates.set(this.getTemplateKey(name, locale), template);
   }
 
   render(opts: {
     templateData?: string;
     templateName?: string;
+    locale?: string;
     data: Record<string, AnyType>;
     requireValidate?: boolean;
   }): string {
-    const { templateData, templateName, data, requireValidate } = opts;
+    const { templateData, templateName, locale, data, requireValidate } = opts;
 
     if (!templateData && !templateName) {
       throw getError({ message: 'Either templateName or templateData must be provided' });
@@ -39,17 +73,19 @@ export class TemplateEngineService extends BaseService implements IMailTemplateE
     let content = templateData;
 
     if (!content && templateName) {
-      const template = this.templates.get(templateName);
+      const template = this.getTemplate(templateName, locale);
 
       if (!template) {
         throw getError({
           statusCode: 404,
           messageCode: MailErrorCodes.TEMPLATE_NOT_FOUND,
-          message: `Template not found: ${templateName}`,
+          message: `Template not found: ${templateName}${locale ? ` | locale: ${locale}` : ''}`,
         });
       }
 
-      this.logger.for(this.render.name).debug('Rendering template: %s', templateName);
+      this.logger
+        .for(this.render.name)
+        .debug('Rendering template: %s | locale: %s', templateName, template.locale ?? '-');
 
       content = template.content;
     }
@@ -58,21 +94,30 @@ export class TemplateEngineService extends BaseService implements IMailTemplateE
     return this.renderSimpleTemplate(content!, data, { requireValidate });
   }
 
-  getTemplate(name: string): ITemplate | undefined {
-    return this.templates.get(name);
+  getTemplate(name: string, locale?: string): ITemplate | undefined {
+    for (const candidate of this.getLocaleCandidates(locale)) {
+      const template = this.templates.get(this.getTemplateKey(name, candidate));
+      if (template) {
+        return template;
+      }
+    }
+
+    return undefined;
   }
 
   listTemplates(): ITemplate[] {
     return Array.from(this.templates.values());
   }
 
-  hasTemplate(name: string): boolean {
-    return this.templates.has(name);
+  hasTemplate(name: string, locale?: string): boolean {
+    return !!this.getTemplate(name, locale);
   }
 
-  removeTemplate(name: string): boolean {
-    this.logger.for(this.removeTemplate.name).info('Removing template: %s', name);
-    return this.templates.delete(name);
+  removeTemplate(name: string, locale?: string): boolean {
+    this.logger
+      .for(this.removeTemplate.name)
+      .info('Removing template: %s | locale: %s', name, locale ?? '-');
+    return this.templates.delete(this.getTemplateKey(name, locale));
   }
 
   clearTemplates(): void {
@@ -144,7 +189,7 @@ export class TemplateEngineService extends BaseService implements IMailTemplateE
     });
   }
 
-  private getNestedValue(obj: AnyType, path: string) {
+  protected getNestedValue(obj: AnyType, path: string) {
     return path.split('.').reduce((current, key) => {
       return current?.[key];
     }, obj);
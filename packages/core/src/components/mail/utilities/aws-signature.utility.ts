@@ -0,0 +1,73 @@
+import C from 'node:crypto';
+
+const SIGNING_ALGORITHM = 'AWS4-HMAC-SHA256';
+
+const sha256 = (value: string) => C.createHash('sha256').update(value).digest('hex');
+const hmac = (key: string | Buffer, value: string) =>
+  C.createHmac('sha256', key).update(value).digest();
+
+/**
+ * Sign a request with AWS Signature Version 4, query strings are not supported.
+ * https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv-create-signed-request.html
+ *
+ * Every given header is signed, `host` included. Returns them with `x-amz-date`,
+ * `x-amz-security-token` (for temporary credentials) and `authorization` added.
+ */
+export function signAwsRequest(opts: {
+  method: string;
+  path: string;
+  headers: Record<string, string>;
+  body: string;
+  region: string;
+  service: string;
+  credentials: { accessKeyId: string; secretAccessKey: string; sessionToken?: string };
+  now?: Date;
+}): Record<string, string> {
+  const { method, path, body, region, service, credentials, now = new Date() } = opts;
+
+  const amzDate = now.toISOString().replace(/[:-]|\.\d{3}/g, '');
+  const dateStamp = amzDate.slice(0, 8);
+
+  const headers: Record<string, string> = {};
+  for (const [name, value] of Object.entries(opts.headers)) {
+    headers[name.toLowerCase()] = value;
+  }
+  headers['x-amz-date'] = amzDate;
+
+  if (credentials.sessionToken) {
+    headers['x-amz-security-token'] = credentials.sessionToken;
+  }
+
+  const names = Object.keys(headers).sort();
+  const signedHeaders = names.join(';');
+  const canonicalHeaders = names.map(name => `${name}:${headers[name].trim()}\n`).join('');
+
+  const canonicalRequest = [
+    method.toUpperCase(),
+    path,
+    '', // query string
+    canonicalHeaders,
+    signedHeaders,
+    sha256(body),
+  ].join('\n');
+
+  const credentialScope = `${dateStamp}/${region}/${service}/aws4_request`;
+  const stringToSign = [
+    SIGNING_ALGORITHM,
+    amzDate,
+    credentialScope,
+    sha256(canonicalRequest),
+  ].join('\n');
+
+  const signingKey = [dateStamp, region, service, 'aws4_request'].reduce<string | Buffer>(
+    (key, value) => hmac(key, value),
+    `AWS4${credentials.secretAccessKey}`,
+  );
+  const signature = C.createHmac('sha256', signingKey).update(stringToSign).digest('hex');
+
+  headers['authorization'] =
+    `${SIGNING_ALGORITHM} Credential=${credentials.accessKeyId}/${credentialScope}, ` +
+    `SignedHeaders=${signedHeaders}, Signature=${signature}`;
+
+  return headers;
+}
@@ -0,0 +1,147 @@
+import { ApplicationError, AnyType, getError } from '@/helpers';
+import { IMailSendResult, MailErrorCodes } from '../common';
+
+type TMailErrorDetails = Pick<IMailSendResult, 'errorCode' | 'statusCode'>;
+
+const CONNECTION_ERROR_CODES = new Set([
+  'ECONNECTION',
+  'ECONNREFUSED',
+  'ECONNRESET',
+  'ETIMEDOUT',
+  'ESOCKET',
+  'EDNS',
+]);
+
+/**
+ * Normalize SMTP / transport failures into `MailErrorCodes` and an HTTP status code.
+ *
+ * - 535 (or `EAUTH`) -> authentication failure
+ * - 550, 551, 553 (or `EENVELOPE`) -> recipient rejected
+ * - other 5xx -> message rejected
+ * - 4xx -> temporary failure, the message can be retried later
+ */
+export function normalizeMailError(error: AnyType): TMailErrorDetails {
+  const code: string | undefined = error?.code;
+  const responseCode = Number(error?.responseCode ?? 0);
+
+  if (code === 'EAUTH' || responseCode === 535) {
+    return { errorCode: MailErrorCodes.AUTHENTICATION_FAILED, statusCode: 500 };
+  }
+
+  if (code === 'EENVELOPE' || [550, 551, 553].includes(responseCode)) {
+    return { errorCode: MailErrorCodes.INVALID_RECIPIENT, statusCode: 422 };
+  }
+
+  if (responseCode >= 500) {
+    return { errorCode: MailErrorCodes.MESSAGE_REJECTED, statusCode: 422 };
+  }
+
+  if (responseCode >= 400) {
+    return { errorCode: MailErrorCodes.TEMPORARY_FAILURE, statusCode: 503 };
+  }
+
+  if (code && CONNECTION_ERROR_CODES.has(code)) {
+    return { errorCode: MailErrorCodes.CONNECTION_FAILED, statusCode: 503 };
+  }
+
+  return { errorCode: MailErrorCodes.SEND_FAILED, statusCode: 500 };
+}
+
+const SES_AUTHENTICATION_ERRORS = new Set([
+  'AccessDeniedException',
+  'InvalidSignatureException',
+  'SignatureDoesNotMatch',
+  'UnrecognizedClientException',
+  'ExpiredTokenException',
+]);
+
+const SES_REJECTED_ERRORS = new Set([
+  'MessageRejected',
+  'MailFromDomainNotVerifiedException',
+  'AccountSuspendedException',
+  'BadRequestException',
+]);
+
+const SES_TEMPORARY_ERRORS = new Set([
+  'TooManyRequestsException',
+  'LimitExceededException',
+  'SendingPausedException',
+  'ThrottlingException',
+]);
+
+/**
+ * Normalize an Amazon SES v2 error response.
+ *
+ * @param opts.statusCode HTTP status of the response
+ * @param opts.errorType value of the `x-amzn-ErrorType` header or the `__type` body field
+ */
+export function normalizeSesError(opts: {
+  statusCode: number;
+  errorType?: string;
+}): TMailErrorDetails {
+  const { statusCode } = opts;
+  // x-amzn-ErrorType may be suffixed with ':<url>' or prefixed with a namespace
+  const errorType = opts.errorType?.split(':')[0].split('#').pop() ?? '';
+
+  if (SES_AUTHENTICATION_ERRORS.has(errorType) || statusCode === 401 || statusCode === 403) {
+    return { errorCode: MailErrorCodes.AUTHENTICATION_FAILED, statusCode: 500 };
+  }
+
+  if (SES_TEMPORARY_ERRORS.has(errorType) || statusCode === 429 || statusCode >= 500) {
+    return { errorCode: MailErrorCodes.TEMPORARY_FAILURE, statusCode: 503 };
+  }
+
+  if (errorType === 'NotFoundException') {
+    return { errorCode: MailErrorCodes.INVALID_CONFIGURATION, statusCode: 500 };
+  }
+
+  if (SES_REJECTED_ERRORS.has(errorType) || statusCode >= 400) {
+    return { errorCode: MailErrorCodes.MESSAGE_REJECTED, statusCode: 422 };
+  }
+
+  return { errorCode: MailErrorCodes.SEND_FAILED, statusCode: 500 };
+}
+
+/**
+ * Normalize a SendGrid v3 error response.
+ *
+ * @param opts.statusCode HTTP status of the response
+ * @param opts.errors `errors` field of the response body
+ */
+export function normalizeSendGridError(opts: {
+  statusCode: number;
+  errors?: Array<{ field?: string | null; message?: string }>;
+}): TMailErrorDetails {
+  const { statusCode, errors = [] } = opts;
+
+  if (statusCode === 401 || statusCode === 403) {
+    return { errorCode: MailErrorCodes.AUTHENTICATION_FAILED, statusCode: 500 };
+  }
+
+  if (statusCode === 429 || statusCode >= 500) {
+    return { errorCode: MailErrorCodes.TEMPORARY_FAILURE, statusCode: 503 };
+  }
+
+  if (statusCode >= 400) {
+    // e.g. personalizations.0.to.0.email, reply_to.email
+    const isRecipientError = errors.some(error =>
+      /^personalizations\.\d+\.(to|cc|bcc)\b/.test(error.field ?? ''),
+    );
+
+    return isRecipientError
+      ? { errorCode: MailErrorCodes.INVALID_RECIPIENT, statusCode: 422 }
+      : { errorCode: MailErrorCodes.MESSAGE_REJECTED, statusCode: 422 };
+  }
+
+  return { errorCode: MailErrorCodes.SEND_FAILED, statusCode: 500 };
+}
+
+export function getMailDeliveryError(opts: { result: IMailSendResult }): ApplicationError {
+  const { result } = opts;
+
+  return getError({
+    statusCode: result.statusCode ?? 500,
+    messageCode: result.errorCode ?? MailErrorCodes.SEND_FAILED,
+    message: `Failed to deliver email: ${result.error ?? 'Unknown error'}`,
+  });
+}
@@ -1,2 +1,5 @@
+export * from './aws-signature.utility';
+export * from './error.utility';
+export * from './message.utility';
 export * from './type.utility';
 export * from './verification.utility';
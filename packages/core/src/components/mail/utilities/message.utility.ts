@@ -0,0 +1,57 @@
+import { EmailNormalizer } from '@venizia/ignis-helpers';
+import fsp from 'node:fs/promises';
+import { Readable } from 'node:stream';
+import { IMailAttachment } from '../common';
+
+export interface IMailAddress {
+  email: string;
+  name?: string;
+}
+
+// Invalid addresses are passed as is and rejected by the provider
+const normalizeEmail = (email: string) => EmailNormalizer.normalize({ email }) ?? email.trim();
+
+/**
+ * Parse `"Name" <email>`, `Name <email>` or a bare `email` into its parts.
+ */
+export function parseMailAddress(value: string): IMailAddress {
+  const matched = value.trim().match(/^(.*?)\s*<([^>]+)>$/);
+  if (!matched) {
+    return { email: normalizeEmail(value) };
+  }
+
+  const name = matched[1].trim().replace(/^"(.*)"$/, '$1');
+  const email = normalizeEmail(matched[2]);
+  return name ? { email, name } : { email };
+}
+
+export function toMailAddresses(value?: string | string[]): IMailAddress[] {
+  if (!value) {
+    return [];
+  }
+
+  const values = Array.isArray(value) ? value : [value];
+  return values.filter(Boolean).map(parseMailAddress);
+}
+
+export async function readAttachmentContent(attachment: IMailAttachment): Promise<Buffer> {
+  const { path, content } = attachment;
+
+  if (path) {
+    return fsp.readFile(path);
+  }
+
+  if (content instanceof Readable) {
+    const chunks: Buffer[] = [];
+    for await (const chunk of content) {
+      chunks.push(typeof chunk === 'string' ? Buffer.from(chunk) : chunk);
+    }
+    return Buffer.concat(chunks);
+  }
+
+  if (typeof content === 'string') {
+    return Buffer.from(content);
+  }
+
+  return content ?? Buffer.alloc(0);
+}
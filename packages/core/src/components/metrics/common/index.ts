@@ -0,0 +1,3 @@
+export * from './keys';
+export * from './rest-paths';
+export * from './types';
@@ -0,0 +1,4 @@
+export class MetricsBindingKeys {
+  static readonly METRICS_OPTIONS = '@app/metrics/options';
+  static readonly METRICS_REGISTRY = '@app/metrics/registry';
+}
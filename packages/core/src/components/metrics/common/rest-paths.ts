@@ -0,0 +1,3 @@
+export class MetricsRestPaths {
+  static readonly METRICS = '/metrics';
+}
@@ -0,0 +1,13 @@
+export interface IMetricsOptions {
+  // Defaults to `/metrics`
+  path?: string;
+  // CPU, memory, event loop lag and uptime, defaults to true
+  isProcessMetricsEnabled?: boolean;
+  // Prefix of the process metric names, e.g. `order_service_`
+  prefix?: string;
+  /**
+   * Serve the metrics on a dedicated listener instead of the application router, so they are
+   * reachable by the scraper without being exposed through the public ingress.
+   */
+  standalone?: { port: number; hostname?: string };
+}
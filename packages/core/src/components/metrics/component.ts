@@ -0,0 +1,127 @@
+import { BaseApplication } from '@/base/applications';
+import { BaseComponent } from '@/base/components';
+import { inject } from '@/base/metadata';
+import { CoreBindings } from '@/common/bindings';
+import { Binding } from '@/helpers/inversion';
+import {
+  HTTP,
+  MetricsRegistry,
+  registerProcessMetrics,
+  ShutdownPhases,
+  ValueOrPromise,
+} from '@venizia/ignis-helpers';
+import http from 'node:http';
+import { IMetricsOptions, MetricsBindingKeys, MetricsRestPaths } from './common';
+
+const DEFAULT_OPTIONS: IMetricsOptions = {
+  path: MetricsRestPaths.METRICS,
+  isProcessMetricsEnabled: true,
+};
+
+/**
+ * Exposes the `MetricsRegistry` in the Prometheus text format, on the application router or
+ * on a standalone listener.
+ *
+ * @example
+ * ```typescript
+ * this.bind<IMetricsOptions>({ key: MetricsBindingKeys.METRICS_OPTIONS }).toValue({
+ *   standalone: { port: 9464 },
+ * });
+ * this.component(MetricsComponent);
+ * ```
+ */
+export class MetricsComponent extends BaseComponent {
+  constructor(
+    @inject({ key: CoreBindings.APPLICATION_INSTANCE }) private application: BaseApplication,
+  ) {
+    super({
+      scope: MetricsComponent.name,
+      initDefault: { enable: true, container: application },
+      bindings: {
+        [MetricsBindingKeys.METRICS_OPTIONS]: Binding.bind<IMetricsOptions>({
+          key: MetricsBindingKeys.METRICS_OPTIONS,
+        }).toValue(DEFAULT_OPTIONS),
+        [MetricsBindingKeys.METRICS_REGISTRY]: Binding.bind<MetricsRegistry>({
+          key: MetricsBindingKeys.METRICS_REGISTRY,
+        }).toValue(MetricsRegistry.getInstance()),
+      },
+    });
+  }
+
+  override binding(): ValueOrPromise<void> {
+    const options = {
+      ...DEFAULT_OPTIONS,
+      ...this.application.get<IMetricsOptions>({
+        key: MetricsBindingKeys.METRICS_OPTIONS,
+        isOptional: true,
+      }),
+    };
+    const registry = MetricsRegistry.getInstance();
+
+    if (options.isProcessMetricsEnabled) {
+      registerProcessMetrics({ registry, prefix: options.prefix });
+    }
+
+    if (options.standalone) {
+      this.bindStandalone({ ...options.standalone, path: options.path, registry });
+      return;
+    }
+
+    // Kept out of the OpenAPI document like the health probes
+    const path = options.path ?? MetricsRestPaths.METRICS;
+    this.application.getRootRouter().get(path, async context => {
+      return context.body(await registry.metrics(), HTTP.ResultCodes.RS_2.Ok, {
+        [HTTP.Headers.CONTENT_TYPE]: registry.contentType,
+      });
+    });
+  }
+
+  private bindStandalone(opts: {
+    port: number;
+    hostname?: string;
+    path?: string;
+    registry: MetricsRegistry;
+  }) {
+    const { port, hostname = '0.0.0.0', path = MetricsRestPaths.METRICS, registry } = opts;
+
+    const server = http.createServer((req, res) => {
+      if (req.method !== 'GET' || req.url?.split('?')[0] !== path) {
+        res.writeHead(HTTP.ResultCodes.RS_4.NotFound).end();
+        return;
+      }
+
+      registry
+        .metrics()
+        .then(body => {
+          res.writeHead(HTTP.ResultCodes.RS_2.Ok, {
+            [HTTP.Headers.CONTENT_TYPE]: registry.contentType,
+          });
+          res.end(body);
+        })
+        .catch(error => {
+          this.logger
+            .for(this.bindStandalone.name)
+            .error('Failed to collect metrics | Error: %s', error);
+          res.writeHead(HTTP.ResultCodes.RS_5.InternalServerError).end();
+        });
+    });
+
+    server.listen(port, hostname, () => {
+      this.logger
+        .for(this.bindStandalone.name)
+        .info('Metrics listener started | address: http://%s:%s%s', hostname, port, path);
+    });
+
+    // Scrapes keep working while the application drains, the listener goes down with resources
+    this.application.registerShutdownHook({
+      identifier: MetricsComponent.name,
+      phase: ShutdownPhases.RESOURCES,
+      hook: () => {
+        return new Promise<void>(resolve => {
+          server.close(() => resolve());
+          server.closeAllConnections();
+        });
+      },
+    });
+  }
+}
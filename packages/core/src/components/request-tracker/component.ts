@@ -1,7 +1,7 @@
 import { BaseApplication } from '@/base/applications';
 import { BaseComponent } from '@/base/components';
 import { inject } from '@/base/metadata';
-import { RequestSpyMiddleware } from '@/base/middlewares';
+import { requestContext, RequestSpyMiddleware } from '@/base/middlewares';
 import { BindingNamespaces, CoreBindings } from '@/common/bindings';
 import { Binding, BindingScopes } from '@/helpers/inversion';
 import { getError, ValueOrPromise } from '@venizia/ignis-helpers';
@@ -32,7 +32,10 @@ export class RequestTrackerComponent extends BaseComponent {
 
   override binding(): ValueOrPromise<void> {
     const server = this.application.getServer();
+    const { requestTimeout } = this.application.getProjectConfigs().server ?? {};
+
     server.use(requestId());
+    server.use(requestContext({ requestTimeout }));
 
     const mw = this.application.get<MiddlewareHandler>({
       key: RequestTrackerComponent.REQUEST_TRACKER_MW_BINDING_KEY,
@@ -0,0 +1,6 @@
+export class SentryDefaults {
+  // Client errors (4xx) are expected and stay out of the error tracker
+  static readonly MIN_STATUS_CODE = 500;
+  // Milliseconds to send pending events on shutdown
+  static readonly FLUSH_TIMEOUT = 2000;
+}
@@ -0,0 +1,3 @@
+export * from './constants';
+export * from './keys';
+export * from './types';
@@ -0,0 +1,3 @@
+export class SentryBindingKeys {
+  static readonly SENTRY_OPTIONS = '@app/sentry/options';
+}
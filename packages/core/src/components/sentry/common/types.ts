@@ -0,0 +1,21 @@
+import type { NodeOptions } from '@sentry/node';
+
+export interface ISentryReporterOptions {
+  // Errors with a lower status code are not reported, defaults to 500
+  minStatusCode?: number;
+  // Expected errors never reported whatever their status, e.g. `UPSTREAM_UNAVAILABLE`
+  ignoredMessageCodes?: Array<string>;
+}
+
+export interface ISentryOptions extends ISentryReporterOptions {
+  // Defaults to `APP_ENV_SENTRY_DSN`, reporting is disabled without one
+  dsn?: string;
+  // Defaults to `APP_ENV_SENTRY_RELEASE`, e.g. the git sha or package version
+  release?: string;
+  // Defaults to `NODE_ENV`
+  environment?: string;
+  // Share of the error events sent (0..1), defaults to 1
+  sampleRate?: number;
+  // Passed through to `Sentry.init`
+  sdkOptions?: Omit<NodeOptions, 'dsn' | 'release' | 'environment' | 'sampleRate'>;
+}
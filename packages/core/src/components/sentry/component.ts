@@ -0,0 +1,72 @@
+import { BaseApplication } from '@/base/applications';
+import { BaseComponent } from '@/base/components';
+import { inject } from '@/base/metadata';
+import { CoreBindings } from '@/common/bindings';
+import { Binding } from '@/helpers/inversion';
+import * as Sentry from '@sentry/node';
+import { ShutdownPhases, ValueOrPromise } from '@venizia/ignis-helpers';
+import { ISentryOptions, SentryBindingKeys, SentryDefaults } from './common';
+import { createSentryErrorReporter } from './reporter';
+
+/**
+ * Initializes Sentry and reports the errors of the application error handler above
+ * `minStatusCode`. Uncaught exceptions and unhandled rejections are captured by the Sentry
+ * default integrations, pending events are flushed during the `flush` shutdown phase.
+ *
+ * @example
+ * ```typescript
+ * this.bind<ISentryOptions>({ key: SentryBindingKeys.SENTRY_OPTIONS }).toValue({
+ *   release: process.env.APP_ENV_GIT_SHA,
+ *   ignoredMessageCodes: ['UPSTREAM_UNAVAILABLE'],
+ * });
+ * this.component(SentryComponent);
+ * ```
+ */
+export class SentryComponent extends BaseComponent {
+  constructor(
+    @inject({ key: CoreBindings.APPLICATION_INSTANCE }) private application: BaseApplication,
+  ) {
+    super({
+      scope: SentryComponent.name,
+      initDefault: { enable: true, container: application },
+      bindings: {
+        [SentryBindingKeys.SENTRY_OPTIONS]: Binding.bind<ISentryOptions>({
+          key: SentryBindingKeys.SENTRY_OPTIONS,
+        }).toValue({}),
+      },
+    });
+  }
+
+  override binding(): ValueOrPromise<void> {
+    const {
+      dsn = process.env.APP_ENV_SENTRY_DSN,
+      release = process.env.APP_ENV_SENTRY_RELEASE,
+      environment = process.env.NODE_ENV,
+      sampleRate = 1,
+      sdkOptions = {},
+      ...reporterOptions
+    } =
+      this.application.get<ISentryOptions>({
+        key: SentryBindingKeys.SENTRY_OPTIONS,
+        isOptional: true,
+      }) ?? {};
+
+    if (!dsn) {
+      this.logger
+        .for(this.binding.name)
+        .warn('Sentry DSN is not configured, error reporting is disabled!');
+      return;
+    }
+
+    Sentry.init({ ...sdkOptions, dsn, release, environment, sampleRate });
+    this.application.registerErrorReporter(createSentryErrorReporter(reporterOptions));
+
+    this.application.registerShutdownHook({
+      identifier: SentryComponent.name,
+      phase: ShutdownPhases.FLUSH,
+      hook: async () => {
+        await Sentry.close(SentryDefaults.FLUSH_TIMEOUT);
+      },
+    });
+  }
+}
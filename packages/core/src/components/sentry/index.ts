@@ -0,0 +1,3 @@
+export * from './common';
+export * from './component';
+export * from './reporter';
@@ -0,0 +1,60 @@
+import { TErrorReporter } from '@/base/middlewares';
+import { AppErrorCodes } from '@/common/constants';
+import * as Sentry from '@sentry/node';
+import { RequestContextStorage } from '@venizia/ignis-helpers';
+import { routePath } from 'hono/route';
+import { ISentryReporterOptions, SentryDefaults } from './common';
+
+/**
+ * Creates an error reporter forwarding handled errors to Sentry, with the request context
+ * (request id, tenant, user, route) attached.
+ *
+ * Errors are grouped by `messageCode` so one code is one issue whatever the message details,
+ * errors without a specific code keep the default stack trace grouping.
+ *
+ * @example
+ * ```typescript
+ * application.registerErrorReporter(createSentryErrorReporter({ minStatusCode: 500 }));
+ * ```
+ */
+export const createSentryErrorReporter = (opts: ISentryReporterOptions = {}): TErrorReporter => {
+  const { minStatusCode = SentryDefaults.MIN_STATUS_CODE, ignoredMessageCodes = [] } = opts;
+  const ignored = new Set(ignoredMessageCodes);
+
+  return report => {
+    const { error, context, statusCode, messageCode, requestId } = report;
+    if (statusCode < minStatusCode || (messageCode && ignored.has(messageCode))) {
+      return;
+    }
+
+    Sentry.withScope(scope => {
+      const requestContext = RequestContextStorage.get();
+
+      scope.setLevel(statusCode >= 500 ? 'error' : 'warning');
+      scope.setTags({ requestId, statusCode });
+      if (messageCode) {
+        scope.setTag('messageCode', messageCode);
+      }
+
+      if (requestContext?.tenantId) {
+        scope.setTag('tenantId', requestContext.tenantId);
+      }
+
+      if (requestContext?.userId !== undefined) {
+        scope.setUser({ id: `${requestContext.userId}` });
+      }
+
+      scope.setContext('request', {
+        method: context.req.method,
+        path: context.req.path,
+        route: routePath(context, -1),
+      });
+
+      if (messageCode && messageCode !== AppErrorCodes.INTERNAL_ERROR) {
+        scope.setFingerprint([messageCode]);
+      }
+
+      Sentry.captureException(error);
+    });
+  };
+};
@@ -1,6 +1,6 @@
 import { jsonContent, jsonResponse } from '@/base/models';
 import { z } from '@hono/zod-openapi';
-import { ErrorSchema, HTTP } from '@venizia/ignis-helpers';
+import { ErrorResponseSchema, HTTP } from '@venizia/ignis-helpers';
 
 // ================================================================================
 const MultipartBodySchema = z.object({
@@ -106,7 +106,7 @@ export const StaticAssetDefinitions = {
           },
         },
       },
-      ['4xx | 5xx']: jsonContent({ description: 'Error Response', schema: ErrorSchema }),
+      ['4xx | 5xx']: jsonContent({ description: 'Error Response', schema: ErrorResponseSchema }),
     },
   },
   DOWNLOAD_OBJECT_BY_NAME: {
@@ -142,7 +142,7 @@ export const StaticAssetDefinitions = {
           },
         },
       },
-      ['4xx | 5xx']: jsonContent({ description: 'Error Response', schema: ErrorSchema }),
+      ['4xx | 5xx']: jsonContent({ description: 'Error Response', schema: ErrorResponseSchema }),
     },
   },
   UPLOAD: {
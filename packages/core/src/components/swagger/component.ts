@@ -7,7 +7,12 @@ import type { Context, Next } from 'hono';
 import { Authentication } from '../auth';
 import { DocumentUITypes, ISwaggerOptions, SwaggerBindingKeys } from './common';
 import { UIProviderFactory } from './ui-factory';
-import { getError } from '@venizia/ignis-helpers';
+import {
+  ErrorResponseSchema,
+  getError,
+  PaginationMetaSchema,
+  ResponseMetaSchema,
+} from '@venizia/ignis-helpers';
 import { Binding } from '@/helpers/inversion';
 
 const DEFAULT_SWAGGER_OPTIONS: ISwaggerOptions = {
@@ -120,5 +125,10 @@ export class SwaggerComponent extends BaseComponent {
       type: 'http',
       scheme: 'basic',
     });
+
+    // Shared envelopes, listed even when no route references them yet
+    rootRouter.openAPIRegistry.register('ErrorResponse', ErrorResponseSchema);
+    rootRouter.openAPIRegistry.register('ResponseMeta', ResponseMetaSchema);
+    rootRouter.openAPIRegistry.register('PaginationMeta', PaginationMetaSchema);
   }
 }
@@ -0,0 +1,6 @@
+export class TelemetryDefaults {
+  // OTLP over HTTP, signals are posted to `<endpoint>/v1/traces` and `<endpoint>/v1/metrics`
+  static readonly ENDPOINT = 'http://localhost:4318';
+  static readonly SAMPLING_RATIO = 1;
+  static readonly METRIC_EXPORT_INTERVAL = 60_000;
+}
@@ -0,0 +1,4 @@
+export class TelemetryBindingKeys {
+  static readonly TELEMETRY_OPTIONS = '@app/telemetry/options';
+  static readonly TELEMETRY_GUARD = '@app/telemetry/guard';
+}
@@ -0,0 +1,28 @@
+import { AnyObject } from '@venizia/ignis-helpers';
+
+export interface ITelemetryOptions {
+  service?: {
+    // Defaults to `APP_ENV_APPLICATION_NAME`
+    name?: string;
+    version?: string;
+    // Defaults to `NODE_ENV`
+    environment?: string;
+  };
+  // Defaults to `OTEL_EXPORTER_OTLP_ENDPOINT`, then `TelemetryDefaults.ENDPOINT`
+  endpoint?: string;
+  // e.g. the collector API key, defaults to `OTEL_EXPORTER_OTLP_HEADERS` (`k1=v1,k2=v2`)
+  headers?: Record<string, string>;
+  // Share of the root traces recorded (0..1), child spans follow their parent decision
+  samplingRatio?: number;
+  // Extra resource attributes, e.g. `{ 'service.namespace': 'billing' }`
+  resourceAttributes?: AnyObject;
+  traces?: { isEnabled?: boolean };
+  metrics?: { isEnabled?: boolean; exportInterval?: number };
+}
+
+export interface ITelemetryGuard {
+  /**
+   * Flush pending spans and metrics then stop the exporters, safe to call more than once.
+   */
+  shutdown(): Promise<void>;
+}
@@ -0,0 +1,56 @@
+import { BaseApplication } from '@/base/applications';
+import { BaseComponent } from '@/base/components';
+import { inject } from '@/base/metadata';
+import { CoreBindings } from '@/common/bindings';
+import { Binding } from '@/helpers/inversion';
+import { ShutdownPhases, ValueOrPromise } from '@venizia/ignis-helpers';
+import { ITelemetryGuard, ITelemetryOptions, TelemetryBindingKeys } from './common';
+import { initTelemetry } from './telemetry';
+
+/**
+ * Starts OpenTelemetry from the `TelemetryBindingKeys.TELEMETRY_OPTIONS` binding and flushes
+ * it during the `flush` shutdown phase, after the HTTP server and consumers are drained.
+ *
+ * @example
+ * ```typescript
+ * this.bind<ITelemetryOptions>({ key: TelemetryBindingKeys.TELEMETRY_OPTIONS }).toValue({
+ *   service: { name: 'order-service', version: pkg.version },
+ *   samplingRatio: 0.1,
+ * });
+ * this.component(TelemetryComponent);
+ * ```
+ */
+export class TelemetryComponent extends BaseComponent {
+  constructor(
+    @inject({ key: CoreBindings.APPLICATION_INSTANCE }) private application: BaseApplication,
+  ) {
+    super({
+      scope: TelemetryComponent.name,
+      initDefault: { enable: true, container: application },
+      bindings: {
+        [TelemetryBindingKeys.TELEMETRY_OPTIONS]: Binding.bind<ITelemetryOptions>({
+          key: TelemetryBindingKeys.TELEMETRY_OPTIONS,
+        }).toValue({}),
+      },
+    });
+  }
+
+  override binding(): ValueOrPromise<void> {
+    const options =
+      this.application.get<ITelemetryOptions>({
+        key: TelemetryBindingKeys.TELEMETRY_OPTIONS,
+        isOptional: true,
+      }) ?? {};
+
+    const guard = initTelemetry(options);
+    this.application
+      .bind<ITelemetryGuard>({ key: TelemetryBindingKeys.TELEMETRY_GUARD })
+      .toValue(guard);
+
+    this.application.registerShutdownHook({
+      identifier: TelemetryComponent.name,
+      phase: ShutdownPhases.FLUSH,
+      hook: () => guard.shutdown(),
+    });
+  }
+}
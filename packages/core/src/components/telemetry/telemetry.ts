@@ -0,0 +1,112 @@
+import { OTLPMetricExporter } from '@opentelemetry/exporter-metrics-otlp-http';
+import { OTLPTraceExporter } from '@opentelemetry/exporter-trace-otlp-http';
+import { resourceFromAttributes } from '@opentelemetry/resources';
+import { PeriodicExportingMetricReader } from '@opentelemetry/sdk-metrics';
+import { NodeSDK } from '@opentelemetry/sdk-node';
+import { ParentBasedSampler, TraceIdRatioBasedSampler } from '@opentelemetry/sdk-trace-base';
+import { ATTR_SERVICE_NAME, ATTR_SERVICE_VERSION } from '@opentelemetry/semantic-conventions';
+import { Defaults, getError, HTTP, LoggerFactory } from '@venizia/ignis-helpers';
+import { ITelemetryGuard, ITelemetryOptions, TelemetryDefaults } from './common';
+
+const logger = LoggerFactory.getLogger(['Telemetry']);
+
+/**
+ * Parse OTLP headers in the `OTEL_EXPORTER_OTLP_HEADERS` format, `k1=v1,k2=v2` with url
+ * encoded values.
+ */
+export const parseOtlpHeaders = (input?: string): Record<string, string> => {
+  const rs: Record<string, string> = {};
+
+  for (const pair of (input ?? '').split(',')) {
+    const index = pair.indexOf('=');
+    if (index <= 0) {
+      continue;
+    }
+
+    const key = pair.slice(0, index).trim();
+    const value = pair.slice(index + 1).trim();
+    if (key && value) {
+      rs[key] = decodeURIComponent(value);
+    }
+  }
+
+  return rs;
+};
+
+// -------------------------------------------------------------------------------------------
+/**
+ * Start the OpenTelemetry SDK with OTLP/HTTP trace and metric exporters.
+ * Call it once, before the application creates spans or instruments, and keep the returned
+ * guard to flush on shutdown (`TelemetryComponent` registers it as a `flush` shutdown hook).
+ *
+ * @example
+ * ```typescript
+ * const telemetry = initTelemetry({
+ *   service: { name: 'order-service', version: '1.4.0' },
+ *   endpoint: 'https://otel-collector.internal:4318',
+ *   headers: { 'x-api-key': process.env.APP_ENV_OTEL_API_KEY },
+ *   samplingRatio: 0.1,
+ * });
+ *
+ * process.on('SIGTERM', () => telemetry.shutdown());
+ * ```
+ */
+export const initTelemetry = (opts: ITelemetryOptions = {}): ITelemetryGuard => {
+  const {
+    service = {},
+    endpoint = process.env.OTEL_EXPORTER_OTLP_ENDPOINT ?? TelemetryDefaults.ENDPOINT,
+    headers = parseOtlpHeaders(process.env.OTEL_EXPORTER_OTLP_HEADERS),
+    samplingRatio = TelemetryDefaults.SAMPLING_RATIO,
+    resourceAttributes = {},
+    traces = {},
+    metrics = {},
+  } = opts;
+
+  if (samplingRatio < 0 || samplingRatio > 1) {
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+      message: `[initTelemetry] Invalid sampling ratio, expected a value in [0, 1] | samplingRatio: ${samplingRatio}`,
+    });
+  }
+
+  const baseUrl = endpoint.replace(/\/+$/, '');
+  const environment = service.environment ?? process.env.NODE_ENV;
+
+  const sdk = new NodeSDK({
+    resource: resourceFromAttributes({
+      [ATTR_SERVICE_NAME]: service.name ?? Defaults.APPLICATION_NAME,
+      ...(service.version ? { [ATTR_SERVICE_VERSION]: service.version } : {}),
+      ...(environment ? { 'deployment.environment.name': environment } : {}),
+      ...resourceAttributes,
+    }),
+    sampler: new ParentBasedSampler({ root: new TraceIdRatioBasedSampler(samplingRatio) }),
+    traceExporter:
+      traces.isEnabled === false
+        ? undefined
+        : new OTLPTraceExporter({ url: `${baseUrl}/v1/traces`, headers }),
+    metricReader:
+      metrics.isEnabled === false
+        ? undefined
+        : new PeriodicExportingMetricReader({
+            exporter: new OTLPMetricExporter({ url: `${baseUrl}/v1/metrics`, headers }),
+            exportIntervalMillis:
+              metrics.exportInterval ?? TelemetryDefaults.METRIC_EXPORT_INTERVAL,
+          }),
+  });
+
+  sdk.start();
+  logger
+    .for(initTelemetry.name)
+    .info('Started | endpoint: %s | samplingRatio: %s', baseUrl, samplingRatio);
+
+  let shutdownPromise: Promise<void> | null = null;
+  return {
+    shutdown: () => {
+      shutdownPromise ??= sdk.shutdown().catch(error => {
+        logger.for('shutdown').error('Failed to flush telemetry | Error: %s', error);
+      });
+
+      return shutdownPromise;
+    },
+  };
+};
@@ -1,5 +1,5 @@
 import { z } from '@hono/zod-openapi';
-import { ErrorSchema, HTTP } from '@venizia/ignis-helpers';
+import { ErrorResponseSchema, HTTP } from '@venizia/ignis-helpers';
 
 /**
  * Create HTML content configuration for OpenAPI documentation
@@ -44,7 +44,7 @@ export const htmlResponse = (opts: { description: string; required?: boolean })
       description: 'Error Response',
       content: {
         'application/json': {
-          schema: ErrorSchema,
+          schema: ErrorResponseSchema,
         },
       },
     },
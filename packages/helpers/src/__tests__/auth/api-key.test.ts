@@ -0,0 +1,80 @@
+/**
+ * API Key Test Suite
+ *
+ * Tests ApiKeyHelper:
+ * 1. Generation — prefixed format, only the hash is part of the record
+ * 2. Verification — valid, tampered, revoked and expired keys
+ *
+ * @module __tests__/auth/api-key
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { ApiKeyErrorCodes, ApiKeyHelper, IApiKeyRecord } from '@/helpers/auth';
+import { ApplicationError } from '@/helpers/error';
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+const records = new Map<string, IApiKeyRecord>();
+const apiKeys = new ApiKeyHelper({
+  prefix: 'sk_test',
+  pepper: 'pepper',
+  store: { findById: ({ id }) => records.get(id) },
+});
+
+const expectErrorCode = async (promise: Promise<unknown>, messageCode: string) => {
+  try {
+    await promise;
+  } catch (error) {
+    expect((error as ApplicationError).messageCode).toBe(messageCode);
+    return;
+  }
+
+  throw new Error('Expected API key verification to throw');
+};
+
+// =============================================================================
+// ApiKeyHelper
+// =============================================================================
+
+describe('ApiKeyHelper', () => {
+  test('TC-001: generates prefixed keys and stores only their hash', () => {
+    const { key, record } = apiKeys.generate({ metadata: { tenantId: 't1' } });
+
+    expect(key).toMatch(/^sk_test_[0-9A-Za-z]{12}_[0-9A-Za-z]{32}$/);
+    expect(ApiKeyHelper.parse(key)).toEqual({
+      prefix: 'sk_test',
+      id: record.id,
+      secret: key.slice(-32),
+    });
+    expect(JSON.stringify(record)).not.toContain(key.slice(-32));
+    expect(record.hash).toBe(apiKeys.hash({ key }));
+  });
+
+  test('TC-002: verifies keys against the store', async () => {
+    const { key, record } = apiKeys.generate({ metadata: { tenantId: 't1' } });
+    records.set(record.id, record);
+
+    expect((await apiKeys.verify({ key })).metadata).toEqual({ tenantId: 't1' });
+
+    const forged = `${key.slice(0, -1)}${key.endsWith('a') ? 'b' : 'a'}`;
+    await expectErrorCode(apiKeys.verify({ key: forged }), ApiKeyErrorCodes.INVALID);
+    await expectErrorCode(apiKeys.verify({ key: 'sk_test_abc' }), ApiKeyErrorCodes.MALFORMED);
+    await expectErrorCode(
+      apiKeys.verify({ key: key.replace('sk_test', 'sk_live') }),
+      ApiKeyErrorCodes.MALFORMED,
+    );
+  });
+
+  test('TC-003: rejects revoked and expired keys', async () => {
+    const revoked = apiKeys.generate();
+    records.set(revoked.record.id, { ...revoked.record, revokedAt: new Date() });
+
+    const expired = apiKeys.generate({ expiresAt: new Date(Date.now() - 1_000) });
+    records.set(expired.record.id, expired.record);
+
+    await expectErrorCode(apiKeys.verify({ key: revoked.key }), ApiKeyErrorCodes.REVOKED);
+    await expectErrorCode(apiKeys.verify({ key: expired.key }), ApiKeyErrorCodes.EXPIRED);
+  });
+});
@@ -0,0 +1,262 @@
+/**
+ * JWT Test Suite
+ *
+ * Tests JWTHelper signing and verification:
+ * 1. Round trips for HS256 / RS256 / ES256
+ * 2. Claim validation — exp / nbf with leeway, issuer, audience, required claims
+ * 3. Rejections — tampered payload, algorithm confusion, malformed tokens
+ * 4. JWKSHelper — key lookup by `kid` and refetch on rotation
+ * 5. Clock — injected clock, per claim leeway and structured claim errors
+ *
+ * @module __tests__/auth/jwt
+ */
+
+import { describe, test, expect, spyOn } from 'bun:test';
+import C from 'node:crypto';
+import { JWKSHelper, JWTAlgorithms, JWTError, JWTErrorCodes, JWTHelper } from '@/helpers/auth';
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+const NOW = 1_760_000_000;
+
+const rsaKeys = C.generateKeyPairSync('rsa', { modulusLength: 2048 });
+const ecKeys = C.generateKeyPairSync('ec', { namedCurve: 'P-256' });
+
+const expectJWTError = async (promise: Promise<unknown>, messageCode: string) => {
+  try {
+    await promise;
+  } catch (error) {
+    expect(error).toBeInstanceOf(JWTError);
+    expect((error as JWTError).messageCode).toBe(messageCode);
+    return;
+  }
+
+  throw new Error('Expected JWT verification to throw');
+};
+
+// =============================================================================
+// Round trips
+// =============================================================================
+
+describe('JWT', () => {
+  describe('Round trips', () => {
+    const helpers = [
+      new JWTHelper({ algorithm: JWTAlgorithms.HS256, secret: 'super-secret' }),
+      new JWTHelper({ algorithm: JWTAlgorithms.RS256, privateKey: rsaKeys.privateKey }),
+      new JWTHelper({ algorithm: JWTAlgorithms.ES256, privateKey: ecKeys.privateKey, keyId: 'k1' }),
+    ];
+
+    for (const jwt of helpers) {
+      const { alg } = JWTHelper.decode(jwt.sign()).header;
+
+      test(`TC-001: signs and verifies with ${alg}`, async () => {
+        const token = jwt.sign({ claims: { roles: ['admin'] }, subject: '42', expiresIn: 60 });
+        const { header, payload } = await jwt.verify<{ roles: string[] }>({ token });
+
+        expect(header.typ).toBe('JWT');
+        expect(payload.sub).toBe('42');
+        expect(payload.roles).toEqual(['admin']);
+        expect(payload.exp).toBe(Number(payload.iat) + 60);
+      });
+    }
+
+    test('TC-002: verifies with a public key only helper', async () => {
+      const issuer = new JWTHelper({
+        algorithm: JWTAlgorithms.ES256,
+        privateKey: ecKeys.privateKey,
+      });
+      const verifier = new JWTHelper({
+        algorithm: JWTAlgorithms.ES256,
+        publicKey: ecKeys.publicKey.export({ type: 'spki', format: 'pem' }),
+      });
+
+      const { payload } = await verifier.verify({ token: issuer.sign({ subject: '42' }) });
+      expect(payload.sub).toBe('42');
+      expect(() => verifier.sign()).toThrow(JWTError);
+    });
+  });
+
+  // ===========================================================================
+  // Claim validation
+  // ===========================================================================
+
+  describe('Claim validation', () => {
+    const jwt = new JWTHelper({
+      algorithm: JWTAlgorithms.HS256,
+      secret: 'super-secret',
+      issuer: 'https://auth.example.com',
+      audience: 'api',
+      leeway: 30,
+    });
+
+    test('TC-003: applies leeway to exp and nbf', async () => {
+      const token = jwt.sign({ now: NOW, expiresIn: 60, notBefore: 10 });
+
+      await jwt.verify({ token, now: NOW - 15 });
+      await jwt.verify({ token, now: NOW + 80 });
+      await expectJWTError(jwt.verify({ token, now: NOW + 90 }), JWTErrorCodes.EXPIRED);
+      await expectJWTError(jwt.verify({ token, now: NOW - 30 }), JWTErrorCodes.NOT_ACTIVE);
+    });
+
+    test('TC-004: validates issuer, audience, subject and required claims', async () => {
+      const token = jwt.sign({ now: NOW, subject: '42', audience: ['api', 'admin'] });
+
+      await jwt.verify({ token, now: NOW, audience: 'admin', subject: '42' });
+      await expectJWTError(
+        jwt.verify({ token, now: NOW, issuer: 'https://other.example.com' }),
+        JWTErrorCodes.INVALID_ISSUER,
+      );
+      await expectJWTError(
+        jwt.verify({ token, now: NOW, audience: 'billing' }),
+        JWTErrorCodes.INVALID_AUDIENCE,
+      );
+      await expectJWTError(
+        jwt.verify({ token, now: NOW, subject: '43' }),
+        JWTErrorCodes.INVALID_SUBJECT,
+      );
+      await expectJWTError(
+        jwt.verify({ token, now: NOW, requiredClaims: ['jti'] }),
+        JWTErrorCodes.MISSING_CLAIM,
+      );
+    });
+
+    test('TC-005: rejects tokens older than maxAge', async () => {
+      const token = jwt.sign({ now: NOW });
+      await expectJWTError(
+        jwt.verify({ token, now: NOW + 3_600, maxAge: 600 }),
+        JWTErrorCodes.EXPIRED,
+      );
+    });
+  });
+
+  // ===========================================================================
+  // Rejections
+  // ===========================================================================
+
+  describe('Rejections', () => {
+    test('TC-006: rejects a tampered payload', async () => {
+      const jwt = new JWTHelper({ algorithm: JWTAlgorithms.HS256, secret: 'super-secret' });
+      const [header, , signature] = jwt.sign({ claims: { role: 'user' } }).split('.');
+      const forged = JWTHelper.encodeSegment({ role: 'admin', iat: NOW });
+
+      await expectJWTError(
+        jwt.verify({ token: `${header}.${forged}.${signature}` }),
+        JWTErrorCodes.INVALID_SIGNATURE,
+      );
+    });
+
+    test('TC-007: rejects HS256 tokens signed with the RSA public key', async () => {
+      const jwt = new JWTHelper({ algorithm: JWTAlgorithms.RS256, privateKey: rsaKeys.privateKey });
+      const publicPem = rsaKeys.publicKey.export({ type: 'spki', format: 'pem' });
+      const token = JWTHelper.signToken({
+        algorithm: JWTAlgorithms.HS256,
+        key: publicPem,
+        claims: { sub: 'attacker' },
+      });
+
+      await expectJWTError(jwt.verify({ token }), JWTErrorCodes.UNSUPPORTED_ALGORITHM);
+      await expectJWTError(
+        jwt.verify({ token, algorithms: [JWTAlgorithms.RS256, JWTAlgorithms.HS256] }),
+        JWTErrorCodes.INVALID_SIGNATURE,
+      );
+    });
+
+    test('TC-008: rejects unsigned and malformed tokens', async () => {
+      const jwt = new JWTHelper({ algorithm: JWTAlgorithms.HS256, secret: 'super-secret' });
+      const unsigned = [
+        JWTHelper.encodeSegment({ alg: 'none' }),
+        JWTHelper.encodeSegment({}),
+        'x',
+      ].join('.');
+
+      await expectJWTError(jwt.verify({ token: unsigned }), JWTErrorCodes.UNSUPPORTED_ALGORITHM);
+      await expectJWTError(jwt.verify({ token: 'not-a-jwt' }), JWTErrorCodes.MALFORMED);
+      await expectJWTError(jwt.verify({ token: 'a.b.c' }), JWTErrorCodes.MALFORMED);
+    });
+  });
+
+  // ===========================================================================
+  // JWKSHelper
+  // ===========================================================================
+
+  describe('JWKSHelper', () => {
+    const rotatedKeys = C.generateKeyPairSync('rsa', { modulusLength: 2048 });
+    const toJWK = (kid: string, key: C.KeyObject) => {
+      return { ...key.export({ format: 'jwk' }), kid, use: 'sig', alg: 'RS256' };
+    };
+
+    test('TC-009: refetches the key set when a token has an unknown kid', async () => {
+      let keys = [toJWK('k1', rsaKeys.publicKey)];
+      const fetchSpy = spyOn(globalThis, 'fetch').mockImplementation((async () => {
+        return Response.json({ keys });
+      }) as unknown as typeof fetch);
+
+      const jwks = new JWKSHelper({ url: 'https://auth.example.com/jwks.json', cooldown: 0 });
+      const verifier = new JWTHelper({
+        algorithm: JWTAlgorithms.RS256,
+        keyResolver: jwks.getKeyResolver(),
+      });
+      const sign = (keyId: string, privateKey: C.KeyObject) => {
+        return new JWTHelper({ algorithm: JWTAlgorithms.RS256, privateKey, keyId }).sign();
+      };
+
+      try {
+        await verifier.verify({ token: sign('k1', rsaKeys.privateKey) });
+        await verifier.verify({ token: sign('k1', rsaKeys.privateKey) });
+        expect(fetchSpy).toHaveBeenCalledTimes(1);
+
+        keys = [toJWK('k1', rsaKeys.publicKey), toJWK('k2', rotatedKeys.publicKey)];
+        await verifier.verify({ token: sign('k2', rotatedKeys.privateKey) });
+        expect(fetchSpy).toHaveBeenCalledTimes(2);
+
+        await expectJWTError(
+          verifier.verify({ token: sign('k3', rotatedKeys.privateKey) }),
+          JWTErrorCodes.INVALID_KEY,
+        );
+      } finally {
+        fetchSpy.mockRestore();
+      }
+    });
+  });
+
+  // ===========================================================================
+  // Clock
+  // ===========================================================================
+
+  describe('Clock', () => {
+    let clock = NOW * 1000;
+    const jwt = new JWTHelper({
+      algorithm: JWTAlgorithms.HS256,
+      secret: 'super-secret',
+      leeway: { exp: 5, nbf: 60 },
+      clock: () => clock,
+    });
+
+    test('TC-010: verifies against the injected clock with per claim leeway', async () => {
+      const token = jwt.sign({ expiresIn: 60, notBefore: 30 });
+
+      clock = NOW * 1000;
+      await jwt.verify({ token });
+
+      clock = (NOW + 70) * 1000;
+      const error = await jwt.verify({ token }).catch(e => e);
+      expect(error).toBeInstanceOf(JWTError);
+      expect(error).toMatchObject({
+        messageCode: JWTErrorCodes.EXPIRED,
+        details: { claim: 'exp', value: NOW + 60, now: NOW + 70, leeway: 5 },
+      });
+    });
+
+    test('TC-011: rejects numeric date claims which are not numbers as malformed', async () => {
+      const token = JWTHelper.signToken({
+        algorithm: JWTAlgorithms.HS256,
+        key: 'super-secret',
+        claims: { exp: 'tomorrow' },
+      });
+
+      await expectJWTError(jwt.verify({ token }), JWTErrorCodes.MALFORMED);
+    });
+  });
+});
@@ -0,0 +1,151 @@
+/**
+ * OAuth2 Test Suite
+ *
+ * Tests OAuth2ClientHelper:
+ * 1. PKCE — S256 challenges of generated verifiers verify, others do not
+ * 2. Authorization code flow — authorization url, callback state check and code exchange
+ * 3. Refresh — kept refresh tokens and `invalid_grant` errors
+ * 4. Token cache — encrypted file reused between runs, expiring tokens refreshed
+ *
+ * @module __tests__/auth/oauth2
+ */
+
+import { describe, test, expect, afterAll, beforeAll, afterEach } from 'bun:test';
+import fsp from 'node:fs/promises';
+import os from 'node:os';
+import path from 'node:path';
+import { FileOAuth2TokenCache, OAuth2ClientHelper, OAuth2ErrorCodes } from '@/helpers/auth';
+import { MockServer } from '@/helpers/testing';
+
+describe('OAuth2ClientHelper', () => {
+  const server = new MockServer();
+  let oauth: OAuth2ClientHelper;
+
+  beforeAll(async () => {
+    await server.start();
+    oauth = new OAuth2ClientHelper({
+      clientId: 'shop-app',
+      clientSecret: 'shop-secret',
+      authorizationUrl: 'https://auth.marketplace.com/oauth2/authorize',
+      tokenUrl: `${server.getBaseUrl()}/oauth2/token`,
+      redirectUri: 'https://api.example.com/callback',
+      scopes: ['orders.read', 'orders.write'],
+    });
+  });
+
+  afterEach(() => {
+    server.reset();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: verifies the S256 challenge of generated verifiers only', () => {
+    // RFC 7636 appendix B
+    expect(
+      OAuth2ClientHelper.computeCodeChallenge({
+        codeVerifier: 'dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk',
+      }),
+    ).toBe('E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM');
+
+    const { codeVerifier, codeChallenge } = OAuth2ClientHelper.generatePKCE();
+    expect(OAuth2ClientHelper.verifyPKCE({ codeVerifier, codeChallenge })).toBe(true);
+
+    const other = OAuth2ClientHelper.generatePKCE();
+    expect(
+      OAuth2ClientHelper.verifyPKCE({ codeVerifier: other.codeVerifier, codeChallenge }),
+    ).toBe(false);
+  });
+
+  test('TC-002: exchanges the code of a callback matching the authorization request', async () => {
+    server.when({ method: 'POST', path: '/oauth2/token' }).respond({
+      json: { access_token: 'at-1', token_type: 'Bearer', expires_in: 3600, refresh_token: 'rt-1' },
+    });
+
+    const { url, state, codeVerifier } = oauth.createAuthorizationRequest();
+    const params = new URL(url).searchParams;
+    expect(params.get('scope')).toBe('orders.read orders.write');
+    expect(params.get('code_challenge_method')).toBe('S256');
+    expect(
+      OAuth2ClientHelper.verifyPKCE({ codeVerifier, codeChallenge: params.get('code_challenge')! }),
+    ).toBe(true);
+
+    const forged = oauth.handleCallback({ url: '/callback?code=c&state=x', state, codeVerifier });
+    await expect(forged).rejects.toMatchObject({ messageCode: OAuth2ErrorCodes.STATE_MISMATCH });
+
+    const tokens = await oauth.handleCallback({
+      url: `/callback?code=c-1&state=${state}`,
+      state,
+      codeVerifier,
+    });
+    expect(tokens.accessToken).toBe('at-1');
+    expect(tokens.expiresAt).toBeGreaterThan(Date.now());
+
+    const [exchange] = server.requests;
+    expect(exchange.headers['authorization']).toBe(
+      `Basic ${Buffer.from('shop-app:shop-secret').toString('base64')}`,
+    );
+    const form = new URLSearchParams(exchange.rawBody);
+    expect(form.get('grant_type')).toBe('authorization_code');
+    expect(form.get('code')).toBe('c-1');
+    expect(form.get('code_verifier')).toBe(codeVerifier);
+  });
+
+  test('TC-003: keeps the refresh token and reports invalid grants as 401', async () => {
+    server
+      .when({ method: 'POST', path: '/oauth2/token' })
+      .respond(rq =>
+        new URLSearchParams(rq.rawBody).get('refresh_token') === 'rt-1'
+          ? { json: { access_token: 'at-2' } }
+          : { status: 400, json: { error: 'invalid_grant' } },
+      );
+
+    const tokens = await oauth.refresh({ refreshToken: 'rt-1' });
+    expect(tokens.accessToken).toBe('at-2');
+    expect(tokens.refreshToken).toBe('rt-1');
+
+    await expect(oauth.refresh({ refreshToken: 'revoked' })).rejects.toMatchObject({
+      statusCode: 401,
+      messageCode: OAuth2ErrorCodes.INVALID_GRANT,
+    });
+  });
+
+  test('TC-004: reuses encrypted cached tokens and refreshes expiring ones', async () => {
+    server.when({ method: 'POST', path: '/oauth2/token' }).respond({
+      json: { access_token: 'at-3', expires_in: 3600 },
+    });
+
+    const directory = await fsp.mkdtemp(path.join(os.tmpdir(), 'ignis-oauth2-'));
+    const file = path.join(directory, 'cli', 'tokens');
+    const createClient = (passphrase: string) =>
+      new OAuth2ClientHelper({
+        clientId: 'ignis-cli',
+        authorizationUrl: 'https://auth.marketplace.com/oauth2/authorize',
+        tokenUrl: `${server.getBaseUrl()}/oauth2/token`,
+        tokenCache: new FileOAuth2TokenCache({ path: file, passphrase }),
+      });
+
+    try {
+      const expiring = { accessToken: 'at-1', tokenType: 'Bearer', refreshToken: 'rt-1', raw: {} };
+      await new FileOAuth2TokenCache({ path: file, passphrase: 'correct horse' }).set({
+        key: `${server.getBaseUrl()}/oauth2/token#ignis-cli`,
+        tokens: { ...expiring, expiresAt: Date.now() + 1_000 },
+      });
+      expect(await fsp.readFile(file, 'utf-8')).not.toContain('rt-1');
+
+      const refreshed = await createClient('correct horse').getCachedTokens();
+      expect(refreshed).toMatchObject({ accessToken: 'at-3', refreshToken: 'rt-1' });
+      expect(server.requests).toHaveLength(1);
+
+      // Next run, fresh tokens come from the file
+      const cached = await createClient('correct horse').getCachedTokens();
+      expect(cached?.accessToken).toBe('at-3');
+      expect(server.requests).toHaveLength(1);
+
+      expect(await createClient('wrong passphrase').getCachedTokens()).toBeUndefined();
+    } finally {
+      await fsp.rm(directory, { recursive: true, force: true });
+    }
+  });
+});
@@ -0,0 +1,89 @@
+/**
+ * OIDC Discovery Test Suite
+ *
+ * Tests OIDCDiscoveryHelper:
+ * 1. Tokens of the issuer verify with the keys of the discovered `jwks_uri`
+ * 2. OAuth2 clients use the discovered endpoints
+ * 3. Documents of another issuer are rejected
+ *
+ * @module __tests__/auth/oidc
+ */
+
+import { describe, test, expect, afterAll, beforeAll, afterEach } from 'bun:test';
+import C from 'node:crypto';
+import { JWTAlgorithms, JWTHelper, OIDCDiscoveryHelper, OIDCErrorCodes } from '@/helpers/auth';
+import { MockServer } from '@/helpers/testing';
+
+describe('OIDCDiscoveryHelper', () => {
+  const server = new MockServer();
+  const keys = C.generateKeyPairSync('rsa', { modulusLength: 2048 });
+
+  const mockProvider = (opts: { issuer?: string } = {}) => {
+    const baseUrl = server.getBaseUrl();
+    server.when({ method: 'GET', path: '/.well-known/openid-configuration' }).respond({
+      headers: { ['cache-control']: 'max-age=300' },
+      json: {
+        issuer: opts.issuer ?? baseUrl,
+        authorization_endpoint: `${baseUrl}/authorize`,
+        token_endpoint: `${baseUrl}/token`,
+        jwks_uri: `${baseUrl}/jwks.json`,
+      },
+    });
+    server.when({ method: 'GET', path: '/jwks.json' }).respond({
+      json: { keys: [{ ...keys.publicKey.export({ format: 'jwk' }), kid: 'k1', alg: 'RS256' }] },
+    });
+  };
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  afterEach(() => {
+    server.reset();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: verifies issuer tokens with the discovered signing keys', async () => {
+    mockProvider();
+    const oidc = new OIDCDiscoveryHelper({ issuer: server.getBaseUrl() });
+    const jwt = oidc.createJWTHelper({ audience: 'api' });
+
+    const token = new JWTHelper({
+      algorithm: JWTAlgorithms.RS256,
+      privateKey: keys.privateKey,
+      keyId: 'k1',
+      issuer: server.getBaseUrl(),
+      audience: 'api',
+    }).sign({ subject: '42' });
+
+    const { payload } = await jwt.verify({ token });
+    expect(payload.sub).toBe('42');
+
+    await jwt.verify({ token });
+    expect(server.requests.map(rq => rq.path)).toEqual([
+      '/.well-known/openid-configuration',
+      '/jwks.json',
+    ]);
+  });
+
+  test('TC-002: builds OAuth2 clients on the discovered endpoints', async () => {
+    mockProvider();
+    const oidc = new OIDCDiscoveryHelper({ issuer: server.getBaseUrl() });
+
+    const oauth = await oidc.createOAuth2Client({ clientId: 'cli' });
+    const { url } = oauth.createAuthorizationRequest();
+    expect(url.startsWith(`${server.getBaseUrl()}/authorize?`)).toBe(true);
+  });
+
+  test('TC-003: rejects discovery documents of another issuer', async () => {
+    mockProvider({ issuer: 'https://evil.example.com' });
+    const oidc = new OIDCDiscoveryHelper({ issuer: server.getBaseUrl() });
+
+    await expect(oidc.getMetadata()).rejects.toMatchObject({
+      messageCode: OIDCErrorCodes.ISSUER_MISMATCH,
+    });
+  });
+});
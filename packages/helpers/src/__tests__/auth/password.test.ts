@@ -0,0 +1,53 @@
+/**
+ * Password Test Suite
+ *
+ * Tests PasswordHelper argon2id hashing:
+ * 1. Hash / verify round trip
+ * 2. needsRehash when the configured costs or algorithm change
+ *
+ * @module __tests__/auth/password
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { PasswordHashDefaults, PasswordHelper } from '@/helpers/auth';
+
+// Keep the suite fast, the costs only need to differ between helpers
+const passwordHelper = new PasswordHelper({ memoryCost: 8_192, timeCost: 1 });
+
+describe('Password', () => {
+  test('TC-001: hashes with argon2id and verifies the password', async () => {
+    const hash = await passwordHelper.hash({ password: 'correct horse battery staple' });
+
+    expect(PasswordHelper.parseHash(hash)).toMatchObject({
+      algorithm: 'argon2id',
+      memoryCost: 8_192,
+      timeCost: 1,
+    });
+    expect(await passwordHelper.verify({ password: 'correct horse battery staple', hash })).toBe(
+      true,
+    );
+    expect(await passwordHelper.verify({ password: 'wrong password', hash })).toBe(false);
+    expect(await passwordHelper.verify({ password: 'x', hash: 'not-a-hash' })).toBe(false);
+  });
+
+  test('TC-002: detects hashes that need a rehash', async () => {
+    const hash = await passwordHelper.hash({ password: 'secret' });
+    const stronger = new PasswordHelper({ memoryCost: 8_192, timeCost: 2 });
+
+    expect(passwordHelper.needsRehash({ hash })).toBe(false);
+    expect(stronger.needsRehash({ hash })).toBe(true);
+    expect(stronger.needsRehash({ hash: await Bun.password.hash('secret', 'bcrypt') })).toBe(true);
+
+    const { isValid, rehashed } = await stronger.verifyAndRehash({ password: 'secret', hash });
+    expect(isValid).toBe(true);
+    expect(PasswordHelper.parseHash(rehashed!)?.timeCost).toBe(2);
+  });
+
+  test('TC-003: rejects costs below the minimum', () => {
+    expect(() => new PasswordHelper({ memoryCost: 1_024 })).toThrow();
+    expect(new PasswordHelper().getCosts()).toEqual({
+      memoryCost: PasswordHashDefaults.MEMORY_COST,
+      timeCost: PasswordHashDefaults.TIME_COST,
+    });
+  });
+});
@@ -0,0 +1,60 @@
+/**
+ * Signed URL Test Suite
+ *
+ * Tests SignedUrlHelper:
+ * 1. Round trips — relative and absolute urls, reordered query parameters
+ * 2. Rejections — tampered query, pushed back expiry, expired and unsigned urls
+ * 3. Rotation — urls signed with a previous secret stay valid
+ *
+ * @module __tests__/auth/signed-url
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { SignedUrlErrorCodes, SignedUrlHelper } from '@/helpers/auth';
+
+const NOW = 1_760_000_000;
+
+describe('SignedUrlHelper', () => {
+  const signedUrls = new SignedUrlHelper({ secrets: 'url-secret', expiresIn: 60 });
+
+  test('TC-001: verifies signed urls with reordered query parameters', () => {
+    const url = signedUrls.sign({ url: '/files/42/download?name=report.pdf&inline=1', now: NOW });
+    expect(url.startsWith('/files/42/download?')).toBe(true);
+
+    const [pathname, query] = url.split('?');
+    const reordered = `${pathname}?${query.split('&').reverse().join('&')}`;
+    const { expiresAt, params } = signedUrls.verify({ url: reordered, now: NOW + 30 });
+
+    expect(expiresAt).toBe(NOW + 60);
+    expect(Object.fromEntries(params)).toEqual({ name: 'report.pdf', inline: '1' });
+
+    const absolute = signedUrls.sign({ url: 'https://api.example.com/files/42', now: NOW });
+    expect(() => signedUrls.verify({ url: absolute, now: NOW })).not.toThrow();
+  });
+
+  test('TC-002: rejects tampered, expired and unsigned urls', () => {
+    const url = signedUrls.sign({ url: '/files/42', now: NOW });
+    const getCode = (opts: { url: string; now?: number }) => {
+      try {
+        signedUrls.verify({ now: NOW, ...opts });
+      } catch (error) {
+        return (error as { messageCode?: string }).messageCode;
+      }
+    };
+
+    const postponed = url.replace(`${NOW + 60}`, `${NOW + 600}`);
+    expect(getCode({ url: url.replace('/42', '/43') })).toBe(SignedUrlErrorCodes.INVALID_SIGNATURE);
+    expect(getCode({ url: postponed })).toBe(SignedUrlErrorCodes.INVALID_SIGNATURE);
+    expect(getCode({ url, now: NOW + 60 })).toBe(SignedUrlErrorCodes.EXPIRED);
+    expect(getCode({ url: '/files/42' })).toBe(SignedUrlErrorCodes.MALFORMED);
+  });
+
+  test('TC-003: accepts urls signed with a previous secret', () => {
+    const previous = new SignedUrlHelper({ secrets: 'old-secret' });
+    const rotated = new SignedUrlHelper({ secrets: ['new-secret', 'old-secret'] });
+
+    const url = previous.sign({ url: '/files/42', now: NOW });
+    expect(rotated.verify({ url, now: NOW }).expiresAt).toBe(NOW + 15 * 60);
+    expect(() => signedUrls.verify({ url, now: NOW })).toThrow();
+  });
+});
@@ -0,0 +1,108 @@
+/**
+ * TOTP Test Suite
+ *
+ * Tests TOTPHelper:
+ * 1. RFC 6238 test vectors and base32 round trips
+ * 2. Verification — drift window and replay protection
+ * 3. Provisioning URI and recovery codes
+ *
+ * @module __tests__/auth/totp
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { TOTPAlgorithms, TOTPHelper } from '@/helpers/auth';
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+// RFC 6238 Appendix B seed for SHA1
+const RFC_SECRET = TOTPHelper.encodeBase32(Buffer.from('12345678901234567890'));
+
+describe('TOTP', () => {
+  // ===========================================================================
+  // Generation
+  // ===========================================================================
+
+  describe('Generation', () => {
+    test('TC-001: matches the RFC 6238 SHA1 test vectors', () => {
+      const totp = new TOTPHelper({ algorithm: TOTPAlgorithms.SHA1, digits: 8 });
+
+      expect(totp.generate({ secret: RFC_SECRET, timestamp: 59_000 })).toBe('94287082');
+      expect(totp.generate({ secret: RFC_SECRET, timestamp: 1_111_111_109_000 })).toBe('07081804');
+      expect(totp.generate({ secret: RFC_SECRET, timestamp: 20_000_000_000_000 })).toBe(
+        '65353130',
+      );
+    });
+
+    test('TC-002: round trips base32 secrets', () => {
+      const secret = TOTPHelper.generateSecret();
+
+      expect(secret).toMatch(/^[A-Z2-7]{32}$/);
+      expect(TOTPHelper.decodeBase32(secret).length).toBe(20);
+      expect(TOTPHelper.decodeBase32(secret.toLowerCase().replace(/(.{4})/g, '$1 '))).toEqual(
+        TOTPHelper.decodeBase32(secret),
+      );
+    });
+  });
+
+  // ===========================================================================
+  // Verification
+  // ===========================================================================
+
+  describe('Verification', () => {
+    const totp = new TOTPHelper();
+    const secret = TOTPHelper.generateSecret();
+    const now = 1_760_000_000_000;
+
+    test('TC-003: accepts codes within the drift window', () => {
+      const previous = totp.generate({ secret, timestamp: now - 30_000 });
+      const older = totp.generate({ secret, timestamp: now - 60_000 });
+
+      expect(totp.verify({ secret, token: previous, timestamp: now })).toMatchObject({
+        isValid: true,
+        delta: -1,
+      });
+      expect(totp.verify({ secret, token: older, timestamp: now }).isValid).toBe(false);
+      expect(totp.verify({ secret, token: older, timestamp: now, window: 2 }).isValid).toBe(true);
+      expect(totp.verify({ secret, token: 'abcdef', timestamp: now }).isValid).toBe(false);
+    });
+
+    test('TC-004: rejects replayed codes', () => {
+      const token = totp.generate({ secret, timestamp: now });
+      const { isValid, step } = totp.verify({ secret, token, timestamp: now });
+
+      expect(isValid).toBe(true);
+      expect(totp.verify({ secret, token, timestamp: now, lastUsedStep: step }).isValid).toBe(
+        false,
+      );
+    });
+  });
+
+  // ===========================================================================
+  // Provisioning & recovery codes
+  // ===========================================================================
+
+  describe('Provisioning & recovery codes', () => {
+    test('TC-005: builds an otpauth provisioning URI', () => {
+      const totp = new TOTPHelper({ issuer: 'Ignis Cloud' });
+      const uri = new URL(totp.getProvisioningUri({ secret: 'JBSWY3DP', accountName: 'a@b.co' }));
+
+      expect(uri.protocol).toBe('otpauth:');
+      expect(uri.host).toBe('totp');
+      expect(decodeURIComponent(uri.pathname)).toBe('/Ignis Cloud:a@b.co');
+      expect(uri.searchParams.get('secret')).toBe('JBSWY3DP');
+      expect(uri.searchParams.get('issuer')).toBe('Ignis Cloud');
+      expect(uri.searchParams.get('digits')).toBe('6');
+    });
+
+    test('TC-006: generates single use recovery codes', () => {
+      const { codes, hashes } = TOTPHelper.generateRecoveryCodes({ count: 5 });
+
+      expect(codes).toHaveLength(5);
+      expect(codes[0]).toMatch(/^[2-9A-Z]{5}-[2-9A-Z]{5}$/);
+      expect(TOTPHelper.verifyRecoveryCode({ code: codes[3].toLowerCase(), hashes })).toBe(3);
+      expect(TOTPHelper.verifyRecoveryCode({ code: 'AAAAA-AAAAA', hashes })).toBe(-1);
+    });
+  });
+});
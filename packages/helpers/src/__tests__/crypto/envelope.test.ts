@@ -0,0 +1,101 @@
+/**
+ * Envelope Crypto Test Suite
+ *
+ * Tests EnvelopeCryptoHelper:
+ * 1. Round trip with and without AAD
+ * 2. Key rotation — old versions keep decrypting, reencrypt moves to the current one
+ * 3. Tampering and unknown key versions
+ *
+ * @module __tests__/crypto/envelope
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { ApplicationError } from '@/helpers/error';
+import { EnvelopeCryptoErrorCodes, EnvelopeCryptoHelper } from '@/helpers/crypto';
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+const KEY_2024 = EnvelopeCryptoHelper.generateKey();
+const KEY_2025 = EnvelopeCryptoHelper.generateKey();
+
+const expectErrorCode = (fn: () => unknown, messageCode: string) => {
+  try {
+    fn();
+  } catch (error) {
+    expect((error as ApplicationError).messageCode).toBe(messageCode);
+    return;
+  }
+
+  throw new Error('Expected envelope operation to throw');
+};
+
+// =============================================================================
+// EnvelopeCryptoHelper
+// =============================================================================
+
+describe('EnvelopeCryptoHelper', () => {
+  const previous = new EnvelopeCryptoHelper({
+    keys: { '2024': KEY_2024 },
+    currentKeyVersion: '2024',
+  });
+  const current = new EnvelopeCryptoHelper({
+    keys: { '2024': KEY_2024, '2025': KEY_2025 },
+    currentKeyVersion: '2025',
+  });
+
+  test('TC-001: round trips values bound to their AAD', () => {
+    const ciphertext = current.encrypt({ plaintext: 'jane@example.com', aad: 'users:42:email' });
+
+    expect(EnvelopeCryptoHelper.isEnvelope(ciphertext)).toBe(true);
+    expect(EnvelopeCryptoHelper.parse(ciphertext)?.keyVersion).toBe('2025');
+    expect(current.decrypt({ ciphertext, aad: 'users:42:email' })).toBe('jane@example.com');
+    expect(current.encrypt({ plaintext: 'same' })).not.toBe(current.encrypt({ plaintext: 'same' }));
+
+    expectErrorCode(
+      () => current.decrypt({ ciphertext, aad: 'users:43:email' }),
+      EnvelopeCryptoErrorCodes.DECRYPTION_FAILED,
+    );
+  });
+
+  test('TC-002: decrypts older key versions and reencrypts them', () => {
+    const ciphertext = previous.encrypt({ plaintext: 'whsec_123' });
+
+    expect(current.decrypt({ ciphertext })).toBe('whsec_123');
+    expect(current.needsReencrypt({ ciphertext })).toBe(true);
+
+    const rotated = current.reencrypt({ ciphertext });
+    expect(EnvelopeCryptoHelper.parse(rotated)?.keyVersion).toBe('2025');
+    expect(current.needsReencrypt({ ciphertext: rotated })).toBe(false);
+    expect(current.decrypt({ ciphertext: rotated })).toBe('whsec_123');
+
+    expectErrorCode(
+      () => previous.decrypt({ ciphertext: rotated }),
+      EnvelopeCryptoErrorCodes.UNKNOWN_KEY_VERSION,
+    );
+  });
+
+  test('TC-003: rejects tampered and malformed envelopes', () => {
+    const [format, keyVersion, wrappedKey, payload] = current
+      .encrypt({ plaintext: 'secret' })
+      .split('.');
+
+    const bytes = Buffer.from(payload, 'base64url');
+    bytes[bytes.length - 1] ^= 0xff;
+    const tampered = [format, keyVersion, wrappedKey, bytes.toString('base64url')].join('.');
+
+    expectErrorCode(
+      () => current.decrypt({ ciphertext: tampered }),
+      EnvelopeCryptoErrorCodes.DECRYPTION_FAILED,
+    );
+    expectErrorCode(
+      () => current.decrypt({ ciphertext: 'plain text' }),
+      EnvelopeCryptoErrorCodes.MALFORMED,
+    );
+    expectErrorCode(
+      () => new EnvelopeCryptoHelper({ keys: { v1: 'short' }, currentKeyVersion: 'v1' }),
+      EnvelopeCryptoErrorCodes.INVALID_KEY,
+    );
+  });
+});
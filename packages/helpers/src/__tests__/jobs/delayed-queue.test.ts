@@ -0,0 +1,287 @@
+/**
+ * Delayed Job Queue Test Suite
+ *
+ * Tests DelayedJobQueueHelper with an in-memory Redis client:
+ * 1. Failed jobs are rescheduled with their attempt counted
+ * 2. Jobs exhausting their attempts are parked as dead
+ * 3. Running jobs keep their claim, the jobs of a crashed worker are reclaimed with the attempt
+ *    it spent
+ * 4. Stopping waits for the jobs claimed by a running poll
+ *
+ * @module __tests__/jobs/delayed-queue
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { DelayedJobQueueHelper, IDelayedJob } from '@/helpers/jobs';
+import { DefaultRedisHelper } from '@/helpers/redis';
+
+// Hashes, sorted sets and the claim / recover scripts of the queue
+class MockRedisClient {
+  hashes = new Map<string, Map<string, string>>();
+  sets = new Map<string, Map<string, number>>();
+
+  private getHash(key: string) {
+    if (!this.hashes.has(key)) {
+      this.hashes.set(key, new Map());
+    }
+    return this.hashes.get(key)!;
+  }
+
+  private getSet(key: string) {
+    if (!this.sets.has(key)) {
+      this.sets.set(key, new Map());
+    }
+    return this.sets.get(key)!;
+  }
+
+  private getRange(key: string, max: number) {
+    return [...this.getSet(key).entries()]
+      .filter(([, score]) => score <= max)
+      .sort((a, b) => a[1] - b[1])
+      .map(([id]) => id);
+  }
+
+  async hset(key: string, field: string, value: string) {
+    this.getHash(key).set(field, value);
+    return 1;
+  }
+
+  async hget(key: string, field: string) {
+    return this.getHash(key).get(field) ?? null;
+  }
+
+  async hdel(key: string, field: string) {
+    return this.getHash(key).delete(field) ? 1 : 0;
+  }
+
+  async hmget(key: string, ...fields: Array<string>) {
+    return fields.map(field => this.getHash(key).get(field) ?? null);
+  }
+
+  async zadd(key: string, ...args: Array<string | number>) {
+    // `XX` only updates existing members
+    const isUpdate = args[0] === 'XX';
+    const [score, member] = isUpdate ? args.slice(1) : args;
+    if (isUpdate && !this.getSet(key).has(`${member}`)) {
+      return 0;
+    }
+
+    this.getSet(key).set(`${member}`, Number(score));
+    return 1;
+  }
+
+  async zrem(key: string, member: string) {
+    return this.getSet(key).delete(member) ? 1 : 0;
+  }
+
+  async zrange(key: string, start: number, stop: number) {
+    return this.getRange(key, Infinity).slice(start, stop + 1);
+  }
+
+  async zcard(key: string) {
+    return this.getSet(key).size;
+  }
+
+  multi() {
+    const commands: Array<() => Promise<unknown>> = [];
+    const chain = new Proxy({} as Record<string, any>, {
+      get: (_target, name: string) => {
+        if (name === 'exec') {
+          return async () => {
+            const rs = [];
+            for (const command of commands) {
+              rs.push([null, await command()]);
+            }
+            return rs;
+          };
+        }
+
+        return (...args: Array<any>) => {
+          commands.push(() => (this as any)[name](...args));
+          return chain;
+        };
+      },
+    });
+    return chain;
+  }
+
+  async eval(script: string, _keys: number, scheduled: string, processing: string, ...argv: any[]) {
+    const [now, limit, deadline] = argv.map(Number);
+
+    // Recover script
+    if (script.includes('return #ids')) {
+      const ids = this.getRange(processing, now);
+      for (const id of ids) {
+        this.getSet(processing).delete(id);
+        this.getSet(scheduled).set(id, now);
+      }
+      return ids.length;
+    }
+
+    const ids = this.getRange(scheduled, now).slice(0, limit);
+    for (const id of ids) {
+      this.getSet(scheduled).delete(id);
+      this.getSet(processing).set(id, deadline);
+    }
+    return ids;
+  }
+}
+
+const wait = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+const settle = () => wait(10);
+
+// Connection of a worker process to the shared client, every command fails once it crashed
+const connect = (client: MockRedisClient) => {
+  let isCrashed = false;
+  const connection = new Proxy(client, {
+    get: (target, name) => {
+      const value = Reflect.get(target, name);
+      if (typeof value !== 'function') {
+        return value;
+      }
+
+      return (...args: Array<any>) =>
+        isCrashed ? Promise.reject(new Error('Connection is closed')) : value.apply(target, args);
+    },
+  });
+
+  return {
+    connection,
+    crash: () => {
+      isCrashed = true;
+    },
+  };
+};
+
+const createQueue = (opts: {
+  handler: (job: IDelayedJob) => Promise<void>;
+  maxAttempts: number;
+  visibilityTimeout?: number;
+  onDead?: (opts: { job: IDelayedJob }) => void;
+  client?: MockRedisClient;
+}) => {
+  const { client = new MockRedisClient(), ...rest } = opts;
+  const queue = new DelayedJobQueueHelper({
+    identifier: 'reminders',
+    redis: { getClient: () => client } as unknown as DefaultRedisHelper,
+    retryDelay: () => 0,
+    ...rest,
+  });
+
+  return { client, queue };
+};
+
+describe('DelayedJobQueueHelper', () => {
+  test('TC-001: reschedules failed jobs with their attempt counted', async () => {
+    let runs = 0;
+    const { queue } = createQueue({
+      maxAttempts: 3,
+      handler: async () => {
+        if (++runs === 1) {
+          throw new Error('Mail provider unavailable');
+        }
+      },
+    });
+
+    const job = await queue.enqueueIn({ delay: 0, name: 'remind', payload: { userId: 1 } });
+    expect(await queue.poll()).toBe(1);
+    await settle();
+
+    expect(await queue.getJob({ id: job.id })).toMatchObject({
+      attempts: 1,
+      lastError: 'Mail provider unavailable',
+    });
+    expect(await queue.getStats()).toMatchObject({ scheduled: 1, processing: 0 });
+
+    expect(await queue.poll()).toBe(1);
+    await settle();
+    expect(runs).toBe(2);
+    expect(await queue.getJob({ id: job.id })).toBeNull();
+  });
+
+  test('TC-002: parks jobs which exhausted their attempts', async () => {
+    const dead: Array<IDelayedJob> = [];
+    const { queue } = createQueue({
+      maxAttempts: 2,
+      handler: async () => {
+        throw new Error('Invalid recipient');
+      },
+      onDead: ({ job }) => void dead.push(job),
+    });
+
+    const job = await queue.enqueueIn({ delay: 0, name: 'remind', payload: { userId: 2 } });
+    for (let i = 0; i < 3; i++) {
+      await queue.poll();
+      await settle();
+    }
+
+    expect(await queue.getStats()).toMatchObject({ scheduled: 0, processing: 0, dead: 1 });
+    expect(dead.map(({ id, attempts }) => [id, attempts])).toEqual([[job.id, 2]]);
+    expect((await queue.getDeadJobs())[0].failedAt).toBeDefined();
+  });
+
+  test('TC-003: reclaims the jobs of crashed workers with the attempt they spent', async () => {
+    const client = new MockRedisClient();
+    let runs = 0;
+    const dead: Array<IDelayedJob> = [];
+
+    // Runs its handler until the process crashes, which stops the heartbeat too
+    const createWorker = () => {
+      const { connection, crash } = connect(client);
+      const { queue } = createQueue({
+        client: connection,
+        maxAttempts: 2,
+        visibilityTimeout: 20,
+        handler: () => {
+          runs++;
+          return new Promise<void>(() => {});
+        },
+        onDead: ({ job }) => void dead.push(job),
+      });
+
+      return { queue, crash };
+    };
+
+    const [first, second, third] = [createWorker(), createWorker(), createWorker()];
+    const job = await first.queue.enqueueIn({ delay: 0, name: 'remind', payload: { userId: 3 } });
+    await first.queue.poll();
+
+    // Running past the visibility timeout does not expire the claim
+    await wait(50);
+    expect(await second.queue.poll()).toBe(0);
+    expect(await first.queue.getJob({ id: job.id })).toMatchObject({ attempts: 1 });
+
+    first.crash();
+    await wait(30);
+    expect(await second.queue.poll()).toBe(1);
+    await settle();
+    expect(await second.queue.getJob({ id: job.id })).toMatchObject({ attempts: 2 });
+
+    second.crash();
+    await wait(30);
+    await third.queue.poll();
+    await settle();
+    expect(runs).toBe(2);
+    expect(dead).toHaveLength(1);
+    expect(dead[0]).toMatchObject({ attempts: 2, lastError: 'Visibility timeout exceeded' });
+  });
+
+  test('TC-004: waits for the jobs of a running poll when stopping', async () => {
+    const handled: Array<string> = [];
+    const { queue } = createQueue({
+      maxAttempts: 1,
+      handler: async job => {
+        await settle();
+        handled.push(job.id);
+      },
+    });
+
+    const job = await queue.enqueueIn({ delay: 0, name: 'remind', payload: { userId: 4 } });
+    const polled = queue.poll();
+    await queue.stop();
+
+    expect(handled).toEqual([job.id]);
+    expect(await polled).toBe(1);
+    expect(await queue.getStats()).toMatchObject({ scheduled: 0, processing: 0, inflight: 0 });
+  });
+});
@@ -0,0 +1,120 @@
+/**
+ * Job Scheduler Test Suite
+ *
+ * Tests JobSchedulerHelper with a process-local lock:
+ * 1. A timed out run is aborted and keeps blocking the next runs until its handler settles
+ * 2. The lock of a timed out run is extended while its handler runs, released once it settles
+ * 3. The lock of a failed run is released
+ *
+ * @module __tests__/jobs/scheduler
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { JobRunStatuses, JobSchedulerHelper } from '@/helpers/jobs';
+import { ILockHandle, MemoryLockHelper } from '@/helpers/lock';
+
+// Records the calls of the scheduler
+class RecordingLockHelper extends MemoryLockHelper {
+  calls: Array<string> = [];
+
+  override acquire(opts: { key: string; ttl: number }) {
+    this.calls.push('acquire');
+    return super.acquire(opts);
+  }
+
+  override extend(opts: { handle: ILockHandle; ttl: number }) {
+    this.calls.push('extend');
+    return super.extend(opts);
+  }
+
+  override release(opts: { handle: ILockHandle }) {
+    this.calls.push('release');
+    return super.release(opts);
+  }
+}
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+// Handler ignoring its signal, settled by `finish`
+const createHandler = () => {
+  let finish = () => {};
+  let signal: AbortSignal | undefined;
+  const handler = (context: { signal: AbortSignal }) => {
+    signal = context.signal;
+    return new Promise<void>(resolve => {
+      finish = resolve;
+    });
+  };
+
+  return { handler, finish: () => finish(), getSignal: () => signal };
+};
+
+describe('JobSchedulerHelper', () => {
+  test('TC-001: skips runs while a timed out run is still in progress', async () => {
+    const { handler, finish, getSignal } = createHandler();
+    const scheduler = new JobSchedulerHelper({ identifier: 'billing' });
+    scheduler.register({ name: 'close-invoices', cronTime: '0 0 0 1 1 *', timeout: 20, handler });
+
+    const result = await scheduler.trigger({ name: 'close-invoices' });
+    expect(result.status).toBe(JobRunStatuses.TIMED_OUT);
+    expect(getSignal()?.aborted).toBe(true);
+    expect(scheduler.getJobStatus({ name: 'close-invoices' })?.isRunning).toBe(true);
+
+    const overlap = await scheduler.trigger({ name: 'close-invoices' });
+    expect(overlap).toMatchObject({
+      status: JobRunStatuses.SKIPPED,
+      error: 'Previous run is still in progress',
+    });
+
+    finish();
+    await sleep(5);
+    expect(scheduler.getJobStatus({ name: 'close-invoices' })?.isRunning).toBe(false);
+  });
+
+  test('TC-002: holds the lock of a timed out run until its handler settles', async () => {
+    const { handler, finish } = createHandler();
+    const lock = new RecordingLockHelper();
+    const scheduler = new JobSchedulerHelper({ identifier: 'billing' });
+    scheduler.register({
+      name: 'close-invoices',
+      cronTime: '0 0 0 1 1 *',
+      timeout: 20,
+      lock: { helper: lock },
+      handler,
+    });
+
+    const result = await scheduler.trigger({ name: 'close-invoices' });
+    expect(result.status).toBe(JobRunStatuses.TIMED_OUT);
+
+    // Past the ttl, which defaults to the timeout
+    await sleep(50);
+    expect(lock.calls).toContain('extend');
+    expect(lock.calls).not.toContain('release');
+    expect(await lock.acquire({ key: 'job:close-invoices', ttl: 1_000 })).toBeNull();
+
+    finish();
+    await sleep(5);
+    expect(lock.calls.filter(call => call === 'release')).toHaveLength(1);
+    expect(await lock.acquire({ key: 'job:close-invoices', ttl: 1_000 })).not.toBeNull();
+  });
+
+  test('TC-003: releases the lock of a failed run', async () => {
+    const lock = new RecordingLockHelper();
+    const scheduler = new JobSchedulerHelper({ identifier: 'billing' });
+    scheduler.register({
+      name: 'close-invoices',
+      cronTime: '0 0 0 1 1 *',
+      lock: { helper: lock, ttl: 1_000 },
+      handler: () => {
+        throw new Error('Ledger unavailable');
+      },
+    });
+
+    const result = await scheduler.trigger({ name: 'close-invoices' });
+    expect(result.status).toBe(JobRunStatuses.FAILED);
+
+    await sleep(5);
+    expect(lock.calls).toEqual(['acquire', 'release']);
+    expect(scheduler.getJobStatus({ name: 'close-invoices' })?.isRunning).toBe(false);
+  });
+});
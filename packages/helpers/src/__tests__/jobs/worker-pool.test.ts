@@ -0,0 +1,130 @@
+/**
+ * Worker Pool Test Suite
+ *
+ * Tests BoundedAsyncQueue and JobWorkerPoolHelper:
+ * 1. Falsy elements are popped as values, the end of a closed queue as `done`
+ * 2. Pushes wait while the queue is full
+ * 3. Workers run falsy jobs and stop once the pool is shut down
+ * 4. A pool which was never started returns its queued jobs on shutdown
+ * 5. The shutdown hook drains the pool and hands over the jobs dropped by its timeout
+ *
+ * @module __tests__/jobs/worker-pool
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { BoundedAsyncQueue, JobWorkerPoolHelper } from '@/helpers/jobs';
+import { ShutdownOrchestrator, ShutdownPhases } from '@/helpers/lifecycle';
+
+describe('BoundedAsyncQueue', () => {
+  test('TC-001: pops falsy elements as values and the end of a closed queue as done', async () => {
+    const queue = new BoundedAsyncQueue<number | null | undefined>({ capacity: 3 });
+    const waiting = queue.pop();
+    queue.tryPush(undefined);
+    queue.tryPush(null);
+    queue.tryPush(0);
+
+    expect(await waiting).toEqual({ done: false, value: undefined });
+    queue.close();
+    expect(await queue.pop()).toEqual({ done: false, value: null });
+    expect(await queue.pop()).toEqual({ done: false, value: 0 });
+    expect(await queue.pop()).toEqual({ done: true, value: undefined });
+
+    const closed = new BoundedAsyncQueue<number>({ capacity: 1 });
+    const pending = closed.pop();
+    closed.close();
+    expect(await pending).toEqual({ done: true, value: undefined });
+  });
+
+  test('TC-002: waits to push while the queue is full', async () => {
+    const queue = new BoundedAsyncQueue<string>({ capacity: 1 });
+    expect(await queue.push('sku-1')).toBe(true);
+    expect(queue.tryPush('sku-2')).toBe(false);
+
+    let isPushed = false;
+    const pushing = queue.push('sku-2').then(rs => (isPushed = rs));
+    await Promise.resolve();
+    expect(isPushed).toBe(false);
+
+    expect(await queue.pop()).toEqual({ done: false, value: 'sku-1' });
+    await pushing;
+    expect(isPushed).toBe(true);
+    expect(queue.size).toBe(1);
+
+    queue.close();
+    expect(await queue.push('sku-3')).toBe(false);
+  });
+});
+
+describe('JobWorkerPoolHelper', () => {
+  test('TC-003: runs falsy jobs and stops once shut down', async () => {
+    const processed: Array<number | null | undefined> = [];
+    const pool = new JobWorkerPoolHelper<number | null | undefined>({
+      identifier: 'product-import',
+      concurrency: 1,
+      handler: job => void processed.push(job),
+    });
+    pool.start();
+
+    for (const job of [0, undefined, null, 7]) {
+      await pool.submit(job);
+    }
+
+    expect(await pool.shutdown()).toEqual([]);
+    expect(processed).toEqual([0, undefined, null, 7]);
+    expect(pool.getStats()).toMatchObject({ processed: 4, failed: 0, isAccepting: false });
+  });
+
+  test('TC-004: returns the queued jobs of a pool which was never started', async () => {
+    const processed: Array<string> = [];
+    const pool = new JobWorkerPoolHelper<string>({
+      identifier: 'product-import',
+      handler: job => void processed.push(job),
+    });
+
+    await pool.submit('sku-1');
+    await pool.submit('sku-2');
+
+    expect(await pool.shutdown()).toEqual(['sku-1', 'sku-2']);
+    expect(processed).toEqual([]);
+    expect(pool.getStats()).toMatchObject({ queued: 0, isAccepting: false });
+    await expect(pool.submit('sku-3')).rejects.toMatchObject({ statusCode: 503 });
+  });
+
+  test('TC-005: drains the pool from the orchestrator shutdown hook', async () => {
+    const aborted: Array<string> = [];
+    const pool = new JobWorkerPoolHelper<string>({
+      identifier: 'product-import',
+      concurrency: 1,
+      handler: (job, { signal }) =>
+        new Promise<void>(resolve => {
+          signal.addEventListener('abort', () => {
+            aborted.push(job);
+            resolve();
+          });
+        }),
+    });
+    pool.start();
+
+    const dropped: Array<string> = [];
+    const hook = pool.getShutdownHook({
+      timeout: 20,
+      onDropped: ({ jobs }) => void dropped.push(...jobs),
+    });
+    expect(hook).toMatchObject({
+      identifier: 'JobWorkerPoolHelper_product-import',
+      phase: ShutdownPhases.CONSUMERS,
+    });
+
+    await pool.submit('sku-1');
+    await pool.submit('sku-2');
+    await pool.submit('sku-3');
+
+    const orchestrator = new ShutdownOrchestrator();
+    orchestrator.register(hook);
+    const report = await orchestrator.execute();
+
+    expect(report).toMatchObject({ isCompleted: true, failed: [], pending: [] });
+    expect(aborted).toEqual(['sku-1']);
+    expect(dropped).toEqual(['sku-2', 'sku-3']);
+  });
+});
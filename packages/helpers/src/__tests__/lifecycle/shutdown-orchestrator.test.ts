@@ -0,0 +1,66 @@
+/**
+ * Shutdown Orchestrator Test Suite
+ *
+ * Tests ShutdownOrchestrator:
+ * 1. Phases run in order, failing hooks do not block the next phase
+ * 2. Hook timeouts and the hard deadline abandon hanging hooks
+ *
+ * @module __tests__/lifecycle/shutdown-orchestrator
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { ShutdownOrchestrator, ShutdownPhases } from '@/helpers/lifecycle';
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe('ShutdownOrchestrator', () => {
+  test('TC-001: runs phases in order and keeps going after failures', async () => {
+    const orchestrator = new ShutdownOrchestrator();
+    const executed: Array<string> = [];
+
+    orchestrator.register({ identifier: 'redis', hook: () => void executed.push('redis') });
+    orchestrator.register({
+      identifier: 'outbox',
+      phase: ShutdownPhases.FLUSH,
+      hook: () => {
+        executed.push('outbox');
+        throw new Error('Broker unavailable');
+      },
+    });
+    orchestrator.register({
+      identifier: 'consumer',
+      phase: ShutdownPhases.CONSUMERS,
+      hook: async () => {
+        await sleep(10);
+        executed.push('consumer');
+      },
+    });
+
+    const report = await orchestrator.execute();
+
+    expect(executed).toEqual(['consumer', 'outbox', 'redis']);
+    expect(report.isCompleted).toBe(true);
+    expect(report.failed).toEqual(['outbox']);
+    expect(report.pending).toEqual([]);
+    expect(orchestrator.isShuttingDown()).toBe(true);
+    expect(await orchestrator.execute()).toBe(report);
+  });
+
+  test('TC-002: abandons timed out hooks and stops at the deadline', async () => {
+    const timedOut = new ShutdownOrchestrator();
+    timedOut.register({ identifier: 'slow', timeout: 10, hook: () => sleep(200) });
+    timedOut.register({ identifier: 'redis', hook: () => {} });
+
+    const report = await timedOut.execute();
+    expect(report.isCompleted).toBe(true);
+    expect(report.failed).toEqual(['slow']);
+
+    const hanging = new ShutdownOrchestrator();
+    hanging.register({ identifier: 'hanging', phase: ShutdownPhases.HTTP, hook: () => sleep(200) });
+    hanging.register({ identifier: 'redis', hook: () => {} });
+
+    const deadline = await hanging.execute({ deadline: 20 });
+    expect(deadline.isCompleted).toBe(false);
+    expect(deadline.pending).toEqual(['hanging', 'redis']);
+  });
+});
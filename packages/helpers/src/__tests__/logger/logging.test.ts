@@ -0,0 +1,106 @@
+/**
+ * Logging Initialization Test Suite
+ *
+ * Tests the shared logging setup:
+ * 1. parseScopeLevels - `scope=level` env parsing
+ * 2. defineLevelFilter - Global and per scope levels
+ * 3. defineServiceFields - Service and request fields
+ * 4. Logger.setDefaultLogger - Cached loggers follow the replaced logger
+ *
+ * @module __tests__/logger/logging
+ */
+
+import { afterEach, describe, expect, test } from 'bun:test';
+import { Writable } from 'node:stream';
+import winston from 'winston';
+import {
+  applicationLogger,
+  defineLevelFilter,
+  defineServiceFields,
+  Logger,
+  parseScopeLevels,
+} from '@/helpers/logger';
+import { RequestContextStorage } from '@/helpers/request-context';
+
+describe('Logging', () => {
+  describe('parseScopeLevels', () => {
+    test('TC-001: should parse pairs and ignore invalid levels', () => {
+      const rs = parseScopeLevels(' UserService=debug, RedisHelper = warn,Broken=loud,,=info');
+      expect(rs).toEqual({ UserService: 'debug', RedisHelper: 'warn' });
+    });
+  });
+
+  describe('defineLevelFilter', () => {
+    const filter = defineLevelFilter({
+      level: 'info',
+      scopeLevels: { UserService: 'debug', 'UserService-sync': 'error' },
+    });
+
+    const isKept = (level: string, message: string) => {
+      return !!filter.transform({ level, message });
+    };
+
+    test('TC-002: should apply the global level to unconfigured scopes', () => {
+      expect(isKept('info', '[OrderService] created')).toBe(true);
+      expect(isKept('debug', '[OrderService] created')).toBe(false);
+      expect(isKept('debug', 'no scope')).toBe(false);
+    });
+
+    test('TC-003: should apply the most specific scope level', () => {
+      expect(isKept('debug', '[UserService-create] created')).toBe(true);
+      expect(isKept('warn', '[UserService-sync] slow')).toBe(false);
+      expect(isKept('debug', '[UserServiceX] created')).toBe(false);
+    });
+  });
+
+  describe('defineServiceFields', () => {
+    const format = defineServiceFields({ name: 'order-service', version: '1.4.0' });
+
+    test('TC-004: should add service fields and the request id', () => {
+      const outside = format.transform({
+        level: 'info',
+        message: 'boot',
+      }) as winston.Logform.TransformableInfo;
+      expect(outside.service).toBe('order-service');
+      expect(outside.version).toBe('1.4.0');
+      expect(outside.requestId).toBeUndefined();
+
+      const inside = RequestContextStorage.run({
+        context: { requestId: 'req-1', tenantId: 'acme' },
+        task: () => format.transform({ level: 'info', message: 'handled' }),
+      }) as winston.Logform.TransformableInfo;
+      expect(inside.requestId).toBe('req-1');
+      expect(inside.tenantId).toBe('acme');
+    });
+  });
+
+  describe('Logger.setDefaultLogger', () => {
+    afterEach(() => {
+      Logger.setDefaultLogger(applicationLogger);
+    });
+
+    test('TC-005: should route cached loggers to the replaced logger', async () => {
+      const logger = Logger.get('LoggingTest');
+
+      const lines: Array<string> = [];
+      const stream = new Writable({
+        write: (chunk, _encoding, callback) => {
+          lines.push(chunk.toString());
+          callback();
+        },
+      });
+
+      Logger.setDefaultLogger(
+        winston.createLogger({
+          format: winston.format.json(),
+          transports: [new winston.transports.Stream({ stream })],
+        }),
+      );
+      logger.info('hello');
+      await new Promise(resolve => setImmediate(resolve));
+
+      expect(lines).toHaveLength(1);
+      expect(JSON.parse(lines[0]).message).toBe('[LoggingTest] hello');
+    });
+  });
+});
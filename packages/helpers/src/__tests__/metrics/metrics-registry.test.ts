@@ -0,0 +1,82 @@
+/**
+ * Metrics Registry Test Suite
+ *
+ * Tests the Prometheus metrics registry:
+ * 1. Counter, Gauge and Histogram exposition
+ * 2. Get or create by name and validation
+ * 3. Collectors and process metrics
+ *
+ * @module __tests__/metrics/metrics-registry
+ */
+
+import { describe, expect, test } from 'bun:test';
+import { MetricsRegistry, registerProcessMetrics } from '@/helpers/metrics';
+
+describe('MetricsRegistry', () => {
+  test('TC-001: should serialize counters and gauges with escaped labels', async () => {
+    const registry = new MetricsRegistry();
+    const requests = registry.counter({
+      name: 'http_requests_total',
+      help: 'HTTP requests',
+      labelNames: ['method', 'path'],
+    });
+    requests.inc({ labels: { method: 'GET', path: '/a"b' } });
+    requests.inc({ labels: { method: 'GET', path: '/a"b' }, value: 2 });
+    registry.gauge({ name: 'queue_depth', help: 'Jobs waiting' }).set({ value: 7 });
+
+    expect(await registry.metrics()).toBe(
+      [
+        '# HELP http_requests_total HTTP requests',
+        '# TYPE http_requests_total counter',
+        'http_requests_total{method="GET",path="/a\\"b"} 3',
+        '',
+        '# HELP queue_depth Jobs waiting',
+        '# TYPE queue_depth gauge',
+        'queue_depth 7',
+        '',
+      ].join('\n'),
+    );
+  });
+
+  test('TC-002: should expose cumulative histogram buckets', async () => {
+    const registry = new MetricsRegistry();
+    const latency = registry.histogram({
+      name: 'latency_seconds',
+      help: 'Latency',
+      buckets: [1, 0.25],
+    });
+    latency.observe({ value: 0.25 });
+    latency.observe({ value: 0.5 });
+    latency.observe({ value: 3 });
+
+    const body = await registry.metrics();
+    expect(body).toContain('latency_seconds_bucket{le="0.25"} 1');
+    expect(body).toContain('latency_seconds_bucket{le="1"} 2');
+    expect(body).toContain('latency_seconds_bucket{le="+Inf"} 3');
+    expect(body).toContain('latency_seconds_sum 3.75');
+    expect(body).toContain('latency_seconds_count 3');
+  });
+
+  test('TC-003: should return the same metric by name and reject type or label mismatches', () => {
+    const registry = new MetricsRegistry();
+    const counter = registry.counter({ name: 'jobs_total', help: 'Jobs', labelNames: ['queue'] });
+
+    expect(registry.counter({ name: 'jobs_total', help: 'Jobs' })).toBe(counter);
+    expect(() => registry.gauge({ name: 'jobs_total', help: 'Jobs' })).toThrow(/another type/);
+    expect(() => counter.inc({ labels: { status: 'ok' } })).toThrow(/Unknown label/);
+    expect(() => counter.inc({ value: -1 })).toThrow(/only increase/);
+    expect(() => registry.counter({ name: 'bad-name', help: 'Bad' })).toThrow(/metric name/);
+  });
+
+  test('TC-004: should refresh process metrics through collectors', async () => {
+    const registry = new MetricsRegistry();
+    registerProcessMetrics({ registry, prefix: 'app_' });
+    registerProcessMetrics({ registry, prefix: 'app_' });
+
+    const body = await registry.metrics();
+    expect(body).toContain('# TYPE app_process_cpu_seconds_total counter');
+    expect(body).toMatch(/app_process_resident_memory_bytes \d+/);
+    expect(body).toMatch(/app_nodejs_heap_size_bytes\{type="used"\} \d+/);
+    expect(body.match(/# TYPE app_process_uptime_seconds/g)).toHaveLength(1);
+  });
+});
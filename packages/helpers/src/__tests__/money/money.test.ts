@@ -0,0 +1,66 @@
+/**
+ * Money Test Suite
+ *
+ * Tests Money:
+ * 1. Exact arithmetic and rounding modes
+ * 2. Allocation without losing minor units
+ * 3. Currency validation and API serialization
+ *
+ * @module __tests__/money/money
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Money, RoundingModes } from '@/helpers/money';
+
+const usd = (amount: string | number) => Money.of({ amount, currency: 'USD' });
+
+describe('Money', () => {
+  test('TC-001: computes exactly and rounds with the given mode', () => {
+    expect(usd('0.1').add(usd('0.2')).amount).toBe('0.30');
+    expect(usd('19.99').multiply({ factor: 3 }).subtract(usd(5)).amount).toBe('54.97');
+
+    expect(() => usd('0.125')).toThrow();
+
+    const halfUp = Money.of({ amount: '0.125', currency: 'USD', rounding: RoundingModes.HALF_UP });
+    const halfEven = Money.of({
+      amount: '0.125',
+      currency: 'USD',
+      rounding: RoundingModes.HALF_EVEN,
+    });
+    expect(halfUp.amount).toBe('0.13');
+    expect(halfEven.amount).toBe('0.12');
+
+    const floored = usd('-1.00').multiply({ factor: '0.335', rounding: RoundingModes.FLOOR });
+    expect(floored.amount).toBe('-0.34');
+
+    const yen = Money.of({ amount: 1500, currency: 'JPY' });
+    expect(yen.multiply({ factor: '0.1' }).amount).toBe('150');
+  });
+
+  test('TC-002: allocates without losing minor units', () => {
+    expect(usd(100).split({ parts: 3 }).map(part => part.amount)).toEqual([
+      '33.34',
+      '33.33',
+      '33.33',
+    ]);
+    expect(usd('0.05').allocate({ ratios: [70, 30] }).map(part => part.amount)).toEqual([
+      '0.04',
+      '0.01',
+    ]);
+    expect(usd('-0.05').allocate({ ratios: [1, 0, 1] }).map(part => part.amount)).toEqual([
+      '-0.03',
+      '0.00',
+      '-0.02',
+    ]);
+  });
+
+  test('TC-003: validates currencies and serializes amounts as strings', () => {
+    expect(() => Money.of({ amount: 1, currency: 'XYZ' })).toThrow();
+    expect(() => usd(1).add(Money.of({ amount: 1, currency: 'EUR' }))).toThrow();
+
+    const money = Money.of({ amount: '12.3', currency: 'KWD' });
+    expect(JSON.stringify({ price: money })).toBe('{"price":{"amount":"12.300","currency":"KWD"}}');
+    expect(Money.fromJSON(JSON.parse(JSON.stringify(money))).equals(money)).toBe(true);
+    expect(() => Money.fromJSON({ amount: 12.3, currency: 'KWD' })).toThrow();
+  });
+});
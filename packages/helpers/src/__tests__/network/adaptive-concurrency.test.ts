@@ -0,0 +1,73 @@
+/**
+ * Adaptive Concurrency Test Suite
+ *
+ * Tests AdaptiveConcurrencyLimiter:
+ * 1. AIMD grows the limit while it is used and backs off on dropped requests
+ * 2. Vegas shrinks the limit once the latency rises above the lowest one seen
+ * 3. Fetchers release the requests rejected by an overloaded upstream as dropped
+ *
+ * @module __tests__/network/adaptive-concurrency
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import {
+  AdaptiveConcurrencyLimiter,
+  AimdLimitAlgorithm,
+  NodeFetchNetworkRequest,
+  VegasLimitAlgorithm,
+} from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('AdaptiveConcurrencyLimiter', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ path: '/busy' }).respond({ status: 503 });
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: grows the AIMD limit while used and backs off on drops', () => {
+    const aimd = new AimdLimitAlgorithm({ initialLimit: 10, backoffRatio: 0.5 });
+
+    expect(aimd.update({ rtt: 10, inflight: 10, isDropped: false })).toBe(11);
+    // Mostly idle, says nothing about the capacity of the upstream
+    expect(aimd.update({ rtt: 10, inflight: 1, isDropped: false })).toBe(11);
+    expect(aimd.update({ rtt: 10, inflight: 11, isDropped: true })).toBe(5);
+    expect(aimd.update({ rtt: 10_000, inflight: 5, isDropped: false })).toBe(2);
+  });
+
+  test('TC-002: shrinks the Vegas limit once the latency rises', () => {
+    const vegas = new VegasLimitAlgorithm({ initialLimit: 20 });
+
+    vegas.update({ rtt: 10, inflight: 20, isDropped: false });
+    const grown = vegas.update({ rtt: 10, inflight: 20, isDropped: false });
+    expect(grown).toBeGreaterThan(20);
+
+    let limit = grown;
+    for (let i = 0; i < 5; i++) {
+      limit = vegas.update({ rtt: 50, inflight: limit, isDropped: false });
+    }
+    expect(limit).toBeLessThan(grown);
+  });
+
+  test('TC-003: releases overloaded responses as dropped', async () => {
+    const limiter = new AdaptiveConcurrencyLimiter({
+      algorithm: new AimdLimitAlgorithm({ initialLimit: 4, backoffRatio: 0.5 }),
+    });
+    const request = new NodeFetchNetworkRequest({
+      name: 'BusyRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      concurrency: limiter,
+    });
+
+    const url = request.getRequestUrl({ paths: ['busy'] });
+    const rs = await request.getNetworkService().get({ url });
+    expect(rs.status).toBe(503);
+    expect(limiter.getLimit()).toBe(2);
+    expect(limiter.getActive()).toBe(0);
+  });
+});
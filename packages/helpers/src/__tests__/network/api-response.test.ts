@@ -0,0 +1,193 @@
+/**
+ * API Response Envelope Test Suite
+ *
+ * Tests ApiResponses:
+ * 1. Envelopes carry the request id and pagination meta
+ * 2. Clients unwrap enveloped and plain payloads alike
+ * 3. Pagination cursors round trip and reject tampering
+ * 4. Failed responses become errors parsed from the envelope or problem details
+ * 5. Error decoders of a network request map the error body type of the upstream
+ * 6. Error mappers of a network request translate upstream failures into domain errors
+ *
+ * @module __tests__/network/api-response
+ */
+
+import { describe, test, expect } from 'bun:test';
+import {
+  ApiResponses,
+  ErrorMappers,
+  HttpResponseError,
+  NodeFetchNetworkRequest,
+  PaginationCursors,
+  paginatedResponseSchema,
+} from '@/helpers/network';
+import { RequestContextStorage } from '@/helpers/request-context';
+import { z } from '@hono/zod-openapi';
+
+describe('ApiResponses', () => {
+  test('TC-001: builds envelopes with request id and pagination', () => {
+    const rs = RequestContextStorage.run({
+      context: { requestId: 'req-1' },
+      task: () => {
+        return ApiResponses.paginated({
+          data: [{ id: 21 }, { id: 22 }],
+          total: 30,
+          limit: 2,
+          offset: 20,
+        });
+      },
+    });
+
+    expect(rs.meta.requestId).toBe('req-1');
+    expect(rs.meta.pagination).toEqual({
+      total: 30,
+      limit: 2,
+      offset: 20,
+      start: 20,
+      end: 21,
+      hasMore: true,
+    });
+    expect(paginatedResponseSchema({ schema: z.object({ id: z.number() }) }).parse(rs)).toEqual(rs);
+  });
+
+  test('TC-002: unwraps enveloped and plain payloads', () => {
+    expect(ApiResponses.unwrap(ApiResponses.ok({ data: { id: 1 } }))).toEqual({ id: 1 });
+    expect(ApiResponses.unwrap({ id: 1, data: 'raw' })).toEqual({ id: 1, data: 'raw' });
+    expect(ApiResponses.unwrap([1, 2])).toEqual([1, 2]);
+  });
+
+  test('TC-003: round trips cursors and rejects tampered ones', () => {
+    const cursors = new PaginationCursors({ secret: 'cursor-secret' });
+    const nextCursor = cursors.encode({ payload: { id: 'ord_22', offset: 20 } });
+
+    const rs = ApiResponses.paginated({ data: [], total: 30, limit: 2, offset: 20, nextCursor });
+    expect(rs.meta.pagination.nextCursor).toBe(nextCursor);
+    expect(cursors.decode({ cursor: nextCursor })).toEqual({ id: 'ord_22', offset: 20 });
+
+    const struct = JSON.parse(Buffer.from(nextCursor, 'base64url').toString());
+    const tampered = Buffer.from(JSON.stringify({ ...struct, d: { id: 'ord_1', offset: 0 } }));
+
+    for (const cursor of [tampered.toString('base64url'), 'garbage', nextCursor.slice(1)]) {
+      expect(() => cursors.decode({ cursor })).toThrow();
+    }
+
+    const foreign = new PaginationCursors({ secret: 'other' });
+    const outdated = new PaginationCursors({ secret: 'cursor-secret', version: 2 });
+    expect(() => foreign.decode({ cursor: nextCursor })).toThrow();
+    expect(() => outdated.decode({ cursor: nextCursor })).toThrow();
+  });
+
+  test('TC-004: converts failed responses into errors', async () => {
+    const getResponseError = (response: Parameters<typeof ApiResponses.ensureOk>[0]) =>
+      ApiResponses.ensureOk(response).then(
+        () => {
+          throw new Error('Expected the response to fail');
+        },
+        error => error as HttpResponseError,
+      );
+
+    const ok = new Response('{}', { status: 201 });
+    expect(await ApiResponses.ensureOk(ok)).toBe(ok);
+
+    const envelope = new Response(
+      JSON.stringify({ message: 'Order not found', statusCode: 404, messageCode: 'NOT_FOUND' }),
+      { status: 404, headers: { 'content-type': 'application/json' } },
+    );
+    const problem = new Response(
+      JSON.stringify({ type: 'https://errors.test/out-of-stock', title: 'Out of stock', sku: 'a' }),
+      { status: 409, headers: { 'content-type': 'application/problem+json' } },
+    );
+
+    const errors = await Promise.all(
+      [envelope, problem, new Response('upstream down', { status: 502 })].map(getResponseError),
+    );
+
+    expect(
+      errors.map(({ statusCode, messageCode, message }) => [statusCode, messageCode, message]),
+    ).toEqual([
+      [404, 'NOT_FOUND', 'Order not found'],
+      [409, 'https://errors.test/out-of-stock', 'Out of stock'],
+      [502, 'REQUEST_FAILED', 'upstream down'],
+    ]);
+    expect(errors[1]).toBeInstanceOf(HttpResponseError);
+    expect(errors[1].details).toMatchObject({ sku: 'a' });
+
+    // Axios responses carry the parsed body
+    const axiosError = await getResponseError({
+      status: 400,
+      data: { message: 'Invalid' },
+      config: { url: '/orders' },
+    });
+    expect(axiosError.url).toBe('/orders');
+    expect(axiosError.message).toBe('Invalid');
+  });
+
+  test('TC-005: maps upstream error bodies with the registered decoder', async () => {
+    const seller = new NodeFetchNetworkRequest({
+      name: 'SellerRequest',
+      networkOptions: { baseUrl: 'http://seller.internal' },
+      errorDecoder: {
+        schema: z.object({ statusCode: z.number(), messageCode: z.string(), message: z.string() }),
+      },
+    });
+    const json = (body: unknown, status: number) =>
+      new Response(JSON.stringify(body), {
+        status,
+        headers: { 'content-type': 'application/json' },
+      });
+
+    const sellerError = await seller
+      .ensureOk(json({ statusCode: 422, messageCode: 'SKU_LOCKED', message: 'Locked' }, 400))
+      .catch(error => error as HttpResponseError);
+    expect(sellerError).toMatchObject({ statusCode: 422, messageCode: 'SKU_LOCKED' });
+
+    seller.setErrorDecoder({
+      schema: z.object({ error: z.object({ code: z.string(), reason: z.string() }) }),
+      map: ({ body, status }) => ({
+        statusCode: status,
+        messageCode: body.error.code,
+        message: body.error.reason,
+      }),
+    });
+
+    const mapped = await seller
+      .ensureOk(json({ error: { code: 'OUT_OF_STOCK', reason: 'No stock left' } }, 409))
+      .catch(error => error as HttpResponseError);
+    expect(mapped).toMatchObject({
+      statusCode: 409,
+      messageCode: 'OUT_OF_STOCK',
+      message: 'No stock left',
+    });
+
+    // Bodies not matching the schema fall back to the standard envelope
+    const fallback = await seller
+      .ensureOk(json({ message: 'Unavailable' }, 503))
+      .catch(error => error as HttpResponseError);
+    expect(fallback).toMatchObject({ statusCode: 503, message: 'Unavailable' });
+  });
+
+  test('TC-006: translates upstream failures with the registered error mapper', async () => {
+    const catalog = new NodeFetchNetworkRequest({
+      name: 'CatalogRequest',
+      networkOptions: { baseUrl: 'http://catalog.internal' },
+      errorMapper: ErrorMappers.byStatus({
+        404: { statusCode: 404, messageCode: 'ITEM_NOT_FOUND' },
+        '4xx': { statusCode: 502, messageCode: 'CATALOG_UNAVAILABLE' },
+      }),
+    });
+    const fail = (status: number) =>
+      catalog
+        .ensureOk(new Response('Upstream failure', { status }))
+        .catch(error => error as HttpResponseError);
+
+    expect(await fail(404)).toMatchObject({ statusCode: 404, messageCode: 'ITEM_NOT_FOUND' });
+
+    const unauthorized = await fail(401);
+    expect(unauthorized).toMatchObject({ statusCode: 502, messageCode: 'CATALOG_UNAVAILABLE' });
+    expect(unauthorized.details).toMatchObject({ upstreamStatus: 401 });
+    expect(unauthorized.message).toBe('Upstream failure');
+
+    // Statuses without a rule keep the upstream error
+    expect(await fail(503)).toMatchObject({ statusCode: 503, messageCode: 'REQUEST_FAILED' });
+  });
+});
@@ -0,0 +1,80 @@
+/**
+ * Bandwidth Throttle Test Suite
+ *
+ * Tests BandwidthThrottle:
+ * 1. Streams piped through one throttle share its rate
+ * 2. Fetchers throttle response bodies unless the request lifts the cap
+ *
+ * @module __tests__/network/bandwidth-throttle
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import { BandwidthThrottle, NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+const drain = async (stream: ReadableStream<Uint8Array>) => {
+  let size = 0;
+  for await (const chunk of stream) {
+    size += chunk.byteLength;
+  }
+  return size;
+};
+
+const toStream = (chunks: number, size: number) => {
+  let sent = 0;
+  return new ReadableStream<Uint8Array>({
+    pull(controller) {
+      if (sent++ >= chunks) {
+        controller.close();
+        return;
+      }
+      controller.enqueue(new Uint8Array(size));
+    },
+  });
+};
+
+describe('BandwidthThrottle', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ path: '/archive' }).respond({ body: 'x'.repeat(3_000) });
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: shares the rate between streams', async () => {
+    const throttle = new BandwidthThrottle({ bytesPerSecond: 10_000, burst: 1_000 });
+
+    const startedAt = Date.now();
+    const sizes = await Promise.all([
+      drain(toStream(5, 200).pipeThrough(throttle.toWebTransform())),
+      drain(toStream(5, 200).pipeThrough(throttle.toWebTransform())),
+    ]);
+
+    expect(sizes).toEqual([1_000, 1_000]);
+    // 1000 bytes of burst then 1000 bytes at 10 KB/s
+    expect(Date.now() - startedAt).toBeGreaterThanOrEqual(90);
+  });
+
+  test('TC-002: throttles fetcher responses', async () => {
+    const request = new NodeFetchNetworkRequest({
+      name: 'ArchiveRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      bandwidth: { bytesPerSecond: 10_000, burst: 1_000 },
+    });
+    const url = request.getRequestUrl({ paths: ['/archive'] });
+
+    let startedAt = Date.now();
+    const throttled = await request.getNetworkService().get({ url });
+    expect(await throttled.text()).toHaveLength(3_000);
+    expect(Date.now() - startedAt).toBeGreaterThanOrEqual(190);
+
+    startedAt = Date.now();
+    const unthrottled = await request.getNetworkService().get({ url, bandwidth: false });
+    expect(await unthrottled.text()).toHaveLength(3_000);
+    expect(Date.now() - startedAt).toBeLessThan(150);
+  });
+});
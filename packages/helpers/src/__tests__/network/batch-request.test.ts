@@ -0,0 +1,87 @@
+/**
+ * Batch Request Test Suite
+ *
+ * Tests BaseNetworkRequest.sendBatch:
+ * 1. Concurrency is capped and results keep the input order
+ * 2. Failed requests are reported per item as ApplicationError
+ *
+ * @module __tests__/network/batch-request
+ */
+
+import { describe, test, expect, afterAll } from 'bun:test';
+import { ApplicationError, getError } from '@/helpers/error';
+import { NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer, MockServerFaults } from '@/helpers/testing';
+
+describe('sendBatch', () => {
+  const server = new MockServer();
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: caps concurrency and preserves ordering', async () => {
+    await server.start();
+
+    let inFlight = 0;
+    let maxInFlight = 0;
+    server.when({ method: 'GET', path: '/items/:id' }).respond(async request => {
+      inFlight++;
+      maxInFlight = Math.max(maxInFlight, inFlight);
+
+      // Later items answer first
+      await new Promise(resolve => setTimeout(resolve, 40 - Number(request.params.id) * 5));
+      inFlight--;
+
+      return { json: { id: request.params.id } };
+    });
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'BatchRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+    });
+
+    const results = await request.sendBatch({
+      requests: [0, 1, 2, 3, 4, 5].map(id => ({
+        url: request.getRequestUrl({ paths: ['/items', `${id}`] }),
+      })),
+      concurrency: 2,
+      transform: async ({ response }) => ((await response.json()) as { id: string }).id,
+    });
+
+    expect(maxInFlight).toBe(2);
+    expect(results).toEqual(
+      ['0', '1', '2', '3', '4', '5'].map((value, index) => ({ index, ok: true, value })),
+    );
+  });
+
+  test('TC-002: reports failures per item', async () => {
+    server.when({ path: '/broken' }).fault(MockServerFaults.CONNECTION_RESET);
+    server.when({ path: '/missing' }).respond({ status: 404 });
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'BatchRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+    });
+
+    const results = await request.sendBatch({
+      requests: ['/items/1', '/broken', '/missing'].map(path => ({
+        url: request.getRequestUrl({ paths: [path] }),
+      })),
+      transform: ({ response }) => {
+        if (!response.ok) {
+          throw getError({ statusCode: response.status, message: 'Not found' });
+        }
+
+        return response.status;
+      },
+    });
+
+    expect(results[0]).toEqual({ index: 0, ok: true, value: 200 });
+
+    const [broken, missing] = results.slice(1).map(rs => (rs.ok ? undefined : rs.error));
+    expect(broken).toBeInstanceOf(ApplicationError);
+    expect(broken?.statusCode).toBe(502);
+    expect(missing?.statusCode).toBe(404);
+  });
+});
@@ -0,0 +1,67 @@
+/**
+ * Bulkhead Test Suite
+ *
+ * Tests Bulkhead and BulkheadRegistry:
+ * 1. Requests beyond the concurrency and queue depth of a bulkhead are rejected
+ * 2. Fetchers of the same downstream share its bulkhead through the registry
+ *
+ * @module __tests__/network/bulkhead
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import {
+  Bulkhead,
+  BulkheadErrorCodes,
+  BulkheadRegistry,
+  NodeFetchNetworkRequest,
+} from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('Bulkhead', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ path: '/slow' }).respond({ status: 200, delay: 50 });
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: rejects requests beyond the queue depth', async () => {
+    const bulkhead = new Bulkhead({ name: 'partner', maxConcurrent: 1, maxQueued: 1 });
+
+    const release = await bulkhead.acquire();
+    const queued = bulkhead.acquire();
+
+    await expect(bulkhead.acquire()).rejects.toMatchObject({
+      messageCode: BulkheadErrorCodes.FULL,
+    });
+    expect(bulkhead.getRejected()).toBe(1);
+
+    release();
+    (await queued)();
+    expect(bulkhead.getActive()).toBe(0);
+  });
+
+  test('TC-002: shares one bulkhead between the fetchers of a downstream', async () => {
+    const bulkheads = new BulkheadRegistry({ defaults: { maxConcurrent: 1 } });
+    const create = (name: string) =>
+      new NodeFetchNetworkRequest({
+        name,
+        networkOptions: { baseUrl: server.getBaseUrl() },
+        concurrency: bulkheads.get({ name: 'partner' }),
+      });
+
+    const [orders, invoices] = [create('OrdersRequest'), create('InvoicesRequest')];
+    const sent = orders.getNetworkService().get({ url: orders.getRequestUrl({ paths: ['slow'] }) });
+
+    const url = invoices.getRequestUrl({ paths: ['slow'] });
+    await expect(invoices.getNetworkService().get({ url })).rejects.toThrow('Bulkhead is full');
+    expect((await sent).status).toBe(200);
+    expect(bulkheads.getStats()).toEqual([
+      { name: 'partner', maxConcurrent: 1, active: 0, queued: 0, rejected: 1 },
+    ]);
+  });
+});
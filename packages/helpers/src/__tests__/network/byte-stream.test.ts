@@ -0,0 +1,72 @@
+/**
+ * Byte Stream Test Suite
+ *
+ * Tests the byte streams of the node fetcher:
+ * 1. Upstream bodies streamed with their metadata and proxied as responses
+ * 2. Failed responses thrown, bodies failing mid-way reported as read failures
+ *
+ * @module __tests__/network/byte-stream
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import { ByteStreamErrorCodes, ByteStreams, NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer, MockServerFaults } from '@/helpers/testing';
+
+describe('NodeFetcher.streamBytes', () => {
+  const server = new MockServer();
+  let request: NodeFetchNetworkRequest;
+
+  beforeAll(async () => {
+    await server.start();
+    request = new NodeFetchNetworkRequest({
+      name: 'InvoiceRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+    });
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: streams the body with its metadata and proxies it', async () => {
+    server.when({ path: '/invoices/1/pdf' }).respond({
+      headers: { 'content-type': 'application/pdf', 'content-length': '16', 'x-internal': '1' },
+      body: '%PDF-1.7 invoice',
+    });
+
+    const stream = await request.getNetworkService().streamBytes({
+      url: request.getRequestUrl({ paths: ['invoices', '1', 'pdf'] }),
+    });
+    expect(stream.contentType).toBe('application/pdf');
+    expect(stream.contentLength).toBe(16);
+
+    const response = ByteStreams.toResponse({
+      stream,
+      headers: { 'content-disposition': 'inline; filename="invoice-1.pdf"' },
+    });
+    expect(response.headers.get('content-type')).toBe('application/pdf');
+    expect(response.headers.get('content-disposition')).toBe('inline; filename="invoice-1.pdf"');
+    expect(response.headers.get('x-internal')).toBeNull();
+    expect(await response.text()).toBe('%PDF-1.7 invoice');
+  });
+
+  test('TC-002: throws failed responses and bodies failing mid-way', async () => {
+    server.when({ path: '/invoices/2/pdf' }).respond({ status: 404, json: { message: 'Gone' } });
+    server.when({ path: '/invoices/3/pdf' }).respond({
+      body: 'x'.repeat(64 * 1024),
+      fault: MockServerFaults.TRUNCATED_BODY,
+    });
+
+    const service = request.getNetworkService();
+    await expect(
+      service.streamBytes({ url: request.getRequestUrl({ paths: ['invoices', '2', 'pdf'] }) }),
+    ).rejects.toMatchObject({ statusCode: 404, message: 'Gone' });
+
+    const stream = await service.streamBytes({
+      url: request.getRequestUrl({ paths: ['invoices', '3', 'pdf'] }),
+    });
+    await expect(new Response(stream.body).text()).rejects.toMatchObject({
+      messageCode: ByteStreamErrorCodes.READ_FAILED,
+    });
+  });
+});
@@ -0,0 +1,65 @@
+/**
+ * Certificate Pinning Test Suite
+ *
+ * Tests CertificatePinning:
+ * 1. Chains matching a pin of their host pass, other chains are rejected
+ * 2. Report-only mode reports mismatches without rejecting
+ *
+ * @module __tests__/network/certificate-pinning
+ */
+
+import { describe, test, expect } from 'bun:test';
+import C from 'node:crypto';
+import tls from 'node:tls';
+import { CertificatePinning, ICertificatePinReport } from '@/helpers/network';
+
+const createCertificate = (opts: { host: string; issuer?: tls.PeerCertificate }) => {
+  const { publicKey } = C.generateKeyPairSync('ec', { namedCurve: 'prime256v1' });
+  const pubkey = publicKey.export({ type: 'spki', format: 'der' });
+
+  return {
+    subject: { CN: opts.host },
+    subjectaltname: `DNS:${opts.host}`,
+    pubkey,
+    issuerCertificate: opts.issuer,
+  } as unknown as tls.DetailedPeerCertificate;
+};
+
+describe('CertificatePinning', () => {
+  const intermediate = createCertificate({ host: 'Acquirer Issuing CA' });
+  const leaf = createCertificate({ host: 'api.acquirer.example', issuer: intermediate });
+  const intermediatePin = CertificatePinning.getPin({ publicKey: intermediate.pubkey });
+
+  test('TC-001: accepts chains matching a pin and rejects the others', () => {
+    const pinning = new CertificatePinning({
+      pins: { '*.acquirer.example': [intermediatePin.replace('sha256/', '')] },
+    });
+
+    expect(pinning.checkServerIdentity('api.acquirer.example', leaf)).toBeUndefined();
+
+    const forged = createCertificate({ host: 'api.acquirer.example' });
+    const error = pinning.checkServerIdentity('api.acquirer.example', forged);
+    expect(error?.message).toContain('No certificate matches the pins');
+
+    // Unpinned hosts are only verified
+    const other = createCertificate({ host: 'status.example' });
+    expect(pinning.checkServerIdentity('status.example', other)).toBeUndefined();
+    expect(pinning.checkServerIdentity('wrong.example', other)).toBeInstanceOf(Error);
+  });
+
+  test('TC-002: reports mismatches without rejecting in report-only mode', () => {
+    const reports: Array<ICertificatePinReport> = [];
+    const pinning = new CertificatePinning({
+      pins: { 'api.acquirer.example': ['sha256/AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA='] },
+      reportOnly: true,
+      onMismatch: report => reports.push(report),
+    });
+
+    expect(pinning.checkServerIdentity('api.acquirer.example', leaf)).toBeUndefined();
+    expect(reports).toHaveLength(1);
+    expect(reports[0].received).toEqual([
+      CertificatePinning.getPin({ publicKey: leaf.pubkey }),
+      intermediatePin,
+    ]);
+  });
+});
@@ -0,0 +1,91 @@
+/**
+ * Concurrency Limiter Test Suite
+ *
+ * Tests ConcurrencyLimiter:
+ * 1. Waiting interactive requests are served before background ones
+ * 2. Background requests waiting past `maxBackgroundWait` are not starved
+ * 3. Fetchers send their requests through the limiter by priority class
+ *
+ * @module __tests__/network/concurrency-limiter
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import { ConcurrencyLimiter, NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('ConcurrencyLimiter', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ path: '/items/:id' }).respond({ status: 200, delay: 20 });
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: serves interactive requests before background ones', async () => {
+    const limiter = new ConcurrencyLimiter({ maxConcurrent: 1 });
+    const order: Array<string> = [];
+
+    const release = await limiter.acquire();
+    const waits = [
+      limiter.acquire({ priorityClass: 'background' }).then(next => {
+        order.push('sync');
+        return next;
+      }),
+      limiter.acquire({ priorityClass: 'interactive' }).then(next => {
+        order.push('user');
+        return next;
+      }),
+    ];
+    expect(limiter.getQueued()).toBe(2);
+
+    release();
+    (await waits[1])();
+    (await waits[0])();
+
+    expect(order).toEqual(['user', 'sync']);
+    expect(limiter.getActive()).toBe(0);
+  });
+
+  test('TC-002: serves background requests waiting past the starvation limit', async () => {
+    const limiter = new ConcurrencyLimiter({ maxConcurrent: 1, maxBackgroundWait: 0 });
+    const order: Array<string> = [];
+
+    const release = await limiter.acquire();
+    const waits = [
+      limiter.acquire({ priorityClass: 'background' }).then(next => {
+        order.push('sync');
+        return next;
+      }),
+      limiter.acquire({ priorityClass: 'interactive' }).then(next => {
+        order.push('user');
+        return next;
+      }),
+    ];
+
+    release();
+    (await waits[0])();
+    (await waits[1])();
+
+    expect(order).toEqual(['sync', 'user']);
+  });
+
+  test('TC-003: orders the requests of a saturated fetcher by priority class', async () => {
+    const request = new NodeFetchNetworkRequest({
+      name: 'CatalogRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      concurrency: { maxConcurrent: 1 },
+    });
+
+    const service = request.getNetworkService();
+    const get = (id: string, priorityClass: 'interactive' | 'background') =>
+      service.get({ url: request.getRequestUrl({ paths: ['items', id] }), priorityClass });
+
+    await Promise.all([get('1', 'background'), get('2', 'background'), get('3', 'interactive')]);
+
+    expect(server.requests.map(rq => rq.path)).toEqual(['/items/1', '/items/3', '/items/2']);
+  });
+});
@@ -0,0 +1,94 @@
+/**
+ * Conditional Update Test Suite
+ *
+ * Tests ConditionalUpdates:
+ * 1. Writes are sent with the ETag of the read as `If-Match`
+ * 2. Lost races are retried from a fresh read, then reported as conflicts
+ *
+ * @module __tests__/network/conditional-update
+ */
+
+import { describe, test, expect, afterAll, beforeAll, afterEach } from 'bun:test';
+import { ConditionalUpdateErrorCodes, NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('ConditionalUpdates', () => {
+  const server = new MockServer();
+  const request = new NodeFetchNetworkRequest({ name: 'CartRequest', networkOptions: {} });
+  const service = request.getNetworkService();
+
+  // Versioned cart, `bump` simulates a concurrent writer
+  const cart = { version: 1, items: [] as Array<string>, bump: () => cart.version++ };
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  afterEach(() => {
+    server.reset();
+    cart.version = 1;
+    cart.items = [];
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  const mockCart = () => {
+    server
+      .when({ method: 'GET', path: '/carts/1' })
+      .respond(() => ({ headers: { etag: `"v${cart.version}"` }, json: { items: cart.items } }));
+
+    server.when({ method: 'PUT', path: '/carts/1' }).respond(rq => {
+      if (rq.headers['if-match'] !== `"v${cart.version}"`) {
+        return { status: 412 };
+      }
+
+      cart.version++;
+      cart.items = (rq.body as { items: Array<string> }).items;
+      return { status: 204 };
+    });
+  };
+
+  const url = () => `${server.getBaseUrl()}/carts/1`;
+
+  test('TC-001: retries lost races from a fresh read', async () => {
+    mockCart();
+
+    const { response, attempts } = await service.conditionalUpdate<{ items: Array<string> }>({
+      url: url(),
+      mutate: ({ resource, attempt }) => {
+        if (attempt === 1) {
+          cart.bump();
+        }
+
+        return { items: [...resource.items, 'book'] };
+      },
+    });
+
+    expect(response.status).toBe(204);
+    expect(attempts).toBe(2);
+    expect(cart.items).toEqual(['book']);
+
+    const writes = server.requests.filter(rq => rq.method === 'PUT');
+    expect(writes.map(rq => rq.headers['if-match'])).toEqual(['"v1"', '"v2"']);
+  });
+
+  test('TC-002: reports a conflict once the attempts are exhausted', async () => {
+    mockCart();
+
+    const update = service.conditionalUpdate({
+      url: url(),
+      maxAttempts: 2,
+      mutate: ({ resource }) => {
+        cart.bump();
+        return resource;
+      },
+    });
+
+    await expect(update).rejects.toMatchObject({
+      statusCode: 409,
+      messageCode: ConditionalUpdateErrorCodes.CONFLICT,
+    });
+  });
+});
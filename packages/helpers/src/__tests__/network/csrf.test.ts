@@ -0,0 +1,104 @@
+/**
+ * CSRF Interceptor Test Suite
+ *
+ * Tests the CSRF interceptor of form based upstreams:
+ * 1. Tokens of a priming GET are sent with mutating requests and obtained again when rejected
+ * 2. Tokens are sent as a form field instead of a header
+ *
+ * @module __tests__/network/csrf
+ */
+
+import { describe, test, expect, afterAll, beforeAll, beforeEach } from 'bun:test';
+import { CookieJar, CsrfInterceptor, NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('CsrfInterceptor', () => {
+  const server = new MockServer();
+  let token = 't1';
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  beforeEach(() => {
+    server.reset();
+    token = 't1';
+
+    server.when({ method: 'GET', path: '/orders/new' }).respond(() => ({
+      status: 200,
+      headers: { 'content-type': 'text/html', 'set-cookie': 'sid=1; Path=/' },
+      body: `<html><head><meta name="csrf-token" content="${token}"></head></html>`,
+    }));
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  const createRequest = (opts: { header?: string | false; field?: string }) => {
+    const jar = new CookieJar();
+    const priming = { url: `${server.getBaseUrl()}/orders/new` };
+
+    return new NodeFetchNetworkRequest({
+      name: 'BackofficeRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      cookieJar: jar,
+      interceptors: [new CsrfInterceptor({ jar, priming, ...opts })],
+    });
+  };
+
+  test('TC-001: sends the primed token with mutating requests and primes again', async () => {
+    server.when({ method: 'GET', path: '/orders' }).respond({ status: 200, json: [] });
+    server
+      .when({ method: 'POST', path: '/orders' })
+      .respond(request =>
+        request.headers['x-csrf-token'] === token && request.headers.cookie === 'sid=1'
+          ? { status: 201 }
+          : { status: 419 },
+      );
+
+    const request = createRequest({});
+    const url = request.getRequestUrl({ paths: ['/orders'] });
+
+    expect((await request.getNetworkService().get({ url })).status).toBe(200);
+    expect(server.requests[0].headers['x-csrf-token']).toBeUndefined();
+
+    expect((await request.getNetworkService().post({ url, body: '{}' })).status).toBe(201);
+
+    // The upstream rotates its token
+    token = 't2';
+    expect((await request.getNetworkService().post({ url, body: '{}' })).status).toBe(201);
+
+    expect(server.requests.map(rq => `${rq.method} ${rq.path}`)).toEqual([
+      'GET /orders',
+      'GET /orders/new',
+      'POST /orders',
+      'POST /orders',
+      'GET /orders/new',
+      'POST /orders',
+    ]);
+  });
+
+  test('TC-002: sends the token as a form field', async () => {
+    server
+      .when({
+        method: 'POST',
+        path: '/orders',
+        body: body => typeof body === 'string' && new URLSearchParams(body).get('_token') === token,
+      })
+      .respond({ status: 204 });
+
+    const request = createRequest({ header: false, field: '_token' });
+    const response = await request.getNetworkService().post({
+      url: request.getRequestUrl({ paths: ['/orders'] }),
+      headers: { 'content-type': 'application/x-www-form-urlencoded' },
+      body: new URLSearchParams({ note: 'rush' }),
+    });
+
+    expect(response.status).toBe(204);
+
+    const sent = server.requests[server.requests.length - 1];
+    expect(sent.headers['x-csrf-token']).toBeUndefined();
+    expect(sent.rawBody).toBe('note=rush&_token=t1');
+  });
+});
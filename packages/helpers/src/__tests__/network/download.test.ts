@@ -0,0 +1,102 @@
+/**
+ * Download Test Suite
+ *
+ * Tests NodeFetchNetworkRequest.download:
+ * 1. Announced and caller supplied checksums are verified, mismatches are downloaded again
+ * 2. Persistent mismatches fail without writing the destination
+ *
+ * @module __tests__/network/download
+ */
+
+import { describe, test, expect, afterAll, beforeAll, beforeEach } from 'bun:test';
+import C from 'node:crypto';
+import fs from 'node:fs';
+import fsp from 'node:fs/promises';
+import os from 'node:os';
+import path from 'node:path';
+import { ChecksumAlgorithms, DownloadErrorCodes, NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+const CONTENT = 'id,status\n1,paid\n2,draft\n';
+
+const digest = (algorithm: string, encoding: 'hex' | 'base64') =>
+  C.createHash(algorithm).update(CONTENT).digest(encoding);
+
+describe('NodeFetchNetworkRequest.download', () => {
+  const server = new MockServer();
+  let directory: string;
+  let request: NodeFetchNetworkRequest;
+
+  beforeAll(async () => {
+    await server.start();
+    directory = await fsp.mkdtemp(path.join(os.tmpdir(), 'ignis-download-'));
+    request = new NodeFetchNetworkRequest({
+      name: 'ExportRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+    });
+  });
+
+  beforeEach(() => {
+    server.reset();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+    await fsp.rm(directory, { recursive: true, force: true });
+  });
+
+  test('TC-001: verifies checksums and downloads corrupted content again', async () => {
+    const headers = { 'content-md5': digest('md5', 'base64') };
+    server.when({ path: '/orders.csv' }).respond({ body: CONTENT, headers });
+    server
+      .when({ path: '/orders.csv' })
+      .respond({ body: CONTENT.replace('paid', 'void'), headers })
+      .times(1);
+
+    const announced = await request.download({
+      url: request.getRequestUrl({ paths: ['/orders.csv'] }),
+      destination: path.join(directory, 'announced.csv'),
+    });
+    expect(announced).toMatchObject({ attempts: 2, size: CONTENT.length });
+    expect(announced.checksum).toEqual({
+      algorithm: ChecksumAlgorithms.MD5,
+      digest: digest('md5', 'hex'),
+    });
+    expect(await fsp.readFile(announced.path, 'utf-8')).toBe(CONTENT);
+
+    const supplied = await request.download({
+      url: request.getRequestUrl({ paths: ['/orders.csv'] }),
+      destination: path.join(directory, 'supplied.csv'),
+      checksum: { algorithm: ChecksumAlgorithms.SHA256, digest: digest('sha256', 'hex') },
+    });
+    expect(supplied).toMatchObject({ attempts: 1 });
+    expect(supplied.checksum?.algorithm).toBe(ChecksumAlgorithms.SHA256);
+  });
+
+  test('TC-002: fails after the last mismatching attempt', async () => {
+    server.when({ path: '/orders.csv' }).respond({
+      body: CONTENT,
+      headers: { 'repr-digest': `sha-256=:${C.randomBytes(32).toString('base64')}:` },
+    });
+
+    const destination = path.join(directory, 'corrupted.csv');
+    const error = await request
+      .download({
+        url: request.getRequestUrl({ paths: ['/orders.csv'] }),
+        destination,
+        maxAttempts: 2,
+      })
+      .then(
+        () => {
+          throw new Error('Expected the download to fail');
+        },
+        (error: { messageCode?: string }) => error,
+      );
+
+    expect(error.messageCode).toBe(DownloadErrorCodes.CHECKSUM_MISMATCH);
+    expect(server.requests).toHaveLength(2);
+    expect(fs.existsSync(destination)).toBe(false);
+    const files = await fsp.readdir(directory);
+    expect(files.some(file => file.startsWith('corrupted'))).toBe(false);
+  });
+});
@@ -0,0 +1,71 @@
+/**
+ * Downstream Prober Test Suite
+ *
+ * Tests DownstreamProber:
+ * 1. Failed probes open the downstream, requests are refused without reaching it
+ * 2. Successful probes close it again before any request
+ *
+ * @module __tests__/network/downstream-prober
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import {
+  DownstreamProber,
+  DownstreamProberErrorCodes,
+  NodeFetchNetworkRequest,
+} from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('DownstreamProber', () => {
+  const server = new MockServer();
+  const changes: Array<boolean> = [];
+  let healthStatus = 503;
+  let prober: DownstreamProber;
+  let request: NodeFetchNetworkRequest;
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ path: '/health' }).respond(() => ({ status: healthStatus }));
+    server.when({ path: '/accounts' }).respond({ json: [] });
+
+    prober = new DownstreamProber({
+      url: `${server.getBaseUrl()}/health`,
+      onChange: ({ state }) => changes.push(state.isOpen),
+    });
+    request = new NodeFetchNetworkRequest({
+      name: 'LedgerRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      prober,
+    });
+  });
+
+  afterAll(async () => {
+    prober.stop();
+    await server.stop();
+  });
+
+  test('TC-001: opens after failed probes and refuses requests', async () => {
+    await prober.probe();
+    expect(prober.isOpen()).toBe(false);
+
+    await prober.probe();
+    expect(prober.getState()).toMatchObject({ isOpen: true, failures: 2 });
+
+    await expect(
+      request.getNetworkService().get({ url: request.getRequestUrl({ paths: ['accounts'] }) }),
+    ).rejects.toMatchObject({ statusCode: 503, messageCode: DownstreamProberErrorCodes.OPEN });
+    expect(server.requests.filter(rq => rq.path === '/accounts')).toHaveLength(0);
+  });
+
+  test('TC-002: closes once the downstream recovers', async () => {
+    healthStatus = 200;
+    await prober.probe();
+    expect(prober.isOpen()).toBe(false);
+
+    const rs = await request.getNetworkService().get({
+      url: request.getRequestUrl({ paths: ['accounts'] }),
+    });
+    expect(rs.status).toBe(200);
+    expect(changes).toEqual([true, false]);
+  });
+});
@@ -0,0 +1,152 @@
+/**
+ * Egress Guard Test Suite
+ *
+ * Tests EgressGuardInterceptor:
+ * 1. Addresses are checked against the allowlist and the always blocked ranges
+ * 2. Hosts are rejected when any address they resolve to is not allowed, requests are pinned to
+ *    the checked address
+ * 3. Redirects are followed by the guard with the node fetcher, a hop to a blocked range is
+ *    rejected
+ * 4. Same with the axios fetcher
+ *
+ * @module __tests__/network/egress-guard
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import {
+  EgressGuardErrorCodes,
+  EgressGuardInterceptor,
+  NodeFetchNetworkRequest,
+} from '@/helpers/network';
+import { AxiosNetworkRequest } from '@/helpers/network/http-request/fetcher/axios-fetcher';
+import { MockServer } from '@/helpers/testing';
+
+describe('EgressGuardInterceptor', () => {
+  test('TC-001: checks addresses against the allowlist and blocked ranges', async () => {
+    const guard = new EgressGuardInterceptor({ allowlist: ['10.0.0.0/8', '::/0', '0.0.0.0/0'] });
+
+    expect(guard.isAllowed({ address: '10.1.2.3' })).toBe(true);
+    expect(guard.isAllowed({ address: '169.254.169.254' })).toBe(false);
+    expect(guard.isAllowed({ address: '::ffff:169.254.169.254' })).toBe(false);
+    expect(guard.isAllowed({ address: 'fe80::1' })).toBe(false);
+
+    const strict = new EgressGuardInterceptor({ allowlist: ['10.0.0.0/8'] });
+    await strict.check({ url: 'http://10.0.0.5:8080/hooks' });
+    await expect(strict.check({ url: 'http://8.8.8.8/hooks' })).rejects.toThrow('Egress denied');
+    await expect(strict.check({ url: 'file:///etc/passwd' })).rejects.toThrow('protocol');
+  });
+
+  test('TC-002: rejects hosts resolving to any denied address', async () => {
+    const addresses: Record<string, Array<string>> = {
+      'hooks.partner.example': ['203.0.113.7'],
+      'rebind.attacker.example': ['203.0.113.8', '169.254.169.254'],
+    };
+    const guard = new EgressGuardInterceptor({
+      allowlist: ['203.0.113.0/24'],
+      lookup: async hostname => {
+        return (addresses[hostname] ?? []).map(address => ({ address, family: 4 }));
+      },
+    });
+
+    const request = { url: 'https://hooks.partner.example/events', method: 'post' };
+    expect(await guard.onRequest({ request })).toMatchObject({
+      ...request,
+      followRedirects: false,
+      resolvedAddress: { address: '203.0.113.7', family: 4 },
+    });
+
+    const rebind = guard.onRequest({ request: { url: 'https://rebind.attacker.example' } });
+    await expect(rebind).rejects.toThrow('169.254.169.254');
+    await expect(guard.check({ url: 'https://unknown.example' })).rejects.toThrow('unresolved');
+  });
+
+  describe('redirects', () => {
+    const server = new MockServer();
+    let origin = '';
+
+    // Only the guard resolves this host, requests to it reach the server through the pinned address
+    const createGuard = () =>
+      new EgressGuardInterceptor({
+        allowlist: ['127.0.0.0/8'],
+        maxRedirects: 2,
+        lookup: async hostname =>
+          hostname === 'hooks.partner.example' ? [{ address: '127.0.0.1', family: 4 }] : [],
+      });
+
+    const createService = (variant: 'node' | 'axios') => {
+      const opts = { name: 'HookRequest', networkOptions: {}, interceptors: [createGuard()] };
+      return variant === 'node'
+        ? new NodeFetchNetworkRequest(opts).getNetworkService()
+        : new AxiosNetworkRequest(opts).getNetworkService();
+    };
+
+    beforeAll(async () => {
+      await server.start();
+      origin = `http://hooks.partner.example:${new URL(server.getBaseUrl()).port}`;
+
+      server.when({ method: 'GET', path: '/start' }).respond({
+        status: 302,
+        headers: { location: '/landing' },
+      });
+      server.when({ method: 'GET', path: '/cross' }).respond(() => ({
+        status: 307,
+        headers: { location: `${server.getBaseUrl()}/landing` },
+      }));
+      server.when({ method: 'GET', path: '/landing' }).respond({ status: 200, json: { ok: true } });
+      server.when({ method: 'GET', path: '/metadata' }).respond({
+        status: 302,
+        headers: { location: 'http://169.254.169.254/latest/meta-data/' },
+      });
+      server.when({ method: 'GET', path: '/loop' }).respond({
+        status: 302,
+        headers: { location: '/loop' },
+      });
+    });
+
+    afterAll(async () => {
+      await server.stop();
+    });
+
+    const getLastRequest = (path: string) => server.requests.filter(rq => rq.path === path).at(-1)!;
+
+    const getError = (promise: Promise<unknown>) =>
+      promise.then(
+        () => {
+          throw new Error('Expected the request to be rejected');
+        },
+        error => error,
+      );
+
+    for (const [id, variant] of [
+      ['TC-003', 'node'],
+      ['TC-004', 'axios'],
+    ] as const) {
+      test(`${id}: follows checked redirects with the ${variant} fetcher`, async () => {
+        const service = createService(variant);
+        server.requests = [];
+
+        const rs = await service.get({ url: `${origin}/start` });
+        expect(rs.status).toBe(200);
+        expect(server.requests.map(rq => rq.path)).toEqual(['/start', '/landing']);
+        expect(getLastRequest('/landing').headers.host).toBe(new URL(origin).host);
+
+        // Credentials stay with the origin
+        await service.get({ url: `${origin}/cross`, headers: { authorization: 'Bearer t' } });
+        expect(getLastRequest('/cross').headers.authorization).toBe('Bearer t');
+        expect(getLastRequest('/landing').headers.authorization).toBeUndefined();
+
+        // Rejected before connecting to the metadata endpoint
+        server.requests = [];
+        const denied = await getError(service.get({ url: `${origin}/metadata` }));
+        expect(denied).toMatchObject({ messageCode: EgressGuardErrorCodes.DENIED });
+        expect(denied.message).toContain('169.254.169.254');
+        expect(server.requests.map(rq => rq.path)).toEqual(['/metadata']);
+
+        server.requests = [];
+        const looped = await getError(service.get({ url: `${origin}/loop` }));
+        expect(looped).toMatchObject({ messageCode: EgressGuardErrorCodes.TOO_MANY_REDIRECTS });
+        expect(server.requests).toHaveLength(3);
+      });
+    }
+  });
+});
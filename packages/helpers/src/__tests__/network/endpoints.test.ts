@@ -0,0 +1,83 @@
+/**
+ * Network Endpoints Test Suite
+ *
+ * Tests the named endpoints of the network requests:
+ * 1. Path params, query and JSON bodies sent with the method of the endpoint, bodies validated
+ * 2. Unknown endpoints, missing params and unexpected bodies rejected
+ *
+ * @module __tests__/network/endpoints
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import { z } from '@hono/zod-openapi';
+import { NetworkEndpointErrorCodes, NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+const OrderSchema = z.object({ id: z.string(), total: z.number() });
+
+describe('Network endpoints', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  const createRequest = () =>
+    new NodeFetchNetworkRequest({
+      name: 'OrderRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      endpoints: {
+        getOrder: { path: '/orders/:id', schema: OrderSchema, totalTimeout: 1_000 },
+        addItem: { path: '/orders/:id/items', method: 'POST', headers: { 'x-source': 'test' } },
+      },
+    });
+
+  test('TC-001: sends the declared method, path and bodies', async () => {
+    server.reset();
+    server.when({ method: 'GET', path: '/orders/ord%201' }).respond({
+      status: 200,
+      json: { id: 'ord 1', total: 25 },
+    });
+    server.when({ method: 'POST', path: '/orders/ord%201/items' }).respond({ status: 204 });
+
+    const request = createRequest();
+    const order = await request.call<z.infer<typeof OrderSchema>>({
+      endpoint: 'getOrder',
+      params: { id: 'ord 1' },
+      query: { expand: 'items' },
+    });
+    expect(order).toEqual({ id: 'ord 1', total: 25 });
+
+    const added = await request.call({
+      endpoint: 'addItem',
+      params: { id: 'ord 1' },
+      body: { sku: 'SKU-1', quantity: 2 },
+    });
+    expect(added).toBeUndefined();
+
+    const [fetched, posted] = server.requests;
+    expect(fetched.query).toEqual({ expand: 'items' });
+    expect(posted.body).toEqual({ sku: 'SKU-1', quantity: 2 });
+    expect(posted.headers['x-source']).toBe('test');
+  });
+
+  test('TC-002: rejects unknown endpoints, missing params and unexpected bodies', async () => {
+    server.reset();
+    server.when({ path: '/orders/ord_2' }).respond({ status: 200, json: { id: 'ord_2' } });
+
+    const request = createRequest();
+    await expect(request.call({ endpoint: 'cancelOrder' })).rejects.toMatchObject({
+      messageCode: NetworkEndpointErrorCodes.UNKNOWN_ENDPOINT,
+    });
+    await expect(request.call({ endpoint: 'getOrder' })).rejects.toMatchObject({
+      messageCode: NetworkEndpointErrorCodes.MISSING_PARAM,
+    });
+    await expect(
+      request.call({ endpoint: 'getOrder', params: { id: 'ord_2' } }),
+    ).rejects.toMatchObject({ messageCode: NetworkEndpointErrorCodes.INVALID_RESPONSE });
+  });
+});
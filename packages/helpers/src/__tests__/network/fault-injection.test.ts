@@ -0,0 +1,96 @@
+/**
+ * Fault Injection Test Suite
+ *
+ * Tests FaultInjector with the node fetcher:
+ * 1. Injected statuses are retried, the retry reaches the upstream
+ * 2. Injected resets fail the request without sending it, other environments inject nothing
+ * 3. Nothing is injected unless switched on
+ *
+ * @module __tests__/network/fault-injection
+ */
+
+import { describe, test, expect, afterAll, beforeAll, beforeEach } from 'bun:test';
+import {
+  FaultInjectionErrorCodes,
+  FaultInjector,
+  NodeFetchNetworkRequest,
+} from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('FaultInjector', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ method: 'GET', path: '/stocks' }).respond({ json: [] });
+  });
+
+  beforeEach(() => {
+    server.requests = [];
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: retries an injected status', async () => {
+    let rolls = 0;
+    const request = new NodeFetchNetworkRequest({
+      name: 'InventoryRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      retry: { maxAttempts: 3, baseDelay: 1 },
+      faults: {
+        isEnabled: true,
+        rules: [{ url: `${server.getBaseUrl()}/stocks`, rate: 0.5, fault: 'status', status: 503 }],
+        // Injected into the first attempt only
+        random: () => (rolls++ === 0 ? 0 : 0.9),
+      },
+    });
+
+    const rs = await request.getNetworkService().get({
+      url: request.getRequestUrl({ paths: ['stocks'] }),
+    });
+    expect(rs.status).toBe(200);
+    expect(rolls).toBe(2);
+    expect(server.requests.filter(rq => rq.path === '/stocks')).toHaveLength(1);
+  });
+
+  test('TC-002: fails with an injected reset without sending the request', async () => {
+    const rules = [{ method: 'get', rate: 1, fault: 'reset' as const }];
+    const request = new NodeFetchNetworkRequest({
+      name: 'InventoryRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      faults: { isEnabled: true, rules },
+    });
+
+    await expect(
+      request.getNetworkService().get({ url: request.getRequestUrl({ paths: ['stocks'] }) }),
+    ).rejects.toMatchObject({ messageCode: FaultInjectionErrorCodes.CONNECTION_RESET });
+    expect(server.requests).toHaveLength(0);
+
+    const production = new NodeFetchNetworkRequest({
+      name: 'InventoryRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      faults: { isEnabled: true, rules, environments: ['production'] },
+    });
+    const rs = await production.getNetworkService().get({
+      url: production.getRequestUrl({ paths: ['stocks'] }),
+    });
+    expect(rs.status).toBe(200);
+  });
+
+  test('TC-003: injects nothing unless switched on', async () => {
+    const injector = new FaultInjector({ rules: [{ rate: 1, fault: 'reset' }] });
+    expect(injector.isEnabled()).toBe(false);
+    expect(injector.pick({ method: 'get', url: server.getBaseUrl() })).toBeUndefined();
+
+    const environment = process.env.NODE_ENV;
+    delete process.env.NODE_ENV;
+    try {
+      const unset = new FaultInjector({ isEnabled: true, rules: [{ rate: 1, fault: 'reset' }] });
+      expect(unset.isEnabled()).toBe(false);
+    } finally {
+      process.env.NODE_ENV = environment;
+    }
+  });
+});
@@ -0,0 +1,105 @@
+/**
+ * Fetcher Headers Test Suite
+ *
+ * Tests the headers sent by the fetchers:
+ * 1. Default `User-Agent` and its overrides
+ * 2. Merge policies between default and per-request headers
+ *
+ * @module __tests__/network/fetcher-headers
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import {
+  FetcherDefaults,
+  HeaderErrorCodes,
+  HeaderMergePolicies,
+  HttpHeaders,
+  NodeFetchNetworkRequest,
+} from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('Fetcher headers', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ path: '/echo' }).respond({ status: 204 });
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  const send = async (opts: { userAgent?: string | false; headers?: Record<string, string> }) => {
+    const request = new NodeFetchNetworkRequest({
+      name: 'EchoRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      userAgent: opts.userAgent,
+    });
+
+    await request.getNetworkService().get({
+      url: request.getRequestUrl({ paths: ['/echo'] }),
+      headers: opts.headers,
+    });
+
+    return server.requests[server.requests.length - 1].headers;
+  };
+
+  test('TC-001: sends a default user agent with overrides', async () => {
+    const version = FetcherDefaults.getVersion();
+
+    expect((await send({}))['user-agent']).toBe(`ignis/${version} (EchoRequest)`);
+    expect((await send({}))['content-type']).toBe('application/json; charset=utf-8');
+    expect((await send({ userAgent: 'orders/1.0' }))['user-agent']).toBe('orders/1.0');
+    expect((await send({ headers: { 'User-Agent': 'cli/2.0' } }))['user-agent']).toBe('cli/2.0');
+    expect((await send({ userAgent: false }))['user-agent']).not.toContain('ignis/');
+  });
+
+  test('TC-002: merges headers with the configured policy', () => {
+    const defaults = {
+      Accept: 'application/json',
+      'Content-Type': 'application/json',
+      cookie: 'a=1',
+    };
+    const headers = { accept: 'text/csv', 'content-type': 'text/plain', Cookie: ['b=2', 'c=3'] };
+
+    expect(HttpHeaders.merge({ defaults, headers })).toEqual({
+      accept: 'text/csv',
+      'content-type': 'text/plain',
+      cookie: 'b=2; c=3',
+    });
+
+    expect(HttpHeaders.merge({ defaults, headers, policy: HeaderMergePolicies.APPEND })).toEqual({
+      accept: 'application/json, text/csv',
+      // Single value headers are replaced
+      'content-type': 'text/plain',
+      cookie: 'a=1; b=2; c=3',
+    });
+
+    const conflicting = { defaults, headers: { ACCEPT: '*/*' }, policy: HeaderMergePolicies.ERROR };
+    expect(() => HttpHeaders.merge(conflicting)).toThrow('name: accept');
+  });
+
+  test('TC-003: applies the per-request merge policy', async () => {
+    const request = new NodeFetchNetworkRequest({
+      name: 'EchoRequest',
+      networkOptions: { baseUrl: server.getBaseUrl(), headers: { 'x-feature': 'a' } },
+      headerMerge: HeaderMergePolicies.ERROR,
+    });
+    const url = request.getRequestUrl({ paths: ['/echo'] });
+
+    await request.getNetworkService().get({
+      url,
+      headers: [
+        ['X-Feature', 'b'],
+        ['X-Feature', 'c'],
+      ],
+      headerMerge: HeaderMergePolicies.APPEND,
+    });
+    expect(server.requests[server.requests.length - 1].headers['x-feature']).toBe('a, b, c');
+
+    await expect(
+      request.getNetworkService().get({ url, headers: { 'x-feature': 'b' } }),
+    ).rejects.toMatchObject({ messageCode: HeaderErrorCodes.HEADER_CONFLICT });
+  });
+});
@@ -0,0 +1,116 @@
+/**
+ * Fetcher Timeouts Test Suite
+ *
+ * Tests the connect, read and total timeouts of NodeFetcher and AxiosFetcher:
+ * 1. Connect timeouts fail each attempt and are retried
+ * 2. The total timeout spans retries and is not retried
+ * 3. Read timeouts fail bodies idle for too long
+ * 4. Axios connect timeouts stop once the body streams, slow downloads complete
+ *
+ * @module __tests__/network/fetcher-timeouts
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import http from 'node:http';
+import { AddressInfo } from 'node:net';
+import { NodeFetchNetworkRequest, TimeoutErrorCodes } from '@/helpers/network';
+import { AxiosNetworkRequest } from '@/helpers/network/http-request/fetcher/axios-fetcher';
+import { MockServer } from '@/helpers/testing';
+
+describe('Fetcher timeouts', () => {
+  const server = new MockServer();
+
+  // Sends the headers and a first chunk then stalls, `/report` dribbles its chunks instead
+  const stalled = http.createServer((req, res) => {
+    res.writeHead(200, { 'content-type': 'text/plain' });
+    res.write('partial');
+    if (req.url !== '/report') {
+      return;
+    }
+
+    let chunks = 0;
+    const timer = setInterval(() => {
+      res.write(' chunk');
+      if (++chunks === 5) {
+        clearInterval(timer);
+        res.end();
+      }
+    }, 30);
+  });
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ path: '/slow' }).respond({ status: 200, delay: 80 });
+    await new Promise<void>(resolve => stalled.listen(0, '127.0.0.1', () => resolve()));
+  });
+
+  afterAll(async () => {
+    await server.stop();
+    stalled.closeAllConnections();
+    await new Promise<void>(resolve => stalled.close(() => resolve()));
+  });
+
+  const createRequest = (baseUrl: string) => {
+    return new NodeFetchNetworkRequest({
+      name: 'SlowRequest',
+      networkOptions: { baseUrl },
+      connectTimeout: 30,
+      retry: { maxAttempts: 5, baseDelay: 0, budget: false },
+    });
+  };
+
+  test('TC-001: retries attempts exceeding the connect timeout', async () => {
+    server.requests = [];
+    const request = createRequest(server.getBaseUrl());
+
+    await expect(
+      request.getNetworkService().get({
+        url: request.getRequestUrl({ paths: ['/slow'] }),
+      }),
+    ).rejects.toMatchObject({ statusCode: 504, messageCode: TimeoutErrorCodes.CONNECT_TIMEOUT });
+    expect(server.requests).toHaveLength(5);
+  });
+
+  test('TC-002: stops retrying once the total timeout passes', async () => {
+    server.requests = [];
+    const request = createRequest(server.getBaseUrl());
+
+    await expect(
+      request.getNetworkService().get({
+        url: request.getRequestUrl({ paths: ['/slow'] }),
+        totalTimeout: 70,
+      }),
+    ).rejects.toMatchObject({ messageCode: TimeoutErrorCodes.TOTAL_TIMEOUT });
+    expect(server.requests.length).toBeLessThan(5);
+  });
+
+  test('TC-003: fails bodies idle past the read timeout', async () => {
+    const { port } = stalled.address() as AddressInfo;
+    const request = createRequest(`http://127.0.0.1:${port}`);
+
+    const response = await request.getNetworkService().get({
+      url: request.getRequestUrl({ paths: ['/export'] }),
+      readTimeout: 50,
+    });
+    expect(response.status).toBe(200);
+
+    await expect(response.text()).rejects.toMatchObject({
+      messageCode: TimeoutErrorCodes.READ_TIMEOUT,
+    });
+  });
+
+  test('TC-004: stops the axios connect timeout once the body streams', async () => {
+    const { port } = stalled.address() as AddressInfo;
+    const request = new AxiosNetworkRequest({
+      name: 'ReportRequest',
+      networkOptions: { baseUrl: `http://127.0.0.1:${port}`, responseType: 'text' },
+      connectTimeout: 50,
+    });
+
+    const rs = await request.getNetworkService().get({
+      url: request.getRequestUrl({ paths: ['/report'] }),
+    });
+    expect(rs.status).toBe(200);
+    expect(rs.data).toBe(`partial${' chunk'.repeat(5)}`);
+  });
+});
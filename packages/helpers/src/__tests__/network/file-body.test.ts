@@ -0,0 +1,84 @@
+/**
+ * File Request Body Test Suite
+ *
+ * Tests FileRequestBody with the node and axios fetchers:
+ * 1. The node fetcher streams the file with its content type and length, and never retries it
+ * 2. The axios fetcher streams the file with its content type and length, and never retries it
+ *
+ * @module __tests__/network/file-body
+ */
+
+import { describe, test, expect, afterAll, beforeAll, beforeEach } from 'bun:test';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import { FileRequestBody, NodeFetchNetworkRequest } from '@/helpers/network';
+import { AxiosNetworkRequest } from '@/helpers/network/http-request/fetcher/axios-fetcher';
+import { MockServer } from '@/helpers/testing';
+
+describe('FileRequestBody', () => {
+  const server = new MockServer();
+  const directory = fs.mkdtempSync(path.join(os.tmpdir(), 'ignis-file-body-'));
+  const filePath = path.join(directory, 'export.csv');
+  const content = 'sku,quantity\n'.concat('sku-1,10\n'.repeat(32 * 1024));
+
+  // Replayable bodies would be retried on the 503
+  const retry = { maxAttempts: 3, baseDelay: 1 };
+
+  beforeAll(async () => {
+    fs.writeFileSync(filePath, content);
+    await server.start();
+    server.when({ method: 'PUT', path: '/exports' }).respond({ status: 503 });
+  });
+
+  beforeEach(() => {
+    server.requests = [];
+  });
+
+  afterAll(async () => {
+    await server.stop();
+    fs.rmSync(directory, { recursive: true, force: true });
+  });
+
+  const expectStreamed = () => {
+    expect(server.requests).toHaveLength(1);
+
+    const [received] = server.requests;
+    expect(received.headers['content-type']).toBe('text/csv');
+    expect(received.headers['content-length']).toBe(`${Buffer.byteLength(content)}`);
+    expect(received.rawBody).toBe(content);
+  };
+
+  test('TC-001: streams the file with the node fetcher', async () => {
+    const body = await FileRequestBody.fromPath({ path: filePath, contentType: 'text/csv' });
+    const request = new NodeFetchNetworkRequest({
+      name: 'ExportRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      retry,
+    });
+
+    const rs = await request.getNetworkService().put({
+      url: request.getRequestUrl({ paths: ['exports'] }),
+      body,
+    });
+    expect(rs.status).toBe(503);
+    expectStreamed();
+  });
+
+  test('TC-002: streams the file with the axios fetcher', async () => {
+    const body = await FileRequestBody.fromPath({ path: filePath, contentType: 'text/csv' });
+    const request = new AxiosNetworkRequest({
+      name: 'ExportRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      retry,
+    });
+
+    // Rejected by the default `validateStatus` of axios
+    const error = await request
+      .getNetworkService()
+      .put({ url: request.getRequestUrl({ paths: ['exports'] }), body })
+      .catch(error => error);
+    expect(error.response?.status).toBe(503);
+    expectStreamed();
+  });
+});
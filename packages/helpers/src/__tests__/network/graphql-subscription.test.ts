@@ -0,0 +1,158 @@
+/**
+ * GraphQL Subscription Test Suite
+ *
+ * Tests GraphQLSubscriptionClient against a `graphql-transport-ws` server:
+ * 1. The auth payload is sent with `connection_init` and results are streamed until complete
+ * 2. Active subscriptions are sent again after a reconnection
+ * 3. Fatal close codes fail the subscriptions without reconnecting
+ *
+ * @module __tests__/network/graphql-subscription
+ */
+
+import { describe, test, expect, afterAll, beforeEach } from 'bun:test';
+import type { ServerWebSocket } from 'bun';
+import {
+  GraphQLErrorCodes,
+  GraphQLSubscriptionClient,
+  GraphQLWsDefaults,
+  IGraphQLResult,
+} from '@/helpers/network';
+
+type TCounter = { counter: { count: number } };
+
+describe('GraphQLSubscriptionClient', () => {
+  let connections = 0;
+  let inits: Array<unknown> = [];
+  let subscribes: Array<string> = [];
+  let onSubscribe: (ws: ServerWebSocket<unknown>, id: string) => void = () => {};
+
+  const server = Bun.serve({
+    port: 0,
+    fetch(request, srv) {
+      const headers = { 'sec-websocket-protocol': GraphQLWsDefaults.PROTOCOL };
+      if (srv.upgrade(request, { headers })) {
+        return undefined;
+      }
+
+      return new Response('Upgrade required', { status: 426 });
+    },
+    websocket: {
+      open() {
+        connections++;
+      },
+      message(ws, raw) {
+        const message = JSON.parse(String(raw));
+
+        switch (message.type) {
+          case 'connection_init': {
+            inits.push(message.payload);
+            if (message.payload?.authorization !== 'Bearer t1') {
+              ws.close(4403, 'Forbidden');
+              return;
+            }
+
+            ws.send(JSON.stringify({ type: 'connection_ack' }));
+            return;
+          }
+          case 'ping': {
+            ws.send(JSON.stringify({ type: 'pong' }));
+            return;
+          }
+          case 'subscribe': {
+            subscribes.push(message.id);
+            onSubscribe(ws, message.id);
+            return;
+          }
+          default: {
+            return;
+          }
+        }
+      },
+    },
+  });
+
+  const url = `ws://localhost:${server.port}/graphql`;
+  const next = (id: string, count: number) =>
+    JSON.stringify({ type: 'next', id, payload: { data: { counter: { count } } } });
+
+  const collect = async (results: AsyncIterable<IGraphQLResult<TCounter>>) => {
+    const counts: Array<number | undefined> = [];
+    for await (const { data } of results) {
+      counts.push(data?.counter.count);
+    }
+    return counts;
+  };
+
+  beforeEach(() => {
+    connections = 0;
+    inits = [];
+    subscribes = [];
+  });
+
+  afterAll(() => {
+    server.stop(true);
+  });
+
+  const query = 'subscription { counter { count } }';
+
+  test('TC-001: sends the auth payload and streams results until complete', async () => {
+    onSubscribe = (ws, id) => {
+      ws.send(next(id, 1));
+      ws.send(next(id, 2));
+      ws.send(JSON.stringify({ type: 'complete', id }));
+    };
+
+    const client = new GraphQLSubscriptionClient({
+      url,
+      connectionParams: { authorization: 'Bearer t1' },
+    });
+
+    expect(await collect(client.subscribe<TCounter>({ query }))).toEqual([1, 2]);
+    expect(inits).toEqual([{ authorization: 'Bearer t1' }]);
+    client.dispose();
+  });
+
+  test('TC-002: subscribes again after a reconnection', async () => {
+    onSubscribe = (ws, id) => {
+      if (connections === 1) {
+        ws.send(next(id, 1));
+        ws.close(1012, 'Service Restart');
+        return;
+      }
+
+      ws.send(next(id, 2));
+      ws.send(JSON.stringify({ type: 'complete', id }));
+    };
+
+    let params = 0;
+    const client = new GraphQLSubscriptionClient({
+      url,
+      connectionParams: () => {
+        params++;
+        return { authorization: 'Bearer t1' };
+      },
+      reconnect: { baseDelay: 1 },
+    });
+
+    const subscription = client.subscribe<TCounter>({ query });
+    expect(await collect(subscription)).toEqual([1, 2]);
+    expect(connections).toBe(2);
+    expect(params).toBe(2);
+    expect(subscribes).toEqual([subscription.id, subscription.id]);
+    client.dispose();
+  });
+
+  test('TC-003: fails the subscriptions on fatal close codes', async () => {
+    const client = new GraphQLSubscriptionClient({
+      url,
+      connectionParams: { authorization: 'Bearer expired' },
+      reconnect: { baseDelay: 1 },
+    });
+
+    await expect(collect(client.subscribe<TCounter>({ query }))).rejects.toMatchObject({
+      messageCode: GraphQLErrorCodes.CONNECTION_CLOSED,
+    });
+    expect(connections).toBe(1);
+    client.dispose();
+  });
+});
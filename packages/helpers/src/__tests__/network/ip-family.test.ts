@@ -0,0 +1,86 @@
+/**
+ * IP Family Test Suite
+ *
+ * Tests IpFamilySelector and the IP family of the node fetcher:
+ * 1. Requests connect to the address of the preferred family, the host header keeps the name
+ * 2. Raced addresses fall back past a stalled one, the winner is reused for its host
+ *
+ * @module __tests__/network/ip-family
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import { IResolvedAddress, IpFamilySelector, NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('IpFamilySelector', () => {
+  const server = new MockServer();
+  let port: string;
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ path: '/health' }).respond({ json: { status: 'ok' } });
+    port = new URL(server.getBaseUrl()).port;
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: connects to the address of the preferred family', async () => {
+    const lookups: Array<unknown> = [];
+    const request = new NodeFetchNetworkRequest({
+      name: 'CarrierRequest',
+      networkOptions: { baseUrl: `http://carrier.test:${port}` },
+      ipFamily: {
+        preference: 'ipv4',
+        lookup: async opts => {
+          lookups.push(opts);
+          return [{ address: '127.0.0.1', family: 4 }];
+        },
+      },
+    });
+
+    const rs = await request.getNetworkService().get({
+      url: request.getRequestUrl({ paths: ['health'] }),
+    });
+    expect(rs.status).toBe(200);
+    expect(lookups).toEqual([{ hostname: 'carrier.test', family: 4 }]);
+    expect(server.requests.at(-1)?.headers.host).toBe(`carrier.test:${port}`);
+
+    expect(IpFamilySelector.from('ipv6')?.getAgentOptions()).toEqual({ family: 6 });
+  });
+
+  test('TC-002: races past a stalled address and reuses the winner', async () => {
+    let lookups = 0;
+    const addresses: Array<IResolvedAddress> = [
+      // Discard prefix (RFC 6666), never connects
+      { address: '100::1', family: 6 },
+      { address: '127.0.0.1', family: 4 },
+    ];
+    const selector = new IpFamilySelector({
+      preference: 'race',
+      attemptDelay: 50,
+      lookup: async () => {
+        lookups++;
+        return addresses;
+      },
+    });
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'CarrierRequest',
+      networkOptions: { baseUrl: `http://carrier.test:${port}` },
+      ipFamily: selector,
+    });
+    const service = request.getNetworkService();
+
+    for (let i = 0; i < 2; i++) {
+      const rs = await service.get({ url: request.getRequestUrl({ paths: ['health'] }) });
+      expect(rs.status).toBe(200);
+    }
+    expect(lookups).toBe(1);
+    expect(selector.getAgentOptions()).toEqual({
+      autoSelectFamily: true,
+      autoSelectFamilyAttemptTimeout: 50,
+    });
+  });
+});
@@ -0,0 +1,90 @@
+/**
+ * JSON Array Stream Test Suite
+ *
+ * Tests JsonArrayParser and the JSON array streams of the node fetcher:
+ * 1. Items parsed whatever the chunk boundaries, strings and nested values included
+ * 2. Items of a response streamed and validated, malformed or truncated arrays rejected
+ *
+ * @module __tests__/network/json-stream
+ */
+
+import { describe, test, expect, afterAll } from 'bun:test';
+import { z } from '@hono/zod-openapi';
+import {
+  JsonArrayParser,
+  JsonArrayStreamErrorCodes,
+  NodeFetchNetworkRequest,
+} from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+const ITEMS = [
+  { sku: 'SKU-1', name: 'Tee, "classic" [white]', tags: ['a', 'b'] },
+  { sku: 'SKU-2', name: 'Mug \\ {blue}', tags: [] },
+  { sku: 'SKU-3', name: 'Cap', tags: [{ nested: true }] },
+];
+
+describe('JsonArrayParser', () => {
+  test('TC-001: parses items whatever the chunk boundaries', () => {
+    const json = ` ${JSON.stringify(ITEMS, null, 2)}\n`;
+
+    for (const size of [1, 2, 7, json.length]) {
+      const parser = new JsonArrayParser();
+      const items: Array<unknown> = [];
+      for (let i = 0; i < json.length; i += size) {
+        items.push(...parser.write(json.slice(i, i + size)));
+      }
+      parser.end();
+
+      expect(items).toEqual(ITEMS);
+    }
+
+    const empty = new JsonArrayParser();
+    expect(empty.write('[ ]')).toEqual([]);
+    expect(() => empty.end()).not.toThrow();
+
+    expect(() => new JsonArrayParser().write('{"data":[]}')).toThrow('Invalid JSON array');
+    expect(() => new JsonArrayParser().write('[1,,2]')).toThrow('missing item at index 1');
+  });
+});
+
+describe('NodeFetcher.streamJsonArray', () => {
+  const server = new MockServer();
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-002: streams validated items and rejects truncated arrays', async () => {
+    await server.start();
+    server.when({ path: '/products' }).respond({ body: JSON.stringify(ITEMS) });
+    server.when({ path: '/truncated' }).respond({ body: JSON.stringify(ITEMS).slice(0, -20) });
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'CatalogRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+    });
+    const service = request.getNetworkService();
+    const schema = z.object({ sku: z.string(), name: z.string() });
+
+    const skus: Array<string> = [];
+    for await (const product of service.streamJsonArray({
+      url: request.getRequestUrl({ paths: ['products'] }),
+      schema,
+    })) {
+      skus.push(product.sku);
+    }
+    expect(skus).toEqual(['SKU-1', 'SKU-2', 'SKU-3']);
+
+    const read: Array<unknown> = [];
+    const truncated = async () => {
+      const url = request.getRequestUrl({ paths: ['truncated'] });
+      for await (const item of service.streamJsonArray({ url })) {
+        read.push(item);
+      }
+    };
+    await expect(truncated()).rejects.toMatchObject({
+      messageCode: JsonArrayStreamErrorCodes.INVALID_JSON,
+    });
+    expect(read).toHaveLength(2);
+  });
+});
@@ -0,0 +1,87 @@
+/**
+ * Key Case Test Suite
+ *
+ * Tests KeyCaseConverter and the key case of the fetchers:
+ * 1. Keys converted between camel, snake and kebab case, nested values included
+ * 2. Params and JSON bodies sent in the case of the fetcher, per-request `preserve`
+ * 3. Params and object bodies of axios sent in the case of the fetcher
+ *
+ * @module __tests__/network/key-case
+ */
+
+import { describe, test, expect, afterAll } from 'bun:test';
+import { KeyCaseConverter, KeyCases, NodeFetchNetworkRequest, TKeyCase } from '@/helpers/network';
+import { AxiosNetworkRequest } from '@/helpers/network/http-request/fetcher/axios-fetcher';
+import { MockServer } from '@/helpers/testing';
+
+describe('KeyCaseConverter', () => {
+  test('TC-001: converts keys at any depth and keeps other values', () => {
+    const convert = (key: string, to: TKeyCase) => KeyCaseConverter.convertKey({ key, to });
+
+    expect(convert('order_id', KeyCases.CAMEL)).toBe('orderId');
+    expect(convert('shipping-address', KeyCases.CAMEL)).toBe('shippingAddress');
+    expect(convert('HTTPStatusCode', KeyCases.SNAKE)).toBe('http_status_code');
+    expect(convert('_id', KeyCases.CAMEL)).toBe('_id');
+
+    const createdAt = new Date();
+    expect(
+      KeyCaseConverter.convertKeys({
+        value: { lineItems: [{ unitPrice: 1 }], createdAt },
+        to: KeyCases.KEBAB,
+      }),
+    ).toEqual({ 'line-items': [{ 'unit-price': 1 }], 'created-at': createdAt });
+  });
+});
+
+describe('Fetcher key case', () => {
+  const server = new MockServer();
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-002: sends params and JSON bodies in the case of the fetcher', async () => {
+    await server.start();
+    server.when({ path: '/orders' }).respond({ status: 204 });
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'OrderRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      keyCase: KeyCases.CAMEL,
+    });
+    const url = request.getRequestUrl({ paths: ['/orders'] });
+    const service = request.getNetworkService();
+
+    await service.post({
+      url,
+      params: { dry_run: true },
+      headers: { ['content-type']: 'application/json' },
+      body: JSON.stringify({ customer_id: 42, line_items: [{ unit_price: 1 }] }),
+    });
+    await service.get({ url, params: { page_size: 10 }, keyCase: KeyCases.PRESERVE });
+
+    const [created, listed] = server.requests;
+    expect(created.query).toEqual({ dryRun: 'true' });
+    expect(created.body).toEqual({ customerId: 42, lineItems: [{ unitPrice: 1 }] });
+    expect(listed.query).toEqual({ page_size: '10' });
+  });
+
+  test('TC-003: sends axios params and object bodies in the case of the fetcher', async () => {
+    server.requests = [];
+    const request = new AxiosNetworkRequest({
+      name: 'OrderRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      keyCase: KeyCases.SNAKE,
+    });
+
+    await request.getNetworkService().post({
+      url: request.getRequestUrl({ paths: ['/orders'] }),
+      params: { dryRun: true },
+      body: { customerId: 42, lineItems: [{ unitPrice: 1 }] },
+    });
+
+    const [created] = server.requests;
+    expect(created.query).toEqual({ dry_run: 'true' });
+    expect(created.body).toEqual({ customer_id: 42, line_items: [{ unit_price: 1 }] });
+  });
+});
@@ -0,0 +1,97 @@
+/**
+ * OpenAPI Client Generator Test Suite
+ *
+ * Tests OpenApiClientGenerator:
+ * 1. Component schemas are converted to TypeScript types
+ * 2. Operations become typed methods calling through the network request
+ *
+ * @module __tests__/network/openapi-client
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { IOpenApiDocument, OpenApiClientGenerator } from '@/helpers/network';
+
+const DOCUMENT: IOpenApiDocument = {
+  openapi: '3.1.0',
+  info: { title: 'Order Service', version: '1.2.0' },
+  components: {
+    schemas: {
+      Order: {
+        type: 'object',
+        required: ['id', 'status'],
+        properties: {
+          id: { type: 'string' },
+          status: { enum: ['draft', 'paid'] },
+          note: { type: ['string', 'null'] },
+          items: { type: 'array', items: { $ref: '#/components/schemas/order-item' } },
+        },
+      },
+      'order-item': {
+        type: 'object',
+        properties: { 'sku-code': { type: 'string' }, quantity: { type: 'integer' } },
+        additionalProperties: { type: 'number' },
+      },
+    },
+  },
+  paths: {
+    '/orders/{id}': {
+      parameters: [{ name: 'id', in: 'path', required: true, schema: { type: 'string' } }],
+      get: {
+        operationId: 'get-order-by-id',
+        summary: 'Find an order',
+        parameters: [{ name: 'expand', in: 'query', schema: { type: 'boolean' } }],
+        responses: {
+          '200': {
+            content: { 'application/json': { schema: { $ref: '#/components/schemas/Order' } } },
+          },
+        },
+      },
+      delete: { responses: { '204': { description: 'Deleted' } } },
+    },
+    '/orders': {
+      post: {
+        operationId: 'createOrder',
+        requestBody: {
+          required: true,
+          content: { 'application/json': { schema: { $ref: '#/components/schemas/Order' } } },
+        },
+        responses: {
+          '201': {
+            content: { 'application/json': { schema: { $ref: '#/components/schemas/Order' } } },
+          },
+        },
+      },
+    },
+  },
+};
+
+describe('OpenApiClientGenerator', () => {
+  const source = OpenApiClientGenerator.generate({
+    document: DOCUMENT,
+    className: 'OrderServiceClient',
+  });
+
+  test('TC-001: generates component types', () => {
+    expect(source).toContain('export type TOrder = {');
+    expect(source).toContain(`  status: "draft" | "paid";`);
+    expect(source).toContain('  note?: string | null;');
+    expect(source).toContain('  items?: Array<TOrderItem>;');
+    expect(source).toContain(`  'sku-code'?: string;`);
+    expect(source).toContain('  [key: string]: number;');
+  });
+
+  test('TC-002: generates typed methods for every operation', () => {
+    expect(source).toContain('export class OrderServiceClient extends NodeFetchNetworkRequest {');
+    expect(source).toContain(
+      'async getOrderById(opts: { path: { id: string }; query?: { expand?: boolean }; ' +
+        'headers?: Record<string, string> }): Promise<TOrder> {',
+    );
+    expect(source).toContain(
+      'url: this.getRequestUrl({ paths: [`/orders/${encodeURIComponent(String(opts.path.id))}`] })',
+    );
+    expect(source).toContain('params: opts.query,');
+    expect(source).toContain('): Promise<void> {');
+    expect(source).toContain('async createOrder(opts: { body: TOrder;');
+    expect(source).toContain('body: opts.body === undefined ? undefined : JSON.stringify(opts.body),');
+  });
+});
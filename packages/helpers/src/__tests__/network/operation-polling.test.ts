@@ -0,0 +1,91 @@
+/**
+ * Operation Polling Test Suite
+ *
+ * Tests OperationPolling:
+ * 1. The status url of a `202 Accepted` is polled until the operation completes
+ * 2. `Retry-After` delays, as seconds or HTTP dates
+ * 3. Operations still pending after the timeout fail with a 504
+ *
+ * @module __tests__/network/operation-polling
+ */
+
+import { describe, test, expect, afterAll, beforeAll, afterEach } from 'bun:test';
+import {
+  NodeFetchNetworkRequest,
+  OperationPolling,
+  OperationPollingErrorCodes,
+} from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('OperationPolling', () => {
+  const server = new MockServer();
+  const request = new NodeFetchNetworkRequest({ name: 'ExportRequest', networkOptions: {} });
+  const service = request.getNetworkService();
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  afterEach(() => {
+    server.reset();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: polls the status url of an accepted operation until it completes', async () => {
+    let polls = 0;
+    server.when({ method: 'POST', path: '/exports' }).respond({
+      status: 202,
+      headers: { location: '/exports/1/status', ['retry-after']: '0' },
+    });
+    server.when({ method: 'GET', path: '/exports/1/status' }).respond(() => {
+      polls++;
+      return polls < 3
+        ? { status: 202, json: { status: 'running' } }
+        : { json: { status: 'Succeeded', fileUrl: '/files/1.csv' } };
+    });
+
+    const accepted = await service.post({ url: `${server.getBaseUrl()}/exports` });
+    const { result, polls: count } = await service.pollOperation<{ fileUrl: string }>({
+      accepted,
+      initialDelay: 1,
+    });
+
+    expect(result.fileUrl).toBe('/files/1.csv');
+    expect(count).toBe(3);
+    expect(server.requests.filter(rq => rq.method === 'GET')).toHaveLength(3);
+  });
+
+  test('TC-002: reads Retry-After as seconds or HTTP dates', () => {
+    const now = Date.parse('2026-01-01T00:00:00Z');
+    const getRetryAfter = (value: string) =>
+      OperationPolling.getRetryAfter({
+        response: new Response(null, { headers: { ['retry-after']: value } }),
+        now,
+      });
+
+    expect(getRetryAfter('3')).toBe(3_000);
+    expect(getRetryAfter('Thu, 01 Jan 2026 00:00:05 GMT')).toBe(5_000);
+    expect(getRetryAfter('soon')).toBeUndefined();
+  });
+
+  test('TC-003: fails with a 504 when the operation outlives the timeout', async () => {
+    server.when({ method: 'GET', path: '/exports/2/status' }).respond({
+      status: 202,
+      json: { status: 'running' },
+    });
+
+    const poll = service.pollOperation({
+      url: `${server.getBaseUrl()}/exports/2/status`,
+      initialDelay: 10,
+      timeout: 50,
+    });
+
+    await expect(poll).rejects.toMatchObject({
+      statusCode: 504,
+      messageCode: OperationPollingErrorCodes.TIMEOUT,
+    });
+  });
+});
@@ -0,0 +1,120 @@
+/**
+ * Outbound Audit Test Suite
+ *
+ * Tests the audit trail of fetcher requests:
+ * 1. Mutating requests recorded with their payload hash, status and correlation id, reads skipped
+ * 2. Rejected and failed requests recorded as failures
+ * 3. Requests of a bare NodeFetcher recorded through its pipeline
+ *
+ * @module __tests__/network/outbound-audit
+ */
+
+import { describe, test, expect, afterAll, beforeAll, beforeEach } from 'bun:test';
+import C from 'node:crypto';
+import { AuditLogger, MemoryAuditSink } from '@/helpers/audit';
+import { NodeFetcher, NodeFetchNetworkRequest } from '@/helpers/network';
+import { RequestContextStorage } from '@/helpers/request-context';
+import { MockServer, MockServerFaults } from '@/helpers/testing';
+
+describe('OutboundAudit', () => {
+  const server = new MockServer();
+  const sink = new MemoryAuditSink();
+  let request: NodeFetchNetworkRequest;
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ method: 'POST', path: '/charges' }).respond({ status: 201, json: {} });
+    server.when({ method: 'GET', path: '/charges/ch_1' }).respond({ json: { id: 'ch_1' } });
+    server.when({ method: 'POST', path: '/refunds' }).respond({ status: 422, json: {} });
+    server.when({ method: 'DELETE', path: '/charges/ch_2' }).respond({
+      fault: MockServerFaults.CONNECTION_RESET,
+    });
+
+    request = new NodeFetchNetworkRequest({
+      name: 'PaymentRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      audit: {
+        auditLogger: new AuditLogger({ sinks: [sink] }),
+        resourceType: 'payment-provider',
+      },
+    });
+  });
+
+  beforeEach(() => {
+    sink.clear();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: records mutating requests and skips reads', async () => {
+    const body = JSON.stringify({ amount: 1_000, currency: 'EUR' });
+    const service = request.getNetworkService();
+
+    await RequestContextStorage.run({
+      context: { requestId: 'rq-audit' },
+      task: async () => {
+        await service.post({
+          url: request.getRequestUrl({ paths: ['charges'] }),
+          params: { expand: 'customer' },
+          body,
+        });
+        await service.get({ url: request.getRequestUrl({ paths: ['charges', 'ch_1'] }) });
+      },
+    });
+
+    expect(sink.events).toHaveLength(1);
+    const [event] = sink.events;
+    expect(event).toMatchObject({
+      action: 'http.post',
+      outcome: 'success',
+      correlationId: 'rq-audit',
+      resource: { type: 'payment-provider', id: `${server.getBaseUrl()}/charges` },
+      metadata: {
+        fetcher: 'PaymentRequest',
+        status: 201,
+        payloadHash: C.createHash('sha256').update(body).digest('hex'),
+      },
+    });
+    expect(event.metadata?.duration).toBeGreaterThanOrEqual(0);
+  });
+
+  test('TC-002: records rejected and failed requests as failures', async () => {
+    const service = request.getNetworkService();
+
+    await service.post({ url: request.getRequestUrl({ paths: ['refunds'] }), body: '{}' });
+    await expect(
+      service.delete({ url: request.getRequestUrl({ paths: ['charges', 'ch_2'] }) }),
+    ).rejects.toThrow();
+
+    const outcomes = sink.events.map(({ action, outcome, metadata }) => [
+      action,
+      outcome,
+      metadata?.status,
+    ]);
+    expect(outcomes).toEqual([
+      ['http.post', 'failure', 422],
+      ['http.delete', 'failure', undefined],
+    ]);
+    expect(sink.events[1].metadata?.error).toBeDefined();
+  });
+
+  test('TC-003: records the requests of a NodeFetcher', async () => {
+    const fetcher = new NodeFetcher({
+      name: 'PaymentFetcher',
+      defaultConfigs: {},
+      audit: { auditLogger: new AuditLogger({ sinks: [sink] }) },
+    });
+
+    const rs = await fetcher.post({ url: `${server.getBaseUrl()}/charges`, body: '{}' });
+    expect(rs.status).toBe(201);
+    expect(sink.events).toHaveLength(1);
+    expect(sink.events[0]).toMatchObject({
+      action: 'http.post',
+      outcome: 'success',
+      resource: { type: 'http-endpoint', id: `${server.getBaseUrl()}/charges` },
+      metadata: { fetcher: 'PaymentFetcher', status: 201 },
+    });
+  });
+});
@@ -0,0 +1,68 @@
+/**
+ * Payload Metrics Test Suite
+ *
+ * Tests PayloadMetrics:
+ * 1. Fetchers record request and response body sizes per endpoint
+ * 2. Endpoints collapse ids and body sizes are resolved without reading streams
+ *
+ * @module __tests__/network/payload-metrics
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import { MetricsRegistry } from '@/helpers/metrics';
+import { NodeFetchNetworkRequest, PayloadMetrics } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('PayloadMetrics', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ method: 'PUT', path: '/orders/:id' }).respond({ body: 'x'.repeat(300) });
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: records body sizes per endpoint', async () => {
+    const registry = new MetricsRegistry();
+    const request = new NodeFetchNetworkRequest({
+      name: 'OrderRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      payloadMetrics: { registry, warnThreshold: 256 },
+    });
+
+    for (const id of [41, 42]) {
+      const response = await request.getNetworkService().put({
+        url: request.getRequestUrl({ paths: [`/orders/${id}`] }),
+        body: JSON.stringify({ status: 'paid' }),
+      });
+      await response.text();
+    }
+
+    const host = new URL(server.getBaseUrl()).host;
+    const labels = `{fetcher="OrderRequest",method="PUT",endpoint="${host}/orders/:id"}`;
+    const body = await registry.metrics();
+
+    expect(body).toContain(`http_client_request_size_bytes_count${labels} 2`);
+    expect(body).toContain(`http_client_request_size_bytes_sum${labels} 34`);
+    expect(body).toContain(`http_client_response_size_bytes_sum${labels} 600`);
+  });
+
+  test('TC-002: resolves endpoints and body sizes', () => {
+    expect(
+      PayloadMetrics.getEndpoint(
+        'https://catalog.internal/products/42/variants/3f2b7c1e-9d4a-4e1b-8c2d-5a6f7b8c9d0e?a=1',
+      ),
+    ).toBe('catalog.internal/products/:id/variants/:id');
+    expect(PayloadMetrics.getEndpoint('/users/65a1f0c2e4b0a1b2c3d4e5f6/avatar')).toBe(
+      '/users/:id/avatar',
+    );
+
+    expect(PayloadMetrics.getBodySize('héllo')).toBe(6);
+    expect(PayloadMetrics.getBodySize(new Uint8Array(16))).toBe(16);
+    expect(PayloadMetrics.getBodySize({ id: 1 })).toBe(8);
+    expect(PayloadMetrics.getBodySize(new ReadableStream())).toBeUndefined();
+  });
+});
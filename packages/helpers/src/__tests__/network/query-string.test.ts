@@ -0,0 +1,59 @@
+/**
+ * Query String Test Suite
+ *
+ * Tests QueryStrings and the query array format of the fetchers:
+ * 1. Array params serialized with every format
+ * 2. Per-request format overriding the fetcher default
+ *
+ * @module __tests__/network/query-string
+ */
+
+import { describe, test, expect, afterAll } from 'bun:test';
+import { NodeFetchNetworkRequest, QueryArrayFormats, QueryStrings } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('QueryStrings', () => {
+  const params = { ids: [1, 2], q: 'a b' };
+
+  test('TC-001: serializes arrays with the chosen format', () => {
+    expect(QueryStrings.stringify({ params })).toBe('ids=1&ids=2&q=a%20b');
+    expect(QueryStrings.stringify({ params, arrayFormat: QueryArrayFormats.COMMA })).toBe(
+      'ids=1%2C2&q=a%20b',
+    );
+    expect(QueryStrings.stringify({ params, arrayFormat: QueryArrayFormats.BRACKETS })).toBe(
+      'ids%5B%5D=1&ids%5B%5D=2&q=a%20b',
+    );
+  });
+});
+
+describe('Fetcher query array format', () => {
+  const server = new MockServer();
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-002: applies the fetcher and per-request formats', async () => {
+    await server.start();
+    server.when({ path: '/orders' }).respond({ status: 204 });
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'OrderRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      queryArrayFormat: QueryArrayFormats.COMMA,
+    });
+    const url = request.getRequestUrl({ paths: ['/orders'] });
+
+    await request.getNetworkService().get({ url, params: { status: ['paid', 'shipped'] } });
+    await request.getNetworkService().get({
+      url,
+      params: { status: ['paid', 'shipped'] },
+      queryArrayFormat: QueryArrayFormats.BRACKETS,
+    });
+
+    expect(server.requests.map(({ query }) => query)).toEqual([
+      { status: 'paid,shipped' },
+      { 'status[]': 'shipped' },
+    ]);
+  });
+});
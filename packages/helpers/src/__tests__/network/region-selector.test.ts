@@ -0,0 +1,71 @@
+/**
+ * Region Selector Test Suite
+ *
+ * Tests RegionSelector and the region base url of the network requests:
+ * 1. Fastest healthy region selected after a probe round, used by the request urls
+ * 2. Failover on reported failures and failed probes
+ *
+ * @module __tests__/network/region-selector
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import { NodeFetchNetworkRequest, RegionSelector } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('RegionSelector', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  const createSelector = () =>
+    new RegionSelector({
+      regions: [
+        { name: 'eu-west-1', url: `${server.getBaseUrl()}/eu` },
+        { name: 'us-east-1', url: `${server.getBaseUrl()}/us` },
+      ],
+      unhealthyThreshold: 1,
+    });
+
+  test('TC-001: selects the fastest healthy region for the request urls', async () => {
+    server.reset();
+    server.when({ path: '/eu/health' }).respond({ status: 200, delay: 100 });
+    server.when({ path: '/us/health' }).respond({ status: 200 });
+
+    const regions = createSelector();
+    expect(regions.getSelected().name).toBe('eu-west-1');
+
+    await regions.probe();
+    expect(regions.getSelected().name).toBe('us-east-1');
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'LedgerRequest',
+      regions,
+      networkOptions: {},
+    });
+    expect(request.getRequestUrl({ paths: ['accounts'] })).toBe(
+      `${server.getBaseUrl()}/us/accounts`,
+    );
+  });
+
+  test('TC-002: fails over on reported failures and failed probes', async () => {
+    server.reset();
+    server.when({ path: '/eu/health' }).respond({ status: 503 });
+    server.when({ path: '/us/health' }).respond({ status: 200, delay: 100 });
+
+    const regions = createSelector();
+    regions.reportFailure({ url: `${server.getBaseUrl()}/eu/accounts` });
+    expect(regions.getSelected().name).toBe('us-east-1');
+
+    await regions.probe();
+    const [eu, us] = regions.getRegions();
+    expect(eu.isHealthy).toBe(false);
+    expect(us.isHealthy).toBe(true);
+    expect(regions.getSelected().name).toBe('us-east-1');
+  });
+});
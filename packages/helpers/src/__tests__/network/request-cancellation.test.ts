@@ -0,0 +1,94 @@
+/**
+ * Request Cancellation Test Suite
+ *
+ * Tests the cancellation of fetcher requests:
+ * 1. Requests aborted by their signal fail fast as cancellations, whatever the timeouts
+ * 2. Requests of a disconnected incoming request are cancelled, queued ones leave the limiter
+ *
+ * @module __tests__/network/request-cancellation
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import {
+  CancellationErrorCodes,
+  ConcurrencyLimiter,
+  NodeFetchNetworkRequest,
+} from '@/helpers/network';
+import { RequestContextStorage } from '@/helpers/request-context';
+import { MockServer } from '@/helpers/testing';
+
+describe('FetcherCancellation', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ path: '/reports/:id' }).respond({ json: { ok: true }, delay: 2_000 });
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: fails aborted requests fast as cancellations', async () => {
+    const request = new NodeFetchNetworkRequest({
+      name: 'ReportRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      totalTimeout: 5_000,
+    });
+
+    const controller = new AbortController();
+    const startedAt = Date.now();
+    setTimeout(() => controller.abort(), 50);
+
+    await expect(
+      request.getNetworkService().get({
+        url: request.getRequestUrl({ paths: ['reports', '1'] }),
+        signal: controller.signal,
+      }),
+    ).rejects.toMatchObject({
+      statusCode: 499,
+      messageCode: CancellationErrorCodes.REQUEST_CANCELLED,
+    });
+    expect(Date.now() - startedAt).toBeLessThan(1_000);
+
+    await expect(
+      request.getNetworkService().get({
+        url: request.getRequestUrl({ paths: ['reports', '2'] }),
+        signal: AbortSignal.abort(),
+      }),
+    ).rejects.toMatchObject({ messageCode: CancellationErrorCodes.REQUEST_CANCELLED });
+    expect(server.requests.filter(rq => rq.path === '/reports/2')).toHaveLength(0);
+  });
+
+  test('TC-002: cancels the requests of a disconnected incoming request', async () => {
+    const limiter = new ConcurrencyLimiter({ maxConcurrent: 1 });
+    const request = new NodeFetchNetworkRequest({
+      name: 'ReportRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      concurrency: limiter,
+      cancelOnDisconnect: true,
+    });
+    const service = request.getNetworkService();
+
+    const client = new AbortController();
+    const calls = RequestContextStorage.run({
+      context: { requestId: 'rq-1', signal: client.signal },
+      task: () =>
+        ['3', '4'].map(id =>
+          service.get({ url: request.getRequestUrl({ paths: ['reports', id] }) }),
+        ),
+    });
+
+    await new Promise(resolve => setTimeout(resolve, 50));
+    expect(limiter.getQueued()).toBe(1);
+
+    client.abort();
+    const rs = await Promise.allSettled(calls);
+    expect(rs.map(r => (r.status === 'rejected' ? r.reason.messageCode : r.status))).toEqual([
+      CancellationErrorCodes.REQUEST_CANCELLED,
+      CancellationErrorCodes.REQUEST_CANCELLED,
+    ]);
+    expect(limiter.getQueued()).toBe(0);
+    expect(limiter.getActive()).toBe(0);
+  });
+});
@@ -0,0 +1,115 @@
+/**
+ * Response Cache Test Suite
+ *
+ * Tests HttpResponseCache:
+ * 1. Expired entries are served while a background request refreshes them
+ * 2. Expired entries answer for failed refreshes during the error grace
+ * 3. Entries with an etag are revalidated with `if-none-match`
+ * 4. Entries are partitioned by the bearer token of the requests
+ *
+ * @module __tests__/network/response-cache
+ */
+
+import { describe, test, expect, afterAll, beforeAll, afterEach } from 'bun:test';
+import { HttpResponseCache, NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe('HttpResponseCache', () => {
+  const server = new MockServer();
+
+  const createRequest = (cache: HttpResponseCache) => {
+    const request = new NodeFetchNetworkRequest({
+      name: 'CatalogRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      cache,
+    });
+
+    const url = request.getRequestUrl({ paths: ['catalog'] });
+    return async () => (await request.getNetworkService().get({ url })).text();
+  };
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  afterEach(() => {
+    server.reset();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: serves stale entries while revalidating in the background', async () => {
+    let version = 0;
+    server.when({ path: '/catalog' }).respond(() => ({
+      headers: { ['cache-control']: 'max-age=0, stale-while-revalidate=60' },
+      body: `v${++version}`,
+    }));
+
+    const get = createRequest(new HttpResponseCache());
+    expect(await get()).toBe('v1');
+    expect(await get()).toBe('v1');
+
+    await sleep(50);
+    expect(server.requests).toHaveLength(2);
+    expect(await get()).toBe('v2');
+
+    // Refresh triggered by the last read
+    await sleep(50);
+  });
+
+  test('TC-002: serves stale entries when the refresh fails during the error grace', async () => {
+    let status = 200;
+    server.when({ path: '/catalog' }).respond(() => ({ status, body: `status ${status}` }));
+
+    const get = createRequest(new HttpResponseCache({ defaultTtl: 1, errorGrace: 60_000 }));
+    expect(await get()).toBe('status 200');
+
+    await sleep(5);
+    status = 503;
+    expect(await get()).toBe('status 200');
+    expect(server.requests).toHaveLength(2);
+  });
+
+  test('TC-003: revalidates entries with their etag', async () => {
+    server.when({ path: '/catalog' }).respond(request => {
+      if (request.headers['if-none-match'] === '"v1"') {
+        return { status: 304, headers: { etag: '"v1"' } };
+      }
+
+      return {
+        headers: { etag: '"v1"', ['cache-control']: 'max-age=0, stale-if-error=60' },
+        body: 'v1',
+      };
+    });
+
+    const get = createRequest(new HttpResponseCache());
+    expect(await get()).toBe('v1');
+    expect(await get()).toBe('v1');
+    expect(server.requests[1].headers['if-none-match']).toBe('"v1"');
+  });
+
+  test('TC-004: never serves the entry of a bearer token to another one', async () => {
+    server.when({ path: '/profile' }).respond(request => ({
+      headers: { ['cache-control']: 'max-age=60' },
+      body: `${request.headers.authorization}`,
+    }));
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'ProfileRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      cache: new HttpResponseCache(),
+    });
+    const url = request.getRequestUrl({ paths: ['profile'] });
+    const get = async (bearerAuth: string) =>
+      (await request.getNetworkService().get({ url, bearerAuth })).text();
+
+    expect(await get('token-a')).toBe('Bearer token-a');
+    expect(await get('token-b')).toBe('Bearer token-b');
+    expect(await get('token-a')).toBe('Bearer token-a');
+    expect(server.requests).toHaveLength(2);
+  });
+});
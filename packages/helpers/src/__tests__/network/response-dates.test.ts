@@ -0,0 +1,62 @@
+/**
+ * Response Dates Test Suite
+ *
+ * Tests ResponseDates and the date schemas of the network requests:
+ * 1. Dates read in every supported format, values of another format rejected
+ * 2. Schemas in the date format of the request, overridden per field
+ *
+ * @module __tests__/network/response-dates
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { z } from '@hono/zod-openapi';
+import {
+  NodeFetchNetworkRequest,
+  ResponseDateFormats,
+  ResponseDates,
+  TResponseDateFormat,
+} from '@/helpers/network';
+
+const EXPECTED = new Date('2026-01-31T10:00:00.000Z');
+
+describe('ResponseDates', () => {
+  test('TC-001: reads dates in every supported format', () => {
+    const parse = (value: unknown, format: TResponseDateFormat) =>
+      ResponseDates.parse({ value, format });
+
+    expect(parse('2026-01-31T17:00:00+07:00', ResponseDateFormats.ISO)).toEqual(EXPECTED);
+    expect(parse(EXPECTED.getTime(), ResponseDateFormats.EPOCH_MILLIS)).toEqual(EXPECTED);
+    expect(parse(`${EXPECTED.getTime() / 1000}`, ResponseDateFormats.EPOCH_SECONDS)).toEqual(
+      EXPECTED,
+    );
+    expect(parse('2026-01-31 10:00:00', ResponseDateFormats.SQL_DATETIME)).toEqual(EXPECTED);
+    expect(parse('Sat, 31 Jan 2026 10:00:00 +0000', ResponseDateFormats.RFC2822)).toEqual(
+      EXPECTED,
+    );
+
+    expect(parse('2026-01-31 10:00:00', ResponseDateFormats.EPOCH_MILLIS)).toBeUndefined();
+    expect(parse('yesterday', ResponseDateFormats.ISO)).toBeUndefined();
+  });
+
+  test('TC-002: builds schemas in the date format of the request', () => {
+    const request = new NodeFetchNetworkRequest({
+      name: 'LegacyErpRequest',
+      networkOptions: {},
+      dates: { format: ResponseDateFormats.SQL_DATETIME, timezone: 'Asia/Ho_Chi_Minh' },
+    });
+
+    const InvoiceSchema = z.object({
+      issuedAt: request.dateSchema(),
+      syncedAt: request.dateSchema({ format: ResponseDateFormats.EPOCH_MILLIS }),
+    });
+
+    const invoice = InvoiceSchema.parse({
+      issuedAt: '2026-01-31 17:00:00',
+      syncedAt: EXPECTED.getTime(),
+    });
+    expect(invoice).toEqual({ issuedAt: EXPECTED, syncedAt: EXPECTED });
+
+    const invalid = InvoiceSchema.safeParse({ issuedAt: EXPECTED.toISOString(), syncedAt: 0 });
+    expect(invalid.success).toBe(false);
+  });
+});
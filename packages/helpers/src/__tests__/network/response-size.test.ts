@@ -0,0 +1,68 @@
+/**
+ * Response Size Test Suite
+ *
+ * Tests the maximum response size guard:
+ * 1. Declared sizes above the fetcher or per-request limit are rejected
+ * 2. Streamed bodies fail once they cross the limit
+ *
+ * @module __tests__/network/response-size
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import {
+  NodeFetchNetworkRequest,
+  ResponseSizeErrorCodes,
+  ResponseSizeGuard,
+} from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('ResponseSizeGuard', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+    server.when({ path: '/report' }).respond({ body: 'x'.repeat(2_048) });
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: rejects responses declared above the limit', async () => {
+    const request = new NodeFetchNetworkRequest({
+      name: 'ReportRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      maxResponseBytes: 1_024,
+    });
+    const url = request.getRequestUrl({ paths: ['/report'] });
+
+    await expect(request.getNetworkService().get({ url })).rejects.toMatchObject({
+      statusCode: 502,
+      messageCode: ResponseSizeErrorCodes.RESPONSE_TOO_LARGE,
+    });
+
+    const response = await request.getNetworkService().get({ url, maxResponseBytes: 4_096 });
+    expect(await response.text()).toHaveLength(2_048);
+  });
+
+  test('TC-002: fails streamed bodies crossing the limit', async () => {
+    const chunk = new Uint8Array(512);
+    let pulled = 0;
+
+    const source = new ReadableStream<Uint8Array>({
+      pull(controller) {
+        pulled++;
+        controller.enqueue(chunk);
+      },
+    });
+
+    const response = ResponseSizeGuard.limit({
+      response: new Response(source),
+      url: 'http://upstream/stream',
+      maxBytes: 1_024,
+    });
+
+    await expect(response.arrayBuffer()).rejects.toThrow('Response exceeds 1024 bytes');
+    expect(pulled).toBeLessThanOrEqual(4);
+  });
+});
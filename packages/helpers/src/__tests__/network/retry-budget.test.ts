@@ -0,0 +1,109 @@
+/**
+ * Retry Budget Test Suite
+ *
+ * Tests fetcher retries:
+ * 1. RetryBudget caps retries to a share of the requests of the window
+ * 2. Idempotent requests are retried on retryable statuses
+ * 3. Retries stop once the budget is spent
+ * 4. Non idempotent requests need an idempotency key or an explicit opt-in
+ *
+ * @module __tests__/network/retry-budget
+ */
+
+import { describe, test, expect, afterAll, beforeAll, beforeEach } from 'bun:test';
+import { NodeFetchNetworkRequest, RetryBudget } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('RetryBudget', () => {
+  test('TC-001: allows retries for a share of the sliding window', () => {
+    const budget = new RetryBudget({ ratio: 0.1, minRetriesPerSecond: 0, window: 1_000 });
+    const now = 10_000;
+
+    for (let i = 0; i < 30; i++) {
+      budget.recordRequest(now);
+    }
+
+    const acquired = Array.from({ length: 5 }, () => budget.tryAcquire(now)).filter(Boolean);
+    expect(acquired).toHaveLength(3);
+    expect(budget.getStats(now)).toEqual({ requests: 30, retries: 3, available: 0 });
+
+    // Requests and retries leave the window
+    expect(budget.getStats(now + 1_000)).toEqual({ requests: 0, retries: 0, available: 0 });
+  });
+});
+
+describe('Fetcher retries', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  beforeEach(() => {
+    server.reset();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  const createRequest = (budget: RetryBudget | false) =>
+    new NodeFetchNetworkRequest({
+      name: 'RetryRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      retry: { maxAttempts: 3, baseDelay: 1, budget },
+    });
+
+  test('TC-002: retries idempotent requests on retryable statuses', async () => {
+    server.when({ path: '/orders' }).respond({ status: 200 });
+    server.when({ path: '/orders' }).respond({ status: 503 }).times(2);
+
+    const request = createRequest(false);
+    const url = request.getRequestUrl({ paths: ['/orders'] });
+
+    const response = await request.getNetworkService().get({ url });
+    expect(response.status).toBe(200);
+    expect(server.requests).toHaveLength(3);
+
+    server.when({ method: 'POST', path: '/orders' }).respond({ status: 503 });
+    const created = await request.getNetworkService().post({ url });
+    expect(created.status).toBe(503);
+    expect(server.requests).toHaveLength(4);
+  });
+
+  test('TC-003: stops retrying once the budget is spent', async () => {
+    server.when({ path: '/orders' }).respond({ status: 503 });
+
+    const budget = new RetryBudget({ ratio: 0.1, minRetriesPerSecond: 0 });
+    const request = createRequest(budget);
+    const url = request.getRequestUrl({ paths: ['/orders'] });
+
+    for (let i = 0; i < 20; i++) {
+      const response = await request.getNetworkService().get({ url });
+      expect(response.status).toBe(503);
+    }
+
+    // 20 requests and the 2 retries of their 10% budget, instead of 60 attempts
+    expect(server.requests).toHaveLength(22);
+    expect(budget.getStats()).toMatchObject({ requests: 20, retries: 2 });
+  });
+
+  test('TC-004: retries non idempotent requests only when allowed', async () => {
+    server.when({ path: '/payments' }).respond({ status: 503 });
+
+    const request = createRequest(false);
+    const url = request.getRequestUrl({ paths: ['/payments'] });
+
+    await request.getNetworkService().patch({ url });
+    expect(server.requests).toHaveLength(1);
+
+    await request.getNetworkService().post({ url, headers: { 'Idempotency-Key': 'pay_1' } });
+    expect(server.requests).toHaveLength(4);
+
+    await request.getNetworkService().patch({ url, retry: true });
+    expect(server.requests).toHaveLength(7);
+
+    await request.getNetworkService().get({ url, retry: false });
+    expect(server.requests).toHaveLength(8);
+  });
+});
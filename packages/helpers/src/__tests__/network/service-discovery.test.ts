@@ -0,0 +1,185 @@
+/**
+ * Service Discovery Test Suite
+ *
+ * Tests ServiceDiscovery:
+ * 1. Endpoints are picked by weighted round robin
+ * 2. Service base urls of network requests resolve to discovered endpoints
+ * 3. A failed refresh keeps the last known endpoints
+ * 4. SRV base urls pick the lowest priority and fail over to the next one when ejected
+ * 5. Requests sent through the fetchers balanced by in flight requests and ejected on failures
+ *
+ * @module __tests__/network/service-discovery
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import {
+  IServiceResolver,
+  LoadBalancingStrategies,
+  NodeFetchNetworkRequest,
+  ServiceDiscovery,
+  StaticServiceResolver,
+} from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('ServiceDiscovery', () => {
+  test('TC-001: picks endpoints by weight', async () => {
+    const discovery = new ServiceDiscovery({
+      resolver: new StaticServiceResolver({
+        services: {
+          orders: [
+            { url: 'http://10.0.0.1:8080', weight: 2 },
+            { url: 'http://10.0.0.2:8080', weight: 1 },
+          ],
+        },
+      }),
+    });
+    await discovery.watch({ services: ['orders'] });
+    discovery.stop();
+
+    const picked = Array.from({ length: 6 }, () => discovery.pick({ service: 'orders' }).url);
+    expect(picked.filter(url => url === 'http://10.0.0.1:8080')).toHaveLength(4);
+    expect(picked.filter(url => url === 'http://10.0.0.2:8080')).toHaveLength(2);
+    expect(() => discovery.pick({ service: 'payments' })).toThrow();
+  });
+
+  test('TC-002: resolves service base urls of network requests', async () => {
+    const discovery = new ServiceDiscovery({
+      resolver: new StaticServiceResolver({ services: { orders: ['http://10.0.0.1:8080/'] } }),
+    });
+    await discovery.refresh({ service: 'orders' });
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'OrderRequest',
+      discovery,
+      networkOptions: { baseUrl: 'service://orders/api/' },
+    });
+
+    expect(request.getRequestUrl({ paths: ['orders', '1'] })).toBe(
+      'http://10.0.0.1:8080/api/orders/1',
+    );
+    expect(discovery.resolveUrl({ url: 'http://localhost:3000' })).toBe('http://localhost:3000');
+  });
+
+  test('TC-003: keeps last known endpoints when a refresh fails', async () => {
+    let isDown = false;
+    const resolver: IServiceResolver = {
+      name: 'flaky',
+      resolve: async () => {
+        if (isDown) {
+          throw new Error('registry unavailable');
+        }
+        return [{ url: 'http://10.0.0.1:8080' }];
+      },
+    };
+
+    const discovery = new ServiceDiscovery({ resolver });
+    await discovery.refresh({ service: 'orders' });
+
+    isDown = true;
+    const endpoints = await discovery.refresh({ service: 'orders' });
+
+    expect(endpoints).toEqual([{ url: 'http://10.0.0.1:8080' }]);
+    expect(discovery.getEndpoints({ service: 'orders' })).toHaveLength(1);
+  });
+
+  test('TC-004: resolves srv urls by priority and fails over ejected endpoints', async () => {
+    const discovery = new ServiceDiscovery({
+      resolver: new StaticServiceResolver({ services: {} }),
+      srvResolver: new StaticServiceResolver({
+        services: {
+          'orders.internal': [
+            { url: 'http://orders-1.internal:8080', priority: 10 },
+            { url: 'http://orders-2.internal:8080', priority: 20 },
+          ],
+        },
+      }),
+    });
+    await discovery.refresh({ service: 'srv://orders.internal' });
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'OrderRequest',
+      discovery,
+      networkOptions: { baseUrl: 'srv://orders.internal/api' },
+    });
+    expect(request.getRequestUrl({ paths: ['orders'] })).toBe(
+      'http://orders-1.internal:8080/api/orders',
+    );
+
+    discovery.eject({ service: 'srv://orders.internal', url: 'http://orders-1.internal:8080' });
+    expect(discovery.resolveUrl({ url: 'srv://orders.internal/api' })).toBe(
+      'http://orders-2.internal:8080/api',
+    );
+    expect(() => discovery.pick({ service: 'orders.internal' })).toThrow();
+  });
+});
+
+describe('ServiceDiscovery balancing', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  const createRequest = async (opts: ConstructorParameters<typeof ServiceDiscovery>[0]) => {
+    const discovery = new ServiceDiscovery(opts);
+    await discovery.refresh({ service: 'search' });
+
+    const request = new NodeFetchNetworkRequest({
+      name: 'SearchRequest',
+      discovery,
+      networkOptions: { baseUrl: 'service://search' },
+    });
+    return { discovery, request, service: request.getNetworkService() };
+  };
+
+  const resolver = () =>
+    new StaticServiceResolver({
+      services: { search: [`${server.getBaseUrl()}/a`, `${server.getBaseUrl()}/b`] },
+    });
+
+  test('TC-005: picks the endpoint with the fewest requests in flight', async () => {
+    server.reset();
+    server.when({ path: '/a/query' }).respond({ status: 200, delay: 100 });
+    server.when({ path: '/b/query' }).respond({ status: 200 });
+
+    const { discovery, request, service } = await createRequest({
+      resolver: resolver(),
+      strategy: LoadBalancingStrategies.LEAST_IN_FLIGHT,
+    });
+    const send = () => service.get({ url: request.getRequestUrl({ paths: ['query'] }) });
+
+    const slow = send();
+    expect(discovery.getInFlight({ service: 'search', url: `${server.getBaseUrl()}/a` })).toBe(1);
+
+    await send();
+    await send();
+    await slow;
+
+    const paths = server.requests.map(rq => rq.path).sort();
+    expect(paths).toEqual(['/a/query', '/b/query', '/b/query']);
+    expect(discovery.getInFlight({ service: 'search', url: `${server.getBaseUrl()}/a` })).toBe(0);
+  });
+
+  test('TC-006: ejects endpoints after consecutive failures', async () => {
+    server.reset();
+    server.when({ path: '/a/query' }).respond({ status: 503 });
+    server.when({ path: '/b/query' }).respond({ status: 200 });
+
+    const { request, service } = await createRequest({
+      resolver: resolver(),
+      failureThreshold: 2,
+    });
+
+    for (let i = 0; i < 6; i++) {
+      await service.get({ url: request.getRequestUrl({ paths: ['query'] }) });
+    }
+
+    const paths = server.requests.map(rq => rq.path);
+    expect(paths.filter(path => path === '/a/query')).toHaveLength(2);
+    expect(paths.slice(-2)).toEqual(['/b/query', '/b/query']);
+  });
+});
@@ -0,0 +1,130 @@
+/**
+ * Session Login Test Suite
+ *
+ * Tests cookie sessions of the fetchers:
+ * 1. CookieJar honours the domain, path, expiry and `Secure` attributes
+ * 2. SessionLoginInterceptor logs in lazily, sends the session cookie and CSRF token, and logs in
+ *    again when the session expires
+ *
+ * @module __tests__/network/session-login
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import { CookieJar, NodeFetchNetworkRequest, SessionLoginInterceptor } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('CookieJar', () => {
+  test('TC-001: sends cookies matching the domain, path and expiry', () => {
+    const jar = new CookieJar();
+    const now = Date.now();
+
+    jar.setCookies({
+      url: 'https://admin.example.com/auth/login',
+      setCookies: [
+        'sid=abc; Path=/; HttpOnly',
+        'theme=dark; Domain=.example.com; Path=/',
+        'scoped=1; Path=/reports',
+        'short=1; Max-Age=60',
+        'other=1; Domain=other.com',
+      ],
+      now,
+    });
+
+    expect(jar.getCookieHeader({ url: 'https://admin.example.com/users', now })).toBe(
+      'sid=abc; theme=dark',
+    );
+    expect(jar.getCookieHeader({ url: 'https://admin.example.com/reports/1', now })).toBe(
+      'scoped=1; sid=abc; theme=dark',
+    );
+    expect(jar.getCookieHeader({ url: 'https://api.example.com/', now })).toBe('theme=dark');
+    expect(jar.get({ url: 'https://admin.example.com/auth/x', name: 'short' })).toBe('1');
+    expect(
+      jar.getCookieHeader({ url: 'https://admin.example.com/auth/x', now: now + 61_000 }),
+    ).not.toContain('short');
+
+    jar.setCookies({ url: 'https://admin.example.com/', setCookies: ['sid=; Max-Age=0'] });
+    expect(jar.get({ url: 'https://admin.example.com/', name: 'sid' })).toBeUndefined();
+
+    jar.setCookies({ url: 'https://admin.example.com/', setCookies: ['token=1; Secure'] });
+    expect(jar.get({ url: 'http://admin.example.com/', name: 'token' })).toBeUndefined();
+  });
+});
+
+describe('SessionLoginInterceptor', () => {
+  const server = new MockServer();
+
+  let logins = 0;
+  let session: string | undefined;
+
+  beforeAll(async () => {
+    await server.start();
+
+    server.when({ method: 'POST', path: '/login' }).respond(() => {
+      session = `s${++logins}`;
+      return {
+        status: 302,
+        headers: {
+          location: '/',
+          'set-cookie': `sid=${session}; Path=/; HttpOnly`,
+          'x-csrf-token': `csrf-${logins}`,
+        },
+      };
+    });
+
+    server
+      .when({ path: '/admin/users' })
+      .respond(request =>
+        request.headers.cookie === `sid=${session}` ? { status: 200, json: [] } : { status: 419 },
+      );
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-002: logs in, sends the session and logs in again once it expires', async () => {
+    const jar = new CookieJar();
+    const request = new NodeFetchNetworkRequest({
+      name: 'AdminRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      cookieJar: jar,
+      interceptors: [
+        new SessionLoginInterceptor({
+          jar,
+          login: {
+            url: `${server.getBaseUrl()}/login`,
+            body: new URLSearchParams({ username: 'admin', password: 'secret' }),
+          },
+          csrf: { responseHeader: 'x-csrf-token' },
+        }),
+      ],
+    });
+    const url = request.getRequestUrl({ paths: ['/admin/users'] });
+
+    const [first, second] = await Promise.all([
+      request.getNetworkService().get({ url }),
+      request.getNetworkService().get({ url }),
+    ]);
+    expect([first.status, second.status]).toEqual([200, 200]);
+    expect(logins).toBe(1);
+
+    // The upstream drops the session
+    session = undefined;
+
+    const response = await request.getNetworkService().get({ url });
+    expect(response.status).toBe(200);
+    expect(logins).toBe(2);
+
+    const last = server.requests[server.requests.length - 1];
+    expect(last.headers.cookie).toBe('sid=s2');
+    expect(last.headers['x-csrf-token']).toBe('csrf-2');
+    expect(server.requests.map(rq => rq.path)).toEqual([
+      '/login',
+      '/admin/users',
+      '/admin/users',
+      '/admin/users',
+      '/login',
+      '/admin/users',
+    ]);
+  });
+});
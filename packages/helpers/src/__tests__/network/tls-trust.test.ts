@@ -0,0 +1,77 @@
+/**
+ * TLS Trust Test Suite
+ *
+ * Tests TlsTrust:
+ * 1. Certificates are loaded from PEM files, bundles and directories
+ * 2. Paths without any certificate are rejected
+ * 3. TlsCredentials reloads rotated files and keeps the current ones on invalid rotations
+ *
+ * @module __tests__/network/tls-trust
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import tls from 'node:tls';
+import { TlsCredentials, TlsTrust } from '@/helpers/network';
+
+const toPem = (body: string) => `-----BEGIN CERTIFICATE-----\n${body}\n-----END CERTIFICATE-----`;
+
+describe('TlsTrust', () => {
+  let directory: string;
+
+  beforeAll(() => {
+    directory = fs.mkdtempSync(path.join(os.tmpdir(), 'ignis-tls-'));
+    fs.writeFileSync(path.join(directory, 'root.crt'), toPem('Uk9PVA=='));
+    fs.writeFileSync(
+      path.join(directory, 'bundle.pem'),
+      [toPem('SU5URVJNRURJQVRFLTE='), toPem('SU5URVJNRURJQVRFLTI=')].join('\n'),
+    );
+    fs.writeFileSync(path.join(directory, 'README.md'), 'Internal CA');
+  });
+
+  afterAll(() => {
+    fs.rmSync(directory, { recursive: true, force: true });
+  });
+
+  test('TC-001: loads certificates from files, bundles and directories', () => {
+    const certificates = TlsTrust.load({ caPaths: [directory], systemRoots: false });
+    expect(certificates).toEqual([
+      toPem('SU5URVJNRURJQVRFLTE='),
+      toPem('SU5URVJNRURJQVRFLTI='),
+      toPem('Uk9PVA=='),
+    ]);
+
+    const withRoots = TlsTrust.load({ caPaths: [path.join(directory, 'root.crt')] });
+    expect(withRoots).toHaveLength(tls.rootCertificates.length + 1);
+    expect(withRoots.at(-1)).toBe(toPem('Uk9PVA=='));
+  });
+
+  test('TC-002: rejects paths without certificates', () => {
+    expect(() => TlsTrust.load({ caPaths: [path.join(directory, 'README.md')] })).toThrow(
+      'No PEM certificate found',
+    );
+    expect(() => TlsTrust.load({ ca: ['not a certificate'] })).toThrow();
+  });
+
+  test('TC-003: reloads rotated files and keeps the current credentials on failures', () => {
+    const file = path.join(directory, 'rotated.crt');
+    fs.writeFileSync(file, toPem('VjE='));
+
+    const credentials = new TlsCredentials({ caPaths: [file], systemRoots: false });
+    expect(credentials.get().ca).toEqual([toPem('VjE=')]);
+
+    fs.writeFileSync(file, toPem('VjI='));
+    expect(credentials.reload()).toBe(true);
+    expect(credentials.get().ca).toEqual([toPem('VjI=')]);
+
+    fs.writeFileSync(file, 'half written');
+    expect(credentials.reload()).toBe(false);
+    expect(credentials.get().ca).toEqual([toPem('VjI=')]);
+
+    expect(() => TlsCredentials.load({ key: 'key without certificate' })).toThrow(
+      'Client certificate and key go together',
+    );
+  });
+});
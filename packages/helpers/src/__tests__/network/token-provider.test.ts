@@ -0,0 +1,49 @@
+/**
+ * Token Provider Test Suite
+ *
+ * Tests the token provider of the fetchers:
+ * 1. Token of the provider read for every request, `bearerAuth` and explicit headers win over it
+ *
+ * @module __tests__/network/token-provider
+ */
+
+import { describe, test, expect, afterAll } from 'bun:test';
+import { NodeFetchNetworkRequest } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('Fetcher token provider', () => {
+  const server = new MockServer();
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: attaches the token of the provider unless the request sets its own', async () => {
+    await server.start();
+    server.when({ path: '/orders' }).respond({ status: 204 });
+
+    let token = 'token-1';
+    const request = new NodeFetchNetworkRequest({
+      name: 'OrderRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      tokenProvider: { getToken: async () => token },
+    });
+    const url = request.getRequestUrl({ paths: ['/orders'] });
+    const service = request.getNetworkService();
+
+    await service.get({ url });
+    token = 'token-2';
+    await service.get({ url });
+    await service.get({ url, bearerAuth: 'user-token' });
+    await service.get({ url, headers: { authorization: 'Basic b3BzOnNlY3JldA==' } });
+    await service.get({ url, bearerAuth: false });
+
+    expect(server.requests.map(rq => rq.headers.authorization)).toEqual([
+      'Bearer token-1',
+      'Bearer token-2',
+      'Bearer user-token',
+      'Basic b3BzOnNlY3JldA==',
+      undefined,
+    ]);
+  });
+});
@@ -0,0 +1,89 @@
+/**
+ * Token Refresh Test Suite
+ *
+ * Tests the token refresh interceptor:
+ * 1. Concurrent rejected requests share one refresh and are replayed once with the new token
+ * 2. Requests still rejected after the refresh are returned as is
+ *
+ * @module __tests__/network/token-refresh
+ */
+
+import { describe, test, expect, afterAll, beforeAll, beforeEach } from 'bun:test';
+import { NodeFetchNetworkRequest, TokenRefreshInterceptor } from '@/helpers/network';
+import { MockServer } from '@/helpers/testing';
+
+describe('TokenRefreshInterceptor', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  beforeEach(() => {
+    server.reset();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  const createRequest = (interceptor: TokenRefreshInterceptor) =>
+    new NodeFetchNetworkRequest({
+      name: 'OrderRequest',
+      networkOptions: { baseUrl: server.getBaseUrl() },
+      interceptors: [interceptor],
+    });
+
+  test('TC-001: refreshes once for concurrent rejections and replays each request', async () => {
+    server.when({ path: '/orders' }).respond({ status: 401 });
+    server
+      .when({ path: '/orders', headers: { authorization: 'Bearer fresh' } })
+      .respond({ status: 200, json: { ok: true } });
+
+    let refreshes = 0;
+    const interceptor = new TokenRefreshInterceptor({
+      token: 'stale',
+      refresh: async () => {
+        refreshes++;
+        await new Promise(resolve => setTimeout(resolve, 20));
+        return 'fresh';
+      },
+    });
+
+    const request = createRequest(interceptor);
+    const url = request.getRequestUrl({ paths: ['/orders'] });
+
+    const responses = await Promise.all(
+      Array.from({ length: 3 }, () => request.getNetworkService().get({ url })),
+    );
+
+    expect(responses.map(response => response.status)).toEqual([200, 200, 200]);
+    expect(refreshes).toBe(1);
+    expect(interceptor.getToken()).toBe('fresh');
+
+    const sent = server.requests.map(rq => rq.headers.authorization);
+    expect(sent.filter(value => value === 'Bearer stale')).toHaveLength(3);
+    expect(sent.filter(value => value === 'Bearer fresh')).toHaveLength(3);
+  });
+
+  test('TC-002: replays a rejected request only once', async () => {
+    server.when({ path: '/orders' }).respond({ status: 401 });
+
+    let refreshes = 0;
+    const interceptor = new TokenRefreshInterceptor({
+      refresh: async () => `token-${++refreshes}`,
+    });
+
+    const request = createRequest(interceptor);
+    const url = request.getRequestUrl({ paths: ['/orders'] });
+
+    const response = await request.getNetworkService().get({ url });
+
+    expect(response.status).toBe(401);
+    expect(refreshes).toBe(1);
+    expect(server.requests.map(rq => rq.headers.authorization)).toEqual([
+      undefined,
+      'Bearer token-1',
+    ]);
+  });
+});
@@ -0,0 +1,50 @@
+/**
+ * Unix Socket Transport Test Suite
+ *
+ * Tests fetchers targeting a Unix socket:
+ * 1. Requests of a fetcher with `socketPath` reach the server listening on the socket
+ *
+ * @module __tests__/network/unix-socket
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import fs from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import { NodeFetchNetworkRequest } from '@/helpers/network';
+
+describe('Unix socket transport', () => {
+  const directory = fs.mkdtempSync(path.join(os.tmpdir(), 'ignis-unix-'));
+  const socketPath = path.join(directory, 'sidecar.sock');
+  let server: ReturnType<typeof Bun.serve>;
+
+  beforeAll(() => {
+    server = Bun.serve({
+      unix: socketPath,
+      fetch: request => {
+        const { pathname, search } = new URL(request.url);
+        return Response.json({ path: `${pathname}${search}`, host: request.headers.get('host') });
+      },
+    });
+  });
+
+  afterAll(() => {
+    server.stop(true);
+    fs.rmSync(directory, { recursive: true, force: true });
+  });
+
+  test('TC-001: sends requests to the socket of the fetcher', async () => {
+    const request = new NodeFetchNetworkRequest({
+      name: 'SidecarRequest',
+      networkOptions: { baseUrl: 'http://sidecar/v1' },
+      socketPath,
+    });
+
+    const response = await request.getNetworkService().get({
+      url: request.getRequestUrl({ paths: ['containers'] }),
+      params: { all: true },
+    });
+
+    expect(await response.json()).toEqual({ path: '/v1/containers?all=true', host: 'sidecar' });
+  });
+});
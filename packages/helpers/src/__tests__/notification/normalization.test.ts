@@ -0,0 +1,59 @@
+/**
+ * Contact Normalization Test Suite
+ *
+ * Tests the normalizers shared by the senders and the validation rules:
+ * 1. PhoneNumberNormalizer — default region resolution
+ * 2. EmailNormalizer — validation, normalization and canonicalization
+ *
+ * @module __tests__/notification/normalization
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { EmailNormalizer, PhoneNumberNormalizer } from '@/helpers/notification';
+
+describe('PhoneNumberNormalizer', () => {
+  test('TC-001: normalizes national numbers with the default region', () => {
+    expect(PhoneNumberNormalizer.normalize({ phone: '091 234 5678', defaultRegion: 'vn' })).toBe(
+      '+84912345678',
+    );
+    expect(
+      PhoneNumberNormalizer.normalize({ phone: '1 (415) 555-2671', defaultRegion: 'US' }),
+    ).toBe('+14155552671');
+    expect(PhoneNumberNormalizer.normalize({ phone: '06 6982 1234', defaultRegion: 'IT' })).toBe(
+      '+390669821234',
+    );
+
+    // The calling code wins over the region
+    expect(
+      PhoneNumberNormalizer.normalize({
+        phone: '0912345678',
+        defaultRegion: 'US',
+        defaultCountryCode: '84',
+      }),
+    ).toBe('+84912345678');
+    expect(PhoneNumberNormalizer.isValid({ phone: '0912345678', defaultRegion: 'XX' })).toBe(false);
+  });
+});
+
+describe('EmailNormalizer', () => {
+  test('TC-002: validates and normalizes addresses', () => {
+    expect(EmailNormalizer.normalize({ email: ' Jane.Doe+promo@GMAIL.com ' })).toBe(
+      'jane.doe+promo@gmail.com',
+    );
+    expect(EmailNormalizer.normalize({ email: 'jane@localhost' })).toBeNull();
+    expect(EmailNormalizer.normalize({ email: 'jane doe@example.com' })).toBeNull();
+    expect(EmailNormalizer.isValid(`${'a'.repeat(65)}@example.com`)).toBe(false);
+    expect(EmailNormalizer.mask('jane@example.com')).toBe('j***@example.com');
+  });
+
+  test('TC-003: canonicalizes aliases of the same mailbox', () => {
+    const canonical = ['Jane.Doe+promo@gmail.com', 'janedoe@googlemail.com'].map(email =>
+      EmailNormalizer.canonicalize({ email }),
+    );
+
+    expect(canonical).toEqual(['janedoe@gmail.com', 'janedoe@gmail.com']);
+    expect(EmailNormalizer.canonicalize({ email: 'jane.doe+work@example.com' })).toBe(
+      'jane.doe@example.com',
+    );
+  });
+});
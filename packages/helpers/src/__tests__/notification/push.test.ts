@@ -0,0 +1,175 @@
+/**
+ * Push Notification Test Suite
+ *
+ * Tests the provider independent push pieces:
+ * 1. PushPayloadBuilder — FCM and APNs payloads built from one notification
+ * 2. Error normalization — invalid token detection for FCM and APNs
+ * 3. signProviderToken — ES256 provider tokens verifiable with the public key
+ *
+ * @module __tests__/notification/push
+ */
+
+import { describe, test, expect } from 'bun:test';
+import C from 'node:crypto';
+import {
+  ApnsPushHelper,
+  FcmPushHelper,
+  PushErrorCodes,
+  PushPayloadBuilder,
+  signProviderToken,
+} from '@/helpers/notification';
+
+// =============================================================================
+// PushPayloadBuilder
+// =============================================================================
+
+describe('Push Notification', () => {
+  describe('PushPayloadBuilder', () => {
+    test('TC-001: builds a FCM message with platform overrides', () => {
+      const message = PushPayloadBuilder.buildFcmMessage({
+        token: 'device-token',
+        notification: {
+          title: 'Order shipped',
+          body: 'Your order is on the way',
+          imageUrl: 'https://cdn.example.com/order.png',
+          data: { orderId: '1024' },
+          ttl: 3600,
+          badge: 2,
+        },
+      });
+
+      expect(message.token).toBe('device-token');
+      expect(message.notification).toEqual({
+        title: 'Order shipped',
+        body: 'Your order is on the way',
+        image: 'https://cdn.example.com/order.png',
+      });
+      expect(message.data).toEqual({ orderId: '1024' });
+      expect(message.android.priority).toBe('HIGH');
+      expect(message.android.ttl).toBe('3600s');
+      expect(message.apns.headers['apns-priority']).toBe('10');
+      expect(message.apns.payload.aps.badge).toBe(2);
+      expect(message.apns.payload.aps['mutable-content']).toBe(1);
+      expect(message.webpush.headers).toEqual({ Urgency: 'high', TTL: '3600' });
+    });
+
+    test('TC-002: builds silent pushes as background data messages', () => {
+      const notification = { data: { sync: 'inbox' }, silent: true };
+      const message = PushPayloadBuilder.buildFcmMessage({ token: 'device-token', notification });
+
+      expect(message.notification).toBeUndefined();
+      expect(message.android.priority).toBe('NORMAL');
+      expect(PushPayloadBuilder.buildApnsPayload(notification)).toEqual({
+        sync: 'inbox',
+        aps: { 'content-available': 1 },
+      });
+      expect(PushPayloadBuilder.buildApnsHeaders({ notification, topic: 'com.example' })).toEqual({
+        ['apns-push-type']: 'background',
+        ['apns-priority']: '5',
+        ['apns-topic']: 'com.example',
+      });
+    });
+  });
+
+  // ===========================================================================
+  // Error normalization
+  // ===========================================================================
+
+  describe('Error normalization', () => {
+    test('TC-003: flags unregistered FCM tokens as invalid', () => {
+      const rs = FcmPushHelper.normalizeError({
+        statusCode: 404,
+        error: {
+          status: 'NOT_FOUND',
+          details: [
+            {
+              '@type': 'type.googleapis.com/google.firebase.fcm.v1.FcmError',
+              errorCode: 'UNREGISTERED',
+            },
+          ],
+        },
+      });
+
+      expect(rs).toEqual({
+        errorCode: PushErrorCodes.INVALID_TOKEN,
+        statusCode: 410,
+        isTokenInvalid: true,
+      });
+    });
+
+    test('TC-004: separates invalid FCM tokens from invalid payloads', () => {
+      const invalidToken = FcmPushHelper.normalizeError({
+        statusCode: 400,
+        error: {
+          status: 'INVALID_ARGUMENT',
+          message: 'The registration token is not a valid FCM registration token',
+        },
+      });
+      const invalidPayload = FcmPushHelper.normalizeError({
+        statusCode: 400,
+        error: { status: 'INVALID_ARGUMENT', message: 'Invalid value at message.android.ttl' },
+      });
+
+      expect(invalidToken.isTokenInvalid).toBe(true);
+      expect(invalidPayload.isTokenInvalid).toBe(false);
+      expect(invalidPayload.errorCode).toBe(PushErrorCodes.INVALID_PAYLOAD);
+    });
+
+    test('TC-005: maps APNs reasons', () => {
+      expect(ApnsPushHelper.normalizeError({ statusCode: 410, reason: 'Unregistered' })).toEqual({
+        errorCode: PushErrorCodes.INVALID_TOKEN,
+        statusCode: 410,
+        isTokenInvalid: true,
+      });
+      expect(
+        ApnsPushHelper.normalizeError({ statusCode: 400, reason: 'BadDeviceToken' }).isTokenInvalid,
+      ).toBe(true);
+
+      const expired = ApnsPushHelper.normalizeError({
+        statusCode: 403,
+        reason: 'ExpiredProviderToken',
+      });
+      expect(expired.errorCode).toBe(PushErrorCodes.AUTHENTICATION_FAILED);
+
+      expect(
+        ApnsPushHelper.normalizeError({ statusCode: 413, reason: 'PayloadTooLarge' }).errorCode,
+      ).toBe(PushErrorCodes.INVALID_PAYLOAD);
+    });
+  });
+
+  // ===========================================================================
+  // signProviderToken
+  // ===========================================================================
+
+  describe('signProviderToken', () => {
+    test('TC-006: signs ES256 tokens in JWS (r || s) form', () => {
+      const { privateKey, publicKey } = C.generateKeyPairSync('ec', { namedCurve: 'P-256' });
+
+      const token = signProviderToken({
+        algorithm: 'ES256',
+        privateKey: privateKey.export({ type: 'pkcs8', format: 'pem' }).toString(),
+        header: { kid: 'KEY123' },
+        claims: { iss: 'TEAM123', iat: 1_760_000_000 },
+      });
+
+      const [header, claims, signature] = token.split('.');
+      expect(JSON.parse(Buffer.from(header, 'base64url').toString())).toEqual({
+        alg: 'ES256',
+        typ: 'JWT',
+        kid: 'KEY123',
+      });
+      expect(JSON.parse(Buffer.from(claims, 'base64url').toString()).iss).toBe('TEAM123');
+
+      const rawSignature = Buffer.from(signature, 'base64url');
+      expect(rawSignature.length).toBe(64);
+      expect(
+        C.verify(
+          'sha256',
+          Buffer.from(`${header}.${claims}`),
+          { key: publicKey, dsaEncoding: 'ieee-p1363' },
+          rawSignature,
+        ),
+      ).toBe(true);
+    });
+  });
+});
@@ -0,0 +1,135 @@
+/**
+ * SMS Test Suite
+ *
+ * Tests the provider independent SMS pieces:
+ * 1. PhoneNumberNormalizer — E.164 normalization and masking
+ * 2. TwilioSmsHelper — error normalization, status callback parsing and signature verification
+ *
+ * @module __tests__/notification/sms
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { ApplicationError } from '@/helpers/error';
+import {
+  PhoneNumberNormalizer,
+  SmsDeliveryStatuses,
+  SmsErrorCodes,
+  TwilioSmsHelper,
+} from '@/helpers/notification';
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+const CALLBACK_URL = 'https://api.example.com/sms/status';
+
+const twilio = new TwilioSmsHelper({
+  accountSid: 'AC00000000000000000000000000000000',
+  authToken: 'twilio_auth_token',
+  from: '+15005550006',
+});
+
+// =============================================================================
+// PhoneNumberNormalizer
+// =============================================================================
+
+describe('SMS', () => {
+  describe('PhoneNumberNormalizer', () => {
+    test('TC-001: keeps valid E.164 numbers and strips formatting characters', () => {
+      expect(PhoneNumberNormalizer.normalize({ phone: '+84912345678' })).toBe('+84912345678');
+      expect(PhoneNumberNormalizer.normalize({ phone: '+1 (415) 555-2671' })).toBe('+14155552671');
+    });
+
+    test('TC-002: converts the 00 international prefix', () => {
+      expect(PhoneNumberNormalizer.normalize({ phone: '0044 20 7946 0958' })).toBe(
+        '+442079460958',
+      );
+    });
+
+    test('TC-003: applies the default country code and drops the trunk prefix', () => {
+      expect(
+        PhoneNumberNormalizer.normalize({ phone: '091.234.5678', defaultCountryCode: '84' }),
+      ).toBe('+84912345678');
+      expect(
+        PhoneNumberNormalizer.normalize({ phone: '4155552671', defaultCountryCode: '+1' }),
+      ).toBe('+14155552671');
+    });
+
+    test('TC-004: rejects national numbers without a country code and invalid input', () => {
+      expect(PhoneNumberNormalizer.normalize({ phone: '0912345678' })).toBeNull();
+      expect(PhoneNumberNormalizer.normalize({ phone: '+0123456789' })).toBeNull();
+      expect(PhoneNumberNormalizer.normalize({ phone: '+1234' })).toBeNull();
+      expect(PhoneNumberNormalizer.normalize({ phone: 'not-a-number' })).toBeNull();
+    });
+
+    test('TC-005: masks the middle digits', () => {
+      expect(PhoneNumberNormalizer.mask('+84912345678')).toBe('+849*****678');
+    });
+  });
+
+  // ===========================================================================
+  // TwilioSmsHelper
+  // ===========================================================================
+
+  describe('TwilioSmsHelper', () => {
+    test('TC-006: maps Twilio error codes to SmsErrorCodes', () => {
+      expect(TwilioSmsHelper.normalizeError({ code: 21211, statusCode: 400 }).errorCode).toBe(
+        SmsErrorCodes.INVALID_PHONE_NUMBER,
+      );
+      expect(TwilioSmsHelper.normalizeError({ code: 21610, statusCode: 400 }).errorCode).toBe(
+        SmsErrorCodes.RECIPIENT_UNREACHABLE,
+      );
+      expect(TwilioSmsHelper.normalizeError({ code: 20003, statusCode: 401 }).errorCode).toBe(
+        SmsErrorCodes.AUTHENTICATION_FAILED,
+      );
+      expect(TwilioSmsHelper.normalizeError({ statusCode: 429 })).toEqual({
+        errorCode: SmsErrorCodes.RATE_LIMITED,
+        statusCode: 429,
+      });
+    });
+
+    test('TC-007: parses form encoded status callbacks', () => {
+      const report = twilio.parseStatusCallback({
+        payload: 'MessageSid=SM123&MessageStatus=undelivered&To=%2B84912345678&ErrorCode=30005',
+      });
+
+      expect(report.messageId).toBe('SM123');
+      expect(report.status).toBe(SmsDeliveryStatuses.UNDELIVERED);
+      expect(report.providerStatus).toBe('undelivered');
+      expect(report.to).toBe('+84912345678');
+      expect(report.errorCode).toBe(SmsErrorCodes.RECIPIENT_UNREACHABLE);
+      expect(SmsDeliveryStatuses.isFinal(report.status)).toBe(true);
+    });
+
+    test('TC-008: accepts callbacks signed with the auth token', () => {
+      const params = { MessageSid: 'SM123', MessageStatus: 'delivered', To: '+84912345678' };
+      const signature = twilio.computeSignature({ url: CALLBACK_URL, params });
+
+      const report = twilio.verifyStatusCallback({
+        url: CALLBACK_URL,
+        payload: new URLSearchParams(params).toString(),
+        signature,
+      });
+
+      expect(report.status).toBe(SmsDeliveryStatuses.DELIVERED);
+    });
+
+    test('TC-009: rejects callbacks with a tampered payload or missing signature', () => {
+      const params = { MessageSid: 'SM123', MessageStatus: 'delivered' };
+      const signature = twilio.computeSignature({ url: CALLBACK_URL, params });
+
+      for (const [payload, header] of [
+        [{ ...params, MessageStatus: 'failed' }, signature],
+        [params, undefined],
+      ] as const) {
+        try {
+          twilio.verifyStatusCallback({ url: CALLBACK_URL, payload, signature: header });
+          throw new Error('Expected verification to throw');
+        } catch (error) {
+          expect(error).toBeInstanceOf(ApplicationError);
+          expect((error as ApplicationError).messageCode).toBe(SmsErrorCodes.INVALID_SIGNATURE);
+        }
+      }
+    });
+  });
+});
@@ -0,0 +1,92 @@
+/**
+ * Request Context Test Suite
+ *
+ * Tests RequestContextStorage:
+ * 1. Scoping — context is visible across awaits and isolated between scopes
+ * 2. Propagation — fetchers forward the request id and tenant headers
+ * 3. Deadlines — fetchers forward the deadline and refuse calls past the remaining budget
+ *
+ * @module __tests__/request-context
+ */
+
+import { describe, test, expect, spyOn } from 'bun:test';
+import { NodeFetchNetworkRequest, TimeoutErrorCodes } from '@/helpers/network';
+import { RequestContextStorage, RequestDeadlines } from '@/helpers/request-context';
+
+describe('RequestContextStorage', () => {
+  test('TC-001: keeps the context across awaits and isolates scopes', async () => {
+    const read = async (requestId: string) => {
+      return RequestContextStorage.run({
+        context: { requestId },
+        task: async () => {
+          await new Promise(resolve => setTimeout(resolve, 5));
+          RequestContextStorage.update({ userId: `user-${requestId}` });
+          return RequestContextStorage.get();
+        },
+      });
+    };
+
+    const [first, second] = await Promise.all([read('a'), read('b')]);
+
+    expect(first).toMatchObject({ requestId: 'a', userId: 'user-a' });
+    expect(second).toMatchObject({ requestId: 'b', userId: 'user-b' });
+    expect(RequestContextStorage.get()).toBeUndefined();
+  });
+
+  test('TC-002: forwards request id and tenant headers from fetchers', async () => {
+    const fetchSpy = spyOn(globalThis, 'fetch').mockImplementation((async () => {
+      return new Response('{}');
+    }) as unknown as typeof fetch);
+
+    try {
+      const network = new NodeFetchNetworkRequest({ name: 'test', networkOptions: {} });
+      await RequestContextStorage.run({
+        context: { requestId: 'req-1', tenantId: 'acme' },
+        task: () => {
+          return network.getNetworkService().get({
+            url: 'https://api.example.com/orders',
+            headers: { ['X-Request-Id']: 'caller-set' },
+          });
+        },
+      });
+
+      const init = fetchSpy.mock.calls[0][1] as RequestInit;
+      expect(init.headers).toEqual({ ['X-Request-Id']: 'caller-set', ['x-tenant-id']: 'acme' });
+    } finally {
+      fetchSpy.mockRestore();
+    }
+  });
+
+  test('TC-003: forwards the deadline and refuses calls past the remaining budget', async () => {
+    const fetchSpy = spyOn(globalThis, 'fetch').mockImplementation((async () => {
+      return new Response('{}');
+    }) as unknown as typeof fetch);
+
+    try {
+      const network = new NodeFetchNetworkRequest({
+        name: 'test',
+        networkOptions: {},
+        minDeadlineBudget: 50,
+      });
+      const get = (deadline: number) =>
+        RequestContextStorage.run({
+          context: { requestId: 'req-1', deadline },
+          task: () => network.getNetworkService().get({ url: 'https://api.example.com/orders' }),
+        });
+
+      expect(RequestDeadlines.parse({ grpcTimeout: '2S', now: 1_000 })).toBe(3_000);
+
+      const deadline = Date.now() + 2_000;
+      await get(deadline);
+      const init = fetchSpy.mock.calls[0][1] as RequestInit;
+      expect(init.headers).toMatchObject({ ['x-request-deadline']: String(deadline) });
+
+      await expect(get(Date.now() + 10)).rejects.toMatchObject({
+        messageCode: TimeoutErrorCodes.BUDGET_EXCEEDED,
+      });
+      expect(fetchSpy).toHaveBeenCalledTimes(1);
+    } finally {
+      fetchSpy.mockRestore();
+    }
+  });
+});
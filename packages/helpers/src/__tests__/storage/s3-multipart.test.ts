@@ -0,0 +1,197 @@
+/**
+ * S3 Multipart Upload Test Suite
+ *
+ * Tests S3ObjectStorageHelper.putMultipart with an in-memory S3 client:
+ * 1. Bodies are split into parts of `partSize`, empty and single part bodies included
+ * 2. Resumed uploads skip the parts already stored with the same size
+ * 3. Failed uploads are aborted unless `abortOnFailure` is off
+ *
+ * @module __tests__/storage/s3-multipart
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Client } from 'minio';
+import { Readable } from 'node:stream';
+import { S3ObjectStorageHelper } from '@/helpers/storage/minio';
+
+const MiB = 1024 * 1024;
+const PART_SIZE = 5 * MiB;
+
+// Multipart calls of the minio client, parts are kept by upload id
+class MockS3Client {
+  uploads = new Map<string, Map<number, Buffer>>();
+  uploadedParts: Array<number> = [];
+  completed: Array<{ uploadId: string; etags: Array<{ part: number; etag: string }> }> = [];
+  aborted: Array<string> = [];
+  failingPart?: number;
+  private sequence = 0;
+
+  async initiateNewMultipartUpload() {
+    const uploadId = `upload-${++this.sequence}`;
+    this.uploads.set(uploadId, new Map());
+    return uploadId;
+  }
+
+  async listParts(_bucket: string, _key: string, uploadId: string) {
+    return [...(this.uploads.get(uploadId) ?? [])].map(([part, payload]) => ({
+      part,
+      etag: `etag-${part}`,
+      size: payload.length,
+    }));
+  }
+
+  async uploadPart(opts: { uploadID: string; partNumber: number }, payload: Buffer) {
+    if (opts.partNumber === this.failingPart) {
+      throw new Error('Connection reset');
+    }
+
+    this.uploadedParts.push(opts.partNumber);
+    this.uploads.get(opts.uploadID)?.set(opts.partNumber, payload);
+    return { etag: `etag-${opts.partNumber}` };
+  }
+
+  async completeMultipartUpload(
+    _bucket: string,
+    _key: string,
+    uploadId: string,
+    etags: Array<{ part: number; etag: string }>,
+  ) {
+    this.completed.push({ uploadId, etags });
+    return { etag: 'etag-object' };
+  }
+
+  async abortMultipartUpload(_bucket: string, _key: string, uploadId: string) {
+    this.aborted.push(uploadId);
+    this.uploads.delete(uploadId);
+  }
+
+  async statObject() {
+    const [{ uploadId }] = this.completed.slice(-1);
+    const parts = [...(this.uploads.get(uploadId)?.values() ?? [])];
+    const size = parts.reduce((total, payload) => total + payload.length, 0);
+    return { size, etag: 'etag-object', lastModified: new Date(), metaData: {} };
+  }
+}
+
+const createStorage = () => {
+  const client = new MockS3Client();
+  const storage = new S3ObjectStorageHelper({
+    bucket: 'uploads',
+    endPoint: 'localhost',
+    accessKey: 'access',
+    secretKey: 'secret',
+  });
+  storage.client = client as unknown as Client;
+
+  return { client, storage };
+};
+
+// Chunks whose sizes do not line up with the parts
+const toStream = (body: Buffer) => {
+  const chunks: Array<Buffer> = [];
+  for (let offset = 0; offset < body.length; offset += MiB) {
+    chunks.push(body.subarray(offset, offset + MiB));
+  }
+  return Readable.from(chunks);
+};
+
+describe('S3ObjectStorageHelper.putMultipart', () => {
+  test('TC-001: splits bodies into parts', async () => {
+    const body = Buffer.alloc(12 * MiB + 3, 1);
+
+    for (const input of [body, toStream(body)]) {
+      const { client, storage } = createStorage();
+      const rs = await storage.putMultipart({
+        key: 'videos/a.mp4',
+        body: input,
+        partSize: PART_SIZE,
+      });
+
+      expect(rs).toMatchObject({ uploadId: 'upload-1', parts: 3, size: body.length });
+      const parts = [...client.uploads.get('upload-1')!.values()];
+      expect(parts.map(part => part.length)).toEqual([PART_SIZE, PART_SIZE, 2 * MiB + 3]);
+      expect(client.completed[0].etags.map(({ part }) => part)).toEqual([1, 2, 3]);
+    }
+
+    for (const input of [Buffer.from('x'), Readable.from([Buffer.from('x')])]) {
+      const { storage } = createStorage();
+      const rs = await storage.putMultipart({
+        key: 'notes/a.txt',
+        body: input,
+        partSize: PART_SIZE,
+      });
+      expect(rs).toMatchObject({ parts: 1, size: 1 });
+    }
+
+    for (const input of [Buffer.alloc(0), Readable.from([])]) {
+      const { client, storage } = createStorage();
+      const rs = await storage.putMultipart({
+        key: 'notes/empty',
+        body: input,
+        partSize: PART_SIZE,
+      });
+      expect(rs).toMatchObject({ parts: 1, size: 0 });
+      expect(client.completed[0].etags).toEqual([{ part: 1, etag: 'etag-1' }]);
+    }
+
+    const { storage } = createStorage();
+    await expect(
+      storage.putMultipart({ key: 'notes/a.txt', body: Buffer.from('x'), partSize: MiB }),
+    ).rejects.toMatchObject({ statusCode: 400 });
+  });
+
+  test('TC-002: resumes an upload from its stored parts', async () => {
+    const body = Buffer.alloc(12 * MiB, 2);
+    const { client, storage } = createStorage();
+
+    client.failingPart = 3;
+    await expect(
+      storage.putMultipart({
+        key: 'videos/b.mp4',
+        body,
+        partSize: PART_SIZE,
+        concurrency: 1,
+        abortOnFailure: false,
+      }),
+    ).rejects.toThrow('Connection reset');
+    expect(client.uploadedParts).toEqual([1, 2]);
+
+    // A stored part whose size differs is uploaded again
+    client.uploads.get('upload-1')!.set(2, Buffer.alloc(MiB));
+    client.failingPart = undefined;
+    client.uploadedParts = [];
+
+    const rs = await storage.putMultipart({
+      key: 'videos/b.mp4',
+      body: toStream(body),
+      partSize: PART_SIZE,
+      uploadId: 'upload-1',
+    });
+    expect(client.uploadedParts.sort((a, b) => a - b)).toEqual([2, 3]);
+    expect(rs).toMatchObject({ uploadId: 'upload-1', parts: 3, size: body.length });
+    expect(client.completed[0].etags.map(({ part }) => part)).toEqual([1, 2, 3]);
+  });
+
+  test('TC-003: aborts failed uploads', async () => {
+    const body = Buffer.alloc(11 * MiB, 3);
+    const { client, storage } = createStorage();
+
+    client.failingPart = 2;
+    await expect(
+      storage.putMultipart({ key: 'videos/c.mp4', body, partSize: PART_SIZE }),
+    ).rejects.toThrow('Connection reset');
+    expect(client.aborted).toEqual(['upload-1']);
+    expect(client.completed).toHaveLength(0);
+
+    await expect(
+      storage.putMultipart({
+        key: 'videos/c.mp4',
+        body,
+        partSize: PART_SIZE,
+        abortOnFailure: false,
+      }),
+    ).rejects.toThrow('Connection reset');
+    expect(client.aborted).toEqual(['upload-1']);
+    expect(client.uploads.has('upload-2')).toBe(true);
+  });
+});
@@ -0,0 +1,72 @@
+/**
+ * Tenant Test Suite
+ *
+ * Tests TenantContext and TenantResourcePool:
+ * 1. Tenant scoping — the tenant is visible across awaits and forwarded by fetchers
+ * 2. Resource pool — one resource per tenant, shared lookups and LRU eviction
+ *
+ * @module __tests__/tenant
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { RequestContextHeaders, RequestContextStorage } from '@/helpers/request-context';
+import { TenantContext, TenantResourcePool } from '@/helpers/tenant';
+
+describe('TenantContext', () => {
+  test('TC-001: scopes the tenant and keeps the request id', async () => {
+    expect(TenantContext.get()).toBeUndefined();
+    expect(() => TenantContext.getOrThrow()).toThrow();
+
+    await RequestContextStorage.run({
+      context: { requestId: 'req-1' },
+      task: () => {
+        return TenantContext.run({
+          tenantId: 'acme',
+          task: async () => {
+            await Promise.resolve();
+
+            expect(TenantContext.getOrThrow()).toBe('acme');
+            expect(RequestContextStorage.getPropagationHeaders()).toEqual({
+              [RequestContextHeaders.REQUEST_ID]: 'req-1',
+              [RequestContextHeaders.TENANT_ID]: 'acme',
+            });
+          },
+        });
+      },
+    });
+  });
+});
+
+describe('TenantResourcePool', () => {
+  test('TC-002: creates one resource per tenant and evicts the least recently used', async () => {
+    const created: Array<string> = [];
+    const disposed: Array<string> = [];
+
+    const pool = new TenantResourcePool({
+      maxSize: 2,
+      factory: async ({ tenantId }) => {
+        created.push(tenantId);
+        return { tenantId };
+      },
+      dispose: ({ tenantId }) => void disposed.push(tenantId),
+    });
+
+    const [first, second] = await Promise.all([
+      pool.get({ tenantId: 'acme' }),
+      pool.get({ tenantId: 'acme' }),
+    ]);
+    expect(first).toBe(second);
+
+    await pool.get({ tenantId: 'globex' });
+    await pool.get({ tenantId: 'acme' });
+    await pool.get({ tenantId: 'initech' });
+    await new Promise(resolve => setTimeout(resolve, 0));
+
+    expect(created).toEqual(['acme', 'globex', 'initech']);
+    expect(disposed).toEqual(['globex']);
+    expect(pool.has({ tenantId: 'acme' })).toBe(true);
+
+    const scoped = await TenantContext.run({ tenantId: 'initech', task: () => pool.get() });
+    expect(scoped.tenantId).toBe('initech');
+  });
+});
@@ -0,0 +1,56 @@
+/**
+ * Fake Data Test Suite
+ *
+ * Tests FakeDataGenerator:
+ * 1. The same seed yields the same sequence
+ * 2. Generated contacts, addresses and amounts follow the locale
+ *
+ * @module __tests__/testing/fake
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { EmailNormalizer, PhoneNumberNormalizer } from '@/helpers/notification';
+import { FakeDataGenerator } from '@/helpers/testing';
+
+describe('FakeDataGenerator', () => {
+  test('TC-001: is reproducible from the seed', () => {
+    const first = new FakeDataGenerator({ seed: 42 });
+    const second = new FakeDataGenerator({ seed: 42 });
+
+    const people = [first.person(), first.person()];
+    expect([second.person(), second.person()]).toEqual(people);
+
+    first.reseed(42);
+    expect(first.person()).toEqual(people[0]);
+    expect(new FakeDataGenerator({ seed: 7 }).person()).not.toEqual(people[0]);
+  });
+
+  test('TC-002: generates locale aware data', () => {
+    const fake = new FakeDataGenerator({ seed: 1, locale: 'vi-VN' });
+
+    for (let i = 0; i < 20; i++) {
+      const person = fake.person();
+
+      expect(person.fullName).toBe(`${person.lastName} ${person.firstName}`);
+      expect(EmailNormalizer.isValid(person.email)).toBe(true);
+      expect(person.email).toMatch(/^[a-z0-9.]+@example\.(com|net|org)$/);
+      expect(person.phone.startsWith('+84')).toBe(true);
+      expect(PhoneNumberNormalizer.isValid({ phone: person.phone })).toBe(true);
+    }
+
+    const address = fake.address({ locale: 'de-DE' });
+    expect(address.country).toBe('DE');
+    expect(address.postalCode).toMatch(/^[1-9]\d{4}$/);
+    expect(address.street).toMatch(/ \d+$/);
+
+    const vnd = fake.money({ min: 10_000, max: 50_000 });
+    expect(vnd.currency).toBe('VND');
+    expect(Number(vnd.amount)).toBeGreaterThanOrEqual(10_000);
+    expect(Number(vnd.amount)).toBeLessThanOrEqual(50_000);
+
+    const usd = fake.money({ min: 1, max: 2, currency: 'USD' });
+    expect(usd.amount).toMatch(/^[12]\.\d{2}$/);
+
+    expect(() => new FakeDataGenerator({ locale: 'xx-XX' })).toThrow();
+  });
+});
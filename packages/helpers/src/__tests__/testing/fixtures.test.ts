@@ -0,0 +1,84 @@
+/**
+ * Test Fixtures Test Suite
+ *
+ * Tests the fixture builders:
+ * 1. FixtureBuilder — deep overrides without leaking into the defaults
+ * 2. Fixtures — canned request options and errors
+ * 3. JWTFixtures — tokens verified against the served JWK set
+ *
+ * @module __tests__/testing/fixtures
+ */
+
+import { describe, test, expect, afterAll } from 'bun:test';
+import { JWKSHelper, JWTAlgorithms, JWTError, JWTHelper } from '@/helpers/auth';
+import { ValidationError } from '@/helpers/error';
+import { FixtureBuilder, Fixtures, JWTFixtures, MockServer } from '@/helpers/testing';
+
+describe('Fixtures', () => {
+  test('TC-001: builds deep copies with overrides applied', () => {
+    const configs = new FixtureBuilder({
+      defaults: { host: '127.0.0.1', port: 0, path: { base: '/api', isStrict: true }, tags: ['a'] },
+    });
+
+    const lenient = configs.with({ path: { isStrict: false } });
+    const built = lenient.build({ port: 3000, tags: ['b', 'c'] });
+
+    expect(built).toEqual({
+      host: '127.0.0.1',
+      port: 3000,
+      path: { base: '/api', isStrict: false },
+      tags: ['b', 'c'],
+    });
+    expect(configs.build().path.isStrict).toBe(true);
+
+    built.path.base = '/changed';
+    expect(lenient.build().path.base).toBe('/api');
+
+    const ports = configs.buildMany({ count: 3, overrides: index => ({ port: 3000 + index }) });
+    expect(ports.map(({ port }) => port)).toEqual([3000, 3001, 3002]);
+  });
+
+  test('TC-002: provides canned request options and errors', () => {
+    const options = Fixtures.requestOptions({ method: 'post', headers: { 'x-request-id': '1' } });
+    expect(options.method).toBe('post');
+    expect(options.headers).toEqual({ 'content-type': 'application/json', 'x-request-id': '1' });
+
+    const error = Fixtures.applicationError({ statusCode: 404, messageCode: 'NOT_FOUND' });
+    expect(error.statusCode).toBe(404);
+    expect(error.message).toBe('Test error');
+
+    const validation = Fixtures.validationError({ fields: [{}, { path: 'email' }] });
+    expect(validation).toBeInstanceOf(ValidationError);
+    expect(validation.fields.map(({ path }) => path)).toEqual(['name', 'email']);
+  });
+});
+
+describe('JWTFixtures', () => {
+  const server = new MockServer();
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-003: issues tokens verifiable through the served JWK set', async () => {
+    await server.start();
+
+    const tokens = new JWTFixtures({ issuer: 'https://auth.test', audience: 'api' });
+    const jwks = new JWKSHelper({ url: tokens.serve({ server }), cooldown: 0 });
+    const jwt = new JWTHelper({
+      algorithm: JWTAlgorithms.RS256,
+      issuer: 'https://auth.test',
+      audience: 'api',
+      keyResolver: jwks.getKeyResolver(),
+    });
+
+    const { payload } = await jwt.verify({ token: tokens.sign({ subject: '42' }) });
+    expect(payload.sub).toBe('42');
+
+    tokens.rotate();
+    await jwt.verify({ token: tokens.sign({ subject: '43' }) });
+
+    await expect(jwt.verify({ token: tokens.expired() })).rejects.toBeInstanceOf(JWTError);
+    await expect(jwt.verify({ token: tokens.forged() })).rejects.toBeInstanceOf(JWTError);
+  });
+});
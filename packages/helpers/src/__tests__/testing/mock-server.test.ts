@@ -0,0 +1,76 @@
+/**
+ * Mock Server Test Suite
+ *
+ * Tests MockServer:
+ * 1. Expectations match on method, path, query and body and render templated responses
+ * 2. Faults and `times` verification
+ *
+ * @module __tests__/testing/mock-server
+ */
+
+import { describe, test, expect, beforeAll, afterAll, beforeEach } from 'bun:test';
+import { MockServer, MockServerFaults } from '@/helpers/testing';
+
+describe('MockServer', () => {
+  const server = new MockServer();
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  beforeEach(() => {
+    server.reset();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: matches requests and renders templated responses', async () => {
+    server.when({ method: 'GET', path: '/orders/:id' }).respond({ json: { id: 'default' } });
+    server
+      .when({ method: 'GET', path: '/orders/:id', query: { expand: 'items' } })
+      .respond({ status: 200, json: { id: '{{params.id}}', expand: '{{query.expand}}' } });
+    server
+      .when({ method: 'POST', path: '/payments', body: { currency: 'USD' } })
+      .respond(request => ({ status: 201, json: { echoed: request.body } }));
+
+    const expanded = await fetch(`${server.getBaseUrl()}/orders/42?expand=items`);
+    expect(await expanded.json()).toEqual({ id: '42', expand: 'items' });
+
+    const plain = await fetch(`${server.getBaseUrl()}/orders/42`);
+    expect(await plain.json()).toEqual({ id: 'default' });
+
+    const payment = await fetch(`${server.getBaseUrl()}/payments`, {
+      method: 'POST',
+      headers: { 'content-type': 'application/json' },
+      body: JSON.stringify({ amount: '10.00', currency: 'USD' }),
+    });
+    expect(payment.status).toBe(201);
+    expect(await payment.json()).toEqual({ echoed: { amount: '10.00', currency: 'USD' } });
+
+    const unmatched = await fetch(`${server.getBaseUrl()}/payments`, { method: 'DELETE' });
+    expect(unmatched.status).toBe(404);
+    expect(server.requests).toHaveLength(4);
+  });
+
+  test('TC-002: injects faults and verifies expected calls', async () => {
+    server.when({ path: '/inventory/*' }).fault(MockServerFaults.CONNECTION_RESET);
+    server.when({ method: 'POST', path: '/refunds' }).respond({ status: 202, delay: 20 }).times(2);
+
+    await expect(fetch(`${server.getBaseUrl()}/inventory/sku-1`)).rejects.toThrow();
+
+    const startedAt = Date.now();
+    const refund = await fetch(`${server.getBaseUrl()}/refunds`, { method: 'POST' });
+    expect(refund.status).toBe(202);
+    expect(Date.now() - startedAt).toBeGreaterThanOrEqual(15);
+
+    expect(() => server.verify()).toThrow();
+    await fetch(`${server.getBaseUrl()}/refunds`, { method: 'POST' });
+    expect(() => server.verify()).not.toThrow();
+
+    // Exhausted expectations no longer match
+    const third = await fetch(`${server.getBaseUrl()}/refunds`, { method: 'POST' });
+    expect(third.status).toBe(404);
+  });
+});
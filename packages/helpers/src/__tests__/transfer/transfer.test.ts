@@ -0,0 +1,257 @@
+/**
+ * File Transfer Test Suite
+ *
+ * Tests the atomic uploads of the SFTP and FTP transfer helpers with in-memory clients:
+ * 1. SFTP uploads are written to the temporary `.part` file and renamed over the target
+ * 2. SFTP falls back to delete and rename without `posix-rename`, and removes the temporary
+ *    file when the upload fails
+ * 3. FTP uploads are renamed once complete, one command at a time, and cleaned up on failure
+ *
+ * @module __tests__/transfer/transfer
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { Client } from 'basic-ftp';
+import { Readable } from 'node:stream';
+import SftpClient from 'ssh2-sftp-client';
+import { FtpTransferHelper } from '@/helpers/transfer/ftp';
+import { SftpTransferHelper } from '@/helpers/transfer/sftp';
+
+const readBody = async (body: Buffer | Readable) => {
+  if (Buffer.isBuffer(body)) {
+    return body.toString('utf-8');
+  }
+
+  const chunks: Array<Buffer> = [];
+  for await (const chunk of body) {
+    chunks.push(Buffer.from(chunk));
+  }
+  return Buffer.concat(chunks).toString('utf-8');
+};
+
+// Remote files of the mock clients, calls are recorded as `<command> <path>`
+class RemoteFiles {
+  files = new Map<string, string>();
+  calls: Array<string> = [];
+  failing = new Set<string>();
+
+  run(command: string, ...paths: Array<string>) {
+    this.calls.push([command, ...paths].join(' '));
+
+    if (this.failing.has(command)) {
+      throw new Error(`${command} failed`);
+    }
+  }
+}
+
+// Calls of ssh2-sftp-client used by the helper
+class MockSftpClient extends RemoteFiles {
+  supportsPosixRename = true;
+
+  async put(body: Buffer | Readable, remotePath: string) {
+    const content = await readBody(body);
+    this.run('put', remotePath);
+    this.files.set(remotePath, content);
+  }
+
+  async posixRename(from: string, to: string) {
+    if (!this.supportsPosixRename) {
+      throw new Error('Server does not support posix-rename@openssh.com');
+    }
+
+    this.run('posixRename', from, to);
+    this.files.set(to, this.files.get(from)!);
+    this.files.delete(from);
+  }
+
+  async rename(from: string, to: string) {
+    this.run('rename', from, to);
+    if (this.files.has(to)) {
+      throw new Error(`${to} already exists`);
+    }
+
+    this.files.set(to, this.files.get(from)!);
+    this.files.delete(from);
+  }
+
+  async delete(remotePath: string, noErrorOK?: boolean) {
+    this.run('delete', remotePath);
+    if (!this.files.delete(remotePath) && !noErrorOK) {
+      throw new Error(`${remotePath} does not exist`);
+    }
+  }
+}
+
+// Calls of basic-ftp used by the helper, which rejects a command sent while another one runs
+class MockFtpClient extends RemoteFiles {
+  private running = false;
+
+  private async command<T>(task: () => Promise<T>) {
+    if (this.running) {
+      throw new Error('User launched a task while another one is still running');
+    }
+
+    this.running = true;
+    try {
+      await new Promise(resolve => setTimeout(resolve, 1));
+      return await task();
+    } finally {
+      this.running = false;
+    }
+  }
+
+  uploadFrom(body: Readable, remotePath: string) {
+    return this.command(async () => {
+      const content = await readBody(body);
+      this.run('uploadFrom', remotePath);
+      this.files.set(remotePath, content);
+    });
+  }
+
+  rename(from: string, to: string) {
+    return this.command(async () => {
+      this.run('rename', from, to);
+      this.files.set(to, this.files.get(from)!);
+      this.files.delete(from);
+    });
+  }
+
+  remove(remotePath: string, ignoreErrorCodes?: boolean) {
+    return this.command(async () => {
+      this.run('remove', remotePath);
+      if (!this.files.delete(remotePath) && !ignoreErrorCodes) {
+        throw new Error(`${remotePath} does not exist`);
+      }
+    });
+  }
+}
+
+const createSftp = () => {
+  const client = new MockSftpClient();
+  const sftp = new SftpTransferHelper({
+    host: 'sftp.partner.example',
+    username: 'ignis',
+    password: 'secret',
+  });
+  sftp.client = client as unknown as SftpClient;
+
+  return { client, sftp };
+};
+
+const createFtp = () => {
+  const client = new MockFtpClient();
+  const ftp = new FtpTransferHelper({ host: 'ftp.partner.example', user: 'ignis', password: 'x' });
+  ftp.client = client as unknown as Client;
+
+  return { client, ftp };
+};
+
+describe('File Transfer', () => {
+  test('TC-001: uploads through a temporary file renamed over the target', async () => {
+    const { client, sftp } = createSftp();
+    client.files.set('/inbound/orders.csv', 'previous');
+
+    const rs = await sftp.upload({ path: '/inbound/orders.csv', body: 'sku,quantity\n' });
+    expect(rs).toEqual({ path: '/inbound/orders.csv', size: 13 });
+    expect(client.calls).toEqual([
+      'put /inbound/.orders.csv.part',
+      'posixRename /inbound/.orders.csv.part /inbound/orders.csv',
+    ]);
+    expect([...client.files]).toEqual([['/inbound/orders.csv', 'sku,quantity\n']]);
+
+    // Streams have no known size
+    client.calls = [];
+    const streamed = await sftp.upload({
+      path: '/inbound/stock.csv',
+      body: Readable.from([Buffer.from('sku,'), Buffer.from('stock\n')]),
+      tempPath: '/tmp/stock.upload',
+    });
+    expect(streamed).toEqual({ path: '/inbound/stock.csv', size: undefined });
+    expect(client.calls).toEqual([
+      'put /tmp/stock.upload',
+      'posixRename /tmp/stock.upload /inbound/stock.csv',
+    ]);
+    expect(client.files.get('/inbound/stock.csv')).toBe('sku,stock\n');
+
+    client.calls = [];
+    await sftp.upload({ path: '/inbound/direct.csv', body: Buffer.from('a'), atomic: false });
+    expect(client.calls).toEqual(['put /inbound/direct.csv']);
+  });
+
+  test('TC-002: falls back to a plain rename and cleans up failed uploads', async () => {
+    const { client, sftp } = createSftp();
+    client.supportsPosixRename = false;
+    client.files.set('/inbound/orders.csv', 'previous');
+
+    await sftp.upload({ path: '/inbound/orders.csv', body: 'next' });
+    expect(client.calls).toEqual([
+      'put /inbound/.orders.csv.part',
+      'delete /inbound/orders.csv',
+      'rename /inbound/.orders.csv.part /inbound/orders.csv',
+    ]);
+    expect([...client.files]).toEqual([['/inbound/orders.csv', 'next']]);
+
+    // The target is never touched by a failed write, the partial file is removed
+    client.calls = [];
+    client.failing.add('put');
+    await expect(sftp.upload({ path: '/inbound/orders.csv', body: 'broken' })).rejects.toThrow(
+      'put failed',
+    );
+    expect(client.calls).toEqual([
+      'put /inbound/.orders.csv.part',
+      'delete /inbound/.orders.csv.part',
+    ]);
+    expect([...client.files]).toEqual([['/inbound/orders.csv', 'next']]);
+
+    // A failed rename leaves no temporary file either, the rename error is kept
+    client.calls = [];
+    client.failing = new Set(['rename']);
+    client.files.delete('/inbound/orders.csv');
+    await expect(sftp.upload({ path: '/inbound/orders.csv', body: 'late' })).rejects.toThrow(
+      'rename failed',
+    );
+    expect(client.calls.at(-1)).toBe('delete /inbound/.orders.csv.part');
+    expect(client.files.size).toBe(0);
+
+    // Cleanup errors do not hide the upload error
+    client.failing = new Set(['put', 'delete']);
+    await expect(sftp.upload({ path: '/inbound/orders.csv', body: 'late' })).rejects.toThrow(
+      'put failed',
+    );
+  });
+
+  test('TC-003: uploads over FTP one command at a time', async () => {
+    const { client, ftp } = createFtp();
+
+    const rs = await Promise.all([
+      ftp.upload({ path: '/inbound/a.csv', body: 'a' }),
+      ftp.upload({ path: '/inbound/b.csv', body: Buffer.from('bb') }),
+    ]);
+    expect(rs).toEqual([
+      { path: '/inbound/a.csv', size: 1 },
+      { path: '/inbound/b.csv', size: 2 },
+    ]);
+    expect(client.calls).toEqual([
+      'uploadFrom /inbound/.a.csv.part',
+      'uploadFrom /inbound/.b.csv.part',
+      'rename /inbound/.a.csv.part /inbound/a.csv',
+      'rename /inbound/.b.csv.part /inbound/b.csv',
+    ]);
+    expect(Object.fromEntries(client.files)).toEqual({
+      '/inbound/a.csv': 'a',
+      '/inbound/b.csv': 'bb',
+    });
+
+    client.calls = [];
+    client.failing.add('rename');
+    await expect(ftp.upload({ path: '/inbound/c.csv', body: 'c' })).rejects.toThrow(
+      'rename failed',
+    );
+    expect(client.calls).toEqual([
+      'uploadFrom /inbound/.c.csv.part',
+      'rename /inbound/.c.csv.part /inbound/c.csv',
+      'remove /inbound/.c.csv.part',
+    ]);
+    expect(client.files.has('/inbound/.c.csv.part')).toBe(false);
+  });
+});
@@ -0,0 +1,51 @@
+/**
+ * ULID & Worker ID Test Suite
+ *
+ * Tests UlidHelper and resolveWorkerId:
+ * 1. ULIDs minted in the same millisecond stay strictly ordered
+ * 2. ULIDs round trip through their UUID representation
+ * 3. Worker ids resolve from env, StatefulSet ordinals and pod names
+ *
+ * @module __tests__/uid/ulid
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { resolveWorkerId, UlidHelper } from '@/helpers/uid';
+
+describe('UlidHelper', () => {
+  test('TC-001: generates monotonic ids', () => {
+    const generator = new UlidHelper();
+    const ids = Array.from({ length: 1000 }, () => generator.nextId());
+
+    expect(ids.every(id => UlidHelper.isValid(id))).toBe(true);
+    expect([...ids].sort()).toEqual(ids);
+    expect(new Set(ids).size).toBe(ids.length);
+
+    const { timestamp } = generator.parseId(ids[0]);
+    expect(Math.abs(timestamp.getTime() - Date.now())).toBeLessThan(5_000);
+  });
+
+  test('TC-002: converts to and from uuid', () => {
+    const id = new UlidHelper().nextId();
+    const uuid = UlidHelper.toUuid(id);
+
+    expect(uuid).toMatch(/^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$/);
+    expect(UlidHelper.fromUuid(uuid)).toBe(id);
+    expect(() => UlidHelper.toUuid('not-a-ulid')).toThrow();
+  });
+});
+
+describe('resolveWorkerId', () => {
+  test('TC-003: resolves from env and pod name', () => {
+    expect(resolveWorkerId({ env: { APP_ENV_WORKER_ID: '42' } })).toBe(42);
+    expect(() => resolveWorkerId({ env: { APP_ENV_WORKER_ID: '4096' } })).toThrow();
+    expect(resolveWorkerId({ env: { POD_NAME: 'orders-3' } })).toBe(3);
+    expect(resolveWorkerId({ env: { HOSTNAME: 'laptop' } })).toBeUndefined();
+
+    const hashed = resolveWorkerId({
+      env: { KUBERNETES_SERVICE_HOST: '10.0.0.1', HOSTNAME: 'orders-7d9f8b6c4-x2kzq' },
+    });
+    expect(hashed).toBeGreaterThanOrEqual(0);
+    expect(hashed).toBeLessThanOrEqual(1023);
+  });
+});
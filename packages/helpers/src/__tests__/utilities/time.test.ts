@@ -0,0 +1,67 @@
+/**
+ * Time Utilities Test Suite
+ *
+ * Tests the time utilities:
+ * 1. RFC 3339 formatting, parsing and timezone conversion
+ * 2. Business day calculations with holidays
+ * 3. Humanized durations
+ *
+ * @module __tests__/utilities/time
+ */
+
+import { describe, test, expect } from 'bun:test';
+import {
+  addBusinessDays,
+  convertTimezone,
+  countBusinessDays,
+  humanizeDuration,
+  isRFC3339,
+  parseRFC3339,
+  toRFC3339,
+} from '@/utilities/time.utility';
+
+describe('time utilities', () => {
+  test('TC-001: formats and parses RFC 3339 in any timezone', () => {
+    const date = new Date('2025-03-01T08:30:00Z');
+
+    expect(toRFC3339({ date })).toBe('2025-03-01T08:30:00.000Z');
+    expect(toRFC3339({ date, timezone: 'Asia/Ho_Chi_Minh' })).toBe(
+      '2025-03-01T15:30:00.000+07:00',
+    );
+    expect(convertTimezone({ date, timezone: 'America/New_York' }).hour()).toBe(3);
+
+    expect(parseRFC3339('2025-03-01T15:30:00+07:00').getTime()).toBe(date.getTime());
+    expect(isRFC3339('2025-03-01T15:30:00')).toBe(false);
+    expect(isRFC3339('2025-03-01')).toBe(false);
+    expect(() => parseRFC3339('yesterday')).toThrow();
+    expect(() => convertTimezone({ date, timezone: 'Mars/Olympus_Mons' })).toThrow();
+  });
+
+  test('TC-002: counts business days skipping weekends and holidays', () => {
+    const calendar = { timezone: 'UTC', holidays: ['2025-01-01'] };
+
+    // Tuesday 2024-12-31 + 2 business days skips the new year holiday
+    const rs = addBusinessDays({ date: '2024-12-31T10:00:00Z', days: 2, ...calendar });
+    expect(rs.format('YYYY-MM-DD HH:mm')).toBe('2025-01-03 10:00');
+
+    // Friday + 1 business day => Monday, Friday - 1 => Thursday
+    const friday = '2025-01-10T12:00:00Z';
+    expect(addBusinessDays({ date: friday, days: 1, ...calendar }).date()).toBe(13);
+    expect(addBusinessDays({ date: friday, days: -1, ...calendar }).date()).toBe(9);
+
+    const monday = '2024-12-30T12:00:00Z';
+    const nextMonday = '2025-01-06T12:00:00Z';
+    expect(countBusinessDays({ from: monday, to: nextMonday, ...calendar })).toBe(4);
+    expect(countBusinessDays({ from: nextMonday, to: monday, ...calendar })).toBe(-4);
+  });
+
+  test('TC-003: humanizes durations', () => {
+    expect(humanizeDuration({ ms: 7_530_000 })).toBe('2h 5m');
+    expect(humanizeDuration({ ms: 90_061_000, largest: 3, style: 'long' })).toBe(
+      '1 day 1 hour 1 minute',
+    );
+    expect(humanizeDuration({ ms: 850 })).toBe('850ms');
+    expect(humanizeDuration({ ms: -61_000 })).toBe('-1m 1s');
+    expect(humanizeDuration({ ms: 0 })).toBe('0ms');
+  });
+});
@@ -0,0 +1,73 @@
+/**
+ * Validator Test Suite
+ *
+ * Tests Validator and ValidationRules:
+ * 1. Failures of every field are accumulated, each field stops at its first failure
+ * 2. Async custom rules and wildcard paths
+ *
+ * @module __tests__/validation/validator
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { ValidationError } from '@/helpers/error';
+import { ValidationRules, Validator } from '@/helpers/validation';
+
+describe('Validator', () => {
+  test('TC-001: accumulates field errors', async () => {
+    const validator = new Validator({
+      schema: {
+        email: [ValidationRules.required(), ValidationRules.email()],
+        name: [ValidationRules.required(), ValidationRules.length({ min: 2, max: 8 })],
+        phone: [ValidationRules.phone()],
+        age: [ValidationRules.range({ min: 18 })],
+      },
+    });
+
+    const fields = await validator.validate({ data: { email: 'not-an-email', age: 12 } });
+
+    expect(fields.map(({ path, code }) => ({ path, code }))).toEqual([
+      { path: 'email', code: 'invalid_email' },
+      { path: 'name', code: 'required' },
+      { path: 'age', code: 'out_of_range' },
+    ]);
+    expect(
+      await validator.validate({
+        data: { email: 'jane@example.com', name: 'Jane', phone: '+84 (912) 345-678', age: 30 },
+      }),
+    ).toEqual([]);
+  });
+
+  test('TC-002: runs async rules on wildcard paths', async () => {
+    const takenEmails = new Set(['taken@example.com']);
+    const validator = new Validator({
+      schema: {
+        email: [
+          ValidationRules.custom({
+            code: 'already_taken',
+            message: 'Email is already taken',
+            test: async (email: string) => !takenEmails.has(email),
+          }),
+        ],
+        'items.*.quantity': [ValidationRules.required(), ValidationRules.range({ min: 1 })],
+      },
+    });
+
+    const error = await validator
+      .assert({
+        data: { email: 'taken@example.com', items: [{ quantity: 2 }, { quantity: 0 }, {}] },
+        target: 'json',
+      })
+      .catch(e => e);
+
+    expect(ValidationError.isValidationError(error)).toBe(true);
+    expect(error.statusCode).toBe(422);
+    expect(error.target).toBe('json');
+
+    const failures = (error as ValidationError).fields.map(({ path, code }) => `${path}:${code}`);
+    expect(failures).toEqual([
+      'email:already_taken',
+      'items.1.quantity:out_of_range',
+      'items.2.quantity:required',
+    ]);
+  });
+});
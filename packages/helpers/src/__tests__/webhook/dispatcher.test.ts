@@ -0,0 +1,296 @@
+/**
+ * Webhook Dispatcher Test Suite
+ *
+ * Tests WebhookDispatcherHelper against a mock subscriber:
+ * 1. Deliveries are signed, the receiver verifies them with the endpoint secret
+ * 2. Retryable failures are retried on the backoff schedule, signed again for every attempt
+ * 3. Deliveries fail after `maxAttempts`, or at once on a non retryable status
+ * 4. Redelivering cancels the pending retry of the delivery
+ * 5. `start` picks up the unfinished deliveries of the store
+ *
+ * @module __tests__/webhook/dispatcher
+ */
+
+import { describe, test, expect, afterAll, beforeAll } from 'bun:test';
+import { MockServer } from '@/helpers/testing';
+import {
+  IWebhookDelivery,
+  IWebhookDispatcherOptions,
+  MemoryWebhookDeliveryStore,
+  WebhookDeliveryStatuses,
+  WebhookDispatcherHelper,
+  WebhookHeaders,
+  WebhookVerifier,
+} from '@/helpers/webhook';
+
+const SECRET = 'whsec_partner';
+
+// Dispatcher whose `settled` resolves once a delivery succeeded or failed for good
+const createDispatcher = (opts: Partial<IWebhookDispatcherOptions> = {}) => {
+  let settle: (delivery: IWebhookDelivery) => void = () => {};
+  const settled = new Promise<IWebhookDelivery>(resolve => {
+    settle = resolve;
+  });
+
+  const dispatcher = new WebhookDispatcherHelper({
+    identifier: 'order-webhooks',
+    onDelivered: ({ delivery }) => settle(delivery),
+    onFailed: ({ delivery }) => settle(delivery),
+    ...opts,
+  });
+
+  return { dispatcher, settled };
+};
+
+// Resolve once `check` passes, polling every few milliseconds
+const waitFor = async (check: () => boolean | Promise<boolean>) => {
+  while (!(await check())) {
+    await new Promise(resolve => setTimeout(resolve, 5));
+  }
+};
+
+describe('WebhookDispatcherHelper', () => {
+  const server = new MockServer();
+  const getRequests = (path: string) => server.requests.filter(rq => rq.path === path);
+
+  beforeAll(async () => {
+    await server.start();
+  });
+
+  afterAll(async () => {
+    await server.stop();
+  });
+
+  test('TC-001: signs the delivered requests', async () => {
+    server.when({ method: 'POST', path: '/hooks/signed' }).respond({ status: 204 });
+    const { dispatcher, settled } = createDispatcher();
+
+    const { id } = await dispatcher.dispatch({
+      endpoint: {
+        url: `${server.getBaseUrl()}/hooks/signed`,
+        secret: SECRET,
+        headers: { 'x-partner-id': 'acme' },
+      },
+      event: 'order.created',
+      payload: { orderId: 1 },
+    });
+
+    const delivery = await settled;
+    expect(delivery.status).toBe(WebhookDeliveryStatuses.SUCCEEDED);
+
+    const [request] = getRequests('/hooks/signed');
+    expect(request.headers).toMatchObject({
+      'content-type': 'application/json; charset=utf-8',
+      'x-partner-id': 'acme',
+      [WebhookHeaders.ID]: id,
+      [WebhookHeaders.EVENT]: 'order.created',
+      [WebhookHeaders.ATTEMPT]: '1',
+    });
+
+    const event = WebhookVerifier.verify({
+      body: request.rawBody,
+      header: request.headers[WebhookHeaders.SIGNATURE],
+      secrets: SECRET,
+    });
+    expect(event).toMatchObject({ id, event: 'order.created', data: { orderId: 1 } });
+    expect(request.headers[WebhookHeaders.SIGNATURE]).toMatch(
+      new RegExp(`^t=${request.headers[WebhookHeaders.TIMESTAMP]},v1=[0-9a-f]{64}$`),
+    );
+    expect(() =>
+      WebhookVerifier.verify({
+        body: request.rawBody,
+        header: request.headers[WebhookHeaders.SIGNATURE],
+        secrets: 'whsec_other',
+      }),
+    ).toThrow();
+
+    dispatcher.close();
+  });
+
+  test('TC-002: retries retryable failures on the backoff schedule', async () => {
+    let calls = 0;
+    server
+      .when({ method: 'POST', path: '/hooks/flaky' })
+      .respond(() => ({ status: ++calls === 1 ? 503 : 204 }));
+    const { dispatcher, settled } = createDispatcher({
+      retry: { baseDelay: 20, factor: 2, maxDelay: 100 },
+    });
+
+    // Jittered within the upper half of the exponential delay, capped by `maxDelay`
+    for (let i = 0; i < 20; i++) {
+      for (const [attempt, delay] of [
+        [1, 20],
+        [2, 40],
+        [3, 80],
+      ]) {
+        const backoff = dispatcher.getBackoffDelay({ attempt });
+        expect(backoff).toBeGreaterThanOrEqual(delay / 2);
+        expect(backoff).toBeLessThanOrEqual(delay);
+      }
+      expect(dispatcher.getBackoffDelay({ attempt: 5 })).toBe(100);
+    }
+    expect(dispatcher.isRetryable({ statusCode: 429 })).toBe(true);
+    expect(dispatcher.isRetryable({ statusCode: 400 })).toBe(false);
+
+    await dispatcher.dispatch({
+      endpoint: { url: `${server.getBaseUrl()}/hooks/flaky`, secret: SECRET },
+      event: 'order.paid',
+      payload: { orderId: 2 },
+    });
+
+    const delivery = await settled;
+    expect(delivery.status).toBe(WebhookDeliveryStatuses.SUCCEEDED);
+    expect(delivery.attempts.map(attempt => attempt.statusCode)).toEqual([503, 204]);
+
+    // The retry waited for its backoff
+    const [first, second] = delivery.attempts;
+    const waited = new Date(second.startedAt).getTime() - new Date(first.finishedAt).getTime();
+    expect(waited).toBeGreaterThanOrEqual(9);
+
+    const requests = getRequests('/hooks/flaky');
+    expect(requests.map(rq => rq.headers[WebhookHeaders.ATTEMPT])).toEqual(['1', '2']);
+    for (const request of requests) {
+      WebhookVerifier.verify({
+        body: request.rawBody,
+        header: request.headers[WebhookHeaders.SIGNATURE],
+        secrets: SECRET,
+      });
+    }
+
+    dispatcher.close();
+  });
+
+  test('TC-003: gives up after the max attempts', async () => {
+    server.when({ method: 'POST', path: '/hooks/down' }).respond({ status: 500, body: 'down' });
+    server.when({ method: 'POST', path: '/hooks/invalid' }).respond({ status: 400 });
+
+    const down = createDispatcher({ retry: { maxAttempts: 3, baseDelay: 2 } });
+    await down.dispatcher.dispatch({
+      endpoint: { url: `${server.getBaseUrl()}/hooks/down`, secret: SECRET },
+      event: 'order.refunded',
+      payload: { orderId: 3 },
+    });
+
+    const failed = await down.settled;
+    expect(failed.status).toBe(WebhookDeliveryStatuses.FAILED);
+    expect(failed.nextAttemptAt).toBeUndefined();
+    expect(failed.attempts.map(attempt => attempt.statusCode)).toEqual([500, 500, 500]);
+    expect(failed.attempts[0].responseBody).toBe('down');
+    expect(getRequests('/hooks/down')).toHaveLength(3);
+
+    const invalid = createDispatcher({ retry: { maxAttempts: 3, baseDelay: 2 } });
+    await invalid.dispatcher.dispatch({
+      endpoint: { url: `${server.getBaseUrl()}/hooks/invalid`, secret: SECRET },
+      event: 'order.refunded',
+      payload: { orderId: 4 },
+    });
+
+    const rejected = await invalid.settled;
+    expect(rejected.status).toBe(WebhookDeliveryStatuses.FAILED);
+    expect(rejected.attempts).toHaveLength(1);
+    expect(getRequests('/hooks/invalid')).toHaveLength(1);
+
+    down.dispatcher.close();
+    invalid.dispatcher.close();
+  });
+
+  test('TC-004: cancels the pending retry when redelivering', async () => {
+    server.when({ method: 'POST', path: '/hooks/redeliver' }).respond({ status: 503 });
+
+    // The first retry is due within 100ms, the next one after 500ms at least
+    const { dispatcher } = createDispatcher({
+      retry: { baseDelay: 100, factor: 10, maxDelay: 1_000 },
+    });
+    const { id } = await dispatcher.dispatch({
+      endpoint: { url: `${server.getBaseUrl()}/hooks/redeliver`, secret: SECRET },
+      event: 'order.updated',
+      payload: { orderId: 5 },
+    });
+
+    const isRetrying = async (attempts: number) => {
+      const delivery = await dispatcher.getDelivery({ id });
+      return (
+        delivery?.status === WebhookDeliveryStatuses.RETRYING &&
+        delivery.attempts.length === attempts
+      );
+    };
+
+    await waitFor(() => isRetrying(1));
+    await dispatcher.redeliver({ id });
+    await waitFor(() => isRetrying(2));
+
+    // The first retry would have sent the payload again by now
+    await new Promise(resolve => setTimeout(resolve, 150));
+    expect(getRequests('/hooks/redeliver')).toHaveLength(2);
+    expect((await dispatcher.getDelivery({ id }))?.attempts).toHaveLength(2);
+
+    dispatcher.close();
+  });
+
+  test('TC-005: recovers the unfinished deliveries of the store on start', async () => {
+    server.when({ method: 'POST', path: '/hooks/recovered' }).respond({ status: 204 });
+
+    // Left behind by a previous process
+    const store = new MemoryWebhookDeliveryStore();
+    const now = Date.now();
+    const createdAt = new Date(now - 60_000).toISOString();
+    const base = {
+      event: 'order.shipped',
+      endpoint: { url: `${server.getBaseUrl()}/hooks/recovered`, secret: SECRET },
+      payload: { orderId: 6 },
+      attempts: [],
+      maxAttempts: 3,
+      createdAt,
+      updatedAt: createdAt,
+    };
+    const deliveries: Array<IWebhookDelivery> = [
+      { ...base, id: 'pending', status: WebhookDeliveryStatuses.PENDING },
+      { ...base, id: 'interrupted', status: WebhookDeliveryStatuses.PROCESSING },
+      {
+        ...base,
+        id: 'due',
+        status: WebhookDeliveryStatuses.RETRYING,
+        nextAttemptAt: new Date(now - 1_000).toISOString(),
+      },
+      {
+        ...base,
+        id: 'later',
+        status: WebhookDeliveryStatuses.RETRYING,
+        nextAttemptAt: new Date(now + 100).toISOString(),
+      },
+      { ...base, id: 'done', status: WebhookDeliveryStatuses.SUCCEEDED },
+    ];
+    for (const delivery of deliveries) {
+      store.save({ delivery });
+    }
+
+    const delivered: Array<IWebhookDelivery> = [];
+    const dispatcher = new WebhookDispatcherHelper({
+      identifier: 'order-webhooks',
+      store,
+      onDelivered: ({ delivery }) => {
+        delivered.push(delivery);
+      },
+    });
+
+    expect(await dispatcher.start()).toBe(4);
+    await waitFor(() => delivered.length === 4);
+
+    expect(delivered.map(delivery => delivery.id)).toEqual([
+      'pending',
+      'interrupted',
+      'due',
+      'later',
+    ]);
+
+    // Not before its `nextAttemptAt`
+    const [later] = delivered[3].attempts;
+    expect(new Date(later.startedAt).getTime()).toBeGreaterThanOrEqual(now + 100);
+
+    const ids = getRequests('/hooks/recovered').map(rq => rq.headers[WebhookHeaders.ID]);
+    expect(ids).toEqual(['pending', 'interrupted', 'due', 'later']);
+    expect((await store.findById({ id: 'done' }))?.attempts).toHaveLength(0);
+
+    dispatcher.close();
+  });
+});
@@ -0,0 +1,276 @@
+/**
+ * Webhook Signature Test Suite
+ *
+ * Tests the Stripe-style webhook signature helpers:
+ * 1. WebhookSigner — header format and deterministic signatures
+ * 2. WebhookVerifier — header parsing, tolerance window, secret rotation, payload parsing
+ * 3. Nonce signatures — replays rejected for the whole tolerance window
+ *
+ * @module __tests__/webhook/signature
+ */
+
+import { describe, test, expect } from 'bun:test';
+import { ApplicationError } from '@/helpers/error';
+import {
+  IWebhookNonceStore,
+  MemoryWebhookNonceStore,
+  WebhookDefaults,
+  WebhookErrorCodes,
+  WebhookSigner,
+  WebhookVerifier,
+} from '@/helpers/webhook';
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+const SECRET = 'whsec_current';
+const PREVIOUS_SECRET = 'whsec_previous';
+const BODY = JSON.stringify({ id: 'evt_1', event: 'order.created', data: { orderId: 1 } });
+const NOW = 1_760_000_000;
+
+const expectWebhookError = (fn: () => unknown, messageCode: string) => {
+  try {
+    fn();
+  } catch (error) {
+    expect(error).toBeInstanceOf(ApplicationError);
+    expect((error as ApplicationError).messageCode).toBe(messageCode);
+    return;
+  }
+
+  throw new Error('Expected webhook verification to throw');
+};
+
+// Nonce store on a clock moved by the test, claimed ttls are recorded
+class ClockNonceStore implements IWebhookNonceStore {
+  now = NOW * 1000;
+  ttls: Array<number> = [];
+  private nonces = new Map<string, number>();
+
+  claim(opts: { nonce: string; ttl: number }) {
+    const { nonce, ttl } = opts;
+    this.ttls.push(ttl);
+
+    const expiresAt = this.nonces.get(nonce);
+    if (expiresAt !== undefined && expiresAt > this.now) {
+      return false;
+    }
+
+    this.nonces.set(nonce, this.now + ttl);
+    return true;
+  }
+}
+
+// =============================================================================
+// WebhookSigner
+// =============================================================================
+
+describe('Webhook Signature', () => {
+  describe('WebhookSigner', () => {
+    test('TC-001: should build header with timestamp and v1 signature', () => {
+      const { header, signature } = WebhookSigner.sign({
+        secret: SECRET,
+        body: BODY,
+        timestamp: NOW,
+      });
+      expect(header).toBe(`t=${NOW},v1=${signature}`);
+      expect(signature).toMatch(/^[0-9a-f]{64}$/);
+    });
+
+    test('TC-002: should produce different signatures for different secrets', () => {
+      const a = WebhookSigner.computeSignature({ secret: SECRET, timestamp: NOW, body: BODY });
+      const b = WebhookSigner.computeSignature({
+        secret: PREVIOUS_SECRET,
+        timestamp: NOW,
+        body: BODY,
+      });
+      expect(a).not.toBe(b);
+    });
+  });
+
+  // ===========================================================================
+  // WebhookVerifier
+  // ===========================================================================
+
+  describe('WebhookVerifier', () => {
+    test('TC-003: should return parsed payload for a valid signature', () => {
+      const { header } = WebhookSigner.sign({ secret: SECRET, body: BODY, timestamp: NOW });
+      const payload = WebhookVerifier.verify<{ id: string; data: { orderId: number } }>({
+        body: BODY,
+        header,
+        secrets: SECRET,
+        now: NOW,
+      });
+
+      expect(payload.id).toBe('evt_1');
+      expect(payload.data.orderId).toBe(1);
+    });
+
+    test('TC-004: should accept any of the active secrets', () => {
+      const { header } = WebhookSigner.sign({
+        secret: PREVIOUS_SECRET,
+        body: BODY,
+        timestamp: NOW,
+      });
+      const payload = WebhookVerifier.verify({
+        body: Buffer.from(BODY),
+        header,
+        secrets: [SECRET, PREVIOUS_SECRET],
+        now: NOW,
+      });
+
+      expect(payload.event).toBe('order.created');
+    });
+
+    test('TC-005: should accept header carrying multiple v1 signatures', () => {
+      const current = WebhookSigner.computeSignature({
+        secret: SECRET,
+        timestamp: NOW,
+        body: BODY,
+      });
+      const header = `t=${NOW},v1=deadbeef,v1=${current}`;
+
+      const parsed = WebhookVerifier.verifySignature({
+        body: BODY,
+        header,
+        secrets: SECRET,
+        now: NOW,
+      });
+      expect(parsed.signatures).toHaveLength(2);
+    });
+
+    test('TC-006: should reject missing header', () => {
+      expectWebhookError(
+        () => WebhookVerifier.verify({ body: BODY, header: undefined, secrets: SECRET }),
+        WebhookErrorCodes.MISSING_SIGNATURE,
+      );
+    });
+
+    test('TC-007: should reject malformed header', () => {
+      expectWebhookError(
+        () => WebhookVerifier.verify({ body: BODY, header: 'v1=abc', secrets: SECRET }),
+        WebhookErrorCodes.MALFORMED_SIGNATURE,
+      );
+    });
+
+    test('TC-008: should reject timestamp outside tolerance window', () => {
+      const { header } = WebhookSigner.sign({ secret: SECRET, body: BODY, timestamp: NOW - 301 });
+      expectWebhookError(
+        () => WebhookVerifier.verify({ body: BODY, header, secrets: SECRET, now: NOW }),
+        WebhookErrorCodes.TIMESTAMP_OUT_OF_TOLERANCE,
+      );
+    });
+
+    test('TC-009: should reject tampered body', () => {
+      const { header } = WebhookSigner.sign({ secret: SECRET, body: BODY, timestamp: NOW });
+      expectWebhookError(
+        () => WebhookVerifier.verify({ body: `${BODY} `, header, secrets: SECRET, now: NOW }),
+        WebhookErrorCodes.SIGNATURE_MISMATCH,
+      );
+    });
+
+    test('TC-010: should reject signature computed with unknown secret', () => {
+      const { header } = WebhookSigner.sign({ secret: 'whsec_other', body: BODY, timestamp: NOW });
+      expectWebhookError(
+        () => WebhookVerifier.verify({ body: BODY, header, secrets: [SECRET], now: NOW }),
+        WebhookErrorCodes.SIGNATURE_MISMATCH,
+      );
+    });
+
+    test('TC-011: should reject non-JSON body with valid signature', () => {
+      const body = 'not-json';
+      const { header } = WebhookSigner.sign({ secret: SECRET, body, timestamp: NOW });
+      expectWebhookError(
+        () => WebhookVerifier.verify({ body, header, secrets: SECRET, now: NOW }),
+        WebhookErrorCodes.INVALID_PAYLOAD,
+      );
+    });
+  });
+
+  // ===========================================================================
+  // Nonce
+  // ===========================================================================
+
+  describe('WebhookVerifier.verifyOnce', () => {
+    test('TC-012: should sign the nonce and reject its replay', async () => {
+      const nonce = WebhookSigner.generateNonce();
+      const { header } = WebhookSigner.sign({ secret: SECRET, body: BODY, timestamp: NOW, nonce });
+      expect(header).toMatch(new RegExp(`^t=${NOW},n=${nonce},v1=[0-9a-f]{64}$`));
+
+      const nonceStore = new MemoryWebhookNonceStore();
+      const verify = () =>
+        WebhookVerifier.verifyOnce({ body: BODY, header, secrets: SECRET, nonceStore, now: NOW });
+
+      expect((await verify()).id).toBe('evt_1');
+      await expect(verify()).rejects.toMatchObject({
+        messageCode: WebhookErrorCodes.REPLAYED_NONCE,
+      });
+
+      const tampered = header.replace(`n=${nonce}`, `n=${WebhookSigner.generateNonce()}`);
+      expectWebhookError(
+        () => WebhookVerifier.verify({ body: BODY, header: tampered, secrets: SECRET, now: NOW }),
+        WebhookErrorCodes.SIGNATURE_MISMATCH,
+      );
+    });
+
+    test('TC-013: should reject signatures without nonce', async () => {
+      const { header } = WebhookSigner.sign({ secret: SECRET, body: BODY, timestamp: NOW });
+      await expect(
+        WebhookVerifier.verifyOnce({
+          body: BODY,
+          header,
+          secrets: SECRET,
+          nonceStore: new MemoryWebhookNonceStore(),
+          now: NOW,
+        }),
+      ).rejects.toMatchObject({ messageCode: WebhookErrorCodes.MISSING_NONCE });
+    });
+
+    test('TC-014: should reject replays of old signatures for the whole window', async () => {
+      const tolerance = WebhookDefaults.SIGNATURE_TOLERANCE;
+      const nonceStore = new ClockNonceStore();
+
+      // Signed close to the end of the window, the nonce outlives it anyway
+      const { header } = WebhookSigner.sign({
+        secret: SECRET,
+        body: BODY,
+        timestamp: NOW - tolerance + 1,
+        nonce: WebhookSigner.generateNonce(),
+      });
+      const verify = (now: number) =>
+        WebhookVerifier.verifyOnce({ body: BODY, header, secrets: SECRET, nonceStore, now });
+
+      expect((await verify(NOW)).id).toBe('evt_1');
+      expect(nonceStore.ttls).toEqual([2 * tolerance * 1000]);
+
+      nonceStore.now += 2_000;
+      await expect(verify(NOW)).rejects.toMatchObject({
+        messageCode: WebhookErrorCodes.REPLAYED_NONCE,
+      });
+      await expect(verify(NOW + 2)).rejects.toMatchObject({
+        messageCode: WebhookErrorCodes.TIMESTAMP_OUT_OF_TOLERANCE,
+      });
+
+      // Any timestamp would be accepted without a window
+      const { header: old } = WebhookSigner.sign({
+        secret: SECRET,
+        body: BODY,
+        timestamp: NOW - 24 * 60 * 60,
+        nonce: WebhookSigner.generateNonce(),
+      });
+      for (const value of [0, -1]) {
+        await expect(
+          WebhookVerifier.verifyOnce({
+            body: BODY,
+            header: old,
+            secrets: SECRET,
+            nonceStore,
+            tolerance: value,
+            now: NOW,
+          }),
+        ).rejects.toMatchObject({ statusCode: 500 });
+      }
+      expect(nonceStore.ttls).toHaveLength(2);
+    });
+  });
+});
@@ -95,6 +95,8 @@ export class HTTP {
       TooManyRequests: 429,
       RequestHeaderFieldsTooLarge: 431,
       UnavailableForLegalReasons: 451,
+      // Non standard (nginx), the client went away before the response
+      ClientClosedRequest: 499,
     },
 
     // 5xx server error – the server failed to fulfil an apparently valid request
@@ -0,0 +1,99 @@
+import { AnyObject } from '@/common/types';
+import { BaseHelper } from '@/helpers/base';
+import { RequestContextStorage } from '@/helpers/request-context';
+import C from 'node:crypto';
+import { AuditOutcomes } from './constants';
+import { IAuditEvent, IAuditSink, TAuditEventInput } from './types';
+
+// --------------------------------------------------------
+/**
+ * Records audit events to every configured sink.
+ *
+ * Missing fields are taken from the request context: `correlationId` is the request id,
+ * `tenantId` the resolved tenant and `actor.id` the authenticated user. A failing sink is
+ * logged and does not prevent the others from receiving the event, unless `isStrict` is set.
+ *
+ * @example
+ * ```typescript
+ * const auditLogger = new AuditLogger({
+ *   sinks: [new QueueAuditSink({ topic: 'audit-events', publish: ({ topic, event }) => ... })],
+ * });
+ *
+ * await auditLogger.record({
+ *   action: 'order.cancel',
+ *   resource: { type: 'order', id: order.id },
+ *   before: order,
+ *   after: cancelled,
+ * });
+ * ```
+ */
+export class AuditLogger extends BaseHelper {
+  private sinks: Array<IAuditSink>;
+  private isStrict: boolean;
+
+  constructor(opts: { sinks: Array<IAuditSink>; isStrict?: boolean; identifier?: string }) {
+    super({ scope: AuditLogger.name, identifier: opts.identifier ?? AuditLogger.name });
+
+    this.sinks = opts.sinks;
+    this.isStrict = opts.isStrict ?? false;
+  }
+
+  // --------------------------------------------------------
+  build<TState extends AnyObject = AnyObject>(
+    input: TAuditEventInput<TState>,
+  ): IAuditEvent<TState> {
+    const context = RequestContextStorage.get();
+    const userId = context?.userId;
+
+    return {
+      ...input,
+      id: input.id ?? C.randomUUID(),
+      outcome: input.outcome ?? AuditOutcomes.SUCCESS,
+      occurredAt: input.occurredAt ?? new Date(),
+      actor: {
+        ...input.actor,
+        id: input.actor?.id ?? (userId !== undefined ? `${userId}` : undefined),
+      },
+      correlationId: input.correlationId ?? context?.requestId,
+      tenantId: input.tenantId ?? context?.tenantId,
+    };
+  }
+
+  async record<TState extends AnyObject = AnyObject>(
+    input: TAuditEventInput<TState>,
+  ): Promise<IAuditEvent<TState>> {
+    const event = this.build(input);
+    await this.write({ events: [event] });
+    return event;
+  }
+
+  async write(opts: { events: Array<IAuditEvent> }) {
+    const { events } = opts;
+    if (!events.length) {
+      return;
+    }
+
+    const results = await Promise.allSettled(this.sinks.map(sink => sink.write({ events })));
+
+    const failures: Array<unknown> = [];
+    results.forEach((rs, index) => {
+      if (rs.status === 'fulfilled') {
+        return;
+      }
+
+      failures.push(rs.reason);
+      this.logger
+        .for(this.write.name)
+        .error(
+          'Failed to write audit events | sink: %s | events: %s | Error: %s',
+          this.sinks[index].name,
+          events.map(event => event.id),
+          rs.reason,
+        );
+    });
+
+    if (this.isStrict && failures.length) {
+      throw failures[0];
+    }
+  }
+}
@@ -0,0 +1,14 @@
+import { TConstValue } from '@/common/types';
+
+export class AuditOutcomes {
+  static readonly SUCCESS = 'success';
+  static readonly FAILURE = 'failure';
+
+  static readonly SCHEME_SET = new Set([this.SUCCESS, this.FAILURE]);
+
+  static isValid(input: string): boolean {
+    return this.SCHEME_SET.has(input);
+  }
+}
+
+export type TAuditOutcome = TConstValue<typeof AuditOutcomes>;
@@ -0,0 +1,4 @@
+export * from './audit-logger';
+export * from './constants';
+export * from './sinks';
+export * from './types';
@@ -0,0 +1,54 @@
+import { ValueOrPromise } from '@/common/types';
+import { IAuditEvent, IAuditSink } from './types';
+
+// --------------------------------------------------------
+/**
+ * Publishes every event to a queue topic, e.g. a BullMQ queue or an MQTT/Kafka topic, for
+ * consumers shipping them to long term storage.
+ *
+ * @example
+ * ```typescript
+ * new QueueAuditSink({
+ *   topic: 'audit-events',
+ *   publish: ({ event }) => auditQueue.queue.add(event.action, event, { jobId: event.id }),
+ * });
+ * ```
+ */
+export class QueueAuditSink implements IAuditSink {
+  readonly name: string;
+  private topic: string;
+  private publish: (opts: { topic: string; event: IAuditEvent }) => ValueOrPromise<unknown>;
+
+  constructor(opts: {
+    topic: string;
+    publish: (opts: { topic: string; event: IAuditEvent }) => ValueOrPromise<unknown>;
+    name?: string;
+  }) {
+    this.topic = opts.topic;
+    this.publish = opts.publish;
+    this.name = opts.name ?? `queue:${opts.topic}`;
+  }
+
+  async write(opts: { events: Array<IAuditEvent> }) {
+    for (const event of opts.events) {
+      await this.publish({ topic: this.topic, event });
+    }
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * Keeps events in memory, for tests and local development.
+ */
+export class MemoryAuditSink implements IAuditSink {
+  readonly name = 'memory';
+  readonly events: Array<IAuditEvent> = [];
+
+  write(opts: { events: Array<IAuditEvent> }) {
+    this.events.push(...opts.events);
+  }
+
+  clear() {
+    this.events.length = 0;
+  }
+}
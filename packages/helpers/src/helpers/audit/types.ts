@@ -0,0 +1,46 @@
+import { AnyObject, ValueOrPromise } from '@/common/types';
+import { TAuditOutcome } from './constants';
+
+// --------------------------------------------------------
+export interface IAuditActor {
+  // Defaults to the user id of the request context
+  id?: string;
+  // e.g. `user`, `service`, `api-key`
+  type?: string;
+  ip?: string;
+}
+
+export interface IAuditResource {
+  // e.g. `order`, `user`
+  type: string;
+  id?: string;
+}
+
+export interface IAuditEvent<TState extends AnyObject = AnyObject> {
+  id: string;
+  // e.g. `order.update`, `user.delete`
+  action: string;
+  actor: IAuditActor;
+  resource: IAuditResource;
+  outcome: TAuditOutcome;
+  // State of the resource before and after the operation, when known
+  before?: TState | null;
+  after?: TState | null;
+  // Request id of the operation, links the audit event to the logs and traces
+  correlationId?: string;
+  tenantId?: string;
+  occurredAt: Date;
+  metadata?: AnyObject;
+}
+
+// Fields filled by `AuditLogger.record` when missing
+export type TAuditEventInput<TState extends AnyObject = AnyObject> = Omit<
+  IAuditEvent<TState>,
+  'id' | 'outcome' | 'occurredAt' | 'actor'
+> &
+  Partial<Pick<IAuditEvent<TState>, 'id' | 'outcome' | 'occurredAt' | 'actor'>>;
+
+export interface IAuditSink {
+  name: string;
+  write(opts: { events: Array<IAuditEvent> }): ValueOrPromise<void>;
+}
@@ -0,0 +1,16 @@
+// --------------------------------------------------------
+export class ApiKeyDefaults {
+  static readonly PREFIX = 'sk_live';
+  // Public part used to look the key up, safe to display (e.g. `sk_live_3fQ9xK2mPa7Z…`)
+  static readonly ID_LENGTH = 12;
+  // ~190 bits of entropy with the base62 alphabet
+  static readonly SECRET_LENGTH = 32;
+}
+
+// --------------------------------------------------------
+export class ApiKeyErrorCodes {
+  static readonly MALFORMED = 'API_KEY_MALFORMED';
+  static readonly INVALID = 'API_KEY_INVALID';
+  static readonly REVOKED = 'API_KEY_REVOKED';
+  static readonly EXPIRED = 'API_KEY_EXPIRED';
+}
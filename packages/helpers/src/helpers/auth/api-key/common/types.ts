@@ -0,0 +1,32 @@
+import { AnyObject, ValueOrPromise } from '@/common/types';
+
+// What gets persisted, the plain key is only returned once by `generate`
+export interface IApiKeyRecord<TMetadata extends AnyObject = AnyObject> {
+  id: string;
+  prefix: string;
+  hash: string;
+  expiresAt?: Date | null;
+  revokedAt?: Date | null;
+  metadata?: TMetadata;
+}
+
+export interface IGeneratedApiKey<TMetadata extends AnyObject = AnyObject> {
+  // Hand this to the client, it can not be recovered later
+  key: string;
+  record: IApiKeyRecord<TMetadata>;
+}
+
+export interface IParsedApiKey {
+  prefix: string;
+  id: string;
+  secret: string;
+}
+
+/**
+ * Storage of API key records, e.g. backed by a repository or Redis.
+ */
+export interface IApiKeyStore<TMetadata extends AnyObject = AnyObject> {
+  findById(opts: { id: string }): ValueOrPromise<IApiKeyRecord<TMetadata> | null | undefined>;
+  // Optional, called after a successful verification
+  markUsed?(opts: { id: string; usedAt: Date }): ValueOrPromise<void>;
+}
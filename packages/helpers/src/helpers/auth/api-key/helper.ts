@@ -0,0 +1,193 @@
+import { HTTP } from '@/common/constants';
+import { AnyObject } from '@/common/types';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import C from 'node:crypto';
+import {
+  ApiKeyDefaults,
+  ApiKeyErrorCodes,
+  IApiKeyRecord,
+  IApiKeyStore,
+  IGeneratedApiKey,
+  IParsedApiKey,
+} from './common';
+
+const BASE62_ALPHABET = '0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz';
+const BASE62_PATTERN = /^[0-9A-Za-z]+$/;
+const PREFIX_PATTERN = /^[a-z][a-z0-9]*(_[a-z0-9]+)*$/;
+
+export interface IApiKeyHelperOptions<TMetadata extends AnyObject = AnyObject> {
+  scope?: string;
+  identifier?: string;
+
+  // e.g. `sk_live` / `sk_test`, keys are formatted as `<prefix>_<id>_<secret>`
+  prefix?: string;
+  store: IApiKeyStore<TMetadata>;
+  // Server side secret mixed into the hashes, a leaked table alone can then not be brute forced
+  pepper?: string | Buffer;
+}
+
+// --------------------------------------------------------
+/**
+ * Issue prefixed API keys and verify them against their stored hashes.
+ *
+ * Only the SHA-256 (HMAC when `pepper` is set) of a key is persisted. A slow hash is not
+ * needed, generated secrets carry far more entropy than passwords.
+ *
+ * @example
+ * ```typescript
+ * const apiKeys = new ApiKeyHelper({ prefix: 'sk_live', store: apiKeyStore, pepper: env.PEPPER });
+ *
+ * const { key, record } = apiKeys.generate({ metadata: { tenantId } });
+ * await apiKeyRepository.create({ data: record });
+ *
+ * // later, from the `x-api-key` header
+ * const { metadata } = await apiKeys.verify({ key: context.req.header('x-api-key') });
+ * ```
+ */
+export class ApiKeyHelper<TMetadata extends AnyObject = AnyObject> extends BaseHelper {
+  private prefix: string;
+  private store: IApiKeyStore<TMetadata>;
+  private pepper?: string | Buffer;
+
+  constructor(opts: IApiKeyHelperOptions<TMetadata>) {
+    super({
+      scope: opts.scope ?? ApiKeyHelper.name,
+      identifier: opts.identifier ?? ApiKeyHelper.name,
+    });
+
+    this.prefix = opts.prefix ?? ApiKeyDefaults.PREFIX;
+    if (!PREFIX_PATTERN.test(this.prefix)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[ApiKeyHelper] Invalid prefix, expected lowercase words joined by "_" | prefix: ${this.prefix}`,
+      });
+    }
+
+    this.store = opts.store;
+    this.pepper = opts.pepper;
+  }
+
+  // --------------------------------------------------------
+  static randomBase62(length: number): string {
+    let rs = '';
+    for (let i = 0; i < length; i++) {
+      rs += BASE62_ALPHABET[C.randomInt(BASE62_ALPHABET.length)];
+    }
+
+    return rs;
+  }
+
+  static parse(key: string): IParsedApiKey | null {
+    const segments = `${key ?? ''}`.trim().split('_');
+    if (segments.length < 3) {
+      return null;
+    }
+
+    const secret = segments.pop()!;
+    const id = segments.pop()!;
+    const prefix = segments.join('_');
+
+    if (
+      id.length !== ApiKeyDefaults.ID_LENGTH ||
+      secret.length !== ApiKeyDefaults.SECRET_LENGTH ||
+      !BASE62_PATTERN.test(id) ||
+      !BASE62_PATTERN.test(secret)
+    ) {
+      return null;
+    }
+
+    return { prefix, id, secret };
+  }
+
+  // --------------------------------------------------------
+  hash(opts: { key: string }): string {
+    const data = `${opts.key}`.trim();
+    if (this.pepper) {
+      return C.createHmac('sha256', this.pepper).update(data).digest('hex');
+    }
+
+    return C.createHash('sha256').update(data).digest('hex');
+  }
+
+  /**
+   * Create a new key. Persist `record` and return `key` to the client, it is not stored.
+   */
+  generate(
+    opts: { expiresAt?: Date | null; metadata?: TMetadata } = {},
+  ): IGeneratedApiKey<TMetadata> {
+    const id = ApiKeyHelper.randomBase62(ApiKeyDefaults.ID_LENGTH);
+    const secret = ApiKeyHelper.randomBase62(ApiKeyDefaults.SECRET_LENGTH);
+    const key = [this.prefix, id, secret].join('_');
+
+    return {
+      key,
+      record: {
+        id,
+        prefix: this.prefix,
+        hash: this.hash({ key }),
+        expiresAt: opts.expiresAt ?? null,
+        revokedAt: null,
+        metadata: opts.metadata,
+      },
+    };
+  }
+
+  isMatched(opts: { key: string; hash: string }): boolean {
+    const expected = Buffer.from(opts.hash ?? '', 'hex');
+    const actual = Buffer.from(this.hash({ key: opts.key }), 'hex');
+
+    return expected.length === actual.length && C.timingSafeEqual(expected, actual);
+  }
+
+  // --------------------------------------------------------
+  async verify(opts: { key?: string | null; now?: Date }): Promise<IApiKeyRecord<TMetadata>> {
+    const { key, now = new Date() } = opts;
+
+    const parsed = key ? ApiKeyHelper.parse(key) : null;
+    if (!parsed || parsed.prefix !== this.prefix) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+        messageCode: ApiKeyErrorCodes.MALFORMED,
+        message: '[verify] Malformed API key!',
+      });
+    }
+
+    const record = await this.store.findById({ id: parsed.id });
+    if (!record || !this.isMatched({ key: key!, hash: record.hash })) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+        messageCode: ApiKeyErrorCodes.INVALID,
+        message: '[verify] Invalid API key!',
+      });
+    }
+
+    if (record.revokedAt) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+        messageCode: ApiKeyErrorCodes.REVOKED,
+        message: `[verify] API key was revoked | id: ${record.id}`,
+      });
+    }
+
+    if (record.expiresAt && new Date(record.expiresAt).getTime() <= now.getTime()) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+        messageCode: ApiKeyErrorCodes.EXPIRED,
+        message: `[verify] API key expired | id: ${record.id}`,
+      });
+    }
+
+    if (this.store.markUsed) {
+      try {
+        await this.store.markUsed({ id: record.id, usedAt: now });
+      } catch (error) {
+        this.logger
+          .for(this.verify.name)
+          .error('Failed to mark API key as used | id: %s | error: %s', record.id, error);
+      }
+    }
+
+    return record;
+  }
+}
@@ -0,0 +1,7 @@
+export * from './api-key';
+export * from './jwt';
+export * from './oauth2';
+export * from './oidc';
+export * from './password';
+export * from './signed-url';
+export * from './totp';
@@ -0,0 +1,37 @@
+// --------------------------------------------------------
+export class JWTAlgorithms {
+  static readonly HS256 = 'HS256';
+  static readonly RS256 = 'RS256';
+  static readonly ES256 = 'ES256';
+
+  static readonly SCHEME_SET = new Set([this.HS256, this.RS256, this.ES256]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+
+  static isSymmetric(scheme: string): boolean {
+    return scheme === this.HS256;
+  }
+}
+
+// --------------------------------------------------------
+export class JWTDefaults {
+  // Seconds of clock skew tolerated on exp / nbf / iat checks
+  static readonly LEEWAY = 30;
+  static readonly TYPE = 'JWT';
+}
+
+// --------------------------------------------------------
+export class JWTErrorCodes {
+  static readonly MALFORMED = 'JWT_MALFORMED';
+  static readonly UNSUPPORTED_ALGORITHM = 'JWT_UNSUPPORTED_ALGORITHM';
+  static readonly INVALID_KEY = 'JWT_INVALID_KEY';
+  static readonly INVALID_SIGNATURE = 'JWT_INVALID_SIGNATURE';
+  static readonly EXPIRED = 'JWT_EXPIRED';
+  static readonly NOT_ACTIVE = 'JWT_NOT_ACTIVE';
+  static readonly INVALID_ISSUER = 'JWT_INVALID_ISSUER';
+  static readonly INVALID_AUDIENCE = 'JWT_INVALID_AUDIENCE';
+  static readonly INVALID_SUBJECT = 'JWT_INVALID_SUBJECT';
+  static readonly MISSING_CLAIM = 'JWT_MISSING_CLAIM';
+}
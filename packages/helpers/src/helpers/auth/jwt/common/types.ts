@@ -0,0 +1,83 @@
+import { AnyObject, TConstValue, ValueOrPromise } from '@/common/types';
+import { KeyObject } from 'node:crypto';
+import { JWTAlgorithms, JWTErrorCodes } from './constants';
+
+export type TJWTAlgorithm = TConstValue<typeof JWTAlgorithms>;
+export type TJWTErrorCode = TConstValue<typeof JWTErrorCodes>;
+
+// Epoch milliseconds, `Date.now` by default, injectable so tests control expiry
+export type TJWTClock = () => number;
+
+/**
+ * Seconds of tolerated clock skew per claim, unset claims use `JWTDefaults.LEEWAY`.
+ */
+export interface IJWTLeeway {
+  exp?: number;
+  nbf?: number;
+  // Applied on the `maxAge` check
+  iat?: number;
+}
+
+export type TJWTLeeway = number | IJWTLeeway;
+
+// Secret for HS256, PEM / JWK backed KeyObject for RS256 and ES256
+export type TJWTKey = string | Buffer | KeyObject;
+
+// --------------------------------------------------------
+export interface IJWTHeader {
+  alg: string;
+  typ?: string;
+  kid?: string;
+  [extra: string]: unknown;
+}
+
+/**
+ * Registered claims of RFC 7519, numeric dates are in seconds since epoch.
+ */
+export interface IJWTClaims {
+  iss?: string;
+  sub?: string;
+  aud?: string | Array<string>;
+  exp?: number;
+  nbf?: number;
+  iat?: number;
+  jti?: string;
+  [claim: string]: unknown;
+}
+
+export interface IDecodedJWT<TClaims extends AnyObject = IJWTClaims> {
+  header: IJWTHeader;
+  payload: TClaims & IJWTClaims;
+  signature: string;
+}
+
+// --------------------------------------------------------
+export interface ISignJWTOptions {
+  // Seconds from now, sets `exp`
+  expiresIn?: number;
+  // Seconds from now, sets `nbf`
+  notBefore?: number;
+  issuer?: string;
+  subject?: string;
+  audience?: string | Array<string>;
+  jwtId?: string;
+  header?: Omit<IJWTHeader, 'alg'>;
+}
+
+export interface IVerifyJWTOptions {
+  // Accepted `alg` values, defaults to the algorithm of the helper
+  algorithms?: Array<TJWTAlgorithm>;
+  issuer?: string | Array<string>;
+  audience?: string | Array<string>;
+  subject?: string;
+  // Seconds of tolerated clock skew, overrides the helper leeway
+  leeway?: TJWTLeeway;
+  // Reject tokens issued more than `maxAge` seconds ago
+  maxAge?: number;
+  requiredClaims?: Array<string>;
+  // Seconds since epoch, overrides the helper clock
+  now?: number;
+}
+
+// Resolve the verification key from the token header (e.g. by `kid` from a JWKS)
+export type TJWTKeyResolver = (opts: { header: IJWTHeader }) => ValueOrPromise<TJWTKey>;
@@ -0,0 +1,34 @@
+import { HTTP } from '@/common/constants';
+import { ApplicationError } from '@/helpers/error';
+import { TJWTErrorCode } from './common';
+
+// Claim which failed validation, numeric dates in seconds since epoch
+export interface IJWTErrorDetails {
+  claim: string;
+  value?: unknown;
+  now?: number;
+  leeway?: number;
+}
+
+// --------------------------------------------------------
+/**
+ * Raised by `JWTHelper` when a token can not be signed or verified.
+ * `messageCode` is one of `JWTErrorCodes`, so callers can tell an expired token from a forged one,
+ * `details` names the claim of claim validation failures.
+ */
+export class JWTError extends ApplicationError {
+  declare messageCode: TJWTErrorCode;
+  details?: IJWTErrorDetails;
+
+  constructor(opts: {
+    messageCode: TJWTErrorCode;
+    message: string;
+    statusCode?: number;
+    details?: IJWTErrorDetails;
+  }) {
+    const { messageCode, message, statusCode = HTTP.ResultCodes.RS_4.Unauthorized, details } = opts;
+    super({ messageCode, message, statusCode });
+    this.name = JWTError.name;
+    this.details = details;
+  }
+}
@@ -0,0 +1,532 @@
+import { HTTP } from '@/common/constants';
+import { AnyObject } from '@/common/types';
+import { BaseHelper } from '@/helpers/base';
+import C from 'node:crypto';
+import {
+  IDecodedJWT,
+  IJWTClaims,
+  IJWTHeader,
+  IJWTLeeway,
+  ISignJWTOptions,
+  IVerifyJWTOptions,
+  JWTAlgorithms,
+  JWTDefaults,
+  JWTErrorCodes,
+  TJWTAlgorithm,
+  TJWTClock,
+  TJWTKey,
+  TJWTKeyResolver,
+  TJWTLeeway,
+} from './common';
+import { JWTError } from './error';
+
+export interface IJWTHelperOptions {
+  scope?: string;
+  identifier?: string;
+
+  algorithm: TJWTAlgorithm;
+  // HS256 shared secret
+  secret?: string | Buffer;
+  // RS256 / ES256 keys, the public key is derived from the private key when omitted
+  privateKey?: TJWTKey;
+  publicKey?: TJWTKey;
+  // Written to the `kid` header of issued tokens
+  keyId?: string;
+  // Resolve verification keys per token instead, e.g. `JWKSHelper.getKeyResolver()`
+  keyResolver?: TJWTKeyResolver;
+
+  // Defaults for both signing and verification
+  issuer?: string;
+  audience?: string | Array<string>;
+  expiresIn?: number;
+  leeway?: TJWTLeeway;
+  // Time source of issued and verified tokens
+  clock?: TJWTClock;
+}
+
+// RFC 7519 NumericDate claims
+const NUMERIC_DATE_CLAIMS = ['exp', 'nbf', 'iat'] as const;
+
+const toArray = <T>(value?: T | Array<T>): Array<T> => {
+  if (value === undefined) {
+    return [];
+  }
+
+  return Array.isArray(value) ? value : [value];
+};
+
+// --------------------------------------------------------
+/**
+ * Issue and validate compact JWS tokens (HS256 / RS256 / ES256) with `node:crypto`.
+ *
+ * @example
+ * ```typescript
+ * const jwt = new JWTHelper({
+ *   algorithm: JWTAlgorithms.ES256,
+ *   privateKey: env.JWT_PRIVATE_KEY,
+ *   keyId: '2025-01',
+ *   issuer: 'https://auth.example.com',
+ *   audience: 'api',
+ *   expiresIn: 15 * 60,
+ * });
+ *
+ * const token = jwt.sign({ claims: { sub: `${user.id}`, roles: ['admin'] } });
+ *
+ * try {
+ *   const { payload } = await jwt.verify<{ roles: string[] }>({ token });
+ * } catch (error) {
+ *   if (error instanceof JWTError && error.messageCode === JWTErrorCodes.EXPIRED) {
+ *     // ask the client to refresh
+ *   }
+ * }
+ * ```
+ */
+export class JWTHelper extends BaseHelper {
+  private options: IJWTHelperOptions;
+  private signingKey?: string | Buffer | C.KeyObject;
+  private verificationKey?: string | Buffer | C.KeyObject;
+
+  constructor(opts: IJWTHelperOptions) {
+    super({
+      scope: opts.scope ?? JWTHelper.name,
+      identifier: opts.identifier ?? JWTHelper.name,
+    });
+
+    const { algorithm, secret, privateKey, publicKey, keyResolver } = opts;
+    if (!JWTAlgorithms.isValid(algorithm)) {
+      throw new JWTError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: JWTErrorCodes.UNSUPPORTED_ALGORITHM,
+        message: `[JWTHelper] Unsupported algorithm: ${algorithm}`,
+      });
+    }
+
+    this.options = opts;
+
+    if (JWTAlgorithms.isSymmetric(algorithm)) {
+      if (!secret && !keyResolver) {
+        throw new JWTError({
+          statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+          messageCode: JWTErrorCodes.INVALID_KEY,
+          message: `[JWTHelper] secret is required for ${algorithm}`,
+        });
+      }
+
+      this.signingKey = secret;
+      this.verificationKey = secret;
+      return;
+    }
+
+    if (!privateKey && !publicKey) {
+      if (keyResolver) {
+        return;
+      }
+
+      throw new JWTError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: JWTErrorCodes.INVALID_KEY,
+        message: `[JWTHelper] privateKey or publicKey is required for ${algorithm}`,
+      });
+    }
+
+    // A helper configured with a public key only can verify but not issue tokens
+    this.signingKey = privateKey
+      ? JWTHelper.toKeyObject({ algorithm, key: privateKey, type: 'private' })
+      : undefined;
+    this.verificationKey = JWTHelper.toKeyObject({
+      algorithm,
+      key: publicKey ?? privateKey!,
+      type: 'public',
+    });
+  }
+
+  // --------------------------------------------------------
+  static toKeyObject(opts: {
+    algorithm: TJWTAlgorithm;
+    key: TJWTKey;
+    type: 'private' | 'public';
+  }): C.KeyObject {
+    const { algorithm, key, type } = opts;
+
+    let keyObject: C.KeyObject;
+    try {
+      if (key instanceof C.KeyObject) {
+        keyObject = type === 'public' && key.type === 'private' ? C.createPublicKey(key) : key;
+      } else {
+        keyObject = type === 'private' ? C.createPrivateKey(key) : C.createPublicKey(key);
+      }
+    } catch (error) {
+      throw new JWTError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: JWTErrorCodes.INVALID_KEY,
+        message: `[toKeyObject] Failed to load ${type} key | error: ${(error as Error).message}`,
+      });
+    }
+
+    const isMatched =
+      algorithm === JWTAlgorithms.RS256
+        ? keyObject.asymmetricKeyType === 'rsa'
+        : keyObject.asymmetricKeyType === 'ec' &&
+          keyObject.asymmetricKeyDetails?.namedCurve === 'prime256v1';
+
+    if (!isMatched) {
+      throw new JWTError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: JWTErrorCodes.INVALID_KEY,
+        message: `[toKeyObject] Key type ${keyObject.asymmetricKeyType} can not be used with ${algorithm}`,
+      });
+    }
+
+    return keyObject;
+  }
+
+  // --------------------------------------------------------
+  static resolveLeeway(leeway: TJWTLeeway = JWTDefaults.LEEWAY): Required<IJWTLeeway> {
+    if (typeof leeway === 'number') {
+      return { exp: leeway, nbf: leeway, iat: leeway };
+    }
+
+    const { exp = JWTDefaults.LEEWAY, nbf = JWTDefaults.LEEWAY, iat = JWTDefaults.LEEWAY } = leeway;
+    return { exp, nbf, iat };
+  }
+
+  static encodeSegment(value: AnyObject) {
+    return Buffer.from(JSON.stringify(value)).toString('base64url');
+  }
+
+  static decode<TClaims extends AnyObject = IJWTClaims>(token: string): IDecodedJWT<TClaims> {
+    const parts = `${token ?? ''}`.split('.');
+    if (parts.length !== 3 || parts.some(part => !part)) {
+      throw new JWTError({
+        messageCode: JWTErrorCodes.MALFORMED,
+        message: '[decode] Token must have three dot separated segments!',
+      });
+    }
+
+    try {
+      const [header, payload, signature] = parts;
+      return {
+        header: JSON.parse(Buffer.from(header, 'base64url').toString('utf-8')),
+        payload: JSON.parse(Buffer.from(payload, 'base64url').toString('utf-8')),
+        signature,
+      };
+    } catch {
+      throw new JWTError({
+        messageCode: JWTErrorCodes.MALFORMED,
+        message: '[decode] Token header or payload is not valid base64url encoded JSON!',
+      });
+    }
+  }
+
+  static createSignature(opts: {
+    algorithm: TJWTAlgorithm;
+    key: TJWTKey;
+    input: string;
+  }): string {
+    const { algorithm, key, input } = opts;
+
+    switch (algorithm) {
+      case JWTAlgorithms.HS256: {
+        return C.createHmac('sha256', key).update(input).digest('base64url');
+      }
+      case JWTAlgorithms.RS256: {
+        return C.sign('sha256', Buffer.from(input), key as C.KeyObject).toString('base64url');
+      }
+      case JWTAlgorithms.ES256: {
+        // JWS expects the raw r || s form for ECDSA signatures instead of DER
+        return C.sign('sha256', Buffer.from(input), {
+          key: key as C.KeyObject,
+          dsaEncoding: 'ieee-p1363',
+        }).toString('base64url');
+      }
+      default: {
+        throw new JWTError({
+          messageCode: JWTErrorCodes.UNSUPPORTED_ALGORITHM,
+          message: `[createSignature] Unsupported algorithm: ${algorithm}`,
+        });
+      }
+    }
+  }
+
+  static isValidSignature(opts: {
+    algorithm: TJWTAlgorithm;
+    key: TJWTKey;
+    input: string;
+    signature: string;
+  }): boolean {
+    const { algorithm, key, input, signature } = opts;
+    const received = Buffer.from(signature, 'base64url');
+
+    switch (algorithm) {
+      case JWTAlgorithms.HS256: {
+        // Reject asymmetric keys, a public key must never be usable as an HMAC secret
+        if (key instanceof C.KeyObject && key.type !== 'secret') {
+          return false;
+        }
+
+        const expected = C.createHmac('sha256', key).update(input).digest();
+        return expected.length === received.length && C.timingSafeEqual(expected, received);
+      }
+      case JWTAlgorithms.RS256: {
+        return C.verify('sha256', Buffer.from(input), key as C.KeyObject, received);
+      }
+      case JWTAlgorithms.ES256: {
+        return C.verify(
+          'sha256',
+          Buffer.from(input),
+          { key: key as C.KeyObject, dsaEncoding: 'ieee-p1363' },
+          received,
+        );
+      }
+      default: {
+        return false;
+      }
+    }
+  }
+
+  /**
+   * Sign arbitrary claims without any defaults, `sign` should be preferred for application tokens.
+   */
+  static signToken(opts: {
+    algorithm: TJWTAlgorithm;
+    key: TJWTKey;
+    claims: AnyObject;
+    header?: Omit<IJWTHeader, 'alg'>;
+  }): string {
+    const { algorithm, claims, header } = opts;
+    const key = JWTAlgorithms.isSymmetric(algorithm)
+      ? opts.key
+      : JWTHelper.toKeyObject({ algorithm, key: opts.key, type: 'private' });
+
+    const input = [
+      JWTHelper.encodeSegment({ alg: algorithm, typ: JWTDefaults.TYPE, ...header }),
+      JWTHelper.encodeSegment(claims),
+    ].join('.');
+
+    return `${input}.${JWTHelper.createSignature({ algorithm, key, input })}`;
+  }
+
+  // --------------------------------------------------------
+  sign<TClaims extends AnyObject = AnyObject>(
+    opts?: { claims?: TClaims; now?: number } & ISignJWTOptions,
+  ): string {
+    const {
+      claims = {} as TClaims,
+      now = this.getNow(),
+      expiresIn = this.options.expiresIn,
+      notBefore,
+      issuer = this.options.issuer,
+      subject,
+      audience = this.options.audience,
+      jwtId,
+      header,
+    } = opts ?? {};
+
+    if (!this.signingKey) {
+      throw new JWTError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: JWTErrorCodes.INVALID_KEY,
+        message: '[sign] Helper has no private key configured!',
+      });
+    }
+
+    const payload: IJWTClaims = { iat: now, ...claims };
+    if (issuer !== undefined) {
+      payload.iss = issuer;
+    }
+
+    if (subject !== undefined) {
+      payload.sub = subject;
+    }
+
+    if (audience !== undefined) {
+      payload.aud = audience;
+    }
+
+    if (jwtId !== undefined) {
+      payload.jti = jwtId;
+    }
+
+    if (expiresIn !== undefined) {
+      payload.exp = now + expiresIn;
+    }
+
+    if (notBefore !== undefined) {
+      payload.nbf = now + notBefore;
+    }
+
+    const { algorithm, keyId } = this.options;
+    const input = [
+      JWTHelper.encodeSegment({
+        alg: algorithm,
+        typ: JWTDefaults.TYPE,
+        ...(keyId ? { kid: keyId } : {}),
+        ...header,
+      }),
+      JWTHelper.encodeSegment(payload),
+    ].join('.');
+
+    return `${input}.${JWTHelper.createSignature({ algorithm, key: this.signingKey, input })}`;
+  }
+
+  // --------------------------------------------------------
+  async verify<TClaims extends AnyObject = IJWTClaims>(
+    opts: { token: string; keyResolver?: TJWTKeyResolver } & IVerifyJWTOptions,
+  ): Promise<IDecodedJWT<TClaims>> {
+    const {
+      token,
+      keyResolver = this.options.keyResolver,
+      algorithms = [this.options.algorithm],
+      issuer = this.options.issuer,
+      audience = this.options.audience,
+      subject,
+      leeway = this.options.leeway,
+      maxAge,
+      requiredClaims = [],
+      now = this.getNow(),
+    } = opts;
+
+    const decoded = JWTHelper.decode<TClaims>(token);
+    const { header, payload, signature } = decoded;
+
+    // Never let the token pick its own algorithm, e.g. `none` or HS256 signed with a public key
+    const algorithm = header.alg as TJWTAlgorithm;
+    if (!JWTAlgorithms.isValid(algorithm) || !algorithms.includes(algorithm)) {
+      throw new JWTError({
+        messageCode: JWTErrorCodes.UNSUPPORTED_ALGORITHM,
+        message: `[verify] Token algorithm is not allowed | alg: ${header.alg}`,
+      });
+    }
+
+    let key = this.verificationKey;
+    if (keyResolver) {
+      const resolved = await keyResolver({ header });
+      key = JWTAlgorithms.isSymmetric(algorithm)
+        ? resolved
+        : JWTHelper.toKeyObject({ algorithm, key: resolved, type: 'public' });
+    }
+
+    if (!key) {
+      throw new JWTError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: JWTErrorCodes.INVALID_KEY,
+        message: '[verify] No verification key available!',
+      });
+    }
+
+    const input = token.slice(0, token.lastIndexOf('.'));
+    if (!JWTHelper.isValidSignature({ algorithm, key, input, signature })) {
+      throw new JWTError({
+        messageCode: JWTErrorCodes.INVALID_SIGNATURE,
+        message: '[verify] Invalid token signature!',
+      });
+    }
+
+    this.validateClaims({
+      payload,
+      issuer,
+      audience,
+      subject,
+      leeway: JWTHelper.resolveLeeway(leeway),
+      maxAge,
+      requiredClaims,
+      now,
+    });
+    return decoded;
+  }
+
+  // Seconds since epoch
+  getNow() {
+    return Math.floor((this.options.clock ?? Date.now)() / 1000);
+  }
+
+  // --------------------------------------------------------
+  protected validateClaims(
+    opts: Omit<IVerifyJWTOptions, 'leeway'> & {
+      payload: IJWTClaims;
+      leeway: Required<IJWTLeeway>;
+      now: number;
+    },
+  ) {
+    const { payload, issuer, audience, subject, leeway, maxAge, requiredClaims = [], now } = opts;
+
+    for (const claim of requiredClaims) {
+      if (payload[claim] === undefined) {
+        throw new JWTError({
+          messageCode: JWTErrorCodes.MISSING_CLAIM,
+          message: `[verify] Missing required claim | claim: ${claim}`,
+          details: { claim },
+        });
+      }
+    }
+
+    // A NaN date would pass every comparison below
+    for (const claim of NUMERIC_DATE_CLAIMS) {
+      const value = payload[claim];
+      if (value !== undefined && (typeof value !== 'number' || !Number.isFinite(value))) {
+        throw new JWTError({
+          messageCode: JWTErrorCodes.MALFORMED,
+          message: `[verify] Claim is not a numeric date | claim: ${claim}`,
+          details: { claim, value },
+        });
+      }
+    }
+
+    if (payload.exp !== undefined && now - leeway.exp >= payload.exp) {
+      throw new JWTError({
+        messageCode: JWTErrorCodes.EXPIRED,
+        message: '[verify] Token has expired!',
+        details: { claim: 'exp', value: payload.exp, now, leeway: leeway.exp },
+      });
+    }
+
+    if (payload.nbf !== undefined && now + leeway.nbf < payload.nbf) {
+      throw new JWTError({
+        messageCode: JWTErrorCodes.NOT_ACTIVE,
+        message: '[verify] Token is not active yet!',
+        details: { claim: 'nbf', value: payload.nbf, now, leeway: leeway.nbf },
+      });
+    }
+
+    if (maxAge !== undefined) {
+      if (payload.iat === undefined) {
+        throw new JWTError({
+          messageCode: JWTErrorCodes.MISSING_CLAIM,
+          message: '[verify] Missing required claim | claim: iat',
+          details: { claim: 'iat' },
+        });
+      }
+
+      if (now - leeway.iat > payload.iat + maxAge) {
+        throw new JWTError({
+          messageCode: JWTErrorCodes.EXPIRED,
+          message: `[verify] Token is older than ${maxAge} seconds!`,
+          details: { claim: 'iat', value: payload.iat, now, leeway: leeway.iat },
+        });
+      }
+    }
+
+    const issuers = toArray(issuer);
+    if (issuers.length && !issuers.includes(payload.iss ?? '')) {
+      throw new JWTError({
+        messageCode: JWTErrorCodes.INVALID_ISSUER,
+        message: `[verify] Unexpected token issuer | iss: ${payload.iss}`,
+      });
+    }
+
+    const audiences = toArray(audience);
+    if (audiences.length && !toArray(payload.aud).some(aud => audiences.includes(aud))) {
+      throw new JWTError({
+        messageCode: JWTErrorCodes.INVALID_AUDIENCE,
+        message: '[verify] Token was not issued for this audience!',
+      });
+    }
+
+    if (subject !== undefined && payload.sub !== subject) {
+      throw new JWTError({
+        messageCode: JWTErrorCodes.INVALID_SUBJECT,
+        message: `[verify] Unexpected token subject | sub: ${payload.sub}`,
+      });
+    }
+  }
+}
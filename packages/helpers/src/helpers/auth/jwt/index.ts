@@ -0,0 +1,4 @@
+export * from './common';
+export * from './error';
+export * from './helper';
+export * from './jwks.helper';
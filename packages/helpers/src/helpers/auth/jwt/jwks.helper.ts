@@ -0,0 +1,226 @@
+import { HTTP } from '@/common/constants';
+import { AnyObject } from '@/common/types';
+import { BaseHelper } from '@/helpers/base';
+import { NodeFetchNetworkRequest } from '@/helpers/network';
+import C from 'node:crypto';
+import { IJWTHeader, JWTAlgorithms, JWTErrorCodes, TJWTAlgorithm, TJWTKeyResolver } from './common';
+import { JWTError } from './error';
+
+export interface IJWKSHelperOptions {
+  scope?: string;
+  identifier?: string;
+
+  // e.g. https://auth.example.com/.well-known/jwks.json
+  url: string;
+  // Used when the response carries no `Cache-Control: max-age`
+  cacheTtl?: number;
+  // Minimum interval between refetches triggered by an unknown `kid`
+  cooldown?: number;
+  timeout?: number;
+}
+
+interface IJWKSCachedKey {
+  key: C.KeyObject;
+  alg: string;
+}
+
+// JWK `kty` to the algorithms its keys can verify
+const KEY_TYPE_ALGORITHMS: Record<string, TJWTAlgorithm> = {
+  RSA: JWTAlgorithms.RS256,
+  EC: JWTAlgorithms.ES256,
+};
+
+// --------------------------------------------------------
+/**
+ * Fetch and cache the signing keys of an identity provider, keyed by `kid`.
+ *
+ * Tokens signed with a `kid` that is not cached yet trigger a refetch, so rotated keys
+ * are picked up without a restart. Refetches are rate limited by `cooldown` and stale
+ * keys are kept when the provider can not be reached.
+ *
+ * @example
+ * ```typescript
+ * const jwks = new JWKSHelper({ url: 'https://auth.example.com/.well-known/jwks.json' });
+ *
+ * const jwt = new JWTHelper({
+ *   algorithm: JWTAlgorithms.RS256,
+ *   issuer: 'https://auth.example.com',
+ *   audience: 'api',
+ *   keyResolver: jwks.getKeyResolver(),
+ * });
+ *
+ * const { payload } = await jwt.verify({ token });
+ * ```
+ */
+export class JWKSHelper extends BaseHelper {
+  static readonly DEFAULT_CACHE_TTL = 10 * 60 * 1_000;
+  static readonly DEFAULT_COOLDOWN = 30 * 1_000;
+  static readonly DEFAULT_TIMEOUT = 10 * 1_000;
+
+  private url: string;
+  private cacheTtl: number;
+  private cooldown: number;
+  private timeout: number;
+  private network: NodeFetchNetworkRequest;
+
+  private keys = new Map<string, IJWKSCachedKey>();
+  private expiresAt = 0;
+  private attemptedAt = 0;
+  private refreshPromise?: Promise<void>;
+
+  constructor(opts: IJWKSHelperOptions) {
+    super({
+      scope: opts.scope ?? JWKSHelper.name,
+      identifier: opts.identifier ?? JWKSHelper.name,
+    });
+
+    this.url = opts.url;
+    this.cacheTtl = opts.cacheTtl ?? JWKSHelper.DEFAULT_CACHE_TTL;
+    this.cooldown = opts.cooldown ?? JWKSHelper.DEFAULT_COOLDOWN;
+    this.timeout = opts.timeout ?? JWKSHelper.DEFAULT_TIMEOUT;
+    this.network = new NodeFetchNetworkRequest({ name: this.identifier, networkOptions: {} });
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Convert a JWK set into verification keys, skipping encryption and unsupported keys.
+   */
+  static parseKeys(opts: { keys?: Array<AnyObject> }): Map<string, IJWKSCachedKey> {
+    const rs = new Map<string, IJWKSCachedKey>();
+
+    for (const jwk of opts.keys ?? []) {
+      if (jwk.use === 'enc' || !KEY_TYPE_ALGORITHMS[jwk.kty]) {
+        continue;
+      }
+
+      // Providers publishing several keys must tell them apart by `kid`
+      const kid = jwk.kid ?? '';
+      try {
+        rs.set(kid, {
+          key: C.createPublicKey({ key: jwk as C.JsonWebKey, format: 'jwk' }),
+          alg: jwk.alg ?? KEY_TYPE_ALGORITHMS[jwk.kty],
+        });
+      } catch {
+        // Skip malformed keys so one bad entry does not take the whole set down
+        continue;
+      }
+    }
+
+    return rs;
+  }
+
+  private getMaxAge(headers: Headers) {
+    const matched = /max-age=(\d+)/i.exec(headers.get('cache-control') ?? '');
+    return matched ? Number(matched[1]) * 1_000 : this.cacheTtl;
+  }
+
+  private async fetchKeys() {
+    this.attemptedAt = Date.now();
+    const response = await this.network.getNetworkService().get({
+      url: this.url,
+      timeout: this.timeout,
+      headers: { ['accept']: 'application/json' },
+    });
+
+    const data = await response.json().catch(() => ({}));
+    if (!response.ok || !Array.isArray(data.keys)) {
+      throw new JWTError({
+        statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+        messageCode: JWTErrorCodes.INVALID_KEY,
+        message: `[fetchKeys] Failed to fetch JWKS | url: ${this.url} | status: ${response.status}`,
+      });
+    }
+
+    this.keys = JWKSHelper.parseKeys(data);
+    this.expiresAt = Date.now() + this.getMaxAge(response.headers);
+
+    this.logger
+      .for(this.fetchKeys.name)
+      .info('JWKS refreshed | url: %s | keys: %j', this.url, [...this.keys.keys()]);
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Refetch the key set. Concurrent callers share a single request and the cached keys
+   * are kept when it fails.
+   */
+  refresh(): Promise<void> {
+    if (!this.refreshPromise) {
+      this.refreshPromise = this.fetchKeys()
+        .catch(error => {
+          // Back off for a cooldown instead of refetching on every lookup
+          this.expiresAt = Date.now() + this.cooldown;
+
+          if (!this.keys.size) {
+            throw error;
+          }
+
+          this.logger
+            .for(this.refresh.name)
+            .error(
+              'Failed to refresh JWKS, keeping cached keys | url: %s | error: %s',
+              this.url,
+              error,
+            );
+        })
+        .finally(() => {
+          this.refreshPromise = undefined;
+        });
+    }
+
+    return this.refreshPromise;
+  }
+
+  private findKey(kid?: string) {
+    if (kid !== undefined) {
+      return this.keys.get(kid);
+    }
+
+    // Tokens without `kid` are only accepted while the provider publishes a single key
+    return this.keys.size === 1 ? [...this.keys.values()][0] : undefined;
+  }
+
+  // --------------------------------------------------------
+  getUrl() {
+    return this.url;
+  }
+
+  async getKey(opts: { kid?: string; alg?: string }): Promise<C.KeyObject> {
+    const { kid, alg } = opts;
+
+    if (this.expiresAt <= Date.now()) {
+      await this.refresh();
+    }
+
+    let cached = this.findKey(kid);
+    if (!cached && Date.now() - this.attemptedAt >= this.cooldown) {
+      // Unknown kid, the provider may have rotated its keys
+      await this.refresh();
+      cached = this.findKey(kid);
+    }
+
+    if (!cached) {
+      throw new JWTError({
+        statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+        messageCode: JWTErrorCodes.INVALID_KEY,
+        message: `[getKey] No signing key found | kid: ${kid}`,
+      });
+    }
+
+    if (cached.alg !== alg) {
+      throw new JWTError({
+        statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+        messageCode: JWTErrorCodes.INVALID_KEY,
+        message: `[getKey] Signing key can not be used with ${alg} | kid: ${kid}`,
+      });
+    }
+
+    return cached.key;
+  }
+
+  getKeyResolver(): TJWTKeyResolver {
+    return ({ header }: { header: IJWTHeader }) => {
+      return this.getKey({ kid: header.kid, alg: header.alg });
+    };
+  }
+}
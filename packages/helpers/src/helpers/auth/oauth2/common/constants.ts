@@ -0,0 +1,62 @@
+// --------------------------------------------------------
+export class PKCEMethods {
+  static readonly S256 = 'S256';
+  // Only for providers which can not do S256, the verifier travels in clear
+  static readonly PLAIN = 'plain';
+
+  static readonly SCHEME_SET = new Set([this.S256, this.PLAIN]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
+
+// --------------------------------------------------------
+export class OAuth2ClientAuthentications {
+  // `Authorization: Basic` with the client id and secret, RFC 6749 section 2.3.1
+  static readonly BASIC = 'client_secret_basic';
+  // `client_id` / `client_secret` in the form body
+  static readonly POST = 'client_secret_post';
+  // Public clients, e.g. CLI tools, only send `client_id`
+  static readonly NONE = 'none';
+
+  static readonly SCHEME_SET = new Set([this.BASIC, this.POST, this.NONE]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
+
+// --------------------------------------------------------
+export class OAuth2Defaults {
+  static readonly PKCE_METHOD = PKCEMethods.S256;
+  // Bytes of the code verifier, 43 base64url characters, the RFC 7636 minimum
+  static readonly CODE_VERIFIER_LENGTH = 32;
+  static readonly STATE_LENGTH = 16;
+  static readonly SCOPE_SEPARATOR = ' ';
+  static readonly TIMEOUT = 10 * 1_000;
+  // ms before `expiresAt` cached tokens are refreshed
+  static readonly EXPIRY_SKEW = 30 * 1_000;
+}
+
+// --------------------------------------------------------
+export class OAuth2TokenCacheDefaults {
+  // Owner only, tokens are secrets even encrypted
+  static readonly FILE_MODE = 0o600;
+  static readonly DIRECTORY_MODE = 0o700;
+  // Key version of the envelopes sealed with a passphrase key
+  static readonly PASSPHRASE_KEY_VERSION = 'passphrase';
+  // Binds the envelope to its use, a cache file can not be decrypted as another value
+  static readonly AAD = 'ignis:oauth2-token-cache';
+}
+
+// --------------------------------------------------------
+export class OAuth2ErrorCodes {
+  static readonly STATE_MISMATCH = 'OAUTH2_STATE_MISMATCH';
+  static readonly MISSING_CODE = 'OAUTH2_MISSING_CODE';
+  // The user or the provider refused the authorization request
+  static readonly ACCESS_DENIED = 'OAUTH2_ACCESS_DENIED';
+  // Expired, used or revoked code / refresh token
+  static readonly INVALID_GRANT = 'OAUTH2_INVALID_GRANT';
+  static readonly TOKEN_REQUEST_FAILED = 'OAUTH2_TOKEN_REQUEST_FAILED';
+}
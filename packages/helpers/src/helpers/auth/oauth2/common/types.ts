@@ -0,0 +1,59 @@
+import { TConstValue } from '@/common/types';
+import { OAuth2ClientAuthentications, PKCEMethods } from './constants';
+
+export type TPKCEMethod = TConstValue<typeof PKCEMethods>;
+export type TOAuth2ClientAuthentication = TConstValue<typeof OAuth2ClientAuthentications>;
+
+export interface IOAuth2ClientHelperOptions {
+  scope?: string;
+  identifier?: string;
+
+  clientId: string;
+  // Omitted by public clients
+  clientSecret?: string;
+  // Defaults to `client_secret_basic` with a secret, `none` without
+  clientAuthentication?: TOAuth2ClientAuthentication;
+  authorizationUrl: string;
+  tokenUrl: string;
+  redirectUri?: string;
+  scopes?: Array<string>;
+  timeout?: number;
+  // Issued tokens are stored there, see `getCachedTokens`
+  tokenCache?: IOAuth2TokenCache;
+  // Entry of the client in the cache, defaults to `<tokenUrl>#<clientId>`
+  tokenCacheKey?: string;
+}
+
+export interface IPKCEPair {
+  codeVerifier: string;
+  codeChallenge: string;
+  codeChallengeMethod: TPKCEMethod;
+}
+
+export interface IOAuth2AuthorizationRequest {
+  url: string;
+  // Kept until the callback, e.g. in a short lived cookie, along with the verifier
+  state: string;
+  codeVerifier: string;
+}
+
+export interface IOAuth2TokenSet {
+  accessToken: string;
+  tokenType: string;
+  // ms since epoch, absent when the provider does not tell
+  expiresAt?: number;
+  refreshToken?: string;
+  scopes?: Array<string>;
+  idToken?: string;
+  // Token response as received, provider specific fields included
+  raw: Record<string, unknown>;
+}
+
+/**
+ * Token sets kept between runs, e.g. `FileOAuth2TokenCache` for CLI tools.
+ */
+export interface IOAuth2TokenCache {
+  get(opts: { key: string }): Promise<IOAuth2TokenSet | undefined>;
+  set(opts: { key: string; tokens: IOAuth2TokenSet }): Promise<void>;
+  delete(opts: { key: string }): Promise<void>;
+}
@@ -0,0 +1,372 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { NodeFetchNetworkRequest } from '@/helpers/network';
+import C from 'node:crypto';
+import {
+  IOAuth2AuthorizationRequest,
+  IOAuth2ClientHelperOptions,
+  IOAuth2TokenCache,
+  IOAuth2TokenSet,
+  IPKCEPair,
+  OAuth2ClientAuthentications,
+  OAuth2Defaults,
+  OAuth2ErrorCodes,
+  PKCEMethods,
+  TOAuth2ClientAuthentication,
+  TPKCEMethod,
+} from './common';
+
+// --------------------------------------------------------
+/**
+ * Client side of the OAuth2 authorization code flow with PKCE (RFC 6749, RFC 7636), e.g. to
+ * connect a shop of a third party marketplace or to log a CLI tool in.
+ *
+ * `createAuthorizationRequest` builds the url the user is sent to, its `state` and code
+ * verifier are kept until the callback. `handleCallback` checks them and exchanges the code,
+ * `refresh` renews the access token later on. Token endpoint errors surface as
+ * `ApplicationError`, `invalid_grant` as a 401.
+ *
+ * With a `tokenCache` every issued token set is stored, `getCachedTokens` gives it back in a
+ * later run and refreshes it once it is about to expire.
+ *
+ * @example
+ * ```typescript
+ * const oauth = new OAuth2ClientHelper({
+ *   clientId: env.SHOP_CLIENT_ID,
+ *   clientSecret: env.SHOP_CLIENT_SECRET,
+ *   authorizationUrl: 'https://auth.marketplace.com/oauth2/authorize',
+ *   tokenUrl: 'https://auth.marketplace.com/oauth2/token',
+ *   redirectUri: 'https://api.example.com/integrations/marketplace/callback',
+ *   scopes: ['orders.read'],
+ * });
+ *
+ * const { url, state, codeVerifier } = oauth.createAuthorizationRequest();
+ *
+ * // in the callback route
+ * const tokens = await oauth.handleCallback({ url: context.req.url, state, codeVerifier });
+ * ```
+ */
+export class OAuth2ClientHelper extends BaseHelper {
+  private clientId: string;
+  private clientSecret?: string;
+  private clientAuthentication: TOAuth2ClientAuthentication;
+  private authorizationUrl: string;
+  private tokenUrl: string;
+  private redirectUri?: string;
+  private scopes: Array<string>;
+  private timeout: number;
+  private network: NodeFetchNetworkRequest;
+  private tokenCache?: IOAuth2TokenCache;
+  private tokenCacheKey: string;
+
+  constructor(opts: IOAuth2ClientHelperOptions) {
+    super({
+      scope: opts.scope ?? OAuth2ClientHelper.name,
+      identifier: opts.identifier ?? OAuth2ClientHelper.name,
+    });
+
+    this.clientId = opts.clientId;
+    this.clientSecret = opts.clientSecret;
+    this.clientAuthentication =
+      opts.clientAuthentication ??
+      (opts.clientSecret ? OAuth2ClientAuthentications.BASIC : OAuth2ClientAuthentications.NONE);
+
+    if (this.clientAuthentication !== OAuth2ClientAuthentications.NONE && !this.clientSecret) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[OAuth2ClientHelper] clientSecret is required | clientAuthentication: ${this.clientAuthentication}`,
+      });
+    }
+
+    this.authorizationUrl = opts.authorizationUrl;
+    this.tokenUrl = opts.tokenUrl;
+    this.redirectUri = opts.redirectUri;
+    this.scopes = opts.scopes ?? [];
+    this.timeout = opts.timeout ?? OAuth2Defaults.TIMEOUT;
+    this.network = new NodeFetchNetworkRequest({ name: this.identifier, networkOptions: {} });
+    this.tokenCache = opts.tokenCache;
+    this.tokenCacheKey = opts.tokenCacheKey ?? `${opts.tokenUrl}#${opts.clientId}`;
+  }
+
+  // --------------------------------------------------------
+  // PKCE
+  // --------------------------------------------------------
+  static computeCodeChallenge(opts: { codeVerifier: string; method?: TPKCEMethod }) {
+    const { codeVerifier, method = OAuth2Defaults.PKCE_METHOD } = opts;
+    if (method === PKCEMethods.PLAIN) {
+      return codeVerifier;
+    }
+
+    return C.createHash('sha256').update(codeVerifier).digest('base64url');
+  }
+
+  static generatePKCE(opts: { method?: TPKCEMethod; length?: number } = {}): IPKCEPair {
+    const { method = OAuth2Defaults.PKCE_METHOD, length = OAuth2Defaults.CODE_VERIFIER_LENGTH } =
+      opts;
+
+    const codeVerifier = C.randomBytes(length).toString('base64url');
+    return {
+      codeVerifier,
+      codeChallenge: OAuth2ClientHelper.computeCodeChallenge({ codeVerifier, method }),
+      codeChallengeMethod: method,
+    };
+  }
+
+  /**
+   * Authorization server side check of a code verifier against the challenge of the
+   * authorization request.
+   */
+  static verifyPKCE(opts: {
+    codeVerifier: string;
+    codeChallenge: string;
+    method?: TPKCEMethod;
+  }): boolean {
+    const { codeVerifier, codeChallenge, method } = opts;
+
+    // RFC 7636 section 4.1, 43 to 128 unreserved characters
+    if (!/^[A-Za-z0-9\-._~]{43,128}$/.test(codeVerifier)) {
+      return false;
+    }
+
+    const expected = Buffer.from(OAuth2ClientHelper.computeCodeChallenge({ codeVerifier, method }));
+    const received = Buffer.from(codeChallenge);
+    return expected.length === received.length && C.timingSafeEqual(expected, received);
+  }
+
+  // --------------------------------------------------------
+  // AUTHORIZATION
+  // --------------------------------------------------------
+  /**
+   * @returns the url of the provider consent page, `params` are added as is, e.g. `prompt`
+   */
+  createAuthorizationRequest(
+    opts: {
+      scopes?: Array<string>;
+      redirectUri?: string;
+      state?: string;
+      pkceMethod?: TPKCEMethod;
+      params?: Record<string, string>;
+    } = {},
+  ): IOAuth2AuthorizationRequest {
+    const {
+      scopes = this.scopes,
+      redirectUri = this.redirectUri,
+      state = C.randomBytes(OAuth2Defaults.STATE_LENGTH).toString('base64url'),
+      pkceMethod,
+      params,
+    } = opts;
+
+    const { codeVerifier, codeChallenge, codeChallengeMethod } = OAuth2ClientHelper.generatePKCE({
+      method: pkceMethod,
+    });
+
+    const url = new URL(this.authorizationUrl);
+    url.searchParams.set('response_type', 'code');
+    url.searchParams.set('client_id', this.clientId);
+    if (redirectUri) {
+      url.searchParams.set('redirect_uri', redirectUri);
+    }
+
+    if (scopes.length) {
+      url.searchParams.set('scope', scopes.join(OAuth2Defaults.SCOPE_SEPARATOR));
+    }
+
+    url.searchParams.set('state', state);
+    url.searchParams.set('code_challenge', codeChallenge);
+    url.searchParams.set('code_challenge_method', codeChallengeMethod);
+    for (const [name, value] of Object.entries(params ?? {})) {
+      url.searchParams.set(name, value);
+    }
+
+    return { url: url.toString(), state, codeVerifier };
+  }
+
+  /**
+   * Check the redirect of the provider against the `state` of the authorization request and
+   * exchange its code.
+   */
+  async handleCallback(opts: {
+    url: string;
+    state: string;
+    codeVerifier: string;
+    redirectUri?: string;
+  }): Promise<IOAuth2TokenSet> {
+    const { url, state, codeVerifier, redirectUri } = opts;
+    const params = new URL(url, 'http://oauth2.local').searchParams;
+
+    const error = params.get('error');
+    if (error) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+        messageCode: OAuth2ErrorCodes.ACCESS_DENIED,
+        message: `[handleCallback] Authorization refused | error: ${error} | description: ${params.get('error_description')}`,
+      });
+    }
+
+    const received = Buffer.from(params.get('state') ?? '');
+    const expected = Buffer.from(state);
+    if (received.length !== expected.length || !C.timingSafeEqual(received, expected)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: OAuth2ErrorCodes.STATE_MISMATCH,
+        message: '[handleCallback] Callback state does not match the authorization request!',
+      });
+    }
+
+    const code = params.get('code');
+    if (!code) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: OAuth2ErrorCodes.MISSING_CODE,
+        message: '[handleCallback] Callback has no authorization code!',
+      });
+    }
+
+    return this.exchangeCode({ code, codeVerifier, redirectUri });
+  }
+
+  // --------------------------------------------------------
+  // TOKENS
+  // --------------------------------------------------------
+  async exchangeCode(opts: {
+    code: string;
+    codeVerifier: string;
+    redirectUri?: string;
+  }): Promise<IOAuth2TokenSet> {
+    const { code, codeVerifier, redirectUri = this.redirectUri } = opts;
+
+    const tokens = await this.requestToken({
+      grant_type: 'authorization_code',
+      code,
+      code_verifier: codeVerifier,
+      ...(redirectUri ? { redirect_uri: redirectUri } : {}),
+    });
+
+    await this.tokenCache?.set({ key: this.tokenCacheKey, tokens });
+    return tokens;
+  }
+
+  /**
+   * Providers which do not rotate refresh tokens answer without one, the current one is kept.
+   */
+  async refresh(opts: { refreshToken: string; scopes?: Array<string> }): Promise<IOAuth2TokenSet> {
+    const { refreshToken, scopes } = opts;
+
+    const tokens = await this.requestToken({
+      grant_type: 'refresh_token',
+      refresh_token: refreshToken,
+      ...(scopes?.length ? { scope: scopes.join(OAuth2Defaults.SCOPE_SEPARATOR) } : {}),
+    });
+
+    const rs = { ...tokens, refreshToken: tokens.refreshToken ?? refreshToken };
+    await this.tokenCache?.set({ key: this.tokenCacheKey, tokens: rs });
+    return rs;
+  }
+
+  /**
+   * Token set of the cache, refreshed when it expires within `OAuth2Defaults.EXPIRY_SKEW`.
+   *
+   * @returns `undefined` when the user has to authorize again: nothing cached, expired without
+   * refresh token or refresh token rejected
+   */
+  async getCachedTokens(): Promise<IOAuth2TokenSet | undefined> {
+    const key = this.tokenCacheKey;
+    const tokens = await this.tokenCache?.get({ key });
+    if (!tokens) {
+      return undefined;
+    }
+
+    const isExpiring =
+      tokens.expiresAt !== undefined && tokens.expiresAt - OAuth2Defaults.EXPIRY_SKEW <= Date.now();
+    if (!isExpiring) {
+      return tokens;
+    }
+
+    if (!tokens.refreshToken) {
+      await this.tokenCache?.delete({ key });
+      return undefined;
+    }
+
+    try {
+      return await this.refresh({ refreshToken: tokens.refreshToken, scopes: tokens.scopes });
+    } catch (error) {
+      if ((error as { messageCode?: string }).messageCode !== OAuth2ErrorCodes.INVALID_GRANT) {
+        throw error;
+      }
+
+      await this.tokenCache?.delete({ key });
+      return undefined;
+    }
+  }
+
+  async clearCachedTokens() {
+    await this.tokenCache?.delete({ key: this.tokenCacheKey });
+  }
+
+  // --------------------------------------------------------
+  private async requestToken(form: Record<string, string>): Promise<IOAuth2TokenSet> {
+    const headers: Record<string, string> = {
+      ['accept']: 'application/json',
+      ['content-type']: 'application/x-www-form-urlencoded',
+    };
+    const body = new URLSearchParams(form);
+
+    switch (this.clientAuthentication) {
+      case OAuth2ClientAuthentications.BASIC: {
+        // RFC 6749 section 2.3.1, both parts are form encoded first
+        const credentials = [this.clientId, this.clientSecret!]
+          .map(part => encodeURIComponent(part))
+          .join(':');
+        headers['authorization'] = `Basic ${Buffer.from(credentials).toString('base64')}`;
+        break;
+      }
+      case OAuth2ClientAuthentications.POST: {
+        body.set('client_id', this.clientId);
+        body.set('client_secret', this.clientSecret!);
+        break;
+      }
+      default: {
+        body.set('client_id', this.clientId);
+        break;
+      }
+    }
+
+    const response = await this.network.getNetworkService().post({
+      url: this.tokenUrl,
+      timeout: this.timeout,
+      headers,
+      body: body.toString(),
+    });
+
+    const data = await response.json().catch(() => ({}));
+    if (!response.ok || typeof data.access_token !== 'string') {
+      // The code or refresh token is dead, the user has to authorize again
+      const isInvalidGrant = data.error === 'invalid_grant';
+      throw getError({
+        statusCode: isInvalidGrant
+          ? HTTP.ResultCodes.RS_4.Unauthorized
+          : HTTP.ResultCodes.RS_5.BadGateway,
+        messageCode: isInvalidGrant
+          ? OAuth2ErrorCodes.INVALID_GRANT
+          : OAuth2ErrorCodes.TOKEN_REQUEST_FAILED,
+        message: `[requestToken] Token request failed | grant: ${form.grant_type} | status: ${response.status} | error: ${data.error_description ?? data.error}`,
+      });
+    }
+
+    this.logger
+      .for(this.requestToken.name)
+      .info('Token issued | grant: %s | expiresIn: %s', form.grant_type, data.expires_in);
+
+    const expiresIn = Number(data.expires_in ?? NaN);
+    return {
+      accessToken: data.access_token,
+      tokenType: data.token_type ?? 'Bearer',
+      expiresAt: Number.isFinite(expiresIn) ? Date.now() + expiresIn * 1_000 : undefined,
+      refreshToken: data.refresh_token,
+      scopes: typeof data.scope === 'string' ? data.scope.split(/\s+/).filter(Boolean) : undefined,
+      idToken: data.id_token,
+      raw: data,
+    };
+  }
+}
@@ -0,0 +1,3 @@
+export * from './common';
+export * from './helper';
+export * from './token-cache';
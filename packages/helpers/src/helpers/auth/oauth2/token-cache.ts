@@ -0,0 +1,184 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { EnvelopeCryptoHelper } from '@/helpers/crypto';
+import { getError } from '@/helpers/error';
+import C from 'node:crypto';
+import fs from 'node:fs/promises';
+import path from 'node:path';
+import { IOAuth2TokenCache, IOAuth2TokenSet, OAuth2TokenCacheDefaults } from './common';
+
+interface ITokenCacheFile {
+  // Scrypt salt of the passphrase key, base64
+  salt?: string;
+  // Envelope of the token sets by key
+  tokens: string;
+}
+
+// --------------------------------------------------------
+/**
+ * Token sets encrypted in one file, so CLI tools and batch jobs reuse their tokens between runs
+ * instead of authenticating every time.
+ *
+ * The file is sealed with `envelope`, or with a key derived from `passphrase` by scrypt, and
+ * written owner only through a rename so a crash never leaves half a file. A file which can not
+ * be read or decrypted, e.g. after a passphrase change, is treated as empty: the tool
+ * authenticates again and overwrites it. Writes of concurrent processes are last write wins.
+ *
+ * @example
+ * ```typescript
+ * const oauth = new OAuth2ClientHelper({
+ *   clientId: 'ignis-cli',
+ *   authorizationUrl: 'https://auth.example.com/oauth2/authorize',
+ *   tokenUrl: 'https://auth.example.com/oauth2/token',
+ *   tokenCache: new FileOAuth2TokenCache({
+ *     path: path.join(os.homedir(), '.config', 'ignis', 'tokens'),
+ *     passphrase: env.IGNIS_CLI_PASSPHRASE,
+ *   }),
+ * });
+ *
+ * const tokens = (await oauth.getCachedTokens()) ?? (await login(oauth));
+ * ```
+ */
+export class FileOAuth2TokenCache extends BaseHelper implements IOAuth2TokenCache {
+  private path: string;
+  private envelope?: EnvelopeCryptoHelper;
+  private passphrase?: string;
+  // Passphrase keys by salt, scrypt is slow on purpose
+  private passphraseKeys = new Map<string, EnvelopeCryptoHelper>();
+  private writing: Promise<void> = Promise.resolve();
+
+  constructor(opts: {
+    path: string;
+    envelope?: EnvelopeCryptoHelper;
+    passphrase?: string;
+    identifier?: string;
+  }) {
+    super({
+      scope: FileOAuth2TokenCache.name,
+      identifier: opts.identifier ?? FileOAuth2TokenCache.name,
+    });
+
+    if (!opts.envelope && !opts.passphrase) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: '[FileOAuth2TokenCache] Either envelope or passphrase is required!',
+      });
+    }
+
+    this.path = opts.path;
+    this.envelope = opts.envelope;
+    this.passphrase = opts.passphrase;
+  }
+
+  // --------------------------------------------------------
+  async get(opts: { key: string }): Promise<IOAuth2TokenSet | undefined> {
+    const { entries } = await this.read();
+    return entries[opts.key];
+  }
+
+  set(opts: { key: string; tokens: IOAuth2TokenSet }): Promise<void> {
+    return this.update(entries => {
+      entries[opts.key] = opts.tokens;
+    });
+  }
+
+  delete(opts: { key: string }): Promise<void> {
+    return this.update(entries => {
+      delete entries[opts.key];
+    });
+  }
+
+  // --------------------------------------------------------
+  private update(mutate: (entries: Record<string, IOAuth2TokenSet>) => void): Promise<void> {
+    // Writes of this process are serialized, each one reads the result of the previous one
+    const rs = this.writing.then(async () => {
+      const { entries, salt } = await this.read();
+      mutate(entries);
+      await this.write({ entries, salt });
+    });
+
+    this.writing = rs.catch(() => {});
+    return rs;
+  }
+
+  private async read(): Promise<{ entries: Record<string, IOAuth2TokenSet>; salt?: string }> {
+    let content: string;
+    try {
+      content = await fs.readFile(this.path, 'utf-8');
+    } catch (error) {
+      if ((error as NodeJS.ErrnoException).code !== 'ENOENT') {
+        this.logger.for(this.read.name).warn('Failed to read token cache | error: %s', error);
+      }
+      return { entries: {} };
+    }
+
+    try {
+      const file = JSON.parse(content) as ITokenCacheFile;
+      const plaintext = this.getEnvelope({ salt: file.salt }).decrypt({
+        ciphertext: file.tokens,
+        aad: OAuth2TokenCacheDefaults.AAD,
+      });
+
+      return { entries: JSON.parse(plaintext), salt: file.salt };
+    } catch (error) {
+      this.logger
+        .for(this.read.name)
+        .warn('Unreadable token cache, starting empty | path: %s | error: %s', this.path, error);
+      return { entries: {} };
+    }
+  }
+
+  private async write(opts: { entries: Record<string, IOAuth2TokenSet>; salt?: string }) {
+    const salt = this.passphrase
+      ? (opts.salt ?? C.randomBytes(16).toString('base64'))
+      : undefined;
+
+    const file: ITokenCacheFile = {
+      salt,
+      tokens: this.getEnvelope({ salt }).encrypt({
+        plaintext: JSON.stringify(opts.entries),
+        aad: OAuth2TokenCacheDefaults.AAD,
+      }),
+    };
+
+    await fs.mkdir(path.dirname(this.path), {
+      recursive: true,
+      mode: OAuth2TokenCacheDefaults.DIRECTORY_MODE,
+    });
+
+    const temporary = `${this.path}.${process.pid}.${C.randomBytes(4).toString('hex')}.tmp`;
+    try {
+      await fs.writeFile(temporary, JSON.stringify(file), {
+        mode: OAuth2TokenCacheDefaults.FILE_MODE,
+      });
+      await fs.rename(temporary, this.path);
+    } catch (error) {
+      await fs.rm(temporary, { force: true });
+      throw error;
+    }
+  }
+
+  private getEnvelope(opts: { salt?: string }): EnvelopeCryptoHelper {
+    const { salt } = opts;
+    if (!this.passphrase) {
+      return this.envelope!;
+    }
+
+    if (!salt) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[FileOAuth2TokenCache] Token cache has no passphrase salt | path: ${this.path}`,
+      });
+    }
+
+    let rs = this.passphraseKeys.get(salt);
+    if (!rs) {
+      const version = OAuth2TokenCacheDefaults.PASSPHRASE_KEY_VERSION;
+      const key = C.scryptSync(this.passphrase, Buffer.from(salt, 'base64'), 32);
+      rs = new EnvelopeCryptoHelper({ keys: { [version]: key }, currentKeyVersion: version });
+      this.passphraseKeys.set(salt, rs);
+    }
+
+    return rs;
+  }
+}
@@ -0,0 +1,17 @@
+// --------------------------------------------------------
+export class OIDCDefaults {
+  static readonly DISCOVERY_PATH = '/.well-known/openid-configuration';
+  // Used when the discovery document carries no `Cache-Control: max-age`
+  static readonly CACHE_TTL = 60 * 60 * 1_000;
+  // Minimum interval between refetches after a failed discovery
+  static readonly COOLDOWN = 30 * 1_000;
+  static readonly TIMEOUT = 10 * 1_000;
+}
+
+// --------------------------------------------------------
+export class OIDCErrorCodes {
+  static readonly DISCOVERY_FAILED = 'OIDC_DISCOVERY_FAILED';
+  // The document does not belong to the configured issuer, OpenID Connect Discovery 4.3
+  static readonly ISSUER_MISMATCH = 'OIDC_ISSUER_MISMATCH';
+  static readonly MISSING_ENDPOINT = 'OIDC_MISSING_ENDPOINT';
+}
@@ -0,0 +1,33 @@
+export interface IOIDCDiscoveryHelperOptions {
+  scope?: string;
+  identifier?: string;
+
+  // e.g. https://accounts.example.com, the discovery document is read from its well known path
+  issuer: string;
+  cacheTtl?: number;
+  cooldown?: number;
+  timeout?: number;
+  // Passed to the `JWKSHelper` of the `jwks_uri`
+  jwksCacheTtl?: number;
+  jwksCooldown?: number;
+}
+
+/**
+ * Provider metadata of OpenID Connect Discovery 1.0, section 3.
+ */
+export interface IOIDCProviderMetadata {
+  issuer: string;
+  authorization_endpoint?: string;
+  token_endpoint?: string;
+  jwks_uri?: string;
+  userinfo_endpoint?: string;
+  end_session_endpoint?: string;
+  revocation_endpoint?: string;
+  introspection_endpoint?: string;
+  scopes_supported?: Array<string>;
+  response_types_supported?: Array<string>;
+  code_challenge_methods_supported?: Array<string>;
+  id_token_signing_alg_values_supported?: Array<string>;
+  token_endpoint_auth_methods_supported?: Array<string>;
+  [extra: string]: unknown;
+}
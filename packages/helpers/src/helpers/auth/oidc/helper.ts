@@ -0,0 +1,223 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { NodeFetchNetworkRequest } from '@/helpers/network';
+import {
+  IJWTHelperOptions,
+  JWKSHelper,
+  JWTAlgorithms,
+  JWTHelper,
+  TJWTAlgorithm,
+  TJWTKeyResolver,
+} from '../jwt';
+import { IOAuth2ClientHelperOptions, OAuth2ClientHelper } from '../oauth2';
+import {
+  IOIDCDiscoveryHelperOptions,
+  IOIDCProviderMetadata,
+  OIDCDefaults,
+  OIDCErrorCodes,
+} from './common';
+
+// --------------------------------------------------------
+/**
+ * Configure token verification and OAuth2 flows from the issuer url alone, reading the endpoints
+ * and signing keys of the provider from its OpenID Connect discovery document.
+ *
+ * The document is cached for its `Cache-Control: max-age` or `cacheTtl`, concurrent callers
+ * share a single request and the cached document is kept when a refresh fails. The signing keys
+ * behind `jwks_uri` are handled by a `JWKSHelper`, rebuilt when the provider moves them.
+ *
+ * @example
+ * ```typescript
+ * const oidc = new OIDCDiscoveryHelper({ issuer: 'https://accounts.example.com' });
+ *
+ * const jwt = oidc.createJWTHelper({ audience: 'api' });
+ * const { payload } = await jwt.verify({ token });
+ *
+ * const oauth = await oidc.createOAuth2Client({ clientId, clientSecret, redirectUri });
+ * ```
+ */
+export class OIDCDiscoveryHelper extends BaseHelper {
+  private issuer: string;
+  private cacheTtl: number;
+  private cooldown: number;
+  private timeout: number;
+  private jwksOptions: { cacheTtl?: number; cooldown?: number };
+  private network: NodeFetchNetworkRequest;
+
+  private metadata?: IOIDCProviderMetadata;
+  private expiresAt = 0;
+  private metadataPromise?: Promise<IOIDCProviderMetadata>;
+  private jwks?: JWKSHelper;
+
+  constructor(opts: IOIDCDiscoveryHelperOptions) {
+    super({
+      scope: opts.scope ?? OIDCDiscoveryHelper.name,
+      identifier: opts.identifier ?? OIDCDiscoveryHelper.name,
+    });
+
+    this.issuer = opts.issuer;
+    this.cacheTtl = opts.cacheTtl ?? OIDCDefaults.CACHE_TTL;
+    this.cooldown = opts.cooldown ?? OIDCDefaults.COOLDOWN;
+    this.timeout = opts.timeout ?? OIDCDefaults.TIMEOUT;
+    this.jwksOptions = { cacheTtl: opts.jwksCacheTtl, cooldown: opts.jwksCooldown };
+    this.network = new NodeFetchNetworkRequest({ name: this.identifier, networkOptions: {} });
+  }
+
+  // --------------------------------------------------------
+  static getDiscoveryUrl(opts: { issuer: string }) {
+    return `${opts.issuer.replace(/\/+$/, '')}${OIDCDefaults.DISCOVERY_PATH}`;
+  }
+
+  private getMaxAge(headers: Headers) {
+    const matched = /max-age=(\d+)/i.exec(headers.get('cache-control') ?? '');
+    return matched ? Number(matched[1]) * 1_000 : this.cacheTtl;
+  }
+
+  private async fetchMetadata(): Promise<IOIDCProviderMetadata> {
+    const url = OIDCDiscoveryHelper.getDiscoveryUrl({ issuer: this.issuer });
+    const response = await this.network.getNetworkService().get({
+      url,
+      timeout: this.timeout,
+      headers: { ['accept']: 'application/json' },
+    });
+
+    const data = await response.json().catch(() => ({}));
+    if (!response.ok || typeof data.issuer !== 'string') {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+        messageCode: OIDCErrorCodes.DISCOVERY_FAILED,
+        message: `[fetchMetadata] Failed to fetch OIDC discovery document | url: ${url} | status: ${response.status}`,
+      });
+    }
+
+    // Trailing slashes aside, a document naming another issuer could hand out foreign keys
+    if (data.issuer.replace(/\/+$/, '') !== this.issuer.replace(/\/+$/, '')) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+        messageCode: OIDCErrorCodes.ISSUER_MISMATCH,
+        message: `[fetchMetadata] Discovery document issuer mismatch | expected: ${this.issuer} | received: ${data.issuer}`,
+      });
+    }
+
+    this.metadata = data as IOIDCProviderMetadata;
+    this.expiresAt = Date.now() + this.getMaxAge(response.headers);
+
+    this.logger
+      .for(this.fetchMetadata.name)
+      .info('OIDC metadata refreshed | issuer: %s | jwks: %s', this.issuer, data.jwks_uri);
+    return this.metadata;
+  }
+
+  /**
+   * Refetch the discovery document. Concurrent callers share a single request and the cached
+   * document is kept when it fails.
+   */
+  refresh(): Promise<IOIDCProviderMetadata> {
+    if (!this.metadataPromise) {
+      this.metadataPromise = this.fetchMetadata()
+        .catch(error => {
+          // Back off for a cooldown instead of refetching on every lookup
+          this.expiresAt = Date.now() + this.cooldown;
+
+          if (!this.metadata) {
+            throw error;
+          }
+
+          this.logger
+            .for(this.refresh.name)
+            .error(
+              'Failed to refresh OIDC metadata, keeping cached document | issuer: %s | error: %s',
+              this.issuer,
+              error,
+            );
+          return this.metadata;
+        })
+        .finally(() => {
+          this.metadataPromise = undefined;
+        });
+    }
+
+    return this.metadataPromise;
+  }
+
+  async getMetadata(): Promise<IOIDCProviderMetadata> {
+    if (this.metadata && this.expiresAt > Date.now()) {
+      return this.metadata;
+    }
+
+    return this.refresh();
+  }
+
+  // --------------------------------------------------------
+  async getJWKS(): Promise<JWKSHelper> {
+    const { jwks_uri: url } = await this.getEndpoints({ names: ['jwks_uri'] });
+
+    if (!this.jwks || this.jwks.getUrl() !== url) {
+      this.jwks = new JWKSHelper({
+        identifier: `${this.identifier}-jwks`,
+        url,
+        timeout: this.timeout,
+        ...this.jwksOptions,
+      });
+    }
+
+    return this.jwks;
+  }
+
+  getKeyResolver(): TJWTKeyResolver {
+    return async ({ header }) => {
+      const jwks = await this.getJWKS();
+      return jwks.getKey({ kid: header.kid, alg: header.alg });
+    };
+  }
+
+  /**
+   * Verifier of the tokens of the provider, `iss` is checked against the issuer.
+   */
+  createJWTHelper(
+    opts: Omit<IJWTHelperOptions, 'algorithm' | 'issuer' | 'keyResolver'> & {
+      algorithm?: TJWTAlgorithm;
+    } = {},
+  ): JWTHelper {
+    const { algorithm = JWTAlgorithms.RS256, ...rest } = opts;
+    return new JWTHelper({
+      ...rest,
+      algorithm,
+      issuer: this.issuer,
+      keyResolver: this.getKeyResolver(),
+    });
+  }
+
+  async createOAuth2Client(
+    opts: Omit<IOAuth2ClientHelperOptions, 'authorizationUrl' | 'tokenUrl'>,
+  ): Promise<OAuth2ClientHelper> {
+    const { authorization_endpoint, token_endpoint } = await this.getEndpoints({
+      names: ['authorization_endpoint', 'token_endpoint'],
+    });
+
+    return new OAuth2ClientHelper({
+      ...opts,
+      authorizationUrl: authorization_endpoint,
+      tokenUrl: token_endpoint,
+    });
+  }
+
+  // --------------------------------------------------------
+  private async getEndpoints<K extends keyof IOIDCProviderMetadata & string>(opts: {
+    names: Array<K>;
+  }): Promise<Record<K, string>> {
+    const metadata = await this.getMetadata();
+
+    const missing = opts.names.filter(name => typeof metadata[name] !== 'string');
+    if (missing.length) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+        messageCode: OIDCErrorCodes.MISSING_ENDPOINT,
+        message: `[getEndpoints] Provider does not publish ${missing.join(', ')} | issuer: ${this.issuer}`,
+      });
+    }
+
+    return metadata as unknown as Record<K, string>;
+  }
+}
@@ -0,0 +1,30 @@
+// --------------------------------------------------------
+export class PasswordHashAlgorithms {
+  static readonly ARGON2ID = 'argon2id';
+
+  static readonly SCHEME_SET = new Set([this.ARGON2ID]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * OWASP recommended argon2id baseline (19 MiB, 2 iterations).
+ */
+export class PasswordHashDefaults {
+  // KiB
+  static readonly MEMORY_COST = 19_456;
+  static readonly TIME_COST = 2;
+
+  // Lower bounds accepted from configuration
+  static readonly MIN_MEMORY_COST = 8_192;
+  static readonly MIN_TIME_COST = 1;
+}
+
+// --------------------------------------------------------
+export class PasswordEnvironmentKeys {
+  static readonly APP_ENV_PASSWORD_HASH_MEMORY_COST = 'APP_ENV_PASSWORD_HASH_MEMORY_COST';
+  static readonly APP_ENV_PASSWORD_HASH_TIME_COST = 'APP_ENV_PASSWORD_HASH_TIME_COST';
+}
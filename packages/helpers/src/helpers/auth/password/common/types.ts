@@ -0,0 +1,13 @@
+export interface IPasswordHashCosts {
+  // Memory in KiB
+  memoryCost: number;
+  // Number of iterations
+  timeCost: number;
+}
+
+// Parameters encoded in a PHC string, e.g. `$argon2id$v=19$m=19456,t=2,p=1$<salt>$<hash>`
+export interface IPasswordHashInfo extends Partial<IPasswordHashCosts> {
+  algorithm: string;
+  version?: number;
+  parallelism?: number;
+}
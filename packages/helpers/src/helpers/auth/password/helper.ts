@@ -0,0 +1,192 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { applicationEnvironment } from '@/helpers/env';
+import { getError } from '@/helpers/error';
+import { int } from '@/utilities/parse.utility';
+import {
+  IPasswordHashCosts,
+  IPasswordHashInfo,
+  PasswordEnvironmentKeys,
+  PasswordHashAlgorithms,
+  PasswordHashDefaults,
+} from './common';
+
+export interface IPasswordHelperOptions extends Partial<IPasswordHashCosts> {
+  scope?: string;
+  identifier?: string;
+}
+
+// --------------------------------------------------------
+/**
+ * Hash and verify passwords with argon2id.
+ *
+ * Costs default to `APP_ENV_PASSWORD_HASH_MEMORY_COST` / `APP_ENV_PASSWORD_HASH_TIME_COST`,
+ * falling back to `PasswordHashDefaults`. Raising them later only affects new hashes, use
+ * `needsRehash` (or `verifyAndRehash`) on login to upgrade the stored ones.
+ *
+ * @example
+ * ```typescript
+ * const passwordHelper = new PasswordHelper();
+ *
+ * const hash = await passwordHelper.hash({ password });
+ *
+ * const { isValid, rehashed } = await passwordHelper.verifyAndRehash({
+ *   password,
+ *   hash: user.password,
+ * });
+ * if (isValid && rehashed) {
+ *   await userRepository.updateById({ id: user.id, data: { password: rehashed } });
+ * }
+ * ```
+ */
+export class PasswordHelper extends BaseHelper {
+  private costs: IPasswordHashCosts;
+
+  constructor(opts: IPasswordHelperOptions = {}) {
+    super({
+      scope: opts.scope ?? PasswordHelper.name,
+      identifier: opts.identifier ?? PasswordHelper.name,
+    });
+
+    const configured = PasswordHelper.getConfiguredCosts();
+    this.costs = {
+      memoryCost: opts.memoryCost ?? configured.memoryCost,
+      timeCost: opts.timeCost ?? configured.timeCost,
+    };
+
+    if (
+      !Number.isInteger(this.costs.memoryCost) ||
+      !Number.isInteger(this.costs.timeCost) ||
+      this.costs.memoryCost < PasswordHashDefaults.MIN_MEMORY_COST ||
+      this.costs.timeCost < PasswordHashDefaults.MIN_TIME_COST
+    ) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[PasswordHelper] Invalid argon2id costs | memoryCost: ${this.costs.memoryCost} | timeCost: ${this.costs.timeCost}`,
+      });
+    }
+  }
+
+  // --------------------------------------------------------
+  static getConfiguredCosts(): IPasswordHashCosts {
+    const memoryCost = int(
+      applicationEnvironment.get<string>(PasswordEnvironmentKeys.APP_ENV_PASSWORD_HASH_MEMORY_COST),
+    );
+    const timeCost = int(
+      applicationEnvironment.get<string>(PasswordEnvironmentKeys.APP_ENV_PASSWORD_HASH_TIME_COST),
+    );
+
+    return {
+      memoryCost: memoryCost || PasswordHashDefaults.MEMORY_COST,
+      timeCost: timeCost || PasswordHashDefaults.TIME_COST,
+    };
+  }
+
+  /**
+   * Read the algorithm and parameters of a PHC formatted hash without verifying it.
+   */
+  static parseHash(hash: string): IPasswordHashInfo | null {
+    // $<algorithm>$v=<version>$<key>=<value>,...$<salt>$<digest>
+    const segments = hash.split('$');
+    if (segments.length < 4 || segments[0] !== '') {
+      return null;
+    }
+
+    const [, algorithm, ...rest] = segments;
+    const info: IPasswordHashInfo = { algorithm };
+
+    for (const segment of rest) {
+      for (const pair of segment.split(',')) {
+        const [key, value] = pair.split('=');
+        if (value === undefined) {
+          continue;
+        }
+
+        switch (key) {
+          case 'v': {
+            info.version = int(value);
+            break;
+          }
+          case 'm': {
+            info.memoryCost = int(value);
+            break;
+          }
+          case 't': {
+            info.timeCost = int(value);
+            break;
+          }
+          case 'p': {
+            info.parallelism = int(value);
+            break;
+          }
+          default: {
+            break;
+          }
+        }
+      }
+    }
+
+    return info;
+  }
+
+  // --------------------------------------------------------
+  getCosts(): IPasswordHashCosts {
+    return { ...this.costs };
+  }
+
+  async hash(opts: { password: string }): Promise<string> {
+    if (!opts.password) {
+      throw getError({ message: '[hash] password must not be empty!' });
+    }
+
+    return Bun.password.hash(opts.password, {
+      algorithm: PasswordHashAlgorithms.ARGON2ID,
+      memoryCost: this.costs.memoryCost,
+      timeCost: this.costs.timeCost,
+    });
+  }
+
+  /**
+   * Constant time comparison of a password against a stored hash.
+   * Legacy bcrypt / argon2i hashes are accepted too, so they can be upgraded on login.
+   */
+  async verify(opts: { password: string; hash: string }): Promise<boolean> {
+    const { password, hash } = opts;
+    if (!password || !hash) {
+      return false;
+    }
+
+    try {
+      return await Bun.password.verify(password, hash);
+    } catch (error) {
+      // Malformed or unsupported hash
+      this.logger.for(this.verify.name).error('Failed to verify password | error: %s', error);
+      return false;
+    }
+  }
+
+  /**
+   * Whether a stored hash was produced with another algorithm or with costs that no longer
+   * match the configured ones.
+   */
+  needsRehash(opts: { hash: string }): boolean {
+    const info = PasswordHelper.parseHash(opts.hash);
+    if (!info || info.algorithm !== PasswordHashAlgorithms.ARGON2ID) {
+      return true;
+    }
+
+    return info.memoryCost !== this.costs.memoryCost || info.timeCost !== this.costs.timeCost;
+  }
+
+  async verifyAndRehash(opts: {
+    password: string;
+    hash: string;
+  }): Promise<{ isValid: boolean; rehashed?: string }> {
+    const isValid = await this.verify(opts);
+    if (!isValid || !this.needsRehash({ hash: opts.hash })) {
+      return { isValid };
+    }
+
+    return { isValid, rehashed: await this.hash({ password: opts.password }) };
+  }
+}
@@ -0,0 +1,14 @@
+// --------------------------------------------------------
+export class SignedUrlDefaults {
+  static readonly EXPIRES_PARAM = 'expires';
+  static readonly SIGNATURE_PARAM = 'signature';
+  // Seconds a signed url stays valid
+  static readonly EXPIRES_IN = 15 * 60;
+}
+
+// --------------------------------------------------------
+export class SignedUrlErrorCodes {
+  static readonly MALFORMED = 'SIGNED_URL_MALFORMED';
+  static readonly EXPIRED = 'SIGNED_URL_EXPIRED';
+  static readonly INVALID_SIGNATURE = 'SIGNED_URL_INVALID_SIGNATURE';
+}
@@ -0,0 +1,18 @@
+export interface ISignedUrlHelperOptions {
+  scope?: string;
+  identifier?: string;
+
+  // The first secret signs, all of them verify, so a rotated secret can stay accepted for a while
+  secrets: string | Buffer | Array<string | Buffer>;
+  // Seconds, defaults to `SignedUrlDefaults.EXPIRES_IN`
+  expiresIn?: number;
+  expiresParam?: string;
+  signatureParam?: string;
+}
+
+export interface IVerifiedSignedUrl {
+  // Seconds since epoch
+  expiresAt: number;
+  // Query of the url without the signature parameters
+  params: URLSearchParams;
+}
@@ -0,0 +1,31 @@
+// --------------------------------------------------------
+export class TOTPAlgorithms {
+  static readonly SHA1 = 'SHA1';
+  static readonly SHA256 = 'SHA256';
+  static readonly SHA512 = 'SHA512';
+
+  static readonly SCHEME_SET = new Set([this.SHA1, this.SHA256, this.SHA512]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * RFC 6238 defaults, the only combination every authenticator app supports.
+ */
+export class TOTPDefaults {
+  static readonly ALGORITHM = TOTPAlgorithms.SHA1;
+  static readonly DIGITS = 6;
+  // Seconds per step
+  static readonly PERIOD = 30;
+  // Steps accepted before / after the current one to tolerate clock drift
+  static readonly WINDOW = 1;
+  // Bytes, 160 bits as recommended by RFC 4226
+  static readonly SECRET_LENGTH = 20;
+
+  static readonly RECOVERY_CODE_COUNT = 10;
+  // Characters per recovery code, displayed as two dash separated groups
+  static readonly RECOVERY_CODE_LENGTH = 10;
+}
@@ -0,0 +1,25 @@
+import { TConstValue } from '@/common/types';
+import { TOTPAlgorithms } from './constants';
+
+export type TTOTPAlgorithm = TConstValue<typeof TOTPAlgorithms>;
+
+export interface ITOTPOptions {
+  algorithm?: TTOTPAlgorithm;
+  digits?: number;
+  period?: number;
+}
+
+export interface ITOTPVerifyResult {
+  isValid: boolean;
+  // Steps between the matched code and the current time, e.g. -1 for the previous code
+  delta?: number;
+  // Store it and pass it back as `lastUsedStep` to reject replays of the same code
+  step?: number;
+}
+
+export interface IRecoveryCodes {
+  // Show these to the user once
+  codes: Array<string>;
+  // Persist these instead
+  hashes: Array<string>;
+}
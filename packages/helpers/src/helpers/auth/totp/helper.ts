@@ -0,0 +1,267 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import C from 'node:crypto';
+import {
+  IRecoveryCodes,
+  ITOTPOptions,
+  ITOTPVerifyResult,
+  TOTPAlgorithms,
+  TOTPDefaults,
+  TTOTPAlgorithm,
+} from './common';
+
+const BASE32_ALPHABET = 'ABCDEFGHIJKLMNOPQRSTUVWXYZ234567';
+// Recovery codes skip look alike characters (0 / O, 1 / I / L)
+const RECOVERY_CODE_ALPHABET = '23456789ABCDEFGHJKMNPQRSTUVWXYZ';
+
+export interface ITOTPHelperOptions extends ITOTPOptions {
+  scope?: string;
+  identifier?: string;
+
+  // Shown by authenticator apps above the account name
+  issuer?: string;
+  window?: number;
+}
+
+// --------------------------------------------------------
+/**
+ * RFC 6238 time based one time passwords, compatible with Google Authenticator, 1Password, Authy...
+ *
+ * @example
+ * ```typescript
+ * const totp = new TOTPHelper({ issuer: 'Ignis' });
+ *
+ * // enrollment, render the URI as a QR code
+ * const secret = TOTPHelper.generateSecret();
+ * const uri = totp.getProvisioningUri({ secret, accountName: user.email });
+ *
+ * // login
+ * const { isValid, step } = totp.verify({ secret, token: '123456', lastUsedStep: user.totpStep });
+ * ```
+ */
+export class TOTPHelper extends BaseHelper {
+  private issuer?: string;
+  private algorithm: TTOTPAlgorithm;
+  private digits: number;
+  private period: number;
+  private window: number;
+
+  constructor(opts: ITOTPHelperOptions = {}) {
+    super({
+      scope: opts.scope ?? TOTPHelper.name,
+      identifier: opts.identifier ?? TOTPHelper.name,
+    });
+
+    this.issuer = opts.issuer;
+    this.algorithm = opts.algorithm ?? TOTPDefaults.ALGORITHM;
+    this.digits = opts.digits ?? TOTPDefaults.DIGITS;
+    this.period = opts.period ?? TOTPDefaults.PERIOD;
+    this.window = opts.window ?? TOTPDefaults.WINDOW;
+
+    if (!TOTPAlgorithms.isValid(this.algorithm)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[TOTPHelper] Unsupported algorithm | algorithm: ${this.algorithm}`,
+      });
+    }
+
+    if (this.digits < 6 || this.digits > 8 || this.period <= 0 || this.window < 0) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[TOTPHelper] Invalid options | digits: ${this.digits} | period: ${this.period} | window: ${this.window}`,
+      });
+    }
+  }
+
+  // --------------------------------------------------------
+  static encodeBase32(buffer: Buffer): string {
+    let bits = 0;
+    let value = 0;
+    let rs = '';
+
+    for (const byte of buffer) {
+      value = (value << 8) | byte;
+      bits += 8;
+
+      while (bits >= 5) {
+        rs += BASE32_ALPHABET[(value >>> (bits - 5)) & 31];
+        bits -= 5;
+      }
+    }
+
+    if (bits > 0) {
+      rs += BASE32_ALPHABET[(value << (5 - bits)) & 31];
+    }
+
+    return rs;
+  }
+
+  static decodeBase32(input: string): Buffer {
+    // Secrets are often displayed in groups or typed in lowercase
+    const normalized = input.replace(/[\s-]/g, '').replace(/=+$/, '').toUpperCase();
+
+    let bits = 0;
+    let value = 0;
+    const bytes: Array<number> = [];
+
+    for (const char of normalized) {
+      const index = BASE32_ALPHABET.indexOf(char);
+      if (index < 0) {
+        throw getError({ message: `[decodeBase32] Invalid base32 character | char: ${char}` });
+      }
+
+      value = (value << 5) | index;
+      bits += 5;
+
+      if (bits >= 8) {
+        bytes.push((value >>> (bits - 8)) & 255);
+        bits -= 8;
+      }
+    }
+
+    return Buffer.from(bytes);
+  }
+
+  static generateSecret(opts: { length?: number } = {}): string {
+    return TOTPHelper.encodeBase32(C.randomBytes(opts.length ?? TOTPDefaults.SECRET_LENGTH));
+  }
+
+  // --------------------------------------------------------
+  /**
+   * `otpauth://` URI for QR codes, see https://github.com/google/google-authenticator/wiki/Key-Uri-Format
+   */
+  getProvisioningUri(opts: { secret: string; accountName: string; issuer?: string }): string {
+    const { secret, accountName, issuer = this.issuer } = opts;
+
+    const label = issuer
+      ? `${encodeURIComponent(issuer)}:${encodeURIComponent(accountName)}`
+      : encodeURIComponent(accountName);
+
+    const params = new URLSearchParams({ secret, algorithm: this.algorithm });
+    params.set('digits', `${this.digits}`);
+    params.set('period', `${this.period}`);
+    if (issuer) {
+      params.set('issuer', issuer);
+    }
+
+    return `otpauth://totp/${label}?${params.toString()}`;
+  }
+
+  getStep(opts: { timestamp?: number } = {}): number {
+    const { timestamp = Date.now() } = opts;
+    return Math.floor(timestamp / 1000 / this.period);
+  }
+
+  /**
+   * RFC 4226 HOTP value of a step.
+   */
+  generateAt(opts: { secret: string; step: number }): string {
+    const { secret, step } = opts;
+
+    const counter = Buffer.alloc(8);
+    counter.writeBigUInt64BE(BigInt(step));
+
+    const digest = C.createHmac(this.algorithm.toLowerCase(), TOTPHelper.decodeBase32(secret))
+      .update(counter)
+      .digest();
+
+    // Dynamic truncation
+    const offset = digest[digest.length - 1] & 0x0f;
+    const code = (digest.readUInt32BE(offset) & 0x7fffffff) % 10 ** this.digits;
+
+    return `${code}`.padStart(this.digits, '0');
+  }
+
+  generate(opts: { secret: string; timestamp?: number }): string {
+    return this.generateAt({ secret: opts.secret, step: this.getStep(opts) });
+  }
+
+  // --------------------------------------------------------
+  verify(opts: {
+    secret: string;
+    token: string;
+    timestamp?: number;
+    window?: number;
+    // Last step accepted for this secret, codes at or before it are rejected
+    lastUsedStep?: number | null;
+  }): ITOTPVerifyResult {
+    const { secret, timestamp, window = this.window, lastUsedStep } = opts;
+    const token = `${opts.token ?? ''}`.replace(/\s/g, '');
+
+    if (token.length !== this.digits || !/^\d+$/.test(token)) {
+      return { isValid: false };
+    }
+
+    const current = this.getStep({ timestamp });
+    const expected = Buffer.from(token);
+
+    // Check every step of the window so timing does not reveal which one matched
+    let matched: number | undefined;
+    for (let delta = -window; delta <= window; delta++) {
+      const step = current + delta;
+      const actual = Buffer.from(this.generateAt({ secret, step }));
+
+      if (C.timingSafeEqual(expected, actual) && matched === undefined) {
+        matched = step;
+      }
+    }
+
+    if (matched === undefined) {
+      return { isValid: false };
+    }
+
+    if (lastUsedStep !== undefined && lastUsedStep !== null && matched <= lastUsedStep) {
+      this.logger.for(this.verify.name).warn('Rejected reused TOTP code | step: %d', matched);
+      return { isValid: false };
+    }
+
+    return { isValid: true, delta: matched - current, step: matched };
+  }
+
+  // --------------------------------------------------------
+  static hashRecoveryCode(code: string): string {
+    const normalized = code.replace(/[\s-]/g, '').toUpperCase();
+    return C.createHash('sha256').update(normalized).digest('hex');
+  }
+
+  /**
+   * Single use backup codes, e.g. `K7QM4-9XW2P`. Persist the hashes only.
+   */
+  static generateRecoveryCodes(opts: { count?: number; length?: number } = {}): IRecoveryCodes {
+    const {
+      count = TOTPDefaults.RECOVERY_CODE_COUNT,
+      length = TOTPDefaults.RECOVERY_CODE_LENGTH,
+    } = opts;
+
+    const codes: Array<string> = [];
+    for (let i = 0; i < count; i++) {
+      let code = '';
+      for (let j = 0; j < length; j++) {
+        code += RECOVERY_CODE_ALPHABET[C.randomInt(RECOVERY_CODE_ALPHABET.length)];
+      }
+
+      const half = Math.ceil(length / 2);
+      codes.push(`${code.slice(0, half)}-${code.slice(half)}`);
+    }
+
+    return { codes, hashes: codes.map(code => TOTPHelper.hashRecoveryCode(code)) };
+  }
+
+  /**
+   * Index of the matched hash, or -1. Remove the matched hash afterwards, codes are single use.
+   */
+  static verifyRecoveryCode(opts: { code: string; hashes: Array<string> }): number {
+    const actual = Buffer.from(TOTPHelper.hashRecoveryCode(opts.code), 'hex');
+
+    let matched = -1;
+    opts.hashes.forEach((hash, index) => {
+      const expected = Buffer.from(hash, 'hex');
+      if (expected.length === actual.length && C.timingSafeEqual(expected, actual)) {
+        matched = matched < 0 ? index : matched;
+      }
+    });
+
+    return matched;
+  }
+}
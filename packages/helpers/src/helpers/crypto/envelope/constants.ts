@@ -0,0 +1,17 @@
+// --------------------------------------------------------
+export class EnvelopeCryptoDefaults {
+  // Prefix of the serialized envelope, bumped when the layout changes
+  static readonly FORMAT = 'ev1';
+  static readonly ALGORITHM = 'aes-256-gcm';
+  static readonly KEY_LENGTH = 32;
+  static readonly IV_LENGTH = 12;
+  static readonly AUTH_TAG_LENGTH = 16;
+}
+
+// --------------------------------------------------------
+export class EnvelopeCryptoErrorCodes {
+  static readonly INVALID_KEY = 'ENVELOPE_INVALID_KEY';
+  static readonly UNKNOWN_KEY_VERSION = 'ENVELOPE_UNKNOWN_KEY_VERSION';
+  static readonly MALFORMED = 'ENVELOPE_MALFORMED';
+  static readonly DECRYPTION_FAILED = 'ENVELOPE_DECRYPTION_FAILED';
+}
@@ -0,0 +1,251 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import C from 'node:crypto';
+import { EnvelopeCryptoDefaults, EnvelopeCryptoErrorCodes } from './constants';
+import { IEnvelopeCryptoOptions, IParsedEnvelope, TEnvelopeKey } from './types';
+
+const KEY_VERSION_PATTERN = /^[A-Za-z0-9_-]+$/;
+
+// --------------------------------------------------------
+/**
+ * Envelope encryption with AES-256-GCM.
+ *
+ * Every value is encrypted with a fresh data key, which is then wrapped by the current
+ * key encryption key. The serialized envelope carries the key version, so keys can be
+ * rotated by adding a new version while older values keep decrypting:
+ *
+ * `ev1.<keyVersion>.<wrapped data key>.<iv | tag | ciphertext>`
+ *
+ * Pass `aad` to bind a value to its context (e.g. `users:42:email`), an envelope copied
+ * to another row then fails to decrypt.
+ *
+ * @example
+ * ```typescript
+ * const envelope = new EnvelopeCryptoHelper({
+ *   keys: { '2024': env.KEK_2024, '2025': env.KEK_2025 },
+ *   currentKeyVersion: '2025',
+ * });
+ *
+ * const email = envelope.encrypt({ plaintext: user.email, aad: `users:${user.id}:email` });
+ * const decrypted = envelope.decrypt({ ciphertext: email, aad: `users:${user.id}:email` });
+ * ```
+ */
+export class EnvelopeCryptoHelper extends BaseHelper {
+  private keys = new Map<string, Buffer>();
+  private currentKeyVersion: string;
+
+  constructor(opts: IEnvelopeCryptoOptions) {
+    super({
+      scope: opts.scope ?? EnvelopeCryptoHelper.name,
+      identifier: opts.identifier ?? EnvelopeCryptoHelper.name,
+    });
+
+    for (const [version, key] of Object.entries(opts.keys ?? {})) {
+      this.keys.set(version, EnvelopeCryptoHelper.toKey({ version, key }));
+    }
+
+    if (!this.keys.has(opts.currentKeyVersion)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: EnvelopeCryptoErrorCodes.UNKNOWN_KEY_VERSION,
+        message: `[EnvelopeCryptoHelper] No key for current version | version: ${opts.currentKeyVersion}`,
+      });
+    }
+
+    this.currentKeyVersion = opts.currentKeyVersion;
+  }
+
+  // --------------------------------------------------------
+  static generateKey(): string {
+    return C.randomBytes(EnvelopeCryptoDefaults.KEY_LENGTH).toString('base64');
+  }
+
+  private static toKey(opts: { version: string; key: TEnvelopeKey }): Buffer {
+    const { version, key } = opts;
+
+    if (!KEY_VERSION_PATTERN.test(version)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: EnvelopeCryptoErrorCodes.INVALID_KEY,
+        message: `[toKey] Key version must match ${KEY_VERSION_PATTERN} | version: ${version}`,
+      });
+    }
+
+    const buffer = Buffer.isBuffer(key) ? key : Buffer.from(key, 'base64');
+    if (buffer.length !== EnvelopeCryptoDefaults.KEY_LENGTH) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: EnvelopeCryptoErrorCodes.INVALID_KEY,
+        message: `[toKey] Key must be ${EnvelopeCryptoDefaults.KEY_LENGTH} bytes | version: ${version} | length: ${buffer.length}`,
+      });
+    }
+
+    return buffer;
+  }
+
+  private static seal(opts: { key: Buffer; plaintext: Buffer; aad?: Buffer }): Buffer {
+    const { key, plaintext, aad } = opts;
+
+    const iv = C.randomBytes(EnvelopeCryptoDefaults.IV_LENGTH);
+    const cipher = C.createCipheriv(EnvelopeCryptoDefaults.ALGORITHM, key, iv, {
+      authTagLength: EnvelopeCryptoDefaults.AUTH_TAG_LENGTH,
+    });
+    if (aad) {
+      cipher.setAAD(aad);
+    }
+
+    const encrypted = Buffer.concat([cipher.update(plaintext), cipher.final()]);
+    return Buffer.concat([iv, cipher.getAuthTag(), encrypted]);
+  }
+
+  private static open(opts: { key: Buffer; sealed: Buffer; aad?: Buffer }): Buffer {
+    const { key, sealed, aad } = opts;
+    const { IV_LENGTH, AUTH_TAG_LENGTH } = EnvelopeCryptoDefaults;
+
+    const iv = sealed.subarray(0, IV_LENGTH);
+    const authTag = sealed.subarray(IV_LENGTH, IV_LENGTH + AUTH_TAG_LENGTH);
+    const decipher = C.createDecipheriv(EnvelopeCryptoDefaults.ALGORITHM, key, iv, {
+      authTagLength: AUTH_TAG_LENGTH,
+    });
+    decipher.setAuthTag(authTag);
+    if (aad) {
+      decipher.setAAD(aad);
+    }
+
+    const encrypted = sealed.subarray(IV_LENGTH + AUTH_TAG_LENGTH);
+    return Buffer.concat([decipher.update(encrypted), decipher.final()]);
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Read the format and key version of an envelope without decrypting it.
+   */
+  static parse(ciphertext: string): IParsedEnvelope | null {
+    const segments = `${ciphertext ?? ''}`.split('.');
+    if (segments.length !== 4 || segments[0] !== EnvelopeCryptoDefaults.FORMAT) {
+      return null;
+    }
+
+    const [format, keyVersion, wrappedKey, payload] = segments;
+    const minLength = EnvelopeCryptoDefaults.IV_LENGTH + EnvelopeCryptoDefaults.AUTH_TAG_LENGTH;
+
+    const rs: IParsedEnvelope = {
+      format,
+      keyVersion,
+      wrappedKey: Buffer.from(wrappedKey, 'base64url'),
+      payload: Buffer.from(payload, 'base64url'),
+    };
+    if (rs.wrappedKey.length <= minLength || rs.payload.length < minLength) {
+      return null;
+    }
+
+    return rs;
+  }
+
+  static isEnvelope(value: unknown): value is string {
+    return typeof value === 'string' && EnvelopeCryptoHelper.parse(value) !== null;
+  }
+
+  getCurrentKeyVersion() {
+    return this.currentKeyVersion;
+  }
+
+  // --------------------------------------------------------
+  encrypt(opts: { plaintext: string | Buffer; aad?: string | Buffer }): string {
+    const { plaintext, aad } = opts;
+    const keyVersion = this.currentKeyVersion;
+    const header = `${EnvelopeCryptoDefaults.FORMAT}.${keyVersion}`;
+
+    const dataKey = C.randomBytes(EnvelopeCryptoDefaults.KEY_LENGTH);
+    try {
+      const wrappedKey = EnvelopeCryptoHelper.seal({
+        key: this.keys.get(keyVersion)!,
+        plaintext: dataKey,
+        aad: Buffer.from(header),
+      });
+      const payload = EnvelopeCryptoHelper.seal({
+        key: dataKey,
+        plaintext: Buffer.isBuffer(plaintext) ? plaintext : Buffer.from(plaintext, 'utf-8'),
+        aad: aad === undefined ? undefined : Buffer.from(aad),
+      });
+
+      return [header, wrappedKey.toString('base64url'), payload.toString('base64url')].join('.');
+    } finally {
+      dataKey.fill(0);
+    }
+  }
+
+  decryptBuffer(opts: { ciphertext: string; aad?: string | Buffer }): Buffer {
+    const { ciphertext, aad } = opts;
+
+    const envelope = EnvelopeCryptoHelper.parse(ciphertext);
+    if (!envelope) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: EnvelopeCryptoErrorCodes.MALFORMED,
+        message: '[decryptBuffer] Value is not a valid envelope!',
+      });
+    }
+
+    const { format, keyVersion, wrappedKey, payload } = envelope;
+    const key = this.keys.get(keyVersion);
+    if (!key) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: EnvelopeCryptoErrorCodes.UNKNOWN_KEY_VERSION,
+        message: `[decryptBuffer] No key for envelope version | version: ${keyVersion}`,
+      });
+    }
+
+    let dataKey: Buffer | undefined;
+    try {
+      dataKey = EnvelopeCryptoHelper.open({
+        key,
+        sealed: wrappedKey,
+        aad: Buffer.from(`${format}.${keyVersion}`),
+      });
+
+      return EnvelopeCryptoHelper.open({
+        key: dataKey,
+        sealed: payload,
+        aad: aad === undefined ? undefined : Buffer.from(aad),
+      });
+    } catch {
+      // Do not leak which part failed, a wrong key, AAD or a tampered value all look alike
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: EnvelopeCryptoErrorCodes.DECRYPTION_FAILED,
+        message: `[decryptBuffer] Failed to decrypt envelope | version: ${keyVersion}`,
+      });
+    } finally {
+      dataKey?.fill(0);
+    }
+  }
+
+  decrypt(opts: { ciphertext: string; aad?: string | Buffer }): string {
+    return this.decryptBuffer(opts).toString('utf-8');
+  }
+
+  // --------------------------------------------------------
+  needsReencrypt(opts: { ciphertext: string }): boolean {
+    return EnvelopeCryptoHelper.parse(opts.ciphertext)?.keyVersion !== this.currentKeyVersion;
+  }
+
+  /**
+   * Move an envelope to the current key version, e.g. from a background rotation job.
+   * Envelopes already on the current version are returned as is.
+   */
+  reencrypt(opts: { ciphertext: string; aad?: string | Buffer }): string {
+    if (!this.needsReencrypt(opts)) {
+      return opts.ciphertext;
+    }
+
+    const plaintext = this.decryptBuffer(opts);
+    try {
+      return this.encrypt({ plaintext, aad: opts.aad });
+    } finally {
+      plaintext.fill(0);
+    }
+  }
+}
@@ -0,0 +1,22 @@
+// 32 bytes key, strings are decoded as base64
+export type TEnvelopeKey = string | Buffer;
+
+export interface IEnvelopeCryptoOptions {
+  scope?: string;
+  identifier?: string;
+
+  // Key encryption keys by version, e.g. `{ '2024': env.KEK_2024, '2025': env.KEK_2025 }`
+  keys: Record<string, TEnvelopeKey>;
+  // Version used for new envelopes, older versions stay available for decryption
+  currentKeyVersion: string;
+}
+
+export interface IEnvelopeInfo {
+  format: string;
+  keyVersion: string;
+}
+
+export interface IParsedEnvelope extends IEnvelopeInfo {
+  wrappedKey: Buffer;
+  payload: Buffer;
+}
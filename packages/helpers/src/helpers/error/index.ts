@@ -1,2 +1,3 @@
 export * from './app-error';
 export * from './types';
+export * from './validation-error';
@@ -19,3 +19,39 @@ export const ErrorSchema = z
   });
 
 export type TError = z.infer<typeof ErrorSchema>;
+
+// --------------------------------------------------------
+/**
+ * Body returned by the application error handler for every failed request.
+ */
+export const ErrorResponseSchema = z
+  .object({
+    message: z.string(),
+    statusCode: z.number(),
+    messageCode: z.string().optional(),
+    requestId: z.string().optional(),
+    details: z
+      .object({
+        url: z.string().optional(),
+        path: z.string().optional(),
+        // Validated part of the request, only set for validation errors
+        target: z.string().optional(),
+        // Omitted in production
+        stack: z.string().optional(),
+        // Field errors for validation errors, the original cause otherwise
+        cause: z.any().optional(),
+      })
+      .optional(),
+  })
+  .openapi('ErrorResponse', {
+    description: 'Error Response Schema',
+    example: {
+      message: 'User not found',
+      statusCode: 404,
+      messageCode: 'USER_NOT_FOUND',
+      requestId: '4651e634-a530-4484-9b09-9616a28f35e3',
+      details: { url: 'http://localhost:3000/api/users/1', path: '/api/users/1' },
+    },
+  });
+
+export type TErrorResponse = z.infer<typeof ErrorResponseSchema>;
@@ -0,0 +1,79 @@
+import { HTTP } from '@/common/constants';
+import { z } from '@hono/zod-openapi';
+import { ApplicationError } from './app-error';
+import { TError } from './types';
+
+export interface IValidationFieldError {
+  // Dot separated, e.g. `items.0.quantity`, `root` for the value itself
+  path: string;
+  message: string;
+  code: string;
+  expected?: unknown;
+  received?: unknown;
+}
+
+export class ValidationErrorCodes {
+  static readonly VALIDATION_ERROR = 'VALIDATION_ERROR';
+}
+
+// --------------------------------------------------------
+/**
+ * `ApplicationError` carrying field level validation failures, returned as 422 by default.
+ *
+ * @example
+ * ```typescript
+ * const rs = schema.safeParse(payload);
+ * if (!rs.success) {
+ *   throw ValidationError.fromZodError({ error: rs.error, target: 'json' });
+ * }
+ *
+ * throw new ValidationError({
+ *   fields: [{ path: 'email', code: 'already_taken', message: 'Email is already taken' }],
+ * });
+ * ```
+ */
+export class ValidationError extends ApplicationError {
+  fields: Array<IValidationFieldError>;
+  // Validated part of the request, e.g. `json`, `query`, `param`
+  target?: string;
+
+  constructor(
+    opts: Partial<TError> & { fields: Array<IValidationFieldError>; target?: string },
+  ) {
+    const {
+      fields,
+      target,
+      message = 'ValidationError',
+      messageCode = ValidationErrorCodes.VALIDATION_ERROR,
+      statusCode = HTTP.ResultCodes.RS_4.UnprocessableEntity,
+    } = opts;
+    super({ message, messageCode, statusCode });
+
+    this.name = 'ValidationError';
+    this.fields = fields;
+    this.target = target;
+  }
+
+  static isValidationError(error: unknown): error is ValidationError {
+    return error instanceof ValidationError;
+  }
+
+  static fromZodError(opts: { error: z.ZodError; target?: string }): ValidationError {
+    const { error, target } = opts;
+
+    return new ValidationError({
+      target,
+      fields: error.issues.map(issue => {
+        const { expected, received } = issue as { expected?: unknown; received?: unknown };
+
+        return {
+          path: issue.path.map(segment => String(segment)).join('.') || 'root',
+          message: issue.message,
+          code: issue.code,
+          expected,
+          received,
+        };
+      }),
+    });
+  }
+}
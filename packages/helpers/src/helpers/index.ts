@@ -1,14 +1,27 @@
 export * from './base';
 
+export * from './audit';
+export * from './auth';
 export * from './crypto';
 export * from './env';
 export * from './error';
+export * from './lifecycle';
+export * from './lock';
 export * from './logger';
+export * from './metrics';
+export * from './money';
 export * from './network';
+export * from './notification';
 export * from './queue';
+export * from './rate-limit';
 export * from './redis';
+export * from './request-context';
 export * from './socket';
 export * from './storage';
+export * from './tenant';
 export * from './testing';
+export * from './transfer';
 export * from './uid';
+export * from './validation';
+export * from './webhook';
 export * from './worker-thread';
@@ -0,0 +1,18 @@
+// --------------------------------------------------------
+export class JobRunStatuses {
+  static readonly SUCCEEDED = '000_SUCCEEDED';
+  static readonly FAILED = '100_FAILED';
+  static readonly TIMED_OUT = '200_TIMED_OUT';
+  static readonly SKIPPED = '300_SKIPPED';
+
+  static readonly SCHEME_SET = new Set([
+    this.SUCCEEDED,
+    this.FAILED,
+    this.TIMED_OUT,
+    this.SKIPPED,
+  ]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
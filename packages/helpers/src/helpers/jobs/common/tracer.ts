@@ -0,0 +1,38 @@
+import { AnyObject } from '@/common/types';
+import { Logger } from '@/helpers/logger';
+import { IJobSpan, IJobTracer, TJobRunStatus } from './types';
+
+// --------------------------------------------------------
+export class LoggerJobTracer implements IJobTracer {
+  private logger: Logger;
+
+  constructor(opts: { logger: Logger }) {
+    this.logger = opts.logger;
+  }
+
+  startSpan(opts: { name: string; attributes?: AnyObject }): IJobSpan {
+    const { name } = opts;
+    const attributes: AnyObject = { ...opts.attributes };
+    const startedAt = performance.now();
+
+    this.logger.for(name).debug('Span STARTED | attributes: %j', attributes);
+
+    return {
+      setAttributes: (extra: AnyObject) => {
+        Object.assign(attributes, extra);
+      },
+      end: (endOpts: { status: TJobRunStatus; error?: unknown }) => {
+        const duration = (performance.now() - startedAt).toFixed(2);
+        this.logger
+          .for(name)
+          .info(
+            'Span ENDED | status: %s | duration: %sms | attributes: %j | error: %s',
+            endOpts.status,
+            duration,
+            attributes,
+            endOpts.error ?? '',
+          );
+      },
+    };
+  }
+}
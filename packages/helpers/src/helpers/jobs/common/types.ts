@@ -0,0 +1,18 @@
+import { AnyObject, TConstValue } from '@/common/types';
+import { JobRunStatuses } from './constants';
+
+export type TJobRunStatus = TConstValue<typeof JobRunStatuses>;
+
+// --------------------------------------------------------
+export interface IJobSpan {
+  setAttributes?(attributes: AnyObject): void;
+  end(opts: { status: TJobRunStatus; error?: unknown }): void;
+}
+
+/**
+ * Minimal tracing contract used by the jobs subsystem, so that any tracer (logger based,
+ * OpenTelemetry, ...) can open one span per job run.
+ */
+export interface IJobTracer {
+  startSpan(opts: { name: string; attributes?: AnyObject }): IJobSpan;
+}
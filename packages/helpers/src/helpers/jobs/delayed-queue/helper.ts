@@ -0,0 +1,408 @@
+import { AnyObject } from '@/common/types';
+import { BaseHelper } from '@/helpers/base';
+import { DefaultRedisHelper } from '@/helpers/redis';
+import C from 'node:crypto';
+import { IJobTracer, JobRunStatuses, LoggerJobTracer } from '../common';
+
+// Move due jobs from the scheduled set into the processing set (score = visibility deadline)
+const CLAIM_SCRIPT = `
+local ids = redis.call("zrangebyscore", KEYS[1], "-inf", ARGV[1], "LIMIT", 0, ARGV[2])
+for _, id in ipairs(ids) do
+  redis.call("zrem", KEYS[1], id)
+  redis.call("zadd", KEYS[2], ARGV[3], id)
+end
+return ids
+`;
+
+// Put back jobs whose worker died before acknowledging them
+const RECOVER_SCRIPT = `
+local ids = redis.call("zrangebyscore", KEYS[2], "-inf", ARGV[1])
+for _, id in ipairs(ids) do
+  redis.call("zrem", KEYS[2], id)
+  redis.call("zadd", KEYS[1], ARGV[1], id)
+end
+return #ids
+`;
+
+export interface IDelayedJob<TPayload extends AnyObject = AnyObject> {
+  id: string;
+  name: string;
+  payload: TPayload;
+  runAt: number;
+  attempts: number;
+  maxAttempts: number;
+  createdAt: number;
+  lastError?: string;
+  failedAt?: number;
+}
+
+export interface IDelayedJobQueueOptions<TPayload extends AnyObject = AnyObject> {
+  identifier: string;
+  redis: DefaultRedisHelper;
+  prefix?: string;
+
+  handler: (job: IDelayedJob<TPayload>) => Promise<void> | void;
+
+  /** Poll interval in milliseconds (default: 1000) */
+  pollInterval?: number;
+  /** Maximum number of due jobs claimed per poll (default: 10) */
+  batchSize?: number;
+  /**
+   * Claimed jobs which are not acknowledged within this window run again (default: 5 minutes).
+   * The claim is extended while the handler runs, so only the jobs of a crashed worker expire.
+   */
+  visibilityTimeout?: number;
+
+  maxAttempts?: number;
+  retryDelay?: (opts: { attempt: number }) => number;
+
+  onDead?: (opts: { job: IDelayedJob<TPayload> }) => void | Promise<void>;
+  tracer?: IJobTracer;
+}
+
+// --------------------------------------------------------
+/**
+ * Redis backed delayed job queue.
+ *
+ * - `<prefix>:jobs` hash stores the serialized jobs
+ * - `<prefix>:scheduled` sorted set indexes job ids by their run time
+ * - `<prefix>:processing` sorted set holds claimed jobs until they are acknowledged
+ * - `<prefix>:dead` sorted set parks jobs which exhausted their attempts
+ *
+ * @example
+ * ```typescript
+ * const queue = new DelayedJobQueueHelper<{ userId: number }>({
+ *   identifier: 'reminders',
+ *   redis,
+ *   handler: async job => { await mailService.sendReminder(job.payload); },
+ * });
+ *
+ * queue.start();
+ * await queue.enqueueIn({ delay: 24 * 60 * 60 * 1000, name: 'remind', payload: { userId: 1 } });
+ * ```
+ */
+export class DelayedJobQueueHelper<TPayload extends AnyObject = AnyObject> extends BaseHelper {
+  private redis: DefaultRedisHelper;
+  private prefix: string;
+  private handler: IDelayedJobQueueOptions<TPayload>['handler'];
+  private onDead?: IDelayedJobQueueOptions<TPayload>['onDead'];
+  private tracer: IJobTracer;
+
+  private pollInterval: number;
+  private batchSize: number;
+  private visibilityTimeout: number;
+  private maxAttempts: number;
+  private retryDelay: (opts: { attempt: number }) => number;
+
+  private timer?: NodeJS.Timeout;
+  private polling?: Promise<number>;
+  private isStarted = false;
+  private inflight = new Set<Promise<void>>();
+
+  constructor(opts: IDelayedJobQueueOptions<TPayload>) {
+    super({
+      scope: `${DelayedJobQueueHelper.name}_${opts.identifier}`,
+      identifier: opts.identifier,
+    });
+
+    this.redis = opts.redis;
+    // Hash tag keeps every key of the queue in the same cluster slot for the lua scripts
+    this.prefix = opts.prefix ?? `{delayed-jobs:${opts.identifier}}`;
+    this.handler = opts.handler;
+    this.onDead = opts.onDead;
+    this.tracer = opts.tracer ?? new LoggerJobTracer({ logger: this.logger });
+
+    this.pollInterval = opts.pollInterval ?? 1_000;
+    this.batchSize = opts.batchSize ?? 10;
+    this.visibilityTimeout = opts.visibilityTimeout ?? 5 * 60 * 1_000;
+    this.maxAttempts = opts.maxAttempts ?? 5;
+    this.retryDelay =
+      opts.retryDelay ??
+      (({ attempt }) => Math.min(1_000 * Math.pow(2, attempt - 1), 60 * 60 * 1_000));
+  }
+
+  private getKey(name: 'jobs' | 'scheduled' | 'processing' | 'dead') {
+    return `${this.prefix}:${name}`;
+  }
+
+  // --------------------------------------------------------
+  async enqueueAt(opts: {
+    runAt: Date | number;
+    name: string;
+    payload: TPayload;
+    id?: string;
+    maxAttempts?: number;
+  }) {
+    const { name, payload, id = C.randomUUID(), maxAttempts = this.maxAttempts } = opts;
+    const runAt = typeof opts.runAt === 'number' ? opts.runAt : opts.runAt.getTime();
+
+    const job: IDelayedJob<TPayload> = {
+      id,
+      name,
+      payload,
+      runAt,
+      attempts: 0,
+      maxAttempts,
+      createdAt: Date.now(),
+    };
+
+    await this.redis
+      .getClient()
+      .multi()
+      .hset(this.getKey('jobs'), id, JSON.stringify(job))
+      .zadd(this.getKey('scheduled'), runAt, id)
+      .exec();
+
+    this.logger
+      .for(this.enqueueAt.name)
+      .debug('Job scheduled | id: %s | name: %s | runAt: %s', id, name, new Date(runAt));
+    return job;
+  }
+
+  enqueueIn(opts: {
+    delay: number;
+    name: string;
+    payload: TPayload;
+    id?: string;
+    maxAttempts?: number;
+  }) {
+    const { delay, ...rest } = opts;
+    return this.enqueueAt({ ...rest, runAt: Date.now() + Math.max(delay, 0) });
+  }
+
+  async cancel(opts: { id: string }) {
+    const rs = await this.redis
+      .getClient()
+      .multi()
+      .zrem(this.getKey('scheduled'), opts.id)
+      .hdel(this.getKey('jobs'), opts.id)
+      .exec();
+
+    return Number(rs?.[0]?.[1] ?? 0) > 0;
+  }
+
+  async getJob(opts: { id: string }): Promise<IDelayedJob<TPayload> | null> {
+    const raw = await this.redis.getClient().hget(this.getKey('jobs'), opts.id);
+    return raw ? JSON.parse(raw) : null;
+  }
+
+  // --------------------------------------------------------
+  async getDeadJobs(opts?: { limit?: number }) {
+    const { limit = 100 } = opts ?? {};
+    const client = this.redis.getClient();
+
+    const ids = await client.zrange(this.getKey('dead'), 0, limit - 1);
+    if (!ids.length) {
+      return [];
+    }
+
+    const raws = await client.hmget(this.getKey('jobs'), ...ids);
+    return raws
+      .filter((raw): raw is string => !!raw)
+      .map(raw => JSON.parse(raw) as IDelayedJob<TPayload>);
+  }
+
+  async requeueDeadJob(opts: { id: string; runAt?: Date }) {
+    const job = await this.getJob(opts);
+    if (!job) {
+      return false;
+    }
+
+    job.attempts = 0;
+    job.runAt = opts.runAt?.getTime() ?? Date.now();
+    job.failedAt = undefined;
+
+    await this.redis
+      .getClient()
+      .multi()
+      .zrem(this.getKey('dead'), job.id)
+      .hset(this.getKey('jobs'), job.id, JSON.stringify(job))
+      .zadd(this.getKey('scheduled'), job.runAt, job.id)
+      .exec();
+    return true;
+  }
+
+  async getStats() {
+    const client = this.redis.getClient();
+    const [scheduled, processing, dead] = await Promise.all([
+      client.zcard(this.getKey('scheduled')),
+      client.zcard(this.getKey('processing')),
+      client.zcard(this.getKey('dead')),
+    ]);
+
+    return { scheduled, processing, dead, inflight: this.inflight.size };
+  }
+
+  // --------------------------------------------------------
+  start() {
+    if (this.isStarted) {
+      return;
+    }
+
+    this.isStarted = true;
+    this.schedulePoll(0);
+    this.logger
+      .for(this.start.name)
+      .info(
+        'Delayed job queue STARTED | prefix: %s | pollInterval: %d',
+        this.prefix,
+        this.pollInterval,
+      );
+  }
+
+  async stop() {
+    this.isStarted = false;
+    clearTimeout(this.timer);
+
+    // A running poll may still claim jobs, which are awaited with the others
+    await this.polling?.catch(() => 0);
+    await Promise.allSettled([...this.inflight]);
+
+    this.logger.for(this.stop.name).info('Delayed job queue STOPPED | prefix: %s', this.prefix);
+  }
+
+  private schedulePoll(delay: number) {
+    if (!this.isStarted) {
+      return;
+    }
+
+    this.timer = setTimeout(() => {
+      this.poll()
+        .catch(error => {
+          this.logger.for('poll').error('Failed to poll delayed jobs | error: %s', error);
+        })
+        .finally(() => this.schedulePoll(this.pollInterval));
+    }, delay);
+  }
+
+  async poll() {
+    if (this.polling) {
+      return 0;
+    }
+
+    this.polling = this.claim();
+    try {
+      return await this.polling;
+    } finally {
+      this.polling = undefined;
+    }
+  }
+
+  private async claim() {
+    const client = this.redis.getClient();
+    const now = Date.now();
+
+    await client.eval(RECOVER_SCRIPT, 2, this.getKey('scheduled'), this.getKey('processing'), now);
+
+    const ids = (await client.eval(
+      CLAIM_SCRIPT,
+      2,
+      this.getKey('scheduled'),
+      this.getKey('processing'),
+      now,
+      this.batchSize,
+      now + this.visibilityTimeout,
+    )) as Array<string>;
+
+    for (const id of ids) {
+      const task = this.process(id).finally(() => this.inflight.delete(task));
+      this.inflight.add(task);
+    }
+
+    return ids.length;
+  }
+
+  // --------------------------------------------------------
+  private async process(id: string) {
+    const client = this.redis.getClient();
+    const job = await this.getJob({ id });
+
+    if (!job) {
+      await client.zrem(this.getKey('processing'), id);
+      return;
+    }
+
+    // Reclaimed after its worker crashed or stalled past the visibility timeout on the last attempt
+    if (job.attempts >= job.maxAttempts) {
+      job.lastError = 'Visibility timeout exceeded';
+      await this.park(job);
+      return;
+    }
+
+    // Saved before running, an attempt which never gets acknowledged is counted too
+    job.attempts++;
+    await client.hset(this.getKey('jobs'), id, JSON.stringify(job));
+
+    const span = this.tracer.startSpan({
+      name: `delayed-job.${job.name}`,
+      attributes: { 'job.id': job.id, 'job.name': job.name, 'job.attempt': job.attempts },
+    });
+
+    // Push the visibility deadline forward until the handler settles
+    const interval = Math.max(Math.floor(this.visibilityTimeout / 2), 1);
+    const heartbeat = setInterval(() => {
+      client
+        .zadd(this.getKey('processing'), 'XX', Date.now() + this.visibilityTimeout, id)
+        .catch(() => 0);
+    }, interval);
+
+    try {
+      await this.handler(job);
+      clearInterval(heartbeat);
+
+      await client.multi().zrem(this.getKey('processing'), id).hdel(this.getKey('jobs'), id).exec();
+      span.end({ status: JobRunStatuses.SUCCEEDED });
+    } catch (error) {
+      clearInterval(heartbeat);
+
+      job.lastError = (error as Error)?.message ?? `${error}`;
+      span.end({ status: JobRunStatuses.FAILED, error });
+
+      if (job.attempts >= job.maxAttempts) {
+        await this.park(job);
+        return;
+      }
+
+      job.runAt = Date.now() + this.retryDelay({ attempt: job.attempts });
+      await client
+        .multi()
+        .zrem(this.getKey('processing'), id)
+        .hset(this.getKey('jobs'), id, JSON.stringify(job))
+        .zadd(this.getKey('scheduled'), job.runAt, id)
+        .exec();
+
+      this.logger
+        .for(this.process.name)
+        .warn(
+          'Job RETRY scheduled | id: %s | name: %s | attempt: %d | runAt: %s | error: %s',
+          id,
+          job.name,
+          job.attempts,
+          new Date(job.runAt),
+          job.lastError,
+        );
+    }
+  }
+
+  private async park(job: IDelayedJob<TPayload>) {
+    job.failedAt = Date.now();
+    await this.redis
+      .getClient()
+      .multi()
+      .zrem(this.getKey('processing'), job.id)
+      .hset(this.getKey('jobs'), job.id, JSON.stringify(job))
+      .zadd(this.getKey('dead'), job.failedAt, job.id)
+      .exec();
+
+    this.logger
+      .for(this.park.name)
+      .error(
+        'Job moved to DEAD | id: %s | name: %s | attempts: %d | error: %s',
+        job.id,
+        job.name,
+        job.attempts,
+        job.lastError,
+      );
+    await Promise.resolve(this.onDead?.({ job })).catch(() => {});
+  }
+}
@@ -0,0 +1,4 @@
+export * from './common';
+export * from './delayed-queue';
+export * from './scheduler';
+export * from './worker-pool';
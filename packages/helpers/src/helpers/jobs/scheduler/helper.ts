@@ -0,0 +1,353 @@
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { ILockHandle, ILockHelper } from '@/helpers/lock';
+import { CronTime } from 'cron';
+import C from 'node:crypto';
+import { IJobTracer, JobRunStatuses, LoggerJobTracer, TJobRunStatus } from '../common';
+
+export interface IScheduledJobContext {
+  name: string;
+  runId: string;
+  scheduledAt: Date;
+  signal: AbortSignal;
+}
+
+export interface IScheduledJob {
+  name: string;
+  cronTime: string;
+  tz?: string;
+  handler: (context: IScheduledJobContext) => Promise<void> | void;
+
+  /** Abort the run and mark it as timed out after this many milliseconds */
+  timeout?: number;
+  /** Delay every run by a random amount in `[0, jitter]` milliseconds */
+  jitter?: number;
+  /** Skip a tick while the previous run of the same job is still in progress (default: true) */
+  preventOverlap?: boolean;
+  /** Prevent overlapping runs across instances by holding a distributed lock during the run */
+  lock?: { helper: ILockHelper; ttl?: number; key?: string };
+}
+
+export interface IJobRunResult {
+  name: string;
+  runId: string;
+  status: TJobRunStatus;
+  scheduledAt: Date;
+  startedAt?: Date;
+  finishedAt?: Date;
+  error?: unknown;
+}
+
+interface IJobState {
+  job: IScheduledJob;
+  cronTime: CronTime;
+  timer?: NodeJS.Timeout;
+  running?: Promise<IJobRunResult>;
+  lastRun?: IJobRunResult;
+  nextRunAt?: Date;
+}
+
+// --------------------------------------------------------
+/**
+ * Run async tasks on cron expressions.
+ *
+ * @example
+ * ```typescript
+ * const scheduler = new JobSchedulerHelper({ identifier: 'billing' });
+ * scheduler.register({
+ *   name: 'close-invoices',
+ *   cronTime: '0 *\/5 * * * *',
+ *   timeout: 60_000,
+ *   jitter: 5_000,
+ *   lock: { helper: new RedisLockHelper({ redis }) },
+ *   handler: async ({ signal }) => { await invoiceService.closeExpired({ signal }); },
+ * });
+ * scheduler.start();
+ * ```
+ */
+export class JobSchedulerHelper extends BaseHelper {
+  private jobs = new Map<string, IJobState>();
+  private tracer: IJobTracer;
+  private isStarted = false;
+
+  private onRunCompleted?: (opts: { result: IJobRunResult }) => void | Promise<void>;
+
+  constructor(opts: {
+    identifier: string;
+    tracer?: IJobTracer;
+    onRunCompleted?: (opts: { result: IJobRunResult }) => void | Promise<void>;
+  }) {
+    super({
+      scope: `${JobSchedulerHelper.name}_${opts.identifier}`,
+      identifier: opts.identifier,
+    });
+
+    this.tracer = opts.tracer ?? new LoggerJobTracer({ logger: this.logger });
+    this.onRunCompleted = opts.onRunCompleted;
+  }
+
+  // --------------------------------------------------------
+  register(job: IScheduledJob) {
+    if (this.jobs.has(job.name)) {
+      throw getError({
+        statusCode: 400,
+        message: `[register] Job already registered | name: ${job.name}`,
+      });
+    }
+
+    let cronTime: CronTime;
+    try {
+      cronTime = new CronTime(job.cronTime, job.tz);
+    } catch (error) {
+      throw getError({
+        statusCode: 400,
+        message: `[register] Invalid cron expression | name: ${job.name} | cronTime: ${job.cronTime} | error: ${(error as Error).message}`,
+      });
+    }
+
+    const state: IJobState = { job, cronTime };
+    this.jobs.set(job.name, state);
+
+    if (this.isStarted) {
+      this.schedule(state);
+    }
+
+    return this;
+  }
+
+  unregister(opts: { name: string }) {
+    const state = this.jobs.get(opts.name);
+    if (!state) {
+      return false;
+    }
+
+    clearTimeout(state.timer);
+    this.jobs.delete(opts.name);
+    return true;
+  }
+
+  // --------------------------------------------------------
+  start() {
+    if (this.isStarted) {
+      return;
+    }
+
+    this.isStarted = true;
+    for (const state of this.jobs.values()) {
+      this.schedule(state);
+    }
+
+    this.logger.for(this.start.name).info('Scheduler STARTED | jobs: %d', this.jobs.size);
+  }
+
+  async stop(opts?: { waitForRunning?: boolean }) {
+    const { waitForRunning = true } = opts ?? {};
+    this.isStarted = false;
+
+    const running: Array<Promise<IJobRunResult>> = [];
+    for (const state of this.jobs.values()) {
+      clearTimeout(state.timer);
+      state.timer = undefined;
+      state.nextRunAt = undefined;
+
+      if (state.running) {
+        running.push(state.running);
+      }
+    }
+
+    if (waitForRunning) {
+      await Promise.allSettled(running);
+    }
+
+    this.logger.for(this.stop.name).info('Scheduler STOPPED | waited runs: %d', running.length);
+  }
+
+  // --------------------------------------------------------
+  trigger(opts: { name: string }) {
+    const state = this.jobs.get(opts.name);
+    if (!state) {
+      throw getError({
+        statusCode: 404,
+        message: `[trigger] Job not found | name: ${opts.name}`,
+      });
+    }
+
+    return this.run(state, new Date());
+  }
+
+  getJobStatus(opts: { name: string }) {
+    const state = this.jobs.get(opts.name);
+    if (!state) {
+      return null;
+    }
+
+    return {
+      name: state.job.name,
+      cronTime: state.job.cronTime,
+      isRunning: !!state.running,
+      nextRunAt: state.nextRunAt,
+      lastRun: state.lastRun,
+    };
+  }
+
+  getJobNames() {
+    return [...this.jobs.keys()];
+  }
+
+  // --------------------------------------------------------
+  private schedule(state: IJobState) {
+    if (!this.isStarted || !this.jobs.has(state.job.name)) {
+      return;
+    }
+
+    const jitter = state.job.jitter ? Math.floor(Math.random() * state.job.jitter) : 0;
+    const scheduledAt = state.cronTime.sendAt().toJSDate();
+    const delay = Math.max(scheduledAt.getTime() - Date.now(), 0) + jitter;
+
+    state.nextRunAt = new Date(Date.now() + delay);
+    state.timer = setTimeout(() => {
+      state.timer = undefined;
+
+      // Schedule the next tick first, so a slow run never shifts the cron cadence
+      this.schedule(state);
+      this.run(state, scheduledAt).catch(error => {
+        this.logger
+          .for(this.schedule.name)
+          .error('Unexpected job error | name: %s | error: %s', state.job.name, error);
+      });
+    }, delay);
+  }
+
+  // --------------------------------------------------------
+  private async run(state: IJobState, scheduledAt: Date): Promise<IJobRunResult> {
+    const { job } = state;
+    const { preventOverlap = true } = job;
+    const runId = C.randomUUID();
+
+    if (preventOverlap && state.running) {
+      const result: IJobRunResult = {
+        name: job.name,
+        runId,
+        status: JobRunStatuses.SKIPPED,
+        scheduledAt,
+        error: 'Previous run is still in progress',
+      };
+
+      this.logger
+        .for(this.run.name)
+        .warn('Skip job run | name: %s | runId: %s | reason: overlap', job.name, runId);
+      await this.onRunCompleted?.({ result });
+      return result;
+    }
+
+    // Held until the handler settles, a timed out run keeps blocking the next ones meanwhile
+    const execution = this.execute(state, runId, scheduledAt);
+    const running = execution.then(async ({ result, settled }) => {
+      await settled;
+      return result;
+    });
+    state.running = running;
+
+    const onSettled = () => {
+      if (state.running === running) {
+        state.running = undefined;
+      }
+    };
+    running.then(onSettled, onSettled);
+
+    const { result } = await execution;
+    state.lastRun = result;
+    await this.onRunCompleted?.({ result });
+    return result;
+  }
+
+  private async execute(
+    state: IJobState,
+    runId: string,
+    scheduledAt: Date,
+  ): Promise<{ result: IJobRunResult; settled: Promise<unknown> }> {
+    const { job } = state;
+    const span = this.tracer.startSpan({
+      name: `job.${job.name}`,
+      attributes: { 'job.name': job.name, 'job.run_id': runId, 'job.cron': job.cronTime },
+    });
+
+    const result: IJobRunResult = {
+      name: job.name,
+      runId,
+      status: JobRunStatuses.SUCCEEDED,
+      scheduledAt,
+    };
+
+    let lockHandle: ILockHandle | null = null;
+    let heartbeat: NodeJS.Timeout | undefined;
+    if (job.lock) {
+      const { helper, ttl = job.timeout ?? 60_000, key = `job:${job.name}` } = job.lock;
+      lockHandle = await helper.acquire({ key, ttl });
+
+      if (!lockHandle) {
+        result.status = JobRunStatuses.SKIPPED;
+        result.error = 'Lock is held by another instance';
+        span.end({ status: result.status });
+        return { result, settled: Promise.resolve() };
+      }
+
+      // The ttl defaults to the timeout, keep the lock alive while a timed out handler still runs
+      const handle = lockHandle;
+      const interval = Math.max(Math.floor(ttl / 2), 1);
+      heartbeat = setInterval(() => {
+        helper.extend({ handle, ttl }).catch(() => false);
+      }, interval);
+    }
+
+    const abortController = new AbortController();
+    let timeoutId: NodeJS.Timeout | undefined;
+    let settled: Promise<unknown> = Promise.resolve();
+
+    result.startedAt = new Date();
+    try {
+      const task = Promise.resolve(
+        job.handler({ name: job.name, runId, scheduledAt, signal: abortController.signal }),
+      );
+      settled = task.catch(() => undefined);
+
+      if (!job.timeout) {
+        await task;
+      } else {
+        const timeout = new Promise<'timeout'>(resolve => {
+          timeoutId = setTimeout(() => resolve('timeout'), job.timeout);
+        });
+
+        const rs = await Promise.race([task, timeout]);
+        if (rs === 'timeout') {
+          abortController.abort();
+          result.status = JobRunStatuses.TIMED_OUT;
+          result.error = `Job exceeded timeout of ${job.timeout}ms`;
+        }
+      }
+    } catch (error) {
+      result.status = JobRunStatuses.FAILED;
+      result.error = error;
+      this.logger
+        .for(this.execute.name)
+        .error('Job run FAILED | name: %s | runId: %s | error: %s', job.name, runId, error);
+    } finally {
+      clearTimeout(timeoutId);
+      result.finishedAt = new Date();
+    }
+
+    // Released once the handler settles, not when the timeout fires
+    const lock = job.lock;
+    const handle = lockHandle;
+    settled = settled.then(async () => {
+      clearInterval(heartbeat);
+
+      if (handle && lock) {
+        await lock.helper.release({ handle }).catch(() => false);
+      }
+    });
+
+    span.end({ status: result.status, error: result.error });
+    return { result, settled };
+  }
+}
@@ -0,0 +1,90 @@
+// --------------------------------------------------------
+/**
+ * FIFO queue with a fixed capacity.
+ *
+ * `push` waits while the queue is full and `pop` waits while it is empty, which gives producers
+ * natural backpressure. `pop` resolves like an iterator does, `{ done: false, value }` for every
+ * element, so `undefined` and `null` are valid elements, then `{ done: true }` once the queue is
+ * closed and the remaining elements are consumed.
+ */
+export class BoundedAsyncQueue<T> {
+  private elements: Array<T> = [];
+  private capacity: number;
+  private isClosed = false;
+
+  private producers: Array<() => void> = [];
+  private consumers: Array<(rs: IteratorResult<T, undefined>) => void> = [];
+
+  constructor(opts: { capacity: number }) {
+    this.capacity = Math.max(opts.capacity, 1);
+  }
+
+  get size() {
+    return this.elements.length;
+  }
+
+  get closed() {
+    return this.isClosed;
+  }
+
+  tryPush(element: T) {
+    if (this.isClosed) {
+      return false;
+    }
+
+    const consumer = this.consumers.shift();
+    if (consumer) {
+      consumer({ done: false, value: element });
+      return true;
+    }
+
+    if (this.elements.length >= this.capacity) {
+      return false;
+    }
+
+    this.elements.push(element);
+    return true;
+  }
+
+  async push(element: T) {
+    while (!this.tryPush(element)) {
+      if (this.isClosed) {
+        return false;
+      }
+
+      await new Promise<void>(resolve => this.producers.push(resolve));
+    }
+
+    return true;
+  }
+
+  pop(): Promise<IteratorResult<T, undefined>> {
+    if (this.elements.length) {
+      const element = this.elements.shift() as T;
+      this.producers.shift()?.();
+      return Promise.resolve({ done: false, value: element });
+    }
+
+    if (this.isClosed) {
+      return Promise.resolve({ done: true, value: undefined });
+    }
+
+    return new Promise(resolve => this.consumers.push(resolve));
+  }
+
+  close() {
+    this.isClosed = true;
+
+    for (const consumer of this.consumers.splice(0)) {
+      consumer({ done: true, value: undefined });
+    }
+
+    for (const producer of this.producers.splice(0)) {
+      producer();
+    }
+  }
+
+  drain() {
+    return this.elements.splice(0);
+  }
+}
@@ -0,0 +1,252 @@
+import { ValueOrPromise } from '@/common/types';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { IShutdownHook, ShutdownPhases, TShutdownPhase } from '@/helpers/lifecycle';
+import { IJobTracer, JobRunStatuses, LoggerJobTracer } from '../common';
+import { BoundedAsyncQueue } from './bounded-queue';
+
+export interface IJobWorkerContext {
+  workerId: number;
+  signal: AbortSignal;
+}
+
+export interface IJobWorkerPoolOptions<TJob> {
+  identifier: string;
+  /** Number of concurrent workers (default: 4) */
+  concurrency?: number;
+  /** Maximum number of queued jobs before `submit` starts waiting (default: 1000) */
+  capacity?: number;
+  handler: (job: TJob, context: IJobWorkerContext) => Promise<void> | void;
+  onError?: (opts: { job: TJob; error: unknown; workerId: number }) => void | Promise<void>;
+  tracer?: IJobTracer;
+}
+
+export interface IJobWorkerPoolStats {
+  queued: number;
+  active: number;
+  processed: number;
+  failed: number;
+  isAccepting: boolean;
+}
+
+// --------------------------------------------------------
+/**
+ * Fixed size pool of async workers pulling jobs from a bounded in-memory queue.
+ *
+ * The pool does not listen to process signals, register {@link getShutdownHook} with the
+ * application (or a `ShutdownOrchestrator`) to drain it on shutdown.
+ *
+ * @example
+ * ```typescript
+ * const pool = new JobWorkerPoolHelper<IImportRow>({
+ *   identifier: 'product-import',
+ *   concurrency: 8,
+ *   capacity: 500,
+ *   handler: async row => { await productService.upsert(row); },
+ * });
+ *
+ * pool.start();
+ * application.registerShutdownHook(pool.getShutdownHook({ timeout: 10_000 }));
+ *
+ * for await (const row of parser) {
+ *   await pool.submit(row); // waits while the queue is full
+ * }
+ *
+ * await pool.shutdown();
+ * ```
+ */
+export class JobWorkerPoolHelper<TJob> extends BaseHelper {
+  private queue: BoundedAsyncQueue<TJob>;
+  private concurrency: number;
+  private handler: IJobWorkerPoolOptions<TJob>['handler'];
+  private onError?: IJobWorkerPoolOptions<TJob>['onError'];
+  private tracer: IJobTracer;
+
+  private workers: Array<Promise<void>> = [];
+  private abortController = new AbortController();
+  private shutdownPromise?: Promise<Array<TJob>>;
+
+  private active = 0;
+  private processed = 0;
+  private failed = 0;
+
+  constructor(opts: IJobWorkerPoolOptions<TJob>) {
+    super({
+      scope: `${JobWorkerPoolHelper.name}_${opts.identifier}`,
+      identifier: opts.identifier,
+    });
+
+    this.concurrency = Math.max(opts.concurrency ?? 4, 1);
+    this.queue = new BoundedAsyncQueue<TJob>({ capacity: opts.capacity ?? 1_000 });
+    this.handler = opts.handler;
+    this.onError = opts.onError;
+    this.tracer = opts.tracer ?? new LoggerJobTracer({ logger: this.logger });
+  }
+
+  // --------------------------------------------------------
+  start() {
+    if (this.workers.length) {
+      return;
+    }
+
+    for (let workerId = 0; workerId < this.concurrency; workerId++) {
+      this.workers.push(this.work(workerId));
+    }
+
+    this.logger
+      .for(this.start.name)
+      .info(
+        'Worker pool STARTED | identifier: %s | concurrency: %d',
+        this.identifier,
+        this.concurrency,
+      );
+  }
+
+  // --------------------------------------------------------
+  async submit(job: TJob) {
+    if (this.queue.closed) {
+      throw getError({
+        statusCode: 503,
+        message: `[submit] Worker pool is shutting down | identifier: ${this.identifier}`,
+      });
+    }
+
+    const isAccepted = await this.queue.push(job);
+    if (!isAccepted) {
+      throw getError({
+        statusCode: 503,
+        message: `[submit] Worker pool is shutting down | identifier: ${this.identifier}`,
+      });
+    }
+  }
+
+  trySubmit(job: TJob) {
+    return this.queue.tryPush(job);
+  }
+
+  getStats(): IJobWorkerPoolStats {
+    return {
+      queued: this.queue.size,
+      active: this.active,
+      processed: this.processed,
+      failed: this.failed,
+      isAccepting: !this.queue.closed,
+    };
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Stop accepting new jobs and wait for queued and in-flight jobs to finish.
+   *
+   * When `timeout` elapses first, workers are signalled through their `AbortSignal` and the
+   * remaining queued jobs are returned so that callers can persist them. A pool which was never
+   * started returns all of its queued jobs.
+   */
+  shutdown(opts?: { timeout?: number }): Promise<Array<TJob>> {
+    if (this.shutdownPromise) {
+      return this.shutdownPromise;
+    }
+
+    const logger = this.logger.for(this.shutdown.name);
+    logger.info('Worker pool SHUTTING DOWN | stats: %j', this.getStats());
+
+    this.queue.close();
+
+    // No worker would ever run them
+    if (!this.workers.length) {
+      const dropped = this.queue.drain();
+      logger.warn('Worker pool was never STARTED | dropped: %d', dropped.length);
+
+      this.shutdownPromise = Promise.resolve(dropped);
+      return this.shutdownPromise;
+    }
+
+    const drained = Promise.allSettled(this.workers).then(() => undefined);
+
+    const timeout = opts?.timeout;
+    if (!timeout) {
+      this.shutdownPromise = drained.then(() => {
+        logger.info('Worker pool STOPPED | stats: %j', this.getStats());
+        return [];
+      });
+      return this.shutdownPromise;
+    }
+
+    this.shutdownPromise = new Promise<Array<TJob>>(resolve => {
+      const timer = setTimeout(() => {
+        const dropped = this.queue.drain();
+        this.abortController.abort();
+        logger.warn(
+          'Worker pool shutdown TIMED OUT | timeout: %dms | dropped: %d | active: %d',
+          timeout,
+          dropped.length,
+          this.active,
+        );
+        resolve(dropped);
+      }, timeout);
+
+      drained.then(() => {
+        clearTimeout(timer);
+        logger.info('Worker pool STOPPED | stats: %j', this.getStats());
+        resolve([]);
+      });
+    });
+
+    return this.shutdownPromise;
+  }
+
+  /**
+   * Hook draining the pool in the `consumers` phase of the shutdown, the jobs dropped by the
+   * `timeout` are handed to `onDropped`.
+   */
+  getShutdownHook(opts?: {
+    timeout?: number;
+    phase?: TShutdownPhase;
+    onDropped?: (opts: { jobs: Array<TJob> }) => ValueOrPromise<void>;
+  }): IShutdownHook {
+    const { timeout, phase = ShutdownPhases.CONSUMERS, onDropped } = opts ?? {};
+
+    return {
+      identifier: `${JobWorkerPoolHelper.name}_${this.identifier}`,
+      phase,
+      hook: async () => {
+        const jobs = await this.shutdown({ timeout });
+        if (jobs.length) {
+          await onDropped?.({ jobs });
+        }
+      },
+    };
+  }
+
+  // --------------------------------------------------------
+  private async work(workerId: number) {
+    while (true) {
+      const { done, value: job } = await this.queue.pop();
+      if (done) {
+        return;
+      }
+
+      this.active++;
+      const span = this.tracer.startSpan({
+        name: `worker.${this.identifier}`,
+        attributes: { 'worker.id': workerId },
+      });
+
+      try {
+        await this.handler(job, { workerId, signal: this.abortController.signal });
+        this.processed++;
+        span.end({ status: JobRunStatuses.SUCCEEDED });
+      } catch (error) {
+        this.failed++;
+        span.end({ status: JobRunStatuses.FAILED, error });
+
+        this.logger
+          .for(this.work.name)
+          .error('Job FAILED | workerId: %d | error: %s', workerId, error);
+        await Promise.resolve(this.onError?.({ job, error, workerId })).catch(() => {});
+      } finally {
+        this.active--;
+      }
+    }
+  }
+}
@@ -0,0 +1,2 @@
+export * from './bounded-queue';
+export * from './helper';
@@ -0,0 +1,31 @@
+import { TConstValue } from '@/common/types';
+
+// --------------------------------------------------------
+/**
+ * Shutdown phases, executed in the order below.
+ */
+export class ShutdownPhases {
+  // Fail readiness probes and wait for load balancers to stop routing traffic
+  static readonly PRE_STOP = 'pre-stop';
+  // Stop accepting requests and drain in-flight ones
+  static readonly HTTP = 'http';
+  // Stop pulling new jobs / messages and wait for the running ones
+  static readonly CONSUMERS = 'consumers';
+  // Flush buffered data, e.g. outbox, metrics, logs
+  static readonly FLUSH = 'flush';
+  // Close datasources, redis, brokers...
+  static readonly RESOURCES = 'resources';
+
+  static readonly ORDERS = [this.PRE_STOP, this.HTTP, this.CONSUMERS, this.FLUSH, this.RESOURCES];
+  static readonly SCHEME_SET = new Set(this.ORDERS);
+
+  static isValid(input: string): boolean {
+    return this.SCHEME_SET.has(input);
+  }
+}
+export type TShutdownPhase = TConstValue<typeof ShutdownPhases>;
+
+// --------------------------------------------------------
+export class ShutdownDefaults {
+  static readonly DEADLINE = 30_000;
+}
@@ -0,0 +1,3 @@
+export * from './constants';
+export * from './orchestrator';
+export * from './types';
@@ -0,0 +1,161 @@
+import { BaseHelper } from '@/helpers/base';
+import { ShutdownDefaults, ShutdownPhases } from './constants';
+import { IShutdownHook, IShutdownReport } from './types';
+
+// --------------------------------------------------------
+/**
+ * Runs shutdown hooks phase by phase within a hard deadline.
+ *
+ * Phases run in `ShutdownPhases.ORDERS`, hooks of the same phase run concurrently. A failing
+ * or timed out hook is logged and does not block the next phase. Once the deadline is
+ * reached the remaining hooks are abandoned and reported as `pending`.
+ *
+ * @example
+ * ```typescript
+ * const orchestrator = new ShutdownOrchestrator();
+ *
+ * orchestrator.register({
+ *   identifier: 'order-consumer',
+ *   phase: ShutdownPhases.CONSUMERS,
+ *   hook: () => orderWorker.close(),
+ * });
+ * orchestrator.register({
+ *   identifier: 'outbox',
+ *   phase: ShutdownPhases.FLUSH,
+ *   hook: () => outbox.flush(),
+ * });
+ * orchestrator.register({ identifier: 'redis', hook: () => redis.disconnect() });
+ *
+ * const { isCompleted } = await orchestrator.execute({ deadline: 30_000 });
+ * ```
+ */
+export class ShutdownOrchestrator extends BaseHelper {
+  private hooks: Array<IShutdownHook> = [];
+  private executePromise?: Promise<IShutdownReport>;
+
+  constructor(opts: { scope?: string; identifier?: string } = {}) {
+    super({
+      scope: opts.scope ?? ShutdownOrchestrator.name,
+      identifier: opts.identifier ?? ShutdownOrchestrator.name,
+    });
+  }
+
+  // --------------------------------------------------------
+  register(opts: IShutdownHook) {
+    const { identifier, phase = ShutdownPhases.RESOURCES } = opts;
+
+    this.hooks.push({ ...opts, phase });
+    this.logger
+      .for(this.register.name)
+      .debug('Registered shutdown hook | identifier: %s | phase: %s', identifier, phase);
+
+    return this;
+  }
+
+  getHooks(): Array<IShutdownHook> {
+    return [...this.hooks];
+  }
+
+  isShuttingDown(): boolean {
+    return this.executePromise !== undefined;
+  }
+
+  // --------------------------------------------------------
+  private async runHook(opts: { hook: IShutdownHook; report: IShutdownReport }) {
+    const { hook, report } = opts;
+    const logger = this.logger.for(this.runHook.name);
+    const t = performance.now();
+
+    let timer: ReturnType<typeof setTimeout> | undefined;
+    try {
+      const tasks: Array<Promise<unknown>> = [Promise.resolve().then(() => hook.hook())];
+      if (hook.timeout) {
+        tasks.push(
+          new Promise((_resolve, reject) => {
+            timer = setTimeout(() => {
+              reject(new Error(`Shutdown hook timed out after ${hook.timeout}ms`));
+            }, hook.timeout);
+          }),
+        );
+      }
+
+      await Promise.race(tasks);
+      logger.info(
+        'Executed shutdown hook | identifier: %s | took: %s (ms)',
+        hook.identifier,
+        performance.now() - t,
+      );
+    } catch (error) {
+      // Keep going, the remaining resources still have to be released
+      report.failed.push(hook.identifier);
+      logger.error(
+        'Failed to execute shutdown hook | identifier: %s | error: %s',
+        hook.identifier,
+        error,
+      );
+    } finally {
+      clearTimeout(timer);
+      report.pending = report.pending.filter(identifier => identifier !== hook.identifier);
+    }
+  }
+
+  private async runPhases(opts: { report: IShutdownReport }) {
+    const { report } = opts;
+
+    for (const phase of ShutdownPhases.ORDERS) {
+      const hooks = this.hooks.filter(hook => hook.phase === phase);
+      if (!hooks.length) {
+        continue;
+      }
+
+      this.logger
+        .for(this.runPhases.name)
+        .info('Shutdown phase STARTED | phase: %s | hooks: %d', phase, hooks.length);
+      await Promise.all(hooks.map(hook => this.runHook({ hook, report })));
+    }
+  }
+
+  /**
+   * Run every registered hook once. Calling it again returns the same report.
+   */
+  execute(opts: { deadline?: number } = {}): Promise<IShutdownReport> {
+    if (this.executePromise) {
+      return this.executePromise;
+    }
+
+    const { deadline = ShutdownDefaults.DEADLINE } = opts;
+    const logger = this.logger.for(this.execute.name);
+
+    this.executePromise = (async () => {
+      const t = performance.now();
+      const report: IShutdownReport = {
+        isCompleted: false,
+        took: 0,
+        failed: [],
+        pending: this.hooks.map(hook => hook.identifier),
+      };
+
+      let timer: ReturnType<typeof setTimeout> | undefined;
+      report.isCompleted = await Promise.race([
+        this.runPhases({ report }).then(() => true),
+        new Promise<boolean>(resolve => {
+          timer = setTimeout(() => resolve(false), deadline);
+        }),
+      ]);
+      clearTimeout(timer);
+
+      const rs = { ...report, pending: [...report.pending], took: performance.now() - t };
+      if (!rs.isCompleted) {
+        logger.error(
+          'Shutdown deadline REACHED | deadline: %s (ms) | pending: %j',
+          deadline,
+          rs.pending,
+        );
+      }
+
+      return rs;
+    })();
+
+    return this.executePromise;
+  }
+}
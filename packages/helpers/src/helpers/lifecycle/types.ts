@@ -0,0 +1,20 @@
+import { ValueOrPromise } from '@/common/types';
+import { TShutdownPhase } from './constants';
+
+export interface IShutdownHook {
+  identifier: string;
+  hook: () => ValueOrPromise<void>;
+  // Defaults to `ShutdownPhases.RESOURCES`
+  phase?: TShutdownPhase;
+  // Milliseconds, the hook is abandoned afterwards so the next phase can start
+  timeout?: number;
+}
+
+export interface IShutdownReport {
+  // false when the deadline was reached before every hook settled
+  isCompleted: boolean;
+  // Milliseconds
+  took: number;
+  failed: Array<string>;
+  pending: Array<string>;
+}
@@ -0,0 +1,3 @@
+export * from './memory.helper';
+export * from './redis.helper';
+export * from './types';
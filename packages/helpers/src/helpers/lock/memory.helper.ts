@@ -0,0 +1,63 @@
+import { BaseHelper } from '@/helpers/base';
+import C from 'node:crypto';
+import { ILockHandle, ILockHelper } from './types';
+
+// --------------------------------------------------------
+/**
+ * Process-local lock, useful for single instance deployments and tests.
+ */
+export class MemoryLockHelper extends BaseHelper implements ILockHelper {
+  private locks = new Map<string, { token: string; expiresAt: number }>();
+
+  constructor(opts?: { identifier?: string }) {
+    super({ scope: MemoryLockHelper.name, identifier: opts?.identifier ?? MemoryLockHelper.name });
+  }
+
+  private isHeld(key: string) {
+    const lock = this.locks.get(key);
+    if (!lock) {
+      return false;
+    }
+
+    if (lock.expiresAt <= Date.now()) {
+      this.locks.delete(key);
+      return false;
+    }
+
+    return true;
+  }
+
+  acquire(opts: { key: string; ttl: number }) {
+    const { key, ttl } = opts;
+    if (this.isHeld(key)) {
+      return Promise.resolve(null);
+    }
+
+    const handle: ILockHandle = { key, token: C.randomUUID(), expiresAt: Date.now() + ttl };
+    this.locks.set(key, { token: handle.token, expiresAt: handle.expiresAt });
+    return Promise.resolve(handle);
+  }
+
+  extend(opts: { handle: ILockHandle; ttl: number }) {
+    const { handle, ttl } = opts;
+    const lock = this.locks.get(handle.key);
+    if (!this.isHeld(handle.key) || lock?.token !== handle.token) {
+      return Promise.resolve(false);
+    }
+
+    handle.expiresAt = Date.now() + ttl;
+    lock.expiresAt = handle.expiresAt;
+    return Promise.resolve(true);
+  }
+
+  release(opts: { handle: ILockHandle }) {
+    const { handle } = opts;
+    const lock = this.locks.get(handle.key);
+    if (lock?.token !== handle.token) {
+      return Promise.resolve(false);
+    }
+
+    this.locks.delete(handle.key);
+    return Promise.resolve(true);
+  }
+}
@@ -0,0 +1,85 @@
+import { BaseHelper } from '@/helpers/base';
+import { DefaultRedisHelper } from '@/helpers/redis';
+import C from 'node:crypto';
+import { ILockHandle, ILockHelper } from './types';
+
+const RELEASE_SCRIPT = `
+if redis.call("get", KEYS[1]) == ARGV[1] then
+  return redis.call("del", KEYS[1])
+end
+return 0
+`;
+
+const EXTEND_SCRIPT = `
+if redis.call("get", KEYS[1]) == ARGV[1] then
+  return redis.call("pexpire", KEYS[1], ARGV[2])
+end
+return 0
+`;
+
+// --------------------------------------------------------
+/**
+ * Distributed lock on a single Redis key (`SET key token NX PX ttl`).
+ *
+ * Only the owner of the token can extend or release the lock, so an expired holder can never
+ * release a lock which was acquired by another instance in the meantime.
+ */
+export class RedisLockHelper extends BaseHelper implements ILockHelper {
+  private redis: DefaultRedisHelper;
+  private prefix: string;
+
+  constructor(opts: { redis: DefaultRedisHelper; prefix?: string; identifier?: string }) {
+    super({ scope: RedisLockHelper.name, identifier: opts.identifier ?? RedisLockHelper.name });
+
+    this.redis = opts.redis;
+    this.prefix = opts.prefix ?? 'lock';
+  }
+
+  private getKey(key: string) {
+    return `${this.prefix}:${key}`;
+  }
+
+  async acquire(opts: { key: string; ttl: number }) {
+    const { key, ttl } = opts;
+    const token = C.randomUUID();
+
+    const rs = await this.redis.getClient().set(this.getKey(key), token, 'PX', ttl, 'NX');
+    if (rs !== 'OK') {
+      return null;
+    }
+
+    const handle: ILockHandle = { key, token, expiresAt: Date.now() + ttl };
+    return handle;
+  }
+
+  async extend(opts: { handle: ILockHandle; ttl: number }) {
+    const { handle, ttl } = opts;
+
+    const rs = await this.redis
+      .getClient()
+      .eval(EXTEND_SCRIPT, 1, this.getKey(handle.key), handle.token, ttl);
+    if (Number(rs) !== 1) {
+      return false;
+    }
+
+    handle.expiresAt = Date.now() + ttl;
+    return true;
+  }
+
+  async release(opts: { handle: ILockHandle }) {
+    const { handle } = opts;
+
+    const rs = await this.redis
+      .getClient()
+      .eval(RELEASE_SCRIPT, 1, this.getKey(handle.key), handle.token);
+
+    if (Number(rs) !== 1) {
+      this.logger
+        .for(this.release.name)
+        .warn('Lock was not owned or already expired | key: %s', handle.key);
+      return false;
+    }
+
+    return true;
+  }
+}
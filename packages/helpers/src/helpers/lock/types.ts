@@ -0,0 +1,12 @@
+// --------------------------------------------------------
+export interface ILockHandle {
+  key: string;
+  token: string;
+  expiresAt: number;
+}
+
+export interface ILockHelper {
+  acquire(opts: { key: string; ttl: number }): Promise<ILockHandle | null>;
+  extend(opts: { handle: ILockHandle; ttl: number }): Promise<boolean>;
+  release(opts: { handle: ILockHandle }): Promise<boolean>;
+}
@@ -16,13 +16,28 @@ export class Logger {
   // Cache: same scope = same logger instance
   private static cache = new Map<string, Logger>();
 
+  // Replaced by `initLogging`, read per call so cached loggers follow it
+  private static defaultLogger: winston.Logger = applicationLogger;
+
   // Pre-formatted prefix with brackets - computed once at construction
   private readonly _formattedPrefix: string;
-  private readonly _logger: winston.Logger;
+  private readonly _customLogger?: winston.Logger;
 
-  private constructor(scope: string, logger: winston.Logger) {
+  private constructor(scope: string, logger?: winston.Logger) {
     this._formattedPrefix = `[${scope}] `;
-    this._logger = logger;
+    this._customLogger = logger;
+  }
+
+  private get _logger(): winston.Logger {
+    return this._customLogger ?? Logger.defaultLogger;
+  }
+
+  // ---------------------------------------------------------------------
+  /**
+   * Replace the winston logger behind every non custom logger, including cached ones.
+   */
+  static setDefaultLogger(logger: winston.Logger) {
+    this.defaultLogger = logger;
   }
 
   // ---------------------------------------------------------------------
@@ -40,7 +55,7 @@ export class Logger {
         return cached;
       }
 
-      cached = new Logger(scope, applicationLogger);
+      cached = new Logger(scope);
       this.cache.set(scope, cached);
       return cached;
     }
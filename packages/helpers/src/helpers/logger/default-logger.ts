@@ -4,6 +4,7 @@ import { int } from '@/utilities/parse.utility';
 import path from 'node:path';
 import winston from 'winston';
 import 'winston-daily-rotate-file';
+import { defineRedactionFormat } from './redaction';
 import { DgramTransport, IDgramTransportOptions } from './transports';
 import { getError } from '@venizia/ignis-inversion';
 
@@ -81,7 +82,10 @@ export const defineLogFormatter = (opts: { label: string; format?: TLoggerFormat
 };
 
 // -------------------------------------------------------------------------------------------
-export const applicationLogFormatter = defineLogFormatter({ label: LOGGER_PREFIX });
+export const applicationLogFormatter = f.combine(
+  defineRedactionFormat(),
+  defineLogFormatter({ label: LOGGER_PREFIX }),
+);
 
 // -------------------------------------------------------------------------------------------
 export interface IFileTransportOptions {
@@ -215,9 +219,9 @@ const dgramOptions: Partial<IDgramTransportOptions> = {
 };
 
 // -------------------------------------------------------------------------------------------
-export const applicationLogger = defineCustomLogger({
-  transports: {
-    info: { file: fileOptions, dgram: dgramOptions },
-    error: { file: fileOptions, dgram: dgramOptions },
-  },
-});
+export const applicationLoggerTransports: ICustomLoggerOptions['transports'] = {
+  info: { file: fileOptions, dgram: dgramOptions },
+  error: { file: fileOptions, dgram: dgramOptions },
+};
+
+export const applicationLogger = defineCustomLogger({ transports: applicationLoggerTransports });
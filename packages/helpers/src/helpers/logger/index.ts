@@ -2,5 +2,7 @@ export * from './application-logger';
 export * from './default-logger';
 export * from './factory';
 export * from './hf-logger';
+export * from './logging';
+export * from './redaction';
 export * from './transports';
 export * from './types';
@@ -0,0 +1,186 @@
+import { Defaults } from '@/common/constants';
+import { RequestContextStorage } from '@/helpers/request-context/storage';
+import winston from 'winston';
+import { Logger } from './application-logger';
+import {
+  applicationLoggerTransports,
+  defineCustomLogger,
+  defineLogFormatter,
+  ICustomLoggerOptions,
+  TLoggerFormat,
+} from './default-logger';
+import { defineRedactionFormat } from './redaction';
+import { LogLevels, TLogLevel } from './types';
+
+const f = winston.format;
+
+// Same ranks as the default `logLevels` of `defineCustomLogger`
+const LOG_LEVEL_RANKS: Record<TLogLevel, number> = {
+  error: 0,
+  alert: 0,
+  emerg: 0,
+  warn: 1,
+  info: 2,
+  http: 3,
+  verbose: 4,
+  debug: 5,
+  silly: 6,
+};
+
+const SCOPE_PATTERN = /^\[([^\]]+)\] /;
+
+// -------------------------------------------------------------------------------------------
+export interface ILoggingOptions {
+  service?: {
+    // Defaults to `APP_ENV_APPLICATION_NAME`
+    name?: string;
+    // Defaults to `APP_ENV_APPLICATION_VERSION`
+    version?: string;
+    // Defaults to `NODE_ENV`
+    environment?: string;
+  };
+  // `json` for collected logs, `text` for readable local dev output.
+  // Defaults to `APP_ENV_LOGGER_FORMAT`
+  format?: TLoggerFormat;
+  // Defaults to `APP_ENV_LOGGER_LEVEL`, then `debug`
+  level?: TLogLevel;
+  /**
+   * Per scope levels, a scope also matches its method loggers (`UserService` matches
+   * `UserService-create`). Defaults to `APP_ENV_LOGGER_LEVELS`,
+   * e.g. `UserService=debug,RedisHelper=warn`.
+   */
+  scopeLevels?: Record<string, TLogLevel>;
+  // Names of the fields masked in metadata and arguments, `false` disables the redaction.
+  // Defaults to `SensitiveFields.DEFAULTS`
+  redactFields?: Array<string> | false;
+  // Defaults to the file and dgram transports of the `APP_ENV_LOGGER_*` envs
+  transports?: ICustomLoggerOptions['transports'];
+}
+
+// -------------------------------------------------------------------------------------------
+/**
+ * Parse `scope=level` pairs separated by commas, invalid levels are ignored.
+ */
+export const parseScopeLevels = (input?: string): Record<string, TLogLevel> => {
+  const rs: Record<string, TLogLevel> = {};
+
+  for (const pair of (input ?? '').split(',')) {
+    const [scope, level] = pair.split('=').map(el => el?.trim());
+    if (!scope || !level || !LogLevels.isValid(level)) {
+      continue;
+    }
+
+    rs[scope] = level as TLogLevel;
+  }
+
+  return rs;
+};
+
+// -------------------------------------------------------------------------------------------
+/**
+ * Drop entries above the level of their scope, the most specific configured scope wins.
+ */
+export const defineLevelFilter = (opts: {
+  level: TLogLevel;
+  scopeLevels?: Record<string, TLogLevel>;
+}) => {
+  const { level, scopeLevels = {} } = opts;
+
+  // Longest first so `UserService-create` wins over `UserService`
+  const scopes = Object.keys(scopeLevels).sort((a, b) => b.length - a.length);
+
+  const resolveRank = (scope?: string) => {
+    const matched = scope
+      ? scopes.find(el => scope === el || scope.startsWith(`${el}-`))
+      : undefined;
+    return LOG_LEVEL_RANKS[matched ? scopeLevels[matched] : level];
+  };
+
+  return f(info => {
+    const scope = SCOPE_PATTERN.exec(`${info.message}`)?.[1];
+    const rank = LOG_LEVEL_RANKS[info.level as TLogLevel] ?? LOG_LEVEL_RANKS.info;
+    return rank <= resolveRank(scope) ? info : false;
+  })();
+};
+
+// -------------------------------------------------------------------------------------------
+/**
+ * Add the service fields and the current request id to every entry.
+ */
+export const defineServiceFields = (opts: {
+  name: string;
+  version?: string;
+  environment?: string;
+}) => {
+  const { name, version, environment } = opts;
+
+  return f(info => {
+    info.service = name;
+    if (version) {
+      info.version = version;
+    }
+
+    if (environment) {
+      info.environment = environment;
+    }
+
+    const context = RequestContextStorage.get();
+    if (context) {
+      info.requestId = context.requestId;
+      if (context.tenantId) {
+        info.tenantId = context.tenantId;
+      }
+    }
+
+    return info;
+  })();
+};
+
+// -------------------------------------------------------------------------------------------
+/**
+ * Configure the application logger once at startup so every service logs in the same shape.
+ * Loggers obtained before (`LoggerFactory.getLogger`, `BaseHelper.logger`) switch to it too.
+ *
+ * @example
+ * ```typescript
+ * initLogging({
+ *   service: { name: 'order-service', version: '1.4.0' },
+ *   format: LoggerFormats.JSON,
+ *   scopeLevels: { OrderService: 'debug' },
+ * });
+ * // {"level":"info","message":"[OrderService-create] Created","service":"order-service",
+ * //  "version":"1.4.0","requestId":"...","label":"order-service","timestamp":"..."}
+ * ```
+ */
+export const initLogging = (opts: ILoggingOptions = {}): winston.Logger => {
+  const {
+    service = {},
+    format,
+    level = (process.env.APP_ENV_LOGGER_LEVEL as TLogLevel | undefined) ?? LogLevels.DEBUG,
+    scopeLevels = parseScopeLevels(process.env.APP_ENV_LOGGER_LEVELS),
+    redactFields,
+    transports = applicationLoggerTransports,
+  } = opts;
+
+  const formats = [defineLevelFilter({ level, scopeLevels })];
+  if (redactFields !== false) {
+    formats.push(defineRedactionFormat({ fields: redactFields }));
+  }
+
+  const name = service.name ?? Defaults.APPLICATION_NAME;
+  const logger = defineCustomLogger({
+    loggerFormatter: f.combine(
+      ...formats,
+      defineServiceFields({
+        name,
+        version: service.version ?? process.env.APP_ENV_APPLICATION_VERSION,
+        environment: service.environment ?? process.env.NODE_ENV,
+      }),
+      defineLogFormatter({ label: name, format }),
+    ),
+    transports,
+  });
+
+  Logger.setDefaultLogger(logger);
+  return logger;
+};
@@ -0,0 +1,150 @@
+import winston from 'winston';
+
+const SPLAT = Symbol.for('splat');
+
+export const REDACTED_VALUE = '[REDACTED]';
+
+// Credentials embedded in messages, e.g. `Bearer eyJ...` or `Basic dXNlcjpwYXNzd29yZA==`
+const CREDENTIAL_PATTERN = /\b(Bearer|Basic)\s+[A-Za-z0-9\-._~+/]{16,}=*/g;
+
+// -------------------------------------------------------------------------------------------
+export class SensitiveFields {
+  // Compared case insensitively, `-` and `_` ignored so `x-api-key` matches `apiKey` too
+  static readonly DEFAULTS = [
+    'authorization',
+    'proxy-authorization',
+    'cookie',
+    'set-cookie',
+    'x-api-key',
+    'api-key',
+    'password',
+    'secret',
+    'client-secret',
+    'token',
+    'access-token',
+    'refresh-token',
+    'id-token',
+    'private-key',
+  ];
+
+  static normalize(name: string) {
+    return name.toLowerCase().replace(/[-_]/g, '');
+  }
+
+  static toSet(fields: Array<string> = this.DEFAULTS) {
+    return new Set(fields.map(field => this.normalize(field)));
+  }
+}
+
+const DEFAULT_SENSITIVE_FIELDS = SensitiveFields.toSet();
+
+// -------------------------------------------------------------------------------------------
+/**
+ * Mask a credential, keeping the auth scheme so logs still tell `Bearer` from `Basic`.
+ */
+export const maskSecret = (value: unknown): string => {
+  if (typeof value !== 'string') {
+    return REDACTED_VALUE;
+  }
+
+  const scheme = /^(Bearer|Basic)\s+/i.exec(value)?.[1];
+  return scheme ? `${scheme} ${REDACTED_VALUE}` : REDACTED_VALUE;
+};
+
+/**
+ * Scrub credentials found in free text.
+ */
+export const redactText = (input: string) => {
+  return input.replace(CREDENTIAL_PATTERN, (_match, scheme) => `${scheme} ${REDACTED_VALUE}`);
+};
+
+const isPlainObject = (value: unknown): value is Record<string, unknown> => {
+  if (!value || typeof value !== 'object') {
+    return false;
+  }
+
+  const proto = Object.getPrototypeOf(value);
+  return proto === Object.prototype || proto === null;
+};
+
+// -------------------------------------------------------------------------------------------
+/**
+ * Copy a value with the sensitive fields masked. Only plain objects, arrays and `Headers` are
+ * traversed, other instances (agents, streams, buffers) are kept as is.
+ *
+ * @example
+ * ```typescript
+ * redact({ headers: { Authorization: 'Bearer eyJ...' }, body: { password: '123' } });
+ * // { headers: { Authorization: 'Bearer [REDACTED]' }, body: { password: '[REDACTED]' } }
+ * ```
+ */
+export const redact = <T>(
+  value: T,
+  opts: { fields?: Set<string>; depth?: number } = {},
+): T => {
+  const { fields = DEFAULT_SENSITIVE_FIELDS, depth = 8 } = opts;
+
+  const walk = (input: unknown, level: number): unknown => {
+    if (level > depth) {
+      return input;
+    }
+
+    if (Array.isArray(input)) {
+      return input.map(el => walk(el, level + 1));
+    }
+
+    let entries: Array<[string, unknown]> | null = null;
+    if (typeof Headers !== 'undefined' && input instanceof Headers) {
+      entries = Array.from(input.entries());
+    } else if (isPlainObject(input)) {
+      entries = Object.entries(input);
+    }
+
+    if (!entries) {
+      return input;
+    }
+
+    const rs: Record<string, unknown> = {};
+    for (const [key, el] of entries) {
+      rs[key] = fields.has(SensitiveFields.normalize(key)) ? maskSecret(el) : walk(el, level + 1);
+    }
+
+    return rs;
+  };
+
+  return walk(value, 0) as T;
+};
+
+// -------------------------------------------------------------------------------------------
+/**
+ * Winston format masking sensitive fields of the metadata and `%o`/`%j` arguments, and
+ * credentials written in the message. Place it before the formatter applying `splat`.
+ */
+export const defineRedactionFormat = (opts: { fields?: Array<string> } = {}) => {
+  const fields = SensitiveFields.toSet(opts.fields);
+
+  return winston.format(info => {
+    if (typeof info.message === 'string') {
+      info.message = redactText(info.message);
+    }
+
+    const splat = info[SPLAT];
+    if (Array.isArray(splat)) {
+      info[SPLAT] = splat.map(el => {
+        return typeof el === 'string' ? redactText(el) : redact(el, { fields });
+      });
+    }
+
+    for (const key of Object.keys(info)) {
+      if (key === 'level' || key === 'message') {
+        continue;
+      }
+
+      info[key] = fields.has(SensitiveFields.normalize(key))
+        ? maskSecret(info[key])
+        : redact(info[key], { fields });
+    }
+
+    return info;
+  })();
+};
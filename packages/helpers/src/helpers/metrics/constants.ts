@@ -0,0 +1,23 @@
+import { TConstValue } from '@/common/types';
+
+export class MetricTypes {
+  static readonly COUNTER = 'counter';
+  static readonly GAUGE = 'gauge';
+  static readonly HISTOGRAM = 'histogram';
+
+  static readonly SCHEME_SET = new Set([this.COUNTER, this.GAUGE, this.HISTOGRAM]);
+
+  static isValid(input: string): boolean {
+    return this.SCHEME_SET.has(input);
+  }
+}
+
+export type TMetricType = TConstValue<typeof MetricTypes>;
+
+export class MetricDefaults {
+  // Seconds, tuned for HTTP latencies
+  static readonly BUCKETS = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1, 2.5, 5, 10];
+  // Bytes, 256B to 64MiB by powers of 4
+  static readonly SIZE_BUCKETS = Array.from({ length: 10 }, (_, i) => 256 * Math.pow(4, i));
+  static readonly CONTENT_TYPE = 'text/plain; version=0.0.4; charset=utf-8';
+}
@@ -0,0 +1,5 @@
+export * from './constants';
+export * from './metrics';
+export * from './process';
+export * from './registry';
+export * from './types';
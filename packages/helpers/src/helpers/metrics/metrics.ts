@@ -0,0 +1,244 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import { MetricDefaults, MetricTypes, TMetricType } from './constants';
+import { IHistogramOptions, IMetricOptions, TMetricLabels } from './types';
+
+const METRIC_NAME_PATTERN = /^[a-zA-Z_:][a-zA-Z0-9_:]*$/;
+const LABEL_NAME_PATTERN = /^[a-zA-Z_][a-zA-Z0-9_]*$/;
+
+const escapeLabelValue = (value: string) => {
+  return value.replace(/\\/g, '\\\\').replace(/"/g, '\\"').replace(/\n/g, '\\n');
+};
+
+const formatValue = (value: number) => {
+  if (Number.isNaN(value)) {
+    return 'NaN';
+  }
+
+  if (!Number.isFinite(value)) {
+    return value > 0 ? '+Inf' : '-Inf';
+  }
+
+  return `${value}`;
+};
+
+// --------------------------------------------------------
+export abstract class BaseMetric<TSeries> {
+  readonly name: string;
+  readonly help: string;
+  readonly labelNames: Array<string>;
+  abstract readonly type: TMetricType;
+
+  protected series = new Map<string, { labels: TMetricLabels; value: TSeries }>();
+
+  constructor(opts: IMetricOptions) {
+    const { name, help, labelNames = [] } = opts;
+
+    if (!METRIC_NAME_PATTERN.test(name)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[BaseMetric] Invalid metric name | name: ${name}`,
+      });
+    }
+
+    const invalidLabel = labelNames.find(label => {
+      return !LABEL_NAME_PATTERN.test(label) || label.startsWith('__');
+    });
+    if (invalidLabel) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[BaseMetric] Invalid label name | name: ${name} | label: ${invalidLabel}`,
+      });
+    }
+
+    this.name = name;
+    this.help = help;
+    this.labelNames = labelNames;
+  }
+
+  protected abstract createSeries(): TSeries;
+
+  protected abstract serializeSeries(opts: { labels: TMetricLabels; value: TSeries }): string[];
+
+  protected getSeries(labels: TMetricLabels = {}): TSeries {
+    const unknown = Object.keys(labels).find(label => !this.labelNames.includes(label));
+    if (unknown) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[${this.name}] Unknown label | label: ${unknown} | labelNames: ${this.labelNames}`,
+      });
+    }
+
+    const key = this.labelNames.map(label => `${labels[label] ?? ''}`).join('\u0000');
+    let series = this.series.get(key);
+    if (!series) {
+      series = { labels, value: this.createSeries() };
+      this.series.set(key, series);
+    }
+
+    return series.value;
+  }
+
+  protected formatSample(opts: { suffix?: string; labels: TMetricLabels; value: number }) {
+    const { suffix = '', labels, value } = opts;
+
+    const pairs = Object.entries(labels)
+      .filter(([, el]) => el !== undefined && el !== null)
+      .map(([label, el]) => `${label}="${escapeLabelValue(`${el}`)}"`);
+
+    const labelText = pairs.length ? `{${pairs.join(',')}}` : '';
+    return `${this.name}${suffix}${labelText} ${formatValue(value)}`;
+  }
+
+  reset() {
+    this.series.clear();
+  }
+
+  serialize(): string {
+    const lines = [
+      `# HELP ${this.name} ${this.help.replace(/\\/g, '\\\\').replace(/\n/g, '\\n')}`,
+      `# TYPE ${this.name} ${this.type}`,
+    ];
+
+    for (const series of this.series.values()) {
+      lines.push(...this.serializeSeries(series));
+    }
+
+    return lines.join('\n');
+  }
+}
+
+// --------------------------------------------------------
+export class Counter extends BaseMetric<{ value: number }> {
+  readonly type = MetricTypes.COUNTER;
+
+  protected createSeries() {
+    return { value: 0 };
+  }
+
+  protected serializeSeries(opts: { labels: TMetricLabels; value: { value: number } }) {
+    return [this.formatSample({ labels: opts.labels, value: opts.value.value })];
+  }
+
+  inc(opts: { labels?: TMetricLabels; value?: number } = {}) {
+    const { labels, value = 1 } = opts;
+    if (value < 0) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[${this.name}] Counters can only increase | value: ${value}`,
+      });
+    }
+
+    this.getSeries(labels).value += value;
+  }
+}
+
+// --------------------------------------------------------
+export class Gauge extends BaseMetric<{ value: number }> {
+  readonly type = MetricTypes.GAUGE;
+
+  protected createSeries() {
+    return { value: 0 };
+  }
+
+  protected serializeSeries(opts: { labels: TMetricLabels; value: { value: number } }) {
+    return [this.formatSample({ labels: opts.labels, value: opts.value.value })];
+  }
+
+  set(opts: { labels?: TMetricLabels; value: number }) {
+    this.getSeries(opts.labels).value = opts.value;
+  }
+
+  inc(opts: { labels?: TMetricLabels; value?: number } = {}) {
+    this.getSeries(opts.labels).value += opts.value ?? 1;
+  }
+
+  dec(opts: { labels?: TMetricLabels; value?: number } = {}) {
+    this.getSeries(opts.labels).value -= opts.value ?? 1;
+  }
+}
+
+// --------------------------------------------------------
+interface IHistogramSeries {
+  counts: Array<number>;
+  sum: number;
+  count: number;
+}
+
+export class Histogram extends BaseMetric<IHistogramSeries> {
+  readonly type = MetricTypes.HISTOGRAM;
+  readonly buckets: Array<number>;
+
+  constructor(opts: IHistogramOptions) {
+    super(opts);
+
+    if ((opts.labelNames ?? []).includes('le')) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[${opts.name}] Label "le" is reserved for histogram buckets`,
+      });
+    }
+
+    this.buckets = [...(opts.buckets ?? MetricDefaults.BUCKETS)].sort((a, b) => a - b);
+  }
+
+  protected createSeries(): IHistogramSeries {
+    return { counts: this.buckets.map(() => 0), sum: 0, count: 0 };
+  }
+
+  protected serializeSeries(opts: { labels: TMetricLabels; value: IHistogramSeries }) {
+    const { labels, value } = opts;
+
+    // Buckets are cumulative in the exposition format
+    const lines = this.buckets.map((bucket, index) => {
+      return this.formatSample({
+        suffix: '_bucket',
+        labels: { ...labels, le: formatValue(bucket) },
+        value: value.counts[index],
+      });
+    });
+
+    lines.push(
+      this.formatSample({
+        suffix: '_bucket',
+        labels: { ...labels, le: '+Inf' },
+        value: value.count,
+      }),
+      this.formatSample({ suffix: '_sum', labels, value: value.sum }),
+      this.formatSample({ suffix: '_count', labels, value: value.count }),
+    );
+
+    return lines;
+  }
+
+  observe(opts: { labels?: TMetricLabels; value: number }) {
+    const { labels, value } = opts;
+    const series = this.getSeries(labels);
+
+    for (let i = 0; i < this.buckets.length; i++) {
+      if (value <= this.buckets[i]) {
+        series.counts[i]++;
+      }
+    }
+
+    series.sum += value;
+    series.count++;
+  }
+
+  /**
+   * Start a timer, the returned function observes the elapsed seconds.
+   * @example
+   * const end = histogram.startTimer();
+   * await handle();
+   * end({ labels: { route: '/users', status: 200 } });
+   */
+  startTimer(opts: { labels?: TMetricLabels } = {}) {
+    const startedAt = performance.now();
+
+    return (endOpts: { labels?: TMetricLabels } = {}) => {
+      const value = (performance.now() - startedAt) / 1000;
+      this.observe({ labels: { ...opts.labels, ...endOpts.labels }, value });
+      return value;
+    };
+  }
+}
@@ -0,0 +1,79 @@
+import { MetricsRegistry } from './registry';
+
+const EVENT_LOOP_SAMPLE_INTERVAL = 500;
+
+// --------------------------------------------------------
+/**
+ * Register the standard process metrics, refreshed on every scrape:
+ * CPU time, resident memory, heap usage, event loop lag and uptime.
+ * Registering twice on the same registry is a no-op.
+ */
+export const registerProcessMetrics = (
+  opts: { registry?: MetricsRegistry; prefix?: string } = {},
+) => {
+  const { registry = MetricsRegistry.getInstance(), prefix = '' } = opts;
+
+  const name = (metric: string) => `${prefix}${metric}`;
+  if (registry.getMetric({ name: name('process_start_time_seconds') })) {
+    return;
+  }
+
+  const cpuSeconds = registry.counter({
+    name: name('process_cpu_seconds_total'),
+    help: 'Total user and system CPU time spent in seconds.',
+    labelNames: ['mode'],
+  });
+  const residentMemory = registry.gauge({
+    name: name('process_resident_memory_bytes'),
+    help: 'Resident memory size in bytes.',
+  });
+  const heap = registry.gauge({
+    name: name('nodejs_heap_size_bytes'),
+    help: 'Process heap size in bytes.',
+    labelNames: ['type'],
+  });
+  const eventLoopLag = registry.gauge({
+    name: name('nodejs_eventloop_lag_seconds'),
+    help: 'Maximum event loop delay since the previous scrape in seconds.',
+  });
+  const startTime = registry.gauge({
+    name: name('process_start_time_seconds'),
+    help: 'Start time of the process since unix epoch in seconds.',
+  });
+  const uptime = registry.gauge({
+    name: name('process_uptime_seconds'),
+    help: 'Seconds since the process started.',
+  });
+
+  startTime.set({ value: Math.round(Date.now() / 1000 - process.uptime()) });
+
+  // A timer firing late measures how long the loop was blocked
+  let maxLag = 0;
+  let expectedAt = performance.now() + EVENT_LOOP_SAMPLE_INTERVAL;
+  setInterval(() => {
+    const now = performance.now();
+    maxLag = Math.max(maxLag, now - expectedAt);
+    expectedAt = now + EVENT_LOOP_SAMPLE_INTERVAL;
+  }, EVENT_LOOP_SAMPLE_INTERVAL).unref();
+
+  let lastCpu = { user: 0, system: 0 };
+  registry.registerCollector({
+    collector: () => {
+      const cpu = process.cpuUsage();
+      cpuSeconds.inc({ labels: { mode: 'user' }, value: (cpu.user - lastCpu.user) / 1e6 });
+      cpuSeconds.inc({ labels: { mode: 'system' }, value: (cpu.system - lastCpu.system) / 1e6 });
+      lastCpu = cpu;
+
+      const memory = process.memoryUsage();
+      residentMemory.set({ value: memory.rss });
+      heap.set({ labels: { type: 'total' }, value: memory.heapTotal });
+      heap.set({ labels: { type: 'used' }, value: memory.heapUsed });
+      heap.set({ labels: { type: 'external' }, value: memory.external });
+
+      eventLoopLag.set({ value: Math.max(0, maxLag) / 1000 });
+      maxLag = 0;
+
+      uptime.set({ value: process.uptime() });
+    },
+  });
+};
@@ -0,0 +1,120 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { MetricDefaults } from './constants';
+import { BaseMetric, Counter, Gauge, Histogram } from './metrics';
+import { IHistogramOptions, IMetricOptions, TMetricCollector } from './types';
+
+// --------------------------------------------------------
+/**
+ * Registry of the application metrics, serialized in the Prometheus text format.
+ *
+ * Metrics are created once by name, asking again for the same name returns the same instance
+ * so modules can declare the metrics they use without sharing references.
+ *
+ * @example
+ * ```typescript
+ * const registry = MetricsRegistry.getInstance();
+ * const orders = registry.counter({
+ *   name: 'orders_created_total',
+ *   help: 'Orders created',
+ *   labelNames: ['channel'],
+ * });
+ *
+ * orders.inc({ labels: { channel: 'web' } });
+ * const body = await registry.metrics();
+ * ```
+ */
+export class MetricsRegistry extends BaseHelper {
+  private static instance: MetricsRegistry;
+
+  private metricsByName = new Map<string, BaseMetric<unknown>>();
+  private collectors: Array<TMetricCollector> = [];
+
+  readonly contentType = MetricDefaults.CONTENT_TYPE;
+
+  constructor() {
+    super({ scope: MetricsRegistry.name, identifier: MetricsRegistry.name });
+  }
+
+  static getInstance(): MetricsRegistry {
+    if (!MetricsRegistry.instance) {
+      MetricsRegistry.instance = new MetricsRegistry();
+    }
+
+    return MetricsRegistry.instance;
+  }
+
+  // --------------------------------------------------------
+  private getOrCreate<T extends BaseMetric<any>>(opts: {
+    name: string;
+    type: abstract new (...args: any[]) => T;
+    create: () => T;
+  }): T {
+    const { name, type, create } = opts;
+
+    const existing = this.metricsByName.get(name);
+    if (!existing) {
+      const metric = create();
+      this.metricsByName.set(name, metric);
+      return metric;
+    }
+
+    if (!(existing instanceof type)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[MetricsRegistry] Metric already registered with another type | name: ${name} | type: ${existing.type}`,
+      });
+    }
+
+    return existing;
+  }
+
+  counter(opts: IMetricOptions): Counter {
+    return this.getOrCreate({ name: opts.name, type: Counter, create: () => new Counter(opts) });
+  }
+
+  gauge(opts: IMetricOptions): Gauge {
+    return this.getOrCreate({ name: opts.name, type: Gauge, create: () => new Gauge(opts) });
+  }
+
+  histogram(opts: IHistogramOptions): Histogram {
+    return this.getOrCreate({
+      name: opts.name,
+      type: Histogram,
+      create: () => new Histogram(opts),
+    });
+  }
+
+  getMetric(opts: { name: string }) {
+    return this.metricsByName.get(opts.name);
+  }
+
+  // --------------------------------------------------------
+  registerCollector(opts: { collector: TMetricCollector }) {
+    this.collectors.push(opts.collector);
+    return this;
+  }
+
+  /**
+   * Run the collectors then serialize every metric, the body of a `/metrics` response.
+   */
+  async metrics(): Promise<string> {
+    for (const collector of this.collectors) {
+      try {
+        await collector();
+      } catch (error) {
+        this.logger.for(this.metrics.name).error('Failed to run collector | Error: %s', error);
+      }
+    }
+
+    const blocks = Array.from(this.metricsByName.values(), metric => metric.serialize());
+    return blocks.length ? `${blocks.join('\n\n')}\n` : '';
+  }
+
+  // Drop every metric and collector, mostly for tests
+  clear() {
+    this.metricsByName.clear();
+    this.collectors = [];
+  }
+}
@@ -0,0 +1,17 @@
+// --------------------------------------------------------
+export type TMetricLabels = Record<string, string | number>;
+
+export interface IMetricOptions {
+  // e.g. `http_requests_total`, must match `[a-zA-Z_:][a-zA-Z0-9_:]*`
+  name: string;
+  help: string;
+  labelNames?: Array<string>;
+}
+
+export interface IHistogramOptions extends IMetricOptions {
+  // Upper bounds in ascending order, `+Inf` is always added
+  buckets?: Array<number>;
+}
+
+// Called before every scrape, e.g. to refresh gauges from process stats
+export type TMetricCollector = () => void | Promise<void>;
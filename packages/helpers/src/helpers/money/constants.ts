@@ -0,0 +1,71 @@
+import { TConstValue } from '@/common/types';
+
+export class RoundingModes {
+  // 2.5 => 3, -2.5 => -3
+  static readonly HALF_UP = 'half_up';
+  // 2.5 => 2, 3.5 => 4, banker's rounding
+  static readonly HALF_EVEN = 'half_even';
+  // Toward zero
+  static readonly DOWN = 'down';
+  // Away from zero
+  static readonly UP = 'up';
+  static readonly FLOOR = 'floor';
+  static readonly CEIL = 'ceil';
+
+  static readonly SCHEME_SET = new Set([
+    this.HALF_UP,
+    this.HALF_EVEN,
+    this.DOWN,
+    this.UP,
+    this.FLOOR,
+    this.CEIL,
+  ]);
+
+  static isValid(input: string): boolean {
+    return this.SCHEME_SET.has(input);
+  }
+}
+
+export type TRoundingMode = TConstValue<typeof RoundingModes>;
+
+export class MoneyErrorCodes {
+  static readonly INVALID_CURRENCY = 'MONEY_INVALID_CURRENCY';
+  static readonly INVALID_AMOUNT = 'MONEY_INVALID_AMOUNT';
+  static readonly CURRENCY_MISMATCH = 'MONEY_CURRENCY_MISMATCH';
+}
+
+// ISO 4217 codes by number of minor unit digits
+const ZERO_DECIMALS = 'BIF CLP DJF GNF ISK JPY KMF KRW PYG RWF UGX VND VUV XAF XOF XPF';
+const THREE_DECIMALS = 'BHD IQD JOD KWD LYD OMR TND';
+const TWO_DECIMALS =
+  'AED ARS AUD BDT BRL CAD CHF CNY COP CZK DKK EGP EUR GBP HKD HUF IDR ILS INR KHR KZT LAK ' +
+  'LKR MAD MMK MXN MYR NGN NOK NZD PEN PHP PKR PLN QAR RON RUB SAR SEK SGD THB TRY TWD UAH USD ZAR';
+
+// --------------------------------------------------------
+/**
+ * Supported currencies and their number of minor unit digits.
+ */
+export class Currencies {
+  private static readonly DECIMALS = new Map<string, number>(
+    [
+      { codes: ZERO_DECIMALS, decimals: 0 },
+      { codes: TWO_DECIMALS, decimals: 2 },
+      { codes: THREE_DECIMALS, decimals: 3 },
+    ].flatMap(({ codes, decimals }) => codes.split(' ').map(code => [code, decimals] as const)),
+  );
+
+  static isValid(code: string): boolean {
+    return this.DECIMALS.has(code);
+  }
+
+  static getDecimals(code: string): number | undefined {
+    return this.DECIMALS.get(code);
+  }
+
+  /**
+   * Register a currency missing from the table, e.g. a loyalty point currency.
+   */
+  static register(opts: { code: string; decimals: number }) {
+    this.DECIMALS.set(opts.code, opts.decimals);
+  }
+}
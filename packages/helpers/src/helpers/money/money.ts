@@ -0,0 +1,327 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import { Currencies, MoneyErrorCodes, RoundingModes, TRoundingMode } from './constants';
+import { IMoneyJSON, TDecimalInput } from './types';
+
+const DECIMAL_PATTERN = /^([+-])?(\d+)(?:\.(\d+))?$/;
+
+const ZERO = BigInt(0);
+const ONE = BigInt(1);
+const TWO = BigInt(2);
+const TEN = BigInt(10);
+
+/**
+ * `'-12.345'` => `{ digits: -12345n, scale: 3 }`
+ */
+const parseDecimal = (input: TDecimalInput): { digits: bigint; scale: number } => {
+  const str = typeof input === 'number' ? toPlainString(input) : String(input).trim();
+  const match = DECIMAL_PATTERN.exec(str);
+
+  if (!match) {
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+      messageCode: MoneyErrorCodes.INVALID_AMOUNT,
+      message: `[Money] Invalid decimal amount | received: ${input}`,
+    });
+  }
+
+  const [, sign, integer, fraction = ''] = match;
+  const digits = BigInt(`${integer}${fraction}`);
+
+  return { digits: sign === '-' ? -digits : digits, scale: fraction.length };
+};
+
+// Numbers are formatted without exponent, `1e-7` => `'0.0000001'`
+const toPlainString = (input: number) => {
+  if (!Number.isFinite(input)) {
+    return String(input);
+  }
+
+  const str = String(input);
+  return /e/i.test(str) ? input.toFixed(20).replace(/\.?0+$/, '') : str;
+};
+
+/**
+ * Integer division of `numerator / denominator` (denominator > 0) rounded with `mode`.
+ */
+const divideRounded = (numerator: bigint, denominator: bigint, mode: TRoundingMode): bigint => {
+  const quotient = numerator / denominator;
+  const remainder = numerator % denominator;
+  if (remainder === ZERO) {
+    return quotient;
+  }
+
+  const sign = numerator < ZERO ? -ONE : ONE;
+  const doubled = (remainder < ZERO ? -remainder : remainder) * TWO;
+
+  switch (mode) {
+    case RoundingModes.DOWN: {
+      return quotient;
+    }
+    case RoundingModes.UP: {
+      return quotient + sign;
+    }
+    case RoundingModes.FLOOR: {
+      return sign < ZERO ? quotient - ONE : quotient;
+    }
+    case RoundingModes.CEIL: {
+      return sign > ZERO ? quotient + ONE : quotient;
+    }
+    case RoundingModes.HALF_EVEN: {
+      if (doubled === denominator) {
+        return quotient % TWO === ZERO ? quotient : quotient + sign;
+      }
+      return doubled > denominator ? quotient + sign : quotient;
+    }
+    case RoundingModes.HALF_UP:
+    default: {
+      return doubled >= denominator ? quotient + sign : quotient;
+    }
+  }
+};
+
+// --------------------------------------------------------
+/**
+ * Immutable amount of money in one currency, stored as an exact integer of minor units
+ * (cents for USD, none for JPY) so arithmetic never drifts like floating point numbers do.
+ *
+ * Serialized as `{ amount: '12.34', currency: 'USD' }`: amounts are decimal strings. Parsing
+ * an amount with more digits than the currency allows fails unless a rounding mode is passed.
+ *
+ * @example
+ * ```typescript
+ * const price = Money.of({ amount: '19.99', currency: 'USD' });
+ * const total = price.multiply({ factor: 3 }).add(Money.of({ amount: 5, currency: 'USD' }));
+ * total.toJSON(); // { amount: '64.97', currency: 'USD' }
+ *
+ * // VAT 8.25%, rounded half even
+ * price.multiply({ factor: '0.0825', rounding: RoundingModes.HALF_EVEN }).amount; // '1.65'
+ *
+ * // Split without losing a cent
+ * Money.of({ amount: '100', currency: 'USD' }).allocate({ ratios: [1, 1, 1] });
+ * // => 33.34, 33.33, 33.33
+ * ```
+ */
+export class Money {
+  readonly minor: bigint;
+  readonly currency: string;
+
+  private constructor(opts: { minor: bigint; currency: string }) {
+    this.minor = opts.minor;
+    this.currency = opts.currency;
+  }
+
+  static getDecimals(currency: string): number {
+    const decimals = Currencies.getDecimals(currency);
+
+    if (decimals === undefined) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: MoneyErrorCodes.INVALID_CURRENCY,
+        message: `[Money] Unsupported currency | received: ${currency}`,
+      });
+    }
+
+    return decimals;
+  }
+
+  // --------------------------------------------------------
+  static of(opts: { amount: TDecimalInput; currency: string; rounding?: TRoundingMode }): Money {
+    const { amount, currency, rounding } = opts;
+    const decimals = Money.getDecimals(currency);
+    const { digits, scale } = parseDecimal(amount);
+
+    if (scale <= decimals) {
+      return new Money({ minor: digits * TEN ** BigInt(decimals - scale), currency });
+    }
+
+    if (!rounding) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: MoneyErrorCodes.INVALID_AMOUNT,
+        message: `[Money] Amount has more than ${decimals} decimals | currency: ${currency} | received: ${amount}`,
+      });
+    }
+
+    const minor = divideRounded(digits, TEN ** BigInt(scale - decimals), rounding);
+    return new Money({ minor, currency });
+  }
+
+  static fromMinor(opts: { minor: bigint | number | string; currency: string }): Money {
+    Money.getDecimals(opts.currency);
+
+    const { digits, scale } = parseDecimal(opts.minor.toString());
+    if (scale > 0) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: MoneyErrorCodes.INVALID_AMOUNT,
+        message: `[Money] Minor units must be an integer | received: ${opts.minor}`,
+      });
+    }
+
+    return new Money({ minor: digits, currency: opts.currency });
+  }
+
+  static zero(opts: { currency: string }): Money {
+    return Money.fromMinor({ minor: ZERO, currency: opts.currency });
+  }
+
+  static fromJSON(value: unknown): Money {
+    const { amount, currency } = (value ?? {}) as Partial<IMoneyJSON>;
+
+    if (typeof amount !== 'string' || typeof currency !== 'string') {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: MoneyErrorCodes.INVALID_AMOUNT,
+        message: '[Money] Invalid money | expected: { amount: string, currency: string }',
+      });
+    }
+
+    return Money.of({ amount, currency });
+  }
+
+  static sum(opts: { values: Array<Money>; currency: string }): Money {
+    return opts.values.reduce((total, value) => total.add(value), Money.zero(opts));
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Decimal string of the amount, e.g. `'12.30'`, `'-0.05'`, `'1000'` in JPY.
+   */
+  get amount(): string {
+    const decimals = Money.getDecimals(this.currency);
+    const isNegative = this.minor < ZERO;
+    const digits = (isNegative ? -this.minor : this.minor).toString().padStart(decimals + 1, '0');
+
+    const integer = digits.slice(0, digits.length - decimals);
+    const fraction = decimals > 0 ? `.${digits.slice(digits.length - decimals)}` : '';
+    return `${isNegative ? '-' : ''}${integer}${fraction}`;
+  }
+
+  private assertSameCurrency(other: Money) {
+    if (other.currency !== this.currency) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: MoneyErrorCodes.CURRENCY_MISMATCH,
+        message: `[Money] Currency mismatch | expected: ${this.currency} | received: ${other.currency}`,
+      });
+    }
+  }
+
+  private with(minor: bigint): Money {
+    return new Money({ minor, currency: this.currency });
+  }
+
+  // --------------------------------------------------------
+  add(other: Money): Money {
+    this.assertSameCurrency(other);
+    return this.with(this.minor + other.minor);
+  }
+
+  subtract(other: Money): Money {
+    this.assertSameCurrency(other);
+    return this.with(this.minor - other.minor);
+  }
+
+  /**
+   * Multiply by an exact decimal factor, rounded to minor units (half up by default).
+   */
+  multiply(opts: { factor: TDecimalInput; rounding?: TRoundingMode }): Money {
+    const { digits, scale } = parseDecimal(opts.factor);
+    const rounding = opts.rounding ?? RoundingModes.HALF_UP;
+
+    return this.with(divideRounded(this.minor * digits, TEN ** BigInt(scale), rounding));
+  }
+
+  /**
+   * Split by ratios without losing minor units, the remainder goes one unit at a time to the
+   * first parts.
+   */
+  allocate(opts: { ratios: Array<number> }): Array<Money> {
+    const ratios = opts.ratios.map(ratio => parseDecimal(ratio));
+    const scale = Math.max(0, ...ratios.map(ratio => ratio.scale));
+    const weights = ratios.map(ratio => ratio.digits * TEN ** BigInt(scale - ratio.scale));
+    const total = weights.reduce((sum, weight) => sum + weight, ZERO);
+
+    if (!weights.length || total <= ZERO || weights.some(weight => weight < ZERO)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: MoneyErrorCodes.INVALID_AMOUNT,
+        message: `[Money] Invalid allocation ratios | received: ${opts.ratios.join(', ')}`,
+      });
+    }
+
+    const parts = weights.map(weight => (this.minor * weight) / total);
+    let remainder = this.minor - parts.reduce((sum, part) => sum + part, ZERO);
+    const step = remainder < ZERO ? -ONE : ONE;
+
+    for (let i = 0; remainder !== ZERO; i = (i + 1) % parts.length) {
+      if (weights[i] === ZERO) {
+        continue;
+      }
+
+      parts[i] += step;
+      remainder -= step;
+    }
+
+    return parts.map(part => this.with(part));
+  }
+
+  split(opts: { parts: number }): Array<Money> {
+    return this.allocate({ ratios: Array.from({ length: opts.parts }, () => 1) });
+  }
+
+  negate(): Money {
+    return this.with(-this.minor);
+  }
+
+  abs(): Money {
+    return this.minor < ZERO ? this.negate() : this;
+  }
+
+  // --------------------------------------------------------
+  compare(other: Money): -1 | 0 | 1 {
+    this.assertSameCurrency(other);
+
+    if (this.minor === other.minor) {
+      return 0;
+    }
+    return this.minor < other.minor ? -1 : 1;
+  }
+
+  equals(other: Money): boolean {
+    return other.currency === this.currency && other.minor === this.minor;
+  }
+
+  isZero(): boolean {
+    return this.minor === ZERO;
+  }
+
+  isNegative(): boolean {
+    return this.minor < ZERO;
+  }
+
+  isPositive(): boolean {
+    return this.minor > ZERO;
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Localized display string, e.g. `$1,234.50`, not meant to be parsed back.
+   */
+  format(opts: { locale?: string } = {}): string {
+    return new Intl.NumberFormat(opts.locale ?? 'en-US', {
+      style: 'currency',
+      currency: this.currency,
+      minimumFractionDigits: Money.getDecimals(this.currency),
+    }).format(Number(this.amount));
+  }
+
+  toJSON(): IMoneyJSON {
+    return { amount: this.amount, currency: this.currency };
+  }
+
+  toString(): string {
+    return `${this.amount} ${this.currency}`;
+  }
+}
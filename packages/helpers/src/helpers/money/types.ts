@@ -0,0 +1,10 @@
+// Decimal strings are exact, numbers are read from their shortest representation
+export type TDecimalInput = string | number;
+
+// API format of money values
+export interface IMoneyJSON {
+  // Decimal string with the currency decimals, e.g. `'12.30'`
+  amount: string;
+  // ISO 4217 code, e.g. `USD`
+  currency: string;
+}
@@ -0,0 +1,60 @@
+import { TConstValue } from '@/common/types';
+
+export class ServiceDiscoveryDefaults {
+  // `service://order-service/api` is resolved to one endpoint of `order-service` + `/api`
+  static readonly SCHEME = 'service://';
+  // `srv://orders.internal/api` is resolved from the SRV records of `orders.internal` + `/api`
+  static readonly SRV_SCHEME = 'srv://';
+  // Milliseconds between two resolutions of a watched service
+  static readonly REFRESH_INTERVAL = 30_000;
+  // Milliseconds an ejected endpoint is left out of the rotation
+  static readonly EJECT_DURATION = 30_000;
+  // Consecutive failed requests before an endpoint is ejected
+  static readonly FAILURE_THRESHOLD = 5;
+}
+
+export class LoadBalancingStrategies {
+  // Weighted rotation over the endpoints
+  static readonly ROUND_ROBIN = 'round_robin';
+  // Endpoint with the fewest requests in flight for its weight, e.g. for uneven response times
+  static readonly LEAST_IN_FLIGHT = 'least_in_flight';
+
+  static readonly SCHEME_SET = new Set([this.ROUND_ROBIN, this.LEAST_IN_FLIGHT]);
+
+  static isValid(strategy: string): boolean {
+    return this.SCHEME_SET.has(strategy);
+  }
+}
+
+export type TLoadBalancingStrategy = TConstValue<typeof LoadBalancingStrategies>;
+
+export class ServiceDiscoveryErrorCodes {
+  static readonly NO_ENDPOINT = 'SERVICE_DISCOVERY_NO_ENDPOINT';
+}
+
+export class RegionSelectorDefaults {
+  // Path probed on every region base url
+  static readonly HEALTH_PATH = '/health';
+  // Milliseconds between two probe rounds
+  static readonly PROBE_INTERVAL = 30_000;
+  static readonly PROBE_TIMEOUT = 2_000;
+  // Consecutive failed probes before a region is unhealthy
+  static readonly UNHEALTHY_THRESHOLD = 2;
+  // Relative latency gain needed to leave the selected region, 0.2 => 20% faster
+  static readonly SWITCH_THRESHOLD = 0.2;
+  // Weight of the last probe in the smoothed latency
+  static readonly SMOOTHING = 0.3;
+}
+
+export class DownstreamProberErrorCodes {
+  static readonly OPEN = 'DOWNSTREAM_PROBER_OPEN';
+}
+
+export class DownstreamProberDefaults {
+  static readonly PROBE_INTERVAL = 10_000;
+  static readonly PROBE_TIMEOUT = 2_000;
+  // Consecutive failed probes before the downstream is open
+  static readonly UNHEALTHY_THRESHOLD = 2;
+  // Consecutive successful probes before it is closed again
+  static readonly HEALTHY_THRESHOLD = 1;
+}
@@ -0,0 +1,375 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import {
+  LoadBalancingStrategies,
+  ServiceDiscoveryDefaults,
+  ServiceDiscoveryErrorCodes,
+  TLoadBalancingStrategy,
+} from './constants';
+import { DnsSrvServiceResolver } from './resolvers';
+import { IServiceEndpoint, IServiceResolver } from './types';
+
+interface IServiceState {
+  endpoints: Array<IServiceEndpoint>;
+  resolvedAt: number;
+  cursor: number;
+  // Ejected endpoint url => epoch milliseconds it is picked again
+  ejected: Map<string, number>;
+  // Endpoint url => requests in flight / consecutive failed requests
+  inFlight: Map<string, number>;
+  failures: Map<string, number>;
+  refreshing?: Promise<Array<IServiceEndpoint>>;
+}
+
+// --------------------------------------------------------
+/**
+ * Resolves logical service names to endpoints and keeps them fresh.
+ *
+ * Watched services are resolved again every `refreshInterval`, a failed refresh keeps the
+ * last known endpoints so a discovery outage does not take the callers down. Endpoints are
+ * picked by weighted round robin or, with `least_in_flight`, by their requests in flight. Network
+ * requests use it for `service://<name>` base urls.
+ *
+ * Fetchers given the discovery report every request to a picked endpoint, see `acquire`. After
+ * `failureThreshold` consecutive failures (errors, 5xx) the endpoint is ejected for
+ * `ejectDuration`, then it is picked again on probation: its next failure ejects it again, a
+ * success closes the count.
+ *
+ * `srv://<name>` base urls are resolved from DNS SRV records with `srvResolver` instead. The
+ * lowest priority is picked first and ejected endpoints fail over to the next ones. Node does
+ * not expose the TTL of SRV records, endpoints older than `refreshInterval` are resolved again
+ * on the next pick.
+ *
+ * @example
+ * ```typescript
+ * const discovery = new ServiceDiscovery({ resolver: new ConsulServiceResolver() });
+ * await discovery.watch({ services: ['order-service'] });
+ *
+ * // Static endpoints, balanced by their requests in flight
+ * const replicas = new ServiceDiscovery({
+ *   resolver: new StaticServiceResolver({
+ *     services: { search: ['http://10.0.4.1:9200', 'http://10.0.4.2:9200'] },
+ *   }),
+ *   strategy: LoadBalancingStrategies.LEAST_IN_FLIGHT,
+ * });
+ *
+ * const request = new NodeFetchNetworkRequest({
+ *   name: 'OrderServiceRequest',
+ *   discovery,
+ *   networkOptions: { baseUrl: 'service://order-service/api' },
+ * });
+ * request.getRequestUrl({ paths: ['orders'] }); // http://10.0.3.12:8080/api/orders
+ *
+ * // _http._tcp.orders.internal
+ * await discovery.refresh({ service: 'srv://orders.internal' });
+ * discovery.resolveUrl({ url: 'srv://orders.internal/api/orders' });
+ * ```
+ */
+export class ServiceDiscovery extends BaseHelper {
+  private resolver: IServiceResolver;
+  private srvResolver: IServiceResolver;
+  private refreshInterval: number;
+  private strategy: TLoadBalancingStrategy;
+  private failureThreshold: number;
+  private ejectDuration: number;
+  private states = new Map<string, IServiceState>();
+  private timer?: ReturnType<typeof setInterval>;
+
+  constructor(opts: {
+    resolver: IServiceResolver;
+    // Resolver of `srv://` services, defaults to `DnsSrvServiceResolver`
+    srvResolver?: IServiceResolver;
+    refreshInterval?: number;
+    // Defaults to `round_robin`
+    strategy?: TLoadBalancingStrategy;
+    failureThreshold?: number;
+    ejectDuration?: number;
+    identifier?: string;
+  }) {
+    super({ scope: ServiceDiscovery.name, identifier: opts.identifier ?? opts.resolver.name });
+
+    this.resolver = opts.resolver;
+    this.srvResolver = opts.srvResolver ?? new DnsSrvServiceResolver();
+    this.refreshInterval = opts.refreshInterval ?? ServiceDiscoveryDefaults.REFRESH_INTERVAL;
+    this.strategy = opts.strategy ?? LoadBalancingStrategies.ROUND_ROBIN;
+    this.failureThreshold = opts.failureThreshold ?? ServiceDiscoveryDefaults.FAILURE_THRESHOLD;
+    this.ejectDuration = opts.ejectDuration ?? ServiceDiscoveryDefaults.EJECT_DURATION;
+  }
+
+  // --------------------------------------------------------
+  static isServiceUrl(url?: string): boolean {
+    return (
+      !!url?.startsWith(ServiceDiscoveryDefaults.SCHEME) ||
+      !!url?.startsWith(ServiceDiscoveryDefaults.SRV_SCHEME)
+    );
+  }
+
+  static isSrvService(service: string): boolean {
+    return service.startsWith(ServiceDiscoveryDefaults.SRV_SCHEME);
+  }
+
+  /**
+   * `service://order-service/api` => `{ service: 'order-service', path: '/api' }`
+   *
+   * `srv://orders.internal/api` => `{ service: 'srv://orders.internal', path: '/api' }`
+   */
+  static parseServiceUrl(url: string) {
+    const isSrv = ServiceDiscovery.isSrvService(url);
+    const scheme = isSrv ? ServiceDiscoveryDefaults.SRV_SCHEME : ServiceDiscoveryDefaults.SCHEME;
+
+    const rest = url.slice(scheme.length);
+    const index = rest.indexOf('/');
+    const name = index < 0 ? rest : rest.slice(0, index);
+    const path = index < 0 ? '' : rest.slice(index).replace(/\/+$/, '');
+
+    // SRV services keep their scheme, they never share a state with the names of `resolver`
+    return { service: isSrv ? `${scheme}${name}` : name, path };
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Resolve the services now then refresh them periodically.
+   */
+  async watch(opts: { services: Array<string> }) {
+    await Promise.all(opts.services.map(service => this.refresh({ service })));
+
+    if (!this.timer) {
+      this.timer = setInterval(() => {
+        for (const service of this.states.keys()) {
+          this.refresh({ service }).catch(() => {});
+        }
+      }, this.refreshInterval);
+      this.timer.unref?.();
+    }
+  }
+
+  stop() {
+    if (this.timer) {
+      clearInterval(this.timer);
+      this.timer = undefined;
+    }
+  }
+
+  async refresh(opts: { service: string }): Promise<Array<IServiceEndpoint>> {
+    const { service } = opts;
+
+    let state = this.states.get(service);
+    if (!state) {
+      state = {
+        endpoints: [],
+        resolvedAt: 0,
+        cursor: 0,
+        ejected: new Map(),
+        inFlight: new Map(),
+        failures: new Map(),
+      };
+      this.states.set(service, state);
+    }
+
+    if (state.refreshing) {
+      return state.refreshing;
+    }
+
+    const isSrv = ServiceDiscovery.isSrvService(service);
+    const resolver = isSrv ? this.srvResolver : this.resolver;
+    const name = isSrv ? service.slice(ServiceDiscoveryDefaults.SRV_SCHEME.length) : service;
+
+    const current = state;
+    current.refreshing = resolver
+      .resolve({ service: name })
+      .then(endpoints => {
+        current.endpoints = endpoints;
+        current.resolvedAt = Date.now();
+        return endpoints;
+      })
+      .catch(error => {
+        this.logger
+          .for(this.refresh.name)
+          .error(
+            'Failed to resolve service, keeping %s known endpoints | service: %s | Error: %s',
+            current.endpoints.length,
+            service,
+            error,
+          );
+        return current.endpoints;
+      })
+      .finally(() => {
+        current.refreshing = undefined;
+      });
+
+    return current.refreshing;
+  }
+
+  // --------------------------------------------------------
+  getEndpoints(opts: { service: string }): Array<IServiceEndpoint> {
+    return this.states.get(opts.service)?.endpoints ?? [];
+  }
+
+  /**
+   * Take an endpoint out of the rotation for `duration`, e.g. after a connection failure, its
+   * traffic fails over to the other endpoints of the service.
+   */
+  eject(opts: { service: string; url: string; duration?: number }) {
+    const { service, url, duration = this.ejectDuration } = opts;
+    const state = this.states.get(service);
+    if (!state) {
+      return;
+    }
+
+    state.ejected.set(url, Date.now() + duration);
+    this.logger
+      .for(this.eject.name)
+      .warn('Endpoint ejected | service: %s | url: %s | duration: %dms', service, url, duration);
+  }
+
+  /**
+   * Count a request to an endpoint of a watched service as in flight, `url` being the full
+   * request url.
+   *
+   * @returns the release of the request, `undefined` when `url` is not on a known endpoint
+   */
+  acquire(opts: { url: string }): ((opts: { isFailure: boolean }) => void) | undefined {
+    const match = this.findEndpoint(opts);
+    if (!match) {
+      return undefined;
+    }
+
+    const { service, state, endpoint } = match;
+    const { url } = endpoint;
+    state.inFlight.set(url, (state.inFlight.get(url) ?? 0) + 1);
+
+    let isReleased = false;
+    return ({ isFailure }) => {
+      if (isReleased) {
+        return;
+      }
+
+      isReleased = true;
+      state.inFlight.set(url, Math.max((state.inFlight.get(url) ?? 1) - 1, 0));
+
+      if (!isFailure) {
+        state.failures.delete(url);
+        return;
+      }
+
+      const failures = (state.failures.get(url) ?? 0) + 1;
+      state.failures.set(url, failures);
+
+      const isEjected = (state.ejected.get(url) ?? 0) > Date.now();
+      if (failures >= this.failureThreshold && !isEjected) {
+        this.eject({ service, url });
+      }
+    };
+  }
+
+  getInFlight(opts: { service: string; url: string }): number {
+    return this.states.get(opts.service)?.inFlight.get(opts.url) ?? 0;
+  }
+
+  /**
+   * Pick an endpoint of a watched service by the strategy of the discovery, among the endpoints
+   * of the lowest priority which are not ejected.
+   */
+  pick(opts: { service: string }): IServiceEndpoint {
+    const { service } = opts;
+    const state = this.states.get(service);
+
+    if (!state || !state.endpoints.length) {
+      const resolver = ServiceDiscovery.isSrvService(service) ? this.srvResolver : this.resolver;
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+        messageCode: ServiceDiscoveryErrorCodes.NO_ENDPOINT,
+        message: `[ServiceDiscovery] No endpoint available | service: ${service} | resolver: ${resolver.name}`,
+      });
+    }
+
+    if (!state.refreshing && Date.now() - state.resolvedAt > this.refreshInterval) {
+      this.refresh({ service }).catch(() => {});
+    }
+
+    const endpoints = this.getCandidates(state);
+    if (this.strategy === LoadBalancingStrategies.LEAST_IN_FLIGHT) {
+      return this.pickLeastInFlight({ state, endpoints });
+    }
+
+    const total = endpoints.reduce((sum, el) => sum + Math.max(el.weight ?? 1, 0), 0);
+    if (total <= 0) {
+      return endpoints[state.cursor++ % endpoints.length];
+    }
+
+    let slot = state.cursor++ % total;
+    for (const endpoint of endpoints) {
+      slot -= Math.max(endpoint.weight ?? 1, 0);
+      if (slot < 0) {
+        return endpoint;
+      }
+    }
+
+    return endpoints[0];
+  }
+
+  /**
+   * Resolve a `service://` or `srv://` url against a picked endpoint, other urls are returned
+   * as is.
+   */
+  resolveUrl(opts: { url: string }): string {
+    const { url } = opts;
+    if (!ServiceDiscovery.isServiceUrl(url)) {
+      return url;
+    }
+
+    const { service, path } = ServiceDiscovery.parseServiceUrl(url);
+    return `${this.pick({ service }).url.replace(/\/+$/, '')}${path}`;
+  }
+
+  // --------------------------------------------------------
+  // Fewest requests in flight per unit of weight, the rotating start spreads the ties
+  private pickLeastInFlight(opts: {
+    state: IServiceState;
+    endpoints: Array<IServiceEndpoint>;
+  }): IServiceEndpoint {
+    const { state, endpoints } = opts;
+    const start = state.cursor++ % endpoints.length;
+
+    let rs = endpoints[start];
+    let lowest = Infinity;
+    for (let offset = 0; offset < endpoints.length; offset++) {
+      const endpoint = endpoints[(start + offset) % endpoints.length];
+      const weight = Math.max(endpoint.weight ?? 1, 0);
+      const load = weight ? (state.inFlight.get(endpoint.url) ?? 0) / weight : Infinity;
+
+      if (load < lowest) {
+        rs = endpoint;
+        lowest = load;
+      }
+    }
+
+    return rs;
+  }
+
+  private findEndpoint(opts: { url: string }) {
+    for (const [service, state] of this.states) {
+      const endpoint = state.endpoints.find(el => {
+        const base = el.url.replace(/\/+$/, '');
+        return opts.url === base || opts.url.startsWith(`${base}/`);
+      });
+
+      if (endpoint) {
+        return { service, state, endpoint };
+      }
+    }
+
+    return undefined;
+  }
+
+  private getCandidates(state: IServiceState): Array<IServiceEndpoint> {
+    const now = Date.now();
+    const available = state.endpoints.filter(el => (state.ejected.get(el.url) ?? 0) <= now);
+
+    // Every endpoint ejected, trying one beats failing every request
+    const endpoints = available.length ? available : state.endpoints;
+    const priority = Math.min(...endpoints.map(el => el.priority ?? 0));
+    return endpoints.filter(el => (el.priority ?? 0) === priority);
+  }
+}
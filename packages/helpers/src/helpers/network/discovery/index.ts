@@ -0,0 +1,6 @@
+export * from './constants';
+export * from './helper';
+export * from './prober';
+export * from './regions';
+export * from './resolvers';
+export * from './types';
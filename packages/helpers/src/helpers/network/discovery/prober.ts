@@ -0,0 +1,173 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { DownstreamProberDefaults, DownstreamProberErrorCodes } from './constants';
+
+export interface IDownstreamProberState {
+  // Requests are refused while the downstream is open
+  isOpen: boolean;
+  failures: number;
+  successes: number;
+  probedAt?: number;
+  changedAt?: number;
+}
+
+export interface IDownstreamProberOptions {
+  identifier?: string;
+  // Health endpoint of the downstream, e.g. `https://ledger.internal/health`
+  url: string;
+  probeInterval?: number;
+  probeTimeout?: number;
+  unhealthyThreshold?: number;
+  healthyThreshold?: number;
+  onChange?: (opts: { url: string; state: IDownstreamProberState }) => void;
+}
+
+// --------------------------------------------------------
+/**
+ * Background health probe of a downstream which opens and closes its circuit ahead of the user
+ * requests, so an outage is caught by the probes and the recovery is not discovered by the first
+ * request after it.
+ *
+ * The health endpoint is probed each `probeInterval`, a non 5xx answer within `probeTimeout`
+ * counts as a success. The downstream opens after `unhealthyThreshold` consecutive failures and
+ * closes after `healthyThreshold` consecutive successes. Fetchers given the prober refuse their
+ * requests with `DOWNSTREAM_PROBER_OPEN` while it is open, without waiting for a timeout.
+ *
+ * @example
+ * ```typescript
+ * const prober = new DownstreamProber({ url: 'https://ledger.internal/health' });
+ * await prober.start();
+ *
+ * const ledger = new NodeFetchNetworkRequest({
+ *   name: 'LedgerRequest',
+ *   networkOptions: { baseUrl: 'https://ledger.internal' },
+ *   prober,
+ * });
+ * ```
+ */
+export class DownstreamProber extends BaseHelper {
+  private url: string;
+  private probeInterval: number;
+  private probeTimeout: number;
+  private unhealthyThreshold: number;
+  private healthyThreshold: number;
+  private onChange?: IDownstreamProberOptions['onChange'];
+
+  private state: IDownstreamProberState = { isOpen: false, failures: 0, successes: 0 };
+  private timer?: ReturnType<typeof setInterval>;
+  private probing?: Promise<void>;
+
+  constructor(opts: IDownstreamProberOptions) {
+    super({ scope: DownstreamProber.name, identifier: opts.identifier ?? DownstreamProber.name });
+
+    this.url = opts.url;
+    this.probeInterval = opts.probeInterval ?? DownstreamProberDefaults.PROBE_INTERVAL;
+    this.probeTimeout = opts.probeTimeout ?? DownstreamProberDefaults.PROBE_TIMEOUT;
+    this.unhealthyThreshold =
+      opts.unhealthyThreshold ?? DownstreamProberDefaults.UNHEALTHY_THRESHOLD;
+    this.healthyThreshold = opts.healthyThreshold ?? DownstreamProberDefaults.HEALTHY_THRESHOLD;
+    this.onChange = opts.onChange;
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Probe now then every `probeInterval`.
+   */
+  async start() {
+    await this.probe();
+
+    if (!this.timer) {
+      this.timer = setInterval(() => {
+        this.probe().catch(() => {});
+      }, this.probeInterval);
+      this.timer.unref?.();
+    }
+  }
+
+  stop() {
+    if (this.timer) {
+      clearInterval(this.timer);
+      this.timer = undefined;
+    }
+  }
+
+  /**
+   * One probe of the health endpoint, concurrent callers share it.
+   */
+  probe(): Promise<void> {
+    if (!this.probing) {
+      this.probing = this.probeOnce().finally(() => {
+        this.probing = undefined;
+      });
+    }
+
+    return this.probing;
+  }
+
+  // --------------------------------------------------------
+  isOpen() {
+    return this.state.isOpen;
+  }
+
+  getState(): IDownstreamProberState {
+    return { ...this.state };
+  }
+
+  /**
+   * Refuse a request to `url` while the downstream is open.
+   */
+  ensureClosed(opts: { url: string }) {
+    if (!this.state.isOpen) {
+      return;
+    }
+
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+      messageCode: DownstreamProberErrorCodes.OPEN,
+      message: `[DownstreamProber] Downstream is unavailable | health: ${this.url} | url: ${opts.url}`,
+    });
+  }
+
+  // --------------------------------------------------------
+  private async probeOnce() {
+    const state = this.state;
+
+    try {
+      const response = await fetch(this.url, { signal: AbortSignal.timeout(this.probeTimeout) });
+      response.body?.cancel().catch(() => {});
+
+      if (response.status >= 500) {
+        throw new Error(`status ${response.status}`);
+      }
+
+      state.failures = 0;
+      state.successes++;
+      if (state.isOpen && state.successes >= this.healthyThreshold) {
+        this.transition({ isOpen: false });
+      }
+    } catch (error) {
+      state.successes = 0;
+      state.failures++;
+      this.logger
+        .for(this.probeOnce.name)
+        .warn('Probe failed | url: %s | failures: %s | error: %s', this.url, state.failures, error);
+
+      if (!state.isOpen && state.failures >= this.unhealthyThreshold) {
+        this.transition({ isOpen: true });
+      }
+    } finally {
+      state.probedAt = Date.now();
+    }
+  }
+
+  private transition(opts: { isOpen: boolean }) {
+    this.state.isOpen = opts.isOpen;
+    this.state.changedAt = Date.now();
+    this.logger
+      .for(this.transition.name)
+      .info('Downstream %s | url: %s', opts.isOpen ? 'opened' : 'closed', this.url);
+
+    this.onChange?.({ url: this.url, state: this.getState() });
+  }
+}
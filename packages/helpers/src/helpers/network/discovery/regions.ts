@@ -0,0 +1,231 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { RegionSelectorDefaults } from './constants';
+
+export interface IRegionEndpoint {
+  // e.g. `eu-west-1`
+  name: string;
+  // Base url of the deployment in this region
+  url: string;
+}
+
+export interface IRegionState extends IRegionEndpoint {
+  isHealthy: boolean;
+  // Smoothed probe latency in milliseconds, unknown until the first successful probe
+  latency?: number;
+  failures: number;
+  probedAt?: number;
+}
+
+export interface IRegionSelectorOptions {
+  identifier?: string;
+  // Order of preference until the first probe round completes
+  regions: Array<IRegionEndpoint>;
+  healthPath?: string;
+  probeInterval?: number;
+  probeTimeout?: number;
+  unhealthyThreshold?: number;
+  switchThreshold?: number;
+  smoothing?: number;
+}
+
+// --------------------------------------------------------
+/**
+ * Picks the fastest healthy region of a service deployed in several regions.
+ *
+ * Every region is probed on `healthPath` each `probeInterval`, a non 5xx answer within
+ * `probeTimeout` counts as healthy and its latency is smoothed. The selected region is sticky: it
+ * is only left for a region faster by more than `switchThreshold`, or once it is unhealthy, so
+ * latency noise does not bounce the traffic. `reportFailure` fails over right away without
+ * waiting for the next probes.
+ *
+ * @example
+ * ```typescript
+ * const regions = new RegionSelector({
+ *   regions: [
+ *     { name: 'ap-southeast-1', url: 'https://sg.ledger.example.com' },
+ *     { name: 'eu-west-1', url: 'https://eu.ledger.example.com' },
+ *   ],
+ * });
+ * await regions.start();
+ *
+ * const ledger = new NodeFetchNetworkRequest({
+ *   name: 'LedgerRequest',
+ *   regions,
+ *   networkOptions: {},
+ * });
+ * ledger.getRequestUrl({ paths: ['accounts'] }); // https://sg.ledger.example.com/accounts
+ * ```
+ */
+export class RegionSelector extends BaseHelper {
+  private regions: Array<IRegionState>;
+  private healthPath: string;
+  private probeInterval: number;
+  private probeTimeout: number;
+  private unhealthyThreshold: number;
+  private switchThreshold: number;
+  private smoothing: number;
+
+  private selected: IRegionState;
+  private timer?: ReturnType<typeof setInterval>;
+  private probing?: Promise<void>;
+
+  constructor(opts: IRegionSelectorOptions) {
+    super({ scope: RegionSelector.name, identifier: opts.identifier ?? RegionSelector.name });
+
+    if (!opts.regions.length) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: '[RegionSelector] At least one region is required!',
+      });
+    }
+
+    this.regions = opts.regions.map(({ name, url }) => ({
+      name,
+      url: url.replace(/\/+$/, ''),
+      isHealthy: true,
+      failures: 0,
+    }));
+    this.healthPath = opts.healthPath ?? RegionSelectorDefaults.HEALTH_PATH;
+    this.probeInterval = opts.probeInterval ?? RegionSelectorDefaults.PROBE_INTERVAL;
+    this.probeTimeout = opts.probeTimeout ?? RegionSelectorDefaults.PROBE_TIMEOUT;
+    this.unhealthyThreshold = opts.unhealthyThreshold ?? RegionSelectorDefaults.UNHEALTHY_THRESHOLD;
+    this.switchThreshold = opts.switchThreshold ?? RegionSelectorDefaults.SWITCH_THRESHOLD;
+    this.smoothing = opts.smoothing ?? RegionSelectorDefaults.SMOOTHING;
+    this.selected = this.regions[0];
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Probe every region now then every `probeInterval`.
+   */
+  async start() {
+    await this.probe();
+
+    if (!this.timer) {
+      this.timer = setInterval(() => {
+        this.probe().catch(() => {});
+      }, this.probeInterval);
+      this.timer.unref?.();
+    }
+  }
+
+  stop() {
+    if (this.timer) {
+      clearInterval(this.timer);
+      this.timer = undefined;
+    }
+  }
+
+  /**
+   * One probe round of every region, concurrent callers share it.
+   */
+  probe(): Promise<void> {
+    if (!this.probing) {
+      this.probing = Promise.all(this.regions.map(region => this.probeRegion(region)))
+        .then(() => this.select())
+        .finally(() => {
+          this.probing = undefined;
+        });
+    }
+
+    return this.probing;
+  }
+
+  // --------------------------------------------------------
+  getSelected(): IRegionEndpoint {
+    const { name, url } = this.selected;
+    return { name, url };
+  }
+
+  getRegions(): Array<IRegionState> {
+    return this.regions.map(region => ({ ...region }));
+  }
+
+  /**
+   * Mark the region serving `url` unhealthy after a failed request, e.g. a connection error,
+   * and fail over to the next fastest healthy region.
+   */
+  reportFailure(opts: { url: string }) {
+    const region = this.regions.find(el => opts.url.startsWith(el.url));
+    if (!region || !region.isHealthy) {
+      return;
+    }
+
+    region.isHealthy = false;
+    region.failures = Math.max(region.failures, this.unhealthyThreshold);
+    this.select();
+  }
+
+  // --------------------------------------------------------
+  private async probeRegion(region: IRegionState) {
+    const startedAt = performance.now();
+
+    try {
+      const response = await fetch(`${region.url}${this.healthPath}`, {
+        signal: AbortSignal.timeout(this.probeTimeout),
+      });
+      response.body?.cancel().catch(() => {});
+
+      if (response.status >= 500) {
+        throw new Error(`status ${response.status}`);
+      }
+
+      const latency = performance.now() - startedAt;
+      region.latency =
+        region.latency === undefined
+          ? latency
+          : this.smoothing * latency + (1 - this.smoothing) * region.latency;
+      region.failures = 0;
+      region.isHealthy = true;
+    } catch (error) {
+      region.failures++;
+      region.isHealthy = region.failures < this.unhealthyThreshold;
+
+      this.logger
+        .for(this.probeRegion.name)
+        .warn(
+          'Region probe failed | region: %s | failures: %s | error: %s',
+          region.name,
+          region.failures,
+          error,
+        );
+    } finally {
+      region.probedAt = Date.now();
+    }
+  }
+
+  private select() {
+    const healthy = this.regions.filter(el => el.isHealthy);
+    if (!healthy.length) {
+      // Every region down, keep the current one rather than failing every request
+      return;
+    }
+
+    // Regions never probed successfully rank last
+    const best = healthy.reduce((rs, el) =>
+      (el.latency ?? Infinity) < (rs.latency ?? Infinity) ? el : rs,
+    );
+
+    const current = this.selected;
+    const isSticky =
+      current.isHealthy &&
+      (best.latency === undefined ||
+        (current.latency ?? Infinity) <= best.latency * (1 + this.switchThreshold));
+    if (isSticky || best === current) {
+      return;
+    }
+
+    this.selected = best;
+    this.logger
+      .for(this.select.name)
+      .info(
+        'Region switched | from: %s (%s) | to: %s | latency: %sms',
+        current.name,
+        current.isHealthy ? 'slower' : 'unhealthy',
+        best.name,
+        Math.round(best.latency ?? 0),
+      );
+  }
+}
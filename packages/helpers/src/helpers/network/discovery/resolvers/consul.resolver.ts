@@ -0,0 +1,88 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import { IServiceEndpoint, IServiceResolver } from '../types';
+
+interface IConsulHealthEntry {
+  Node: { Address: string };
+  Service: {
+    ID: string;
+    Address: string;
+    Port: number;
+    Tags?: Array<string> | null;
+    Meta?: Record<string, string> | null;
+    Weights?: { Passing: number; Warning: number };
+  };
+}
+
+// --------------------------------------------------------
+/**
+ * Resolves services from the Consul health API, only instances passing their checks are
+ * returned unless `isPassingOnly` is false.
+ *
+ * @example
+ * ```typescript
+ * new ConsulServiceResolver({ url: 'http://consul.internal:8500', token: env.CONSUL_TOKEN });
+ * ```
+ */
+export class ConsulServiceResolver implements IServiceResolver {
+  readonly name = 'consul';
+  private url: string;
+  private token?: string;
+  private datacenter?: string;
+  private tag?: string;
+  private scheme: string;
+  private isPassingOnly: boolean;
+
+  constructor(
+    opts: {
+      url?: string;
+      token?: string;
+      datacenter?: string;
+      // Only instances with this tag, e.g. `v2`
+      tag?: string;
+      scheme?: string;
+      isPassingOnly?: boolean;
+    } = {},
+  ) {
+    this.url = (opts.url ?? 'http://127.0.0.1:8500').replace(/\/+$/, '');
+    this.token = opts.token;
+    this.datacenter = opts.datacenter;
+    this.tag = opts.tag;
+    this.scheme = opts.scheme ?? 'http';
+    this.isPassingOnly = opts.isPassingOnly ?? true;
+  }
+
+  async resolve(opts: { service: string }): Promise<Array<IServiceEndpoint>> {
+    const query = new URLSearchParams();
+    if (this.isPassingOnly) {
+      query.set('passing', 'true');
+    }
+
+    if (this.datacenter) {
+      query.set('dc', this.datacenter);
+    }
+
+    if (this.tag) {
+      query.set('tag', this.tag);
+    }
+
+    const rs = await fetch(
+      `${this.url}/v1/health/service/${encodeURIComponent(opts.service)}?${query}`,
+      { headers: this.token ? { 'x-consul-token': this.token } : {} },
+    );
+    if (!rs.ok) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+        message: `[ConsulServiceResolver] Failed to resolve service | service: ${opts.service} | status: ${rs.status}`,
+      });
+    }
+
+    const entries = (await rs.json()) as Array<IConsulHealthEntry>;
+    return entries.map(entry => ({
+      // The service address falls back to the node address when not set
+      url: `${this.scheme}://${entry.Service.Address || entry.Node.Address}:${entry.Service.Port}`,
+      weight: entry.Service.Weights?.Passing ?? 1,
+      metadata: { id: entry.Service.ID, tags: entry.Service.Tags ?? [], ...entry.Service.Meta },
+    }));
+  }
+}
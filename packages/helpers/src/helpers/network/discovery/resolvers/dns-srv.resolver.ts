@@ -0,0 +1,53 @@
+import dns from 'node:dns/promises';
+import { IServiceEndpoint, IServiceResolver } from '../types';
+
+// --------------------------------------------------------
+/**
+ * Resolves services from DNS SRV records, e.g. Consul DNS or Kubernetes headless services.
+ * Every record is returned with its priority, `ServiceDiscovery` picks the lowest priority and
+ * fails over to the higher ones (RFC 2782).
+ *
+ * @example
+ * ```typescript
+ * // order-service => _http._tcp.order-service.default.svc.cluster.local
+ * new DnsSrvServiceResolver({ domain: 'default.svc.cluster.local' });
+ * ```
+ */
+export class DnsSrvServiceResolver implements IServiceResolver {
+  readonly name = 'dns-srv';
+  private scheme: string;
+  private portName: string;
+  private protocol: string;
+  private domain?: string;
+
+  constructor(
+    opts: { scheme?: string; portName?: string; protocol?: string; domain?: string } = {},
+  ) {
+    this.scheme = opts.scheme ?? 'http';
+    this.portName = opts.portName ?? 'http';
+    this.protocol = opts.protocol ?? 'tcp';
+    this.domain = opts.domain;
+  }
+
+  getRecordName(opts: { service: string }) {
+    const { service } = opts;
+
+    // Already a full SRV name
+    if (service.startsWith('_')) {
+      return service;
+    }
+
+    const host = this.domain ? `${service}.${this.domain}` : service;
+    return `_${this.portName}._${this.protocol}.${host}`;
+  }
+
+  async resolve(opts: { service: string }): Promise<Array<IServiceEndpoint>> {
+    const records = await dns.resolveSrv(this.getRecordName(opts));
+    return records.map(record => ({
+      url: `${this.scheme}://${record.name.replace(/\.$/, '')}:${record.port}`,
+      weight: record.weight || 1,
+      priority: record.priority,
+      metadata: { priority: record.priority },
+    }));
+  }
+}
@@ -0,0 +1,4 @@
+export * from './consul.resolver';
+export * from './dns-srv.resolver';
+export * from './kubernetes.resolver';
+export * from './static.resolver';
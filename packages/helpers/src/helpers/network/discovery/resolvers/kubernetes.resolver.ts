@@ -0,0 +1,119 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import fs from 'node:fs';
+import { IServiceEndpoint, IServiceResolver } from '../types';
+
+const SERVICE_ACCOUNT_PATH = '/var/run/secrets/kubernetes.io/serviceaccount';
+
+interface IEndpointSliceList {
+  items: Array<{
+    ports?: Array<{ name?: string; port?: number }> | null;
+    endpoints?: Array<{
+      addresses: Array<string>;
+      conditions?: { ready?: boolean | null };
+      targetRef?: { name?: string };
+      zone?: string;
+    }> | null;
+  }>;
+}
+
+const readServiceAccountFile = (name: string) => {
+  try {
+    return fs.readFileSync(`${SERVICE_ACCOUNT_PATH}/${name}`, 'utf-8').trim();
+  } catch {
+    return undefined;
+  }
+};
+
+// --------------------------------------------------------
+/**
+ * Resolves ready pods of a Kubernetes service from its EndpointSlices, bypassing the service
+ * virtual IP so requests can be balanced per pod. Runs in cluster with the pod service account
+ * by default, the role needs `list` on `endpointslices`.
+ *
+ * @example
+ * ```typescript
+ * new KubernetesServiceResolver({ portName: 'http' });
+ * ```
+ */
+export class KubernetesServiceResolver implements IServiceResolver {
+  readonly name = 'kubernetes';
+  private apiUrl: string;
+  private namespace: string;
+  private token?: string;
+  private ca?: string;
+  private portName?: string;
+  private scheme: string;
+
+  constructor(
+    opts: {
+      // Defaults to the in cluster API server
+      apiUrl?: string;
+      // Defaults to the namespace of the pod
+      namespace?: string;
+      token?: string;
+      ca?: string;
+      // Port of the slice to use, defaults to the first one
+      portName?: string;
+      scheme?: string;
+    } = {},
+  ) {
+    const host = process.env.KUBERNETES_SERVICE_HOST;
+    const port = process.env.KUBERNETES_SERVICE_PORT ?? '443';
+
+    this.apiUrl = opts.apiUrl ?? `https://${host}:${port}`;
+    this.namespace = opts.namespace ?? readServiceAccountFile('namespace') ?? 'default';
+    this.token = opts.token ?? readServiceAccountFile('token');
+    this.ca = opts.ca ?? readServiceAccountFile('ca.crt');
+    this.portName = opts.portName;
+    this.scheme = opts.scheme ?? 'http';
+  }
+
+  async resolve(opts: { service: string }): Promise<Array<IServiceEndpoint>> {
+    const { service } = opts;
+    const selector = encodeURIComponent(`kubernetes.io/service-name=${service}`);
+    const path = `/apis/discovery.k8s.io/v1/namespaces/${this.namespace}/endpointslices`;
+    const url = `${this.apiUrl}${path}?labelSelector=${selector}`;
+
+    // `tls` is the Bun fetch option for custom certificate authorities
+    const rs = await fetch(url, {
+      headers: this.token ? { authorization: `Bearer ${this.token}` } : {},
+      ...(this.ca ? { tls: { ca: this.ca } } : {}),
+    } as RequestInit);
+
+    if (!rs.ok) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+        message: `[KubernetesServiceResolver] Failed to resolve service | service: ${service} | status: ${rs.status}`,
+      });
+    }
+
+    const { items } = (await rs.json()) as IEndpointSliceList;
+    const endpoints: Array<IServiceEndpoint> = [];
+
+    for (const slice of items) {
+      const port = (
+        this.portName ? slice.ports?.find(el => el.name === this.portName) : slice.ports?.[0]
+      )?.port;
+      if (!port) {
+        continue;
+      }
+
+      for (const endpoint of slice.endpoints ?? []) {
+        // A missing condition means ready
+        if (endpoint.conditions?.ready === false) {
+          continue;
+        }
+
+        for (const address of endpoint.addresses) {
+          endpoints.push({
+            url: `${this.scheme}://${address.includes(':') ? `[${address}]` : address}:${port}`,
+            metadata: { pod: endpoint.targetRef?.name, zone: endpoint.zone },
+          });
+        }
+      }
+    }
+
+    return endpoints;
+  }
+}
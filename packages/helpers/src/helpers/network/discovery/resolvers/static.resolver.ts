@@ -0,0 +1,26 @@
+import { IServiceEndpoint, IServiceResolver } from '../types';
+
+// --------------------------------------------------------
+/**
+ * Fixed endpoints per service, for local development and tests.
+ *
+ * @example
+ * ```typescript
+ * new StaticServiceResolver({
+ *   services: { 'order-service': ['http://localhost:3001', 'http://localhost:3002'] },
+ * });
+ * ```
+ */
+export class StaticServiceResolver implements IServiceResolver {
+  readonly name = 'static';
+  private services: Record<string, Array<string | IServiceEndpoint>>;
+
+  constructor(opts: { services: Record<string, Array<string | IServiceEndpoint>> }) {
+    this.services = opts.services;
+  }
+
+  async resolve(opts: { service: string }): Promise<Array<IServiceEndpoint>> {
+    const endpoints = this.services[opts.service] ?? [];
+    return endpoints.map(el => (typeof el === 'string' ? { url: el } : el));
+  }
+}
@@ -0,0 +1,17 @@
+import { AnyObject } from '@/common/types';
+
+// --------------------------------------------------------
+export interface IServiceEndpoint {
+  // Base url of one instance, e.g. `http://10.0.3.12:8080`
+  url: string;
+  // Relative share of the traffic, defaults to 1
+  weight?: number;
+  // Lower priorities are picked first, higher ones only when all lower endpoints are ejected
+  priority?: number;
+  metadata?: AnyObject;
+}
+
+export interface IServiceResolver {
+  name: string;
+  resolve(opts: { service: string }): Promise<Array<IServiceEndpoint>>;
+}
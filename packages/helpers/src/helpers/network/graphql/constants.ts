@@ -0,0 +1,79 @@
+import { TConstValue } from '@/common/types';
+
+export class GraphQLWsDefaults {
+  // WebSocket sub-protocol of the `graphql-ws` library
+  static readonly PROTOCOL = 'graphql-transport-ws';
+  // Milliseconds the server has to acknowledge `connection_init`
+  static readonly CONNECTION_ACK_TIMEOUT = 10_000;
+  // Milliseconds between two pings, a ping left without pong terminates the connection
+  static readonly KEEP_ALIVE = 15_000;
+  static readonly RECONNECT_BASE_DELAY = 500;
+  static readonly RECONNECT_MAX_DELAY = 30_000;
+}
+
+export class GraphQLWsMessageTypes {
+  static readonly CONNECTION_INIT = 'connection_init';
+  static readonly CONNECTION_ACK = 'connection_ack';
+  static readonly PING = 'ping';
+  static readonly PONG = 'pong';
+  static readonly SUBSCRIBE = 'subscribe';
+  static readonly NEXT = 'next';
+  static readonly ERROR = 'error';
+  static readonly COMPLETE = 'complete';
+
+  static readonly SCHEME_SET = new Set([
+    this.CONNECTION_INIT,
+    this.CONNECTION_ACK,
+    this.PING,
+    this.PONG,
+    this.SUBSCRIBE,
+    this.NEXT,
+    this.ERROR,
+    this.COMPLETE,
+  ]);
+
+  static isValid(input: string): input is TGraphQLWsMessageType {
+    return this.SCHEME_SET.has(input);
+  }
+}
+
+export type TGraphQLWsMessageType = TConstValue<typeof GraphQLWsMessageTypes>;
+
+export class GraphQLWsCloseCodes {
+  static readonly NORMAL = 1000;
+  static readonly INTERNAL_ERROR = 4500;
+  static readonly BAD_REQUEST = 4400;
+  // Sent by the client for a message it cannot read
+  static readonly BAD_RESPONSE = 4004;
+  static readonly UNAUTHORIZED = 4401;
+  static readonly FORBIDDEN = 4403;
+  static readonly SUBPROTOCOL_NOT_ACCEPTABLE = 4406;
+  static readonly CONNECTION_INIT_TIMEOUT = 4408;
+  static readonly SUBSCRIBER_ALREADY_EXISTS = 4409;
+  static readonly TOO_MANY_INITIALISATION_REQUESTS = 4429;
+  // Sent by the client when `connection_ack` does not arrive in time
+  static readonly CONNECTION_ACK_TIMEOUT = 4504;
+  // Sent by the client after a missed pong
+  static readonly TERMINATED = 4499;
+
+  // Reconnecting would be rejected again
+  static readonly FATAL_SET = new Set<number>([
+    this.INTERNAL_ERROR,
+    this.BAD_REQUEST,
+    this.BAD_RESPONSE,
+    this.UNAUTHORIZED,
+    this.FORBIDDEN,
+    this.SUBPROTOCOL_NOT_ACCEPTABLE,
+    this.SUBSCRIBER_ALREADY_EXISTS,
+    this.TOO_MANY_INITIALISATION_REQUESTS,
+  ]);
+
+  static isFatal(code: number): boolean {
+    return this.FATAL_SET.has(code);
+  }
+}
+
+export class GraphQLErrorCodes {
+  static readonly SUBSCRIPTION_ERROR = 'GRAPHQL_SUBSCRIPTION_ERROR';
+  static readonly CONNECTION_CLOSED = 'GRAPHQL_CONNECTION_CLOSED';
+}
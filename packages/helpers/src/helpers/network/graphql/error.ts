@@ -0,0 +1,29 @@
+import { HTTP } from '@/common/constants';
+import { ApplicationError, TError } from '@/helpers/error';
+import { GraphQLErrorCodes } from './constants';
+import { IGraphQLError } from './types';
+
+// --------------------------------------------------------
+/**
+ * `ApplicationError` carrying the GraphQL errors returned for an operation.
+ */
+export class GraphQLRequestError extends ApplicationError {
+  errors: Array<IGraphQLError>;
+
+  constructor(opts: Partial<TError> & { errors: Array<IGraphQLError> }) {
+    const {
+      errors,
+      message = errors.map(error => error.message).join('; ') || 'GraphQLRequestError',
+      messageCode = GraphQLErrorCodes.SUBSCRIPTION_ERROR,
+      statusCode = HTTP.ResultCodes.RS_5.BadGateway,
+    } = opts;
+    super({ message, messageCode, statusCode });
+
+    this.name = 'GraphQLRequestError';
+    this.errors = errors;
+  }
+
+  static isGraphQLRequestError(error: unknown): error is GraphQLRequestError {
+    return error instanceof GraphQLRequestError;
+  }
+}
@@ -0,0 +1,356 @@
+import { HTTP } from '@/common/constants';
+import { AnyObject } from '@/common/types';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import C from 'node:crypto';
+import {
+  GraphQLErrorCodes,
+  GraphQLWsCloseCodes,
+  GraphQLWsDefaults,
+  GraphQLWsMessageTypes,
+} from './constants';
+import { GraphQLRequestError } from './error';
+import { GraphQLSubscription } from './subscription';
+import {
+  IGraphQLOperation,
+  IGraphQLReconnectOptions,
+  IGraphQLSubscriptionClientOptions,
+  TGraphQLWsMessage,
+} from './types';
+
+// --------------------------------------------------------
+/**
+ * GraphQL subscriptions over one WebSocket with the `graphql-transport-ws` protocol.
+ *
+ * The connection opens with the first subscription and sends `connectionParams` as the
+ * `connection_init` payload, so auth tokens are read again on every connection. Pings keep the
+ * connection alive and detect dead ones, a dropped connection is opened again with a backoff
+ * and every active subscription is sent again once the server acknowledges it.
+ *
+ * @example
+ * ```typescript
+ * const client = new GraphQLSubscriptionClient({
+ *   url: 'wss://catalog.internal/graphql',
+ *   connectionParams: async () => ({ authorization: `Bearer ${await tokens.get()}` }),
+ * });
+ *
+ * const prices = client.subscribe<{ priceChanged: { sku: string; price: number } }>({
+ *   query: 'subscription ($sku: String!) { priceChanged(sku: $sku) { sku price } }',
+ *   variables: { sku: 'SKU-1' },
+ * });
+ *
+ * for await (const { data } of prices) {
+ *   console.log(data?.priceChanged.price);
+ * }
+ * ```
+ */
+export class GraphQLSubscriptionClient extends BaseHelper {
+  private url: string;
+  private connectionParams: IGraphQLSubscriptionClientOptions['connectionParams'];
+  private connectionAckTimeout: number;
+  private keepAlive: number;
+  private reconnect: IGraphQLReconnectOptions | false;
+  private createWebSocket: NonNullable<IGraphQLSubscriptionClientOptions['createWebSocket']>;
+
+  private socket?: WebSocket;
+  private isAcknowledged = false;
+  private isDisposed = false;
+  private isPongPending = false;
+  private attempts = 0;
+  private subscriptions = new Map<string, GraphQLSubscription<any>>();
+
+  private ackTimer?: ReturnType<typeof setTimeout>;
+  private keepAliveTimer?: ReturnType<typeof setInterval>;
+  private reconnectTimer?: ReturnType<typeof setTimeout>;
+
+  constructor(opts: IGraphQLSubscriptionClientOptions) {
+    super({ scope: GraphQLSubscriptionClient.name, identifier: opts.identifier ?? opts.url });
+
+    const {
+      url,
+      connectionParams,
+      connectionAckTimeout = GraphQLWsDefaults.CONNECTION_ACK_TIMEOUT,
+      keepAlive = GraphQLWsDefaults.KEEP_ALIVE,
+      reconnect = {},
+      createWebSocket = ({ url: socketUrl, protocol }) => new WebSocket(socketUrl, protocol),
+    } = opts;
+
+    this.url = url;
+    this.connectionParams = connectionParams;
+    this.connectionAckTimeout = connectionAckTimeout;
+    this.keepAlive = keepAlive;
+    this.reconnect = reconnect;
+    this.createWebSocket = createWebSocket;
+  }
+
+  isConnected() {
+    return this.isAcknowledged;
+  }
+
+  /**
+   * Start a subscription, sent as soon as the connection is acknowledged.
+   */
+  subscribe<TData = AnyObject, TVariables = AnyObject>(
+    operation: IGraphQLOperation<TVariables>,
+  ): GraphQLSubscription<TData> {
+    if (this.isDisposed) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: GraphQLErrorCodes.CONNECTION_CLOSED,
+        message: `[subscribe] Client is disposed | url: ${this.url}`,
+      });
+    }
+
+    const subscription = new GraphQLSubscription<TData>({
+      id: C.randomUUID(),
+      operation: operation as IGraphQLOperation,
+      onUnsubscribe: id => this.handleUnsubscribe(id),
+    });
+    this.subscriptions.set(subscription.id, subscription);
+
+    if (this.isAcknowledged) {
+      this.sendSubscribe(subscription);
+    } else {
+      this.connect();
+    }
+
+    return subscription;
+  }
+
+  /**
+   * Close the connection and complete every subscription.
+   */
+  dispose() {
+    this.isDisposed = true;
+    clearTimeout(this.reconnectTimer);
+
+    for (const subscription of this.subscriptions.values()) {
+      subscription.end();
+    }
+    this.subscriptions.clear();
+
+    const socket = this.socket;
+    if (socket) {
+      this.handleClose({ socket, code: GraphQLWsCloseCodes.NORMAL, reason: 'Disposed' });
+      socket.close(GraphQLWsCloseCodes.NORMAL, 'Normal Closure');
+    }
+  }
+
+  // --------------------------------------------------------
+  private connect() {
+    if (this.socket || this.reconnectTimer || this.isDisposed) {
+      return;
+    }
+
+    const socket = this.createWebSocket({ url: this.url, protocol: GraphQLWsDefaults.PROTOCOL });
+    this.socket = socket;
+
+    socket.onopen = () => {
+      this.handleOpen(socket).catch(error => {
+        this.logger.for('connect').error('Failed to initialize connection | Error: %s', error);
+        this.terminate({ socket, code: GraphQLWsCloseCodes.BAD_REQUEST, reason: String(error) });
+      });
+    };
+    socket.onmessage = event => {
+      this.handleMessage({ socket, data: event.data });
+    };
+    socket.onclose = event => {
+      this.handleClose({ socket, code: event.code, reason: event.reason });
+    };
+    socket.onerror = () => {
+      this.logger.for('connect').warn('WebSocket error | url: %s', this.url);
+    };
+  }
+
+  private async handleOpen(socket: WebSocket) {
+    const payload =
+      typeof this.connectionParams === 'function'
+        ? await this.connectionParams()
+        : this.connectionParams;
+
+    if (socket !== this.socket) {
+      return;
+    }
+
+    this.send({ type: GraphQLWsMessageTypes.CONNECTION_INIT, payload });
+    this.ackTimer = setTimeout(() => {
+      this.terminate({
+        socket,
+        code: GraphQLWsCloseCodes.CONNECTION_ACK_TIMEOUT,
+        reason: 'Connection acknowledgement timeout',
+      });
+    }, this.connectionAckTimeout);
+  }
+
+  private handleMessage(opts: { socket: WebSocket; data: unknown }) {
+    const { socket, data } = opts;
+    if (socket !== this.socket) {
+      return;
+    }
+
+    let message: TGraphQLWsMessage;
+    try {
+      message = JSON.parse(String(data));
+    } catch {
+      this.terminate({ socket, code: GraphQLWsCloseCodes.BAD_RESPONSE, reason: 'Invalid message' });
+      return;
+    }
+
+    switch (message.type) {
+      case GraphQLWsMessageTypes.CONNECTION_ACK: {
+        clearTimeout(this.ackTimer);
+        this.isAcknowledged = true;
+        this.attempts = 0;
+        this.startKeepAlive(socket);
+
+        for (const subscription of this.subscriptions.values()) {
+          this.sendSubscribe(subscription);
+        }
+        break;
+      }
+      case GraphQLWsMessageTypes.PING: {
+        this.send({ type: GraphQLWsMessageTypes.PONG });
+        break;
+      }
+      case GraphQLWsMessageTypes.PONG: {
+        this.isPongPending = false;
+        break;
+      }
+      case GraphQLWsMessageTypes.NEXT: {
+        this.subscriptions.get(message.id)?.push(message.payload);
+        break;
+      }
+      case GraphQLWsMessageTypes.ERROR: {
+        const subscription = this.subscriptions.get(message.id);
+        this.subscriptions.delete(message.id);
+        subscription?.end(new GraphQLRequestError({ errors: message.payload }));
+        break;
+      }
+      case GraphQLWsMessageTypes.COMPLETE: {
+        const subscription = this.subscriptions.get(message.id);
+        this.subscriptions.delete(message.id);
+        subscription?.end();
+        break;
+      }
+      default: {
+        this.terminate({
+          socket,
+          code: GraphQLWsCloseCodes.BAD_RESPONSE,
+          reason: `Unexpected message | type: ${(message as { type?: string }).type}`,
+        });
+        break;
+      }
+    }
+  }
+
+  private handleClose(opts: { socket: WebSocket; code: number; reason?: string }) {
+    const { socket, code, reason } = opts;
+    if (socket !== this.socket) {
+      return;
+    }
+
+    this.socket = undefined;
+    this.isAcknowledged = false;
+    clearTimeout(this.ackTimer);
+    clearInterval(this.keepAliveTimer);
+
+    // Opened again by the next subscription
+    if (this.isDisposed || !this.subscriptions.size) {
+      return;
+    }
+
+    const { reconnect, attempts } = this;
+    const canReconnect =
+      !!reconnect &&
+      !GraphQLWsCloseCodes.isFatal(code) &&
+      (reconnect.maxAttempts === undefined || attempts < reconnect.maxAttempts);
+
+    if (!canReconnect) {
+      const error = getError({
+        statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+        messageCode: GraphQLErrorCodes.CONNECTION_CLOSED,
+        message: `[GraphQLSubscriptionClient] Connection closed | url: ${this.url} | code: ${code} | reason: ${reason}`,
+      });
+
+      for (const subscription of this.subscriptions.values()) {
+        subscription.end(error);
+      }
+      this.subscriptions.clear();
+      return;
+    }
+
+    const {
+      baseDelay = GraphQLWsDefaults.RECONNECT_BASE_DELAY,
+      maxDelay = GraphQLWsDefaults.RECONNECT_MAX_DELAY,
+    } = reconnect;
+
+    // Full jitter spreads the reconnections of many clients after a server restart
+    const delay = Math.random() * Math.min(maxDelay, baseDelay * 2 ** attempts);
+    this.attempts++;
+
+    this.logger
+      .for('handleClose')
+      .warn(
+        'Connection closed, reconnecting | url: %s | code: %s | attempt: %s | delay: %dms',
+        this.url,
+        code,
+        this.attempts,
+        Math.round(delay),
+      );
+
+    this.reconnectTimer = setTimeout(() => {
+      this.reconnectTimer = undefined;
+      this.connect();
+    }, delay);
+  }
+
+  // A ping left without pong means the connection is dead without the socket noticing it
+  private startKeepAlive(socket: WebSocket) {
+    if (!this.keepAlive) {
+      return;
+    }
+
+    this.isPongPending = false;
+    this.keepAliveTimer = setInterval(() => {
+      if (this.isPongPending) {
+        this.terminate({ socket, code: GraphQLWsCloseCodes.TERMINATED, reason: 'Missed pong' });
+        return;
+      }
+
+      this.isPongPending = true;
+      this.send({ type: GraphQLWsMessageTypes.PING });
+    }, this.keepAlive);
+  }
+
+  // Closing handshakes of dead connections never finish, the close is handled right away
+  private terminate(opts: { socket: WebSocket; code: number; reason: string }) {
+    const { socket, code, reason } = opts;
+
+    this.handleClose({ socket, code, reason });
+    socket.close(code, reason);
+  }
+
+  private handleUnsubscribe(id: string) {
+    this.subscriptions.delete(id);
+
+    if (this.isAcknowledged) {
+      this.send({ type: GraphQLWsMessageTypes.COMPLETE, id });
+    }
+  }
+
+  private sendSubscribe(subscription: GraphQLSubscription<any>) {
+    this.send({
+      type: GraphQLWsMessageTypes.SUBSCRIBE,
+      id: subscription.id,
+      payload: subscription.operation,
+    });
+  }
+
+  private send(message: TGraphQLWsMessage) {
+    if (this.socket?.readyState !== WebSocket.OPEN) {
+      return;
+    }
+
+    this.socket.send(JSON.stringify(message));
+  }
+}
@@ -0,0 +1,5 @@
+export * from './constants';
+export * from './error';
+export * from './helper';
+export * from './subscription';
+export * from './types';
@@ -0,0 +1,115 @@
+import { AnyObject } from '@/common/types';
+import { IGraphQLOperation, IGraphQLResult } from './types';
+
+interface IPendingRead<TData> {
+  resolve: (result: IteratorResult<IGraphQLResult<TData>>) => void;
+  reject: (error: unknown) => void;
+}
+
+// --------------------------------------------------------
+/**
+ * Results of one subscription, read with `for await` until the server completes it, it fails
+ * or `unsubscribe` is called. Breaking out of the loop unsubscribes as well.
+ */
+export class GraphQLSubscription<TData = AnyObject>
+  implements AsyncIterable<IGraphQLResult<TData>>
+{
+  readonly id: string;
+  readonly operation: IGraphQLOperation;
+
+  private results: Array<IGraphQLResult<TData>> = [];
+  private reads: Array<IPendingRead<TData>> = [];
+  private error?: unknown;
+  private isDone = false;
+  private onUnsubscribe: (id: string) => void;
+
+  constructor(opts: {
+    id: string;
+    operation: IGraphQLOperation;
+    onUnsubscribe: (id: string) => void;
+  }) {
+    this.id = opts.id;
+    this.operation = opts.operation;
+    this.onUnsubscribe = opts.onUnsubscribe;
+  }
+
+  isActive() {
+    return !this.isDone;
+  }
+
+  unsubscribe() {
+    if (this.isDone) {
+      return;
+    }
+
+    this.onUnsubscribe(this.id);
+    this.end();
+  }
+
+  // --------------------------------------------------------
+  push(result: IGraphQLResult<TData>) {
+    if (this.isDone) {
+      return;
+    }
+
+    const read = this.reads.shift();
+    if (read) {
+      read.resolve({ value: result, done: false });
+      return;
+    }
+
+    this.results.push(result);
+  }
+
+  /**
+   * Stop the stream, buffered results are still read before `error` is thrown.
+   */
+  end(error?: unknown) {
+    if (this.isDone) {
+      return;
+    }
+
+    this.isDone = true;
+
+    // Reads only wait on an empty buffer
+    const reads = this.reads.splice(0);
+    if (!reads.length) {
+      this.error = error;
+      return;
+    }
+
+    for (const read of reads) {
+      if (error) {
+        read.reject(error);
+        continue;
+      }
+
+      read.resolve({ value: undefined, done: true });
+    }
+  }
+
+  [Symbol.asyncIterator](): AsyncIterator<IGraphQLResult<TData>> {
+    return {
+      next: () => {
+        const result = this.results.shift();
+        if (result) {
+          return Promise.resolve({ value: result, done: false });
+        }
+
+        if (!this.isDone) {
+          return new Promise((resolve, reject) => {
+            this.reads.push({ resolve, reject });
+          });
+        }
+
+        const error = this.error;
+        this.error = undefined;
+        return error ? Promise.reject(error) : Promise.resolve({ value: undefined, done: true });
+      },
+      return: () => {
+        this.unsubscribe();
+        return Promise.resolve({ value: undefined, done: true });
+      },
+    };
+  }
+}
@@ -0,0 +1,54 @@
+import { AnyObject, ValueOrPromise } from '@/common/types';
+
+export interface IGraphQLError {
+  message: string;
+  locations?: Array<{ line: number; column: number }>;
+  path?: Array<string | number>;
+  extensions?: AnyObject;
+}
+
+export interface IGraphQLResult<TData = AnyObject> {
+  data?: TData | null;
+  errors?: Array<IGraphQLError>;
+  extensions?: AnyObject;
+}
+
+export interface IGraphQLOperation<TVariables = AnyObject> {
+  query: string;
+  variables?: TVariables;
+  operationName?: string;
+  extensions?: AnyObject;
+}
+
+export interface IGraphQLReconnectOptions {
+  // Attempts in a row before the subscriptions fail, unlimited when omitted
+  maxAttempts?: number;
+  baseDelay?: number;
+  maxDelay?: number;
+}
+
+export interface IGraphQLSubscriptionClientOptions {
+  url: string;
+  // `connection_init` payload, e.g. `{ authorization: 'Bearer ...' }`, read on every connection
+  connectionParams?: AnyObject | (() => ValueOrPromise<AnyObject | undefined>);
+  connectionAckTimeout?: number;
+  // Milliseconds between two pings, `0` disables the keepalive
+  keepAlive?: number;
+  // Set `false` to fail the subscriptions on the first disconnection
+  reconnect?: IGraphQLReconnectOptions | false;
+  // Defaults to the global `WebSocket`
+  createWebSocket?: (opts: { url: string; protocol: string }) => WebSocket;
+  identifier?: string;
+}
+
+// --------------------------------------------------------
+// graphql-transport-ws messages
+export type TGraphQLWsMessage =
+  | { type: 'connection_init'; payload?: AnyObject }
+  | { type: 'connection_ack'; payload?: AnyObject }
+  | { type: 'ping'; payload?: AnyObject }
+  | { type: 'pong'; payload?: AnyObject }
+  | { type: 'subscribe'; id: string; payload: IGraphQLOperation }
+  | { type: 'next'; id: string; payload: IGraphQLResult }
+  | { type: 'error'; id: string; payload: Array<IGraphQLError> }
+  | { type: 'complete'; id: string };
@@ -1,22 +1,63 @@
+import { HTTP } from '@/common/constants';
+import { ValueOrPromise } from '@/common/types';
 import { BaseHelper } from '@/helpers/base';
-import { getError } from '@/helpers/error';
+import { ApplicationError, getError } from '@/helpers/error';
+import { executePromiseWithLimit } from '@/utilities/promise.utility';
 import isEmpty from 'lodash/isEmpty';
+import { RegionSelector, ServiceDiscovery } from '../discovery';
+import {
+  INetworkEndpoint,
+  INetworkEndpointCall,
+  NetworkEndpointErrorCodes,
+  NetworkEndpoints,
+} from './endpoints';
 import { IFetchable, IRequestOptions } from './fetcher/base-fetcher';
-import { TFetcherResponse, TFetcherVariant } from './types';
+import {
+  ApiResponses,
+  IAxiosLikeResponse,
+  IErrorBodyDecoder,
+  IResponseDateOptions,
+  responseDateSchema,
+  TErrorMapper,
+} from './response';
+import { TBatchResult, TFetcherResponse, TFetcherVariant } from './types';
 
 // -----------------------------------------------------------------------------
 export class BaseNetworkRequest<T extends TFetcherVariant> extends BaseHelper {
+  static readonly DEFAULT_BATCH_CONCURRENCY = 5;
+
   protected baseUrl: string;
   protected fetcher: IFetchable<T, IRequestOptions, TFetcherResponse<T>>;
+  protected discovery?: ServiceDiscovery;
+  protected regions?: RegionSelector;
+  protected errorDecoder?: IErrorBodyDecoder;
+  protected errorMapper?: TErrorMapper;
+  protected dates: IResponseDateOptions;
+  protected endpoints = new Map<string, INetworkEndpoint>();
 
   constructor(opts: {
     name: string;
     baseUrl?: string;
     fetcher: IFetchable<T, IRequestOptions, TFetcherResponse<T>>;
+    discovery?: ServiceDiscovery;
+    regions?: RegionSelector;
+    errorDecoder?: IErrorBodyDecoder;
+    errorMapper?: TErrorMapper;
+    dates?: IResponseDateOptions;
+    endpoints?: Record<string, INetworkEndpoint>;
   }) {
     super({ scope: opts.name, identifier: opts.name });
     this.baseUrl = opts.baseUrl ?? '';
     this.fetcher = opts.fetcher;
+    this.discovery = opts.discovery;
+    this.regions = opts.regions;
+    this.errorDecoder = opts.errorDecoder;
+    this.errorMapper = opts.errorMapper;
+    this.dates = opts.dates ?? {};
+
+    for (const [name, endpoint] of Object.entries(opts.endpoints ?? {})) {
+      this.defineEndpoint({ name, endpoint });
+    }
   }
 
   getRequestPath(opts: { paths: Array<string> }) {
@@ -36,7 +77,8 @@ export class BaseNetworkRequest<T extends TFetcherVariant> extends BaseHelper {
   }
 
   getRequestUrl(opts: { baseUrl?: string; paths: Array<string> }) {
-    let baseUrl = opts?.baseUrl ?? this.baseUrl ?? '';
+    // The selected region takes over the configured base url
+    let baseUrl = opts?.baseUrl ?? this.regions?.getSelected().url ?? this.baseUrl ?? '';
     const paths = opts?.paths ?? [];
 
     if (!baseUrl || isEmpty(baseUrl)) {
@@ -46,12 +88,243 @@ export class BaseNetworkRequest<T extends TFetcherVariant> extends BaseHelper {
       });
     }
 
+    // service://<name>/<path> base urls are resolved per request to a discovered endpoint
+    if (ServiceDiscovery.isServiceUrl(baseUrl)) {
+      if (!this.discovery) {
+        throw getError({
+          statusCode: 500,
+          message: `[getRequestUrl] Service base url requires a discovery | baseUrl: ${baseUrl}`,
+        });
+      }
+
+      baseUrl = this.discovery.resolveUrl({ url: baseUrl });
+    }
+
     if (baseUrl.endsWith('/')) {
       baseUrl = baseUrl.slice(0, -1); // Remove / at the end
     }
 
     const joined = this.getRequestPath({ paths });
-    return `${baseUrl ?? this.baseUrl}${joined}`;
+    return `${baseUrl}${joined}`;
+  }
+
+  // -----------------------------------------------------------------------------
+  /**
+   * Send `requests` with at most `concurrency` of them in flight.
+   *
+   * Results keep the order of `requests` and a failed request does not fail the batch: its
+   * error, or the one thrown by `transform`, is reported as an `ApplicationError` instead.
+   *
+   * @example
+   * ```typescript
+   * const results = await request.sendBatch({
+   *   requests: ids.map(id => ({ url: request.getRequestUrl({ paths: ['/orders', id] }) })),
+   *   concurrency: 10,
+   *   transform: async ({ response }) => {
+   *     if (!response.ok) {
+   *       throw getError({ statusCode: response.status, message: await response.text() });
+   *     }
+   *
+   *     return response.json() as Promise<TOrder>;
+   *   },
+   * });
+   *
+   * const failed = results.filter(rs => !rs.ok);
+   * ```
+   */
+  async sendBatch<R = TFetcherResponse<T>>(opts: {
+    requests: Array<IRequestOptions>;
+    concurrency?: number;
+    transform?: (opts: {
+      response: TFetcherResponse<T>;
+      request: IRequestOptions;
+      index: number;
+    }) => ValueOrPromise<R>;
+  }): Promise<Array<TBatchResult<R>>> {
+    const {
+      requests,
+      concurrency = BaseNetworkRequest.DEFAULT_BATCH_CONCURRENCY,
+      transform,
+    } = opts;
+
+    if (!requests.length) {
+      return [];
+    }
+
+    return executePromiseWithLimit<TBatchResult<R>>({
+      limit: Math.max(concurrency, 1),
+      tasks: requests.map((request, index) => async () => {
+        try {
+          const response = await this.fetcher.send(request, this.logger);
+          const value = transform
+            ? await transform({ response, request, index })
+            : (response as unknown as R);
+
+          return { index, ok: true as const, value };
+        } catch (error) {
+          return { index, ok: false as const, error: this.toBatchError({ request, error }) };
+        }
+      }),
+    });
+  }
+
+  protected toBatchError(opts: { request: IRequestOptions; error: unknown }): ApplicationError {
+    const { request, error } = opts;
+
+    if (error instanceof ApplicationError) {
+      return error;
+    }
+
+    const { name, message, response } = (error ?? {}) as {
+      name?: string;
+      message?: string;
+      response?: { status?: number };
+    };
+
+    // Timeouts abort the request, axios carries the status of rejected responses
+    const statusCode =
+      name === 'AbortError' || name === 'TimeoutError'
+        ? HTTP.ResultCodes.RS_5.GatewayTimeout
+        : (response?.status ?? HTTP.ResultCodes.RS_5.BadGateway);
+
+    return getError({
+      statusCode,
+      message: `[sendBatch] Request failed | url: ${request.url} | error: ${message ?? error}`,
+    });
+  }
+
+  // -----------------------------------------------------------------------------
+  /**
+   * Register the error body type of the upstream, read by `ensureOk`.
+   *
+   * @example
+   * ```typescript
+   * network.setErrorDecoder({
+   *   schema: z.object({ statusCode: z.number(), messageCode: z.string(), message: z.string() }),
+   * });
+   *
+   * // HttpResponseError carrying the messageCode and message sent by the upstream
+   * await network.ensureOk(await network.getNetworkService().get({ url }));
+   * ```
+   */
+  setErrorDecoder<E>(decoder: IErrorBodyDecoder<E> | undefined) {
+    this.errorDecoder = decoder;
+    return this;
+  }
+
+  getErrorDecoder() {
+    return this.errorDecoder;
+  }
+
+  /**
+   * Register the translation of the upstream failures into domain errors, applied by `ensureOk`
+   * after the error decoder, see `ErrorMappers`.
+   */
+  setErrorMapper(mapper: TErrorMapper | undefined) {
+    this.errorMapper = mapper;
+    return this;
+  }
+
+  getErrorMapper() {
+    return this.errorMapper;
+  }
+
+  /**
+   * `ApiResponses.ensureOk` reading failed bodies with the error decoder and mapper of the
+   * request.
+   */
+  ensureOk<R extends Response | IAxiosLikeResponse>(response: R): Promise<R> {
+    return ApiResponses.ensureOk(response, {
+      decoder: this.errorDecoder,
+      mapper: this.errorMapper,
+    });
+  }
+
+  /**
+   * `responseDateSchema` in the date format of the upstream, `opts` override it per field.
+   *
+   * @example
+   * ```typescript
+   * const legacy = new NodeFetchNetworkRequest({
+   *   name: 'LegacyErpRequest',
+   *   networkOptions: { baseUrl: 'https://erp.partner.com' },
+   *   dates: { format: ResponseDateFormats.SQL_DATETIME, timezone: 'Asia/Ho_Chi_Minh' },
+   * });
+   *
+   * const InvoiceSchema = z.object({
+   *   issuedAt: legacy.dateSchema(),
+   *   syncedAt: legacy.dateSchema({ format: ResponseDateFormats.EPOCH_MILLIS }),
+   * });
+   * ```
+   */
+  dateSchema(opts: IResponseDateOptions = {}) {
+    return responseDateSchema({ ...this.dates, ...opts });
+  }
+
+  // -----------------------------------------------------------------------------
+  /**
+   * Declare an endpoint of the upstream once, its method, timeouts, retries and response body
+   * then apply to every `call`.
+   *
+   * @example
+   * ```typescript
+   * network.defineEndpoint({
+   *   name: 'getOrder',
+   *   endpoint: { path: '/orders/:id', totalTimeout: 2_000, retry: true, schema: OrderSchema },
+   * });
+   *
+   * const order = await network.call<TOrder>({ endpoint: 'getOrder', params: { id } });
+   * ```
+   */
+  defineEndpoint(opts: { name: string; endpoint: INetworkEndpoint }) {
+    this.endpoints.set(opts.name, opts.endpoint);
+    return this;
+  }
+
+  getEndpoint(opts: { name: string }) {
+    return this.endpoints.get(opts.name);
+  }
+
+  /**
+   * Send a request to a declared endpoint, see `defineEndpoint`.
+   *
+   * Failed responses are thrown by `ensureOk`, the body of the others is returned, validated by
+   * the schema of the endpoint when it has one.
+   */
+  async call<R = unknown>(opts: INetworkEndpointCall): Promise<R> {
+    const { endpoint: name, params, query, body, headers, options } = opts;
+
+    const endpoint = this.endpoints.get(name);
+    if (!endpoint) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: NetworkEndpointErrorCodes.UNKNOWN_ENDPOINT,
+        message: `[call] Unknown endpoint | name: ${name} | request: ${this.identifier}`,
+      });
+    }
+
+    const { path, method = 'get', schema, headers: endpointHeaders, ...rest } = endpoint;
+    const url = this.getRequestUrl({ paths: [NetworkEndpoints.buildPath({ path, params })] });
+
+    const response = await this.fetcher.send(
+      {
+        ...rest,
+        url,
+        method: method.toLowerCase(),
+        params: query,
+        body: NetworkEndpoints.toRequestBody(body),
+        headers: { ...endpointHeaders, ...headers },
+        ...options,
+      },
+      this.logger,
+    );
+    await this.ensureOk(response);
+
+    return NetworkEndpoints.parseBody<R>({
+      name,
+      body: await NetworkEndpoints.readBody(response),
+      schema,
+    });
   }
 
   getNetworkService() {
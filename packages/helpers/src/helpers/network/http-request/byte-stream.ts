@@ -0,0 +1,152 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import { IRequestOptions } from './fetcher/base-fetcher';
+import { IFetcherTimeoutOptions } from './fetcher/timeouts';
+import { ApiResponses } from './response';
+
+export class ByteStreamErrorCodes {
+  // The upstream body failed mid-way, e.g. reset connection or read timeout
+  static readonly READ_FAILED = 'BYTE_STREAM_READ_FAILED';
+}
+
+export interface IStreamBytesOptions extends IFetcherTimeoutOptions {
+  url: string;
+  // Defaults to `get`
+  method?: string;
+  params?: Record<string, any>;
+  headers?: Record<string, string>;
+  body?: any;
+}
+
+export interface IByteStream {
+  status: number;
+  headers: Headers;
+  contentType?: string;
+  // Bytes of the body, unknown for chunked and for compressed bodies which are decoded on read
+  contentLength?: number;
+  // Read errors surface as `BYTE_STREAM_READ_FAILED` application errors
+  body: ReadableStream<Uint8Array>;
+}
+
+// Describe the upstream body, hop-by-hop and decoding related ones do not apply to a proxied body
+const FORWARDED_HEADERS = [
+  'content-type',
+  'content-disposition',
+  'content-language',
+  'cache-control',
+  'etag',
+  'last-modified',
+];
+
+// --------------------------------------------------------
+/**
+ * Upstream bodies as byte streams, the building block to proxy them through our own responses
+ * without buffering.
+ *
+ * Failed responses throw like `ApiResponses.ensureOk`. Nothing is read until the body is
+ * consumed, cancelling the stream cancels the upstream body.
+ *
+ * @example
+ * ```typescript
+ * app.get('/invoices/:id/pdf', async context => {
+ *   const upstream = await network.getNetworkService().streamBytes({
+ *     url: network.getRequestUrl({ paths: ['invoices', context.req.param('id'), 'pdf'] }),
+ *   });
+ *
+ *   return ByteStreams.toResponse({ stream: upstream });
+ * });
+ * ```
+ */
+export class ByteStreams {
+  static async open(opts: {
+    options: IStreamBytesOptions;
+    fetch: (request: IRequestOptions) => Promise<Response>;
+  }): Promise<IByteStream> {
+    const { options, fetch } = opts;
+    const { method = 'get', ...request } = options;
+
+    const response = await ApiResponses.ensureOk(await fetch({ ...request, method }));
+    return ByteStreams.fromResponse({ response, url: options.url });
+  }
+
+  static fromResponse(opts: { response: Response; url?: string }): IByteStream {
+    const { response, url = response.url } = opts;
+    const { headers } = response;
+
+    // Fetch decodes compressed bodies, the header counts the encoded bytes
+    const length = headers.get('content-length');
+    const contentLength =
+      length && !headers.get('content-encoding') && /^\d+$/.test(length)
+        ? Number(length)
+        : undefined;
+
+    return {
+      status: response.status,
+      headers,
+      contentType: headers.get('content-type') ?? undefined,
+      contentLength,
+      body: ByteStreams.wrap({ body: response.body, url }),
+    };
+  }
+
+  /**
+   * Response forwarding the status, body and body describing headers of `stream`, `headers`
+   * are added over them.
+   */
+  static toResponse(opts: { stream: IByteStream; headers?: Record<string, string> }): Response {
+    const { stream, headers: extra } = opts;
+
+    const headers = new Headers();
+    for (const name of FORWARDED_HEADERS) {
+      const value = stream.headers.get(name);
+      if (value) {
+        headers.set(name, value);
+      }
+    }
+
+    if (stream.contentLength !== undefined) {
+      headers.set('content-length', String(stream.contentLength));
+    }
+
+    for (const [name, value] of Object.entries(extra ?? {})) {
+      headers.set(name, value);
+    }
+
+    return new Response(stream.body, { status: stream.status, headers });
+  }
+
+  // --------------------------------------------------------
+  private static wrap(opts: {
+    body: ReadableStream<Uint8Array> | null;
+    url: string;
+  }): ReadableStream<Uint8Array> {
+    const { body, url } = opts;
+    if (!body) {
+      return new ReadableStream({ start: controller => controller.close() });
+    }
+
+    const reader = body.getReader();
+    return new ReadableStream<Uint8Array>({
+      pull: async controller => {
+        try {
+          const { done, value } = await reader.read();
+          if (done) {
+            controller.close();
+            return;
+          }
+
+          controller.enqueue(value);
+        } catch (error) {
+          controller.error(
+            getError({
+              statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+              messageCode: ByteStreamErrorCodes.READ_FAILED,
+              message: `[ByteStreams] Upstream body failed | url: ${url} | error: ${error}`,
+            }),
+          );
+        }
+      },
+      cancel: reason => reader.cancel(reason),
+    });
+  }
+}
@@ -0,0 +1,137 @@
+import { HTTP } from '@/common/constants';
+import { ValueOrPromise } from '@/common/types';
+import { getError } from '@/helpers/error';
+import { IRequestOptions } from './fetcher/base-fetcher';
+import { IFetcherTimeoutOptions } from './fetcher/timeouts';
+import { ApiResponses } from './response';
+
+export class ConditionalUpdateErrorCodes {
+  static readonly MISSING_ETAG = 'CONDITIONAL_UPDATE_MISSING_ETAG';
+  static readonly CONFLICT = 'CONDITIONAL_UPDATE_CONFLICT';
+}
+
+export interface IConditionalUpdateOptions<T> extends IFetcherTimeoutOptions {
+  url: string;
+  params?: Record<string, any>;
+  headers?: Record<string, string>;
+  // Defaults to `put`
+  method?: string;
+  // Resource of the read response, defaults to its JSON body
+  read?: (response: Response) => Promise<T>;
+  // Body sent with `If-Match`, objects are sent as JSON
+  mutate: (opts: { resource: T; attempt: number }) => ValueOrPromise<unknown>;
+  // Read-modify-write rounds before a lost race is reported
+  maxAttempts?: number;
+}
+
+export interface IConditionalUpdateResult<T> {
+  response: Response;
+  // As read by the successful round
+  resource: T;
+  etag: string;
+  attempts: number;
+}
+
+const isPlainBody = (body: unknown) =>
+  typeof body === 'object' &&
+  body !== null &&
+  [Object.prototype, Array.prototype, null].includes(Object.getPrototypeOf(body));
+
+// --------------------------------------------------------
+/**
+ * Read-modify-write of a REST resource with optimistic concurrency.
+ *
+ * The resource is read with its `ETag`, `mutate` builds the new representation and it is sent
+ * back with `If-Match`. A `412 Precondition Failed` means someone else changed it in between,
+ * the round starts over from a fresh read up to `maxAttempts` times, then fails with a 409
+ * `CONDITIONAL_UPDATE_CONFLICT` error. Any other response of the write is returned as is.
+ *
+ * @example
+ * ```typescript
+ * const { response } = await network.getNetworkService().conditionalUpdate<TCart>({
+ *   url: network.getRequestUrl({ paths: ['carts', cartId] }),
+ *   mutate: ({ resource }) => ({ ...resource, items: [...resource.items, item] }),
+ * });
+ * await network.ensureOk(response);
+ * ```
+ */
+export class ConditionalUpdates {
+  static readonly DEFAULT_MAX_ATTEMPTS = 3;
+
+  static async update<T>(opts: {
+    options: IConditionalUpdateOptions<T>;
+    fetch: (request: IRequestOptions) => Promise<Response>;
+    logger?: any;
+  }): Promise<IConditionalUpdateResult<T>> {
+    const { options, fetch, logger } = opts;
+    const {
+      url,
+      params,
+      headers,
+      method = 'put',
+      read = async response => (await response.json()) as T,
+      mutate,
+      maxAttempts = ConditionalUpdates.DEFAULT_MAX_ATTEMPTS,
+      connectTimeout,
+      readTimeout,
+      totalTimeout,
+    } = options;
+    const timeouts = { connectTimeout, readTimeout, totalTimeout };
+
+    for (let attempt = 1; ; attempt++) {
+      // Never answered by a response cache, a stale ETag would lose every round
+      const current = await ApiResponses.ensureOk(
+        await fetch({
+          url,
+          method: 'get',
+          params,
+          headers: { ...headers, ['cache-control']: 'no-cache' },
+          ...timeouts,
+        }),
+      );
+
+      const etag = current.headers.get('etag');
+      if (!etag) {
+        current.body?.cancel().catch(() => {});
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+          messageCode: ConditionalUpdateErrorCodes.MISSING_ETAG,
+          message: `[conditionalUpdate] Resource has no ETag | url: ${url}`,
+        });
+      }
+
+      const resource = await read(current);
+      const body = await mutate({ resource, attempt });
+      const isJson = isPlainBody(body);
+
+      const response = await fetch({
+        url,
+        method,
+        params,
+        headers: {
+          ...headers,
+          ...(isJson ? { ['content-type']: 'application/json; charset=utf-8' } : {}),
+          ['if-match']: etag,
+        },
+        body: isJson ? JSON.stringify(body) : body,
+        ...timeouts,
+      });
+
+      if (response.status !== HTTP.ResultCodes.RS_4.PreconditionFailed) {
+        return { response, resource, etag, attempts: attempt };
+      }
+
+      response.body?.cancel().catch(() => {});
+      const message = `Resource changed concurrently | url: ${url} | attempt: ${attempt}/${maxAttempts} | etag: ${etag}`;
+      if (attempt >= maxAttempts) {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_4.Conflict,
+          messageCode: ConditionalUpdateErrorCodes.CONFLICT,
+          message: `[conditionalUpdate] ${message}`,
+        });
+      }
+
+      logger?.for(ConditionalUpdates.update.name).warn('%s | Reading again', message);
+    }
+  }
+}
@@ -0,0 +1,266 @@
+import { HTTP } from '@/common/constants';
+import { TConstValue } from '@/common/types';
+import { getError } from '@/helpers/error';
+import C from 'node:crypto';
+import fs from 'node:fs';
+import fsp from 'node:fs/promises';
+import path from 'node:path';
+import { Readable } from 'node:stream';
+import { pipeline } from 'node:stream/promises';
+import { ReadableStream as NodeReadableStream } from 'node:stream/web';
+import { IRequestOptions } from './fetcher/base-fetcher';
+import { IFetcherTimeoutOptions } from './fetcher/timeouts';
+import { ApiResponses } from './response';
+
+export class ChecksumAlgorithms {
+  static readonly MD5 = 'md5';
+  static readonly SHA256 = 'sha256';
+
+  static readonly SCHEME_SET = new Set([this.MD5, this.SHA256]);
+
+  static isValid(algorithm: string): boolean {
+    return this.SCHEME_SET.has(algorithm);
+  }
+}
+
+export type TChecksumAlgorithm = TConstValue<typeof ChecksumAlgorithms>;
+
+export class DownloadErrorCodes {
+  static readonly CHECKSUM_MISMATCH = 'DOWNLOAD_CHECKSUM_MISMATCH';
+  static readonly MISSING_CHECKSUM = 'DOWNLOAD_MISSING_CHECKSUM';
+  static readonly EMPTY_BODY = 'DOWNLOAD_EMPTY_BODY';
+}
+
+export interface IDownloadChecksum {
+  algorithm: TChecksumAlgorithm;
+  // Expected digest, hex or base64
+  digest?: string;
+  // Response header carrying the digest instead, e.g. `x-checksum-sha256`
+  header?: string;
+}
+
+export interface IDownloadOptions extends IFetcherTimeoutOptions {
+  url: string;
+  destination: string;
+  params?: Record<string, any>;
+  headers?: Record<string, string>;
+  maxResponseBytes?: number;
+  bandwidth?: IRequestOptions['bandwidth'];
+  // Defaults to the digest announced by the response (`Repr-Digest`, `Digest`, `Content-MD5`)
+  checksum?: IDownloadChecksum;
+  // Downloads attempted before a checksum mismatch is reported
+  maxAttempts?: number;
+}
+
+export interface IDownloadResult {
+  path: string;
+  size: number;
+  attempts: number;
+  // Verified digest, hex
+  checksum?: { algorithm: TChecksumAlgorithm; digest: string };
+}
+
+// RFC 9530 / RFC 3230 digest names
+const DIGEST_ALGORITHMS: Record<string, TChecksumAlgorithm> = {
+  'sha-256': ChecksumAlgorithms.SHA256,
+  md5: ChecksumAlgorithms.MD5,
+};
+
+const DIGEST_LENGTHS: Record<TChecksumAlgorithm, number> = {
+  [ChecksumAlgorithms.MD5]: 16,
+  [ChecksumAlgorithms.SHA256]: 32,
+};
+
+// --------------------------------------------------------
+export class DownloadChecksums {
+  /**
+   * Decode a hex or base64 digest, `undefined` when it does not fit the algorithm.
+   */
+  static decode(opts: { algorithm: TChecksumAlgorithm; digest: string }): Buffer | undefined {
+    const { algorithm, digest } = opts;
+    const value = digest.trim().replace(/^:|:$/g, '');
+    const length = DIGEST_LENGTHS[algorithm];
+
+    if (/^[\da-f]+$/i.test(value) && value.length === length * 2) {
+      return Buffer.from(value, 'hex');
+    }
+
+    const decoded = Buffer.from(value, 'base64');
+    return decoded.length === length ? decoded : undefined;
+  }
+
+  /**
+   * Expected digest of a response, the caller supplied checksum wins over the announced ones.
+   */
+  static resolve(opts: {
+    headers: Headers;
+    checksum?: IDownloadChecksum;
+  }): { algorithm: TChecksumAlgorithm; expected: Buffer } | undefined {
+    const { headers, checksum } = opts;
+
+    if (checksum) {
+      const digest = checksum.digest ?? (checksum.header ? headers.get(checksum.header) : null);
+      if (!digest) {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+          messageCode: DownloadErrorCodes.MISSING_CHECKSUM,
+          message: `[download] Missing checksum | header: ${checksum.header}`,
+        });
+      }
+
+      const expected = DownloadChecksums.decode({ algorithm: checksum.algorithm, digest });
+      if (!expected) {
+        throw getError({
+          statusCode: checksum.digest
+            ? HTTP.ResultCodes.RS_5.InternalServerError
+            : HTTP.ResultCodes.RS_5.BadGateway,
+          messageCode: DownloadErrorCodes.MISSING_CHECKSUM,
+          message: `[download] Invalid ${checksum.algorithm} checksum | digest: ${digest}`,
+        });
+      }
+
+      return { algorithm: checksum.algorithm, expected };
+    }
+
+    // e.g. `sha-256=:X48E9qOokqqrvdts8nOJRJN3OWDUoyWxBf7kbu9DBPE=:` or `SHA-256=X48E...`
+    const announced = [headers.get('repr-digest'), headers.get('digest')]
+      .flatMap(value => value?.split(',') ?? [])
+      .map(entry => /^\s*([\w-]+)\s*=\s*(.+?)\s*$/.exec(entry))
+      .flatMap(matched => (matched ? [[matched[1].toLowerCase(), matched[2]] as const] : []));
+
+    const contentMD5 = headers.get('content-md5');
+    if (contentMD5) {
+      announced.push(['md5', contentMD5]);
+    }
+
+    // Prefer the strongest algorithm
+    for (const name of ['sha-256', 'md5']) {
+      const entry = announced.find(([key]) => key === name);
+      const algorithm = DIGEST_ALGORITHMS[name];
+      const expected = entry && DownloadChecksums.decode({ algorithm, digest: entry[1] });
+
+      if (expected) {
+        return { algorithm, expected };
+      }
+    }
+
+    return undefined;
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * Response bodies saved to disk with their checksum verified.
+ *
+ * The expected digest is the caller supplied one, otherwise the one announced by the response
+ * (`Repr-Digest`, `Digest` or `Content-MD5`). Content not matching it is discarded and downloaded
+ * again up to `maxAttempts` times, `destination` is only written once the content is verified.
+ *
+ * @example
+ * ```typescript
+ * const result = await network.download({
+ *   url: network.getRequestUrl({ paths: ['/exports/orders.csv'] }),
+ *   destination: '/tmp/orders.csv',
+ *   checksum: { algorithm: ChecksumAlgorithms.SHA256, header: 'x-checksum-sha256' },
+ * });
+ * ```
+ */
+export class FileDownloads {
+  static readonly DEFAULT_MAX_ATTEMPTS = 3;
+
+  static async download(opts: {
+    options: IDownloadOptions;
+    fetch: () => Promise<Response>;
+    logger?: any;
+  }): Promise<IDownloadResult> {
+    const { options, fetch, logger } = opts;
+    const {
+      url,
+      destination,
+      checksum,
+      maxAttempts = FileDownloads.DEFAULT_MAX_ATTEMPTS,
+    } = options;
+
+    await fsp.mkdir(path.dirname(destination), { recursive: true });
+
+    for (let attempt = 1; ; attempt++) {
+      const response = await ApiResponses.ensureOk(await fetch());
+      const expected = DownloadChecksums.resolve({ headers: response.headers, checksum });
+
+      const tmpPath = `${destination}.${C.randomUUID()}.tmp`;
+      const { size, digest } = await FileDownloads.write({
+        response,
+        path: tmpPath,
+        algorithm: expected?.algorithm,
+      });
+
+      if (!expected || digest?.equals(expected.expected)) {
+        await fsp.rename(tmpPath, destination);
+
+        return {
+          path: destination,
+          size,
+          attempts: attempt,
+          checksum: expected && {
+            algorithm: expected.algorithm,
+            digest: expected.expected.toString('hex'),
+          },
+        };
+      }
+
+      await fsp.rm(tmpPath, { force: true });
+
+      const message = [
+        `Checksum mismatch | url: ${url} | attempt: ${attempt}/${maxAttempts}`,
+        `algorithm: ${expected.algorithm}`,
+        `expected: ${expected.expected.toString('hex')}`,
+        `received: ${digest?.toString('hex')}`,
+      ].join(' | ');
+
+      if (attempt >= maxAttempts) {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+          messageCode: DownloadErrorCodes.CHECKSUM_MISMATCH,
+          message: `[download] ${message}`,
+        });
+      }
+
+      logger?.for(FileDownloads.download.name).warn('%s | Downloading again', message);
+    }
+  }
+
+  // Stream the body to `path`, hashing it on the fly
+  private static async write(opts: {
+    response: Response;
+    path: string;
+    algorithm?: TChecksumAlgorithm;
+  }): Promise<{ size: number; digest?: Buffer }> {
+    const { response, path: filePath, algorithm } = opts;
+
+    if (!response.body) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+        messageCode: DownloadErrorCodes.EMPTY_BODY,
+        message: `[download] Response has no body | url: ${response.url}`,
+      });
+    }
+
+    const hash = algorithm ? C.createHash(algorithm) : undefined;
+    let size = 0;
+
+    const source = Readable.fromWeb(response.body as NodeReadableStream<Uint8Array>);
+    source.on('data', (chunk: Buffer) => {
+      size += chunk.length;
+      hash?.update(chunk);
+    });
+
+    try {
+      await pipeline(source, fs.createWriteStream(filePath));
+    } catch (error) {
+      await fsp.rm(filePath, { force: true });
+      throw error;
+    }
+
+    return { size, digest: hash?.digest() };
+  }
+}
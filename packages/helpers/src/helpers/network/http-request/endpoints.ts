@@ -0,0 +1,111 @@
+import { z } from '@hono/zod-openapi';
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import isPlainObject from 'lodash/isPlainObject';
+import { IRequestOptions } from './fetcher/base-fetcher';
+import { IFetcherTimeoutOptions } from './fetcher/timeouts';
+import { IAxiosLikeResponse } from './response';
+
+export class NetworkEndpointErrorCodes {
+  static readonly UNKNOWN_ENDPOINT = 'NETWORK_ENDPOINT_UNKNOWN';
+  static readonly MISSING_PARAM = 'NETWORK_ENDPOINT_MISSING_PARAM';
+  static readonly INVALID_RESPONSE = 'NETWORK_ENDPOINT_INVALID_RESPONSE';
+}
+
+// Timeouts apply to every call of the endpoint, over the ones of the fetcher
+export interface INetworkEndpoint extends IFetcherTimeoutOptions {
+  // `/orders/:id/items`, `:<name>` segments are filled from the params of the call
+  path: string;
+  // Defaults to `get`
+  method?: string;
+  headers?: Record<string, string>;
+  // Force (`true`) or prevent (`false`) retries of the calls, see `IRequestOptions.retry`
+  retry?: boolean;
+  // Body of the 2xx responses, calls fail with `NETWORK_ENDPOINT_INVALID_RESPONSE` otherwise
+  schema?: z.ZodType;
+}
+
+export interface INetworkEndpointCall {
+  endpoint: string;
+  // Path params of the template
+  params?: Record<string, string | number>;
+  query?: Record<string, any>;
+  // Plain objects and arrays are sent as JSON
+  body?: any;
+  headers?: Record<string, string>;
+  // Request options of this call only, e.g. a longer `totalTimeout`
+  options?: Partial<IRequestOptions>;
+}
+
+const PATH_PARAM_PATTERN = /:([A-Za-z_]\w*)/g;
+
+// --------------------------------------------------------
+/**
+ * Path templates and response bodies of the named endpoints of a network request, see
+ * `BaseNetworkRequest.call`.
+ */
+export class NetworkEndpoints {
+  /**
+   * `{ path: '/orders/:id', params: { id: 'ord 1' } }` => `/orders/ord%201`
+   */
+  static buildPath(opts: { path: string; params?: Record<string, string | number> }): string {
+    const { path, params = {} } = opts;
+
+    return path.replace(PATH_PARAM_PATTERN, (_segment, name: string) => {
+      const value = params[name];
+      if (value === undefined || value === null || value === '') {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+          messageCode: NetworkEndpointErrorCodes.MISSING_PARAM,
+          message: `[NetworkEndpoints] Missing path param | path: ${path} | param: ${name}`,
+        });
+      }
+
+      return encodeURIComponent(String(value));
+    });
+  }
+
+  static toRequestBody(body: unknown) {
+    return isPlainObject(body) || Array.isArray(body) ? JSON.stringify(body) : body;
+  }
+
+  /**
+   * JSON body of a response of either fetcher, text bodies are returned as is and empty ones as
+   * `undefined`.
+   */
+  static async readBody(response: Response | IAxiosLikeResponse): Promise<unknown> {
+    if (!(response instanceof Response)) {
+      return response.data;
+    }
+
+    const text = await response.text();
+    if (!text) {
+      return undefined;
+    }
+
+    if (!response.headers.get('content-type')?.includes('json')) {
+      return text;
+    }
+
+    return JSON.parse(text);
+  }
+
+  static parseBody<R>(opts: { name: string; body: unknown; schema?: z.ZodType }): R {
+    const { name, body, schema } = opts;
+    if (!schema) {
+      return body as R;
+    }
+
+    const rs = schema.safeParse(body);
+    if (!rs.success) {
+      const issues = rs.error.issues.map(el => `${el.path.join('.') || '<root>'} ${el.message}`);
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+        messageCode: NetworkEndpointErrorCodes.INVALID_RESPONSE,
+        message: `[NetworkEndpoints] Unexpected response body | endpoint: ${name} | issues: ${issues.join(', ')}`,
+      });
+    }
+
+    return rs.data as R;
+  }
+}
@@ -0,0 +1,219 @@
+import { TConstValue } from '@/common/types';
+import { ConcurrencyLimiter, IConcurrencySample } from './concurrency';
+
+export class ConcurrencyLimitAlgorithms {
+  static readonly AIMD = 'aimd';
+  static readonly VEGAS = 'vegas';
+
+  static readonly SCHEME_SET = new Set([this.AIMD, this.VEGAS]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
+
+export type TConcurrencyLimitAlgorithm = TConstValue<typeof ConcurrencyLimitAlgorithms>;
+
+export interface IConcurrencyLimitAlgorithm {
+  name: string;
+  getLimit(): number;
+  // New limit after a released request
+  update(sample: IConcurrencySample): number;
+}
+
+export interface IConcurrencyLimitBounds {
+  // Defaults to 20
+  initialLimit?: number;
+  // Defaults to 1
+  minLimit?: number;
+  // Defaults to 200
+  maxLimit?: number;
+}
+
+const resolveBounds = (opts: IConcurrencyLimitBounds) => {
+  const { initialLimit = 20, minLimit = 1, maxLimit = 200 } = opts;
+  return { initialLimit, minLimit: Math.max(minLimit, 1), maxLimit: Math.max(maxLimit, minLimit) };
+};
+
+// Limits only grow while they are used, an idle upstream says nothing about its capacity
+const isLimitUsed = (opts: { inflight: number; limit: number }) => opts.inflight * 2 >= opts.limit;
+
+// --------------------------------------------------------
+/**
+ * Additive increase, multiplicative decrease: one more slot per successful request, the limit
+ * cut by `backoffRatio` on a dropped or slow one.
+ */
+export class AimdLimitAlgorithm implements IConcurrencyLimitAlgorithm {
+  readonly name = ConcurrencyLimitAlgorithms.AIMD;
+
+  private limit: number;
+  private minLimit: number;
+  private maxLimit: number;
+  private backoffRatio: number;
+  private timeout: number;
+
+  constructor(
+    opts: IConcurrencyLimitBounds & {
+      // Defaults to 0.9
+      backoffRatio?: number;
+      // Milliseconds above which a request counts as dropped, defaults to 5 seconds
+      timeout?: number;
+    } = {},
+  ) {
+    const { initialLimit, minLimit, maxLimit } = resolveBounds(opts);
+
+    this.limit = initialLimit;
+    this.minLimit = minLimit;
+    this.maxLimit = maxLimit;
+    this.backoffRatio = opts.backoffRatio ?? 0.9;
+    this.timeout = opts.timeout ?? 5_000;
+  }
+
+  getLimit() {
+    return this.limit;
+  }
+
+  update(sample: IConcurrencySample) {
+    const { rtt, inflight, isDropped } = sample;
+
+    if (isDropped || rtt > this.timeout) {
+      this.limit = Math.max(this.minLimit, Math.floor(this.limit * this.backoffRatio));
+    } else if (isLimitUsed({ inflight, limit: this.limit })) {
+      this.limit = Math.min(this.maxLimit, this.limit + 1);
+    }
+
+    return this.limit;
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * TCP Vegas: the queue building up at the upstream is estimated from how much the latency
+ * exceeds the lowest latency seen, the limit grows while the queue is short and shrinks once it
+ * gets long or requests are dropped.
+ *
+ * The lowest latency is measured again every `probeInterval` samples, it may have grown for good,
+ * e.g. after the upstream moved.
+ */
+export class VegasLimitAlgorithm implements IConcurrencyLimitAlgorithm {
+  readonly name = ConcurrencyLimitAlgorithms.VEGAS;
+
+  private limit: number;
+  private minLimit: number;
+  private maxLimit: number;
+  private probeInterval: number;
+
+  private rttNoLoad = 0;
+  private samples = 0;
+
+  constructor(
+    opts: IConcurrencyLimitBounds & {
+      // Defaults to 1000
+      probeInterval?: number;
+    } = {},
+  ) {
+    const { initialLimit, minLimit, maxLimit } = resolveBounds(opts);
+
+    this.limit = initialLimit;
+    this.minLimit = minLimit;
+    this.maxLimit = maxLimit;
+    this.probeInterval = opts.probeInterval ?? 1_000;
+  }
+
+  getLimit() {
+    return this.limit;
+  }
+
+  update(sample: IConcurrencySample) {
+    const { rtt, inflight, isDropped } = sample;
+    if (rtt <= 0) {
+      return this.limit;
+    }
+
+    this.samples++;
+    if (this.samples >= this.probeInterval) {
+      this.samples = 0;
+      this.rttNoLoad = 0;
+    }
+
+    if (!this.rttNoLoad || rtt < this.rttNoLoad) {
+      this.rttNoLoad = rtt;
+      return this.limit;
+    }
+
+    const log = Math.max(1, Math.log10(this.limit));
+    const alpha = 3 * log;
+    const beta = 6 * log;
+    const queueSize = Math.ceil(this.limit * (1 - this.rttNoLoad / rtt));
+
+    let limit = this.limit;
+    if (isDropped) {
+      limit -= log;
+    } else if (!isLimitUsed({ inflight, limit: this.limit })) {
+      return this.limit;
+    } else if (queueSize <= log) {
+      limit += beta;
+    } else if (queueSize < alpha) {
+      limit += log;
+    } else if (queueSize > beta) {
+      limit -= log;
+    }
+
+    this.limit = Math.min(this.maxLimit, Math.max(this.minLimit, Math.round(limit)));
+    return this.limit;
+  }
+}
+
+// --------------------------------------------------------
+export interface IAdaptiveConcurrencyLimiterOptions {
+  // Defaults to `vegas`
+  algorithm?: TConcurrencyLimitAlgorithm | IConcurrencyLimitAlgorithm;
+  maxBackgroundWait?: number | false;
+}
+
+const toAlgorithm = (algorithm: IAdaptiveConcurrencyLimiterOptions['algorithm']) => {
+  if (algorithm && typeof algorithm === 'object') {
+    return algorithm;
+  }
+
+  return algorithm === ConcurrencyLimitAlgorithms.AIMD
+    ? new AimdLimitAlgorithm()
+    : new VegasLimitAlgorithm();
+};
+
+/**
+ * Concurrency limiter whose limit follows the latency and the failures of the upstream, like
+ * Netflix concurrency-limits. Give each upstream its own instance, the samples of one upstream
+ * say nothing about another.
+ *
+ * Requests released as dropped (errors, 429, 503 and 504 responses) shrink the limit.
+ *
+ * @example
+ * ```typescript
+ * const inventory = new NodeFetchNetworkRequest({
+ *   name: 'InventoryRequest',
+ *   networkOptions: { baseUrl: 'https://inventory.internal' },
+ *   concurrency: new AdaptiveConcurrencyLimiter({
+ *     algorithm: new AimdLimitAlgorithm({ initialLimit: 10, maxLimit: 100 }),
+ *   }),
+ * });
+ * ```
+ */
+export class AdaptiveConcurrencyLimiter extends ConcurrencyLimiter {
+  private algorithm: IConcurrencyLimitAlgorithm;
+
+  constructor(opts: IAdaptiveConcurrencyLimiterOptions = {}) {
+    const algorithm = toAlgorithm(opts.algorithm);
+    super({ maxConcurrent: algorithm.getLimit(), maxBackgroundWait: opts.maxBackgroundWait });
+
+    this.algorithm = algorithm;
+  }
+
+  getAlgorithm() {
+    return this.algorithm;
+  }
+
+  protected override onSample(sample: IConcurrencySample) {
+    this.limit = this.algorithm.update(sample);
+  }
+}
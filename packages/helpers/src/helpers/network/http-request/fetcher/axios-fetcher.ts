@@ -1,71 +1,375 @@
 import { AnyObject } from '@/common';
-import axios, { AxiosRequestConfig } from 'axios';
+import axios, { AxiosError, AxiosRequestConfig, AxiosResponse } from 'axios';
+import http from 'node:http';
 import https from 'node:https';
-import { stringify } from 'node:querystring';
-import { AbstractNetworkFetchableHelper, IRequestOptions } from './base-fetcher';
+import { pipeline, Readable } from 'node:stream';
+import { redact } from '@/helpers/logger/redaction';
+import {
+  AbstractNetworkFetchableHelper,
+  IBaseFetcherOptions,
+  IRequestOptions,
+} from './base-fetcher';
+import { THeadersInput } from './headers';
+import { KeyCaseConverter } from './key-case';
+import { PayloadMetrics } from './payload-metrics';
+import { QueryStrings } from './query';
+import { ResponseSizeGuard } from './response-size';
+import { BandwidthThrottle } from './throttle';
+import { FetchDeadline, FetcherTimeouts, TimeoutErrorCodes } from './timeouts';
+import { ITlsCredentials } from './tls';
+import { RegionSelector, ServiceDiscovery } from '../../discovery';
 import { BaseNetworkRequest } from '../base-network-request.helper';
+import { INetworkEndpoint } from '../endpoints';
+import { IErrorBodyDecoder, IResponseDateOptions, TErrorMapper } from '../response';
+import { FileRequestBody } from '../file-body';
 
 export interface IAxiosRequestOptions extends AxiosRequestConfig, IRequestOptions {
   url: string;
   method?: 'get' | 'post' | 'put' | 'patch' | 'delete' | 'options';
   params?: AnyObject;
-  body?: AnyObject;
+  body?: AnyObject | FileRequestBody;
   headers?: AnyObject;
 }
 
+const HTTP_CONTENT_LENGTH = 'content-length';
+const JSON_CONTENT_TYPE = /^application\/([\w.-]+\+)?json\b/i;
+
 // -------------------------------------------------------------
 export class AxiosFetcher extends AbstractNetworkFetchableHelper<
   'axios',
   IAxiosRequestOptions,
   axios.AxiosResponse<any, any>['data']
 > {
-  constructor(opts: { name: string; defaultConfigs: AxiosRequestConfig; logger?: any }) {
-    super({ name: opts.name, variant: 'axios' });
-    const { defaultConfigs } = opts;
-    opts?.logger?.info('Creating new network request worker instance! Name: %s', this.name);
+  private defaultHeaders?: THeadersInput;
+  private trustedAgents = new Map<boolean, https.Agent>();
+  // Plain http agent selecting the IP family, see `IBaseFetcherOptions.ipFamily`
+  private httpAgent?: http.Agent;
+  // Credentials the trusted agents were built with, reloaded credentials need new agents
+  private trustedCredentials?: ITlsCredentials;
+
+  constructor(
+    opts: { name: string; defaultConfigs: AxiosRequestConfig; logger?: any } & IBaseFetcherOptions,
+  ) {
+    const { name, defaultConfigs, logger, ...fetcherOptions } = opts;
+    super({ name, variant: 'axios', ...fetcherOptions });
+    logger?.info('Creating new network request worker instance! Name: %s', this.name);
 
-    this.worker = axios.create({ ...defaultConfigs });
+    this.defaultHeaders = defaultConfigs.headers as THeadersInput;
+    this.worker = axios.create({
+      ...defaultConfigs,
+      headers: this.mergeHeaders({ defaults: this.defaultHeaders }),
+    });
   }
 
   // -------------------------------------------------------------
-  // SEND REQUEST
+  // DISPATCH REQUEST
   // -------------------------------------------------------------
-  override send<T = any>(opts: IAxiosRequestOptions, logger?: any) {
-    const { url, method = 'get', params = {}, body: data, headers, ...rest } = opts;
+  protected override dispatch<T = any>(opts: IAxiosRequestOptions, logger?: any) {
+    const {
+      url,
+      method = 'get',
+      params = {},
+      body,
+      headers,
+      headerMerge,
+      queryArrayFormat = this.queryArrayFormat,
+      keyCase = this.keyCase,
+      maxResponseBytes = this.maxResponseBytes,
+      bandwidth,
+      connectTimeout = this.timeouts.connectTimeout,
+      readTimeout = this.timeouts.readTimeout,
+      totalTimeout = this.timeouts.totalTimeout,
+      retry,
+      socketPath = this.socketPath,
+      resolvedAddress,
+      followRedirects,
+      ...rest
+    } = opts;
+    const mergedHeaders = this.mergeHeaders({
+      defaults: this.defaultHeaders,
+      headers,
+      policy: headerMerge,
+    });
+    // Plain objects are serialized by axios, file bodies and buffers are kept
+    const data =
+      typeof body === 'string' && JSON_CONTENT_TYPE.test(mergedHeaders['content-type'] ?? '')
+        ? KeyCaseConverter.convertJson({ json: body, to: keyCase })
+        : KeyCaseConverter.convertKeys<typeof body>({ value: body, to: keyCase });
+
     const props: AxiosRequestConfig = {
       url,
       method,
-      params,
+      params: KeyCaseConverter.convertKeys({ value: params, to: keyCase }),
       data,
-      headers,
-      paramsSerializer: { serialize: p => stringify(p) },
+      // Merged here instead of by axios so that the merge policy applies
+      headers: this.withPropagationHeaders(mergedHeaders),
+      paramsSerializer: {
+        serialize: p => QueryStrings.stringify({ params: p, arrayFormat: queryArrayFormat }),
+      },
       ...rest,
     };
 
+    if (socketPath) {
+      props.socketPath = socketPath;
+    }
+
+    // 3xx responses are resolved with their `Location` instead of followed
+    if (followRedirects === false) {
+      const validateStatus = props.validateStatus ?? this.worker.defaults.validateStatus;
+      props.maxRedirects = 0;
+      props.validateStatus = status =>
+        (status >= 300 && status < 400) || !validateStatus || validateStatus(status);
+    }
+
+    // The url host is kept for the host header, SNI and certificate checks
+    if (resolvedAddress) {
+      const { address, family } = resolvedAddress;
+      props.lookup = (_hostname, _options, callback) => callback(null, address, family as 4 | 6);
+    }
+
+    // Socket idle timeout of axios
+    if (readTimeout) {
+      props.timeout = readTimeout;
+    }
+
+    // Enforced by axios while the body streams
+    if (maxResponseBytes) {
+      props.maxContentLength = maxResponseBytes;
+    }
+
+    // Stream file bodies instead of buffering them
+    if (FileRequestBody.isFileBody(data)) {
+      props.data = data.toReadable();
+      props.headers = { ...props.headers, ...data.getHeaders() };
+      props.maxBodyLength = Infinity;
+    }
+
+    const throttle = this.getBandwidthThrottle(bandwidth);
+    if (throttle && props.data instanceof Readable) {
+      props.data = this.throttleStream({ stream: props.data, throttle });
+    }
+
+    logger?.for(this.send.name).info('URL: %s | Props: %o', url, redact(props));
+
+    // Set after logging to keep the certificates out of the logs, trusted roots and pins are only
+    // checked when verifying
     const protocol = this.getProtocol(url);
     if (protocol === 'https') {
-      props.httpsAgent = new https.Agent({
-        rejectUnauthorized: opts.rejectUnauthorized ?? false,
+      const rejectUnauthorized =
+        opts.rejectUnauthorized ?? (!!this.tlsCredentials || !!this.pinning);
+      props.httpsAgent = this.getHttpsAgent({ rejectUnauthorized });
+    } else if (this.ipFamily && !props.httpAgent) {
+      this.httpAgent ??= new http.Agent({ keepAlive: true, ...this.ipFamily.getAgentOptions() });
+      props.httpAgent = this.httpAgent;
+    }
+
+    this.payloadMetrics?.observe({
+      direction: 'request',
+      method,
+      url,
+      size: PayloadMetrics.getBodySize(data),
+    });
+
+    // The total deadline spans every attempt, axios resolves once the body is read
+    const userSignal = props.signal as AbortSignal | undefined;
+    let deadline: FetchDeadline | undefined;
+    if (totalTimeout) {
+      deadline = new FetchDeadline({ url, signal: userSignal });
+      deadline.start({ code: TimeoutErrorCodes.TOTAL_TIMEOUT, timeout: totalTimeout });
+    }
+
+    const exchange = this.executeWithRetry({
+      method,
+      url,
+      logger,
+      isReplayable: !FileRequestBody.isFileBody(data),
+      headers: props.headers as AnyObject,
+      isRetryEnabled: retry,
+      execute: () => {
+        let attempt: FetchDeadline | undefined;
+        if (connectTimeout) {
+          attempt = new FetchDeadline({ url, signal: deadline?.signal ?? userSignal });
+          attempt.start({ code: TimeoutErrorCodes.CONNECT_TIMEOUT, timeout: connectTimeout });
+        }
+
+        const signal = attempt?.signal ?? deadline?.signal ?? userSignal;
+        const request: AxiosRequestConfig = { ...props, signal };
+        if (attempt) {
+          // Axios only settles once the body is read, the first chunk stops the connect timeout
+          const { onDownloadProgress } = props;
+          request.onDownloadProgress = event => {
+            attempt.stop();
+            onDownloadProgress?.(event);
+          };
+        }
+
+        return this.injectFaults({
+          method,
+          url,
+          signal: deadline?.signal ?? userSignal,
+          logger,
+          execute: () => this.worker.request<T>(request),
+          respond: status => this.getInjectedResponse<T>({ props, status }),
+        })
+          .catch(error => {
+            throw this.toRequestError({ error, signal, url, readTimeout, maxResponseBytes });
+          })
+          .finally(() => attempt?.stop());
+      },
+      // Statuses rejected by `validateStatus` are thrown with their response
+      getStatus: ({ response, error }) =>
+        response?.status ?? (error as { response?: { status?: number } })?.response?.status,
+    });
+    const response = exchange.finally(() => deadline?.stop());
+
+    if (!this.payloadMetrics && !throttle) {
+      return response;
+    }
+
+    return response.then(rs => {
+      // Only `responseType: 'stream'` bodies reach the caller unread
+      if (throttle && rs.data instanceof Readable) {
+        rs.data = this.throttleStream({ stream: rs.data, throttle });
+      }
+
+      if (this.payloadMetrics) {
+        const size =
+          PayloadMetrics.getContentLength(rs.headers?.[HTTP_CONTENT_LENGTH]) ??
+          PayloadMetrics.getBodySize(rs.data);
+        this.payloadMetrics.observe({ direction: 'response', method, url, size });
+      }
+
+      return rs;
+    });
+  }
+
+  // Injected statuses are resolved or rejected like the responses of the upstream
+  private getInjectedResponse<T>(opts: { props: AxiosRequestConfig; status: number }) {
+    const { props, status } = opts;
+    const response = {
+      status,
+      statusText: '',
+      headers: {},
+      data: '',
+      config: props,
+    } as AxiosResponse<T>;
+
+    const validateStatus = props.validateStatus ?? this.worker.defaults.validateStatus;
+    if (!validateStatus || validateStatus(status)) {
+      return response;
+    }
+
+    throw new AxiosError(
+      `Request failed with status code ${status}`,
+      status >= 500 ? AxiosError.ERR_BAD_RESPONSE : AxiosError.ERR_BAD_REQUEST,
+      response.config,
+      undefined,
+      response,
+    );
+  }
+
+  // Axios rejects aborted requests with a cancellation, the timeout is the reason of the abort
+  private toRequestError(opts: {
+    error: unknown;
+    signal?: AbortSignal;
+    url: string;
+    readTimeout?: number;
+    maxResponseBytes?: number;
+  }) {
+    const { error, signal, url, readTimeout, maxResponseBytes } = opts;
+
+    const reason = signal?.aborted ? signal.reason : undefined;
+    if (FetcherTimeouts.isError(reason)) {
+      return reason;
+    }
+
+    const { code, message = '' } = (error ?? {}) as { code?: string; message?: string };
+    if (readTimeout && code === 'ECONNABORTED') {
+      return FetcherTimeouts.getError({
+        code: TimeoutErrorCodes.READ_TIMEOUT,
+        url,
+        timeout: readTimeout,
       });
     }
 
-    logger?.for(this.send.name).info('URL: %s | Props: %o', url, props);
-    return this.worker.request<T>(props);
+    if (maxResponseBytes && /maxContentLength/.test(message)) {
+      return ResponseSizeGuard.getError({ url, maxBytes: maxResponseBytes });
+    }
+
+    return error;
+  }
+
+  // Agents of the trusted roots are reused, building the secure context of a bundle is costly
+  private getHttpsAgent(opts: { rejectUnauthorized: boolean }): https.Agent {
+    const family = this.ipFamily?.getAgentOptions();
+    if (!this.tlsCredentials && !this.pinning) {
+      return new https.Agent({ ...opts, ...family });
+    }
+
+    const credentials = this.tlsCredentials?.get();
+    if (credentials !== this.trustedCredentials) {
+      for (const agent of this.trustedAgents.values()) {
+        agent.destroy();
+      }
+
+      this.trustedAgents.clear();
+      this.trustedCredentials = credentials;
+    }
+
+    let agent = this.trustedAgents.get(opts.rejectUnauthorized);
+    if (!agent) {
+      agent = new https.Agent({
+        ...opts,
+        ...family,
+        ...credentials,
+        checkServerIdentity: this.pinning?.checkServerIdentity,
+      });
+      this.trustedAgents.set(opts.rejectUnauthorized, agent);
+    }
+
+    return agent;
+  }
+
+  private throttleStream(opts: { stream: Readable; throttle: BandwidthThrottle }): Readable {
+    // Errors of the source are forwarded to the throttled stream
+    return pipeline(opts.stream, opts.throttle.toNodeTransform(), () => {});
   }
 }
 
 // -----------------------------------------------------------------------------
-export interface IAxiosNetworkRequestOptions {
+export interface IAxiosNetworkRequestOptions extends IBaseFetcherOptions {
   name: string;
   networkOptions: Omit<AxiosRequestConfig, 'baseURL'> & {
     baseUrl?: string;
   };
+  // Resolves `service://<name>` base urls and balances the requests to their endpoints
+  discovery?: ServiceDiscovery;
+  // Base url of the fastest healthy region, see `RegionSelector`
+  regions?: RegionSelector;
+  // Error body type of the upstream, see `BaseNetworkRequest.setErrorDecoder`
+  errorDecoder?: IErrorBodyDecoder;
+  // Domain errors of the upstream failures, see `BaseNetworkRequest.setErrorMapper`
+  errorMapper?: TErrorMapper;
+  // Named endpoints invoked with `call`, see `BaseNetworkRequest.defineEndpoint`
+  endpoints?: Record<string, INetworkEndpoint>;
+  // Date format of the upstream payloads, see `BaseNetworkRequest.dateSchema`
+  dates?: IResponseDateOptions;
 }
 
 // -----------------------------------------------------------------------------
 export class AxiosNetworkRequest extends BaseNetworkRequest<'axios'> {
   constructor(opts: IAxiosNetworkRequestOptions) {
-    const { name, networkOptions } = opts;
+    const {
+      name,
+      networkOptions,
+      discovery,
+      regions,
+      errorDecoder,
+      errorMapper,
+      dates,
+      endpoints,
+      ...fetcherOptions
+    } = opts;
     const { headers, baseUrl, timeout, ...rest } = networkOptions;
 
     // Build headers with user values taking precedence
@@ -80,14 +384,21 @@ export class AxiosNetworkRequest extends BaseNetworkRequest<'axios'> {
       validateStatus: (status: number) => status < 500,
       timeout: timeout ?? 60 * 1000,
       ...rest,
-      baseURL: baseUrl,
+      // Service urls are resolved per request by getRequestUrl
+      baseURL: ServiceDiscovery.isServiceUrl(baseUrl) ? undefined : baseUrl,
       headers: mergedHeaders,
     };
 
     super({
       name,
       baseUrl,
-      fetcher: new AxiosFetcher({ name, defaultConfigs }),
+      discovery,
+      regions,
+      errorDecoder,
+      errorMapper,
+      dates,
+      endpoints,
+      fetcher: new AxiosFetcher({ name, defaultConfigs, discovery, ...fetcherOptions }),
     });
   }
 }
@@ -1,13 +1,74 @@
+import { HTTP } from '@/common/constants';
+import { AnyObject } from '@/common/types';
+import { getError } from '@/helpers/error';
+import { RequestContextStorage } from '@/helpers/request-context';
 import { TFetcherResponse, TFetcherVariant, TFetcherWorker } from '../types';
+import {
+  ConcurrencyDefaults,
+  ConcurrencyLimiter,
+  IConcurrencyLimiterOptions,
+  TRequestPriorityClass,
+} from './concurrency';
+import { HttpResponseCache, HttpResponseCacheDefaults } from './cache';
+import { FetcherCancellation } from './cancellation';
+import { FaultInjector, IFaultInjectionOptions } from './fault-injection';
+import { CookieJar } from './cookie-jar';
+import { HeaderMergePolicies, HttpHeaders, THeaderMergePolicy, THeadersInput } from './headers';
+import { FetcherExchanges } from './interceptors/common';
+import { IFetcherInterceptor } from './interceptors/types';
+import {
+  IIpFamilyOptions,
+  IpFamilySelector,
+  IResolvedAddress,
+  TIpFamilyPreference,
+} from './ip-family';
+import { KeyCases, TKeyCase } from './key-case';
+import { IOutboundAuditOptions, OutboundAudit } from './outbound-audit';
+import { IPayloadMetricsOptions, PayloadMetrics } from './payload-metrics';
+import { QueryArrayFormats, TQueryArrayFormat } from './query';
+import { ResponseSizeGuard } from './response-size';
+import { FetcherRetryDefaults, IFetcherRetryOptions, RetryBudget } from './retry';
+import { BandwidthThrottle, IBandwidthThrottleOptions } from './throttle';
+import { FetcherTimeouts, IFetcherTimeoutOptions, TimeoutErrorCodes } from './timeouts';
+import { CertificatePinning } from './pinning';
+import { ITlsTrustOptions, TlsCredentials } from './tls';
+import { BearerAuth, ITokenProvider } from './token-provider';
+import { DownstreamProber, ServiceDiscovery } from '../../discovery';
 
-const HTTP = 'http';
-const HTTPS = 'https';
+const PROTOCOL_HTTP = 'http';
+const PROTOCOL_HTTPS = 'https';
+const HTTP_USER_AGENT = 'user-agent';
 
-export interface IRequestOptions {
+export interface IRequestOptions extends IFetcherTimeoutOptions {
   url: string;
   params?: Record<string | symbol, any>;
   method?: string;
+  /** @deprecated use `connectTimeout`, `readTimeout` or `totalTimeout` */
   timeout?: number;
+  // Override the header merge policy / query array format / key case of the fetcher for this
+  // request
+  headerMerge?: THeaderMergePolicy;
+  queryArrayFormat?: TQueryArrayFormat;
+  keyCase?: TKeyCase;
+  maxResponseBytes?: number;
+  // `false` lifts the bandwidth cap of the fetcher for this request
+  bandwidth?: BandwidthThrottle | IBandwidthThrottleOptions | false;
+  // Force (`true`) or prevent (`false`) retries of this request whatever its method
+  retry?: boolean;
+  // Unix socket of this request, see `IBaseFetcherOptions.socketPath`
+  socketPath?: string;
+  // Address the connection is pinned to instead of resolving the url host, which is kept for the
+  // host header, SNI and certificate checks, see `EgressGuardInterceptor`
+  resolvedAddress?: IResolvedAddress;
+  // `false` returns 3xx responses as is instead of following their `Location`
+  followRedirects?: boolean;
+  // Order of this request when the concurrency limiter is saturated, `interactive` by default
+  priorityClass?: TRequestPriorityClass;
+  // Bearer token of this request over the one of the token provider, `false` sends none
+  bearerAuth?: string | false;
+  // Override `IBaseFetcherOptions.cancelOnDisconnect` for this request, its `signal` cancels it
+  // either way, see `FetcherCancellation`
+  cancelOnDisconnect?: boolean;
   [extra: symbol | string]: any;
 }
 
@@ -23,9 +84,93 @@ export interface IFetchable<
   patch(opts: RQ, logger?: any): Promise<RS>;
   delete(opts: RQ, logger?: any): Promise<RS>;
 
+  addInterceptor(interceptor: IFetcherInterceptor<RQ, RS>): this;
   getWorker(): TFetcherWorker<V>;
 }
 
+// -------------------------------------------------------------
+export class FetcherDefaults {
+  static readonly USER_AGENT_PRODUCT = 'ignis';
+
+  private static version?: string;
+
+  static getVersion(): string {
+    if (FetcherDefaults.version === undefined) {
+      try {
+        FetcherDefaults.version = require('@venizia/ignis-helpers/package.json').version;
+      } catch {
+        FetcherDefaults.version = '0.0.0';
+      }
+    }
+
+    return FetcherDefaults.version;
+  }
+
+  /**
+   * `ignis/0.0.6 (PaymentRequest)`
+   */
+  static getUserAgent(opts: { name: string }): string {
+    return `${FetcherDefaults.USER_AGENT_PRODUCT}/${FetcherDefaults.getVersion()} (${opts.name})`;
+  }
+}
+
+// Timeouts apply to every request of the fetcher unless the request sets its own
+export interface IBaseFetcherOptions extends IFetcherTimeoutOptions {
+  // Defaults to `ignis/<version> (<name>)`, `false` leaves the header to the runtime
+  userAgent?: string | false;
+  // How per-request headers combine with the default headers, `override` by default
+  headerMerge?: THeaderMergePolicy;
+  // How array params are serialized, `repeat` by default
+  queryArrayFormat?: TQueryArrayFormat;
+  // Case the keys of params and JSON bodies are converted to, e.g. `camel` for camelCase
+  // backends, `preserve` by default
+  keyCase?: TKeyCase;
+  // Bytes read from a response body before the request is aborted, unlimited by default
+  maxResponseBytes?: number;
+  // Record request / response body sizes per endpoint, see `PayloadMetrics`
+  payloadMetrics?: IPayloadMetricsOptions | boolean;
+  // Throughput cap of streamed bodies, shared by every request of the fetcher
+  bandwidth?: BandwidthThrottle | IBandwidthThrottleOptions;
+  // Opt-in retries of failed idempotent requests, capped by a retry budget
+  retry?: IFetcherRetryOptions;
+  interceptors?: Array<IFetcherInterceptor<any, any>>;
+  // Cookies stored from the responses and sent back, shared by fetchers given the same jar
+  cookieJar?: CookieJar;
+  // Root certificates, SPKI pins and client certificate of https requests, e.g. the bundle of a
+  // private CA
+  tls?: ITlsTrustOptions;
+  // Unix socket every request is sent to, e.g. `/var/run/docker.sock`, the url host is only sent
+  // as the `host` header
+  socketPath?: string;
+  // IP family of the connections, `race` for Happy Eyeballs, see `IpFamilySelector`
+  ipFamily?: IpFamilySelector | TIpFamilyPreference | IIpFamilyOptions;
+  // Cap on the requests in flight, shared by every fetcher given the same limiter
+  concurrency?: ConcurrencyLimiter | IConcurrencyLimiterOptions;
+  // GET responses kept by their freshness headers, see `HttpResponseCache`
+  cache?: HttpResponseCache;
+  // Token sent as `authorization: Bearer <token>` with every request, see `BearerAuth.authorize`
+  tokenProvider?: ITokenProvider;
+  // Requests to its endpoints are reported for balancing and ejection, see
+  // `ServiceDiscovery.acquire`
+  discovery?: ServiceDiscovery;
+  // Background health probe of the downstream, requests are refused while it is open, see
+  // `DownstreamProber`
+  prober?: DownstreamProber;
+  // ms a request needs at least, refused when the deadline of the incoming request leaves less,
+  // defaults to 0
+  minDeadlineBudget?: number;
+  // Cancel the requests made for an incoming request once its client disconnects, defaults to
+  // `false` for the requests which have to complete anyway, e.g. payments
+  cancelOnDisconnect?: boolean;
+  // Audit trail of the mutating requests, see `OutboundAudit`
+  audit?: OutboundAudit | IOutboundAuditOptions;
+  // Opt-in failures injected into the attempts of test / staging requests, see `FaultInjector`
+  faults?: FaultInjector | IFaultInjectionOptions;
+}
+
+type TResolvedRetryOptions = Required<Omit<IFetcherRetryOptions, 'budget'>>;
+
+// -------------------------------------------------------------
 export abstract class AbstractNetworkFetchableHelper<
   V extends TFetcherVariant,
   RQ extends IRequestOptions,
@@ -34,22 +179,558 @@ export abstract class AbstractNetworkFetchableHelper<
   protected name: string;
   protected variant: V;
   protected worker: TFetcherWorker<V>;
+  protected retry?: TResolvedRetryOptions;
+  protected retryBudget?: RetryBudget;
+  protected userAgent?: string;
+  protected headerMerge: THeaderMergePolicy;
+  protected queryArrayFormat: TQueryArrayFormat;
+  protected keyCase: TKeyCase;
+  protected maxResponseBytes?: number;
+  protected payloadMetrics?: PayloadMetrics;
+  protected bandwidth?: BandwidthThrottle;
+  protected timeouts: IFetcherTimeoutOptions;
+  protected interceptors: Array<IFetcherInterceptor<RQ, RS>>;
+  protected cookieJar?: CookieJar;
+  protected tlsCredentials?: TlsCredentials;
+  protected pinning?: CertificatePinning;
+  protected socketPath?: string;
+  protected ipFamily?: IpFamilySelector;
+  protected concurrency?: ConcurrencyLimiter;
+  protected minDeadlineBudget: number;
+  protected cache?: HttpResponseCache;
+  protected discovery?: ServiceDiscovery;
+  protected prober?: DownstreamProber;
+  protected tokenProvider?: ITokenProvider;
+  protected cancelOnDisconnect: boolean;
+  protected outboundAudit?: OutboundAudit;
+  protected faults?: FaultInjector;
 
-  constructor(opts: { name: string; variant: V }) {
+  constructor(opts: { name: string; variant: V } & IBaseFetcherOptions) {
     this.name = opts.name;
     this.variant = opts.variant;
+    this.userAgent =
+      opts.userAgent === false
+        ? undefined
+        : (opts.userAgent ?? FetcherDefaults.getUserAgent({ name: opts.name }));
+    this.headerMerge = opts.headerMerge ?? HeaderMergePolicies.OVERRIDE;
+    this.queryArrayFormat = opts.queryArrayFormat ?? QueryArrayFormats.REPEAT;
+    this.keyCase = opts.keyCase ?? KeyCases.PRESERVE;
+    this.maxResponseBytes = opts.maxResponseBytes;
+    this.bandwidth = BandwidthThrottle.from(opts.bandwidth);
+    this.timeouts = {
+      connectTimeout: opts.connectTimeout,
+      readTimeout: opts.readTimeout,
+      totalTimeout: opts.totalTimeout,
+    };
+    this.interceptors = [...(opts.interceptors ?? [])];
+    this.cookieJar = opts.cookieJar;
+    this.socketPath = opts.socketPath;
+    this.ipFamily = IpFamilySelector.from(opts.ipFamily);
+    this.concurrency = ConcurrencyLimiter.from(opts.concurrency);
+    this.minDeadlineBudget = opts.minDeadlineBudget ?? 0;
+    this.cache = opts.cache;
+    this.discovery = opts.discovery;
+    this.prober = opts.prober;
+    this.tokenProvider = opts.tokenProvider;
+    this.cancelOnDisconnect = opts.cancelOnDisconnect ?? false;
+    this.outboundAudit = OutboundAudit.from(opts.audit);
+    this.faults = FaultInjector.from(opts.faults);
+    if (opts.tls) {
+      const { pinning, ...credentials } = opts.tls;
+      this.tlsCredentials = TlsCredentials.hasCredentials(credentials)
+        ? new TlsCredentials(credentials)
+        : undefined;
+      this.pinning = pinning ? new CertificatePinning(pinning) : undefined;
+    }
+
+    if (opts.payloadMetrics) {
+      const metricsOptions = opts.payloadMetrics === true ? {} : opts.payloadMetrics;
+      this.payloadMetrics = new PayloadMetrics({ fetcher: opts.name, ...metricsOptions });
+    }
+
+    if (opts.retry) {
+      const { budget = {}, ...retry } = opts.retry;
+      this.retry = this.resolveRetry(retry);
+
+      if (budget) {
+        this.retryBudget = budget instanceof RetryBudget ? budget : new RetryBudget(budget);
+      }
+    }
   }
 
-  abstract send(opts: RQ, logger?: any): Promise<RS>;
+  // Send one request, without the interceptors
+  protected abstract dispatch(opts: RQ, logger?: any): Promise<RS>;
+
+  /**
+   * Send `opts` through the interceptors of the fetcher, see `IFetcherInterceptor`. With a
+   * concurrency limiter the request first waits for a slot, held until the response is received.
+   * Errors and overloaded statuses release the slot as dropped, see `AdaptiveConcurrencyLimiter`.
+   *
+   * Within a request scope with a deadline, `totalTimeout` is capped by the remaining budget and
+   * requests left with less than `minDeadlineBudget` fail with `FETCHER_BUDGET_EXCEEDED`.
+   *
+   * With a response cache, GET requests are answered from it first.
+   *
+   * Requests aborted by their `signal`, or by the disconnect of the incoming request with
+   * `cancelOnDisconnect`, fail with `FETCHER_REQUEST_CANCELLED`, waiting for a slot included.
+   * Requests to a downstream the prober found down fail with `DOWNSTREAM_PROBER_OPEN`.
+   *
+   * With fault rules, attempts may fail on purpose before being sent, see `FaultInjector`.
+   */
+  async send(opts: RQ, logger?: any): Promise<RS> {
+    if (!this.cache || !this.isCacheable(opts)) {
+      return this.limit(opts, logger);
+    }
+
+    return this.cache.fetch({
+      key: HttpResponseCache.getKey({ ...opts, partition: this.getCachePartition(opts) }),
+      fetch: headers => {
+        const request = headers
+          ? FetcherExchanges.withRequestHeaders({ request: opts, headers })
+          : opts;
+        return this.limit(request, logger);
+      },
+    });
+  }
+
+  // Wait for a slot of the concurrency limiter, if any, cache hits skip both the budget check and
+  // the limiter. Failures of cancelled requests are reported as cancellations
+  private async limit(opts: RQ, logger?: any): Promise<RS> {
+    this.prober?.ensureClosed({ url: opts.url });
+
+    const request = this.withCancellation(this.withDeadline(opts));
+    const { signal } = request;
+
+    try {
+      return await this.admit(request, logger);
+    } catch (error) {
+      if (signal?.aborted && !FetcherCancellation.isError(error)) {
+        throw FetcherCancellation.getError({ url: request.url, reason: signal.reason });
+      }
+      throw error;
+    }
+  }
+
+  private async admit(request: RQ, logger?: any): Promise<RS> {
+    if (!this.concurrency) {
+      return this.track(request, logger);
+    }
+
+    const release = await this.concurrency.acquire({
+      priorityClass: request.priorityClass,
+      signal: request.signal,
+    });
+    let isDropped = true;
+    try {
+      const rs = await this.track(request, logger);
+      const { status } = (rs ?? {}) as { status?: number };
+      isDropped = status !== undefined && ConcurrencyDefaults.DROPPED_STATUS_CODES.includes(status);
+      return rs;
+    } finally {
+      release({ isDropped });
+    }
+  }
+
+  // Record the outcome of mutating requests with the audit trail, if any
+  private track(opts: RQ, logger?: any): Promise<RS> {
+    if (!this.outboundAudit) {
+      return this.balance(opts, logger);
+    }
+
+    return this.outboundAudit.track({
+      fetcher: this.name,
+      request: opts,
+      execute: () => this.balance(opts, logger),
+      logger,
+    });
+  }
+
+  addInterceptor(interceptor: IFetcherInterceptor<RQ, RS>) {
+    this.interceptors.push(interceptor);
+    return this;
+  }
+
+  getCookieJar() {
+    return this.cookieJar;
+  }
+
+  getConcurrencyLimiter() {
+    return this.concurrency;
+  }
+
+  getResponseCache() {
+    return this.cache;
+  }
+
+  getTlsCredentials() {
+    return this.tlsCredentials;
+  }
+
+  getTokenProvider() {
+    return this.tokenProvider;
+  }
+
+  getProber() {
+    return this.prober;
+  }
+
+  getFaultInjector() {
+    return this.faults;
+  }
+
+  // Report the outcome of requests to discovered endpoints, errors and 5xx count as failures
+  private async balance(opts: RQ, logger?: any): Promise<RS> {
+    const release = this.discovery?.acquire({ url: opts.url });
+    if (!release) {
+      return this.intercept(opts, logger);
+    }
+
+    let status: number | undefined;
+    try {
+      const rs = await this.intercept(opts, logger);
+      ({ status } = (rs ?? {}) as { status?: number });
+      return rs;
+    } catch (error) {
+      // Axios throws the rejected statuses with their response
+      ({ status } = (error as { response?: { status?: number } })?.response ?? {});
+      throw error;
+    } finally {
+      release({ isFailure: status === undefined || status >= 500 });
+    }
+  }
+
+  // Bearer token and request hooks, the exchange then the response hooks. Replays read the token
+  // again
+  private async intercept(opts: RQ, logger?: any): Promise<RS> {
+    const authorize = (request: RQ) =>
+      BearerAuth.authorize({ request, provider: this.tokenProvider });
+    if (!this.interceptors.length) {
+      return this.exchange(await authorize(opts), logger);
+    }
+
+    const prepare = async (base: RQ) => {
+      let request = await authorize(base);
+      for (const interceptor of this.interceptors) {
+        request = (await interceptor.onRequest?.({ request })) ?? request;
+      }
+      return request;
+    };
+
+    const request = await prepare(opts);
+    const replay = async (replayOpts?: { request?: RQ }) =>
+      this.exchange(await prepare(replayOpts?.request ?? opts), logger);
+
+    let response = await this.exchange(request, logger);
+    for (const interceptor of this.interceptors) {
+      response = (await interceptor.onResponse?.({ request, response, replay })) ?? response;
+    }
+
+    return response;
+  }
+
+  // Requests carry the cookies of the jar, every response updates it, replays included
+  private async exchange(opts: RQ, logger?: any): Promise<RS> {
+    const jar = this.cookieJar;
+    if (!jar || !URL.canParse(opts.url)) {
+      return this.dispatch(opts, logger);
+    }
+
+    const cookie = jar.getCookieHeader({ url: opts.url });
+    const request = cookie
+      ? FetcherExchanges.withRequestHeaders({
+          request: opts,
+          headers: { cookie },
+          policy: HeaderMergePolicies.APPEND,
+        })
+      : opts;
+
+    const response = await this.dispatch(request, logger);
+    jar.setCookies({ url: opts.url, setCookies: FetcherExchanges.getSetCookies(response) });
+
+    return response;
+  }
+
+  private resolveRetry(retry: Omit<IFetcherRetryOptions, 'budget'>): TResolvedRetryOptions {
+    const { idempotencyKeyHeader = FetcherRetryDefaults.IDEMPOTENCY_KEY_HEADER } = retry;
+
+    return {
+      maxAttempts: retry.maxAttempts ?? FetcherRetryDefaults.MAX_ATTEMPTS,
+      baseDelay: retry.baseDelay ?? FetcherRetryDefaults.BASE_DELAY,
+      maxDelay: retry.maxDelay ?? FetcherRetryDefaults.MAX_DELAY,
+      factor: retry.factor ?? FetcherRetryDefaults.FACTOR,
+      methods: (retry.methods ?? FetcherRetryDefaults.METHODS).map(m => m.toLowerCase()),
+      statusCodes: retry.statusCodes ?? FetcherRetryDefaults.STATUS_CODES,
+      idempotencyKeyHeader: idempotencyKeyHeader && idempotencyKeyHeader.toLowerCase(),
+    };
+  }
 
   getProtocol(url: string) {
-    return url.startsWith('http:') ? HTTP : HTTPS;
+    return url.startsWith('http:') ? PROTOCOL_HTTP : PROTOCOL_HTTPS;
   }
 
   getWorker() {
     return this.worker;
   }
 
+  getRetryBudget() {
+    return this.retryBudget;
+  }
+
+  getUserAgent() {
+    return this.userAgent;
+  }
+
+  getPayloadMetrics() {
+    return this.payloadMetrics;
+  }
+
+  /**
+   * Throttle of a request, the per-request one wins over the one of the fetcher.
+   */
+  protected getBandwidthThrottle(bandwidth?: IRequestOptions['bandwidth']) {
+    if (bandwidth === false) {
+      return undefined;
+    }
+
+    return BandwidthThrottle.from(bandwidth) ?? this.bandwidth;
+  }
+
+  /**
+   * Merge per-request headers into the default headers with the merge policy of the fetcher,
+   * see `HttpHeaders.merge`. The user agent is only added when neither of them sets one.
+   */
+  protected mergeHeaders(opts: {
+    defaults?: THeadersInput;
+    headers?: THeadersInput;
+    policy?: THeaderMergePolicy;
+  }): Record<string, string> {
+    const { defaults, headers, policy = this.headerMerge } = opts;
+    const rs = HttpHeaders.merge({ defaults, headers, policy });
+
+    if (this.userAgent && !rs[HTTP_USER_AGENT]) {
+      rs[HTTP_USER_AGENT] = this.userAgent;
+    }
+
+    return rs;
+  }
+
+  // -------------------------------------------------------------
+  protected getRetryDelay(attempt: number) {
+    const { baseDelay, maxDelay, factor } = this.retry!;
+    const delay = baseDelay * Math.pow(factor, Math.max(attempt - 1, 0));
+
+    // Full jitter spreads the retries of concurrent callers
+    return Math.min(maxDelay, Math.round(Math.random() * delay));
+  }
+
+  /**
+   * Run `execute` under the retry policy of the fetcher.
+   *
+   * Network errors, connect / read timeouts and the configured statuses are retried for the
+   * configured methods, aborted requests (total timeouts, cancellations, oversized responses)
+   * are not. Every retry is withdrawn from the retry budget, once it is spent the last outcome is
+   * returned as is.
+   *
+   * Only idempotent methods are retried by default. Other requests are retried when they carry
+   * the idempotency key header or opt in with `retry: true`, which also applies the default
+   * policy to fetchers configured without one.
+   */
+  protected async executeWithRetry<R>(opts: {
+    method: string;
+    url: string;
+    // `false` for bodies which can only be read once, e.g. streams
+    isReplayable?: boolean;
+    execute: () => Promise<R>;
+    getStatus: (opts: { response?: R; error?: unknown }) => number | undefined;
+    // Release a response which is about to be retried
+    discard?: (response: R) => void;
+    // Headers sent with the request, checked for the idempotency key
+    headers?: AnyObject;
+    // Per-request override, see `IRequestOptions.retry`
+    isRetryEnabled?: boolean;
+    logger?: any;
+  }): Promise<R> {
+    const {
+      method,
+      url,
+      isReplayable = true,
+      execute,
+      getStatus,
+      discard,
+      headers = {},
+      isRetryEnabled,
+      logger,
+    } = opts;
+    const log = logger?.for(this.executeWithRetry.name);
+
+    this.retryBudget?.recordRequest();
+
+    const retry = this.retry ?? (isRetryEnabled ? this.resolveRetry({}) : undefined);
+    if (!retry || !isReplayable || isRetryEnabled === false) {
+      return execute();
+    }
+
+    const { idempotencyKeyHeader } = retry;
+    const hasIdempotencyKey =
+      !!idempotencyKeyHeader &&
+      Object.keys(headers).some(key => key.toLowerCase() === idempotencyKeyHeader);
+    const isAllowed =
+      isRetryEnabled || hasIdempotencyKey || retry.methods.includes(method.toLowerCase());
+
+    if (!isAllowed) {
+      return execute();
+    }
+
+    for (let attempt = 1; ; attempt++) {
+      let response: R | undefined;
+      let error: unknown;
+
+      try {
+        response = await execute();
+      } catch (e) {
+        error = e;
+      }
+
+      const status = getStatus({ response, error });
+      const { name, code } = (error ?? {}) as { name?: string; code?: string };
+      const isAborted =
+        name === 'AbortError' ||
+        code === 'ECONNABORTED' ||
+        code === 'ERR_CANCELED' ||
+        ResponseSizeGuard.isError(error) ||
+        FetcherTimeouts.isError(error, TimeoutErrorCodes.TOTAL_TIMEOUT);
+      const isRetryable =
+        !isAborted &&
+        (status === undefined ? error !== undefined : retry.statusCodes.includes(status));
+
+      let isRetrying = isRetryable && attempt < retry.maxAttempts;
+      if (isRetrying && this.retryBudget && !this.retryBudget.tryAcquire()) {
+        log?.warn('Retry budget exhausted | URL: %s | Attempt: %d', url, attempt);
+        isRetrying = false;
+      }
+
+      if (!isRetrying) {
+        if (error !== undefined) {
+          throw error;
+        }
+
+        return response as R;
+      }
+
+      if (response !== undefined) {
+        discard?.(response);
+      }
+
+      const delay = this.getRetryDelay(attempt);
+      log?.warn(
+        'Retrying | URL: %s | Attempt: %d | Status: %s | Delay: %d',
+        url,
+        attempt,
+        status,
+        delay,
+      );
+      await new Promise(resolve => setTimeout(resolve, delay));
+    }
+  }
+
+  /**
+   * Run one attempt of a request, unless the fault injector of the fetcher fails it first.
+   * `respond` builds the response of injected statuses.
+   */
+  protected injectFaults<R>(opts: {
+    method: string;
+    url: string;
+    signal?: AbortSignal | null;
+    execute: () => Promise<R>;
+    respond: (status: number) => R;
+    logger?: any;
+  }): Promise<R> {
+    return this.faults ? this.faults.apply(opts) : opts.execute();
+  }
+
+  // Only GET requests which do not ask to bypass the cache
+  private isCacheable(request: RQ) {
+    if ((request.method ?? 'get').toLowerCase() !== 'get') {
+      return false;
+    }
+
+    const cacheControl = FetcherExchanges.getRequestHeader({ request, name: 'cache-control' });
+    return !/no-cache|no-store/i.test(cacheControl ?? '');
+  }
+
+  // Values of the partition headers, the per-request bearer token and the propagated tenant are
+  // only added after the cache lookup
+  private getCachePartition(request: RQ) {
+    const rs: Record<string, string | undefined> = {};
+    for (const name of HttpResponseCacheDefaults.PARTITION_HEADERS) {
+      rs[name] = FetcherExchanges.getRequestHeader({ request, name });
+    }
+
+    if (typeof request.bearerAuth === 'string') {
+      rs.authorization = `Bearer ${request.bearerAuth}`;
+    }
+
+    rs['x-tenant-id'] ??= RequestContextStorage.get()?.tenantId;
+    return rs;
+  }
+
+  private withCancellation(opts: RQ): RQ {
+    const { cancelOnDisconnect = this.cancelOnDisconnect, ...request } = opts;
+    const signal = FetcherCancellation.getSignal({
+      signal: request.signal,
+      isBoundToContext: cancelOnDisconnect,
+    });
+
+    if (signal?.aborted) {
+      throw FetcherCancellation.getError({ url: request.url, reason: signal.reason });
+    }
+
+    return { ...request, signal } as RQ;
+  }
+
+  private withDeadline(opts: RQ): RQ {
+    const remaining = RequestContextStorage.getRemainingBudget();
+    if (remaining === undefined) {
+      return opts;
+    }
+
+    if (remaining <= this.minDeadlineBudget) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.GatewayTimeout,
+        messageCode: TimeoutErrorCodes.BUDGET_EXCEEDED,
+        message: `[${this.name}] Request budget exceeded | remaining: ${Math.max(remaining, 0)}ms | minDeadlineBudget: ${this.minDeadlineBudget}ms | url: ${opts.url}`,
+      });
+    }
+
+    const totalTimeout = opts.totalTimeout ?? this.timeouts.totalTimeout ?? Infinity;
+    return { ...opts, totalTimeout: Math.min(totalTimeout, Math.ceil(remaining)) };
+  }
+
+  /**
+   * Add the ids of the current request context (request id, tenant) to outgoing headers.
+   * Headers set by the caller win, requests made outside of a request scope are unchanged.
+   */
+  protected withPropagationHeaders<H extends AnyObject | Headers | undefined>(
+    headers: H,
+  ): H | AnyObject {
+    const propagated = RequestContextStorage.getPropagationHeaders();
+    if (!Object.keys(propagated).length) {
+      return headers;
+    }
+
+    const rs: AnyObject =
+      headers instanceof Headers ? Object.fromEntries(headers.entries()) : { ...headers };
+    const existed = new Set(Object.keys(rs).map(key => key.toLowerCase()));
+
+    for (const [key, value] of Object.entries(propagated)) {
+      if (!existed.has(key)) {
+        rs[key] = value;
+      }
+    }
+
+    return rs;
+  }
+
   // -------------------------------------------------------------
   // GET REQUEST
   // -------------------------------------------------------------
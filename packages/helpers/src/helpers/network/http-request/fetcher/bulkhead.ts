@@ -0,0 +1,130 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import {
+  ConcurrencyLimiter,
+  IConcurrencyAcquireOptions,
+  IConcurrencyLimiterOptions,
+  TConcurrencyRelease,
+} from './concurrency';
+
+export class BulkheadErrorCodes {
+  static readonly FULL = 'BULKHEAD_FULL';
+  static readonly UNKNOWN_BULKHEAD = 'UNKNOWN_BULKHEAD';
+}
+
+export interface IBulkheadOptions extends IConcurrencyLimiterOptions {
+  name: string;
+  // Requests waiting for a slot before new ones are rejected, defaults to 0
+  maxQueued?: number;
+}
+
+// --------------------------------------------------------
+/**
+ * Concurrency limiter of one downstream which rejects requests instead of queueing them
+ * without bound, so a slow partner fails fast rather than holding every task of the service.
+ *
+ * Rejections are 503 errors with the `BULKHEAD_FULL` message code.
+ */
+export class Bulkhead extends ConcurrencyLimiter {
+  readonly name: string;
+
+  private maxQueued: number;
+  private rejected = 0;
+
+  constructor(opts: IBulkheadOptions) {
+    super(opts);
+
+    this.name = opts.name;
+    this.maxQueued = Math.max(opts.maxQueued ?? 0, 0);
+  }
+
+  getRejected() {
+    return this.rejected;
+  }
+
+  override acquire(opts: IConcurrencyAcquireOptions = {}) {
+    if (this.getActive() < this.getLimit() || this.getQueued() < this.maxQueued) {
+      return super.acquire(opts);
+    }
+
+    this.rejected++;
+    return Promise.reject<TConcurrencyRelease>(
+      getError({
+        statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+        messageCode: BulkheadErrorCodes.FULL,
+        message: `[Bulkhead] Bulkhead is full | name: ${this.name} | maxConcurrent: ${this.getLimit()} | maxQueued: ${this.maxQueued}`,
+      }),
+    );
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * Named bulkheads of a service, one per downstream. Fetchers of the same downstream share its
+ * bulkhead through the registry.
+ *
+ * @example
+ * ```typescript
+ * const bulkheads = new BulkheadRegistry({
+ *   defaults: { maxConcurrent: 16, maxQueued: 32 },
+ *   bulkheads: [{ name: 'payments', maxConcurrent: 4, maxQueued: 8 }],
+ * });
+ *
+ * const payments = new NodeFetchNetworkRequest({
+ *   name: 'PaymentsRequest',
+ *   networkOptions: { baseUrl: 'https://payments.partner.com' },
+ *   concurrency: bulkheads.get({ name: 'payments' }),
+ * });
+ * ```
+ */
+export class BulkheadRegistry {
+  private defaults?: Omit<IBulkheadOptions, 'name'>;
+  private bulkheads = new Map<string, Bulkhead>();
+
+  constructor(
+    opts: {
+      // Options of the bulkheads created on first use, unknown names throw when not set
+      defaults?: Omit<IBulkheadOptions, 'name'>;
+      bulkheads?: Array<IBulkheadOptions>;
+    } = {},
+  ) {
+    this.defaults = opts.defaults;
+
+    for (const bulkhead of opts.bulkheads ?? []) {
+      this.register(bulkhead);
+    }
+  }
+
+  register(opts: IBulkheadOptions) {
+    const bulkhead = new Bulkhead(opts);
+    this.bulkheads.set(opts.name, bulkhead);
+    return bulkhead;
+  }
+
+  get(opts: { name: string }) {
+    const bulkhead = this.bulkheads.get(opts.name);
+    if (bulkhead) {
+      return bulkhead;
+    }
+
+    if (!this.defaults) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: BulkheadErrorCodes.UNKNOWN_BULKHEAD,
+        message: `[BulkheadRegistry] Unknown bulkhead | name: ${opts.name}`,
+      });
+    }
+
+    return this.register({ ...this.defaults, name: opts.name });
+  }
+
+  getStats() {
+    return [...this.bulkheads.values()].map(bulkhead => ({
+      name: bulkhead.name,
+      maxConcurrent: bulkhead.getLimit(),
+      active: bulkhead.getActive(),
+      queued: bulkhead.getQueued(),
+      rejected: bulkhead.getRejected(),
+    }));
+  }
+}
@@ -0,0 +1,300 @@
+import C from 'node:crypto';
+
+export interface IHttpResponseCacheOptions {
+  // Entries kept, the least recently used one is evicted first, defaults to 1000
+  maxEntries?: number;
+  // Bodies larger than this many bytes are not cached, defaults to 1 MiB
+  maxEntryBytes?: number;
+  // ms responses without `max-age` / `expires` stay fresh, defaults to 0 (not cached)
+  defaultTtl?: number;
+  // ms an expired entry is still served while it is refreshed in the background, defaults to the
+  // `stale-while-revalidate` directive of the response
+  maxStale?: number;
+  // ms an expired entry is served when the refresh fails (network error or 5xx), defaults to the
+  // `stale-if-error` directive of the response
+  errorGrace?: number;
+}
+
+interface ICacheEntry {
+  status: number;
+  statusText: string;
+  headers: Array<[string, string]>;
+  body: ArrayBuffer;
+  storedAt: number;
+  ttl: number;
+  maxStale: number;
+  errorGrace: number;
+}
+
+export class HttpResponseCacheDefaults {
+  static readonly MAX_ENTRIES = 1_000;
+  static readonly MAX_ENTRY_BYTES = 1024 * 1024;
+  // Heuristically cacheable statuses of RFC 9110
+  static readonly STATUS_CODES = [200, 203, 204, 300, 301, 404, 405, 410, 414, 501];
+  // Request headers partitioning the entries, the responses of a user or tenant are only served
+  // to the requests of the same one
+  static readonly PARTITION_HEADERS = ['authorization', 'cookie', 'x-tenant-id'];
+}
+
+const parseCacheControl = (header?: string | null) => {
+  const rs = new Map<string, string>();
+  for (const part of (header ?? '').split(',')) {
+    const [name, value = ''] = part.trim().split('=');
+    if (name) {
+      rs.set(name.toLowerCase(), value.replace(/^"|"$/g, ''));
+    }
+  }
+
+  return rs;
+};
+
+// Seconds of a `max-age=<n>` style directive in ms
+const getDirectiveMs = (opts: { directives: Map<string, string>; name: string }) => {
+  const value = Number(opts.directives.get(opts.name));
+  return opts.directives.has(opts.name) && Number.isFinite(value) ? value * 1_000 : undefined;
+};
+
+// --------------------------------------------------------
+/**
+ * Private cache of the GET responses of node fetchers, freshness from `cache-control: max-age`
+ * or `expires`.
+ *
+ * Expired entries are served right away during `maxStale` while one background request per url
+ * refreshes them, revalidating with `if-none-match` when the response had an etag. During
+ * `errorGrace` an expired entry also answers for a refresh which failed. Suited to slow but
+ * rarely changing endpoints, e.g. catalogs.
+ *
+ * Responses with `no-store` or a `vary` on other headers than `accept-encoding` are not cached,
+ * requests with `cache-control: no-cache` skip the cache. Entries are partitioned by the
+ * `authorization`, `cookie` and `x-tenant-id` of the requests, per-request bearer tokens and the
+ * tenant of the request context included, so a fetcher shared by users or tenants never serves
+ * the response of one to another.
+ *
+ * @example
+ * ```typescript
+ * const catalog = new NodeFetchNetworkRequest({
+ *   name: 'CatalogRequest',
+ *   networkOptions: { baseUrl: 'https://catalog.partner.com' },
+ *   cache: new HttpResponseCache({ maxStale: 10 * 60_000, errorGrace: 60 * 60_000 }),
+ * });
+ * ```
+ */
+export class HttpResponseCache {
+  private maxEntries: number;
+  private maxEntryBytes: number;
+  private defaultTtl: number;
+  private maxStale?: number;
+  private errorGrace?: number;
+
+  private entries = new Map<string, ICacheEntry>();
+  private revalidations = new Map<string, Promise<unknown>>();
+
+  constructor(opts: IHttpResponseCacheOptions = {}) {
+    this.maxEntries = opts.maxEntries ?? HttpResponseCacheDefaults.MAX_ENTRIES;
+    this.maxEntryBytes = opts.maxEntryBytes ?? HttpResponseCacheDefaults.MAX_ENTRY_BYTES;
+    this.defaultTtl = opts.defaultTtl ?? 0;
+    this.maxStale = opts.maxStale;
+    this.errorGrace = opts.errorGrace;
+  }
+
+  /**
+   * Key of a request, its url and params then a SHA-256 of the values of its partition headers,
+   * whose credentials are not kept as is.
+   */
+  static getKey(opts: {
+    url: string;
+    params?: object;
+    partition?: Record<string, string | undefined>;
+  }) {
+    const { url, params, partition = {} } = opts;
+    const rs = params && Object.keys(params).length ? `${url} ${JSON.stringify(params)}` : url;
+
+    const values = Object.entries(partition).filter(([, value]) => value !== undefined);
+    if (!values.length) {
+      return rs;
+    }
+
+    const hash = C.createHash('sha256').update(JSON.stringify(values)).digest('hex');
+    return `${rs} ${hash}`;
+  }
+
+  size() {
+    return this.entries.size;
+  }
+
+  delete(opts: { key: string }) {
+    return this.entries.delete(opts.key);
+  }
+
+  clear() {
+    this.entries.clear();
+  }
+
+  /**
+   * Answer from the cache or `fetch`, whose extra headers carry the revalidation validators.
+   * Responses which are not a `Response` pass through uncached.
+   */
+  async fetch<RS>(opts: {
+    key: string;
+    fetch: (headers?: Record<string, string>) => Promise<RS>;
+  }): Promise<RS> {
+    const { key, fetch } = opts;
+
+    const entry = this.entries.get(key);
+    if (!entry) {
+      return this.store({ key, response: await fetch() });
+    }
+
+    // Most recently used last
+    this.entries.delete(key);
+    this.entries.set(key, entry);
+
+    const age = Date.now() - entry.storedAt;
+    if (age < entry.ttl) {
+      return this.toResponse(entry) as RS;
+    }
+
+    if (age < entry.ttl + entry.maxStale) {
+      this.revalidate({ key, entry, fetch }).catch(() => {});
+      return this.toResponse(entry) as RS;
+    }
+
+    const isGraced = age < entry.ttl + entry.errorGrace;
+    try {
+      const response = await this.revalidate({ key, entry, fetch });
+      if (isGraced && response instanceof Response && response.status >= 500) {
+        response.body?.cancel().catch(() => {});
+        return this.toResponse(entry) as RS;
+      }
+
+      return response;
+    } catch (error) {
+      if (isGraced) {
+        return this.toResponse(entry) as RS;
+      }
+
+      throw error;
+    }
+  }
+
+  // --------------------------------------------------------
+  // One refresh per key, a 304 only renews the stored entry
+  private revalidate<RS>(opts: {
+    key: string;
+    entry: ICacheEntry;
+    fetch: (headers?: Record<string, string>) => Promise<RS>;
+  }): Promise<RS> {
+    const { key, entry, fetch } = opts;
+
+    const running = this.revalidations.get(key);
+    if (running) {
+      return running as Promise<RS>;
+    }
+
+    const etag = entry.headers.find(([name]) => name === 'etag')?.[1];
+    const revalidation = fetch(etag ? { ['if-none-match']: etag } : undefined)
+      .then(async response => {
+        if (!(response instanceof Response) || response.status !== 304) {
+          return this.store({ key, response });
+        }
+
+        // Headers of the 304 replace the stored ones, freshness included
+        response.body?.cancel().catch(() => {});
+        const headers = new Headers(entry.headers);
+        for (const [name, value] of response.headers) {
+          headers.set(name, value);
+        }
+
+        const { status, statusText, body } = entry;
+        const renewed = this.toEntry({ status, statusText, headers, body });
+        this.entries.set(key, renewed);
+        return this.toResponse(renewed) as RS;
+      })
+      .finally(() => {
+        this.revalidations.delete(key);
+      });
+
+    this.revalidations.set(key, revalidation);
+    return revalidation;
+  }
+
+  private async store<RS>(opts: { key: string; response: RS }): Promise<RS> {
+    const { key, response } = opts;
+    if (!(response instanceof Response) || !this.isStorable(response)) {
+      return response;
+    }
+
+    // Buffered once, every hit gets its own copy
+    const body = await response.arrayBuffer();
+    const { status, statusText, headers } = response;
+    const entry = this.toEntry({ status, statusText, headers, body });
+    const isUseful = entry.ttl + Math.max(entry.maxStale, entry.errorGrace) > 0;
+    if (!isUseful || body.byteLength > this.maxEntryBytes) {
+      return this.toResponse(entry) as RS;
+    }
+
+    this.entries.delete(key);
+    this.entries.set(key, entry);
+    while (this.entries.size > this.maxEntries) {
+      this.entries.delete(this.entries.keys().next().value!);
+    }
+
+    return this.toResponse(entry) as RS;
+  }
+
+  private isStorable(response: Response) {
+    if (!HttpResponseCacheDefaults.STATUS_CODES.includes(response.status)) {
+      return false;
+    }
+
+    const directives = parseCacheControl(response.headers.get('cache-control'));
+    if (directives.has('no-store')) {
+      return false;
+    }
+
+    const length = Number(response.headers.get('content-length'));
+    if (Number.isFinite(length) && length > this.maxEntryBytes) {
+      return false;
+    }
+
+    const vary = (response.headers.get('vary') ?? '').toLowerCase().split(',');
+    return vary.every(name => !name.trim() || name.trim() === 'accept-encoding');
+  }
+
+  private toEntry(
+    opts: Pick<ICacheEntry, 'status' | 'statusText' | 'body'> & { headers: Headers },
+  ): ICacheEntry {
+    const { status, statusText, headers, body } = opts;
+    const directives = parseCacheControl(headers.get('cache-control'));
+
+    let ttl = directives.has('no-cache') ? 0 : getDirectiveMs({ directives, name: 'max-age' });
+    if (ttl === undefined) {
+      const expires = Date.parse(headers.get('expires') ?? '');
+      ttl = Number.isNaN(expires) ? this.defaultTtl : expires - Date.now();
+    }
+
+    const maxStale =
+      this.maxStale ?? getDirectiveMs({ directives, name: 'stale-while-revalidate' }) ?? 0;
+    const errorGrace =
+      this.errorGrace ?? getDirectiveMs({ directives, name: 'stale-if-error' }) ?? 0;
+
+    return {
+      status,
+      statusText,
+      headers: [...headers.entries()],
+      body,
+      storedAt: Date.now(),
+      ttl,
+      maxStale,
+      errorGrace,
+    };
+  }
+
+  private toResponse(entry: ICacheEntry) {
+    const { status, statusText, headers, body, storedAt } = entry;
+
+    const rs = new Response(status === 204 ? null : body.slice(0), { status, statusText, headers });
+    rs.headers.set('age', `${Math.floor((Date.now() - storedAt) / 1_000)}`);
+    return rs;
+  }
+}
@@ -0,0 +1,54 @@
+import { HTTP } from '@/common/constants';
+import { ApplicationError, getError } from '@/helpers/error';
+import { RequestContextStorage } from '@/helpers/request-context';
+
+export class CancellationErrorCodes {
+  // Aborted by the signal of the caller or by the disconnect of the incoming request
+  static readonly REQUEST_CANCELLED = 'FETCHER_REQUEST_CANCELLED';
+}
+
+// --------------------------------------------------------
+/**
+ * Signals aborting the requests nobody waits for anymore.
+ *
+ * A request is cancelled by its own `signal`, and with `cancelOnDisconnect` by the signal of the
+ * incoming request in scope, see `IRequestContext.signal`. Cancelled requests stop waiting for a
+ * concurrency slot, close their connection and fail with `FETCHER_REQUEST_CANCELLED`, they are
+ * never retried.
+ */
+export class FetcherCancellation {
+  static getError(opts: { url: string; reason?: unknown }) {
+    const { url, reason } = opts;
+
+    return getError({
+      statusCode: HTTP.ResultCodes.RS_4.ClientClosedRequest,
+      messageCode: CancellationErrorCodes.REQUEST_CANCELLED,
+      message: `[FetcherCancellation] Request cancelled | url: ${url} | reason: ${reason}`,
+    });
+  }
+
+  static isError(error: unknown): error is ApplicationError {
+    return (
+      error instanceof ApplicationError &&
+      error.messageCode === CancellationErrorCodes.REQUEST_CANCELLED
+    );
+  }
+
+  /**
+   * Signal of a request, the one of the caller combined with the one of the incoming request
+   * when `isBoundToContext`.
+   */
+  static getSignal(opts: {
+    signal?: AbortSignal | null;
+    isBoundToContext?: boolean;
+  }): AbortSignal | undefined {
+    const { signal, isBoundToContext } = opts;
+    const disconnect = isBoundToContext ? RequestContextStorage.get()?.signal : undefined;
+
+    if (!signal || !disconnect) {
+      return signal ?? disconnect;
+    }
+
+    return AbortSignal.any([signal, disconnect]);
+  }
+}
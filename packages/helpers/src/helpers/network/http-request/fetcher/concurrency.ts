@@ -0,0 +1,206 @@
+import { HTTP } from '@/common/constants';
+import { TConstValue } from '@/common/types';
+import { getError } from '@/helpers/error';
+
+export class RequestPriorityClasses {
+  // User facing requests, served first when the limiter is saturated
+  static readonly INTERACTIVE = 'interactive';
+  // Sync jobs and prefetches, yield to interactive requests
+  static readonly BACKGROUND = 'background';
+
+  static readonly SCHEME_SET = new Set([this.INTERACTIVE, this.BACKGROUND]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
+
+export type TRequestPriorityClass = TConstValue<typeof RequestPriorityClasses>;
+
+export interface IConcurrencyLimiterOptions {
+  maxConcurrent: number;
+  // Milliseconds after which a waiting background request goes first, so sustained interactive
+  // traffic can not starve it, defaults to 30 seconds, `false` for strict priority
+  maxBackgroundWait?: number | false;
+}
+
+export interface IConcurrencySample {
+  // Milliseconds between the grant of the slot and its release
+  rtt: number;
+  // Requests in flight when the slot was granted, this one included
+  inflight: number;
+  // Failed or rejected by an overloaded upstream
+  isDropped: boolean;
+}
+
+export interface IConcurrencyAcquireOptions {
+  priorityClass?: TRequestPriorityClass;
+  // Gives up waiting for a slot, e.g. the request was cancelled
+  signal?: AbortSignal;
+}
+
+export type TConcurrencyRelease = (opts?: { isDropped?: boolean }) => void;
+
+interface IWaiter {
+  resolve: (release: TConcurrencyRelease) => void;
+  queuedAt: number;
+}
+
+export class ConcurrencyDefaults {
+  static readonly MAX_BACKGROUND_WAIT = 30_000;
+  // Statuses of an overloaded upstream, released as dropped
+  static readonly DROPPED_STATUS_CODES = [429, 503, 504];
+}
+
+// --------------------------------------------------------
+/**
+ * Cap on the requests a fetcher has in flight, waiting requests are served by priority class
+ * instead of FIFO.
+ *
+ * Every fetcher given the same instance shares its slots, so a background sync and the user
+ * facing calls to the same upstream can share one cap.
+ *
+ * @example
+ * ```typescript
+ * const limiter = new ConcurrencyLimiter({ maxConcurrent: 8 });
+ * const catalog = new NodeFetchNetworkRequest({
+ *   name: 'CatalogRequest',
+ *   networkOptions: { baseUrl: 'https://catalog.internal' },
+ *   concurrency: limiter,
+ * });
+ *
+ * await catalog.getNetworkService().get({ url, priorityClass: 'background' });
+ * ```
+ */
+export class ConcurrencyLimiter {
+  protected limit: number;
+
+  private maxBackgroundWait: number | false;
+  private active = 0;
+  private queues: Record<TRequestPriorityClass, Array<IWaiter>> = {
+    [RequestPriorityClasses.INTERACTIVE]: [],
+    [RequestPriorityClasses.BACKGROUND]: [],
+  };
+
+  constructor(opts: IConcurrencyLimiterOptions) {
+    const { maxConcurrent, maxBackgroundWait = ConcurrencyDefaults.MAX_BACKGROUND_WAIT } = opts;
+
+    if (!Number.isInteger(maxConcurrent) || maxConcurrent < 1) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[ConcurrencyLimiter] Invalid limit | maxConcurrent: ${maxConcurrent}`,
+      });
+    }
+
+    this.limit = maxConcurrent;
+    this.maxBackgroundWait = maxBackgroundWait;
+  }
+
+  static from(opts?: ConcurrencyLimiter | IConcurrencyLimiterOptions) {
+    if (!opts || opts instanceof ConcurrencyLimiter) {
+      return opts;
+    }
+
+    return new ConcurrencyLimiter(opts);
+  }
+
+  getLimit() {
+    return this.limit;
+  }
+
+  getActive() {
+    return this.active;
+  }
+
+  getQueued(priorityClass?: TRequestPriorityClass) {
+    if (priorityClass) {
+      return this.queues[priorityClass].length;
+    }
+
+    return this.queues.interactive.length + this.queues.background.length;
+  }
+
+  /**
+   * Resolve with the release of a slot once the request may be sent, aborting `signal` rejects
+   * with its reason and leaves the queue.
+   */
+  acquire(opts: IConcurrencyAcquireOptions = {}): Promise<TConcurrencyRelease> {
+    const { priorityClass = RequestPriorityClasses.INTERACTIVE, signal } = opts;
+
+    if (signal?.aborted) {
+      return Promise.reject(signal.reason);
+    }
+
+    if (this.active < this.limit) {
+      this.active++;
+      return Promise.resolve(this.createRelease());
+    }
+
+    const queue = this.queues[priorityClass] ?? this.queues.interactive;
+    return new Promise((resolve, reject) => {
+      const waiter: IWaiter = { resolve, queuedAt: Date.now() };
+      queue.push(waiter);
+
+      if (!signal) {
+        return;
+      }
+
+      const onAbort = () => {
+        queue.splice(queue.indexOf(waiter), 1);
+        reject(signal.reason);
+      };
+      signal.addEventListener('abort', onAbort, { once: true });
+      waiter.resolve = release => {
+        signal.removeEventListener('abort', onAbort);
+        resolve(release);
+      };
+    });
+  }
+
+  // --------------------------------------------------------
+  // Adaptive limiters move `limit` from the samples of the released requests
+  protected onSample?(sample: IConcurrencySample): void;
+
+  private createRelease(): TConcurrencyRelease {
+    const grantedAt = performance.now();
+    const inflight = this.active;
+    let isReleased = false;
+
+    return (opts = {}) => {
+      if (isReleased) {
+        return;
+      }
+
+      isReleased = true;
+      this.active--;
+      const rtt = performance.now() - grantedAt;
+      this.onSample?.({ rtt, inflight, isDropped: !!opts.isDropped });
+
+      while (this.active < this.limit) {
+        const next = this.next();
+        if (!next) {
+          return;
+        }
+
+        this.active++;
+        next.resolve(this.createRelease());
+      }
+    };
+  }
+
+  private next(): IWaiter | undefined {
+    const { interactive, background } = this.queues;
+    const oldest = background[0];
+
+    const isStarving =
+      this.maxBackgroundWait !== false &&
+      !!oldest &&
+      Date.now() - oldest.queuedAt >= this.maxBackgroundWait;
+
+    if (isStarving || !interactive.length) {
+      return background.shift() ?? interactive.shift();
+    }
+
+    return interactive.shift();
+  }
+}
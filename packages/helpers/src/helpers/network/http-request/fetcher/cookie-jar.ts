@@ -0,0 +1,213 @@
+export interface ICookie {
+  name: string;
+  value: string;
+  // Lower cased, without a leading dot
+  domain: string;
+  path: string;
+  // Epoch milliseconds, session cookies never expire
+  expiresAt?: number;
+  secure: boolean;
+  httpOnly: boolean;
+  // Sent to `domain` only, set when the cookie has no `Domain` attribute
+  hostOnly: boolean;
+}
+
+// RFC 6265 default path, the directory of the request path
+const getDefaultPath = (pathname: string) => {
+  const index = pathname.lastIndexOf('/');
+  return index > 0 ? pathname.slice(0, index) : '/';
+};
+
+const isDomainMatch = (opts: { host: string; cookie: ICookie }) => {
+  const { host, cookie } = opts;
+  if (cookie.hostOnly) {
+    return host === cookie.domain;
+  }
+
+  return host === cookie.domain || host.endsWith(`.${cookie.domain}`);
+};
+
+const isPathMatch = (opts: { pathname: string; cookie: ICookie }) => {
+  const { pathname, cookie } = opts;
+  if (pathname === cookie.path) {
+    return true;
+  }
+
+  const prefix = cookie.path.endsWith('/') ? cookie.path : `${cookie.path}/`;
+  return pathname.startsWith(prefix);
+};
+
+// --------------------------------------------------------
+/**
+ * Cookies received by a fetcher, sent back on the following requests to the same site.
+ *
+ * Domain, path, expiry and `Secure` attributes are honoured, enough for session cookies of
+ * upstreams which do not support token authentication.
+ *
+ * @example
+ * ```typescript
+ * const jar = new CookieJar();
+ * const admin = new NodeFetchNetworkRequest({
+ *   name: 'AdminRequest',
+ *   networkOptions: { baseUrl: 'https://admin.legacy.internal' },
+ *   cookieJar: jar,
+ * });
+ *
+ * jar.get({ url: 'https://admin.legacy.internal', name: 'JSESSIONID' });
+ * ```
+ */
+export class CookieJar {
+  private cookies = new Map<string, ICookie>();
+
+  /**
+   * Parse a `Set-Cookie` header received from `url`, `undefined` when it is malformed or its
+   * domain does not match `url`.
+   */
+  static parse(opts: { url: string; setCookie: string; now?: number }): ICookie | undefined {
+    const { url, setCookie, now = Date.now() } = opts;
+    const { hostname, pathname } = new URL(url);
+
+    const [pair, ...attributes] = setCookie.split(';');
+    const separator = pair.indexOf('=');
+    if (separator <= 0) {
+      return undefined;
+    }
+
+    const cookie: ICookie = {
+      name: pair.slice(0, separator).trim(),
+      value: pair.slice(separator + 1).trim(),
+      domain: hostname.toLowerCase(),
+      path: getDefaultPath(pathname),
+      secure: false,
+      httpOnly: false,
+      hostOnly: true,
+    };
+
+    let maxAge: number | undefined;
+    for (const attribute of attributes) {
+      const [key, ...rest] = attribute.split('=');
+      const value = rest.join('=').trim();
+
+      switch (key.trim().toLowerCase()) {
+        case 'domain': {
+          const domain = value.replace(/^\./, '').toLowerCase();
+          if (domain) {
+            cookie.domain = domain;
+            cookie.hostOnly = false;
+          }
+          break;
+        }
+        case 'path': {
+          if (value.startsWith('/')) {
+            cookie.path = value;
+          }
+          break;
+        }
+        case 'expires': {
+          const expiresAt = Date.parse(value);
+          if (!Number.isNaN(expiresAt)) {
+            cookie.expiresAt = expiresAt;
+          }
+          break;
+        }
+        case 'max-age': {
+          if (/^-?\d+$/.test(value)) {
+            maxAge = Number(value);
+          }
+          break;
+        }
+        case 'secure': {
+          cookie.secure = true;
+          break;
+        }
+        case 'httponly': {
+          cookie.httpOnly = true;
+          break;
+        }
+        default: {
+          break;
+        }
+      }
+    }
+
+    // Max-Age wins over Expires
+    if (maxAge !== undefined) {
+      cookie.expiresAt = now + maxAge * 1000;
+    }
+
+    if (!isDomainMatch({ host: hostname.toLowerCase(), cookie: { ...cookie, hostOnly: false } })) {
+      return undefined;
+    }
+
+    return cookie;
+  }
+
+  /**
+   * Store the `Set-Cookie` headers of a response received from `url`, expired cookies are removed.
+   */
+  setCookies(opts: { url: string; setCookies: Array<string>; now?: number }) {
+    const { url, setCookies, now = Date.now() } = opts;
+
+    for (const setCookie of setCookies) {
+      const cookie = CookieJar.parse({ url, setCookie, now });
+      if (!cookie) {
+        continue;
+      }
+
+      const key = [cookie.domain, cookie.path, cookie.name].join(';');
+      if (cookie.expiresAt !== undefined && cookie.expiresAt <= now) {
+        this.cookies.delete(key);
+        continue;
+      }
+
+      this.cookies.set(key, cookie);
+    }
+  }
+
+  /**
+   * Cookies to send to `url`, longest paths first.
+   */
+  getCookies(opts: { url: string; now?: number }): Array<ICookie> {
+    const { url, now = Date.now() } = opts;
+    const { protocol, hostname, pathname } = new URL(url);
+    const host = hostname.toLowerCase();
+
+    const rs: Array<ICookie> = [];
+    for (const [key, cookie] of this.cookies) {
+      if (cookie.expiresAt !== undefined && cookie.expiresAt <= now) {
+        this.cookies.delete(key);
+        continue;
+      }
+
+      if (cookie.secure && protocol !== 'https:') {
+        continue;
+      }
+
+      if (isDomainMatch({ host, cookie }) && isPathMatch({ pathname, cookie })) {
+        rs.push(cookie);
+      }
+    }
+
+    return rs.sort((a, b) => b.path.length - a.path.length);
+  }
+
+  /**
+   * `Cookie` header of a request to `url`, `undefined` without cookies.
+   */
+  getCookieHeader(opts: { url: string; now?: number }): string | undefined {
+    const cookies = this.getCookies(opts);
+    if (!cookies.length) {
+      return undefined;
+    }
+
+    return cookies.map(cookie => `${cookie.name}=${cookie.value}`).join('; ');
+  }
+
+  get(opts: { url: string; name: string }): string | undefined {
+    return this.getCookies({ url: opts.url }).find(cookie => cookie.name === opts.name)?.value;
+  }
+
+  clear() {
+    this.cookies.clear();
+  }
+}
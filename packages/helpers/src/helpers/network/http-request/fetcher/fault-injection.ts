@@ -0,0 +1,239 @@
+import { HTTP } from '@/common/constants';
+import { TConstValue } from '@/common/types';
+import { applicationEnvironment, Environment } from '@/helpers/env';
+import { getError } from '@/helpers/error';
+import { toBoolean } from '@/utilities/parse.utility';
+
+export class FaultKinds {
+  // Answer with `status` without sending the request
+  static readonly STATUS = 'status';
+  // Fail like a connection reset by the peer, the request is not sent
+  static readonly RESET = 'reset';
+  // Hold the request for `delay` ms, then send it
+  static readonly DELAY = 'delay';
+
+  static readonly SCHEME_SET = new Set([this.STATUS, this.RESET, this.DELAY]);
+
+  static isValid(input: string): boolean {
+    return this.SCHEME_SET.has(input);
+  }
+}
+
+export type TFaultKind = TConstValue<typeof FaultKinds>;
+
+export class FaultInjectionErrorCodes {
+  static readonly CONNECTION_RESET = 'FAULT_INJECTION_CONNECTION_RESET';
+}
+
+export class FaultInjectionEnvironmentKeys {
+  static readonly APP_ENV_FAULT_INJECTION_ENABLED = 'APP_ENV_FAULT_INJECTION_ENABLED';
+}
+
+export class FaultInjectionDefaults {
+  // Every environment but production, `NODE_ENV` has to be set
+  static readonly ENVIRONMENTS = [
+    Environment.LOCAL,
+    Environment.DEBUG,
+    Environment.DEVELOPMENT,
+    Environment.ALPHA,
+    Environment.BETA,
+    Environment.STAGING,
+    'test',
+  ];
+}
+
+export interface IFaultRule {
+  // Prefix of the request url, or a pattern tested against it, every url when unset
+  url?: string | RegExp;
+  // Every method when unset
+  method?: string;
+  // Share of the matching requests the fault is injected into, from 0 to 1
+  rate: number;
+  fault: TFaultKind;
+  // Status of `status` faults, defaults to 503
+  status?: number;
+  // ms of `delay` faults
+  delay?: number;
+}
+
+export interface IFaultInjectionOptions {
+  rules: Array<IFaultRule>;
+  // Opt-in switch, defaults to `APP_ENV_FAULT_INJECTION_ENABLED`, faults are never injected
+  // without it
+  isEnabled?: boolean;
+  // `NODE_ENV` values the faults are injected in, defaults to every one but `production`
+  environments?: Array<string>;
+  // Defaults to `Math.random`, returns a number from 0 (included) to 1 (excluded)
+  random?: () => number;
+}
+
+// --------------------------------------------------------
+/**
+ * Failures injected into the requests of a fetcher, to check its retry, timeout and prober
+ * settings on test or staging before an upstream outage does.
+ *
+ * Every attempt of a request is matched against the rules in order, the first matching rule
+ * whose roll falls within its `rate` injects its fault: a `status` response, a connection
+ * `reset` or a `delay` before the request is sent. Faults are injected below the retries, a
+ * retried request rolls again for every attempt.
+ *
+ * Injection is opt-in, the rules are ignored unless `isEnabled` or
+ * `APP_ENV_FAULT_INJECTION_ENABLED` turns it on, and outside of `environments`. An unset
+ * `NODE_ENV` matches none of them, a configuration copied to production injects nothing.
+ *
+ * @example
+ * ```typescript
+ * const request = new NodeFetchNetworkRequest({
+ *   name: 'InventoryRequest',
+ *   networkOptions: { baseUrl: 'https://inventory.staging.example.com' },
+ *   retry: { maxAttempts: 3 },
+ *   faults: {
+ *     isEnabled: Environment.is({ name: Environment.STAGING }),
+ *     rules: [
+ *       { url: 'https://inventory.staging.example.com/stocks', rate: 0.2, fault: 'status' },
+ *       { rate: 0.05, fault: 'reset' },
+ *     ],
+ *   },
+ * });
+ * ```
+ */
+export class FaultInjector {
+  private rules: Array<IFaultRule>;
+  private isSwitchedOn?: boolean;
+  private environments: Array<string>;
+  private random: () => number;
+
+  constructor(opts: IFaultInjectionOptions) {
+    for (const rule of opts.rules) {
+      if (!FaultKinds.isValid(rule.fault)) {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+          message: `[FaultInjector] Invalid fault | fault: ${rule.fault}`,
+        });
+      }
+
+      if (rule.rate < 0 || rule.rate > 1) {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+          message: `[FaultInjector] Rate must be from 0 to 1 | rate: ${rule.rate}`,
+        });
+      }
+    }
+
+    this.rules = opts.rules;
+    this.isSwitchedOn = opts.isEnabled;
+    this.environments = opts.environments ?? FaultInjectionDefaults.ENVIRONMENTS;
+    this.random = opts.random ?? Math.random;
+  }
+
+  static from(opts?: FaultInjector | IFaultInjectionOptions) {
+    if (!opts || opts instanceof FaultInjector) {
+      return opts;
+    }
+
+    return new FaultInjector(opts);
+  }
+
+  static isError(error: unknown) {
+    return (
+      (error as { messageCode?: string })?.messageCode === FaultInjectionErrorCodes.CONNECTION_RESET
+    );
+  }
+
+  isEnabled() {
+    const isSwitchedOn =
+      this.isSwitchedOn ??
+      toBoolean(
+        applicationEnvironment.get<string>(
+          FaultInjectionEnvironmentKeys.APP_ENV_FAULT_INJECTION_ENABLED,
+        ),
+      );
+
+    // Not `Environment.current`, which falls back to development when unset
+    const environment = process.env.NODE_ENV;
+    return isSwitchedOn && !!environment && this.environments.includes(environment);
+  }
+
+  /**
+   * Rule of the fault to inject into a request, `undefined` for most requests.
+   */
+  pick(opts: { method: string; url: string }): IFaultRule | undefined {
+    if (!this.isEnabled()) {
+      return undefined;
+    }
+
+    const method = opts.method.toLowerCase();
+    return this.rules.find(rule => {
+      if (rule.method && rule.method.toLowerCase() !== method) {
+        return false;
+      }
+
+      const isMatched =
+        rule.url === undefined ||
+        (typeof rule.url === 'string' ? opts.url.startsWith(rule.url) : rule.url.test(opts.url));
+      return isMatched && this.random() < rule.rate;
+    });
+  }
+
+  /**
+   * Run `execute` unless a fault is injected, `respond` builds the response of `status` faults.
+   */
+  async apply<R>(opts: {
+    method: string;
+    url: string;
+    signal?: AbortSignal | null;
+    execute: () => Promise<R>;
+    respond: (status: number) => R;
+    logger?: any;
+  }): Promise<R> {
+    const { method, url, signal, execute, respond, logger } = opts;
+    const rule = this.pick({ method, url });
+    if (!rule) {
+      return execute();
+    }
+
+    logger
+      ?.for(this.apply.name)
+      .warn('Injecting fault | URL: %s | Method: %s | Fault: %s', url, method, rule.fault);
+
+    switch (rule.fault) {
+      case FaultKinds.STATUS: {
+        return respond(rule.status ?? HTTP.ResultCodes.RS_5.ServiceUnavailable);
+      }
+      case FaultKinds.RESET: {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+          messageCode: FaultInjectionErrorCodes.CONNECTION_RESET,
+          message: `[FaultInjector] Injected connection reset | method: ${method} | url: ${url}`,
+        });
+      }
+      default: {
+        await this.wait({ delay: rule.delay ?? 0, signal });
+        return execute();
+      }
+    }
+  }
+
+  // --------------------------------------------------------
+  // Aborted by the signal of the request, e.g. its total timeout
+  private wait(opts: { delay: number; signal?: AbortSignal | null }) {
+    const { delay, signal } = opts;
+
+    return new Promise<void>((resolve, reject) => {
+      if (signal?.aborted) {
+        reject(signal.reason);
+        return;
+      }
+
+      const onAbort = () => {
+        clearTimeout(timer);
+        reject(signal?.reason);
+      };
+      const timer = setTimeout(() => {
+        signal?.removeEventListener('abort', onAbort);
+        resolve();
+      }, delay);
+      signal?.addEventListener('abort', onAbort, { once: true });
+    });
+  }
+}
@@ -0,0 +1,137 @@
+import { HTTP } from '@/common/constants';
+import { AnyObject, TConstValue } from '@/common/types';
+import { getError } from '@/helpers/error';
+
+// Plain objects, `Headers` instances or `[name, value]` tuples, values may be arrays
+export type THeadersInput = AnyObject | Headers | Array<[string, string]>;
+
+export class HeaderMergePolicies {
+  // Per-request values replace the defaults
+  static readonly OVERRIDE = 'override';
+  // Per-request values are added after the defaults, single value headers are still replaced
+  static readonly APPEND = 'append';
+  // A per-request header also present in the defaults is a configuration error
+  static readonly ERROR = 'error';
+
+  static readonly SCHEME_SET = new Set([this.OVERRIDE, this.APPEND, this.ERROR]);
+
+  static isValid(policy: string): boolean {
+    return this.SCHEME_SET.has(policy);
+  }
+}
+
+export type THeaderMergePolicy = TConstValue<typeof HeaderMergePolicies>;
+
+export class HeaderErrorCodes {
+  static readonly HEADER_CONFLICT = 'HEADER_CONFLICT';
+}
+
+// Headers which can only carry one value, appending to them would corrupt the request
+const SINGLE_VALUE_HEADERS = new Set(
+  'authorization content-length content-type host user-agent'.split(' '),
+);
+
+const toValues = (value: unknown): Array<string> => {
+  if (value === undefined || value === null) {
+    return [];
+  }
+
+  return Array.isArray(value) ? value.map(item => String(item)) : [String(value)];
+};
+
+// --------------------------------------------------------
+export class HttpHeaders {
+  static toEntries(headers?: THeadersInput): Array<[string, unknown]> {
+    if (!headers) {
+      return [];
+    }
+
+    if (headers instanceof Headers) {
+      return [...headers.entries()];
+    }
+
+    return Array.isArray(headers) ? headers : Object.entries(headers);
+  }
+
+  /**
+   * Group values by lower cased name, `{ Accept: 'a', accept: ['b'] }` => `{ accept: ['a', 'b'] }`
+   */
+  static normalize(headers?: THeadersInput): Map<string, Array<string>> {
+    const rs = new Map<string, Array<string>>();
+
+    for (const [key, value] of HttpHeaders.toEntries(headers)) {
+      const values = toValues(value);
+      if (!values.length) {
+        continue;
+      }
+
+      const name = key.toLowerCase();
+      rs.set(name, [...(rs.get(name) ?? []), ...values]);
+    }
+
+    return rs;
+  }
+
+  /**
+   * Join the values of a header, `cookie` pairs are separated by `; ` and others by `, `.
+   */
+  static join(opts: { name: string; values: Array<string> }): string {
+    return opts.values.join(opts.name === 'cookie' ? '; ' : ', ');
+  }
+
+  /**
+   * Merge per-request headers into the defaults with `policy`.
+   *
+   * @example
+   * ```typescript
+   * HttpHeaders.merge({
+   *   defaults: { Accept: 'application/json', 'x-feature': 'a' },
+   *   headers: { accept: 'text/csv', 'X-Feature': ['b', 'c'] },
+   *   policy: HeaderMergePolicies.APPEND,
+   * });
+   * // { accept: 'application/json, text/csv', 'x-feature': 'a, b, c' }
+   * ```
+   *
+   * @throws `HEADER_CONFLICT` with the `error` policy when both define a header
+   * @returns headers with lower cased names
+   */
+  static merge(opts: {
+    defaults?: THeadersInput;
+    headers?: THeadersInput;
+    policy?: THeaderMergePolicy;
+  }): Record<string, string> {
+    const { policy = HeaderMergePolicies.OVERRIDE } = opts;
+
+    const merged = HttpHeaders.normalize(opts.defaults);
+    for (const [name, values] of HttpHeaders.normalize(opts.headers)) {
+      const existing = merged.get(name);
+
+      if (!existing) {
+        merged.set(name, values);
+        continue;
+      }
+
+      switch (policy) {
+        case HeaderMergePolicies.ERROR: {
+          throw getError({
+            statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+            messageCode: HeaderErrorCodes.HEADER_CONFLICT,
+            message: `[HttpHeaders] Request header conflicts with a default header | name: ${name}`,
+          });
+        }
+        case HeaderMergePolicies.APPEND: {
+          merged.set(name, SINGLE_VALUE_HEADERS.has(name) ? values : [...existing, ...values]);
+          break;
+        }
+        default: {
+          merged.set(name, values);
+          break;
+        }
+      }
+    }
+
+    return Object.fromEntries(
+      [...merged.entries()].map(([name, values]) => [name, HttpHeaders.join({ name, values })]),
+    );
+  }
+}
@@ -1,2 +1,23 @@
+export * from './adaptive-concurrency';
 export * from './base-fetcher';
+export * from './bulkhead';
+export * from './cancellation';
+export * from './cache';
+export * from './concurrency';
+export * from './cookie-jar';
+export * from './fault-injection';
+export * from './headers';
+export * from './interceptors';
+export * from './ip-family';
+export * from './key-case';
 export * from './node-fetcher';
+export * from './outbound-audit';
+export * from './payload-metrics';
+export * from './pinning';
+export * from './query';
+export * from './response-size';
+export * from './retry';
+export * from './throttle';
+export * from './timeouts';
+export * from './tls';
+export * from './token-provider';
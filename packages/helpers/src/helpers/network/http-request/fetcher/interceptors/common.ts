@@ -0,0 +1,78 @@
+import { Readable } from 'node:stream';
+import { IRequestOptions } from '../base-fetcher';
+import { HttpHeaders, THeaderMergePolicy } from '../headers';
+
+// Session expired / CSRF token mismatch status of Laravel and similar frameworks
+export const HTTP_SESSION_EXPIRED = 419;
+
+// Axios lower cases the response header names
+const getAxiosHeader = (opts: { response: unknown; name: string }): unknown => {
+  const { headers } = (opts.response ?? {}) as { headers?: Record<string, unknown> };
+  return headers?.[opts.name.toLowerCase()];
+};
+
+// --------------------------------------------------------
+/**
+ * Read and update requests / responses of both fetchers from an interceptor.
+ */
+export class FetcherExchanges {
+  static getRequestHeader(opts: { request: IRequestOptions; name: string }): string | undefined {
+    const { request, name } = opts;
+    const values = HttpHeaders.normalize(request.headers).get(name.toLowerCase());
+
+    return values ? HttpHeaders.join({ name, values }) : undefined;
+  }
+
+  static withRequestHeaders<RQ extends IRequestOptions>(opts: {
+    request: RQ;
+    headers: Record<string, string>;
+    policy?: THeaderMergePolicy;
+  }): RQ {
+    const { request, headers, policy } = opts;
+    return {
+      ...request,
+      headers: HttpHeaders.merge({ defaults: request.headers, headers, policy }),
+    };
+  }
+
+  // `Response` of the node fetcher or `AxiosResponse`
+  static getResponseHeader(opts: { response: unknown; name: string }): string | undefined {
+    const { response, name } = opts;
+    if (response instanceof Response) {
+      return response.headers.get(name) ?? undefined;
+    }
+
+    const value = getAxiosHeader({ response, name });
+    if (value === undefined || value === null) {
+      return undefined;
+    }
+
+    return Array.isArray(value) ? HttpHeaders.join({ name, values: value }) : String(value);
+  }
+
+  static getSetCookies(response: unknown): Array<string> {
+    if (response instanceof Response) {
+      return response.headers.getSetCookie();
+    }
+
+    const value = getAxiosHeader({ response, name: 'set-cookie' });
+    if (!value) {
+      return [];
+    }
+
+    return Array.isArray(value) ? value.map(String) : [String(value)];
+  }
+
+  // Stream bodies can only be sent once
+  static isReplayable(request: IRequestOptions) {
+    const { body } = request;
+    return !(body instanceof ReadableStream || body instanceof Readable);
+  }
+
+  // Release the body of a response which is about to be replayed
+  static discard(response: unknown) {
+    if (response instanceof Response) {
+      response.body?.cancel().catch(() => {});
+    }
+  }
+}
@@ -0,0 +1,228 @@
+import { BaseHelper } from '@/helpers/base';
+import { IRequestOptions } from '../base-fetcher';
+import { CookieJar } from '../cookie-jar';
+import { FetcherExchanges, HTTP_SESSION_EXPIRED } from './common';
+import { IFetcherInterceptor } from './types';
+
+// `<meta name="csrf-token" content="...">` of server rendered pages
+const META_TOKEN_PATTERN = /<meta[^>]+name=["']csrf-token["'][^>]+content=["']([^"']+)["']/i;
+
+export interface ICsrfPrimingOptions {
+  // Page or endpoint sent a GET to obtain the token
+  url: string;
+  // Response header carrying the token, the `csrf-token` meta tag of the page is read otherwise
+  responseHeader?: string;
+  // Read the token from the response body instead
+  extract?: (body: string) => string | undefined;
+}
+
+export interface ICsrfOptions {
+  // Jar of the fetcher, needed to read `cookie` and to keep the cookies of the priming GET
+  jar?: CookieJar;
+  // Cookie carrying the token, e.g. `XSRF-TOKEN`
+  cookie?: string;
+  // Used when the token is not available from `cookie`
+  priming?: ICsrfPrimingOptions;
+  // Request header the token is sent with, defaults to `x-csrf-token`, `false` to only send `field`
+  header?: string | false;
+  // Form field the token is sent with, for `URLSearchParams`, `FormData` and object bodies
+  field?: string;
+  // Defaults to post, put, patch and delete
+  methods?: Array<string>;
+  // Statuses meaning the token was rejected, the token is obtained again and the request replayed
+  statusCodes?: Array<number>;
+}
+
+// --------------------------------------------------------
+/**
+ * Attach a CSRF token to the mutating requests of a fetcher, for form based upstreams.
+ *
+ * The token is read from a cookie of the fetcher jar, or obtained by a priming GET whose
+ * cookies are stored in the jar. A rejected token (419 by default) is obtained again and the
+ * request replayed once.
+ *
+ * @example
+ * ```typescript
+ * const jar = new CookieJar();
+ * const backoffice = new NodeFetchNetworkRequest({
+ *   name: 'BackofficeRequest',
+ *   networkOptions: { baseUrl: 'https://backoffice.legacy.internal' },
+ *   cookieJar: jar,
+ *   interceptors: [
+ *     new CsrfInterceptor({
+ *       jar,
+ *       priming: { url: 'https://backoffice.legacy.internal/orders' },
+ *       header: false,
+ *       field: '_token',
+ *     }),
+ *   ],
+ * });
+ * ```
+ */
+export class CsrfInterceptor extends BaseHelper implements IFetcherInterceptor {
+  readonly name = CsrfInterceptor.name;
+
+  private jar?: CookieJar;
+  private cookie?: string;
+  private priming?: ICsrfPrimingOptions;
+  private header: string | false;
+  private field?: string;
+  private methods: Set<string>;
+  private statusCodes: Array<number>;
+
+  private token?: string;
+  private primingRequest?: Promise<string | undefined>;
+
+  constructor(opts: ICsrfOptions) {
+    super({ scope: CsrfInterceptor.name });
+
+    const {
+      jar,
+      cookie,
+      priming,
+      header = 'x-csrf-token',
+      field,
+      methods = ['post', 'put', 'patch', 'delete'],
+      statusCodes = [HTTP_SESSION_EXPIRED],
+    } = opts;
+
+    this.jar = jar;
+    this.cookie = cookie;
+    this.priming = priming;
+    this.header = header;
+    this.field = field;
+    this.methods = new Set(methods.map(method => method.toLowerCase()));
+    this.statusCodes = statusCodes;
+  }
+
+  /**
+   * Token to send to `url`, from the cookie when present, otherwise from the last priming GET.
+   */
+  getToken(opts: { url: string }): string | undefined {
+    if (!this.cookie || !this.jar || !URL.canParse(opts.url)) {
+      return this.token;
+    }
+
+    // Cookie tokens are usually URL encoded, e.g. Laravel `XSRF-TOKEN`
+    const value = this.jar.get({ url: opts.url, name: this.cookie });
+    return value ? decodeURIComponent(value) : this.token;
+  }
+
+  /**
+   * Obtain a token with the priming GET, joining the one already running if any.
+   */
+  async prime(): Promise<string | undefined> {
+    if (!this.priming) {
+      return undefined;
+    }
+
+    if (!this.primingRequest) {
+      this.primingRequest = this.sendPriming(this.priming).finally(() => {
+        this.primingRequest = undefined;
+      });
+    }
+
+    return this.primingRequest;
+  }
+
+  // --------------------------------------------------------
+  onRequest = async <RQ extends IRequestOptions>(opts: { request: RQ }): Promise<RQ> => {
+    const { request } = opts;
+    if (!this.methods.has((request.method ?? 'get').toLowerCase())) {
+      return request;
+    }
+
+    const token = this.getToken({ url: request.url }) ?? (await this.prime());
+    if (!token) {
+      this.logger.for('onRequest').warn('No CSRF token available | URL: %s', request.url);
+      return request;
+    }
+
+    let rs = request;
+    if (this.field) {
+      rs = { ...rs, body: this.withField({ body: rs.body, token }) };
+    }
+
+    if (this.header) {
+      rs = FetcherExchanges.withRequestHeaders({ request: rs, headers: { [this.header]: token } });
+    }
+
+    return rs;
+  };
+
+  onResponse = async <RS extends { status: number }>(opts: {
+    request: IRequestOptions;
+    response: RS;
+    replay: () => Promise<RS>;
+  }): Promise<RS> => {
+    const { request, response, replay } = opts;
+    const isRejected =
+      this.methods.has((request.method ?? 'get').toLowerCase()) &&
+      this.statusCodes.includes(response.status);
+
+    if (!isRejected || !FetcherExchanges.isReplayable(request)) {
+      return response;
+    }
+
+    this.logger
+      .for('onResponse')
+      .info('CSRF token rejected | URL: %s | Status: %s', request.url, response.status);
+
+    this.token = undefined;
+    if (!this.priming && !this.cookie) {
+      return response;
+    }
+
+    // Cookie tokens are renewed by the rejection itself
+    if (!this.getToken({ url: request.url })) {
+      await this.prime();
+    }
+
+    FetcherExchanges.discard(response);
+    return replay();
+  };
+
+  // --------------------------------------------------------
+  private withField(opts: { body: unknown; token: string }) {
+    const { body, token } = opts;
+    const field = this.field as string;
+
+    if (body instanceof URLSearchParams) {
+      const rs = new URLSearchParams(body);
+      rs.set(field, token);
+      return rs;
+    }
+
+    if (body instanceof FormData) {
+      const rs = new FormData();
+      body.forEach((value, key) => rs.append(key, value));
+      rs.set(field, token);
+      return rs;
+    }
+
+    // Axios serializes object bodies
+    if (body && typeof body === 'object' && Object.getPrototypeOf(body) === Object.prototype) {
+      return { ...body, [field]: token };
+    }
+
+    return body;
+  }
+
+  private async sendPriming(priming: ICsrfPrimingOptions) {
+    const { url, responseHeader, extract } = priming;
+
+    const cookie = this.jar?.getCookieHeader({ url });
+    const response = await fetch(url, { headers: cookie ? { cookie } : {} });
+    this.jar?.setCookies({ url, setCookies: response.headers.getSetCookie() });
+
+    if (responseHeader) {
+      await response.body?.cancel().catch(() => {});
+      this.token = response.headers.get(responseHeader) ?? undefined;
+    } else {
+      const body = await response.text();
+      this.token = extract ? extract(body) : META_TOKEN_PATTERN.exec(body)?.[1];
+    }
+
+    return this.getToken({ url });
+  }
+}
@@ -0,0 +1,284 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import dns from 'node:dns/promises';
+import { BlockList, isIP } from 'node:net';
+import { IRequestOptions } from '../base-fetcher';
+import { HttpHeaders } from '../headers';
+import { FetcherExchanges } from './common';
+import { IFetcherInterceptor } from './types';
+
+export class EgressGuardErrorCodes {
+  static readonly DENIED = 'EGRESS_GUARD_DENIED';
+  static readonly TOO_MANY_REDIRECTS = 'EGRESS_GUARD_TOO_MANY_REDIRECTS';
+}
+
+export class EgressGuardDefaults {
+  // Link-local and cloud metadata ranges, blocked even when the allowlist covers them
+  static readonly BLOCKED_RANGES = [
+    '169.254.0.0/16',
+    '100.100.100.200/32',
+    'fe80::/10',
+    'fd00:ec2::254/128',
+  ];
+  static readonly PROTOCOLS = ['http:', 'https:'];
+  static readonly MAX_REDIRECTS = 5;
+}
+
+export interface IEgressAddress {
+  address: string;
+  family: number;
+}
+
+export interface IEgressGuardOptions {
+  // Addresses or CIDR ranges requests may be sent to, e.g. `10.20.0.0/16` or `203.0.113.7`
+  allowlist: Array<string>;
+  // Defaults to http and https
+  protocols?: Array<string>;
+  // Resolves every address of a host, defaults to `dns.lookup`
+  lookup?: (hostname: string) => Promise<Array<IEgressAddress>>;
+  // Redirects followed by the guard before the request fails, defaults to 5
+  maxRedirects?: number;
+}
+
+// Whether the caller lets redirects be followed, kept on every hop of a guarded request
+const FOLLOW_REDIRECTS = Symbol('egress.followRedirects');
+
+const REDIRECT_STATUSES = new Set([301, 302, 303, 307, 308]);
+
+// Sent to the origin of the request only
+const CREDENTIAL_HEADERS = new Set(['authorization', 'cookie', 'proxy-authorization']);
+const BODY_HEADERS = new Set(['content-type', 'content-length', 'content-encoding']);
+
+// `::ffff:169.254.169.254` is checked as the IPv4 address it maps
+const toAddress = (address: string): IEgressAddress => {
+  const mapped = /^::ffff:(\d+\.\d+\.\d+\.\d+)$/i.exec(address)?.[1];
+  if (mapped) {
+    return { address: mapped, family: 4 };
+  }
+
+  return { address, family: isIP(address) };
+};
+
+const toBlockList = (ranges: Array<string>) => {
+  const rs = new BlockList();
+
+  for (const range of ranges) {
+    const [network, prefix] = range.split('/');
+    const { address, family } = toAddress(network);
+    if (!family) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[EgressGuardInterceptor] Invalid range | range: ${range}`,
+      });
+    }
+
+    const type = family === 6 ? 'ipv6' : 'ipv4';
+    if (prefix === undefined) {
+      rs.addAddress(address, type);
+      continue;
+    }
+
+    rs.addSubnet(address, Number(prefix), type);
+  }
+
+  return rs;
+};
+
+// --------------------------------------------------------
+/**
+ * Reject requests to addresses outside an allowlist, against SSRF when urls come from user
+ * input.
+ *
+ * The host of every request is resolved before it is dispatched and every address it resolves
+ * to must be allowed. Link-local and cloud metadata ranges are always rejected. Relative urls
+ * target the configured base url and are not checked.
+ *
+ * The connection is pinned to the checked address, a DNS answer changing in between (rebinding)
+ * is never connected to. Redirects are followed by the guard instead of the fetcher, the
+ * `Location` of every hop is checked the same way and credentials are dropped when it leaves the
+ * origin. Requests with `followRedirects: false` get the 3xx response as is.
+ *
+ * @example
+ * ```typescript
+ * const webhooks = new NodeFetchNetworkRequest({
+ *   name: 'WebhookRequest',
+ *   networkOptions: {},
+ *   interceptors: [new EgressGuardInterceptor({ allowlist: ['203.0.113.0/24'] })],
+ * });
+ * ```
+ */
+export class EgressGuardInterceptor extends BaseHelper implements IFetcherInterceptor {
+  readonly name = EgressGuardInterceptor.name;
+
+  private allowed: BlockList;
+  private blocked: BlockList;
+  private protocols: Set<string>;
+  private lookup: NonNullable<IEgressGuardOptions['lookup']>;
+  private maxRedirects: number;
+
+  constructor(opts: IEgressGuardOptions) {
+    super({ scope: EgressGuardInterceptor.name });
+
+    const {
+      allowlist,
+      protocols = EgressGuardDefaults.PROTOCOLS,
+      lookup = hostname => dns.lookup(hostname, { all: true, verbatim: true }),
+      maxRedirects = EgressGuardDefaults.MAX_REDIRECTS,
+    } = opts;
+
+    this.allowed = toBlockList(allowlist);
+    this.blocked = toBlockList(EgressGuardDefaults.BLOCKED_RANGES);
+    this.protocols = new Set(protocols);
+    this.lookup = lookup;
+    this.maxRedirects = maxRedirects;
+  }
+
+  isAllowed(opts: { address: string }): boolean {
+    const { address, family } = toAddress(opts.address);
+    if (!family) {
+      return false;
+    }
+
+    const type = family === 6 ? 'ipv6' : 'ipv4';
+    return !this.blocked.check(address, type) && this.allowed.check(address, type);
+  }
+
+  /**
+   * Throw when `url` may not be requested, resolving its host when it is not an address.
+   *
+   * @returns the checked addresses of the host, empty when it is an address
+   */
+  async check(opts: { url: string }): Promise<Array<IEgressAddress>> {
+    const { url } = opts;
+    const { protocol, hostname } = new URL(url);
+
+    if (!this.protocols.has(protocol)) {
+      throw this.getDeniedError({ url, reason: `protocol ${protocol}` });
+    }
+
+    // IPv6 hosts are bracketed
+    const host = hostname.replace(/^\[|\]$/g, '');
+    const family = isIP(host);
+    const addresses = family ? [{ address: host, family }] : await this.lookup(host);
+
+    const denied = addresses.find(el => !this.isAllowed({ address: el.address }));
+    if (!addresses.length || denied) {
+      throw this.getDeniedError({ url, reason: `address ${denied?.address ?? 'unresolved'}` });
+    }
+
+    return family ? [] : addresses;
+  }
+
+  // --------------------------------------------------------
+  onRequest = async <RQ extends IRequestOptions>(opts: { request: RQ }): Promise<RQ> => {
+    const { request } = opts;
+    if (!URL.canParse(request.url)) {
+      return request;
+    }
+
+    const [address] = await this.check({ url: request.url });
+    return {
+      ...request,
+      [FOLLOW_REDIRECTS]: request[FOLLOW_REDIRECTS] ?? request.followRedirects !== false,
+      followRedirects: false,
+      resolvedAddress: address,
+    };
+  };
+
+  onResponse = async <RQ extends IRequestOptions, RS>(opts: {
+    request: RQ;
+    response: RS;
+    replay: (opts?: { request?: RQ }) => Promise<RS>;
+  }): Promise<RS> => {
+    const { replay } = opts;
+    let { request, response } = opts;
+    if (!request[FOLLOW_REDIRECTS]) {
+      return response;
+    }
+
+    for (let hop = 0; ; hop++) {
+      const { status } = (response ?? {}) as { status?: number };
+      const location = FetcherExchanges.getResponseHeader({ response, name: 'location' });
+      if (!status || !REDIRECT_STATUSES.has(status) || !location) {
+        return response;
+      }
+
+      const next = this.getRedirectRequest({ request, status, location });
+      if (!next) {
+        return response;
+      }
+
+      if (hop >= this.maxRedirects) {
+        FetcherExchanges.discard(response);
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+          messageCode: EgressGuardErrorCodes.TOO_MANY_REDIRECTS,
+          message: `[EgressGuardInterceptor] Too many redirects | url: ${request.url} | maxRedirects: ${this.maxRedirects}`,
+        });
+      }
+
+      // Checked and pinned by `onRequest` before it is sent
+      FetcherExchanges.discard(response);
+      response = await replay({ request: next });
+      request = next;
+    }
+  };
+
+  // --------------------------------------------------------
+  // Next hop of `request` as browsers follow it, `undefined` when its stream body can not be
+  // sent again
+  private getRedirectRequest<RQ extends IRequestOptions>(opts: {
+    request: RQ;
+    status: number;
+    location: string;
+  }): RQ | undefined {
+    const { request, status, location } = opts;
+    const url = new URL(location, request.url);
+    const method = (request.method ?? 'get').toLowerCase();
+
+    const isGet =
+      status === 303 ? method !== 'head' : [301, 302].includes(status) && method === 'post';
+    if (!isGet && !FetcherExchanges.isReplayable(request)) {
+      return undefined;
+    }
+
+    const isCrossOrigin = url.origin !== new URL(request.url).origin;
+    const headers: Record<string, string> = {};
+    for (const [name, values] of HttpHeaders.normalize(request.headers)) {
+      if ((isCrossOrigin && CREDENTIAL_HEADERS.has(name)) || (isGet && BODY_HEADERS.has(name))) {
+        continue;
+      }
+
+      headers[name] = HttpHeaders.join({ name, values });
+    }
+
+    const next: IRequestOptions & { body?: unknown } = { ...request, url: url.toString(), headers };
+    delete next.params;
+    delete next.resolvedAddress;
+
+    if (isGet) {
+      next.method = 'get';
+      delete next.body;
+    }
+
+    // The token provider would authorize the other origin again
+    if (isCrossOrigin) {
+      next.bearerAuth = false;
+    }
+
+    return next as RQ;
+  }
+
+  // --------------------------------------------------------
+  private getDeniedError(opts: { url: string; reason: string }) {
+    const { url, reason } = opts;
+    this.logger.for('check').warn('Egress denied | URL: %s | Reason: %s', url, reason);
+
+    return getError({
+      statusCode: HTTP.ResultCodes.RS_4.Forbidden,
+      messageCode: EgressGuardErrorCodes.DENIED,
+      message: `[EgressGuardInterceptor] Egress denied | url: ${url} | ${reason}`,
+    });
+  }
+}
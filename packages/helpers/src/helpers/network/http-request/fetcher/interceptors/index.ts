@@ -0,0 +1,6 @@
+export * from './common';
+export * from './csrf';
+export * from './egress-guard';
+export * from './session-login';
+export * from './token-refresh';
+export * from './types';
@@ -0,0 +1,204 @@
+import { HTTP } from '@/common/constants';
+import { AnyObject } from '@/common/types';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { IRequestOptions } from '../base-fetcher';
+import { CookieJar } from '../cookie-jar';
+import { FetcherExchanges, HTTP_SESSION_EXPIRED } from './common';
+import { IFetcherInterceptor } from './types';
+
+export class SessionLoginErrorCodes {
+  static readonly LOGIN_FAILED = 'SESSION_LOGIN_FAILED';
+}
+
+export interface ISessionLoginRequest {
+  url: string;
+  // Defaults to `post`
+  method?: string;
+  headers?: Record<string, string>;
+  // Objects are sent as JSON, use `URLSearchParams` for form logins
+  body?: AnyObject | string | URLSearchParams;
+}
+
+export interface ISessionCsrfOptions {
+  // Cookie carrying the token, e.g. `XSRF-TOKEN`
+  cookie?: string;
+  // Header of the login response carrying the token instead
+  responseHeader?: string;
+  // Request header the token is sent with, defaults to `x-csrf-token`
+  header?: string;
+}
+
+export interface ISessionLoginOptions {
+  // Jar of the fetcher, the session cookies are stored there
+  jar: CookieJar;
+  login: ISessionLoginRequest;
+  csrf?: ISessionCsrfOptions;
+  // Statuses meaning the session expired, defaults to 401 and 419
+  statusCodes?: Array<number>;
+}
+
+// --------------------------------------------------------
+/**
+ * Log in before the first request of a fetcher and again when the upstream reports the session
+ * expired, then replay the rejected request once.
+ *
+ * The session cookies of the login response are stored in the cookie jar of the fetcher, which
+ * sends them with every request. A CSRF token read from a cookie or a login response header is
+ * sent with every request as well. Sessions rejected at the same time share one login.
+ *
+ * @example
+ * ```typescript
+ * const jar = new CookieJar();
+ * const admin = new NodeFetchNetworkRequest({
+ *   name: 'AdminRequest',
+ *   networkOptions: { baseUrl: 'https://admin.legacy.internal' },
+ *   cookieJar: jar,
+ *   interceptors: [
+ *     new SessionLoginInterceptor({
+ *       jar,
+ *       login: {
+ *         url: 'https://admin.legacy.internal/login',
+ *         body: new URLSearchParams({ username, password }),
+ *       },
+ *       csrf: { cookie: 'XSRF-TOKEN', header: 'x-xsrf-token' },
+ *     }),
+ *   ],
+ * });
+ * ```
+ */
+export class SessionLoginInterceptor extends BaseHelper implements IFetcherInterceptor {
+  readonly name = SessionLoginInterceptor.name;
+
+  private jar: CookieJar;
+  private login: ISessionLoginRequest;
+  private csrf?: ISessionCsrfOptions;
+  private statusCodes: Array<number>;
+
+  private isLoggedIn = false;
+  private csrfToken?: string;
+  private loggingIn?: Promise<void>;
+
+  constructor(opts: ISessionLoginOptions) {
+    super({ scope: SessionLoginInterceptor.name });
+
+    const {
+      jar,
+      login,
+      csrf,
+      statusCodes = [HTTP.ResultCodes.RS_4.Unauthorized, HTTP_SESSION_EXPIRED],
+    } = opts;
+
+    this.jar = jar;
+    this.login = login;
+    this.csrf = csrf;
+    this.statusCodes = statusCodes;
+  }
+
+  /**
+   * CSRF token to send to `url`, cookie tokens are read from the jar as they may rotate.
+   */
+  getCsrfToken(opts: { url: string }): string | undefined {
+    const cookie = this.csrf?.cookie;
+    const token = (cookie && this.jar.get({ url: opts.url, name: cookie })) || this.csrfToken;
+
+    // Cookie tokens are usually URL encoded, e.g. Laravel `XSRF-TOKEN`
+    return token ? decodeURIComponent(token) : undefined;
+  }
+
+  /**
+   * Drop the session, the next request logs in again.
+   */
+  resetSession() {
+    this.isLoggedIn = false;
+    this.csrfToken = undefined;
+  }
+
+  /**
+   * Log in, joining the login already running if any.
+   */
+  async authenticate(): Promise<void> {
+    if (!this.loggingIn) {
+      this.loggingIn = this.sendLogin().finally(() => {
+        this.loggingIn = undefined;
+      });
+    }
+
+    return this.loggingIn;
+  }
+
+  // --------------------------------------------------------
+  onRequest = async <RQ extends IRequestOptions>(opts: { request: RQ }): Promise<RQ> => {
+    const { request } = opts;
+    if (!this.isLoggedIn) {
+      await this.authenticate();
+    }
+
+    const csrfToken = URL.canParse(request.url)
+      ? this.getCsrfToken({ url: request.url })
+      : this.csrfToken;
+    if (!csrfToken) {
+      return request;
+    }
+
+    return FetcherExchanges.withRequestHeaders({
+      request,
+      headers: { [this.csrf?.header ?? 'x-csrf-token']: csrfToken },
+    });
+  };
+
+  onResponse = async <RS extends { status: number }>(opts: {
+    request: IRequestOptions;
+    response: RS;
+    replay: () => Promise<RS>;
+  }): Promise<RS> => {
+    const { request, response, replay } = opts;
+    if (!this.statusCodes.includes(response.status) || !FetcherExchanges.isReplayable(request)) {
+      return response;
+    }
+
+    this.logger
+      .for('onResponse')
+      .info('Session expired, logging in | URL: %s | Status: %s', request.url, response.status);
+
+    await this.authenticate();
+
+    FetcherExchanges.discard(response);
+    return replay();
+  };
+
+  // --------------------------------------------------------
+  private async sendLogin() {
+    const { url, method = 'post', headers, body } = this.login;
+    const isJson =
+      body !== undefined && typeof body !== 'string' && !(body instanceof URLSearchParams);
+
+    const cookie = this.jar.getCookieHeader({ url });
+    const response = await fetch(url, {
+      method: method.toUpperCase(),
+      headers: {
+        ...(isJson ? { 'content-type': 'application/json; charset=utf-8' } : {}),
+        ...(cookie ? { cookie } : {}),
+        ...headers,
+      },
+      body: isJson ? JSON.stringify(body) : (body as string | URLSearchParams | undefined),
+      // Form logins answer with a redirect carrying the session cookie
+      redirect: 'manual',
+    });
+    await response.body?.cancel().catch(() => {});
+
+    if (response.status >= HTTP.ResultCodes.RS_4.BadRequest) {
+      this.isLoggedIn = false;
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+        messageCode: SessionLoginErrorCodes.LOGIN_FAILED,
+        message: `[SessionLoginInterceptor] Login rejected | url: ${url} | status: ${response.status}`,
+      });
+    }
+
+    this.jar.setCookies({ url, setCookies: response.headers.getSetCookie() });
+    const responseHeader = this.csrf?.responseHeader;
+    this.csrfToken = (responseHeader && response.headers.get(responseHeader)) || undefined;
+    this.isLoggedIn = true;
+  }
+}
@@ -0,0 +1,131 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { IRequestOptions } from '../base-fetcher';
+import { FetcherExchanges } from './common';
+import { IFetcherInterceptor } from './types';
+
+export interface ITokenRefreshOptions {
+  // Fetch a new token, concurrent requests rejected at the same time share one call
+  refresh: () => Promise<string>;
+  // Token of the first requests, refreshed on the first rejection otherwise
+  token?: string;
+  // Defaults to `authorization`
+  header?: string;
+  // Defaults to `Bearer <token>`
+  format?: (token: string) => string;
+  // Defaults to 401
+  statusCodes?: Array<number>;
+}
+
+// --------------------------------------------------------
+/**
+ * Attach a token to every request, refresh it when the upstream rejects it and replay the
+ * rejected request once with the new token.
+ *
+ * Refreshes are single flighted: requests rejected while a refresh runs wait for it, and a
+ * request rejected with a token already replaced by another refresh is replayed directly.
+ *
+ * @example
+ * ```typescript
+ * const tokens = new TokenRefreshInterceptor({
+ *   refresh: async () => (await auth.login({ clientId, clientSecret })).accessToken,
+ * });
+ *
+ * const orders = new NodeFetchNetworkRequest({
+ *   name: 'OrderRequest',
+ *   networkOptions: { baseUrl: 'https://orders.internal' },
+ *   interceptors: [tokens],
+ * });
+ * ```
+ */
+export class TokenRefreshInterceptor extends BaseHelper implements IFetcherInterceptor {
+  readonly name = TokenRefreshInterceptor.name;
+
+  private token?: string;
+  private refreshing?: Promise<string>;
+  private refresh: () => Promise<string>;
+  private header: string;
+  private format: (token: string) => string;
+  private statusCodes: Array<number>;
+
+  constructor(opts: ITokenRefreshOptions) {
+    super({ scope: TokenRefreshInterceptor.name });
+
+    const {
+      refresh,
+      token,
+      header = 'authorization',
+      format = (value: string) => `Bearer ${value}`,
+      statusCodes = [HTTP.ResultCodes.RS_4.Unauthorized],
+    } = opts;
+
+    this.refresh = refresh;
+    this.token = token;
+    this.header = header.toLowerCase();
+    this.format = format;
+    this.statusCodes = statusCodes;
+  }
+
+  getToken() {
+    return this.token;
+  }
+
+  setToken(token: string | undefined) {
+    this.token = token;
+  }
+
+  /**
+   * Refresh the token, joining the refresh already running if any.
+   */
+  async refreshToken(): Promise<string> {
+    if (!this.refreshing) {
+      this.refreshing = this.refresh()
+        .then(token => {
+          this.token = token;
+          return token;
+        })
+        .finally(() => {
+          this.refreshing = undefined;
+        });
+    }
+
+    return this.refreshing;
+  }
+
+  // --------------------------------------------------------
+  onRequest = <RQ extends IRequestOptions>(opts: { request: RQ }): RQ => {
+    const { request } = opts;
+    if (!this.token) {
+      return request;
+    }
+
+    return FetcherExchanges.withRequestHeaders({
+      request,
+      headers: { [this.header]: this.format(this.token) },
+    });
+  };
+
+  onResponse = async <RS extends { status: number }>(opts: {
+    request: IRequestOptions;
+    response: RS;
+    replay: () => Promise<RS>;
+  }): Promise<RS> => {
+    const { request, response, replay } = opts;
+    if (!this.statusCodes.includes(response.status) || !FetcherExchanges.isReplayable(request)) {
+      return response;
+    }
+
+    const sent = FetcherExchanges.getRequestHeader({ request, name: this.header });
+    const isRotated = !!this.token && sent !== this.format(this.token);
+
+    if (!isRotated) {
+      this.logger
+        .for('onResponse')
+        .info('Refreshing rejected token | URL: %s | Status: %s', request.url, response.status);
+      await this.refreshToken();
+    }
+
+    FetcherExchanges.discard(response);
+    return replay();
+  };
+}
@@ -0,0 +1,21 @@
+import { ValueOrPromise } from '@/common/types';
+import { IRequestOptions } from '../base-fetcher';
+
+/**
+ * Hook around every request of a fetcher, registered with `interceptors` or `addInterceptor`.
+ *
+ * Request hooks run in registration order before each dispatch, replays included. Response
+ * hooks run in registration order on the response of the first dispatch, `replay` sends the
+ * original request (or `request`, e.g. the next hop of a redirect) again through the request
+ * hooks only, so a replayed response is never intercepted twice.
+ */
+export interface IFetcherInterceptor<RQ extends IRequestOptions = IRequestOptions, RS = any> {
+  name: string;
+  onRequest?: (opts: { request: RQ }) => ValueOrPromise<RQ>;
+  onResponse?: (opts: {
+    // As dispatched, after the request hooks
+    request: RQ;
+    response: RS;
+    replay: (opts?: { request?: RQ }) => Promise<RS>;
+  }) => ValueOrPromise<RS>;
+}
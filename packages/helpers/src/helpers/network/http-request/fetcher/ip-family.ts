@@ -0,0 +1,259 @@
+import { HTTP } from '@/common/constants';
+import { TConstValue } from '@/common/types';
+import { getError } from '@/helpers/error';
+import dns from 'node:dns/promises';
+import net from 'node:net';
+
+export class IpFamilyPreferences {
+  // Connect over one family only, e.g. upstreams publishing broken AAAA records
+  static readonly IPV4 = 'ipv4';
+  static readonly IPV6 = 'ipv6';
+  // Happy Eyeballs (RFC 8305), the addresses of both families are raced
+  static readonly RACE = 'race';
+
+  static readonly SCHEME_SET = new Set([this.IPV4, this.IPV6, this.RACE]);
+
+  static isValid(input: string): boolean {
+    return this.SCHEME_SET.has(input);
+  }
+}
+
+export type TIpFamilyPreference = TConstValue<typeof IpFamilyPreferences>;
+
+export class IpFamilyErrorCodes {
+  static readonly NO_ADDRESS = 'IP_FAMILY_NO_ADDRESS';
+  static readonly UNREACHABLE = 'IP_FAMILY_UNREACHABLE';
+}
+
+export class IpFamilyDefaults {
+  // Connection attempt delay of RFC 8305
+  static readonly ATTEMPT_DELAY = 250;
+  // ms the winner of a race is reused for its host
+  static readonly WINNER_TTL = 30_000;
+}
+
+export interface IResolvedAddress {
+  address: string;
+  family: number;
+}
+
+export interface IIpFamilyOptions {
+  preference: TIpFamilyPreference;
+  // ms before the next address joins the race, defaults to 250
+  attemptDelay?: number;
+  // Resolves the addresses of a host, of `family` only when set, defaults to `dns.lookup`
+  lookup?: (opts: { hostname: string; family?: 4 | 6 }) => Promise<Array<IResolvedAddress>>;
+}
+
+export interface IIpFamilyAgentOptions {
+  family?: 4 | 6;
+  autoSelectFamily?: boolean;
+  autoSelectFamilyAttemptTimeout?: number;
+}
+
+const lookupAddresses: NonNullable<IIpFamilyOptions['lookup']> = ({ hostname, family }) =>
+  dns.lookup(hostname, { all: true, family: family ?? 0 });
+
+// Alternate the families, starting with the one of the first address (RFC 8305 section 4)
+const interleave = (addresses: Array<IResolvedAddress>) => {
+  const first = addresses[0]?.family;
+  const preferred = addresses.filter(address => address.family === first);
+  const others = addresses.filter(address => address.family !== first);
+
+  const rs: Array<IResolvedAddress> = [];
+  for (let i = 0; i < Math.max(preferred.length, others.length); i++) {
+    if (preferred[i]) {
+      rs.push(preferred[i]);
+    }
+
+    if (others[i]) {
+      rs.push(others[i]);
+    }
+  }
+
+  return rs;
+};
+
+// --------------------------------------------------------
+/**
+ * IP family of the connections of a fetcher, against upstreams whose addresses of one family
+ * are broken and stall every connect until it times out.
+ *
+ * `ipv4` / `ipv6` only connect to the addresses of that family. `race` starts a connect to the
+ * first address, and to the next one every `attemptDelay` or as soon as the previous one fails,
+ * the first connected address wins and is reused for its host for a while. Axios agents select
+ * the family natively (`family` / `autoSelectFamily`), the node fetcher connects to the selected
+ * address while the host header, SNI and certificate checks keep the host name.
+ *
+ * @example
+ * ```typescript
+ * const request = new NodeFetchNetworkRequest({
+ *   name: 'CarrierRequest',
+ *   networkOptions: { baseUrl: 'https://api.carrier.example' },
+ *   ipFamily: 'ipv4',
+ * });
+ * ```
+ */
+export class IpFamilySelector {
+  private preference: TIpFamilyPreference;
+  private attemptDelay: number;
+  private lookup: NonNullable<IIpFamilyOptions['lookup']>;
+  private winners = new Map<string, { address: IResolvedAddress; expiresAt: number }>();
+
+  constructor(opts: IIpFamilyOptions) {
+    if (!IpFamilyPreferences.isValid(opts.preference)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[IpFamilySelector] Invalid preference | preference: ${opts.preference}`,
+      });
+    }
+
+    this.preference = opts.preference;
+    this.attemptDelay = opts.attemptDelay ?? IpFamilyDefaults.ATTEMPT_DELAY;
+    this.lookup = opts.lookup ?? lookupAddresses;
+  }
+
+  static from(opts?: IpFamilySelector | TIpFamilyPreference | IIpFamilyOptions) {
+    if (!opts || opts instanceof IpFamilySelector) {
+      return opts;
+    }
+
+    return new IpFamilySelector(typeof opts === 'string' ? { preference: opts } : opts);
+  }
+
+  getPreference() {
+    return this.preference;
+  }
+
+  /**
+   * Options of node http(s) agents, which select the family of their connections themselves.
+   */
+  getAgentOptions(): IIpFamilyAgentOptions {
+    switch (this.preference) {
+      case IpFamilyPreferences.IPV4: {
+        return { family: 4 };
+      }
+      case IpFamilyPreferences.IPV6: {
+        return { family: 6 };
+      }
+      default: {
+        return { autoSelectFamily: true, autoSelectFamilyAttemptTimeout: this.attemptDelay };
+      }
+    }
+  }
+
+  /**
+   * Address to connect to for `hostname`, `undefined` when it already is an address.
+   */
+  async select(opts: {
+    hostname: string;
+    port: number;
+    signal?: AbortSignal | null;
+  }): Promise<IResolvedAddress | undefined> {
+    const { hostname, port, signal } = opts;
+    if (net.isIP(hostname)) {
+      return undefined;
+    }
+
+    if (this.preference !== IpFamilyPreferences.RACE) {
+      const family = this.preference === IpFamilyPreferences.IPV4 ? 4 : 6;
+      const [rs] = await this.lookup({ hostname, family });
+      if (!rs) {
+        throw this.getError({ code: IpFamilyErrorCodes.NO_ADDRESS, hostname, reason: family });
+      }
+
+      return rs;
+    }
+
+    const key = `${hostname}:${port}`;
+    const winner = this.winners.get(key);
+    if (winner && winner.expiresAt > Date.now()) {
+      return winner.address;
+    }
+
+    const addresses = interleave(await this.lookup({ hostname }));
+    if (!addresses.length) {
+      throw this.getError({ code: IpFamilyErrorCodes.NO_ADDRESS, hostname, reason: 'any' });
+    }
+
+    const rs = await this.race({ hostname, addresses, port, signal });
+    this.winners.set(key, { address: rs, expiresAt: Date.now() + IpFamilyDefaults.WINNER_TTL });
+    return rs;
+  }
+
+  // --------------------------------------------------------
+  private race(opts: {
+    hostname: string;
+    addresses: Array<IResolvedAddress>;
+    port: number;
+    signal?: AbortSignal | null;
+  }): Promise<IResolvedAddress> {
+    const { hostname, addresses, port, signal } = opts;
+
+    return new Promise((resolve, reject) => {
+      const sockets: Array<net.Socket> = [];
+      const timers: Array<ReturnType<typeof setTimeout>> = [];
+      let next = 0;
+      let failures = 0;
+      let isSettled = false;
+
+      const settle = (done: () => void) => {
+        if (isSettled) {
+          return;
+        }
+
+        isSettled = true;
+        timers.forEach(timer => clearTimeout(timer));
+        sockets.forEach(socket => socket.destroy());
+        signal?.removeEventListener('abort', onAbort);
+        done();
+      };
+
+      const onAbort = () => settle(() => reject(signal?.reason));
+      if (signal?.aborted) {
+        onAbort();
+        return;
+      }
+      signal?.addEventListener('abort', onAbort, { once: true });
+
+      const start = () => {
+        if (isSettled || next >= addresses.length) {
+          return;
+        }
+
+        const address = addresses[next++];
+        const socket = net.connect({ host: address.address, port, family: address.family });
+        sockets.push(socket);
+
+        socket.once('connect', () => settle(() => resolve(address)));
+        socket.once('error', error => {
+          failures++;
+          if (failures < addresses.length) {
+            start();
+            return;
+          }
+
+          settle(() =>
+            reject(
+              this.getError({ code: IpFamilyErrorCodes.UNREACHABLE, hostname, reason: error }),
+            ),
+          );
+        });
+
+        timers.push(setTimeout(start, this.attemptDelay));
+      };
+
+      start();
+    });
+  }
+
+  private getError(opts: { code: string; hostname: string; reason: unknown }) {
+    const { code, hostname, reason } = opts;
+
+    return getError({
+      statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+      messageCode: code,
+      message: `[IpFamilySelector] No address to connect to | hostname: ${hostname} | preference: ${this.preference} | reason: ${reason}`,
+    });
+  }
+}
@@ -0,0 +1,122 @@
+import { TConstValue } from '@/common/types';
+
+export class KeyCases {
+  // Keys are sent as written
+  static readonly PRESERVE = 'preserve';
+  // `orderId`
+  static readonly CAMEL = 'camel';
+  // `order_id`
+  static readonly SNAKE = 'snake';
+  // `order-id`
+  static readonly KEBAB = 'kebab';
+
+  static readonly SCHEME_SET = new Set([this.PRESERVE, this.CAMEL, this.SNAKE, this.KEBAB]);
+
+  static isValid(keyCase: string): boolean {
+    return this.SCHEME_SET.has(keyCase);
+  }
+}
+
+export type TKeyCase = TConstValue<typeof KeyCases>;
+
+// Lower case words of a camel, pascal, snake or kebab cased key, `userID2FA` => user, id2, fa
+const splitWords = (key: string) => {
+  return key
+    .replace(/([a-z\d])([A-Z])/g, '$1 $2')
+    .replace(/([A-Z]+)([A-Z][a-z])/g, '$1 $2')
+    .split(/[\s_-]+/)
+    .filter(Boolean)
+    .map(word => word.toLowerCase());
+};
+
+const isPlainObject = (value: unknown): value is Record<string, unknown> => {
+  if (typeof value !== 'object' || value === null) {
+    return false;
+  }
+
+  const prototype = Object.getPrototypeOf(value);
+  return prototype === Object.prototype || prototype === null;
+};
+
+// --------------------------------------------------------
+/**
+ * Field name conversion of outgoing params and JSON bodies, so payloads are written in the case
+ * of the codebase whatever the convention of the backend.
+ *
+ * @example
+ * ```typescript
+ * KeyCaseConverter.convertKey({ key: 'order_id', to: KeyCases.CAMEL }); // 'orderId'
+ * KeyCaseConverter.convertKeys({ value: { shipTo: { zipCode: '1' } }, to: KeyCases.SNAKE });
+ * // { ship_to: { zip_code: '1' } }
+ * ```
+ */
+export class KeyCaseConverter {
+  static convertKey(opts: { key: string; to: TKeyCase }): string {
+    const { key, to } = opts;
+
+    // Leading underscores mark private or reserved fields, e.g. `_id`
+    const prefix = /^_*/.exec(key)![0];
+    const words = splitWords(key.slice(prefix.length));
+    if (to === KeyCases.PRESERVE || !words.length) {
+      return key;
+    }
+
+    switch (to) {
+      case KeyCases.SNAKE: {
+        return `${prefix}${words.join('_')}`;
+      }
+      case KeyCases.KEBAB: {
+        return `${prefix}${words.join('-')}`;
+      }
+      default: {
+        const [first, ...rest] = words;
+        const capitalized = rest.map(word => `${word[0].toUpperCase()}${word.slice(1)}`);
+        return `${prefix}${first}${capitalized.join('')}`;
+      }
+    }
+  }
+
+  /**
+   * Keys of plain objects at any depth, other values (dates, buffers, class instances) are kept.
+   */
+  static convertKeys<T = unknown>(opts: { value: unknown; to: TKeyCase }): T {
+    const { value, to } = opts;
+    if (to === KeyCases.PRESERVE) {
+      return value as T;
+    }
+
+    if (Array.isArray(value)) {
+      return value.map(item => KeyCaseConverter.convertKeys({ value: item, to })) as T;
+    }
+
+    if (!isPlainObject(value)) {
+      return value as T;
+    }
+
+    const rs: Record<string, unknown> = {};
+    for (const [key, item] of Object.entries(value)) {
+      rs[KeyCaseConverter.convertKey({ key, to })] = KeyCaseConverter.convertKeys({
+        value: item,
+        to,
+      });
+    }
+
+    return rs as T;
+  }
+
+  /**
+   * Keys of a JSON text, text which is not JSON is returned as is.
+   */
+  static convertJson(opts: { json: string; to: TKeyCase }): string {
+    const { json, to } = opts;
+    if (to === KeyCases.PRESERVE) {
+      return json;
+    }
+
+    try {
+      return JSON.stringify(KeyCaseConverter.convertKeys({ value: JSON.parse(json), to }));
+    } catch {
+      return json;
+    }
+  }
+}
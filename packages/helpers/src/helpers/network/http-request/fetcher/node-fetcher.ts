@@ -1,13 +1,39 @@
 import { AnyObject } from '@/common/types';
-import { stringify } from 'node:querystring';
-import { AbstractNetworkFetchableHelper, IRequestOptions } from './base-fetcher';
+import { redact } from '@/helpers/logger/redaction';
+import tls from 'node:tls';
+import {
+  AbstractNetworkFetchableHelper,
+  IBaseFetcherOptions,
+  IRequestOptions,
+} from './base-fetcher';
+import { THeadersInput } from './headers';
+import { IResolvedAddress } from './ip-family';
+import { KeyCaseConverter } from './key-case';
+import { PayloadMetrics } from './payload-metrics';
+import { QueryStrings } from './query';
+import { replaceResponseBody, tapResponseBody } from './response-body';
+import { ResponseSizeGuard } from './response-size';
+import { FetchDeadline, IFetcherTimeoutOptions, TimeoutErrorCodes } from './timeouts';
+import { RegionSelector, ServiceDiscovery } from '../../discovery';
 import { BaseNetworkRequest } from '../base-network-request.helper';
+import { INetworkEndpoint } from '../endpoints';
+import { IErrorBodyDecoder, IResponseDateOptions, TErrorMapper } from '../response';
+import { ConditionalUpdates, IConditionalUpdateOptions } from '../conditional-update';
+import { FileDownloads, IDownloadOptions } from '../download';
+import { IStreamJsonArrayOptions, JsonArrayStreams } from '../json-stream';
+import { ByteStreams, IStreamBytesOptions } from '../byte-stream';
+import { FileRequestBody } from '../file-body';
+import { IPollOperationOptions, OperationPolling } from '../operation-polling';
 
-export interface INodeFetchRequestOptions extends RequestInit, IRequestOptions {
+export interface INodeFetchRequestOptions extends Omit<RequestInit, 'body'>, IRequestOptions {
   url: string;
   params?: Record<string | symbol, any>;
+  body?: RequestInit['body'] | FileRequestBody;
 }
 
+// `application/json`, `application/problem+json`...
+const JSON_CONTENT_TYPE = /^application\/([\w.-]+\+)?json\b/i;
+
 // -------------------------------------------------------------
 export class NodeFetcher extends AbstractNetworkFetchableHelper<
   'node-fetch',
@@ -16,44 +42,99 @@ export class NodeFetcher extends AbstractNetworkFetchableHelper<
 > {
   private defaultConfigs: RequestInit;
 
-  constructor(opts: { name: string; defaultConfigs: RequestInit; logger?: any }) {
-    super({ name: opts.name, variant: 'node-fetch' });
-    const { name, defaultConfigs } = opts;
+  constructor(
+    opts: { name: string; defaultConfigs: RequestInit; logger?: any } & IBaseFetcherOptions,
+  ) {
+    const { name, defaultConfigs, logger, ...fetcherOptions } = opts;
+    super({ name, variant: 'node-fetch', ...fetcherOptions });
     this.name = name;
-    opts?.logger?.info('Creating new network request worker instance! Name: %s', this.name);
+    logger?.info('Creating new network request worker instance! Name: %s', this.name);
 
     this.defaultConfigs = defaultConfigs;
   }
 
   // -------------------------------------------------------------
-  // SEND REQUEST
+  // DISPATCH REQUEST
   // -------------------------------------------------------------
-  override async send(opts: INodeFetchRequestOptions, logger?: any) {
-    const { url, method = 'get', params, body, headers, timeout, signal, ...rest } = opts;
-
-    let timeoutId: NodeJS.Timeout | undefined;
-    let abortController: AbortController | undefined;
+  protected override async dispatch(opts: INodeFetchRequestOptions, logger?: any) {
+    const {
+      url,
+      method = 'get',
+      params,
+      body,
+      headers,
+      headerMerge,
+      queryArrayFormat = this.queryArrayFormat,
+      keyCase = this.keyCase,
+      maxResponseBytes = this.maxResponseBytes,
+      bandwidth,
+      timeout,
+      connectTimeout = timeout ?? this.timeouts.connectTimeout,
+      readTimeout = this.timeouts.readTimeout,
+      totalTimeout = this.timeouts.totalTimeout,
+      retry,
+      socketPath = this.socketPath,
+      resolvedAddress,
+      followRedirects,
+      signal,
+      ...rest
+    } = opts;
 
-    if (timeout) {
-      abortController = new AbortController();
-      timeoutId = setTimeout(() => {
-        abortController?.abort();
-      }, timeout);
-    }
+    const mergedHeaders = this.mergeHeaders({
+      defaults: this.defaultConfigs.headers as THeadersInput,
+      headers: headers as THeadersInput,
+      policy: headerMerge,
+    });
+    const isJsonBody =
+      typeof body === 'string' && JSON_CONTENT_TYPE.test(mergedHeaders['content-type'] ?? '');
 
-    const requestConfigs: RequestInit = {
+    const requestConfigs: RequestInit & { duplex?: 'half'; tls?: AnyObject; unix?: string } = {
       ...this.defaultConfigs,
       ...rest,
       method,
-      body,
-      headers,
-      signal: abortController?.signal ?? signal,
+      body: isJsonBody
+        ? KeyCaseConverter.convertJson({ json: body, to: keyCase })
+        : (body as RequestInit['body']),
+      headers: this.withPropagationHeaders(mergedHeaders),
+      signal,
     };
 
+    if (followRedirects === false) {
+      requestConfigs.redirect = 'manual';
+    }
+
+    // Stream file bodies instead of buffering them, fetch requires half duplex for stream bodies
+    if (FileRequestBody.isFileBody(body)) {
+      const userHeaders = requestConfigs.headers as AnyObject | Headers | undefined;
+
+      requestConfigs.body = body.toWebStream();
+      requestConfigs.duplex = 'half';
+      requestConfigs.headers = {
+        ...(userHeaders instanceof Headers ? Object.fromEntries(userHeaders.entries()) : userHeaders),
+        ...body.getHeaders(),
+      };
+    }
+
+    const throttle = this.getBandwidthThrottle(bandwidth);
+    if (throttle && requestConfigs.body instanceof ReadableStream) {
+      requestConfigs.body = requestConfigs.body.pipeThrough(throttle.toWebTransform());
+      requestConfigs.duplex = 'half';
+    }
+
+    // Bun fetch option, the host of the url is only sent as the `host` header
+    if (socketPath) {
+      requestConfigs.unix = socketPath;
+    }
+
     let requestUrl = '';
     const urlParts = [url];
     if (params) {
-      urlParts.push(stringify(params));
+      urlParts.push(
+        QueryStrings.stringify({
+          params: KeyCaseConverter.convertKeys({ value: params, to: keyCase }),
+          arrayFormat: queryArrayFormat,
+        }),
+      );
       requestUrl = urlParts.join('?');
     } else {
       requestUrl = urlParts.join();
@@ -61,30 +142,320 @@ export class NodeFetcher extends AbstractNetworkFetchableHelper<
 
     logger
       ?.for(this.send.name)
-      .info('URL: %s | Props: %o | Timeout: %s', url, requestConfigs, timeout);
+      .info(
+        'URL: %s | Props: %o | Timeouts: %o',
+        url,
+        redact(requestConfigs),
+        { connectTimeout, readTimeout, totalTimeout },
+      );
+
+    // Bun fetch option, set after logging to keep the certificates out of the logs
+    if ((this.tlsCredentials || this.pinning) && !requestConfigs.tls) {
+      requestConfigs.tls = {
+        ...this.tlsCredentials?.get(),
+        checkServerIdentity: this.pinning?.checkServerIdentity,
+      };
+    }
+
+    this.payloadMetrics?.observe({
+      direction: 'request',
+      method,
+      url,
+      size: PayloadMetrics.getBodySize(body),
+    });
+
+    // The total deadline spans every attempt and the body of the final response
+    let deadline: FetchDeadline | undefined;
+    if (totalTimeout) {
+      deadline = new FetchDeadline({ url, signal });
+      deadline.start({ code: TimeoutErrorCodes.TOTAL_TIMEOUT, timeout: totalTimeout });
+    }
 
+    let response: Response;
     try {
-      return await fetch(requestUrl, requestConfigs);
-    } finally {
-      if (timeoutId) {
-        clearTimeout(timeoutId);
+      if (resolvedAddress && !socketPath) {
+        requestUrl = this.pinAddress({
+          url: requestUrl,
+          configs: requestConfigs,
+          address: resolvedAddress,
+        });
+      } else if (this.ipFamily && !socketPath) {
+        requestUrl = await this.connectByFamily({
+          url: requestUrl,
+          configs: requestConfigs,
+          signal: deadline?.signal ?? signal,
+        });
       }
+
+      response = await this.executeWithRetry({
+        method,
+        url,
+        logger,
+        // Streams can only be sent once
+        isReplayable: !(requestConfigs.body instanceof ReadableStream),
+        headers: requestConfigs.headers as AnyObject,
+        isRetryEnabled: retry,
+        execute: () =>
+          this.injectFaults({
+            method,
+            url,
+            signal: deadline?.signal ?? signal,
+            logger,
+            execute: () =>
+              this.fetchWithTimeout({
+                url: requestUrl,
+                configs: { ...requestConfigs, signal: deadline?.signal ?? signal },
+                connectTimeout,
+                readTimeout,
+              }),
+            respond: status => new Response(null, { status }),
+          }),
+        getStatus: ({ response }) => response?.status,
+        discard: response => {
+          response.body?.cancel().catch(() => {});
+        },
+      });
+    } catch (error) {
+      deadline?.stop();
+      throw error;
+    }
+
+    if (deadline) {
+      response = tapResponseBody({ response, onEnd: () => deadline.stop() });
+    }
+
+    let throttled = response;
+    if (throttle && response.body) {
+      const throttledBody = response.body.pipeThrough(throttle.toWebTransform());
+      throttled = replaceResponseBody({ response, body: throttledBody });
+    }
+
+    const limited = ResponseSizeGuard.limit({
+      response: throttled,
+      url,
+      maxBytes: maxResponseBytes,
+    });
+    return this.payloadMetrics?.measure({ response: limited, method, url }) ?? limited;
+  }
+
+  // -------------------------------------------------------------
+  // DOWNLOAD
+  // -------------------------------------------------------------
+  /**
+   * Save the response body of `opts.url` to `opts.destination`, see {@link FileDownloads}.
+   */
+  download(opts: IDownloadOptions, logger?: any) {
+    const {
+      url,
+      params,
+      headers,
+      connectTimeout,
+      readTimeout,
+      totalTimeout,
+      maxResponseBytes,
+      bandwidth,
+    } = opts;
+
+    const request = {
+      url,
+      method: 'get',
+      params,
+      headers,
+      connectTimeout,
+      readTimeout,
+      totalTimeout,
+      maxResponseBytes,
+      bandwidth,
+    };
+
+    return FileDownloads.download({
+      options: opts,
+      fetch: () => this.send(request, logger),
+      logger,
+    });
+  }
+
+  // -------------------------------------------------------------
+  // CONDITIONAL UPDATE
+  // -------------------------------------------------------------
+  /**
+   * Read-modify-write of `opts.url` guarded by its ETag, see {@link ConditionalUpdates}.
+   */
+  conditionalUpdate<T>(opts: IConditionalUpdateOptions<T>, logger?: any) {
+    return ConditionalUpdates.update({
+      options: opts,
+      fetch: request => this.send(request, logger),
+      logger,
+    });
+  }
+
+  // -------------------------------------------------------------
+  // OPERATION POLLING
+  // -------------------------------------------------------------
+  /**
+   * Wait for the asynchronous operation behind a `202 Accepted`, see {@link OperationPolling}.
+   */
+  pollOperation<T>(opts: IPollOperationOptions<T>, logger?: any) {
+    return OperationPolling.poll({
+      options: opts,
+      fetch: request => this.send(request, logger),
+      logger,
+    });
+  }
+
+  // -------------------------------------------------------------
+  // BYTE STREAM
+  // -------------------------------------------------------------
+  /**
+   * Body of `opts.url` as a byte stream with its length and type, see {@link ByteStreams}.
+   */
+  streamBytes(opts: IStreamBytesOptions, logger?: any) {
+    return ByteStreams.open({ options: opts, fetch: request => this.send(request, logger) });
+  }
+
+  // -------------------------------------------------------------
+  // JSON ARRAY STREAM
+  // -------------------------------------------------------------
+  /**
+   * Items of the JSON array body of `opts.url` read as they arrive, see {@link JsonArrayStreams}.
+   */
+  streamJsonArray<T>(opts: IStreamJsonArrayOptions<T>, logger?: any) {
+    return JsonArrayStreams.stream<T>({
+      options: opts,
+      fetch: request => this.send(request, logger),
+      logger,
+    });
+  }
+
+  private async connectByFamily(opts: {
+    url: string;
+    configs: RequestInit & { tls?: AnyObject };
+    signal?: AbortSignal | null;
+  }): Promise<string> {
+    const { url, configs, signal } = opts;
+    if (!URL.canParse(url)) {
+      return url;
+    }
+
+    const target = new URL(url);
+    const address = await this.ipFamily!.select({
+      hostname: target.hostname.replace(/^\[|\]$/g, ''),
+      port: Number(target.port) || (target.protocol === 'https:' ? 443 : 80),
+      signal,
+    });
+    if (!address) {
+      return url;
     }
+
+    return this.pinAddress({ url, configs, address });
+  }
+
+  // Bun fetch resolves hosts itself, the selected address replaces the host of the url while the
+  // host header, SNI and certificate checks keep the name
+  private pinAddress(opts: {
+    url: string;
+    configs: RequestInit & { tls?: AnyObject };
+    address: IResolvedAddress;
+  }): string {
+    const { url, configs, address } = opts;
+    if (!URL.canParse(url)) {
+      return url;
+    }
+
+    const target = new URL(url);
+    const hostname = target.hostname.replace(/^\[|\]$/g, '');
+    if (hostname === address.address) {
+      return url;
+    }
+
+    const isHttps = target.protocol === 'https:';
+    configs.headers = { host: target.host, ...(configs.headers as AnyObject) };
+    if (isHttps) {
+      const verify = this.pinning?.checkServerIdentity ?? tls.checkServerIdentity;
+      configs.tls = {
+        ...configs.tls,
+        serverName: hostname,
+        checkServerIdentity: (_host: string, cert: tls.PeerCertificate) => verify(hostname, cert),
+      };
+    }
+
+    target.hostname = address.family === 6 ? `[${address.address}]` : address.address;
+    return target.toString();
+  }
+
+  // The connect and read timeouts apply to every attempt
+  private async fetchWithTimeout(
+    opts: { url: string; configs: RequestInit } & Omit<IFetcherTimeoutOptions, 'totalTimeout'>,
+  ) {
+    const { url, configs, connectTimeout, readTimeout } = opts;
+    if (!connectTimeout && !readTimeout) {
+      return fetch(url, configs);
+    }
+
+    const deadline = new FetchDeadline({ url, signal: configs.signal });
+    if (connectTimeout) {
+      deadline.start({ code: TimeoutErrorCodes.CONNECT_TIMEOUT, timeout: connectTimeout });
+    }
+
+    let response: Response;
+    try {
+      response = await fetch(url, { ...configs, signal: deadline.signal });
+    } finally {
+      deadline.stop();
+    }
+
+    if (!readTimeout) {
+      return response;
+    }
+
+    // Restarted by every chunk, aborting the attempt errors the body with the timeout
+    const startReadDeadline = () => {
+      deadline.start({ code: TimeoutErrorCodes.READ_TIMEOUT, timeout: readTimeout });
+    };
+
+    startReadDeadline();
+    return tapResponseBody({
+      response,
+      onChunk: startReadDeadline,
+      onEnd: () => deadline.stop(),
+    });
   }
 }
 
 // -----------------------------------------------------------------------------
-export interface INodeFetchNetworkRequestOptions {
+export interface INodeFetchNetworkRequestOptions extends IBaseFetcherOptions {
   name: string;
   networkOptions: RequestInit & {
     baseUrl?: string;
   };
+  // Resolves `service://<name>` base urls and balances the requests to their endpoints
+  discovery?: ServiceDiscovery;
+  // Base url of the fastest healthy region, see `RegionSelector`
+  regions?: RegionSelector;
+  // Error body type of the upstream, see `BaseNetworkRequest.setErrorDecoder`
+  errorDecoder?: IErrorBodyDecoder;
+  // Domain errors of the upstream failures, see `BaseNetworkRequest.setErrorMapper`
+  errorMapper?: TErrorMapper;
+  // Named endpoints invoked with `call`, see `BaseNetworkRequest.defineEndpoint`
+  endpoints?: Record<string, INetworkEndpoint>;
+  // Date format of the upstream payloads, see `BaseNetworkRequest.dateSchema`
+  dates?: IResponseDateOptions;
 }
 
 // -----------------------------------------------------------------------------
 export class NodeFetchNetworkRequest extends BaseNetworkRequest<'node-fetch'> {
   constructor(opts: INodeFetchNetworkRequestOptions) {
-    const { name, networkOptions } = opts;
+    const {
+      name,
+      networkOptions,
+      discovery,
+      regions,
+      errorDecoder,
+      errorMapper,
+      dates,
+      endpoints,
+      ...fetcherOptions
+    } = opts;
     const { headers, baseUrl, ...rest } = networkOptions;
 
     // Build headers with user values taking precedence
@@ -103,7 +474,17 @@ export class NodeFetchNetworkRequest extends BaseNetworkRequest<'node-fetch'> {
     super({
       name,
       baseUrl,
-      fetcher: new NodeFetcher({ name, defaultConfigs }),
+      discovery,
+      regions,
+      errorDecoder,
+      errorMapper,
+      dates,
+      endpoints,
+      fetcher: new NodeFetcher({ name, defaultConfigs, discovery, ...fetcherOptions }),
     });
   }
+
+  download(opts: IDownloadOptions) {
+    return (this.fetcher as unknown as NodeFetcher).download(opts, this.logger);
+  }
 }
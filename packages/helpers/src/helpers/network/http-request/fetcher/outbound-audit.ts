@@ -0,0 +1,165 @@
+import { AnyObject } from '@/common/types';
+import { AuditLogger, AuditOutcomes } from '@/helpers/audit';
+import C from 'node:crypto';
+import { IRequestOptions } from './base-fetcher';
+
+export class OutboundAuditDefaults {
+  // Mutating methods, reads are not recorded
+  static readonly METHODS = ['post', 'put', 'patch', 'delete'];
+  static readonly ACTION_PREFIX = 'http';
+  static readonly RESOURCE_TYPE = 'http-endpoint';
+}
+
+export interface IOutboundAuditOptions {
+  auditLogger: AuditLogger;
+  // Defaults to `OutboundAuditDefaults.METHODS`
+  methods?: Array<string>;
+  // e.g. `payment-provider`, defaults to `http-endpoint`
+  resourceType?: string;
+}
+
+// --------------------------------------------------------
+/**
+ * Audit trail of the outgoing requests of a fetcher, for compliance reviews of the calls to
+ * payment or tax providers.
+ *
+ * Every mutating request is recorded once its outcome is known, retries included, as an
+ * `http.<method>` event of the endpoint (url without query). The payload is only kept as its
+ * SHA-256, next to the status, the duration and the request id of the request context. Failed
+ * requests and statuses from 400 are recorded as failures.
+ *
+ * A failing audit write is logged, it never fails a request which was already sent. Use sinks
+ * of `AuditLogger` for the table or the queue topic, e.g. `DrizzleAuditSink` or `QueueAuditSink`.
+ *
+ * @example
+ * ```typescript
+ * const payments = new NodeFetchNetworkRequest({
+ *   name: 'PaymentRequest',
+ *   networkOptions: { baseUrl: 'https://api.psp.example.com' },
+ *   audit: { auditLogger, resourceType: 'payment-provider' },
+ * });
+ * ```
+ */
+export class OutboundAudit {
+  private auditLogger: AuditLogger;
+  private methods: Array<string>;
+  private resourceType: string;
+
+  constructor(opts: IOutboundAuditOptions) {
+    this.auditLogger = opts.auditLogger;
+    this.methods = (opts.methods ?? OutboundAuditDefaults.METHODS).map(m => m.toLowerCase());
+    this.resourceType = opts.resourceType ?? OutboundAuditDefaults.RESOURCE_TYPE;
+  }
+
+  static from(opts?: OutboundAudit | IOutboundAuditOptions) {
+    if (!opts || opts instanceof OutboundAudit) {
+      return opts;
+    }
+
+    return new OutboundAudit(opts);
+  }
+
+  /**
+   * SHA-256 of a request body, `undefined` for bodies which can not be read twice, e.g. streams.
+   */
+  static hashPayload(body: unknown): string | undefined {
+    if (body === undefined || body === null) {
+      return undefined;
+    }
+
+    const hash = C.createHash('sha256');
+    if (typeof body === 'string' || body instanceof Uint8Array) {
+      return hash.update(body).digest('hex');
+    }
+
+    if (body instanceof ArrayBuffer) {
+      return hash.update(new Uint8Array(body)).digest('hex');
+    }
+
+    if (body instanceof URLSearchParams) {
+      return hash.update(body.toString()).digest('hex');
+    }
+
+    const isPlainObject = Array.isArray(body) || Object.getPrototypeOf(body) === Object.prototype;
+    return isPlainObject ? hash.update(JSON.stringify(body)).digest('hex') : undefined;
+  }
+
+  isAudited(request: IRequestOptions) {
+    return this.methods.includes((request.method ?? 'get').toLowerCase());
+  }
+
+  /**
+   * Run `execute` and record its outcome, the status of the response or of the error.
+   */
+  async track<R>(opts: {
+    fetcher: string;
+    request: IRequestOptions;
+    execute: () => Promise<R>;
+    logger?: any;
+  }): Promise<R> {
+    const { fetcher, request, execute, logger } = opts;
+    if (!this.isAudited(request)) {
+      return execute();
+    }
+
+    const startedAt = performance.now();
+    let status: number | undefined;
+    let error: unknown;
+    try {
+      const rs = await execute();
+      ({ status } = (rs ?? {}) as { status?: number });
+      return rs;
+    } catch (e) {
+      error = e;
+      // Axios throws the rejected statuses with their response
+      ({ status } = (e as { response?: { status?: number } })?.response ?? {});
+      throw e;
+    } finally {
+      await this.record({
+        fetcher,
+        request,
+        status,
+        error,
+        duration: Math.round(performance.now() - startedAt),
+      }).catch(e => {
+        logger
+          ?.for(this.track.name)
+          .error('Failed to record outbound request | url: %s | error: %s', request.url, e);
+      });
+    }
+  }
+
+  // --------------------------------------------------------
+  private async record(opts: {
+    fetcher: string;
+    request: IRequestOptions;
+    status?: number;
+    error?: unknown;
+    duration: number;
+  }) {
+    const { fetcher, request, status, error, duration } = opts;
+    const method = (request.method ?? 'get').toLowerCase();
+    const endpoint = request.url.split('?')[0];
+
+    const metadata: AnyObject = {
+      fetcher,
+      method,
+      endpoint,
+      status,
+      duration,
+      // Axios requests carry their body as `data`
+      payloadHash: OutboundAudit.hashPayload(request.body ?? request.data),
+    };
+    if (error !== undefined) {
+      metadata.error = `${error}`;
+    }
+
+    const isFailure = error !== undefined || status === undefined || status >= 400;
+    await this.auditLogger.record({
+      action: `${OutboundAuditDefaults.ACTION_PREFIX}.${method}`,
+      resource: { type: this.resourceType, id: endpoint },
+      outcome: isFailure ? AuditOutcomes.FAILURE : AuditOutcomes.SUCCESS,
+      metadata,
+    });
+  }
+}
@@ -0,0 +1,195 @@
+import { BaseHelper } from '@/helpers/base';
+import { Histogram, MetricDefaults, MetricsRegistry } from '@/helpers/metrics';
+import { FileRequestBody } from '../file-body';
+import { replaceResponseBody } from './response-body';
+
+const HTTP_CONTENT_LENGTH = 'content-length';
+
+// Numeric ids, UUIDs and object ids collapse to `:id` so an endpoint is one series
+const ID_SEGMENT_PATTERN = /^(\d+|[\da-f]{8}(-[\da-f]{4}){3}-[\da-f]{12}|[\da-f]{24,})$/i;
+
+export interface IPayloadMetricsOptions {
+  registry?: MetricsRegistry;
+  buckets?: Array<number>;
+  // Bodies larger than this many bytes are logged with a warning
+  warnThreshold?: number;
+  // Label of a request url, defaults to its host and path with ids replaced by `:id`
+  getEndpoint?: (url: string) => string;
+}
+
+// --------------------------------------------------------
+/**
+ * Request and response body sizes of a fetcher, recorded per endpoint in the
+ * `http_client_request_size_bytes` and `http_client_response_size_bytes` histograms.
+ *
+ * @example
+ * ```typescript
+ * const request = new NodeFetchNetworkRequest({
+ *   name: 'CatalogRequest',
+ *   networkOptions: { baseUrl: 'https://catalog.internal' },
+ *   payloadMetrics: { warnThreshold: 5 * 1024 * 1024 },
+ * });
+ * ```
+ */
+export class PayloadMetrics extends BaseHelper {
+  static readonly REQUEST_SIZE_METRIC = 'http_client_request_size_bytes';
+  static readonly RESPONSE_SIZE_METRIC = 'http_client_response_size_bytes';
+
+  private fetcher: string;
+  private requestSize: Histogram;
+  private responseSize: Histogram;
+  private warnThreshold?: number;
+  private getEndpoint: (url: string) => string;
+
+  constructor(opts: { fetcher: string } & IPayloadMetricsOptions) {
+    super({ scope: PayloadMetrics.name, identifier: opts.fetcher });
+
+    const {
+      fetcher,
+      registry = MetricsRegistry.getInstance(),
+      buckets = MetricDefaults.SIZE_BUCKETS,
+      warnThreshold,
+      getEndpoint = PayloadMetrics.getEndpoint,
+    } = opts;
+
+    this.fetcher = fetcher;
+    this.warnThreshold = warnThreshold;
+    this.getEndpoint = getEndpoint;
+
+    const labelNames = ['fetcher', 'method', 'endpoint'];
+    this.requestSize = registry.histogram({
+      name: PayloadMetrics.REQUEST_SIZE_METRIC,
+      help: 'Size of the HTTP client request bodies in bytes.',
+      labelNames,
+      buckets,
+    });
+    this.responseSize = registry.histogram({
+      name: PayloadMetrics.RESPONSE_SIZE_METRIC,
+      help: 'Size of the HTTP client response bodies in bytes.',
+      labelNames,
+      buckets,
+    });
+  }
+
+  /**
+   * `https://catalog.internal/products/42?expand=1` => `catalog.internal/products/:id`
+   */
+  static getEndpoint(url: string): string {
+    let host = '';
+    let pathname = url.split('?')[0];
+
+    try {
+      ({ host, pathname } = new URL(url));
+    } catch {
+      // Relative url, e.g. resolved by the axios base url
+    }
+
+    const path = pathname
+      .split('/')
+      .map(segment => (ID_SEGMENT_PATTERN.test(segment) ? ':id' : segment))
+      .join('/');
+
+    return `${host}${path}`;
+  }
+
+  /**
+   * Size in bytes of a request body, `undefined` for streams.
+   */
+  static getBodySize(body: unknown): number | undefined {
+    if (body === undefined || body === null) {
+      return 0;
+    }
+
+    if (typeof body === 'string') {
+      return Buffer.byteLength(body);
+    }
+
+    if (FileRequestBody.isFileBody(body)) {
+      return body.size;
+    }
+
+    if (body instanceof ArrayBuffer || ArrayBuffer.isView(body)) {
+      return body.byteLength;
+    }
+
+    if (body instanceof Blob) {
+      return body.size;
+    }
+
+    if (body instanceof URLSearchParams) {
+      return Buffer.byteLength(body.toString());
+    }
+
+    const isStream = body instanceof ReadableStream || Symbol.asyncIterator in (body as object);
+    if (isStream) {
+      return undefined;
+    }
+
+    // Plain objects are sent as JSON
+    return Buffer.byteLength(JSON.stringify(body) ?? '');
+  }
+
+  static getContentLength(value: unknown): number | undefined {
+    if (value === undefined || value === null || value === '') {
+      return undefined;
+    }
+
+    const length = Number(value);
+    return Number.isFinite(length) ? length : undefined;
+  }
+
+  // --------------------------------------------------------
+  observe(opts: { direction: 'request' | 'response'; method: string; url: string; size?: number }) {
+    const { direction, method, url, size } = opts;
+    if (size === undefined) {
+      return;
+    }
+
+    const endpoint = this.getEndpoint(url);
+    const labels = { fetcher: this.fetcher, method: method.toUpperCase(), endpoint };
+    const histogram = direction === 'request' ? this.requestSize : this.responseSize;
+    histogram.observe({ labels, value: size });
+
+    if (this.warnThreshold !== undefined && size > this.warnThreshold) {
+      this.logger
+        .for(this.observe.name)
+        .warn(
+          'Large %s body | Method: %s | Endpoint: %s | Size: %d | Threshold: %d',
+          direction,
+          labels.method,
+          endpoint,
+          size,
+          this.warnThreshold,
+        );
+    }
+  }
+
+  /**
+   * Record the body size of a fetch response, counted while it streams when no
+   * `content-length` is declared.
+   */
+  measure(opts: { response: Response; method: string; url: string }): Response {
+    const { response, method, url } = opts;
+
+    const declared = PayloadMetrics.getContentLength(response.headers.get(HTTP_CONTENT_LENGTH));
+    if (declared !== undefined || !response.body) {
+      this.observe({ direction: 'response', method, url, size: declared ?? 0 });
+      return response;
+    }
+
+    let size = 0;
+    const body = response.body.pipeThrough(
+      new TransformStream<Uint8Array, Uint8Array>({
+        transform: (chunk, controller) => {
+          size += chunk.byteLength;
+          controller.enqueue(chunk);
+        },
+        flush: () => {
+          this.observe({ direction: 'response', method, url, size });
+        },
+      }),
+    );
+
+    return replaceResponseBody({ response, body });
+  }
+}
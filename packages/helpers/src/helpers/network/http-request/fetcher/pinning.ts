@@ -0,0 +1,144 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import C from 'node:crypto';
+import tls from 'node:tls';
+
+export class CertificatePinningErrorCodes {
+  static readonly PIN_MISMATCH = 'CERTIFICATE_PIN_MISMATCH';
+}
+
+export interface ICertificatePinReport {
+  host: string;
+  // `sha256/<base64>` pins of the presented chain, leaf first
+  received: Array<string>;
+  expected: Array<string>;
+}
+
+export interface ICertificatePinningOptions {
+  // Host, or `*.` wildcard, => base64 SHA-256 pins of the SPKI, `sha256/` prefix optional
+  pins: Record<string, Array<string>>;
+  // Report mismatches without rejecting the connection, to roll out or rotate pins safely
+  reportOnly?: boolean;
+  onMismatch?: (report: ICertificatePinReport) => void;
+}
+
+const PIN_PREFIX = 'sha256/';
+
+const toPin = (value: string) => (value.startsWith(PIN_PREFIX) ? value : `${PIN_PREFIX}${value}`);
+
+// --------------------------------------------------------
+/**
+ * SPKI pins of the hosts a fetcher calls, checked on every TLS handshake after the regular
+ * certificate verification.
+ *
+ * A host passes when any certificate of its chain matches one of its pins, pinning the
+ * intermediate as well as the leaf survives certificate renewals. Hosts without pins are only
+ * verified.
+ *
+ * @example
+ * ```typescript
+ * // openssl x509 -in cert.pem -pubkey -noout | openssl pkey -pubin -outform der |
+ * //   openssl dgst -sha256 -binary | base64
+ * const request = new NodeFetchNetworkRequest({
+ *   name: 'AcquirerRequest',
+ *   networkOptions: { baseUrl: 'https://api.acquirer.example' },
+ *   tls: {
+ *     pinning: {
+ *       pins: { 'api.acquirer.example': ['<leaf pin>', '<backup pin>'] },
+ *     },
+ *   },
+ * });
+ * ```
+ */
+export class CertificatePinning extends BaseHelper {
+  private pins: Map<string, Set<string>>;
+  private reportOnly: boolean;
+  private onMismatch?: (report: ICertificatePinReport) => void;
+
+  constructor(opts: ICertificatePinningOptions) {
+    super({ scope: CertificatePinning.name });
+
+    this.pins = new Map();
+    for (const [host, pins] of Object.entries(opts.pins)) {
+      this.pins.set(host.toLowerCase(), new Set(pins.map(toPin)));
+    }
+    this.reportOnly = opts.reportOnly ?? false;
+    this.onMismatch = opts.onMismatch;
+  }
+
+  /**
+   * `sha256/<base64>` pin of a DER encoded public key.
+   */
+  static getPin(opts: { publicKey: Buffer }): string {
+    return toPin(C.createHash('sha256').update(opts.publicKey).digest('base64'));
+  }
+
+  getPins(opts: { host: string }): Set<string> | undefined {
+    const host = opts.host.toLowerCase();
+    const pins = this.pins.get(host);
+    if (pins) {
+      return pins;
+    }
+
+    const dot = host.indexOf('.');
+    return dot < 0 ? undefined : this.pins.get(`*${host.slice(dot)}`);
+  }
+
+  /**
+   * `checkServerIdentity` of the TLS options, the error rejects the connection.
+   */
+  checkServerIdentity = (host: string, cert: tls.PeerCertificate): Error | undefined => {
+    const error = tls.checkServerIdentity(host, cert);
+    if (error) {
+      return error;
+    }
+
+    const expected = this.getPins({ host });
+    if (!expected) {
+      return undefined;
+    }
+
+    const received = this.getChainPins(cert);
+    if (received.some(pin => expected.has(pin))) {
+      return undefined;
+    }
+
+    const report = { host, received, expected: [...expected] };
+    this.logger
+      .for('checkServerIdentity')
+      .warn(
+        'Certificate pin mismatch | host: %s | received: %o | reportOnly: %s',
+        host,
+        received,
+        this.reportOnly,
+      );
+    this.onMismatch?.(report);
+
+    if (this.reportOnly) {
+      return undefined;
+    }
+
+    return getError({
+      statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+      messageCode: CertificatePinningErrorCodes.PIN_MISMATCH,
+      message: `[CertificatePinning] No certificate matches the pins | host: ${host}`,
+    });
+  };
+
+  // --------------------------------------------------------
+  // The issuer of a self signed root is the root itself
+  private getChainPins(cert: tls.PeerCertificate): Array<string> {
+    const rs: Array<string> = [];
+    const seen = new Set<tls.PeerCertificate>();
+
+    let current: tls.PeerCertificate | undefined = cert;
+    while (current?.pubkey && !seen.has(current)) {
+      seen.add(current);
+      rs.push(CertificatePinning.getPin({ publicKey: current.pubkey }));
+      current = (current as tls.DetailedPeerCertificate).issuerCertificate;
+    }
+
+    return rs;
+  }
+}
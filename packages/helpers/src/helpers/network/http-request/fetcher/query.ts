@@ -0,0 +1,71 @@
+import { TConstValue } from '@/common/types';
+import { stringify } from 'node:querystring';
+
+export class QueryArrayFormats {
+  // `ids=1&ids=2`
+  static readonly REPEAT = 'repeat';
+  // `ids=1,2`
+  static readonly COMMA = 'comma';
+  // `ids[]=1&ids[]=2`
+  static readonly BRACKETS = 'brackets';
+
+  static readonly SCHEME_SET = new Set([this.REPEAT, this.COMMA, this.BRACKETS]);
+
+  static isValid(format: string): boolean {
+    return this.SCHEME_SET.has(format);
+  }
+}
+
+export type TQueryArrayFormat = TConstValue<typeof QueryArrayFormats>;
+
+// --------------------------------------------------------
+export class QueryStrings {
+  /**
+   * Serialize request params, array values follow `arrayFormat`.
+   *
+   * @example
+   * ```typescript
+   * QueryStrings.stringify({ params: { ids: [1, 2], q: 'a b' } }); // 'ids=1&ids=2&q=a%20b'
+   * QueryStrings.stringify({ params: { ids: [1, 2] }, arrayFormat: QueryArrayFormats.COMMA });
+   * // 'ids=1%2C2'
+   * ```
+   */
+  static stringify(opts: {
+    params?: Record<string | symbol, any>;
+    arrayFormat?: TQueryArrayFormat;
+  }): string {
+    const { params, arrayFormat = QueryArrayFormats.REPEAT } = opts;
+    if (!params) {
+      return '';
+    }
+
+    if (arrayFormat === QueryArrayFormats.REPEAT) {
+      return stringify(params);
+    }
+
+    const rs: Record<string, unknown> = {};
+    for (const [key, value] of Object.entries(params)) {
+      if (!Array.isArray(value)) {
+        rs[key] = value;
+        continue;
+      }
+
+      switch (arrayFormat) {
+        case QueryArrayFormats.COMMA: {
+          rs[key] = value.join(',');
+          break;
+        }
+        case QueryArrayFormats.BRACKETS: {
+          rs[`${key}[]`] = value;
+          break;
+        }
+        default: {
+          rs[key] = value;
+          break;
+        }
+      }
+    }
+
+    return stringify(rs as Parameters<typeof stringify>[0]);
+  }
+}
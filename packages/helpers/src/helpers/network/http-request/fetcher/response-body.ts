@@ -0,0 +1,46 @@
+/**
+ * Copy of a fetch response reading from `body`, the url of the original response is kept.
+ */
+export const replaceResponseBody = (opts: {
+  response: Response;
+  body: ReadableStream<Uint8Array>;
+}): Response => {
+  const { response, body } = opts;
+
+  const rs = new Response(body, {
+    status: response.status,
+    statusText: response.statusText,
+    headers: response.headers,
+  });
+  Object.defineProperty(rs, 'url', { value: response.url });
+
+  return rs;
+};
+
+/**
+ * Copy of a fetch response calling `onChunk` for every chunk of its body and `onEnd` once the
+ * body is fully read or failed.
+ */
+export const tapResponseBody = (opts: {
+  response: Response;
+  onChunk?: (chunk: Uint8Array) => void;
+  onEnd: () => void;
+}): Response => {
+  const { response, onChunk, onEnd } = opts;
+  if (!response.body) {
+    onEnd();
+    return response;
+  }
+
+  const body = response.body.pipeThrough(
+    new TransformStream<Uint8Array, Uint8Array>({
+      transform: (chunk, controller) => {
+        onChunk?.(chunk);
+        controller.enqueue(chunk);
+      },
+      flush: () => onEnd(),
+    }),
+  );
+
+  return replaceResponseBody({ response, body });
+};
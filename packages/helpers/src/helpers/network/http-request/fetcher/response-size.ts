@@ -0,0 +1,72 @@
+import { HTTP } from '@/common/constants';
+import { ApplicationError, getError } from '@/helpers/error';
+import { replaceResponseBody } from './response-body';
+
+const HTTP_CONTENT_LENGTH = 'content-length';
+
+export class ResponseSizeErrorCodes {
+  static readonly RESPONSE_TOO_LARGE = 'RESPONSE_TOO_LARGE';
+}
+
+// --------------------------------------------------------
+/**
+ * Cap on the bytes read from a response body, so a misbehaving upstream can not exhaust the
+ * memory of the process.
+ *
+ * A declared `content-length` above the limit is rejected before the body is read, otherwise
+ * the body is counted while it streams and the connection is aborted once the limit is crossed.
+ */
+export class ResponseSizeGuard {
+  static getError(opts: { url: string; maxBytes: number; received?: number }): ApplicationError {
+    const { url, maxBytes, received } = opts;
+    const details = received === undefined ? '' : ` | received: ${received}`;
+
+    return getError({
+      statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+      messageCode: ResponseSizeErrorCodes.RESPONSE_TOO_LARGE,
+      message: `[ResponseSizeGuard] Response exceeds ${maxBytes} bytes | url: ${url}${details}`,
+    });
+  }
+
+  static isError(error: unknown): error is ApplicationError {
+    return (
+      error instanceof ApplicationError &&
+      error.messageCode === ResponseSizeErrorCodes.RESPONSE_TOO_LARGE
+    );
+  }
+
+  /**
+   * Fetch response whose body errors with `RESPONSE_TOO_LARGE` past `maxBytes`.
+   */
+  static limit(opts: { response: Response; url: string; maxBytes?: number }): Response {
+    const { response, url, maxBytes } = opts;
+    if (!maxBytes || !response.body) {
+      return response;
+    }
+
+    const declared = Number(response.headers.get(HTTP_CONTENT_LENGTH) ?? 0);
+    if (declared > maxBytes) {
+      response.body.cancel().catch(() => {});
+      throw ResponseSizeGuard.getError({ url, maxBytes, received: declared });
+    }
+
+    let received = 0;
+    const body = response.body.pipeThrough(
+      new TransformStream<Uint8Array, Uint8Array>({
+        transform(chunk, controller) {
+          received += chunk.byteLength;
+
+          // Erroring the stream cancels the source, which aborts the connection
+          if (received > maxBytes) {
+            controller.error(ResponseSizeGuard.getError({ url, maxBytes, received }));
+            return;
+          }
+
+          controller.enqueue(chunk);
+        },
+      }),
+    );
+
+    return replaceResponseBody({ response, body });
+  }
+}
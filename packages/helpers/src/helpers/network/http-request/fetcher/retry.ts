@@ -0,0 +1,129 @@
+export interface IRetryBudgetOptions {
+  // Share of the requests of the window which may be retried, `0.1` => at most 10% extra load
+  ratio?: number;
+  // Retries always allowed per second, so low traffic clients can still retry
+  minRetriesPerSecond?: number;
+  // Sliding window in ms
+  window?: number;
+}
+
+export interface IFetcherRetryOptions {
+  // Total attempts including the first one
+  maxAttempts?: number;
+  baseDelay?: number;
+  maxDelay?: number;
+  factor?: number;
+  // Non idempotent methods are never retried unless listed, see `FetcherRetryDefaults.METHODS`
+  methods?: Array<string>;
+  // Requests carrying this header are retried whatever their method, `false` to disable
+  idempotencyKeyHeader?: string | false;
+  // Response statuses worth retrying, network errors are always retried
+  statusCodes?: Array<number>;
+  // Shared budget instance, options for a fetcher local budget or `false` to retry without one
+  budget?: RetryBudget | IRetryBudgetOptions | false;
+}
+
+export class FetcherRetryDefaults {
+  static readonly MAX_ATTEMPTS = 3;
+  static readonly BASE_DELAY = 100;
+  static readonly MAX_DELAY = 2_000;
+  static readonly FACTOR = 2;
+  // POST and PATCH may create duplicates, they need an idempotency key or an explicit opt-in
+  static readonly METHODS = 'get head put delete'.split(' ');
+  static readonly IDEMPOTENCY_KEY_HEADER = 'idempotency-key';
+  static readonly STATUS_CODES = [408, 429, 502, 503, 504];
+
+  static readonly BUDGET_RATIO = 0.1;
+  static readonly BUDGET_MIN_RETRIES_PER_SECOND = 10;
+  static readonly BUDGET_WINDOW = 10 * 1_000;
+  // Counters are kept per bucket so the window slides without storing every request
+  static readonly BUDGET_BUCKETS = 10;
+}
+
+// --------------------------------------------------------
+/**
+ * Sliding window budget capping retries to a share of the recent requests.
+ *
+ * Every request deposits `ratio` of a retry and every retry withdraws one, so when a dependency
+ * fails all requests the retries add at most `ratio` extra load instead of multiplying it by the
+ * number of attempts.
+ *
+ * @example
+ * ```typescript
+ * // One budget for every client talking to the payment service
+ * const budget = new RetryBudget({ ratio: 0.1, minRetriesPerSecond: 5 });
+ *
+ * const payments = new NodeFetchNetworkRequest({
+ *   name: 'PaymentRequest',
+ *   networkOptions: { baseUrl: 'https://payments.internal' },
+ *   retry: { maxAttempts: 3, budget },
+ * });
+ *
+ * budget.getStats(); // { requests: 120, retries: 12, available: 100 }
+ * ```
+ */
+export class RetryBudget {
+  private ratio: number;
+  private minRetriesPerSecond: number;
+  private window: number;
+  private bucketSize: number;
+  private buckets: Array<{ startedAt: number; requests: number; retries: number }>;
+
+  constructor(opts: IRetryBudgetOptions = {}) {
+    this.ratio = opts.ratio ?? FetcherRetryDefaults.BUDGET_RATIO;
+    this.minRetriesPerSecond =
+      opts.minRetriesPerSecond ?? FetcherRetryDefaults.BUDGET_MIN_RETRIES_PER_SECOND;
+    this.window = opts.window ?? FetcherRetryDefaults.BUDGET_WINDOW;
+    this.bucketSize = Math.max(Math.ceil(this.window / FetcherRetryDefaults.BUDGET_BUCKETS), 1);
+    this.buckets = Array.from({ length: FetcherRetryDefaults.BUDGET_BUCKETS }, () => ({
+      startedAt: 0,
+      requests: 0,
+      retries: 0,
+    }));
+  }
+
+  private getBucket(now: number) {
+    const startedAt = now - (now % this.bucketSize);
+    const bucket = this.buckets[Math.floor(startedAt / this.bucketSize) % this.buckets.length];
+
+    // Reused slot of an expired bucket
+    if (bucket.startedAt !== startedAt) {
+      bucket.startedAt = startedAt;
+      bucket.requests = 0;
+      bucket.retries = 0;
+    }
+
+    return bucket;
+  }
+
+  getStats(now = Date.now()) {
+    let requests = 0;
+    let retries = 0;
+
+    for (const bucket of this.buckets) {
+      if (now - bucket.startedAt < this.window) {
+        requests += bucket.requests;
+        retries += bucket.retries;
+      }
+    }
+
+    const allowance = (this.minRetriesPerSecond * this.window) / 1_000 + this.ratio * requests;
+    return { requests, retries, available: Math.max(Math.floor(allowance - retries), 0) };
+  }
+
+  recordRequest(now = Date.now()) {
+    this.getBucket(now).requests++;
+  }
+
+  /**
+   * Withdraw one retry, `false` when the budget of the window is spent.
+   */
+  tryAcquire(now = Date.now()): boolean {
+    if (this.getStats(now).available < 1) {
+      return false;
+    }
+
+    this.getBucket(now).retries++;
+    return true;
+  }
+}
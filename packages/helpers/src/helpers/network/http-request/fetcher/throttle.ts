@@ -0,0 +1,93 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import { Transform } from 'node:stream';
+
+export interface IBandwidthThrottleOptions {
+  bytesPerSecond: number;
+  // Bytes which may be sent at once after an idle period, defaults to one second of transfer
+  burst?: number;
+}
+
+// --------------------------------------------------------
+/**
+ * Token bucket capping the throughput of streamed request and response bodies.
+ *
+ * Every stream piped through the same instance shares its rate, so one throttle passed to all
+ * fetchers of a sync job caps the whole job rather than each transfer.
+ *
+ * @example
+ * ```typescript
+ * const throttle = new BandwidthThrottle({ bytesPerSecond: 5 * 1024 * 1024 });
+ *
+ * const storage = new NodeFetchNetworkRequest({
+ *   name: 'ArchiveRequest',
+ *   networkOptions: { baseUrl: 'https://archive.internal' },
+ *   bandwidth: throttle,
+ * });
+ * ```
+ */
+export class BandwidthThrottle {
+  readonly bytesPerSecond: number;
+  readonly burst: number;
+
+  private tokens: number;
+  private updatedAt = Date.now();
+
+  constructor(opts: IBandwidthThrottleOptions) {
+    const { bytesPerSecond, burst = bytesPerSecond } = opts;
+
+    if (!(bytesPerSecond > 0) || !(burst > 0)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[BandwidthThrottle] Invalid rate | bytesPerSecond: ${bytesPerSecond} | burst: ${burst}`,
+      });
+    }
+
+    this.bytesPerSecond = bytesPerSecond;
+    this.burst = burst;
+    this.tokens = burst;
+  }
+
+  static from(opts?: BandwidthThrottle | IBandwidthThrottleOptions) {
+    if (!opts || opts instanceof BandwidthThrottle) {
+      return opts;
+    }
+
+    return new BandwidthThrottle(opts);
+  }
+
+  /**
+   * Resolve once `bytes` may be sent. The tokens are reserved immediately, so concurrent
+   * callers queue behind each other instead of all waking up at once.
+   */
+  async consume(bytes: number, now = Date.now()) {
+    const refilled = ((now - this.updatedAt) * this.bytesPerSecond) / 1000;
+    this.tokens = Math.min(this.burst, this.tokens + refilled) - bytes;
+    this.updatedAt = now;
+
+    if (this.tokens >= 0) {
+      return;
+    }
+
+    const delay = Math.ceil((-this.tokens / this.bytesPerSecond) * 1000);
+    await new Promise(resolve => setTimeout(resolve, delay));
+  }
+
+  // --------------------------------------------------------
+  toWebTransform(): TransformStream<Uint8Array, Uint8Array> {
+    return new TransformStream<Uint8Array, Uint8Array>({
+      transform: async (chunk, controller) => {
+        await this.consume(chunk.byteLength);
+        controller.enqueue(chunk);
+      },
+    });
+  }
+
+  toNodeTransform(): Transform {
+    return new Transform({
+      transform: (chunk: Buffer, _encoding, callback) => {
+        this.consume(chunk.length).then(() => callback(null, chunk), callback);
+      },
+    });
+  }
+}
@@ -0,0 +1,91 @@
+import { HTTP } from '@/common/constants';
+import { TConstValue } from '@/common/types';
+import { ApplicationError, getError } from '@/helpers/error';
+
+export class TimeoutErrorCodes {
+  static readonly CONNECT_TIMEOUT = 'FETCHER_CONNECT_TIMEOUT';
+  static readonly READ_TIMEOUT = 'FETCHER_READ_TIMEOUT';
+  static readonly TOTAL_TIMEOUT = 'FETCHER_TOTAL_TIMEOUT';
+  // Not sent, the deadline of the incoming request leaves too little time
+  static readonly BUDGET_EXCEEDED = 'FETCHER_BUDGET_EXCEEDED';
+
+  static readonly SCHEME_SET = new Set([
+    this.CONNECT_TIMEOUT,
+    this.READ_TIMEOUT,
+    this.TOTAL_TIMEOUT,
+    this.BUDGET_EXCEEDED,
+  ]);
+
+  static isValid(input: string): boolean {
+    return this.SCHEME_SET.has(input);
+  }
+}
+
+export type TTimeoutErrorCode = TConstValue<typeof TimeoutErrorCodes>;
+
+export interface IFetcherTimeoutOptions {
+  // ms until the response headers are received (its first body chunk with axios), per attempt
+  connectTimeout?: number;
+  // ms the response body may stay idle between two chunks, per attempt
+  readTimeout?: number;
+  // ms for the whole exchange, retries and body included, capped by the deadline of the incoming
+  // request
+  totalTimeout?: number;
+}
+
+// --------------------------------------------------------
+export class FetcherTimeouts {
+  static getError(opts: { code: TTimeoutErrorCode; url: string; timeout: number }) {
+    const { code, url, timeout } = opts;
+
+    return getError({
+      statusCode: HTTP.ResultCodes.RS_5.GatewayTimeout,
+      messageCode: code,
+      message: `[FetcherTimeouts] Request timed out | code: ${code} | timeout: ${timeout}ms | url: ${url}`,
+    });
+  }
+
+  static isError(error: unknown, code?: TTimeoutErrorCode): error is ApplicationError {
+    if (!(error instanceof ApplicationError) || !error.messageCode) {
+      return false;
+    }
+
+    return code ? error.messageCode === code : TimeoutErrorCodes.isValid(error.messageCode);
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * Abort signal fired with a timeout error once the running deadline passes, restarting it
+ * replaces the previous one.
+ */
+export class FetchDeadline {
+  readonly signal: AbortSignal;
+
+  private url: string;
+  private controller = new AbortController();
+  private timer?: ReturnType<typeof setTimeout>;
+
+  constructor(opts: { url: string; signal?: AbortSignal | null }) {
+    const { url, signal } = opts;
+
+    this.url = url;
+    this.signal = signal
+      ? AbortSignal.any([signal, this.controller.signal])
+      : this.controller.signal;
+  }
+
+  start(opts: { code: TTimeoutErrorCode; timeout: number }) {
+    const { code, timeout } = opts;
+    this.stop();
+
+    this.timer = setTimeout(() => {
+      this.controller.abort(FetcherTimeouts.getError({ code, url: this.url, timeout }));
+    }, timeout);
+  }
+
+  stop() {
+    clearTimeout(this.timer);
+    this.timer = undefined;
+  }
+}
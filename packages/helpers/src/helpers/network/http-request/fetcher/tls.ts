@@ -0,0 +1,240 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import fs from 'node:fs';
+import path from 'node:path';
+import tls from 'node:tls';
+import { ICertificatePinningOptions } from './pinning';
+
+const PEM_CERTIFICATE_PATTERN = /-----BEGIN CERTIFICATE-----[\s\S]+?-----END CERTIFICATE-----/g;
+const PEM_EXTENSIONS = new Set(['.pem', '.crt', '.cer']);
+
+export class TlsTrustErrorCodes {
+  static readonly INVALID_CA = 'TLS_TRUST_INVALID_CA';
+  static readonly INVALID_CLIENT_CERTIFICATE = 'TLS_TRUST_INVALID_CLIENT_CERTIFICATE';
+}
+
+export interface ITlsTrustOptions {
+  // PEM files or directories of `.pem` / `.crt` / `.cer` files, e.g. the bundle of a private CA
+  caPaths?: Array<string>;
+  // PEM certificates given inline
+  ca?: Array<string>;
+  // Keep trusting the bundled root certificates next to `caPaths` and `ca`, defaults to `true`
+  systemRoots?: boolean;
+  // SPKI pins of high security hosts, see `CertificatePinning`
+  pinning?: ICertificatePinningOptions;
+
+  // Client certificate of mutual TLS, PEM files or PEM given inline
+  certPath?: string;
+  keyPath?: string;
+  cert?: string;
+  key?: string;
+  passphrase?: string;
+  // Reload `caPaths`, `certPath` and `keyPath` when they change, e.g. rotated by cert-manager,
+  // polled every `interval` ms, defaults to `TlsCredentials.DEFAULT_WATCH_INTERVAL`
+  watch?: boolean | { interval?: number };
+}
+
+// Bun fetch `tls` options
+export interface ITlsCredentials {
+  ca?: Array<string>;
+  cert?: string;
+  key?: string;
+  passphrase?: string;
+}
+
+// --------------------------------------------------------
+/**
+ * Root certificates trusted by a fetcher, so services signed by a private CA are reached with
+ * verification on instead of `rejectUnauthorized: false`.
+ *
+ * @example
+ * ```typescript
+ * const request = new NodeFetchNetworkRequest({
+ *   name: 'LedgerRequest',
+ *   networkOptions: { baseUrl: 'https://ledger.internal' },
+ *   tls: { caPaths: ['/etc/ssl/internal'] },
+ * });
+ * ```
+ */
+export class TlsTrust {
+  /**
+   * Certificates to pass as `ca`, the bundled roots first unless `systemRoots` is `false`.
+   * Files without any certificate are rejected, a typo in a path fails on startup.
+   */
+  static load(opts: ITlsTrustOptions): Array<string> {
+    const { caPaths = [], ca = [], systemRoots = true } = opts;
+
+    const rs = systemRoots ? [...tls.rootCertificates] : [];
+    for (const file of caPaths.flatMap(caPath => TlsTrust.listFiles(caPath))) {
+      rs.push(...TlsTrust.parse({ pem: fs.readFileSync(file, 'utf8'), source: file }));
+    }
+
+    for (const pem of ca) {
+      rs.push(...TlsTrust.parse({ pem, source: 'ca' }));
+    }
+
+    return rs;
+  }
+
+  // Pins alone keep the default roots of the runtime
+  static hasRoots(opts: ITlsTrustOptions): boolean {
+    return !!opts.caPaths?.length || !!opts.ca?.length || opts.systemRoots === false;
+  }
+
+  // Bundles hold several certificates
+  static parse(opts: { pem: string; source: string }): Array<string> {
+    const { pem, source } = opts;
+    const certificates = pem.match(PEM_CERTIFICATE_PATTERN) ?? [];
+
+    if (!certificates.length) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: TlsTrustErrorCodes.INVALID_CA,
+        message: `[TlsTrust] No PEM certificate found | source: ${source}`,
+      });
+    }
+
+    return certificates;
+  }
+
+  // --------------------------------------------------------
+  private static listFiles(caPath: string): Array<string> {
+    if (!fs.statSync(caPath).isDirectory()) {
+      return [caPath];
+    }
+
+    return fs
+      .readdirSync(caPath)
+      .filter(name => PEM_EXTENSIONS.has(path.extname(name).toLowerCase()))
+      .sort()
+      .map(name => path.join(caPath, name));
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * Trusted roots and client certificate of a fetcher, read on every request so rotated files are
+ * picked up by the fetcher instances already handed out.
+ *
+ * With `watch`, the files are polled and reloaded once they change. A reload that fails, e.g. a
+ * certificate written before its key, keeps the previous credentials and the next change is
+ * tried again, requests never see a half rotated pair.
+ *
+ * @example
+ * ```typescript
+ * const request = new NodeFetchNetworkRequest({
+ *   name: 'LedgerRequest',
+ *   networkOptions: { baseUrl: 'https://ledger.internal' },
+ *   tls: {
+ *     caPaths: ['/etc/tls/ca.crt'],
+ *     certPath: '/etc/tls/tls.crt',
+ *     keyPath: '/etc/tls/tls.key',
+ *     watch: true,
+ *   },
+ * });
+ * ```
+ */
+export class TlsCredentials extends BaseHelper {
+  static readonly DEFAULT_WATCH_INTERVAL = 10 * 1_000;
+
+  private options: ITlsTrustOptions;
+  private credentials: ITlsCredentials;
+  private watched: Array<string> = [];
+
+  constructor(opts: ITlsTrustOptions) {
+    super({ scope: TlsCredentials.name });
+
+    this.options = opts;
+    this.credentials = TlsCredentials.load(opts);
+
+    if (opts.watch) {
+      const { interval = TlsCredentials.DEFAULT_WATCH_INTERVAL } =
+        opts.watch === true ? {} : opts.watch;
+      this.watch({ interval });
+    }
+  }
+
+  static hasCredentials(opts: ITlsTrustOptions): boolean {
+    return TlsTrust.hasRoots(opts) || !!(opts.certPath ?? opts.cert ?? opts.keyPath ?? opts.key);
+  }
+
+  /**
+   * Read every file once, a client certificate is checked against its key.
+   */
+  static load(opts: ITlsTrustOptions): ITlsCredentials {
+    const { passphrase } = opts;
+    const ca = TlsTrust.hasRoots(opts) ? TlsTrust.load(opts) : undefined;
+
+    const cert = opts.certPath ? fs.readFileSync(opts.certPath, 'utf8') : opts.cert;
+    const key = opts.keyPath ? fs.readFileSync(opts.keyPath, 'utf8') : opts.key;
+    if (!cert && !key) {
+      return { ca };
+    }
+
+    const source = `cert: ${opts.certPath ?? 'inline'} | key: ${opts.keyPath ?? 'inline'}`;
+    if (!cert || !key) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: TlsTrustErrorCodes.INVALID_CLIENT_CERTIFICATE,
+        message: `[TlsCredentials] Client certificate and key go together | ${source}`,
+      });
+    }
+
+    try {
+      tls.createSecureContext({ cert, key, passphrase });
+    } catch (error) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: TlsTrustErrorCodes.INVALID_CLIENT_CERTIFICATE,
+        message: `[TlsCredentials] Invalid client certificate | ${source} | error: ${(error as Error).message}`,
+      });
+    }
+
+    return { ca, cert, key, passphrase };
+  }
+
+  get(): ITlsCredentials {
+    return this.credentials;
+  }
+
+  /**
+   * @returns whether the credentials were replaced, failures keep the current ones
+   */
+  reload(): boolean {
+    try {
+      this.credentials = TlsCredentials.load(this.options);
+      this.logger.for(this.reload.name).info('TLS credentials reloaded | files: %j', this.watched);
+      return true;
+    } catch (error) {
+      this.logger
+        .for(this.reload.name)
+        .error('Failed to reload TLS credentials, keeping current ones | error: %s', error);
+      return false;
+    }
+  }
+
+  close() {
+    for (const file of this.watched) {
+      fs.unwatchFile(file);
+    }
+
+    this.watched = [];
+  }
+
+  // --------------------------------------------------------
+  // Stat polling follows the symlink swaps of mounted secrets, unlike `fs.watch`
+  private watch(opts: { interval: number }) {
+    const { caPaths = [], certPath, keyPath } = this.options;
+
+    const files = [...caPaths, certPath, keyPath].filter((file): file is string => !!file);
+    for (const file of new Set(files)) {
+      fs.watchFile(file, { persistent: false, interval: opts.interval }, (current, previous) => {
+        if (current.mtimeMs !== previous.mtimeMs || current.ino !== previous.ino) {
+          this.reload();
+        }
+      });
+      this.watched.push(file);
+    }
+  }
+}
@@ -0,0 +1,51 @@
+import { ValueOrPromise } from '@/common/types';
+import { IRequestOptions } from './base-fetcher';
+import { FetcherExchanges } from './interceptors/common';
+
+const HTTP_AUTHORIZATION = 'authorization';
+
+/**
+ * Source of the bearer token of a fetcher, read for every request so rotated tokens are picked
+ * up, e.g. a client credentials cache or `TokenRefreshInterceptor`.
+ */
+export interface ITokenProvider {
+  getToken(): ValueOrPromise<string | undefined>;
+}
+
+// --------------------------------------------------------
+export class BearerAuth {
+  /**
+   * `authorization` header of a request: its `bearerAuth` token, else the token of `provider`.
+   * Requests setting the header themselves or `bearerAuth: false` are sent as is.
+   */
+  static async authorize<RQ extends IRequestOptions>(opts: {
+    request: RQ;
+    provider?: ITokenProvider;
+  }): Promise<RQ> {
+    const { provider } = opts;
+    // Only read here, kept out of the options handed to the fetch implementations
+    const { bearerAuth, ...rest } = opts.request;
+    const request = rest as RQ;
+
+    if (bearerAuth === false) {
+      return request;
+    }
+
+    if (bearerAuth === undefined) {
+      const current = FetcherExchanges.getRequestHeader({ request, name: HTTP_AUTHORIZATION });
+      if (current || !provider) {
+        return request;
+      }
+    }
+
+    const token = bearerAuth ?? (await provider?.getToken());
+    if (!token) {
+      return request;
+    }
+
+    return FetcherExchanges.withRequestHeaders({
+      request,
+      headers: { [HTTP_AUTHORIZATION]: `Bearer ${token}` },
+    });
+  }
+}
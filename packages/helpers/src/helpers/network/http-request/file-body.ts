@@ -0,0 +1,80 @@
+import { getError } from '@/helpers/error';
+import fs from 'node:fs';
+import fsp from 'node:fs/promises';
+import path from 'node:path';
+import { Readable } from 'node:stream';
+
+// -----------------------------------------------------------------------------
+/**
+ * Request body streamed from a file on disk.
+ *
+ * The size is resolved once when the body is created, so the request carries an exact
+ * `content-length` and the file is never fully loaded in memory.
+ *
+ * @example
+ * ```typescript
+ * const body = await FileRequestBody.fromPath({ path: '/tmp/export.csv', contentType: 'text/csv' });
+ * await network.getNetworkService().put({ url, body });
+ * ```
+ */
+export class FileRequestBody {
+  readonly path: string;
+  readonly size: number;
+  readonly contentType: string;
+
+  private constructor(opts: { path: string; size: number; contentType: string }) {
+    this.path = opts.path;
+    this.size = opts.size;
+    this.contentType = opts.contentType;
+  }
+
+  static async fromPath(opts: { path: string; contentType?: string }) {
+    const filePath = path.resolve(opts.path);
+
+    let stat: fs.Stats;
+    try {
+      stat = await fsp.stat(filePath);
+    } catch (error) {
+      throw getError({
+        statusCode: 400,
+        message: `[FileRequestBody][fromPath] Unable to read file | path: ${filePath} | error: ${(error as Error).message}`,
+      });
+    }
+
+    if (!stat.isFile()) {
+      throw getError({
+        statusCode: 400,
+        message: `[FileRequestBody][fromPath] Path is not a regular file | path: ${filePath}`,
+      });
+    }
+
+    return new FileRequestBody({
+      path: filePath,
+      size: stat.size,
+      contentType: opts.contentType ?? 'application/octet-stream',
+    });
+  }
+
+  static isFileBody(body: unknown): body is FileRequestBody {
+    return body instanceof FileRequestBody;
+  }
+
+  getFileName() {
+    return path.basename(this.path);
+  }
+
+  getHeaders(): Record<string, string> {
+    return {
+      ['content-type']: this.contentType,
+      ['content-length']: `${this.size}`,
+    };
+  }
+
+  toReadable(): Readable {
+    return fs.createReadStream(this.path);
+  }
+
+  toWebStream(): ReadableStream<Uint8Array> {
+    return Readable.toWeb(this.toReadable()) as ReadableStream<Uint8Array>;
+  }
+}
@@ -1,3 +1,11 @@
 export * from './fetcher/';
 
 export * from './base-network-request.helper';
+export * from './byte-stream';
+export * from './conditional-update';
+export * from './download';
+export * from './endpoints';
+export * from './file-body';
+export * from './json-stream';
+export * from './operation-polling';
+export * from './response';
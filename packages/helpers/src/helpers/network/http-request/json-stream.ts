@@ -0,0 +1,264 @@
+import { z } from '@hono/zod-openapi';
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import { IRequestOptions } from './fetcher/base-fetcher';
+import { IFetcherTimeoutOptions } from './fetcher/timeouts';
+import { ApiResponses } from './response';
+
+export class JsonArrayStreamErrorCodes {
+  // Not an array, malformed or truncated
+  static readonly INVALID_JSON = 'JSON_ARRAY_STREAM_INVALID_JSON';
+  static readonly INVALID_ITEM = 'JSON_ARRAY_STREAM_INVALID_ITEM';
+}
+
+export interface IStreamJsonArrayOptions<T> extends IFetcherTimeoutOptions {
+  url: string;
+  // Defaults to `get`
+  method?: string;
+  params?: Record<string, any>;
+  headers?: Record<string, string>;
+  body?: any;
+  // Validates every item, the stream fails on the first invalid one
+  schema?: z.ZodType<T>;
+}
+
+const isWhitespace = (char: string) =>
+  char === ' ' || char === '\n' || char === '\r' || char === '\t';
+
+// --------------------------------------------------------
+/**
+ * Incremental reader of a top-level JSON array, written chunk by chunk. Only the text of the
+ * item being received is buffered, each item is parsed as soon as it is complete.
+ */
+export class JsonArrayParser {
+  private state: 'before' | 'items' | 'after' = 'before';
+  private depth = 0;
+  private isInString = false;
+  private isEscaped = false;
+  private buffer = '';
+  private count = 0;
+
+  /**
+   * @returns the items completed by `chunk`
+   */
+  write(chunk: string): Array<unknown> {
+    const rs: Array<unknown> = [];
+    let start = 0;
+
+    for (let i = 0; i < chunk.length; i++) {
+      const char = chunk[i];
+
+      if (this.isInString) {
+        if (this.isEscaped) {
+          this.isEscaped = false;
+        } else if (char === '\\') {
+          this.isEscaped = true;
+        } else if (char === '"') {
+          this.isInString = false;
+        }
+        continue;
+      }
+
+      if (this.state !== 'items') {
+        if (isWhitespace(char)) {
+          continue;
+        }
+
+        if (this.state === 'before' && char === '[') {
+          this.state = 'items';
+          start = i + 1;
+          continue;
+        }
+
+        throw this.getSyntaxError(`unexpected '${char}' ${this.state} the array`);
+      }
+
+      switch (char) {
+        case '"': {
+          this.isInString = true;
+          break;
+        }
+        case '[':
+        case '{': {
+          this.depth++;
+          break;
+        }
+        case '}': {
+          this.depth--;
+          break;
+        }
+        case ']': {
+          if (this.depth > 0) {
+            this.depth--;
+            break;
+          }
+
+          this.buffer += chunk.slice(start, i);
+          this.flush({ items: rs, isLast: true });
+          this.state = 'after';
+          break;
+        }
+        case ',': {
+          if (this.depth > 0) {
+            break;
+          }
+
+          this.buffer += chunk.slice(start, i);
+          this.flush({ items: rs, isLast: false });
+          start = i + 1;
+          break;
+        }
+        default: {
+          break;
+        }
+      }
+    }
+
+    if (this.state === 'items') {
+      this.buffer += chunk.slice(start);
+    }
+
+    return rs;
+  }
+
+  /**
+   * Fail unless the array was closed.
+   */
+  end() {
+    if (this.state !== 'after') {
+      throw this.getSyntaxError(`body ended after ${this.count} items`);
+    }
+  }
+
+  getCount() {
+    return this.count;
+  }
+
+  // --------------------------------------------------------
+  private flush(opts: { items: Array<unknown>; isLast: boolean }) {
+    const text = this.buffer.trim();
+    this.buffer = '';
+
+    if (!text) {
+      // `[]`, anything else is a missing item
+      if (opts.isLast && !this.count) {
+        return;
+      }
+
+      throw this.getSyntaxError(`missing item at index ${this.count}`);
+    }
+
+    try {
+      opts.items.push(JSON.parse(text));
+    } catch (error) {
+      throw this.getSyntaxError(`invalid item at index ${this.count} | ${error}`);
+    }
+
+    this.count++;
+  }
+
+  private getSyntaxError(reason: string) {
+    return getError({
+      statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+      messageCode: JsonArrayStreamErrorCodes.INVALID_JSON,
+      message: `[JsonArrayParser] Invalid JSON array | ${reason}`,
+    });
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * Items of a JSON array response read as they arrive, so large list endpoints are processed
+ * without buffering the whole body.
+ *
+ * The request is sent on the first read. Failed responses throw like `ApiResponses.ensureOk`,
+ * malformed or truncated arrays fail with `JSON_ARRAY_STREAM_INVALID_JSON` once the items before
+ * the fault were read. Leaving the loop early cancels the body.
+ *
+ * @example
+ * ```typescript
+ * const products = network.getNetworkService().streamJsonArray<TProduct>({
+ *   url: network.getRequestUrl({ paths: ['products', 'export'] }),
+ *   schema: ProductSchema,
+ * });
+ *
+ * for await (const product of products) {
+ *   await index.upsert(product);
+ * }
+ * ```
+ */
+export class JsonArrayStreams {
+  static async *read<T>(opts: { response: Response; schema?: z.ZodType<T> }): AsyncGenerator<T> {
+    const { response, schema } = opts;
+    const parser = new JsonArrayParser();
+
+    if (!response.body) {
+      parser.end();
+      return;
+    }
+
+    const reader = response.body.getReader();
+    const decoder = new TextDecoder();
+    let isDone = false;
+    let index = 0;
+
+    try {
+      while (!isDone) {
+        const { done, value } = await reader.read();
+        isDone = done;
+
+        const text = done ? decoder.decode() : decoder.decode(value, { stream: true });
+        for (const item of parser.write(text)) {
+          yield JsonArrayStreams.parseItem({ item, index: index++, schema });
+        }
+      }
+
+      parser.end();
+    } finally {
+      if (!isDone) {
+        reader.cancel().catch(() => {});
+      }
+      reader.releaseLock();
+    }
+  }
+
+  static async *stream<T>(opts: {
+    options: IStreamJsonArrayOptions<T>;
+    fetch: (request: IRequestOptions) => Promise<Response>;
+    logger?: any;
+  }): AsyncGenerator<T> {
+    const { options, fetch, logger } = opts;
+    const { schema, method = 'get', ...request } = options;
+
+    const response = await ApiResponses.ensureOk(await fetch({ ...request, method }));
+
+    let count = 0;
+    for await (const item of JsonArrayStreams.read<T>({ response, schema })) {
+      count++;
+      yield item;
+    }
+
+    logger
+      ?.for(JsonArrayStreams.stream.name)
+      .info('JSON array streamed | url: %s | items: %s', options.url, count);
+  }
+
+  // --------------------------------------------------------
+  private static parseItem<T>(opts: { item: unknown; index: number; schema?: z.ZodType<T> }): T {
+    const { item, schema } = opts;
+    if (!schema) {
+      return item as T;
+    }
+
+    const rs = schema.safeParse(item);
+    if (!rs.success) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+        messageCode: JsonArrayStreamErrorCodes.INVALID_ITEM,
+        message: `[JsonArrayStreams] Invalid item | index: ${opts.index} | error: ${rs.error.message}`,
+      });
+    }
+
+    return rs.data;
+  }
+}
@@ -0,0 +1,211 @@
+import { HTTP } from '@/common/constants';
+import { TConstValue, ValueOrPromise } from '@/common/types';
+import { getError } from '@/helpers/error';
+import { IRequestOptions } from './fetcher/base-fetcher';
+import { IFetcherTimeoutOptions } from './fetcher/timeouts';
+import { ApiResponses } from './response';
+
+export class OperationStates {
+  static readonly PENDING = 'pending';
+  static readonly SUCCEEDED = 'succeeded';
+  static readonly FAILED = 'failed';
+
+  static readonly SCHEME_SET = new Set([this.PENDING, this.SUCCEEDED, this.FAILED]);
+
+  static isValid(state: string): boolean {
+    return this.SCHEME_SET.has(state);
+  }
+}
+
+export type TOperationState = TConstValue<typeof OperationStates>;
+
+export class OperationPollingErrorCodes {
+  static readonly MISSING_STATUS_URL = 'OPERATION_MISSING_STATUS_URL';
+  static readonly FAILED = 'OPERATION_FAILED';
+  static readonly TIMEOUT = 'OPERATION_TIMEOUT';
+}
+
+export interface IPollOperationOptions<T> extends IFetcherTimeoutOptions {
+  // Status endpoint, or the `202 Accepted` response whose `Operation-Location` / `Location` header
+  // points to it
+  url?: string;
+  accepted?: Response;
+  headers?: Record<string, string>;
+  // State of a status response, defaults to its JSON `status` field, see `OperationPolling.parse`
+  parse?: (opts: { response: Response }) => ValueOrPromise<{ state: TOperationState; result?: T }>;
+  // ms between the first polls, grown by `factor` up to `maxDelay` unless `Retry-After` is set
+  initialDelay?: number;
+  maxDelay?: number;
+  factor?: number;
+  // ms before the operation is reported as timed out, defaults to 5 minutes
+  timeout?: number;
+}
+
+export interface IPollOperationResult<T> {
+  result: T;
+  polls: number;
+  // Last status response, its body already read by `parse`
+  response: Response;
+}
+
+const SUCCEEDED_STATUSES = new Set(['succeeded', 'success', 'completed', 'complete', 'done']);
+const FAILED_STATUSES = new Set(['failed', 'failure', 'error', 'canceled', 'cancelled']);
+
+// --------------------------------------------------------
+/**
+ * Wait for an asynchronous operation started with `202 Accepted` and a status url.
+ *
+ * The status endpoint is polled with an exponential backoff, a `Retry-After` of the upstream
+ * wins over it, until `parse` reports a terminal state. A failed operation raises a 502
+ * `OPERATION_FAILED` error, one still pending after `timeout` a 504 `OPERATION_TIMEOUT` error.
+ * Status endpoints redirecting to the result (`303 See Other`) complete with the result body.
+ *
+ * @example
+ * ```typescript
+ * const fetcher = network.getNetworkService();
+ * const url = network.getRequestUrl({ paths: ['exports'] });
+ * const accepted = await fetcher.post({ url, body: JSON.stringify(query) });
+ *
+ * const { result } = await fetcher.pollOperation<{ fileUrl: string }>({
+ *   accepted,
+ *   timeout: 10 * 60_000,
+ * });
+ * ```
+ */
+export class OperationPolling {
+  static readonly DEFAULT_INITIAL_DELAY = 500;
+  static readonly DEFAULT_MAX_DELAY = 10_000;
+  static readonly DEFAULT_FACTOR = 1.5;
+  static readonly DEFAULT_TIMEOUT = 5 * 60_000;
+
+  /**
+   * Default `parse`, the JSON `status` field of the body: `succeeded` / `completed` / `done`...,
+   * `failed` / `canceled`... or pending, the body is the result.
+   */
+  static async parse<T>(opts: {
+    response: Response;
+  }): Promise<{ state: TOperationState; result?: T }> {
+    const { response } = opts;
+    const body = await response.json().catch(() => undefined);
+    if (response.redirected) {
+      return { state: OperationStates.SUCCEEDED, result: body as T };
+    }
+
+    const status = `${(body as { status?: unknown } | undefined)?.status ?? ''}`.toLowerCase();
+    if (SUCCEEDED_STATUSES.has(status)) {
+      return { state: OperationStates.SUCCEEDED, result: body as T };
+    }
+
+    if (FAILED_STATUSES.has(status)) {
+      return { state: OperationStates.FAILED, result: body as T };
+    }
+
+    return { state: OperationStates.PENDING };
+  }
+
+  /**
+   * ms of a `Retry-After` header, delay seconds or an HTTP date.
+   */
+  static getRetryAfter(opts: { response: Response; now?: number }): number | undefined {
+    const { response, now = Date.now() } = opts;
+    const value = response.headers.get('retry-after')?.trim();
+    if (!value) {
+      return undefined;
+    }
+
+    if (/^\d+$/.test(value)) {
+      return Number(value) * 1_000;
+    }
+
+    const date = Date.parse(value);
+    return Number.isNaN(date) ? undefined : Math.max(date - now, 0);
+  }
+
+  static getStatusUrl(opts: { response: Response }): string | undefined {
+    const { response } = opts;
+    const location = response.headers.get('operation-location') ?? response.headers.get('location');
+    if (!location) {
+      return undefined;
+    }
+
+    return response.url ? new URL(location, response.url).toString() : location;
+  }
+
+  // --------------------------------------------------------
+  static async poll<T>(opts: {
+    options: IPollOperationOptions<T>;
+    fetch: (request: IRequestOptions) => Promise<Response>;
+    logger?: any;
+  }): Promise<IPollOperationResult<T>> {
+    const { options, fetch, logger } = opts;
+    const {
+      accepted,
+      headers,
+      parse = opts => OperationPolling.parse<T>(opts),
+      initialDelay = OperationPolling.DEFAULT_INITIAL_DELAY,
+      maxDelay = OperationPolling.DEFAULT_MAX_DELAY,
+      factor = OperationPolling.DEFAULT_FACTOR,
+      timeout = OperationPolling.DEFAULT_TIMEOUT,
+      connectTimeout,
+      readTimeout,
+      totalTimeout,
+    } = options;
+
+    const url = options.url ?? (accepted && OperationPolling.getStatusUrl({ response: accepted }));
+    if (!url) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+        messageCode: OperationPollingErrorCodes.MISSING_STATUS_URL,
+        message: '[pollOperation] No status url, set opts.url or accept a response with a Location',
+      });
+    }
+
+    const deadline = Date.now() + timeout;
+    const retryAfter = accepted && OperationPolling.getRetryAfter({ response: accepted });
+    let delay = retryAfter ?? initialDelay;
+    accepted?.body?.cancel().catch(() => {});
+
+    for (let polls = 1; ; polls++) {
+      const remaining = deadline - Date.now();
+      if (remaining <= 0) {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.GatewayTimeout,
+          messageCode: OperationPollingErrorCodes.TIMEOUT,
+          message: `[pollOperation] Operation still pending | url: ${url} | timeout: ${timeout}ms | polls: ${polls - 1}`,
+        });
+      }
+
+      await new Promise(resolve => setTimeout(resolve, Math.min(delay, remaining)));
+
+      // Never answered by a response cache, the operation would look pending forever
+      const response = await ApiResponses.ensureOk(
+        await fetch({
+          url,
+          method: 'get',
+          headers: { ...headers, ['cache-control']: 'no-cache' },
+          connectTimeout,
+          readTimeout,
+          totalTimeout,
+        }),
+      );
+      const { state, result } = await parse({ response });
+
+      if (state === OperationStates.SUCCEEDED) {
+        return { result: result as T, polls, response };
+      }
+
+      if (state === OperationStates.FAILED) {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.BadGateway,
+          messageCode: OperationPollingErrorCodes.FAILED,
+          message: `[pollOperation] Operation failed | url: ${url} | result: ${JSON.stringify(result)}`,
+        });
+      }
+
+      delay = OperationPolling.getRetryAfter({ response }) ?? Math.min(delay * factor, maxDelay);
+      logger
+        ?.for(OperationPolling.poll.name)
+        .info('Operation pending | url: %s | polls: %s | next: %sms', url, polls, delay);
+    }
+  }
+}
@@ -0,0 +1,110 @@
+import { HTTP } from '@/common/constants';
+import { AnyObject } from '@/common/types';
+import { getError } from '@/helpers/error';
+import C from 'node:crypto';
+
+export class CursorErrorCodes {
+  static readonly INVALID_CURSOR = 'INVALID_CURSOR';
+}
+
+interface ICursorStruct<T extends AnyObject = AnyObject> {
+  // Version
+  v: number;
+  // Payload
+  d: T;
+  // base64url hmac-sha256 of "<v>.<json payload>"
+  s: string;
+}
+
+// --------------------------------------------------------
+/**
+ * Encode and decode opaque pagination cursors.
+ *
+ * A cursor is the base64url of a `{ v, d, s }` struct: the format version, the payload and its
+ * signature. Clients can't read or forge them, any tampered, foreign or outdated cursor fails
+ * with a 400 `INVALID_CURSOR` error. Bump `version` when the payload shape changes so cursors
+ * issued before the change are rejected instead of misread.
+ *
+ * @example
+ * ```typescript
+ * const cursors = new PaginationCursors({ secret: env.CURSOR_SECRET });
+ *
+ * // server, next page starts after the last row
+ * const last = data[data.length - 1];
+ * const nextCursor = cursors.encode({ payload: { id: last.id, createdAt: last.createdAt } });
+ * return context.json(ApiResponses.paginated({ data, total, limit, nextCursor }));
+ *
+ * // next request
+ * const { id, createdAt } = cursors.decode<{ id: string; createdAt: string }>({ cursor });
+ * ```
+ */
+export class PaginationCursors {
+  private secret: string;
+  private version: number;
+
+  constructor(opts: { secret?: string; version?: number } = {}) {
+    const secret = opts.secret ?? process.env.APP_ENV_CURSOR_SECRET;
+
+    if (!secret) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: '[PaginationCursors] Invalid secret | Set opts.secret or APP_ENV_CURSOR_SECRET',
+      });
+    }
+
+    this.secret = secret;
+    this.version = opts.version ?? 1;
+  }
+
+  private sign(opts: { version: number; json: string }) {
+    return C.createHmac('sha256', this.secret)
+      .update(`${opts.version}.${opts.json}`)
+      .digest('base64url');
+  }
+
+  private invalid(reason: string) {
+    return getError({
+      statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+      messageCode: CursorErrorCodes.INVALID_CURSOR,
+      message: `[PaginationCursors] Invalid cursor | ${reason}`,
+    });
+  }
+
+  // --------------------------------------------------------
+  encode<T extends AnyObject>(opts: { payload: T }): string {
+    const struct: ICursorStruct<T> = {
+      v: this.version,
+      d: opts.payload,
+      s: this.sign({ version: this.version, json: JSON.stringify(opts.payload) }),
+    };
+
+    return Buffer.from(JSON.stringify(struct)).toString('base64url');
+  }
+
+  decode<T extends AnyObject>(opts: { cursor: string }): T {
+    let struct: Partial<ICursorStruct<T>>;
+
+    try {
+      struct = JSON.parse(Buffer.from(opts.cursor, 'base64url').toString('utf-8'));
+    } catch {
+      throw this.invalid('malformed');
+    }
+
+    if (!struct || typeof struct !== 'object' || typeof struct.s !== 'string') {
+      throw this.invalid('malformed');
+    }
+
+    if (struct.v !== this.version) {
+      throw this.invalid(`unsupported version ${struct.v}`);
+    }
+
+    const expected = Buffer.from(this.sign({ version: struct.v, json: JSON.stringify(struct.d) }));
+    const actual = Buffer.from(struct.s);
+
+    if (expected.length !== actual.length || !C.timingSafeEqual(expected, actual)) {
+      throw this.invalid('signature mismatch');
+    }
+
+    return struct.d as T;
+  }
+}
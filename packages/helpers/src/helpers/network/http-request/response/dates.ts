@@ -0,0 +1,125 @@
+import { z } from '@hono/zod-openapi';
+import { TConstValue } from '@/common/types';
+import { dayjs } from '@/utilities/date.utility';
+
+export class ResponseDateFormats {
+  // `2026-01-31T10:00:00.000Z`, offsets included
+  static readonly ISO = 'iso';
+  // `1769853600000`, as a number or a numeric string
+  static readonly EPOCH_MILLIS = 'epoch_millis';
+  // `1769853600`
+  static readonly EPOCH_SECONDS = 'epoch_seconds';
+  // `2026-01-31 10:00:00`, wall clock time of `timezone`
+  static readonly SQL_DATETIME = 'sql_datetime';
+  // `Sat, 31 Jan 2026 10:00:00 +0000`, e.g. feeds and mail headers
+  static readonly RFC2822 = 'rfc2822';
+
+  static readonly SCHEME_SET = new Set([
+    this.ISO,
+    this.EPOCH_MILLIS,
+    this.EPOCH_SECONDS,
+    this.SQL_DATETIME,
+    this.RFC2822,
+  ]);
+
+  static isValid(format: string): boolean {
+    return this.SCHEME_SET.has(format);
+  }
+}
+
+export type TResponseDateFormat = TConstValue<typeof ResponseDateFormats>;
+
+export interface IResponseDateOptions {
+  // Defaults to `iso`
+  format?: TResponseDateFormat;
+  // Zone of dates without offset (`sql_datetime`), defaults to `UTC`
+  timezone?: string;
+}
+
+const SQL_DATETIME_PATTERN = /^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}(\.\d{1,3})?$/;
+const RFC2822_PATTERN = /^([A-Za-z]{3}, )?\d{1,2} [A-Za-z]{3} \d{4} \d{2}:\d{2}(:\d{2})? /;
+const NUMERIC_PATTERN = /^-?\d+(\.\d+)?$/;
+
+// --------------------------------------------------------
+/**
+ * Dates of upstream payloads in the formats JSON leaves open, read into `Date` instances.
+ *
+ * @example
+ * ```typescript
+ * const OrderSchema = z.object({
+ *   id: z.string(),
+ *   paidAt: responseDateSchema({ format: ResponseDateFormats.EPOCH_MILLIS }),
+ *   shippedAt: responseDateSchema({ format: ResponseDateFormats.SQL_DATETIME }).nullable(),
+ * });
+ *
+ * const order = OrderSchema.parse(await response.json());
+ * ```
+ */
+export class ResponseDates {
+  /**
+   * @returns `undefined` when `value` is not a date of `format`
+   */
+  static parse(opts: { value: unknown } & IResponseDateOptions): Date | undefined {
+    const { value, format = ResponseDateFormats.ISO, timezone = 'UTC' } = opts;
+
+    let rs: Date | undefined;
+    switch (format) {
+      case ResponseDateFormats.EPOCH_MILLIS:
+      case ResponseDateFormats.EPOCH_SECONDS: {
+        const isNumeric =
+          typeof value === 'number' || (typeof value === 'string' && NUMERIC_PATTERN.test(value));
+        if (!isNumeric) {
+          break;
+        }
+
+        const unit = format === ResponseDateFormats.EPOCH_SECONDS ? 1_000 : 1;
+        rs = new Date(Number(value) * unit);
+        break;
+      }
+      case ResponseDateFormats.SQL_DATETIME: {
+        if (typeof value !== 'string' || !SQL_DATETIME_PATTERN.test(value)) {
+          break;
+        }
+
+        const pattern = value.includes('.') ? 'YYYY-MM-DD HH:mm:ss.SSS' : 'YYYY-MM-DD HH:mm:ss';
+        rs = dayjs.tz(value, pattern, timezone).toDate();
+        break;
+      }
+      case ResponseDateFormats.RFC2822: {
+        if (typeof value !== 'string' || !RFC2822_PATTERN.test(value)) {
+          break;
+        }
+
+        rs = new Date(value);
+        break;
+      }
+      default: {
+        if (typeof value !== 'string' || !dayjs(value).isValid()) {
+          break;
+        }
+
+        rs = new Date(value);
+        break;
+      }
+    }
+
+    return rs && !Number.isNaN(rs.getTime()) ? rs : undefined;
+  }
+}
+
+/**
+ * Schema of a date field sent in `format`, parsed into a `Date`.
+ */
+export const responseDateSchema = (opts: IResponseDateOptions = {}) => {
+  const { format = ResponseDateFormats.ISO } = opts;
+
+  return z.union([z.string(), z.number()]).transform((value, ctx) => {
+    const rs = ResponseDates.parse({ value, ...opts });
+    if (!rs) {
+      ctx.addIssue({ code: 'custom', message: `Invalid date | format: ${format}` });
+      return z.NEVER;
+    }
+
+    return rs;
+  });
+};
@@ -0,0 +1,36 @@
+import { AnyObject } from '@/common/types';
+import { ApplicationError, TError } from '@/helpers/error';
+
+export class ResponseErrorCodes {
+  static readonly REQUEST_FAILED = 'REQUEST_FAILED';
+}
+
+// --------------------------------------------------------
+/**
+ * `ApplicationError` built from a failed upstream response, keeping what the upstream sent.
+ */
+export class HttpResponseError extends ApplicationError {
+  url?: string;
+  // Parsed JSON body, or the text of non JSON bodies
+  body?: unknown;
+  // Request id reported by the upstream, useful to correlate with its logs
+  requestId?: string;
+  details?: AnyObject;
+
+  constructor(
+    opts: TError & { url?: string; body?: unknown; requestId?: string; details?: AnyObject },
+  ) {
+    const { url, body, requestId, details, ...rest } = opts;
+    super(rest);
+
+    this.name = 'HttpResponseError';
+    this.url = url;
+    this.body = body;
+    this.requestId = requestId;
+    this.details = details;
+  }
+
+  static isHttpResponseError(error: unknown): error is HttpResponseError {
+    return error instanceof HttpResponseError;
+  }
+}
@@ -0,0 +1,235 @@
+import { AnyObject } from '@/common/types';
+import { RequestContextStorage } from '@/helpers/request-context';
+import { HttpResponseError, ResponseErrorCodes } from './error';
+import {
+  IApiResponse,
+  IAxiosLikeResponse,
+  IErrorBodyDecoder,
+  IErrorBodyFields,
+  IPaginatedResponse,
+  IPaginationMeta,
+  IResponseMeta,
+  TErrorMapper,
+} from './types';
+
+const isObject = (value: unknown): value is AnyObject => {
+  return typeof value === 'object' && value !== null && !Array.isArray(value);
+};
+
+// --------------------------------------------------------
+/**
+ * Build and read the standard `{ data, meta }` response envelope.
+ *
+ * Servers wrap handler results with `ok` / `paginated`, clients read them back with `unwrap`,
+ * so both sides share one definition.
+ *
+ * @example
+ * ```typescript
+ * // server
+ * const { data, range } = await repository.find({ filter, options: { shouldQueryRange: true } });
+ * return context.json(ApiResponses.paginated({ data, total: range.total, limit, offset }));
+ *
+ * // client
+ * const response = await fetcher.get({ url });
+ * const users = ApiResponses.unwrap<Array<IUser>>(await response.json());
+ * ```
+ */
+export class ApiResponses {
+  private static getMeta<TMeta extends IResponseMeta>(meta?: TMeta): TMeta {
+    return {
+      requestId: RequestContextStorage.get()?.requestId,
+      timestamp: new Date().toISOString(),
+      ...meta,
+    } as TMeta;
+  }
+
+  static ok<T, TMeta extends IResponseMeta = IResponseMeta>(opts: {
+    data: T;
+    meta?: TMeta;
+  }): IApiResponse<T, TMeta> {
+    return { data: opts.data, meta: ApiResponses.getMeta(opts.meta) };
+  }
+
+  static getPagination(opts: {
+    count: number;
+    total: number;
+    limit: number;
+    offset?: number;
+    nextCursor?: string;
+  }) {
+    const { count, total, limit, offset = 0, nextCursor } = opts;
+
+    const rs: IPaginationMeta = {
+      total,
+      limit,
+      offset,
+      start: offset,
+      end: count > 0 ? offset + count - 1 : offset,
+      hasMore: offset + count < total,
+    };
+
+    if (nextCursor) {
+      rs.nextCursor = nextCursor;
+    }
+    return rs;
+  }
+
+  static paginated<T>(opts: {
+    data: Array<T>;
+    total: number;
+    limit: number;
+    offset?: number;
+    nextCursor?: string;
+    meta?: IResponseMeta;
+  }): IPaginatedResponse<T> {
+    const { data, total, limit, offset, nextCursor, meta } = opts;
+
+    return {
+      data,
+      meta: ApiResponses.getMeta({
+        ...meta,
+        pagination: ApiResponses.getPagination({
+          count: data.length,
+          total,
+          limit,
+          offset,
+          nextCursor,
+        }),
+      }),
+    };
+  }
+
+  // --------------------------------------------------------
+  static isApiResponse<T = unknown>(value: unknown): value is IApiResponse<T> {
+    return (
+      typeof value === 'object' &&
+      value !== null &&
+      !Array.isArray(value) &&
+      'data' in value &&
+      Object.keys(value).every(key => key === 'data' || key === 'meta')
+    );
+  }
+
+  /**
+   * Payload of an envelope, values that are not enveloped are returned as is.
+   */
+  static unwrap<T = unknown>(value: unknown): T {
+    return (ApiResponses.isApiResponse<T>(value) ? value.data : value) as T;
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Error for a failed response body, read from the standard error envelope
+   * (`{ message, statusCode, messageCode, requestId, details }`) or an RFC 9457
+   * `application/problem+json` document. Bodies matching the schema of `decoder` are mapped
+   * by it instead.
+   */
+  static toError(opts: {
+    status: number;
+    body?: unknown;
+    url?: string;
+    decoder?: IErrorBodyDecoder;
+  }): HttpResponseError {
+    const { status, body, url, decoder } = opts;
+    const fallback = `[toError] Request failed | status: ${status}${url ? ` | url: ${url}` : ''}`;
+
+    const decoded = decoder?.schema.safeParse(body);
+    if (decoder && decoded?.success) {
+      const fields: IErrorBodyFields = decoder.map
+        ? decoder.map({ body: decoded.data, status, url })
+        : (decoded.data ?? {});
+
+      return new HttpResponseError({
+        statusCode: fields.statusCode ?? status,
+        messageCode: fields.messageCode ?? ResponseErrorCodes.REQUEST_FAILED,
+        message: fields.message || fallback,
+        url,
+        body,
+        requestId: fields.requestId,
+        details: fields.details,
+      });
+    }
+
+    if (!isObject(body)) {
+      return new HttpResponseError({
+        statusCode: status,
+        messageCode: ResponseErrorCodes.REQUEST_FAILED,
+        message: typeof body === 'string' && body ? body : fallback,
+        url,
+        body,
+      });
+    }
+
+    // Problem details, `type` is a uri identifying the problem, `about:blank` means none
+    if ('title' in body || 'detail' in body) {
+      const { type, title, detail, instance, code, ...extensions } = body;
+
+      return new HttpResponseError({
+        statusCode: status,
+        messageCode: code ?? (type && type !== 'about:blank' ? type : undefined),
+        message: detail ?? title ?? fallback,
+        url,
+        body,
+        details: { ...extensions, instance },
+      });
+    }
+
+    const { message, messageCode, requestId, details } = body;
+    return new HttpResponseError({
+      statusCode: status,
+      messageCode: messageCode ?? ResponseErrorCodes.REQUEST_FAILED,
+      message: typeof message === 'string' && message ? message : fallback,
+      url,
+      body,
+      requestId,
+      details,
+    });
+  }
+
+  /**
+   * Return `response` when its status is 2xx, throw an `HttpResponseError` parsed from its body
+   * otherwise. Works with the responses of both the fetch and the axios fetchers, `decoder`
+   * reads the error body type of the upstream, see `toError`, and `mapper` translates the error
+   * into one of the domain, see `ErrorMappers`.
+   *
+   * @example
+   * ```typescript
+   * const response = await ApiResponses.ensureOk(await network.getNetworkService().get({ url }));
+   * const order = ApiResponses.unwrap<TOrder>(await response.json());
+   * ```
+   */
+  static async ensureOk<R extends Response | IAxiosLikeResponse>(
+    response: R,
+    opts: { decoder?: IErrorBodyDecoder; mapper?: TErrorMapper } = {},
+  ): Promise<R> {
+    const { decoder, mapper } = opts;
+    if (response.status >= 200 && response.status < 300) {
+      return response;
+    }
+
+    let error: HttpResponseError;
+    if (response instanceof Response) {
+      const text = await response.text().catch(() => '');
+
+      let body: unknown = text;
+      if (text && response.headers.get('content-type')?.includes('json')) {
+        try {
+          body = JSON.parse(text);
+        } catch {
+          body = text;
+        }
+      }
+
+      error = ApiResponses.toError({ status: response.status, body, url: response.url, decoder });
+    } else {
+      error = ApiResponses.toError({
+        status: response.status,
+        body: response.data,
+        url: response.config?.url,
+        decoder,
+      });
+    }
+
+    throw mapper?.({ error, status: response.status }) ?? error;
+  }
+}
@@ -0,0 +1,7 @@
+export * from './cursor';
+export * from './dates';
+export * from './error';
+export * from './helper';
+export * from './mapping';
+export * from './schemas';
+export * from './types';
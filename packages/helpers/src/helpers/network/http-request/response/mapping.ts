@@ -0,0 +1,74 @@
+import { ApplicationError } from '@/helpers/error';
+import { HttpResponseError } from './error';
+import { IErrorBodyFields, TErrorMapper } from './types';
+
+// Fields replacing the ones of the upstream error, or a factory of the domain error
+export type TErrorMapping =
+  | IErrorBodyFields
+  | ((opts: { error: HttpResponseError; status: number }) => ApplicationError | undefined);
+
+// --------------------------------------------------------
+/**
+ * Builders of `TErrorMapper`, the translation rules of an upstream kept in one place.
+ *
+ * @example
+ * ```typescript
+ * const catalog = new NodeFetchNetworkRequest({
+ *   name: 'CatalogRequest',
+ *   networkOptions: { baseUrl: 'https://catalog.partner.com' },
+ *   errorMapper: ErrorMappers.byStatus({
+ *     404: { statusCode: 404, messageCode: 'ITEM_NOT_FOUND' },
+ *     // Our credentials are wrong, not the ones of our caller
+ *     401: { statusCode: 502, messageCode: 'CATALOG_UNAVAILABLE' },
+ *     403: { statusCode: 502, messageCode: 'CATALOG_UNAVAILABLE' },
+ *   }),
+ * });
+ *
+ * // throws ITEM_NOT_FOUND (404) for an upstream 404
+ * await catalog.ensureOk(await catalog.getNetworkService().get({ url }));
+ * ```
+ */
+export class ErrorMappers {
+  /**
+   * Mapping per upstream status, `5xx` style keys match a whole class. Field mappings keep the
+   * url, body and request id of the upstream error, the message too unless it is replaced.
+   */
+  static byStatus(rules: Record<number | string, TErrorMapping>): TErrorMapper {
+    return ({ error, status }) => {
+      const mapping = rules[status] ?? rules[`${Math.floor(status / 100)}xx`];
+      if (!mapping) {
+        return undefined;
+      }
+
+      if (typeof mapping === 'function') {
+        return mapping({ error, status });
+      }
+
+      return new HttpResponseError({
+        statusCode: mapping.statusCode ?? error.statusCode,
+        messageCode: mapping.messageCode ?? error.messageCode,
+        message: mapping.message ?? error.message,
+        url: error.url,
+        body: error.body,
+        requestId: mapping.requestId ?? error.requestId,
+        details: { ...error.details, ...mapping.details, upstreamStatus: status },
+      });
+    };
+  }
+
+  /**
+   * First mapper returning an error wins.
+   */
+  static chain(...mappers: Array<TErrorMapper>): TErrorMapper {
+    return opts => {
+      for (const mapper of mappers) {
+        const rs = mapper(opts);
+        if (rs) {
+          return rs;
+        }
+      }
+
+      return undefined;
+    };
+  }
+}
@@ -0,0 +1,33 @@
+import { z } from '@hono/zod-openapi';
+
+export const PaginationMetaSchema = z
+  .object({
+    total: z.number().int(),
+    limit: z.number().int(),
+    offset: z.number().int(),
+    start: z.number().int(),
+    end: z.number().int(),
+    hasMore: z.boolean(),
+    nextCursor: z.string().optional(),
+  })
+  .openapi('PaginationMeta');
+
+export const ResponseMetaSchema = z
+  .object({
+    requestId: z.string().optional(),
+    timestamp: z.string().optional(),
+    pagination: PaginationMetaSchema.optional(),
+  })
+  .catchall(z.any())
+  .openapi('ResponseMeta');
+
+export const apiResponseSchema = <T extends z.ZodType>(opts: { schema: T }) => {
+  return z.object({ data: opts.schema, meta: ResponseMetaSchema.optional() });
+};
+
+export const paginatedResponseSchema = <T extends z.ZodType>(opts: { schema: T }) => {
+  return z.object({
+    data: z.array(opts.schema),
+    meta: ResponseMetaSchema.extend({ pagination: PaginationMetaSchema }),
+  });
+};
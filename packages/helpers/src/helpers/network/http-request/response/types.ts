@@ -0,0 +1,70 @@
+import { AnyObject } from '@/common/types';
+import { ApplicationError } from '@/helpers/error';
+import { z } from '@hono/zod-openapi';
+import type { HttpResponseError } from './error';
+
+export interface IPaginationMeta {
+  total: number;
+  limit: number;
+  offset: number;
+  // 0-based, inclusive, same as the `Content-Range` header
+  start: number;
+  end: number;
+  hasMore: boolean;
+  // Opaque cursor of the next page, see `PaginationCursors`
+  nextCursor?: string;
+}
+
+export interface IResponseMeta extends AnyObject {
+  requestId?: string;
+  // ISO 8601
+  timestamp?: string;
+  pagination?: IPaginationMeta;
+}
+
+export interface IApiResponse<T = unknown, TMeta extends IResponseMeta = IResponseMeta> {
+  data: T;
+  meta?: TMeta;
+}
+
+export interface IPaginatedResponse<T = unknown>
+  extends IApiResponse<Array<T>, IResponseMeta & { pagination: IPaginationMeta }> {}
+
+/**
+ * Fields of an axios response read by `ApiResponses.ensureOk`, without depending on axios.
+ */
+export interface IAxiosLikeResponse {
+  status: number;
+  data?: unknown;
+  config?: { url?: string };
+}
+
+// --------------------------------------------------------
+// Fields of the `HttpResponseError` taken from an upstream error body
+export interface IErrorBodyFields {
+  statusCode?: number;
+  messageCode?: string;
+  message?: string;
+  requestId?: string;
+  details?: AnyObject;
+}
+
+/**
+ * Error body type of an upstream. Bodies matching `schema` are mapped into the error fields,
+ * others are read as the standard error envelope or problem details.
+ */
+export interface IErrorBodyDecoder<E = any> {
+  schema: z.ZodType<E>;
+  // Defaults to the fields of the body named like the error fields
+  map?: (opts: { body: E; status: number; url?: string }) => IErrorBodyFields;
+}
+
+/**
+ * Translation of upstream failures into the errors of the domain, run by `ensureOk` once the
+ * body is decoded. Returning `undefined` keeps `error`.
+ */
+export type TErrorMapper = (opts: {
+  error: HttpResponseError;
+  // Status sent by the upstream, `error.statusCode` may have been changed by the decoder
+  status: number;
+}) => ApplicationError | undefined;
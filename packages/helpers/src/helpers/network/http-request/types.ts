@@ -1,3 +1,4 @@
+import type { ApplicationError } from '@/helpers/error';
 import type { AxiosInstance, AxiosResponse } from 'axios';
 
 export type TFetcherVariant = 'node-fetch' | 'axios';
@@ -8,3 +9,10 @@ export type TFetcherResponse<T extends TFetcherVariant> = T extends 'node-fetch'
 export type TFetcherWorker<T extends TFetcherVariant> = T extends 'axios'
   ? AxiosInstance
   : typeof fetch;
+
+/**
+ * Outcome of one request of `sendBatch`, `index` is the position of the request in the input.
+ */
+export type TBatchResult<R> =
+  | { index: number; ok: true; value: R }
+  | { index: number; ok: false; error: ApplicationError };
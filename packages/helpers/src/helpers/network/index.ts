@@ -1,3 +1,7 @@
+export * from './discovery';
+export * from './graphql';
 export * from './http-request';
+export * from './offline-queue';
+export * from './openapi';
 export * from './tcp-socket';
 export * from './udp-socket';
@@ -0,0 +1,43 @@
+// --------------------------------------------------------
+export class OfflineRequestStatuses {
+  // Waiting to be replayed, in enqueue order
+  static readonly QUEUED = '000_QUEUED';
+  // Set aside after a conflict or a failed replay, left for manual resolution
+  static readonly PARKED = '400_PARKED';
+
+  static readonly SCHEME_SET = new Set([this.QUEUED, this.PARKED]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
+
+// --------------------------------------------------------
+export class OfflineConflictActions {
+  // Keep the request at the head of the queue and replay it on the next flush
+  static readonly RETRY = 'retry';
+  // Remove the request, e.g. the upstream already has a newer state
+  static readonly DROP = 'drop';
+  // Set the request aside and replay the next ones
+  static readonly PARK = 'park';
+
+  static readonly SCHEME_SET = new Set([this.RETRY, this.DROP, this.PARK]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
+
+// --------------------------------------------------------
+export class OfflineQueueDefaults {
+  static readonly METHODS = ['post', 'put', 'patch', 'delete'];
+  // Milliseconds between two flushes of a started queue
+  static readonly REPLAY_INTERVAL = 5_000;
+  static readonly CONFLICT_STATUS_CODES = [409, 412, 422];
+  // Statuses meaning the upstream is unreachable, e.g. from a gateway in front of it
+  static readonly UNAVAILABLE_STATUS_CODES = [502, 503, 504];
+}
+
+export class OfflineQueueErrorCodes {
+  static readonly UNSUPPORTED_BODY = 'OFFLINE_QUEUE_UNSUPPORTED_BODY';
+}
@@ -0,0 +1,352 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { SensitiveFields } from '@/helpers/logger/redaction';
+import C from 'node:crypto';
+import {
+  FetcherExchanges,
+  FetcherRetryDefaults,
+  HttpHeaders,
+  IRequestOptions,
+  THeadersInput,
+} from '../http-request';
+import {
+  OfflineConflictActions,
+  OfflineQueueDefaults,
+  OfflineQueueErrorCodes,
+  OfflineRequestStatuses,
+} from './constants';
+import { MemoryOfflineQueueStore } from './stores';
+import {
+  IOfflineQueueOptions,
+  IOfflineQueueStore,
+  IOfflineRequest,
+  TOfflineRequestStatus,
+  TOfflineSendResult,
+} from './types';
+
+const HTTP_CONTENT_TYPE = 'content-type';
+
+const isPlainBody = (body: object) =>
+  [Object.prototype, Array.prototype, null].includes(Object.getPrototypeOf(body));
+
+// --------------------------------------------------------
+/**
+ * Store-and-forward queue of mutating requests, for edge deployments whose upstream is not
+ * always reachable.
+ *
+ * A mutating request which can not reach the upstream is stored and replayed in enqueue order
+ * once connectivity returns, requests sent while others wait are queued behind them. Every
+ * request carries its id as idempotency key, a replay whose first attempt did reach the
+ * upstream is not applied twice. Conflicting replays (409, 412 and 422 by default) are handed
+ * to `onConflict`, parked when it is not set.
+ *
+ * Credential headers, `authorization`, `cookie`, `x-api-key` and the like, are never stored,
+ * replays are authorized again by the `tokenProvider` or `bearerAuth` of the fetcher.
+ *
+ * @example
+ * ```typescript
+ * const queue = new OfflineRequestQueue({
+ *   identifier: 'pos-sales',
+ *   fetcher: salesRequest.getNetworkService(),
+ *   store: new SqliteOfflineQueueStore({ path: '/var/lib/pos/offline.db' }),
+ *   onConflict: ({ response }) => (response.status === 409 ? 'drop' : 'park'),
+ * });
+ * queue.start();
+ *
+ * const url = salesRequest.getRequestUrl({ paths: ['sales'] });
+ * const rs = await queue.send({ url, method: 'post', body: sale });
+ * if (rs.queued) {
+ *   printReceipt({ sale, pending: true });
+ * }
+ * ```
+ */
+export class OfflineRequestQueue<RS extends { status: number } = Response> extends BaseHelper {
+  private fetcher: IOfflineQueueOptions<RS>['fetcher'];
+  private store: IOfflineQueueStore;
+  private methods: Set<string>;
+  private replayInterval: number;
+  private conflictStatusCodes: Array<number>;
+  private unavailableStatusCodes: Array<number>;
+  private isUnreachable: (error: unknown) => boolean;
+  private idempotencyKeyHeader: string | false;
+  private sensitiveHeaders: Set<string>;
+  private onConflict?: IOfflineQueueOptions<RS>['onConflict'];
+  private onReplayed?: IOfflineQueueOptions<RS>['onReplayed'];
+
+  private timer?: ReturnType<typeof setInterval>;
+  private flushing?: Promise<number>;
+
+  constructor(opts: IOfflineQueueOptions<RS>) {
+    super({ scope: `${OfflineRequestQueue.name}_${opts.identifier}`, identifier: opts.identifier });
+
+    const {
+      fetcher,
+      store = new MemoryOfflineQueueStore(),
+      methods = OfflineQueueDefaults.METHODS,
+      replayInterval = OfflineQueueDefaults.REPLAY_INTERVAL,
+      conflictStatusCodes = OfflineQueueDefaults.CONFLICT_STATUS_CODES,
+      unavailableStatusCodes = OfflineQueueDefaults.UNAVAILABLE_STATUS_CODES,
+      isUnreachable = error => !(error instanceof Error && error.name === 'AbortError'),
+      idempotencyKeyHeader = FetcherRetryDefaults.IDEMPOTENCY_KEY_HEADER,
+      sensitiveHeaders = SensitiveFields.DEFAULTS,
+      onConflict,
+      onReplayed,
+    } = opts;
+
+    this.fetcher = fetcher;
+    this.store = store;
+    this.methods = new Set(methods.map(method => method.toLowerCase()));
+    this.replayInterval = replayInterval;
+    this.conflictStatusCodes = conflictStatusCodes;
+    this.unavailableStatusCodes = unavailableStatusCodes;
+    this.isUnreachable = isUnreachable;
+    this.idempotencyKeyHeader = idempotencyKeyHeader;
+    this.sensitiveHeaders = SensitiveFields.toSet(sensitiveHeaders);
+    this.onConflict = onConflict;
+    this.onReplayed = onReplayed;
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Flush the queue now then every `replayInterval`.
+   */
+  start() {
+    if (this.timer) {
+      return;
+    }
+
+    this.flush().catch(() => {});
+    this.timer = setInterval(() => {
+      this.flush().catch(() => {});
+    }, this.replayInterval);
+    this.timer.unref?.();
+  }
+
+  stop() {
+    clearInterval(this.timer);
+    this.timer = undefined;
+  }
+
+  /**
+   * Send a request, mutating requests are queued when the upstream is unreachable or when
+   * earlier requests are still queued.
+   */
+  async send(opts: IRequestOptions): Promise<TOfflineSendResult<RS>> {
+    if (!this.methods.has((opts.method ?? 'get').toLowerCase())) {
+      return { queued: false, response: await this.fetcher.send(opts) };
+    }
+
+    // Serialized first, a body which can not be stored fails before anything is sent
+    const request = this.toOfflineRequest(opts);
+    const queued = await this.store.count({ status: OfflineRequestStatuses.QUEUED });
+    if (queued > 0) {
+      return { queued: true, request: await this.enqueue(request) };
+    }
+
+    try {
+      const response = await this.fetcher.send(
+        FetcherExchanges.withRequestHeaders({ request: opts, headers: this.getKeyHeader(request) }),
+      );
+
+      if (!this.unavailableStatusCodes.includes(response.status)) {
+        return { queued: false, response };
+      }
+
+      request.lastStatusCode = response.status;
+      FetcherExchanges.discard(response);
+    } catch (error) {
+      if (!this.isUnreachable(error)) {
+        throw error;
+      }
+
+      request.lastError = String(error);
+    }
+
+    return { queued: true, request: await this.enqueue(request) };
+  }
+
+  /**
+   * Replay the queued requests in order until the queue is empty or the upstream is unreachable
+   * again, joining the flush already running if any.
+   *
+   * @returns the number of replayed requests
+   */
+  flush(): Promise<number> {
+    if (!this.flushing) {
+      this.flushing = this.replay().finally(() => {
+        this.flushing = undefined;
+      });
+    }
+
+    return this.flushing;
+  }
+
+  // --------------------------------------------------------
+  getRequests(opts: { status?: TOfflineRequestStatus; limit?: number } = {}) {
+    const { status = OfflineRequestStatuses.QUEUED, limit } = opts;
+    return this.store.list({ status, limit });
+  }
+
+  async size() {
+    return this.store.count({ status: OfflineRequestStatuses.QUEUED });
+  }
+
+  /**
+   * Queue a parked request again, behind the requests already queued.
+   */
+  async requeue(opts: { id: string }) {
+    const parked = await this.store.list({ status: OfflineRequestStatuses.PARKED });
+    const request = parked.find(el => el.id === opts.id);
+    if (!request) {
+      return false;
+    }
+
+    await this.store.remove({ id: request.id });
+    await this.store.append({ request: { ...request, status: OfflineRequestStatuses.QUEUED } });
+    return true;
+  }
+
+  async remove(opts: { id: string }) {
+    await this.store.remove(opts);
+  }
+
+  // --------------------------------------------------------
+  private async enqueue(request: IOfflineRequest) {
+    await this.store.append({ request });
+
+    this.logger
+      .for(this.enqueue.name)
+      .info(
+        'Request queued | id: %s | method: %s | url: %s',
+        request.id,
+        request.method,
+        request.url,
+      );
+    return request;
+  }
+
+  private async replay(): Promise<number> {
+    let replayed = 0;
+
+    while (true) {
+      const [request] = await this.store.list({ status: OfflineRequestStatuses.QUEUED, limit: 1 });
+      if (!request) {
+        return replayed;
+      }
+
+      request.attempts++;
+
+      let response: RS;
+      try {
+        response = await this.fetcher.send(this.toRequestOptions(request));
+      } catch (error) {
+        request.lastError = String(error);
+
+        // Any other error would block the queue forever
+        if (!this.isUnreachable(error)) {
+          request.status = OfflineRequestStatuses.PARKED;
+          await this.store.save({ request });
+          continue;
+        }
+
+        await this.store.save({ request });
+        return replayed;
+      }
+
+      request.lastStatusCode = response.status;
+      if (this.unavailableStatusCodes.includes(response.status)) {
+        FetcherExchanges.discard(response);
+        await this.store.save({ request });
+        return replayed;
+      }
+
+      if (this.conflictStatusCodes.includes(response.status)) {
+        const action =
+          (await this.onConflict?.({ request, response })) ?? OfflineConflictActions.PARK;
+        this.logger
+          .for(this.replay.name)
+          .warn(
+            'Replay conflict | id: %s | url: %s | status: %s | action: %s',
+            request.id,
+            request.url,
+            response.status,
+            action,
+          );
+
+        if (action === OfflineConflictActions.RETRY) {
+          await this.store.save({ request });
+          return replayed;
+        }
+
+        if (action === OfflineConflictActions.DROP) {
+          await this.store.remove({ id: request.id });
+          continue;
+        }
+
+        request.status = OfflineRequestStatuses.PARKED;
+        await this.store.save({ request });
+        continue;
+      }
+
+      await this.store.remove({ id: request.id });
+      await this.onReplayed?.({ request, response });
+      replayed++;
+    }
+  }
+
+  private getKeyHeader(request: IOfflineRequest): Record<string, string> {
+    return this.idempotencyKeyHeader ? { [this.idempotencyKeyHeader]: request.id } : {};
+  }
+
+  private toOfflineRequest(opts: IRequestOptions): IOfflineRequest {
+    const { url, method = 'get', params, headers, body } = opts;
+
+    const rs: IOfflineRequest = {
+      id: C.randomUUID(),
+      method: method.toLowerCase(),
+      url,
+      params,
+      headers: {},
+      status: OfflineRequestStatuses.QUEUED,
+      attempts: 0,
+      enqueuedAt: Date.now(),
+    };
+
+    for (const [name, values] of HttpHeaders.normalize(headers as THeadersInput)) {
+      if (this.sensitiveHeaders.has(SensitiveFields.normalize(name))) {
+        continue;
+      }
+
+      rs.headers[name] = HttpHeaders.join({ name, values });
+    }
+
+    if (body === undefined || body === null || typeof body === 'string') {
+      rs.body = body ?? undefined;
+      return rs;
+    }
+
+    if (body instanceof URLSearchParams) {
+      rs.body = body.toString();
+      rs.headers[HTTP_CONTENT_TYPE] ??= 'application/x-www-form-urlencoded';
+      return rs;
+    }
+
+    if (typeof body === 'object' && isPlainBody(body)) {
+      rs.body = JSON.stringify(body);
+      rs.headers[HTTP_CONTENT_TYPE] ??= 'application/json; charset=utf-8';
+      return rs;
+    }
+
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+      messageCode: OfflineQueueErrorCodes.UNSUPPORTED_BODY,
+      message: `[OfflineRequestQueue] Body can not be queued, use a string, URLSearchParams or a plain object | url: ${url}`,
+    });
+  }
+
+  private toRequestOptions(request: IOfflineRequest): IRequestOptions {
+    const { url, method, params, headers, body } = request;
+    return { url, method, params, body, headers: { ...headers, ...this.getKeyHeader(request) } };
+  }
+}
@@ -0,0 +1,4 @@
+export * from './constants';
+export * from './helper';
+export * from './stores';
+export * from './types';
@@ -0,0 +1,83 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import { Database } from 'bun:sqlite';
+import { IOfflineQueueStore, IOfflineRequest, TOfflineRequestStatus } from '../types';
+
+// --------------------------------------------------------
+/**
+ * Queue persisted in SQLite, survives restarts and power losses of edge devices.
+ *
+ * Requests are rows of `table` ordered by an autoincrement sequence, the database runs in WAL
+ * mode so an enqueue does not wait on a replay.
+ *
+ * @example
+ * ```typescript
+ * new SqliteOfflineQueueStore({ path: '/var/lib/pos/offline.db' });
+ * ```
+ */
+export class SqliteOfflineQueueStore implements IOfflineQueueStore {
+  private database: Database;
+  private table: string;
+
+  constructor(opts: { path?: string; database?: Database; table?: string } = {}) {
+    const { path = ':memory:', database, table = 'offline_requests' } = opts;
+
+    if (!/^\w+$/.test(table)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[SqliteOfflineQueueStore] Invalid table name | table: ${table}`,
+      });
+    }
+
+    this.table = table;
+    this.database = database ?? new Database(path, { create: true });
+    this.database.run('PRAGMA journal_mode = WAL');
+    this.database.run(
+      `CREATE TABLE IF NOT EXISTS ${table} (seq INTEGER PRIMARY KEY AUTOINCREMENT, id TEXT NOT NULL UNIQUE, status TEXT NOT NULL, data TEXT NOT NULL)`,
+    );
+    this.database.run(`CREATE INDEX IF NOT EXISTS ${table}_status_seq ON ${table} (status, seq)`);
+  }
+
+  append(opts: { request: IOfflineRequest }) {
+    const { request } = opts;
+    this.database
+      .query(`INSERT INTO ${this.table} (id, status, data) VALUES (?, ?, ?)`)
+      .run(request.id, request.status, JSON.stringify(request));
+  }
+
+  list(opts: { status: TOfflineRequestStatus; limit?: number }) {
+    const { status, limit = -1 } = opts;
+    const rows = this.database
+      .query<{ data: string }, [string, number]>(
+        `SELECT data FROM ${this.table} WHERE status = ? ORDER BY seq LIMIT ?`,
+      )
+      .all(status, limit);
+
+    return rows.map(row => JSON.parse(row.data) as IOfflineRequest);
+  }
+
+  save(opts: { request: IOfflineRequest }) {
+    const { request } = opts;
+    this.database
+      .query(`UPDATE ${this.table} SET status = ?, data = ? WHERE id = ?`)
+      .run(request.status, JSON.stringify(request), request.id);
+  }
+
+  remove(opts: { id: string }) {
+    this.database.query(`DELETE FROM ${this.table} WHERE id = ?`).run(opts.id);
+  }
+
+  count(opts: { status: TOfflineRequestStatus }) {
+    const row = this.database
+      .query<{ total: number }, [string]>(
+        `SELECT COUNT(*) AS total FROM ${this.table} WHERE status = ?`,
+      )
+      .get(opts.status);
+
+    return row?.total ?? 0;
+  }
+
+  close() {
+    this.database.close();
+  }
+}
@@ -0,0 +1 @@
+export * from './memory.store';
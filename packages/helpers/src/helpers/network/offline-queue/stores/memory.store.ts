@@ -0,0 +1,55 @@
+import { IOfflineQueueStore, IOfflineRequest, TOfflineRequestStatus } from '../types';
+
+// --------------------------------------------------------
+/**
+ * Queue kept in memory, lost on restart, for tests and processes which may drop requests.
+ */
+export class MemoryOfflineQueueStore implements IOfflineQueueStore {
+  // Insertion ordered, updates keep the position of a request
+  private requests = new Map<string, IOfflineRequest>();
+
+  append(opts: { request: IOfflineRequest }) {
+    const { request } = opts;
+    this.requests.set(request.id, structuredClone(request));
+  }
+
+  list(opts: { status: TOfflineRequestStatus; limit?: number }) {
+    const { status, limit } = opts;
+
+    const rs: Array<IOfflineRequest> = [];
+    for (const request of this.requests.values()) {
+      if (request.status !== status) {
+        continue;
+      }
+
+      rs.push(structuredClone(request));
+      if (limit && rs.length >= limit) {
+        break;
+      }
+    }
+
+    return rs;
+  }
+
+  save(opts: { request: IOfflineRequest }) {
+    const { request } = opts;
+    if (this.requests.has(request.id)) {
+      this.requests.set(request.id, structuredClone(request));
+    }
+  }
+
+  remove(opts: { id: string }) {
+    this.requests.delete(opts.id);
+  }
+
+  count(opts: { status: TOfflineRequestStatus }) {
+    let rs = 0;
+    for (const request of this.requests.values()) {
+      if (request.status === opts.status) {
+        rs++;
+      }
+    }
+
+    return rs;
+  }
+}
@@ -0,0 +1,66 @@
+import { AnyObject, TConstValue, ValueOrPromise } from '@/common/types';
+import { IRequestOptions } from '../http-request';
+import { OfflineConflictActions, OfflineRequestStatuses } from './constants';
+
+export type TOfflineRequestStatus = TConstValue<typeof OfflineRequestStatuses>;
+export type TOfflineConflictAction = TConstValue<typeof OfflineConflictActions>;
+
+// --------------------------------------------------------
+export interface IOfflineRequest {
+  id: string;
+  method: string;
+  url: string;
+  params?: AnyObject;
+  headers: Record<string, string>;
+  // Serialized body, objects are stored as JSON and form params as urlencoded
+  body?: string;
+  status: TOfflineRequestStatus;
+  attempts: number;
+  enqueuedAt: number;
+  lastStatusCode?: number;
+  lastError?: string;
+}
+
+export interface IOfflineQueueStore {
+  append(opts: { request: IOfflineRequest }): ValueOrPromise<void>;
+  // In enqueue order
+  list(opts: {
+    status: TOfflineRequestStatus;
+    limit?: number;
+  }): ValueOrPromise<Array<IOfflineRequest>>;
+  save(opts: { request: IOfflineRequest }): ValueOrPromise<void>;
+  remove(opts: { id: string }): ValueOrPromise<void>;
+  count(opts: { status: TOfflineRequestStatus }): ValueOrPromise<number>;
+}
+
+// --------------------------------------------------------
+export type TOfflineSendResult<RS> =
+  | { queued: false; response: RS }
+  | { queued: true; request: IOfflineRequest };
+
+export interface IOfflineQueueOptions<RS extends { status: number }> {
+  identifier: string;
+  // Fetcher the requests are sent and replayed with, e.g. `request.getNetworkService()`
+  fetcher: { send(opts: IRequestOptions): Promise<RS> };
+  // Defaults to `MemoryOfflineQueueStore`, use a persistent store to survive restarts
+  store?: IOfflineQueueStore;
+  // Methods queued when the upstream is unreachable, defaults to post, put, patch and delete
+  methods?: Array<string>;
+  replayInterval?: number;
+  conflictStatusCodes?: Array<number>;
+  unavailableStatusCodes?: Array<number>;
+  // Errors thrown by the fetcher meaning the upstream is unreachable, every error but aborts
+  isUnreachable?: (error: unknown) => boolean;
+  // Header carrying the request id so the upstream drops duplicated replays, `false` to disable
+  idempotencyKeyHeader?: string | false;
+  // Headers left out of the stored requests, defaults to `SensitiveFields.DEFAULTS`, `[]` to store
+  // every header
+  sensitiveHeaders?: Array<string>;
+
+  // Defaults to parking the request
+  onConflict?: (opts: {
+    request: IOfflineRequest;
+    response: RS;
+  }) => ValueOrPromise<TOfflineConflictAction>;
+  onReplayed?: (opts: { request: IOfflineRequest; response: RS }) => ValueOrPromise<void>;
+}
@@ -0,0 +1,430 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import fs from 'node:fs/promises';
+import path from 'node:path';
+import { IOpenApiDocument, IOpenApiOperation, IOpenApiParameter, IOpenApiSchema } from './types';
+
+const HTTP_METHODS = ['get', 'post', 'put', 'patch', 'delete', 'head', 'options'];
+const IDENTIFIER_PATTERN = /^[A-Za-z_$][A-Za-z0-9_$]*$/;
+
+const toWords = (value: string) => {
+  return value
+    .replace(/([a-z0-9])([A-Z])/g, '$1 $2')
+    .split(/[^A-Za-z0-9]+/)
+    .filter(Boolean);
+};
+
+const toPascalCase = (value: string) => {
+  return toWords(value)
+    .map(word => `${word[0].toUpperCase()}${word.slice(1)}`)
+    .join('');
+};
+
+const toCamelCase = (value: string) => {
+  const pascal = toPascalCase(value);
+  return `${pascal[0]?.toLowerCase() ?? ''}${pascal.slice(1)}`;
+};
+
+const toPropertyKey = (key: string) => (IDENTIFIER_PATTERN.test(key) ? key : `'${key}'`);
+
+const toComment = (opts: { lines: Array<string | undefined>; indent: string }) => {
+  const lines = opts.lines
+    .filter((line): line is string => !!line)
+    .flatMap(line => line.split('\n'))
+    .map(line => line.replace(/\*\//g, '*\\/'));
+
+  if (!lines.length) {
+    return '';
+  }
+
+  return [
+    `${opts.indent}/**`,
+    ...lines.map(line => `${opts.indent} *${line ? ` ${line}` : ''}`),
+    `${opts.indent} */`,
+    '',
+  ].join('\n');
+};
+
+interface IGeneratedOperation {
+  name: string;
+  method: string;
+  path: string;
+  operation: IOpenApiOperation;
+  parameters: Array<IOpenApiParameter>;
+}
+
+// --------------------------------------------------------
+/**
+ * Generates a typed client from an OpenAPI 3 document at build time.
+ *
+ * Every component schema becomes a `T<Name>` type and every operation a method of a class
+ * extending `NodeFetchNetworkRequest`, named after its `operationId`. Methods take `{ path,
+ * query, headers, body }`, resolve to the payload of the first 2xx JSON response (unwrapping
+ * `{ data, meta }` envelopes) and throw an `ApplicationError` carrying the response status
+ * otherwise.
+ *
+ * @example
+ * ```typescript
+ * // scripts/generate-clients.ts, run with `bun scripts/generate-clients.ts`
+ * await OpenApiClientGenerator.write({
+ *   input: 'https://orders.internal/doc/openapi.json',
+ *   outFile: 'src/clients/order-service.client.ts',
+ *   className: 'OrderServiceClient',
+ * });
+ *
+ * // application code
+ * const orders = new OrderServiceClient({ networkOptions: { baseUrl: 'service://orders/api' } });
+ * const order = await orders.getOrderById({ path: { id: '42' } }); // TOrder
+ * ```
+ */
+export class OpenApiClientGenerator {
+  static async load(opts: { input: string | IOpenApiDocument }): Promise<IOpenApiDocument> {
+    const { input } = opts;
+
+    if (typeof input !== 'string') {
+      return input;
+    }
+
+    if (/^https?:\/\//.test(input)) {
+      const rs = await fetch(input);
+      if (!rs.ok) {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+          message: `[OpenApiClientGenerator] Failed to fetch document | url: ${input} | status: ${rs.status}`,
+        });
+      }
+
+      return (await rs.json()) as IOpenApiDocument;
+    }
+
+    return JSON.parse(await fs.readFile(input, 'utf-8')) as IOpenApiDocument;
+  }
+
+  static async write(opts: {
+    input: string | IOpenApiDocument;
+    outFile: string;
+    className: string;
+    importPath?: string;
+  }) {
+    const { outFile, ...rest } = opts;
+    const document = await this.load({ input: opts.input });
+    const source = this.generate({ ...rest, document });
+
+    await fs.mkdir(path.dirname(outFile), { recursive: true });
+    await fs.writeFile(outFile, source, 'utf-8');
+    return source;
+  }
+
+  static generate(opts: {
+    document: IOpenApiDocument;
+    className: string;
+    // Module the runtime pieces are imported from
+    importPath?: string;
+  }): string {
+    const { document, className, importPath = '@venizia/ignis-helpers' } = opts;
+
+    const schemas = Object.entries(document.components?.schemas ?? {}).map(([name, schema]) => {
+      return [
+        toComment({ lines: [schema.description], indent: '' }),
+        `export type ${this.getTypeName(name)} = ${this.toType({ schema, indent: '' })};`,
+      ].join('');
+    });
+
+    const methods = this.getOperations({ document }).map(operation =>
+      this.generateMethod({ document, ...operation }),
+    );
+
+    return [
+      `// Generated by OpenApiClientGenerator from ${document.info.title} ${document.info.version}, do not edit.`,
+      '/* eslint-disable */',
+      'import {',
+      '  ApiResponses,',
+      '  INodeFetchNetworkRequestOptions,',
+      '  NodeFetchNetworkRequest,',
+      `} from '${importPath}';`,
+      '',
+      ...schemas.flatMap(schema => [schema, '']),
+      `export class ${className} extends NodeFetchNetworkRequest {`,
+      `  constructor(opts: Omit<INodeFetchNetworkRequestOptions, 'name'> & { name?: string }) {`,
+      `    super({ ...opts, name: opts.name ?? '${className}' });`,
+      '  }',
+      '',
+      '  protected async parseResponse<T>(response: Response): Promise<T> {',
+      '    await this.ensureOk(response);',
+      '    if (response.status === 204) {',
+      '      return undefined as T;',
+      '    }',
+      '',
+      "    const isJson = response.headers.get('content-type')?.includes('json');",
+      '    const body = isJson ? await response.json() : await response.text();',
+      '    return ApiResponses.unwrap<T>(body);',
+      '  }',
+      ...methods.flatMap(method => ['', method]),
+      '}',
+      '',
+    ].join('\n');
+  }
+
+  // --------------------------------------------------------
+  static getTypeName(name: string) {
+    return `T${toPascalCase(name)}`;
+  }
+
+  private static getOperations(opts: { document: IOpenApiDocument }): Array<IGeneratedOperation> {
+    const { document } = opts;
+    const usedNames = new Set<string>();
+    const rs: Array<IGeneratedOperation> = [];
+
+    for (const [routePath, pathItem] of Object.entries(document.paths ?? {})) {
+      const sharedParameters = (pathItem.parameters ?? []) as IOpenApiOperation['parameters'];
+
+      for (const method of HTTP_METHODS) {
+        const operation = pathItem[method] as IOpenApiOperation | undefined;
+        if (!operation) {
+          continue;
+        }
+
+        const baseName = toCamelCase(operation.operationId ?? `${method} ${routePath}`);
+        let name = baseName;
+        for (let i = 2; usedNames.has(name); i++) {
+          name = `${baseName}${i}`;
+        }
+        usedNames.add(name);
+
+        // Operation parameters override the path level ones with the same name and location
+        const parameters = new Map<string, IOpenApiParameter>();
+        for (const parameter of [...(sharedParameters ?? []), ...(operation.parameters ?? [])]) {
+          const resolved = this.resolveParameter({ document, parameter });
+          parameters.set(`${resolved.in}:${resolved.name}`, resolved);
+        }
+
+        rs.push({
+          name,
+          method,
+          path: routePath,
+          operation,
+          parameters: [...parameters.values()],
+        });
+      }
+    }
+
+    return rs;
+  }
+
+  private static resolveParameter(opts: {
+    document: IOpenApiDocument;
+    parameter: IOpenApiParameter | { $ref: string };
+  }): IOpenApiParameter {
+    const { document, parameter } = opts;
+    if (!('$ref' in parameter)) {
+      return parameter;
+    }
+
+    const name = parameter.$ref.split('/').pop() ?? '';
+    const resolved = document.components?.parameters?.[name];
+    if (!resolved) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[OpenApiClientGenerator] Unresolved parameter | $ref: ${parameter.$ref}`,
+      });
+    }
+
+    return resolved;
+  }
+
+  private static generateMethod(opts: IGeneratedOperation & { document: IOpenApiDocument }) {
+    const { name, method, path: routePath, operation, parameters } = opts;
+
+    const groups: Array<string> = [];
+    for (const location of ['path', 'query', 'header'] as const) {
+      const items = parameters.filter(parameter => parameter.in === location);
+      if (!items.length) {
+        continue;
+      }
+
+      const fields = items.map(parameter => {
+        const optional = parameter.required || location === 'path' ? '' : '?';
+        const type = parameter.schema
+          ? this.toType({ schema: parameter.schema, indent: '' })
+          : 'string';
+        return `${toPropertyKey(parameter.name)}${optional}: ${type}`;
+      });
+
+      const key = location === 'header' ? 'headers' : location;
+      const isRequired = location === 'path' || items.some(parameter => parameter.required);
+      groups.push(`${key}${isRequired ? '' : '?'}: { ${fields.join('; ')} }`);
+    }
+
+    const bodySchema = operation.requestBody?.content?.['application/json']?.schema;
+    if (operation.requestBody) {
+      const type = bodySchema ? this.toType({ schema: bodySchema, indent: '' }) : 'unknown';
+      groups.push(`body${operation.requestBody.required ? '' : '?'}: ${type}`);
+    }
+
+    if (!parameters.some(parameter => parameter.in === 'header')) {
+      groups.push('headers?: Record<string, string>');
+    }
+
+    const isOptsRequired = groups.some(group => !/^\w+\?/.test(group));
+    const optsType = `{ ${groups.join('; ')} }`;
+    const returnType = this.getResponseType({ operation });
+
+    const url = routePath.replace(/\{([^}]+)\}/g, (_, key: string) => {
+      const accessor = IDENTIFIER_PATTERN.test(key) ? `.${key}` : `['${key}']`;
+      return `\${encodeURIComponent(String(opts.path${accessor}))}`;
+    });
+
+    const request = [
+      `url: this.getRequestUrl({ paths: [\`${url}\`] })`,
+      `method: '${method.toUpperCase()}'`,
+      parameters.some(parameter => parameter.in === 'query') ? 'params: opts.query' : undefined,
+      'headers: opts.headers as Record<string, string> | undefined',
+      operation.requestBody
+        ? 'body: opts.body === undefined ? undefined : JSON.stringify(opts.body)'
+        : undefined,
+    ].filter(Boolean);
+
+    return [
+      toComment({
+        lines: [
+          operation.summary,
+          operation.description,
+          `\`${method.toUpperCase()} ${routePath}\``,
+          operation.deprecated ? '@deprecated' : undefined,
+        ],
+        indent: '  ',
+      }),
+      `  async ${name}(opts: ${optsType}${isOptsRequired ? '' : ' = {}'}): Promise<${returnType}> {`,
+      '    const response = await this.getNetworkService().send({',
+      ...request.map(line => `      ${line},`),
+      '    });',
+      `    return this.parseResponse<${returnType}>(response);`,
+      '  }',
+    ].join('\n');
+  }
+
+  private static getResponseType(opts: { operation: IOpenApiOperation }) {
+    const responses = Object.entries(opts.operation.responses ?? {})
+      .filter(([status]) => /^2(\d\d|XX)$/.test(status))
+      .sort(([a], [b]) => a.localeCompare(b));
+
+    for (const [status, response] of responses) {
+      if (status === '204') {
+        continue;
+      }
+
+      const media = Object.entries(response.content ?? {}).find(([type]) => type.includes('json'));
+      if (media?.[1].schema) {
+        return this.toType({ schema: media[1].schema, indent: '' });
+      }
+    }
+
+    return responses.length ? 'void' : 'unknown';
+  }
+
+  // --------------------------------------------------------
+  /**
+   * TypeScript type of a JSON schema, `$ref`s point to the generated component types.
+   */
+  static toType(opts: { schema: IOpenApiSchema; indent: string }): string {
+    const { schema, indent } = opts;
+    const types = Array.isArray(schema.type) ? schema.type : schema.type ? [schema.type] : [];
+    const isNullable = schema.nullable || types.includes('null');
+
+    const base = this.toBaseType({ schema, types: types.filter(type => type !== 'null'), indent });
+    return isNullable && base !== 'unknown' ? `${base} | null` : base;
+  }
+
+  private static toBaseType(opts: {
+    schema: IOpenApiSchema;
+    types: Array<string>;
+    indent: string;
+  }): string {
+    const { schema, types, indent } = opts;
+
+    if (schema.$ref) {
+      return this.getTypeName(schema.$ref.split('/').pop() ?? '');
+    }
+
+    if (schema.const !== undefined) {
+      return JSON.stringify(schema.const);
+    }
+
+    if (schema.enum) {
+      return schema.enum.map(value => JSON.stringify(value)).join(' | ');
+    }
+
+    for (const [key, separator] of [
+      ['oneOf', ' | '],
+      ['anyOf', ' | '],
+      ['allOf', ' & '],
+    ] as const) {
+      const members = schema[key];
+      if (members?.length) {
+        const joined = members
+          .map(member => this.toType({ schema: member, indent }))
+          .join(separator);
+        return members.length > 1 ? `(${joined})` : joined;
+      }
+    }
+
+    if (types.length > 1) {
+      return types.map(type => this.toBaseType({ schema, types: [type], indent })).join(' | ');
+    }
+
+    switch (types[0]) {
+      case 'string': {
+        return 'string';
+      }
+      case 'integer':
+      case 'number': {
+        return 'number';
+      }
+      case 'boolean': {
+        return 'boolean';
+      }
+      case 'array': {
+        const items = schema.items ? this.toType({ schema: schema.items, indent }) : 'unknown';
+        return `Array<${items}>`;
+      }
+      case 'object':
+      case undefined: {
+        if (!schema.properties && !schema.additionalProperties) {
+          return types[0] === 'object' ? 'Record<string, unknown>' : 'unknown';
+        }
+
+        return this.toObjectType({ schema, indent });
+      }
+      default: {
+        return 'unknown';
+      }
+    }
+  }
+
+  private static toObjectType(opts: { schema: IOpenApiSchema; indent: string }): string {
+    const { schema, indent } = opts;
+    const required = new Set(schema.required ?? []);
+    const innerIndent = `${indent}  `;
+
+    const fields = Object.entries(schema.properties ?? {}).map(([key, property]) => {
+      const optional = required.has(key) ? '' : '?';
+      const type = this.toType({ schema: property, indent: innerIndent });
+
+      return [
+        toComment({ lines: [property.description], indent: innerIndent }),
+        `${innerIndent}${toPropertyKey(key)}${optional}: ${type};`,
+      ].join('');
+    });
+
+    const { additionalProperties } = schema;
+    if (additionalProperties) {
+      const type =
+        additionalProperties === true
+          ? 'unknown'
+          : this.toType({ schema: additionalProperties, indent: innerIndent });
+      fields.push(`${innerIndent}[key: string]: ${type};`);
+    }
+
+    return fields.length ? `{\n${fields.join('\n')}\n${indent}}` : 'Record<string, never>';
+  }
+}
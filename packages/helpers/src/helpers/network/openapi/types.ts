@@ -0,0 +1,49 @@
+// Subset of OpenAPI 3.0 / 3.1 read by the client generator
+export interface IOpenApiSchema {
+  $ref?: string;
+  type?: string | Array<string>;
+  format?: string;
+  enum?: Array<unknown>;
+  const?: unknown;
+  nullable?: boolean;
+  items?: IOpenApiSchema;
+  properties?: Record<string, IOpenApiSchema>;
+  required?: Array<string>;
+  additionalProperties?: boolean | IOpenApiSchema;
+  oneOf?: Array<IOpenApiSchema>;
+  anyOf?: Array<IOpenApiSchema>;
+  allOf?: Array<IOpenApiSchema>;
+  description?: string;
+}
+
+export interface IOpenApiParameter {
+  name: string;
+  in: 'path' | 'query' | 'header' | 'cookie';
+  required?: boolean;
+  schema?: IOpenApiSchema;
+  description?: string;
+}
+
+export interface IOpenApiMediaType {
+  schema?: IOpenApiSchema;
+}
+
+export interface IOpenApiOperation {
+  operationId?: string;
+  summary?: string;
+  description?: string;
+  deprecated?: boolean;
+  parameters?: Array<IOpenApiParameter | { $ref: string }>;
+  requestBody?: { required?: boolean; content?: Record<string, IOpenApiMediaType> };
+  responses?: Record<string, { description?: string; content?: Record<string, IOpenApiMediaType> }>;
+}
+
+export interface IOpenApiDocument {
+  openapi: string;
+  info: { title: string; version: string };
+  paths: Record<string, Record<string, IOpenApiOperation | Array<IOpenApiParameter>>>;
+  components?: {
+    schemas?: Record<string, IOpenApiSchema>;
+    parameters?: Record<string, IOpenApiParameter>;
+  };
+}
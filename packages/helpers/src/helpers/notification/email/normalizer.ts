@@ -0,0 +1,82 @@
+// Practical subset of RFC 5322, no quoted local parts or IP literal domains
+const LOCAL_PART = "[A-Za-z0-9.!#$%&'*+/=?^_`{|}~-]+";
+const DOMAIN_LABEL = '[A-Za-z0-9](?:[A-Za-z0-9-]{0,61}[A-Za-z0-9])?';
+const EMAIL_PATTERN = new RegExp(`^${LOCAL_PART}@${DOMAIN_LABEL}(?:\\.${DOMAIN_LABEL})+$`);
+
+// Providers ignoring dots in the local part
+const DOT_INSENSITIVE_DOMAINS = new Set(['gmail.com']);
+const DOMAIN_ALIASES: Record<string, string> = { 'googlemail.com': 'gmail.com' };
+
+// --------------------------------------------------------
+/**
+ * Validate and normalize email addresses.
+ *
+ * `normalize` gives the address to store and send to. `canonicalize` also drops `+tags` and
+ * provider specific aliases so that sign ups with `Jane.Doe+promo@gmail.com` and
+ * `janedoe@googlemail.com` can be detected as the same mailbox, it is meant for uniqueness
+ * checks and never as a delivery address.
+ *
+ * @example
+ * ```typescript
+ * EmailNormalizer.normalize({ email: ' Jane.Doe+promo@GMAIL.com ' });
+ * // => 'jane.doe+promo@gmail.com'
+ *
+ * EmailNormalizer.canonicalize({ email: 'Jane.Doe+promo@googlemail.com' });
+ * // => 'janedoe@gmail.com'
+ * ```
+ */
+export class EmailNormalizer {
+  static isValid(email: string): boolean {
+    if (typeof email !== 'string' || email.length > 254) {
+      return false;
+    }
+
+    const [local] = email.split('@');
+    return local.length <= 64 && EMAIL_PATTERN.test(email);
+  }
+
+  /**
+   * @returns the trimmed, lower cased address or `null` when it is invalid
+   */
+  static normalize(opts: { email: string }): string | null {
+    const email = opts.email?.trim().toLowerCase();
+    if (!email || !this.isValid(email)) {
+      return null;
+    }
+
+    return email;
+  }
+
+  /**
+   * @returns the mailbox identity of the address or `null` when it is invalid
+   */
+  static canonicalize(opts: { email: string }): string | null {
+    const email = this.normalize(opts);
+    if (!email) {
+      return null;
+    }
+
+    const index = email.lastIndexOf('@');
+    const rawDomain = email.slice(index + 1);
+    const domain = DOMAIN_ALIASES[rawDomain] ?? rawDomain;
+
+    let local = email.slice(0, index).split('+')[0];
+    if (DOT_INSENSITIVE_DOMAINS.has(domain)) {
+      local = local.replace(/\./g, '');
+    }
+
+    return local ? `${local}@${domain}` : null;
+  }
+
+  /**
+   * Hide the local part so that addresses can be logged, `jane@x.com` => `j***@x.com`.
+   */
+  static mask(email: string): string {
+    const index = email.lastIndexOf('@');
+    if (index <= 1) {
+      return email;
+    }
+
+    return `${email[0]}${'*'.repeat(index - 1)}${email.slice(index)}`;
+  }
+}
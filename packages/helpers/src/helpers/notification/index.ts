@@ -0,0 +1,3 @@
+export * from './email';
+export * from './push';
+export * from './sms';
@@ -0,0 +1,47 @@
+// --------------------------------------------------------
+export class PushPlatforms {
+  static readonly ANDROID = 'android';
+  static readonly IOS = 'ios';
+  static readonly WEB = 'web';
+
+  static readonly SCHEME_SET = new Set([this.ANDROID, this.IOS, this.WEB]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
+
+// --------------------------------------------------------
+export class PushPriorities {
+  static readonly HIGH = 'high';
+  static readonly NORMAL = 'normal';
+
+  static readonly SCHEME_SET = new Set([this.HIGH, this.NORMAL]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+}
+
+// --------------------------------------------------------
+export class PushProviders {
+  static readonly FCM = 'fcm';
+  static readonly APNS = 'apns';
+}
+
+// --------------------------------------------------------
+export class PushDefaults {
+  static readonly BATCH_CONCURRENCY = 10;
+  static readonly TIMEOUT = 10_000;
+}
+
+// --------------------------------------------------------
+export class PushErrorCodes {
+  static readonly INVALID_TOKEN = 'PUSH_INVALID_TOKEN';
+  static readonly INVALID_PAYLOAD = 'PUSH_INVALID_PAYLOAD';
+  static readonly AUTHENTICATION_FAILED = 'PUSH_AUTHENTICATION_FAILED';
+  static readonly RATE_LIMITED = 'PUSH_RATE_LIMITED';
+  static readonly UNAVAILABLE = 'PUSH_UNAVAILABLE';
+  static readonly CONNECTION_FAILED = 'PUSH_CONNECTION_FAILED';
+  static readonly SEND_FAILED = 'PUSH_SEND_FAILED';
+}
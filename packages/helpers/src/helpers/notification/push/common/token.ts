@@ -0,0 +1,16 @@
+import { AnyObject } from '@/common/types';
+import { JWTHelper } from '@/helpers/auth';
+
+/**
+ * Sign the short lived JWTs used to authenticate against push providers, i.e. the Google OAuth
+ * assertion (RS256) and the APNs provider token (ES256).
+ */
+export const signProviderToken = (opts: {
+  algorithm: 'RS256' | 'ES256';
+  privateKey: string;
+  header?: AnyObject;
+  claims: AnyObject;
+}) => {
+  const { algorithm, privateKey, header, claims } = opts;
+  return JWTHelper.signToken({ algorithm, key: privateKey, header, claims });
+};
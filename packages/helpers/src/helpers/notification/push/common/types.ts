@@ -0,0 +1,61 @@
+import { TConstValue, ValueOrPromise } from '@/common/types';
+import { PushPlatforms, PushPriorities, PushProviders } from './constants';
+
+export type TPushPlatform = TConstValue<typeof PushPlatforms>;
+export type TPushPriority = TConstValue<typeof PushPriorities>;
+export type TPushProvider = TConstValue<typeof PushProviders>;
+
+// --------------------------------------------------------
+export interface IPushNotification {
+  title?: string;
+  body?: string;
+  // FCM only accepts string values in the data payload
+  data?: Record<string, string>;
+  imageUrl?: string;
+  sound?: string;
+  badge?: number;
+  // Notification category on iOS, click action on Android
+  category?: string;
+  threadId?: string;
+  collapseKey?: string;
+  priority?: TPushPriority;
+  // Time to live in seconds
+  ttl?: number;
+  // Data only push which wakes the app up without showing an alert
+  silent?: boolean;
+}
+
+export interface IPushSendResult {
+  token: string;
+  success: boolean;
+  messageId?: string;
+  error?: string;
+  // Normalized failure details, see PushErrorCodes
+  errorCode?: string;
+  statusCode?: number;
+  // The device token is no longer valid and should be removed from storage
+  isTokenInvalid: boolean;
+}
+
+export interface IPushBatchResult {
+  successCount: number;
+  failureCount: number;
+  invalidTokens: Array<string>;
+  results: Array<IPushSendResult>;
+}
+
+export type TPushInvalidTokensHandler = (opts: {
+  provider: TPushProvider;
+  tokens: Array<string>;
+}) => ValueOrPromise<void>;
+
+// --------------------------------------------------------
+export interface IPushSender {
+  send(opts: { token: string; notification: IPushNotification }): Promise<IPushSendResult>;
+  sendBatch(opts: {
+    tokens: Array<string>;
+    notification: IPushNotification;
+    concurrency?: number;
+  }): Promise<IPushBatchResult>;
+  close?(): ValueOrPromise<void>;
+}
@@ -0,0 +1,3 @@
+export * from './common';
+export * from './payload-builder';
+export * from './providers';
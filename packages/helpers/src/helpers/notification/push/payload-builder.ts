@@ -0,0 +1,124 @@
+import { AnyObject } from '@/common/types';
+import { IPushNotification, PushPriorities } from './common';
+
+// --------------------------------------------------------
+/**
+ * Translate a provider independent `IPushNotification` into the payload of every platform.
+ */
+export class PushPayloadBuilder {
+  private static isHighPriority(notification: IPushNotification) {
+    return !notification.silent && notification.priority !== PushPriorities.NORMAL;
+  }
+
+  // --------------------------------------------------------
+  /**
+   * The `aps` dictionary of an APNs payload.
+   * https://developer.apple.com/documentation/usernotifications/generating-a-remote-notification
+   */
+  static buildAps(notification: IPushNotification): AnyObject {
+    const { title, body, sound, badge, category, threadId, imageUrl, silent } = notification;
+
+    if (silent) {
+      return { 'content-available': 1 };
+    }
+
+    const aps: AnyObject = { alert: { title, body } };
+    if (sound) {
+      aps.sound = sound;
+    }
+
+    if (badge !== undefined) {
+      aps.badge = badge;
+    }
+
+    if (category) {
+      aps.category = category;
+    }
+
+    if (threadId) {
+      aps['thread-id'] = threadId;
+    }
+
+    // Lets a notification service extension download the image
+    if (imageUrl) {
+      aps['mutable-content'] = 1;
+    }
+
+    return aps;
+  }
+
+  static buildApnsHeaders(opts: {
+    notification: IPushNotification;
+    topic?: string;
+  }): Record<string, string> {
+    const { notification, topic } = opts;
+
+    const headers: Record<string, string> = {
+      ['apns-push-type']: notification.silent ? 'background' : 'alert',
+      // Background pushes must use priority 5
+      ['apns-priority']: this.isHighPriority(notification) ? '10' : '5',
+    };
+
+    if (topic) {
+      headers['apns-topic'] = topic;
+    }
+
+    if (notification.collapseKey) {
+      headers['apns-collapse-id'] = notification.collapseKey;
+    }
+
+    if (notification.ttl !== undefined) {
+      headers['apns-expiration'] = `${Math.floor(Date.now() / 1000) + notification.ttl}`;
+    }
+
+    return headers;
+  }
+
+  static buildApnsPayload(notification: IPushNotification): AnyObject {
+    return { ...notification.data, aps: this.buildAps(notification) };
+  }
+
+  // --------------------------------------------------------
+  /**
+   * A FCM HTTP v1 `Message` carrying the android, apns and webpush overrides.
+   * https://firebase.google.com/docs/reference/fcm/rest/v1/projects.messages
+   */
+  static buildFcmMessage(opts: { token: string; notification: IPushNotification }): AnyObject {
+    const { token, notification } = opts;
+    const { title, body, imageUrl, data, sound, category, collapseKey, ttl, silent } = notification;
+    const isHighPriority = this.isHighPriority(notification);
+
+    const message: AnyObject = {
+      token,
+      data,
+      android: {
+        priority: isHighPriority ? 'HIGH' : 'NORMAL',
+        collapse_key: collapseKey,
+        ttl: ttl !== undefined ? `${ttl}s` : undefined,
+      },
+      apns: {
+        headers: this.buildApnsHeaders({ notification }),
+        payload: { aps: this.buildAps(notification) },
+      },
+      webpush: {
+        headers: {
+          Urgency: isHighPriority ? 'high' : 'normal',
+          ...(ttl !== undefined ? { TTL: `${ttl}` } : {}),
+        },
+      },
+    };
+
+    if (silent) {
+      return message;
+    }
+
+    message.notification = { title, body, image: imageUrl };
+    message.android.notification = { sound, click_action: category, tag: collapseKey };
+
+    if (imageUrl) {
+      message.apns.fcm_options = { image: imageUrl };
+    }
+
+    return message;
+  }
+}
@@ -0,0 +1,282 @@
+import { HTTP } from '@/common/constants';
+import http2 from 'node:http2';
+import {
+  IPushNotification,
+  IPushSendResult,
+  PushErrorCodes,
+  PushProviders,
+  signProviderToken,
+} from '../common';
+import { PushPayloadBuilder } from '../payload-builder';
+import { AbstractPushSenderHelper, IBasePushSenderOptions } from './base';
+
+const APNS_PRODUCTION_URL = 'https://api.push.apple.com';
+const APNS_SANDBOX_URL = 'https://api.sandbox.push.apple.com';
+
+// APNs rejects provider tokens older than one hour and throttles refreshes under 20 minutes
+const PROVIDER_TOKEN_TTL = 50 * 60 * 1_000;
+
+const INVALID_TOKEN_REASONS = new Set(['BadDeviceToken', 'Unregistered', 'DeviceTokenNotForTopic']);
+const AUTHENTICATION_REASONS = new Set([
+  'ExpiredProviderToken',
+  'InvalidProviderToken',
+  'MissingProviderToken',
+  'BadCertificate',
+  'BadCertificateEnvironment',
+  'Forbidden',
+]);
+const RATE_LIMIT_REASONS = new Set(['TooManyRequests', 'TooManyProviderTokenUpdates']);
+
+interface IApnsResponse {
+  status: number;
+  headers: http2.IncomingHttpHeaders;
+  body: string;
+}
+
+export interface IApnsPushOptions extends IBasePushSenderOptions {
+  teamId: string;
+  keyId: string;
+  // Content of the .p8 signing key
+  privateKey: string;
+  // Bundle id of the app, sent as `apns-topic`
+  bundleId: string;
+  // Use the sandbox gateway for development builds (default: false)
+  isSandbox?: boolean;
+}
+
+// --------------------------------------------------------
+/**
+ * Send push notifications to Apple devices with APNs token based (.p8) authentication.
+ *
+ * APNs only speaks HTTP/2, a single session is kept open and multiplexes every request.
+ *
+ * @example
+ * ```typescript
+ * const apns = new ApnsPushHelper({
+ *   teamId: env.APNS_TEAM_ID,
+ *   keyId: env.APNS_KEY_ID,
+ *   privateKey: env.APNS_PRIVATE_KEY,
+ *   bundleId: 'com.example.app',
+ *   onInvalidTokens: async ({ tokens }) => { await deviceRepository.deleteByTokens(tokens); },
+ * });
+ *
+ * await apns.send({ token, notification: { title: 'Hello', body: 'World', badge: 1 } });
+ * await apns.close();
+ * ```
+ */
+export class ApnsPushHelper extends AbstractPushSenderHelper {
+  protected provider = PushProviders.APNS;
+
+  private options: IApnsPushOptions;
+  private session?: http2.ClientHttp2Session;
+  private providerToken?: { value: string; issuedAt: number };
+
+  constructor(opts: IApnsPushOptions) {
+    super({
+      ...opts,
+      scope: opts.scope ?? ApnsPushHelper.name,
+      identifier: opts.identifier ?? ApnsPushHelper.name,
+    });
+
+    this.options = opts;
+  }
+
+  // --------------------------------------------------------
+  protected getProviderToken() {
+    if (this.providerToken && Date.now() - this.providerToken.issuedAt < PROVIDER_TOKEN_TTL) {
+      return this.providerToken.value;
+    }
+
+    const { teamId, keyId, privateKey } = this.options;
+    const issuedAt = Date.now();
+
+    this.providerToken = {
+      value: signProviderToken({
+        algorithm: 'ES256',
+        privateKey,
+        header: { kid: keyId },
+        claims: { iss: teamId, iat: Math.floor(issuedAt / 1000) },
+      }),
+      issuedAt,
+    };
+
+    return this.providerToken.value;
+  }
+
+  protected getSession() {
+    if (this.session && !this.session.closed && !this.session.destroyed) {
+      return this.session;
+    }
+
+    const url = this.options.isSandbox ? APNS_SANDBOX_URL : APNS_PRODUCTION_URL;
+    const session = http2.connect(url);
+
+    const reset = () => {
+      if (this.session === session) {
+        this.session = undefined;
+      }
+    };
+
+    session.on('error', error => {
+      this.logger.for(this.getSession.name).error('APNs session error | error: %s', error);
+      reset();
+    });
+    session.on('goaway', reset);
+    session.on('close', reset);
+
+    this.session = session;
+    return session;
+  }
+
+  private request(opts: {
+    token: string;
+    headers: Record<string, string>;
+    body: string;
+  }): Promise<IApnsResponse> {
+    const { token, headers, body } = opts;
+
+    return new Promise((resolve, reject) => {
+      const request = this.getSession().request({
+        [http2.constants.HTTP2_HEADER_METHOD]: 'POST',
+        [http2.constants.HTTP2_HEADER_PATH]: `/3/device/${encodeURIComponent(token)}`,
+        ['authorization']: `bearer ${this.getProviderToken()}`,
+        ['content-type']: 'application/json',
+        ...headers,
+      });
+
+      let responseHeaders: http2.IncomingHttpHeaders = {};
+      let data = '';
+
+      request.setEncoding('utf8');
+      request.setTimeout(this.timeout, () => {
+        request.close(http2.constants.NGHTTP2_CANCEL);
+        reject(new Error(`APNs request timed out after ${this.timeout}ms`));
+      });
+
+      request.on('response', rs => {
+        responseHeaders = rs;
+      });
+      request.on('data', chunk => {
+        data += chunk;
+      });
+      request.on('end', () => {
+        resolve({
+          status: Number(responseHeaders[http2.constants.HTTP2_HEADER_STATUS] ?? 0),
+          headers: responseHeaders,
+          body: data,
+        });
+      });
+      request.on('error', reject);
+
+      request.end(body);
+    });
+  }
+
+  // --------------------------------------------------------
+  /**
+   * https://developer.apple.com/documentation/usernotifications/handling-notification-responses-from-apns
+   */
+  static normalizeError(opts: {
+    statusCode: number;
+    reason?: string;
+  }): Pick<IPushSendResult, 'errorCode' | 'statusCode' | 'isTokenInvalid'> {
+    const { statusCode, reason = '' } = opts;
+
+    if (INVALID_TOKEN_REASONS.has(reason) || statusCode === 410) {
+      return { errorCode: PushErrorCodes.INVALID_TOKEN, statusCode: 410, isTokenInvalid: true };
+    }
+
+    if (AUTHENTICATION_REASONS.has(reason) || statusCode === 403) {
+      return {
+        errorCode: PushErrorCodes.AUTHENTICATION_FAILED,
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        isTokenInvalid: false,
+      };
+    }
+
+    if (RATE_LIMIT_REASONS.has(reason) || statusCode === 429) {
+      return {
+        errorCode: PushErrorCodes.RATE_LIMITED,
+        statusCode: HTTP.ResultCodes.RS_4.TooManyRequests,
+        isTokenInvalid: false,
+      };
+    }
+
+    if (statusCode >= 500) {
+      return {
+        errorCode: PushErrorCodes.UNAVAILABLE,
+        statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+        isTokenInvalid: false,
+      };
+    }
+
+    if (statusCode === 400 || statusCode === 413) {
+      return {
+        errorCode: PushErrorCodes.INVALID_PAYLOAD,
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        isTokenInvalid: false,
+      };
+    }
+
+    return {
+      errorCode: PushErrorCodes.SEND_FAILED,
+      statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+      isTokenInvalid: false,
+    };
+  }
+
+  // --------------------------------------------------------
+  protected async deliver(opts: {
+    token: string;
+    notification: IPushNotification;
+  }): Promise<IPushSendResult> {
+    const { token, notification } = opts;
+
+    const response = await this.request({
+      token,
+      headers: PushPayloadBuilder.buildApnsHeaders({ notification, topic: this.options.bundleId }),
+      body: JSON.stringify(PushPayloadBuilder.buildApnsPayload(notification)),
+    });
+
+    const messageId = response.headers['apns-id'] as string | undefined;
+    if (response.status === 200) {
+      return { token, success: true, messageId, isTokenInvalid: false };
+    }
+
+    let reason: string | undefined;
+    try {
+      reason = JSON.parse(response.body).reason;
+    } catch {
+      // APNs always answers errors with a JSON body, keep the status only otherwise
+    }
+
+    if (reason === 'ExpiredProviderToken') {
+      this.providerToken = undefined;
+    }
+
+    this.logger
+      .for(this.deliver.name)
+      .error('APNs send failed | status: %d | reason: %s', response.status, reason);
+
+    return {
+      token,
+      success: false,
+      messageId,
+      error: reason ?? `APNs responded with status ${response.status}`,
+      ...ApnsPushHelper.normalizeError({ statusCode: response.status, reason }),
+    };
+  }
+
+  // --------------------------------------------------------
+  close() {
+    return new Promise<void>(resolve => {
+      if (!this.session || this.session.closed) {
+        resolve();
+        return;
+      }
+
+      this.session.close(() => resolve());
+      this.session = undefined;
+    });
+  }
+}
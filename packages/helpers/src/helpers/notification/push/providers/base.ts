@@ -0,0 +1,142 @@
+import { BaseHelper } from '@/helpers/base';
+import { ApplicationError } from '@/helpers/error';
+import { executePromiseWithLimit } from '@/utilities/promise.utility';
+import {
+  IPushBatchResult,
+  IPushNotification,
+  IPushSender,
+  IPushSendResult,
+  PushDefaults,
+  PushErrorCodes,
+  TPushInvalidTokensHandler,
+  TPushProvider,
+} from '../common';
+
+export interface IBasePushSenderOptions {
+  scope?: string;
+  identifier?: string;
+  // Default concurrency of `sendBatch`
+  concurrency?: number;
+  timeout?: number;
+  // Called with the tokens the provider reported as unregistered, so they can be pruned
+  onInvalidTokens?: TPushInvalidTokensHandler;
+}
+
+// --------------------------------------------------------
+export abstract class AbstractPushSenderHelper extends BaseHelper implements IPushSender {
+  protected abstract provider: TPushProvider;
+
+  protected concurrency: number;
+  protected timeout: number;
+  protected onInvalidTokens?: TPushInvalidTokensHandler;
+
+  constructor(opts: IBasePushSenderOptions & { scope: string; identifier: string }) {
+    super({ scope: opts.scope, identifier: opts.identifier });
+
+    this.concurrency = Math.max(opts.concurrency ?? PushDefaults.BATCH_CONCURRENCY, 1);
+    this.timeout = opts.timeout ?? PushDefaults.TIMEOUT;
+    this.onInvalidTokens = opts.onInvalidTokens;
+  }
+
+  protected abstract deliver(opts: {
+    token: string;
+    notification: IPushNotification;
+  }): Promise<IPushSendResult>;
+
+  // --------------------------------------------------------
+  protected getDeliveryFailure(opts: { token: string; error: unknown }): IPushSendResult {
+    const { token, error } = opts;
+
+    // e.g. provider credentials were rejected before the message could be sent
+    if (error instanceof ApplicationError) {
+      return {
+        token,
+        success: false,
+        error: error.message,
+        errorCode: error.messageCode ?? PushErrorCodes.SEND_FAILED,
+        statusCode: error.statusCode,
+        isTokenInvalid: false,
+      };
+    }
+
+    return {
+      token,
+      success: false,
+      error: (error as Error)?.message ?? `${error}`,
+      errorCode: PushErrorCodes.CONNECTION_FAILED,
+      statusCode: 503,
+      isTokenInvalid: false,
+    };
+  }
+
+  protected async notifyInvalidTokens(tokens: Array<string>) {
+    if (!tokens.length || !this.onInvalidTokens) {
+      return;
+    }
+
+    try {
+      await this.onInvalidTokens({ provider: this.provider, tokens });
+    } catch (error) {
+      this.logger
+        .for(this.notifyInvalidTokens.name)
+        .error('Failed to handle invalid tokens | count: %d | error: %s', tokens.length, error);
+    }
+  }
+
+  // --------------------------------------------------------
+  async send(opts: { token: string; notification: IPushNotification }) {
+    let result: IPushSendResult;
+    try {
+      result = await this.deliver(opts);
+    } catch (error) {
+      result = this.getDeliveryFailure({ token: opts.token, error });
+    }
+
+    if (result.isTokenInvalid) {
+      await this.notifyInvalidTokens([result.token]);
+    }
+
+    return result;
+  }
+
+  async sendBatch(opts: {
+    tokens: Array<string>;
+    notification: IPushNotification;
+    concurrency?: number;
+  }): Promise<IPushBatchResult> {
+    const { notification, concurrency = this.concurrency } = opts;
+    const tokens = [...new Set(opts.tokens)];
+
+    const results = await executePromiseWithLimit<IPushSendResult>({
+      limit: concurrency,
+      tasks: tokens.map(token => async () => {
+        try {
+          return await this.deliver({ token, notification });
+        } catch (error) {
+          return this.getDeliveryFailure({ token, error });
+        }
+      }),
+    });
+
+    const invalidTokens = results.filter(rs => rs.isTokenInvalid).map(rs => rs.token);
+    await this.notifyInvalidTokens(invalidTokens);
+
+    const successCount = results.filter(rs => rs.success).length;
+    this.logger
+      .for(this.sendBatch.name)
+      .info(
+        'Push batch sent | provider: %s | success: %d | failure: %d | invalid: %d',
+        this.provider,
+        successCount,
+        results.length - successCount,
+        invalidTokens.length,
+      );
+
+    return {
+      successCount,
+      failureCount: results.length - successCount,
+      invalidTokens,
+      results,
+    };
+  }
+}
@@ -0,0 +1,243 @@
+import { HTTP } from '@/common/constants';
+import { AnyObject } from '@/common/types';
+import { getError } from '@/helpers/error';
+import { NodeFetchNetworkRequest } from '@/helpers/network';
+import {
+  IPushNotification,
+  IPushSendResult,
+  PushErrorCodes,
+  PushProviders,
+  signProviderToken,
+} from '../common';
+import { PushPayloadBuilder } from '../payload-builder';
+import { AbstractPushSenderHelper, IBasePushSenderOptions } from './base';
+
+const FCM_SCOPE = 'https://www.googleapis.com/auth/firebase.messaging';
+const GOOGLE_TOKEN_URI = 'https://oauth2.googleapis.com/token';
+
+// Refresh the OAuth access token a bit before Google expires it
+const ACCESS_TOKEN_EXPIRY_MARGIN = 60 * 1_000;
+
+// Subset of the service account JSON downloaded from the Firebase console
+export interface IFcmServiceAccount {
+  project_id?: string;
+  client_email: string;
+  private_key: string;
+  token_uri?: string;
+}
+
+export interface IFcmPushOptions extends IBasePushSenderOptions {
+  serviceAccount: IFcmServiceAccount;
+  // Defaults to the project of the service account
+  projectId?: string;
+  baseUrl?: string;
+}
+
+// --------------------------------------------------------
+/**
+ * Send push notifications through the Firebase Cloud Messaging HTTP v1 API.
+ *
+ * @example
+ * ```typescript
+ * const fcm = new FcmPushHelper({
+ *   serviceAccount: JSON.parse(env.FCM_SERVICE_ACCOUNT),
+ *   onInvalidTokens: async ({ tokens }) => { await deviceRepository.deleteByTokens(tokens); },
+ * });
+ *
+ * const rs = await fcm.sendBatch({
+ *   tokens,
+ *   notification: { title: 'Order shipped', body: 'Your order #1024 is on the way' },
+ * });
+ * ```
+ */
+export class FcmPushHelper extends AbstractPushSenderHelper {
+  static readonly DEFAULT_BASE_URL = 'https://fcm.googleapis.com';
+
+  protected provider = PushProviders.FCM;
+
+  private serviceAccount: IFcmServiceAccount;
+  private projectId: string;
+  private network: NodeFetchNetworkRequest;
+
+  private accessToken?: { value: string; expiresAt: number };
+  private accessTokenPromise?: Promise<string>;
+
+  constructor(opts: IFcmPushOptions) {
+    super({
+      ...opts,
+      scope: opts.scope ?? FcmPushHelper.name,
+      identifier: opts.identifier ?? FcmPushHelper.name,
+    });
+
+    const projectId = opts.projectId ?? opts.serviceAccount.project_id;
+    if (!projectId) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: '[FcmPushHelper] projectId is required when the service account has none!',
+      });
+    }
+
+    this.projectId = projectId;
+    this.serviceAccount = opts.serviceAccount;
+    this.network = new NodeFetchNetworkRequest({
+      name: this.identifier,
+      networkOptions: { baseUrl: opts.baseUrl ?? FcmPushHelper.DEFAULT_BASE_URL },
+    });
+  }
+
+  // --------------------------------------------------------
+  private async requestAccessToken() {
+    const { client_email, private_key, token_uri = GOOGLE_TOKEN_URI } = this.serviceAccount;
+    const now = Math.floor(Date.now() / 1000);
+
+    const assertion = signProviderToken({
+      algorithm: 'RS256',
+      privateKey: private_key,
+      claims: { iss: client_email, scope: FCM_SCOPE, aud: token_uri, iat: now, exp: now + 3600 },
+    });
+
+    const response = await this.network.getNetworkService().post({
+      url: token_uri,
+      timeout: this.timeout,
+      headers: { ['content-type']: 'application/x-www-form-urlencoded' },
+      body: new URLSearchParams({
+        grant_type: 'urn:ietf:params:oauth:grant-type:jwt-bearer',
+        assertion,
+      }).toString(),
+    });
+
+    const data = await response.json().catch(() => ({}));
+    if (!response.ok || !data.access_token) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        messageCode: PushErrorCodes.AUTHENTICATION_FAILED,
+        message: `[requestAccessToken] Failed to obtain FCM access token | status: ${response.status} | error: ${data.error_description ?? data.error}`,
+      });
+    }
+
+    this.accessToken = {
+      value: data.access_token,
+      expiresAt: Date.now() + Number(data.expires_in ?? 3600) * 1_000,
+    };
+    return this.accessToken.value;
+  }
+
+  protected async getAccessToken() {
+    if (this.accessToken && this.accessToken.expiresAt - ACCESS_TOKEN_EXPIRY_MARGIN > Date.now()) {
+      return this.accessToken.value;
+    }
+
+    // Share a single refresh between concurrent sends of a batch
+    if (!this.accessTokenPromise) {
+      this.accessTokenPromise = this.requestAccessToken().finally(() => {
+        this.accessTokenPromise = undefined;
+      });
+    }
+
+    return this.accessTokenPromise;
+  }
+
+  // --------------------------------------------------------
+  /**
+   * https://firebase.google.com/docs/reference/fcm/rest/v1/ErrorCode
+   */
+  static normalizeError(opts: {
+    statusCode: number;
+    error?: AnyObject;
+  }): Pick<IPushSendResult, 'errorCode' | 'statusCode' | 'isTokenInvalid'> {
+    const { statusCode, error = {} } = opts;
+
+    const fcmError = (error.details as Array<AnyObject> | undefined)?.find(detail =>
+      `${detail['@type']}`.endsWith('google.firebase.fcm.v1.FcmError'),
+    );
+    const code: string = fcmError?.errorCode ?? error.status ?? '';
+
+    if (code === 'UNREGISTERED' || code === 'SENDER_ID_MISMATCH') {
+      return { errorCode: PushErrorCodes.INVALID_TOKEN, statusCode: 410, isTokenInvalid: true };
+    }
+
+    if (code === 'INVALID_ARGUMENT') {
+      // Malformed tokens are reported as invalid arguments too
+      const isTokenInvalid = /registration token/i.test(error.message ?? '');
+      return {
+        errorCode: isTokenInvalid ? PushErrorCodes.INVALID_TOKEN : PushErrorCodes.INVALID_PAYLOAD,
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        isTokenInvalid,
+      };
+    }
+
+    if (code === 'THIRD_PARTY_AUTH_ERROR' || statusCode === 401 || statusCode === 403) {
+      return {
+        errorCode: PushErrorCodes.AUTHENTICATION_FAILED,
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        isTokenInvalid: false,
+      };
+    }
+
+    if (code === 'QUOTA_EXCEEDED' || statusCode === 429) {
+      return {
+        errorCode: PushErrorCodes.RATE_LIMITED,
+        statusCode: HTTP.ResultCodes.RS_4.TooManyRequests,
+        isTokenInvalid: false,
+      };
+    }
+
+    if (statusCode >= 500) {
+      return {
+        errorCode: PushErrorCodes.UNAVAILABLE,
+        statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+        isTokenInvalid: false,
+      };
+    }
+
+    return {
+      errorCode: PushErrorCodes.SEND_FAILED,
+      statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+      isTokenInvalid: false,
+    };
+  }
+
+  // --------------------------------------------------------
+  protected async deliver(opts: {
+    token: string;
+    notification: IPushNotification;
+  }): Promise<IPushSendResult> {
+    const { token, notification } = opts;
+    const accessToken = await this.getAccessToken();
+
+    const response = await this.network.getNetworkService().post({
+      url: this.network.getRequestUrl({
+        paths: ['v1', 'projects', `${this.projectId}`, 'messages:send'],
+      }),
+      timeout: this.timeout,
+      headers: {
+        ['authorization']: `Bearer ${accessToken}`,
+        ['content-type']: 'application/json; charset=utf-8',
+      },
+      body: JSON.stringify({
+        message: PushPayloadBuilder.buildFcmMessage({ token, notification }),
+      }),
+    });
+
+    const data = await response.json().catch(() => ({}));
+    if (response.ok) {
+      return { token, success: true, messageId: data.name, isTokenInvalid: false };
+    }
+
+    if (response.status === 401) {
+      // Access token was revoked, force a refresh on the next send
+      this.accessToken = undefined;
+    }
+
+    this.logger
+      .for(this.deliver.name)
+      .error('FCM send failed | status: %d | error: %j', response.status, data.error);
+
+    return {
+      token,
+      success: false,
+      error: data.error?.message ?? `FCM responded with status ${response.status}`,
+      ...FcmPushHelper.normalizeError({ statusCode: response.status, error: data.error }),
+    };
+  }
+}
@@ -0,0 +1,3 @@
+export * from './apns.helper';
+export * from './base';
+export * from './fcm.helper';
@@ -0,0 +1,37 @@
+// --------------------------------------------------------
+export class SmsDeliveryStatuses {
+  static readonly QUEUED = '000_QUEUED';
+  static readonly SENT = '100_SENT';
+  static readonly DELIVERED = '200_DELIVERED';
+  static readonly UNDELIVERED = '300_UNDELIVERED';
+  static readonly FAILED = '400_FAILED';
+  static readonly CANCELED = '500_CANCELED';
+
+  static readonly SCHEME_SET = new Set([
+    this.QUEUED,
+    this.SENT,
+    this.DELIVERED,
+    this.UNDELIVERED,
+    this.FAILED,
+    this.CANCELED,
+  ]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+
+  static isFinal(scheme: string): boolean {
+    return scheme !== this.QUEUED && scheme !== this.SENT;
+  }
+}
+
+// --------------------------------------------------------
+export class SmsErrorCodes {
+  static readonly INVALID_PHONE_NUMBER = 'SMS_INVALID_PHONE_NUMBER';
+  static readonly AUTHENTICATION_FAILED = 'SMS_AUTHENTICATION_FAILED';
+  static readonly RECIPIENT_UNREACHABLE = 'SMS_RECIPIENT_UNREACHABLE';
+  static readonly RATE_LIMITED = 'SMS_RATE_LIMITED';
+  static readonly CONNECTION_FAILED = 'SMS_CONNECTION_FAILED';
+  static readonly SEND_FAILED = 'SMS_SEND_FAILED';
+  static readonly INVALID_SIGNATURE = 'SMS_INVALID_SIGNATURE';
+}
@@ -0,0 +1,42 @@
+import { AnyObject, TConstValue, ValueOrPromise } from '@/common/types';
+import { SmsDeliveryStatuses } from './constants';
+
+export type TSmsDeliveryStatus = TConstValue<typeof SmsDeliveryStatuses>;
+
+// --------------------------------------------------------
+export interface ISmsMessage {
+  to: string;
+  body: string;
+  // Falls back to the sender configured on the provider
+  from?: string;
+  // Provider will POST delivery status updates to this url
+  statusCallback?: string;
+}
+
+export interface ISmsSendResult {
+  success: boolean;
+  messageId?: string;
+  status?: TSmsDeliveryStatus;
+  to?: string;
+  error?: string;
+  // Normalized failure details, see SmsErrorCodes
+  errorCode?: string;
+  statusCode?: number;
+  response?: AnyObject;
+}
+
+export interface ISmsDeliveryReport {
+  messageId: string;
+  status: TSmsDeliveryStatus;
+  providerStatus: string;
+  to?: string;
+  from?: string;
+  errorCode?: string;
+  errorMessage?: string;
+  raw: Record<string, string>;
+}
+
+// --------------------------------------------------------
+export interface ISmsSender {
+  send(message: ISmsMessage): ValueOrPromise<ISmsSendResult>;
+}
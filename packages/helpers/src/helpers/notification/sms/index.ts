@@ -0,0 +1,3 @@
+export * from './common';
+export * from './phone-number';
+export * from './providers';
@@ -0,0 +1,107 @@
+const E164_PATTERN = /^\+[1-9]\d{6,14}$/;
+
+// ISO 3166-1 alpha-2 region => calling code
+const REGION_CALLING_CODES = new Map(
+  [
+    'AE:971 AR:54 AU:61 BD:880 BR:55 CA:1 CH:41 CN:86 DE:49 DK:45 EG:20 ES:34 FI:358 FR:33',
+    'GB:44 HK:852 ID:62 IE:353 IL:972 IN:91 IT:39 JP:81 KH:855 KR:82 LA:856 MM:95 MX:52 MY:60',
+    'NG:234 NL:31 NO:47 NZ:64 PH:63 PK:92 PL:48 PT:351 RU:7 SA:966 SE:46 SG:65 TH:66 TR:90',
+    'TW:886 UA:380 US:1 VN:84 ZA:27',
+  ]
+    .join(' ')
+    .split(' ')
+    .map(entry => entry.split(':') as [string, string]),
+);
+
+// Regions where the leading 0 is part of the national number and kept in E.164
+const KEEP_LEADING_ZERO_REGIONS = new Set(['IT']);
+
+// --------------------------------------------------------
+/**
+ * Normalize user entered phone numbers into E.164 (`+<country code><subscriber number>`).
+ *
+ * @example
+ * ```typescript
+ * PhoneNumberNormalizer.normalize({ phone: '(091) 234-5678', defaultCountryCode: '84' });
+ * // => '+84912345678'
+ *
+ * PhoneNumberNormalizer.normalize({ phone: '0044 20 7946 0958' });
+ * // => '+442079460958'
+ *
+ * PhoneNumberNormalizer.normalize({ phone: '1 (415) 555-2671', defaultRegion: 'US' });
+ * // => '+14155552671'
+ * ```
+ */
+export class PhoneNumberNormalizer {
+  static isE164(phone: string): boolean {
+    return E164_PATTERN.test(phone);
+  }
+
+  static getCallingCode(region: string): string | undefined {
+    return REGION_CALLING_CODES.get(region.toUpperCase());
+  }
+
+  static isValid(opts: {
+    phone: string;
+    defaultCountryCode?: string;
+    defaultRegion?: string;
+  }): boolean {
+    return this.normalize(opts) !== null;
+  }
+
+  /**
+   * @param opts.defaultCountryCode calling code used for national numbers, e.g. `84` or `+1`
+   * @param opts.defaultRegion ISO 3166-1 alpha-2 region used for national numbers, e.g. `VN`,
+   * ignored when `defaultCountryCode` is set
+   * @returns the E.164 number or `null` when the input can not be normalized
+   */
+  static normalize(opts: {
+    phone: string;
+    defaultCountryCode?: string;
+    defaultRegion?: string;
+  }): string | null {
+    const { phone, defaultRegion } = opts;
+    if (!phone) {
+      return null;
+    }
+
+    const defaultCountryCode =
+      opts.defaultCountryCode ?? (defaultRegion ? this.getCallingCode(defaultRegion) : undefined);
+
+    let normalized = phone.trim().replace(/[\s\-().]/g, '');
+
+    if (normalized.startsWith('00')) {
+      // International call prefix used across most of Europe and Asia
+      normalized = `+${normalized.slice(2)}`;
+    } else if (!normalized.startsWith('+')) {
+      const countryCode = defaultCountryCode?.replace(/^\+/, '');
+      if (!countryCode) {
+        return null;
+      }
+
+      let national = normalized;
+      if (countryCode === '1' && /^1\d{10}$/.test(national)) {
+        // North American trunk prefix, 1 415... => +1415...
+        national = national.slice(1);
+      } else if (!KEEP_LEADING_ZERO_REGIONS.has(defaultRegion?.toUpperCase() ?? '')) {
+        // Drop the national trunk prefix, 091... => +8491...
+        national = national.replace(/^0/, '');
+      }
+
+      normalized = `+${countryCode}${national}`;
+    }
+
+    return this.isE164(normalized) ? normalized : null;
+  }
+
+  /**
+   * Hide the middle digits so that numbers can be logged, `+84912345678` => `+849*****678`.
+   */
+  static mask(phone: string): string {
+    if (phone.length <= 7) {
+      return phone;
+    }
+
+    return `${phone.slice(0, 4)}${'*'.repeat(phone.length - 7)}${phone.slice(-3)}`;
+  }
+}
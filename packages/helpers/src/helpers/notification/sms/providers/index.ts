@@ -0,0 +1 @@
+export * from './twilio.helper';
@@ -0,0 +1,339 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { NodeFetchNetworkRequest } from '@/helpers/network';
+import C from 'node:crypto';
+import {
+  ISmsDeliveryReport,
+  ISmsMessage,
+  ISmsSender,
+  ISmsSendResult,
+  SmsDeliveryStatuses,
+  SmsErrorCodes,
+  TSmsDeliveryStatus,
+} from '../common';
+import { PhoneNumberNormalizer } from '../phone-number';
+
+const TWILIO_STATUS_MAP: Record<string, TSmsDeliveryStatus> = {
+  accepted: SmsDeliveryStatuses.QUEUED,
+  scheduled: SmsDeliveryStatuses.QUEUED,
+  queued: SmsDeliveryStatuses.QUEUED,
+  sending: SmsDeliveryStatuses.QUEUED,
+  sent: SmsDeliveryStatuses.SENT,
+  partially_delivered: SmsDeliveryStatuses.SENT,
+  delivered: SmsDeliveryStatuses.DELIVERED,
+  read: SmsDeliveryStatuses.DELIVERED,
+  receiving: SmsDeliveryStatuses.DELIVERED,
+  received: SmsDeliveryStatuses.DELIVERED,
+  undelivered: SmsDeliveryStatuses.UNDELIVERED,
+  failed: SmsDeliveryStatuses.FAILED,
+  canceled: SmsDeliveryStatuses.CANCELED,
+};
+
+// https://www.twilio.com/docs/api/errors
+const TWILIO_AUTHENTICATION_ERRORS = new Set([20003, 20005, 20008]);
+const TWILIO_INVALID_NUMBER_ERRORS = new Set([21211, 21614, 21217, 21401]);
+const TWILIO_UNREACHABLE_ERRORS = new Set([
+  21408, 21610, 21612, 30003, 30004, 30005, 30006, 30007, 30008,
+]);
+const TWILIO_RATE_LIMIT_ERRORS = new Set([20429, 14107, 30022]);
+
+// --------------------------------------------------------
+export interface ITwilioSmsOptions {
+  accountSid: string;
+  // Required to verify status callbacks, and to send when no api key is configured
+  authToken?: string;
+  apiKey?: { sid: string; secret: string };
+
+  // Either a sender number or a messaging service must be configured
+  from?: string;
+  messagingServiceSid?: string;
+
+  // Calling code used to normalize national recipient numbers, e.g. `84`
+  defaultCountryCode?: string;
+  // Region used when no calling code is configured, e.g. `VN`
+  defaultRegion?: string;
+  statusCallback?: string;
+
+  baseUrl?: string;
+  timeout?: number;
+  scope?: string;
+  identifier?: string;
+}
+
+// --------------------------------------------------------
+/**
+ * Send SMS through the Twilio Programmable Messaging API.
+ *
+ * @example
+ * ```typescript
+ * const sms = new TwilioSmsHelper({
+ *   accountSid: env.TWILIO_ACCOUNT_SID,
+ *   authToken: env.TWILIO_AUTH_TOKEN,
+ *   from: '+15005550006',
+ *   defaultCountryCode: '84',
+ *   statusCallback: 'https://api.example.com/sms/status',
+ * });
+ *
+ * const rs = await sms.send({ to: '0912345678', body: 'Your code is 123456' });
+ *
+ * // Status callback route
+ * const report = sms.verifyStatusCallback({
+ *   url: 'https://api.example.com/sms/status',
+ *   payload: await context.req.text(),
+ *   signature: context.req.header('x-twilio-signature'),
+ * });
+ * ```
+ */
+export class TwilioSmsHelper extends BaseHelper implements ISmsSender {
+  static readonly DEFAULT_BASE_URL = 'https://api.twilio.com';
+  static readonly SIGNATURE_HEADER = 'x-twilio-signature';
+
+  private options: ITwilioSmsOptions;
+  private network: NodeFetchNetworkRequest;
+  private authorization: string;
+
+  constructor(opts: ITwilioSmsOptions) {
+    super({
+      scope: opts.scope ?? TwilioSmsHelper.name,
+      identifier: opts.identifier ?? TwilioSmsHelper.name,
+    });
+
+    const { accountSid, authToken, apiKey, from, messagingServiceSid } = opts;
+    if (!apiKey && !authToken) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: '[TwilioSmsHelper] Either authToken or apiKey is required!',
+      });
+    }
+
+    if (!from && !messagingServiceSid) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: '[TwilioSmsHelper] Either from or messagingServiceSid is required!',
+      });
+    }
+
+    this.options = opts;
+    this.authorization = `Basic ${Buffer.from(
+      apiKey ? `${apiKey.sid}:${apiKey.secret}` : `${accountSid}:${authToken}`,
+    ).toString('base64')}`;
+
+    this.network = new NodeFetchNetworkRequest({
+      name: this.identifier,
+      networkOptions: { baseUrl: opts.baseUrl ?? TwilioSmsHelper.DEFAULT_BASE_URL },
+    });
+  }
+
+  // --------------------------------------------------------
+  static toDeliveryStatus(providerStatus: string): TSmsDeliveryStatus {
+    return TWILIO_STATUS_MAP[providerStatus?.toLowerCase()] ?? SmsDeliveryStatuses.QUEUED;
+  }
+
+  static normalizeError(opts: {
+    code?: number | string;
+    statusCode?: number;
+  }): Pick<ISmsSendResult, 'errorCode' | 'statusCode'> {
+    const code = Number(opts.code ?? 0);
+    const statusCode = opts.statusCode ?? 0;
+
+    if (TWILIO_AUTHENTICATION_ERRORS.has(code) || statusCode === 401) {
+      return {
+        errorCode: SmsErrorCodes.AUTHENTICATION_FAILED,
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+      };
+    }
+
+    if (TWILIO_INVALID_NUMBER_ERRORS.has(code)) {
+      return {
+        errorCode: SmsErrorCodes.INVALID_PHONE_NUMBER,
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+      };
+    }
+
+    if (TWILIO_UNREACHABLE_ERRORS.has(code)) {
+      return {
+        errorCode: SmsErrorCodes.RECIPIENT_UNREACHABLE,
+        statusCode: HTTP.ResultCodes.RS_4.UnprocessableEntity,
+      };
+    }
+
+    if (TWILIO_RATE_LIMIT_ERRORS.has(code) || statusCode === 429) {
+      return {
+        errorCode: SmsErrorCodes.RATE_LIMITED,
+        statusCode: HTTP.ResultCodes.RS_4.TooManyRequests,
+      };
+    }
+
+    if (statusCode >= 500) {
+      return {
+        errorCode: SmsErrorCodes.SEND_FAILED,
+        statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+      };
+    }
+
+    return {
+      errorCode: SmsErrorCodes.SEND_FAILED,
+      statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+    };
+  }
+
+  // --------------------------------------------------------
+  async send(message: ISmsMessage): Promise<ISmsSendResult> {
+    const logger = this.logger.for(this.send.name);
+
+    const to = PhoneNumberNormalizer.normalize({
+      phone: message.to,
+      defaultCountryCode: this.options.defaultCountryCode,
+      defaultRegion: this.options.defaultRegion,
+    });
+
+    if (!to) {
+      return {
+        success: false,
+        error: `Invalid phone number: ${PhoneNumberNormalizer.mask(message.to ?? '')}`,
+        errorCode: SmsErrorCodes.INVALID_PHONE_NUMBER,
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+      };
+    }
+
+    const form = new URLSearchParams({ To: to, Body: message.body });
+    const from = message.from ?? this.options.from;
+    if (from) {
+      form.set('From', from);
+    } else if (this.options.messagingServiceSid) {
+      form.set('MessagingServiceSid', this.options.messagingServiceSid);
+    }
+
+    const statusCallback = message.statusCallback ?? this.options.statusCallback;
+    if (statusCallback) {
+      form.set('StatusCallback', statusCallback);
+    }
+
+    let response: Response;
+    try {
+      logger.debug('Sending SMS with Twilio | to: %s', PhoneNumberNormalizer.mask(to));
+      response = await this.network.getNetworkService().post({
+        url: this.network.getRequestUrl({
+          paths: ['2010-04-01', 'Accounts', this.options.accountSid, 'Messages.json'],
+        }),
+        body: form.toString(),
+        timeout: this.options.timeout,
+        headers: {
+          ['authorization']: this.authorization,
+          ['content-type']: 'application/x-www-form-urlencoded',
+        },
+      });
+    } catch (error) {
+      logger.error('Twilio request failed | error: %s', error);
+      return {
+        success: false,
+        to,
+        error: (error as Error)?.message ?? `${error}`,
+        errorCode: SmsErrorCodes.CONNECTION_FAILED,
+        statusCode: HTTP.ResultCodes.RS_5.ServiceUnavailable,
+      };
+    }
+
+    const data = await response.json().catch(() => ({}));
+    if (response.ok) {
+      return {
+        success: true,
+        messageId: data.sid,
+        status: TwilioSmsHelper.toDeliveryStatus(data.status),
+        to,
+        response: data,
+      };
+    }
+
+    logger.error(
+      'Twilio send failed | status: %d | code: %s | message: %s',
+      response.status,
+      data.code,
+      data.message,
+    );
+
+    return {
+      success: false,
+      to,
+      error: data.message ?? `Twilio responded with status ${response.status}`,
+      response: data,
+      ...TwilioSmsHelper.normalizeError({ code: data.code, statusCode: response.status }),
+    };
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Compute the `X-Twilio-Signature` of a request: base64 HMAC-SHA1 of the full url followed by
+   * every POST parameter (sorted by name) concatenated as `name + value`.
+   */
+  computeSignature(opts: { url: string; params: Record<string, string> }) {
+    const { url, params } = opts;
+    if (!this.options.authToken) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: '[computeSignature] authToken is required to verify Twilio signatures!',
+      });
+    }
+
+    const data = Object.keys(params)
+      .sort()
+      .reduce((rs, key) => `${rs}${key}${params[key]}`, url);
+
+    return C.createHmac('sha1', this.options.authToken).update(data).digest('base64');
+  }
+
+  parseStatusCallback(opts: { payload: string | Record<string, string> }): ISmsDeliveryReport {
+    const { payload } = opts;
+    const raw =
+      typeof payload === 'string' ? Object.fromEntries(new URLSearchParams(payload)) : payload;
+
+    const messageId = raw.MessageSid ?? raw.SmsSid;
+    const providerStatus = raw.MessageStatus ?? raw.SmsStatus;
+    if (!messageId || !providerStatus) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        message: '[parseStatusCallback] Missing MessageSid or MessageStatus in status callback!',
+      });
+    }
+
+    const report: ISmsDeliveryReport = {
+      messageId,
+      status: TwilioSmsHelper.toDeliveryStatus(providerStatus),
+      providerStatus,
+      to: raw.To,
+      from: raw.From,
+      raw,
+    };
+
+    if (raw.ErrorCode) {
+      report.errorCode = TwilioSmsHelper.normalizeError({ code: raw.ErrorCode }).errorCode;
+      report.errorMessage = raw.ErrorMessage ?? `Twilio error ${raw.ErrorCode}`;
+    }
+
+    return report;
+  }
+
+  verifyStatusCallback(opts: {
+    url: string;
+    payload: string | Record<string, string>;
+    signature?: string | null;
+  }): ISmsDeliveryReport {
+    const { url, payload, signature } = opts;
+    const params =
+      typeof payload === 'string' ? Object.fromEntries(new URLSearchParams(payload)) : payload;
+
+    const expected = Buffer.from(this.computeSignature({ url, params }));
+    const received = Buffer.from(signature ?? '');
+
+    if (expected.length !== received.length || !C.timingSafeEqual(expected, received)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+        messageCode: SmsErrorCodes.INVALID_SIGNATURE,
+        message: '[verifyStatusCallback] Invalid Twilio signature!',
+      });
+    }
+
+    return this.parseStatusCallback({ payload: params });
+  }
+}
@@ -0,0 +1,45 @@
+import { BaseHelper } from '@/helpers/base';
+import { IRateLimiter, IRateLimitResult } from './types';
+
+// --------------------------------------------------------
+/**
+ * Process-local fixed window rate limiter, useful for single instance deployments and tests.
+ */
+export class MemoryRateLimiter extends BaseHelper implements IRateLimiter {
+  private windows = new Map<string, { count: number; resetAt: number }>();
+
+  constructor(opts?: { identifier?: string }) {
+    super({
+      scope: MemoryRateLimiter.name,
+      identifier: opts?.identifier ?? MemoryRateLimiter.name,
+    });
+  }
+
+  consume(opts: { key: string; limit: number; window: number; cost?: number }) {
+    const { key, limit, window, cost = 1 } = opts;
+    const now = Date.now();
+
+    let current = this.windows.get(key);
+    if (!current || current.resetAt <= now) {
+      current = { count: 0, resetAt: now + window };
+      this.windows.set(key, current);
+    }
+
+    current.count += cost;
+
+    const isAllowed = current.count <= limit;
+    const rs: IRateLimitResult = {
+      isAllowed,
+      limit,
+      remaining: Math.max(limit - current.count, 0),
+      resetAt: current.resetAt,
+      retryAfter: isAllowed ? 0 : current.resetAt - now,
+    };
+    return Promise.resolve(rs);
+  }
+
+  reset(opts: { key: string }) {
+    this.windows.delete(opts.key);
+    return Promise.resolve();
+  }
+}
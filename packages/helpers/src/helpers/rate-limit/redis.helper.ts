@@ -0,0 +1,63 @@
+import { BaseHelper } from '@/helpers/base';
+import { DefaultRedisHelper } from '@/helpers/redis';
+import { IRateLimiter, IRateLimitResult } from './types';
+
+// Increment and start the window atomically, returns the count and the window ttl
+const CONSUME_SCRIPT = `
+local current = redis.call("incrby", KEYS[1], ARGV[1])
+if current == tonumber(ARGV[1]) then
+  redis.call("pexpire", KEYS[1], ARGV[2])
+end
+local ttl = redis.call("pttl", KEYS[1])
+if ttl < 0 then
+  redis.call("pexpire", KEYS[1], ARGV[2])
+  ttl = tonumber(ARGV[2])
+end
+return { current, ttl }
+`;
+
+// --------------------------------------------------------
+/**
+ * Fixed window rate limiter shared by every instance through Redis.
+ *
+ * A single Lua script increments the counter and sets its expiry, so concurrent requests
+ * on different instances can never overshoot the limit.
+ */
+export class RedisRateLimiter extends BaseHelper implements IRateLimiter {
+  private redis: DefaultRedisHelper;
+  private prefix: string;
+
+  constructor(opts: { redis: DefaultRedisHelper; prefix?: string; identifier?: string }) {
+    super({ scope: RedisRateLimiter.name, identifier: opts.identifier ?? RedisRateLimiter.name });
+
+    this.redis = opts.redis;
+    this.prefix = opts.prefix ?? 'rate-limit';
+  }
+
+  private getKey(key: string) {
+    return `${this.prefix}:${key}`;
+  }
+
+  async consume(opts: { key: string; limit: number; window: number; cost?: number }) {
+    const { key, limit, window, cost = 1 } = opts;
+
+    const [count, ttl] = (await this.redis
+      .getClient()
+      .eval(CONSUME_SCRIPT, 1, this.getKey(key), cost, window)) as [number, number];
+
+    const now = Date.now();
+    const isAllowed = Number(count) <= limit;
+    const rs: IRateLimitResult = {
+      isAllowed,
+      limit,
+      remaining: Math.max(limit - Number(count), 0),
+      resetAt: now + Number(ttl),
+      retryAfter: isAllowed ? 0 : Number(ttl),
+    };
+    return rs;
+  }
+
+  async reset(opts: { key: string }) {
+    await this.redis.getClient().del(this.getKey(opts.key));
+  }
+}
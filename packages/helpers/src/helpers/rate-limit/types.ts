@@ -0,0 +1,23 @@
+// --------------------------------------------------------
+export interface IRateLimitResult {
+  isAllowed: boolean;
+  limit: number;
+  remaining: number;
+  // Epoch milliseconds the current window ends
+  resetAt: number;
+  // Milliseconds until the next request may be allowed, 0 when allowed
+  retryAfter: number;
+}
+
+export interface IRateLimiter {
+  /**
+   * Count `cost` (default 1) against `key` within a fixed window of `window` milliseconds.
+   */
+  consume(opts: {
+    key: string;
+    limit: number;
+    window: number;
+    cost?: number;
+  }): Promise<IRateLimitResult>;
+  reset(opts: { key: string }): Promise<void>;
+}
@@ -0,0 +1,9 @@
+// --------------------------------------------------------
+export class RequestContextHeaders {
+  static readonly REQUEST_ID = 'x-request-id';
+  static readonly TENANT_ID = 'x-tenant-id';
+  // Epoch milliseconds by which the caller needs the response
+  static readonly DEADLINE = 'x-request-deadline';
+  // Remaining budget of gRPC callers, e.g. `250m` or `2S`
+  static readonly GRPC_TIMEOUT = 'grpc-timeout';
+}
@@ -0,0 +1,54 @@
+// --------------------------------------------------------
+/**
+ * Deadline of an incoming request, from `x-request-deadline` (epoch milliseconds) or the relative
+ * `grpc-timeout` of gRPC callers. The relative form is immune to clock skew between the hosts.
+ */
+export class RequestDeadlines {
+  // Milliseconds per `grpc-timeout` unit
+  private static GRPC_TIMEOUT_UNITS: Record<string, number> = {
+    H: 3_600_000,
+    M: 60_000,
+    S: 1_000,
+    m: 1,
+    u: 0.001,
+    n: 0.000_001,
+  };
+
+  /**
+   * @returns the deadline in epoch milliseconds, `undefined` when no valid header is set
+   */
+  static parse(opts: {
+    deadline?: string | null;
+    grpcTimeout?: string | null;
+    now?: number;
+  }): number | undefined {
+    const { deadline, grpcTimeout, now = Date.now() } = opts;
+
+    if (deadline && /^\d+$/.test(deadline.trim())) {
+      return Number(deadline);
+    }
+
+    const timeout = this.parseGrpcTimeout(grpcTimeout);
+    return timeout === undefined ? undefined : now + timeout;
+  }
+
+  /**
+   * @returns the earliest of `deadlines`, `undefined` when none is set
+   */
+  static earliest(deadlines: Array<number | undefined>): number | undefined {
+    const rs = deadlines.filter((deadline): deadline is number => deadline !== undefined);
+    return rs.length ? Math.min(...rs) : undefined;
+  }
+
+  /**
+   * @returns the `grpc-timeout` in milliseconds, up to 8 digits and a unit as in the gRPC spec
+   */
+  static parseGrpcTimeout(value?: string | null): number | undefined {
+    const match = value?.trim().match(/^(\d{1,8})([HMSmun])$/);
+    if (!match) {
+      return undefined;
+    }
+
+    return Number(match[1]) * this.GRPC_TIMEOUT_UNITS[match[2]];
+  }
+}
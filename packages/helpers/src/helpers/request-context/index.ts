@@ -0,0 +1,4 @@
+export * from './constants';
+export * from './deadline';
+export * from './storage';
+export * from './types';
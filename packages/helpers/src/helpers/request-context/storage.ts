@@ -0,0 +1,87 @@
+import { AnyObject } from '@/common/types';
+import { AsyncLocalStorage } from 'node:async_hooks';
+import { RequestContextHeaders } from './constants';
+import { IRequestContext } from './types';
+
+// --------------------------------------------------------
+/**
+ * Task local storage of the `IRequestContext` of the request being handled.
+ *
+ * The HTTP server starts a scope per request, anything awaited from the handler (services,
+ * repositories, fetchers) can then read it without passing it around. Outside of a request
+ * scope (cron jobs, queue consumers) `get` returns `undefined` unless `run` is used.
+ *
+ * @example
+ * ```typescript
+ * const requestId = RequestContextStorage.get()?.requestId;
+ *
+ * // propagate to a background job
+ * await RequestContextStorage.run({
+ *   context: { requestId: job.requestId },
+ *   task: () => processJob(job),
+ * });
+ * ```
+ */
+export class RequestContextStorage {
+  private static storage = new AsyncLocalStorage<IRequestContext>();
+
+  static run<T>(opts: {
+    context: Partial<IRequestContext> & Pick<IRequestContext, 'requestId'>;
+    task: () => T;
+  }): T {
+    const { context, task } = opts;
+    return this.storage.run({ startedAt: Date.now(), extra: {}, ...context }, task);
+  }
+
+  static get<TExtra extends AnyObject = AnyObject>(): IRequestContext<TExtra> | undefined {
+    return this.storage.getStore() as IRequestContext<TExtra> | undefined;
+  }
+
+  /**
+   * Merge values into the current context, e.g. the user id once authentication succeeded.
+   * No-op outside of a request scope.
+   */
+  static update(opts: Partial<Omit<IRequestContext, 'startedAt'>>) {
+    const current = this.storage.getStore();
+    if (!current) {
+      return;
+    }
+
+    const { extra, ...rest } = opts;
+    Object.assign(current, rest);
+    if (extra) {
+      Object.assign(current.extra, extra);
+    }
+  }
+
+  /**
+   * Milliseconds left before the deadline of the current request, `undefined` without one.
+   */
+  static getRemainingBudget(): number | undefined {
+    const deadline = this.storage.getStore()?.deadline;
+    return deadline === undefined ? undefined : deadline - Date.now();
+  }
+
+  /**
+   * Headers forwarded to downstream services so their logs can be correlated, and the deadline
+   * so they stop working on requests nobody waits for anymore.
+   */
+  static getPropagationHeaders(): Record<string, string> {
+    const current = this.storage.getStore();
+    if (!current) {
+      return {};
+    }
+
+    const rs: Record<string, string> = {
+      [RequestContextHeaders.REQUEST_ID]: current.requestId,
+    };
+    if (current.tenantId) {
+      rs[RequestContextHeaders.TENANT_ID] = current.tenantId;
+    }
+    if (current.deadline !== undefined) {
+      rs[RequestContextHeaders.DEADLINE] = String(current.deadline);
+    }
+
+    return rs;
+  }
+}
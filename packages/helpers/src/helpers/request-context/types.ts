@@ -0,0 +1,14 @@
+import { AnyObject } from '@/common/types';
+
+export interface IRequestContext<TExtra extends AnyObject = AnyObject> {
+  requestId: string;
+  userId?: string | number | bigint;
+  tenantId?: string;
+  // Receive time in epoch milliseconds
+  startedAt: number;
+  // Epoch milliseconds by which the caller needs the response, see `RequestDeadlines`
+  deadline?: number;
+  // Aborted once the client of the incoming request disconnects, see `cancelOnDisconnect`
+  signal?: AbortSignal;
+  extra: TExtra;
+}
@@ -0,0 +1,150 @@
+import { Bucket, File, Storage, StorageOptions } from '@google-cloud/storage';
+import { pipeline } from 'node:stream/promises';
+import { AbstractObjectStorageHelper } from '../object/base';
+import {
+  IGetObjectResult,
+  IListObjectsQuery,
+  IListObjectsResult,
+  IObjectMetadata,
+  IPutObjectOptions,
+} from '../object/types';
+
+// ================================================================================
+export interface IGcsObjectStorageOptions extends StorageOptions {
+  bucket: string;
+  scope?: string;
+  identifier?: string;
+}
+
+// ================================================================================
+/**
+ * Object storage on Google Cloud Storage.
+ */
+export class GcsObjectStorageHelper extends AbstractObjectStorageHelper {
+  client: Storage;
+  protected bucket: Bucket;
+
+  constructor(opts: IGcsObjectStorageOptions) {
+    const { bucket, scope, identifier, ...storageOptions } = opts;
+    super({
+      scope: scope ?? GcsObjectStorageHelper.name,
+      identifier: identifier ?? GcsObjectStorageHelper.name,
+    });
+
+    this.client = new Storage(storageOptions);
+    this.bucket = this.client.bucket(bucket);
+  }
+
+  // ---------------------------------------------------------------------
+  protected toMetadata(opts: { key: string; file: File }): IObjectMetadata {
+    const { key, file } = opts;
+    const { size, contentType, etag, updated, metadata = {} } = file.metadata;
+
+    return {
+      key,
+      size: Number(size ?? 0),
+      contentType,
+      etag,
+      lastModified: updated ? new Date(updated) : undefined,
+      metadata: Object.fromEntries(
+        Object.entries(metadata).map(([name, value]) => [name, `${value ?? ''}`]),
+      ),
+    };
+  }
+
+  protected isNotFound(error: unknown) {
+    return (error as { code?: number })?.code === 404;
+  }
+
+  // ---------------------------------------------------------------------
+  async put(opts: IPutObjectOptions): Promise<IObjectMetadata> {
+    const { key, body, contentType, metadata = {} } = opts;
+    this.validateKey({ key, method: this.put.name });
+
+    const file = this.bucket.file(key);
+    const writable = file.createWriteStream({
+      resumable: false,
+      contentType: contentType ?? AbstractObjectStorageHelper.DEFAULT_CONTENT_TYPE,
+      metadata: { metadata },
+    });
+
+    await pipeline(this.toReadable(body), writable);
+    await file.getMetadata();
+    return this.toMetadata({ key, file });
+  }
+
+  // ---------------------------------------------------------------------
+  async head(opts: { key: string }): Promise<IObjectMetadata | null> {
+    const { key } = opts;
+    this.validateKey({ key, method: this.head.name });
+
+    const file = this.bucket.file(key);
+    try {
+      await file.getMetadata();
+    } catch (error) {
+      if (this.isNotFound(error)) {
+        return null;
+      }
+
+      throw error;
+    }
+
+    return this.toMetadata({ key, file });
+  }
+
+  // ---------------------------------------------------------------------
+  async get(opts: { key: string }): Promise<IGetObjectResult> {
+    const { key } = opts;
+
+    const metadata = await this.head({ key });
+    if (!metadata) {
+      throw this.getNotFoundError({ key, method: this.get.name });
+    }
+
+    return { ...metadata, body: this.bucket.file(key).createReadStream() };
+  }
+
+  // ---------------------------------------------------------------------
+  async delete(opts: { key: string }) {
+    const { key } = opts;
+    this.validateKey({ key, method: this.delete.name });
+
+    await this.bucket.file(key).delete({ ignoreNotFound: true });
+  }
+
+  // ---------------------------------------------------------------------
+  async list(opts?: IListObjectsQuery): Promise<IListObjectsResult> {
+    const { prefix, cursor, limit = 1_000 } = opts ?? {};
+
+    const [files, nextQuery] = await this.bucket.getFiles({
+      prefix,
+      pageToken: cursor,
+      maxResults: limit,
+      autoPaginate: false,
+    });
+
+    return {
+      objects: files.map(file => {
+        const { key, size, contentType, etag, lastModified } = this.toMetadata({
+          key: file.name,
+          file,
+        });
+        return { key, size, contentType, etag, lastModified };
+      }),
+      nextCursor: (nextQuery as { pageToken?: string } | null)?.pageToken,
+    };
+  }
+
+  // ---------------------------------------------------------------------
+  async getSignedUrl(opts: { key: string; expiresIn?: number }): Promise<string> {
+    const { key, expiresIn = AbstractObjectStorageHelper.SIGNED_URL_EXPIRES_IN } = opts;
+    this.validateKey({ key, method: this.getSignedUrl.name });
+
+    const [url] = await this.bucket.file(key).getSignedUrl({
+      version: 'v4',
+      action: 'read',
+      expires: Date.now() + expiresIn * 1_000,
+    });
+    return url;
+  }
+}
@@ -1,4 +1,5 @@
 export * from './base';
 export * from './disk';
 export * from './in-memory';
+export * from './object';
 export * from './types';
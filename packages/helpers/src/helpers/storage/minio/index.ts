@@ -1 +1,2 @@
 export * from './helper';
+export * from './object-storage.helper';
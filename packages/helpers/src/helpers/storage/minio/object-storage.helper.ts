@@ -0,0 +1,392 @@
+import { getError } from '@/helpers/error';
+import { Client, ClientOptions } from 'minio';
+import { Readable } from 'node:stream';
+import { AbstractObjectStorageHelper } from '../object/base';
+import {
+  IGetObjectResult,
+  IListObjectsQuery,
+  IListObjectsResult,
+  IObjectMetadata,
+  IPutObjectOptions,
+} from '../object/types';
+
+const CONTENT_TYPE_KEY = 'content-type';
+const META_PREFIX = 'x-amz-meta-';
+
+// S3 limits: every part but the last must be at least 5MiB and an upload has at most 10k parts
+const MIN_PART_SIZE = 5 * 1024 * 1024;
+const MAX_PART_COUNT = 10_000;
+
+// ================================================================================
+export interface IMultipartUploadOptions extends Omit<IPutObjectOptions, 'body'> {
+  body: Readable | Buffer;
+  /** Size of each part in bytes (default: 16MiB, minimum: 5MiB) */
+  partSize?: number;
+  /** Number of parts uploaded in parallel (default: 4) */
+  concurrency?: number;
+  /** Resume a previous upload, parts which are already stored are skipped */
+  uploadId?: string;
+  /** Abort the upload (and drop stored parts) when a part fails (default: true) */
+  abortOnFailure?: boolean;
+  onProgress?: (opts: { uploadId: string; part: number; uploadedBytes: number }) => void;
+}
+
+export interface IMultipartUploadResult extends IObjectMetadata {
+  uploadId: string;
+  parts: number;
+}
+
+// ================================================================================
+export interface IS3ObjectStorageOptions extends ClientOptions {
+  bucket: string;
+  scope?: string;
+  identifier?: string;
+}
+
+// ================================================================================
+/**
+ * Object storage on any S3 compatible service (AWS S3, MinIO, Cloudflare R2, ...).
+ */
+export class S3ObjectStorageHelper extends AbstractObjectStorageHelper {
+  client: Client;
+  protected bucket: string;
+
+  constructor(opts: IS3ObjectStorageOptions) {
+    const { bucket, scope, identifier, ...clientOptions } = opts;
+    super({
+      scope: scope ?? S3ObjectStorageHelper.name,
+      identifier: identifier ?? S3ObjectStorageHelper.name,
+    });
+
+    this.bucket = bucket;
+    this.client = new Client(clientOptions);
+  }
+
+  // ---------------------------------------------------------------------
+  protected toMetadata(opts: { key: string; stat: Awaited<ReturnType<Client['statObject']>> }) {
+    const { key, stat } = opts;
+
+    const metadata: Record<string, string> = {};
+    let contentType: string | undefined;
+    for (const [name, value] of Object.entries(stat.metaData ?? {})) {
+      const normalized = name.toLowerCase();
+      if (normalized === CONTENT_TYPE_KEY) {
+        contentType = `${value}`;
+        continue;
+      }
+
+      const metaKey = normalized.startsWith(META_PREFIX)
+        ? normalized.slice(META_PREFIX.length)
+        : normalized;
+      metadata[metaKey] = `${value}`;
+    }
+
+    const rs: IObjectMetadata = {
+      key,
+      size: stat.size,
+      etag: stat.etag,
+      lastModified: stat.lastModified,
+      contentType,
+      metadata,
+    };
+    return rs;
+  }
+
+  protected isNotFound(error: unknown) {
+    const code = (error as { code?: string })?.code;
+    return code === 'NotFound' || code === 'NoSuchKey';
+  }
+
+  // ---------------------------------------------------------------------
+  async put(opts: IPutObjectOptions): Promise<IObjectMetadata> {
+    const { key, body, contentType, contentLength, metadata = {} } = opts;
+    this.validateKey({ key, method: this.put.name });
+
+    const size = contentLength ?? this.getBodyLength(body);
+    await this.client.putObject(this.bucket, key, this.toReadable(body), size, {
+      ...metadata,
+      [CONTENT_TYPE_KEY]: contentType ?? AbstractObjectStorageHelper.DEFAULT_CONTENT_TYPE,
+    });
+
+    const rs = await this.head({ key });
+    if (!rs) {
+      throw this.getNotFoundError({ key, method: this.put.name });
+    }
+
+    return rs;
+  }
+
+  // ---------------------------------------------------------------------
+  // An empty body is a single empty part, an upload can not be completed without parts
+  protected async *readParts(opts: { body: Readable | Buffer; partSize: number }) {
+    const { body, partSize } = opts;
+
+    if (Buffer.isBuffer(body)) {
+      for (let offset = 0; offset < body.length; offset += partSize) {
+        yield body.subarray(offset, offset + partSize);
+      }
+
+      if (!body.length) {
+        yield body;
+      }
+      return;
+    }
+
+    let chunks: Array<Buffer> = [];
+    let size = 0;
+    let parts = 0;
+    for await (const chunk of body) {
+      let buffer: Buffer = Buffer.isBuffer(chunk) ? chunk : Buffer.from(chunk);
+
+      while (size + buffer.length >= partSize) {
+        const take = partSize - size;
+        chunks.push(buffer.subarray(0, take));
+        yield Buffer.concat(chunks);
+        parts++;
+
+        buffer = buffer.subarray(take);
+        chunks = [];
+        size = 0;
+      }
+
+      if (buffer.length) {
+        chunks.push(buffer);
+        size += buffer.length;
+      }
+    }
+
+    if (size || !parts) {
+      yield Buffer.concat(chunks);
+    }
+  }
+
+  // ---------------------------------------------------------------------
+  /**
+   * Upload a large object in parts.
+   *
+   * Parts are read sequentially from the body and uploaded with bounded parallelism, so at most
+   * `concurrency * partSize` bytes are buffered. When `uploadId` is given, the stored part list
+   * is fetched first and parts with the same number and size are not uploaded again, the body
+   * must therefore be replayed from its beginning.
+   */
+  async putMultipart(opts: IMultipartUploadOptions): Promise<IMultipartUploadResult> {
+    const {
+      key,
+      body,
+      contentType,
+      metadata = {},
+      partSize = 16 * 1024 * 1024,
+      concurrency = 4,
+      abortOnFailure = true,
+      onProgress,
+    } = opts;
+    this.validateKey({ key, method: this.putMultipart.name });
+
+    if (partSize < MIN_PART_SIZE) {
+      throw getError({
+        statusCode: 400,
+        message: `[putMultipart] Invalid part size | partSize: ${partSize} | min: ${MIN_PART_SIZE}`,
+      });
+    }
+
+    const logger = this.logger.for(this.putMultipart.name);
+    const uploadId =
+      opts.uploadId ??
+      (await this.client.initiateNewMultipartUpload(this.bucket, key, {
+        ...metadata,
+        [CONTENT_TYPE_KEY]: contentType ?? AbstractObjectStorageHelper.DEFAULT_CONTENT_TYPE,
+      }));
+
+    const storedParts = new Map<number, { etag: string; size: number }>();
+    if (opts.uploadId) {
+      const parts = await this.client.listParts(this.bucket, key, uploadId);
+      for (const part of parts) {
+        storedParts.set(part.part, { etag: part.etag, size: part.size });
+      }
+
+      logger.info('Resume multipart upload | key: %s | storedParts: %d', key, storedParts.size);
+    }
+
+    const etags: Array<{ part: number; etag: string }> = [];
+    const inflight = new Set<Promise<void>>();
+    let uploadedBytes = 0;
+    let failure: unknown;
+    let partNumber = 0;
+
+    try {
+      for await (const payload of this.readParts({ body, partSize })) {
+        if (failure) {
+          break;
+        }
+
+        partNumber++;
+        if (partNumber > MAX_PART_COUNT) {
+          throw getError({
+            statusCode: 400,
+            message: `[putMultipart] Too many parts | max: ${MAX_PART_COUNT} | partSize: ${partSize}`,
+          });
+        }
+
+        const part = partNumber;
+        const stored = storedParts.get(part);
+        if (stored && stored.size === payload.length) {
+          etags.push({ part, etag: stored.etag });
+          uploadedBytes += payload.length;
+          continue;
+        }
+
+        const task = this.client
+          .uploadPart(
+            {
+              bucketName: this.bucket,
+              objectName: key,
+              uploadID: uploadId,
+              partNumber: part,
+              headers: {},
+            },
+            payload,
+          )
+          .then(rs => {
+            etags.push({ part, etag: rs.etag });
+            uploadedBytes += payload.length;
+            onProgress?.({ uploadId, part, uploadedBytes });
+          })
+          .catch(error => {
+            failure = failure ?? error;
+          })
+          .finally(() => inflight.delete(task));
+        inflight.add(task);
+
+        if (inflight.size >= concurrency) {
+          await Promise.race(inflight);
+        }
+      }
+
+      await Promise.all(inflight);
+      if (failure) {
+        throw failure;
+      }
+
+      etags.sort((a, b) => a.part - b.part);
+      await this.client.completeMultipartUpload(this.bucket, key, uploadId, etags);
+    } catch (error) {
+      await Promise.allSettled(inflight);
+
+      logger.error(
+        'Multipart upload FAILED | key: %s | uploadId: %s | abort: %s | error: %s',
+        key,
+        uploadId,
+        abortOnFailure,
+        error,
+      );
+
+      if (abortOnFailure) {
+        await this.abortMultipart({ key, uploadId }).catch(() => {});
+      }
+
+      throw error;
+    }
+
+    logger.info('Multipart upload COMPLETED | key: %s | parts: %d', key, etags.length);
+
+    const rs = await this.head({ key });
+    if (!rs) {
+      throw this.getNotFoundError({ key, method: this.putMultipart.name });
+    }
+
+    return { ...rs, uploadId, parts: etags.length };
+  }
+
+  abortMultipart(opts: { key: string; uploadId: string }) {
+    const { key, uploadId } = opts;
+    return this.client.abortMultipartUpload(this.bucket, key, uploadId);
+  }
+
+  findMultipartUploadId(opts: { key: string }) {
+    return this.client.findUploadId(this.bucket, opts.key);
+  }
+
+  // ---------------------------------------------------------------------
+  async head(opts: { key: string }): Promise<IObjectMetadata | null> {
+    const { key } = opts;
+    this.validateKey({ key, method: this.head.name });
+
+    try {
+      const stat = await this.client.statObject(this.bucket, key);
+      return this.toMetadata({ key, stat });
+    } catch (error) {
+      if (this.isNotFound(error)) {
+        return null;
+      }
+
+      throw error;
+    }
+  }
+
+  // ---------------------------------------------------------------------
+  async get(opts: { key: string }): Promise<IGetObjectResult> {
+    const { key } = opts;
+
+    const metadata = await this.head({ key });
+    if (!metadata) {
+      throw this.getNotFoundError({ key, method: this.get.name });
+    }
+
+    const body = await this.client.getObject(this.bucket, key);
+    return { ...metadata, body };
+  }
+
+  // ---------------------------------------------------------------------
+  async delete(opts: { key: string }) {
+    const { key } = opts;
+    this.validateKey({ key, method: this.delete.name });
+
+    await this.client.removeObject(this.bucket, key);
+  }
+
+  // ---------------------------------------------------------------------
+  list(opts?: IListObjectsQuery): Promise<IListObjectsResult> {
+    const { prefix = '', cursor, limit = 1_000 } = opts ?? {};
+
+    return new Promise((resolve, reject) => {
+      const objects: IListObjectsResult['objects'] = [];
+      let hasMore = false;
+
+      const stream = this.client.listObjectsV2(this.bucket, prefix, true, cursor ?? '');
+      stream.on('data', obj => {
+        if (!obj.name) {
+          return;
+        }
+
+        if (objects.length >= limit) {
+          hasMore = true;
+          stream.destroy();
+          resolve({ objects, nextCursor: objects[objects.length - 1]?.key });
+          return;
+        }
+
+        objects.push({
+          key: obj.name,
+          size: obj.size,
+          etag: obj.etag,
+          lastModified: obj.lastModified,
+        });
+      });
+
+      stream.on('end', () => {
+        if (!hasMore) {
+          resolve({ objects });
+        }
+      });
+      stream.on('error', error => reject(error));
+    });
+  }
+
+  // ---------------------------------------------------------------------
+  async getSignedUrl(opts: { key: string; expiresIn?: number }): Promise<string> {
+    const { key, expiresIn = AbstractObjectStorageHelper.SIGNED_URL_EXPIRES_IN } = opts;
+    this.validateKey({ key, method: this.getSignedUrl.name });
+
+    return this.client.presignedGetObject(this.bucket, key, expiresIn);
+  }
+}
@@ -0,0 +1,99 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { Readable } from 'node:stream';
+import {
+  IGetObjectResult,
+  IListObjectsQuery,
+  IListObjectsResult,
+  IObjectMetadata,
+  IObjectStorage,
+  IPutObjectOptions,
+  TObjectBody,
+} from './types';
+
+// -------------------------------------------------------------------------
+export abstract class AbstractObjectStorageHelper extends BaseHelper implements IObjectStorage {
+  static readonly DEFAULT_CONTENT_TYPE = 'application/octet-stream';
+  static readonly MAX_KEY_LENGTH = 1024;
+  // Seconds a signed url stays valid
+  static readonly SIGNED_URL_EXPIRES_IN = 15 * 60;
+
+  constructor(opts: { scope: string; identifier: string }) {
+    super(opts);
+  }
+
+  // -------------------------------------------------------------------------
+  /**
+   * Object keys are slash separated paths: no empty, `.` or `..` segments and no control
+   * characters, so the same key is valid for every backend (including the local filesystem).
+   */
+  isValidKey(key: string) {
+    if (typeof key !== 'string' || !key.length) {
+      return false;
+    }
+
+    if (key.length > AbstractObjectStorageHelper.MAX_KEY_LENGTH) {
+      return false;
+    }
+
+    if (key.startsWith('/') || key.includes('\\') || /[\0\r\n]/.test(key)) {
+      return false;
+    }
+
+    return key.split('/').every(segment => segment.length && segment !== '.' && segment !== '..');
+  }
+
+  protected validateKey(opts: { key: string; method: string }) {
+    const { key, method } = opts;
+    if (this.isValidKey(key)) {
+      return;
+    }
+
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+      message: `[${method}] Invalid object key | key: ${key}`,
+    });
+  }
+
+  protected getNotFoundError(opts: { key: string; method: string }) {
+    return getError({
+      statusCode: HTTP.ResultCodes.RS_4.NotFound,
+      message: `[${opts.method}] Object not found | key: ${opts.key}`,
+    });
+  }
+
+  // -------------------------------------------------------------------------
+  protected toReadable(body: TObjectBody): Readable {
+    if (body instanceof Readable) {
+      return body;
+    }
+
+    return Readable.from([typeof body === 'string' ? Buffer.from(body) : body]);
+  }
+
+  protected getBodyLength(body: TObjectBody): number | undefined {
+    if (typeof body === 'string') {
+      return Buffer.byteLength(body);
+    }
+
+    if (body instanceof Readable) {
+      return undefined;
+    }
+
+    return body.byteLength;
+  }
+
+  // -------------------------------------------------------------------------
+  async exists(opts: { key: string }) {
+    const metadata = await this.head(opts);
+    return !!metadata;
+  }
+
+  abstract put(opts: IPutObjectOptions): Promise<IObjectMetadata>;
+  abstract get(opts: { key: string }): Promise<IGetObjectResult>;
+  abstract head(opts: { key: string }): Promise<IObjectMetadata | null>;
+  abstract delete(opts: { key: string }): Promise<void>;
+  abstract list(opts?: IListObjectsQuery): Promise<IListObjectsResult>;
+  abstract getSignedUrl(opts: { key: string; expiresIn?: number }): Promise<string>;
+}
@@ -0,0 +1,3 @@
+export * from './base';
+export * from './local.helper';
+export * from './types';
@@ -0,0 +1,255 @@
+import { HTTP } from '@/common/constants';
+import { SignedUrlHelper } from '@/helpers/auth/signed-url';
+import { getError } from '@/helpers/error';
+import C from 'node:crypto';
+import fs from 'node:fs';
+import fsp from 'node:fs/promises';
+import path from 'node:path';
+import { pipeline } from 'node:stream/promises';
+import { AbstractObjectStorageHelper } from './base';
+import {
+  IGetObjectResult,
+  IListObjectsQuery,
+  IListObjectsResult,
+  IObjectMetadata,
+  IPutObjectOptions,
+} from './types';
+
+const METADATA_FOLDER = '.metadata';
+const SIGNED_URL_BASE = '/objects';
+
+// Resolves relative signed urls
+const RELATIVE_BASE = 'http://local-object-storage.local';
+
+interface IStoredMetadata {
+  contentType?: string;
+  etag?: string;
+  metadata: Record<string, string>;
+}
+
+// ================================================================================
+export interface ILocalObjectStorageOptions {
+  basePath: string;
+  // Signs the urls of `getSignedUrl`, which throws without it
+  signer?: SignedUrlHelper;
+  // Url the objects are served under, e.g. by a download route of the app (default: `/objects`)
+  signedUrlBase?: string;
+  scope?: string;
+  identifier?: string;
+}
+
+// ================================================================================
+/**
+ * Filesystem backed object storage for local development and tests.
+ *
+ * Objects are written under `basePath/<key>` and their metadata is kept in a sidecar JSON file
+ * under `basePath/.metadata/<key>.json`. Signed urls point at `signedUrlBase/<key>`, the route
+ * serving them checks them with `verifySignedUrl`.
+ */
+export class LocalObjectStorageHelper extends AbstractObjectStorageHelper {
+  private basePath: string;
+  private signer?: SignedUrlHelper;
+  private signedUrlBase: string;
+
+  constructor(opts: ILocalObjectStorageOptions) {
+    super({
+      scope: opts.scope ?? LocalObjectStorageHelper.name,
+      identifier: opts.identifier ?? LocalObjectStorageHelper.name,
+    });
+
+    this.basePath = path.resolve(opts.basePath);
+    this.signer = opts.signer;
+    this.signedUrlBase = (opts.signedUrlBase ?? SIGNED_URL_BASE).replace(/\/+$/, '');
+    if (!fs.existsSync(this.basePath)) {
+      fs.mkdirSync(this.basePath, { recursive: true });
+    }
+  }
+
+  // ---------------------------------------------------------------------
+  override isValidKey(key: string) {
+    if (!super.isValidKey(key)) {
+      return false;
+    }
+
+    return key.split('/')[0] !== METADATA_FOLDER && !key.endsWith('.tmp');
+  }
+
+  private getObjectPath(key: string) {
+    return path.join(this.basePath, key);
+  }
+
+  private getMetadataPath(key: string) {
+    return path.join(this.basePath, METADATA_FOLDER, `${key}.json`);
+  }
+
+  private async readMetadata(key: string): Promise<IStoredMetadata> {
+    try {
+      const raw = await fsp.readFile(this.getMetadataPath(key), 'utf-8');
+      return JSON.parse(raw);
+    } catch {
+      return { metadata: {} };
+    }
+  }
+
+  // ---------------------------------------------------------------------
+  async put(opts: IPutObjectOptions): Promise<IObjectMetadata> {
+    const { key, body, contentType, metadata = {} } = opts;
+    this.validateKey({ key, method: this.put.name });
+
+    const objectPath = this.getObjectPath(key);
+    const tmpPath = `${objectPath}.${C.randomUUID()}.tmp`;
+    await fsp.mkdir(path.dirname(objectPath), { recursive: true });
+
+    const hash = C.createHash('md5');
+    const source = this.toReadable(body);
+    source.on('data', chunk => hash.update(chunk));
+
+    // Write to a temporary file first so readers never observe a partially written object
+    try {
+      await pipeline(source, fs.createWriteStream(tmpPath));
+      await fsp.rename(tmpPath, objectPath);
+    } catch (error) {
+      await fsp.rm(tmpPath, { force: true });
+      throw error;
+    }
+
+    const stored: IStoredMetadata = {
+      contentType: contentType ?? AbstractObjectStorageHelper.DEFAULT_CONTENT_TYPE,
+      etag: hash.digest('hex'),
+      metadata,
+    };
+
+    const metadataPath = this.getMetadataPath(key);
+    await fsp.mkdir(path.dirname(metadataPath), { recursive: true });
+    await fsp.writeFile(metadataPath, JSON.stringify(stored));
+
+    const stat = await fsp.stat(objectPath);
+    this.logger.for(this.put.name).debug('Stored object | key: %s | size: %d', key, stat.size);
+
+    return { key, size: stat.size, lastModified: stat.mtime, ...stored };
+  }
+
+  // ---------------------------------------------------------------------
+  async head(opts: { key: string }): Promise<IObjectMetadata | null> {
+    const { key } = opts;
+    this.validateKey({ key, method: this.head.name });
+
+    let stat: fs.Stats;
+    try {
+      stat = await fsp.stat(this.getObjectPath(key));
+    } catch {
+      return null;
+    }
+
+    if (!stat.isFile()) {
+      return null;
+    }
+
+    const stored = await this.readMetadata(key);
+    return { key, size: stat.size, lastModified: stat.mtime, ...stored };
+  }
+
+  // ---------------------------------------------------------------------
+  async get(opts: { key: string }): Promise<IGetObjectResult> {
+    const { key } = opts;
+
+    const metadata = await this.head({ key });
+    if (!metadata) {
+      throw this.getNotFoundError({ key, method: this.get.name });
+    }
+
+    return { ...metadata, body: fs.createReadStream(this.getObjectPath(key)) };
+  }
+
+  // ---------------------------------------------------------------------
+  async delete(opts: { key: string }) {
+    const { key } = opts;
+    this.validateKey({ key, method: this.delete.name });
+
+    await fsp.rm(this.getObjectPath(key), { force: true });
+    await fsp.rm(this.getMetadataPath(key), { force: true });
+  }
+
+  // ---------------------------------------------------------------------
+  async list(opts?: IListObjectsQuery): Promise<IListObjectsResult> {
+    const { prefix = '', cursor, limit = 1_000 } = opts ?? {};
+
+    const keys: Array<string> = [];
+    const walk = async (folder: string) => {
+      const entries = await fsp.readdir(folder, { withFileTypes: true });
+      for (const entry of entries) {
+        const entryPath = path.join(folder, entry.name);
+        const key = path.relative(this.basePath, entryPath).split(path.sep).join('/');
+
+        if (entry.isDirectory()) {
+          if (key !== METADATA_FOLDER) {
+            await walk(entryPath);
+          }
+          continue;
+        }
+
+        if (entry.isFile() && key.startsWith(prefix) && !key.endsWith('.tmp')) {
+          keys.push(key);
+        }
+      }
+    };
+
+    await walk(this.basePath);
+    keys.sort();
+
+    const startIndex = cursor ? keys.findIndex(key => key > cursor) : 0;
+    const pageKeys = startIndex < 0 ? [] : keys.slice(startIndex, startIndex + limit);
+
+    const objects: IListObjectsResult['objects'] = [];
+    for (const key of pageKeys) {
+      const stat = await fsp.stat(this.getObjectPath(key));
+      const { contentType, etag } = await this.readMetadata(key);
+      objects.push({ key, size: stat.size, lastModified: stat.mtime, contentType, etag });
+    }
+
+    const lastKey = pageKeys[pageKeys.length - 1];
+    const hasMore = startIndex >= 0 && startIndex + limit < keys.length;
+    return { objects, nextCursor: hasMore ? lastKey : undefined };
+  }
+
+  // ---------------------------------------------------------------------
+  private getSigner(method: string) {
+    if (!this.signer) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[${method}] Signed urls need a signer | basePath: ${this.basePath}`,
+      });
+    }
+
+    return this.signer;
+  }
+
+  async getSignedUrl(opts: { key: string; expiresIn?: number }): Promise<string> {
+    const { key, expiresIn = AbstractObjectStorageHelper.SIGNED_URL_EXPIRES_IN } = opts;
+    this.validateKey({ key, method: this.getSignedUrl.name });
+
+    const pathname = key.split('/').map(encodeURIComponent).join('/');
+    return this.getSigner(this.getSignedUrl.name).sign({
+      url: `${this.signedUrlBase}/${pathname}`,
+      expiresIn,
+    });
+  }
+
+  /**
+   * @returns the key of a signed url minted by `getSignedUrl`
+   * @throws when the url is forged, expired or not under `signedUrlBase`
+   */
+  verifySignedUrl(opts: { url: string; now?: number }): { key: string; expiresAt: number } {
+    const { url, now } = opts;
+    const { expiresAt } = this.getSigner(this.verifySignedUrl.name).verify({ url, now });
+
+    const prefix = `${new URL(this.signedUrlBase, RELATIVE_BASE).pathname.replace(/\/+$/, '')}/`;
+    const { pathname } = new URL(url, RELATIVE_BASE);
+    const key = pathname.startsWith(prefix)
+      ? pathname.slice(prefix.length).split('/').map(decodeURIComponent).join('/')
+      : '';
+    this.validateKey({ key, method: this.verifySignedUrl.name });
+
+    return { key, expiresAt };
+  }
+}
@@ -0,0 +1,53 @@
+import { Readable } from 'node:stream';
+
+export type TObjectBody = Buffer | Uint8Array | string | Readable;
+
+// -------------------------------------------------------------------------
+export interface IObjectMetadata {
+  key: string;
+  size: number;
+  contentType?: string;
+  etag?: string;
+  lastModified?: Date;
+  metadata: Record<string, string>;
+}
+
+// -------------------------------------------------------------------------
+export interface IPutObjectOptions {
+  key: string;
+  body: TObjectBody;
+  contentType?: string;
+  /** Required by some backends to stream without buffering the body */
+  contentLength?: number;
+  metadata?: Record<string, string>;
+}
+
+// -------------------------------------------------------------------------
+export interface IGetObjectResult extends IObjectMetadata {
+  body: Readable;
+}
+
+// -------------------------------------------------------------------------
+export interface IListObjectsQuery {
+  prefix?: string;
+  /** Opaque cursor returned by the previous page */
+  cursor?: string;
+  limit?: number;
+}
+
+export interface IListObjectsResult {
+  objects: Array<Omit<IObjectMetadata, 'metadata'>>;
+  nextCursor?: string;
+}
+
+// -------------------------------------------------------------------------
+export interface IObjectStorage {
+  put(opts: IPutObjectOptions): Promise<IObjectMetadata>;
+  get(opts: { key: string }): Promise<IGetObjectResult>;
+  head(opts: { key: string }): Promise<IObjectMetadata | null>;
+  exists(opts: { key: string }): Promise<boolean>;
+  delete(opts: { key: string }): Promise<void>;
+  list(opts?: IListObjectsQuery): Promise<IListObjectsResult>;
+  /** Time limited download url of an object, `expiresIn` in seconds */
+  getSignedUrl(opts: { key: string; expiresIn?: number }): Promise<string>;
+}
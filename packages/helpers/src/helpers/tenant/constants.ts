@@ -0,0 +1,4 @@
+// --------------------------------------------------------
+export class TenantErrorCodes {
+  static readonly TENANT_REQUIRED = 'TENANT_REQUIRED';
+}
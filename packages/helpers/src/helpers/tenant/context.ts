@@ -0,0 +1,57 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import { RequestContextStorage } from '@/helpers/request-context';
+import C from 'node:crypto';
+import { TenantErrorCodes } from './constants';
+
+// --------------------------------------------------------
+/**
+ * Task local access to the tenant of the current request.
+ *
+ * The tenant id lives in the `RequestContextStorage` scope, so it is forwarded as
+ * `x-tenant-id` by every fetcher without extra wiring. Use `run` to scope background
+ * work (cron jobs, queue consumers) to a tenant.
+ *
+ * @example
+ * ```typescript
+ * const tenantId = TenantContext.getOrThrow();
+ *
+ * await TenantContext.run({ tenantId: job.data.tenantId, task: () => syncInvoices(job) });
+ * ```
+ */
+export class TenantContext {
+  static get(): string | undefined {
+    return RequestContextStorage.get()?.tenantId;
+  }
+
+  static getOrThrow(): string {
+    const tenantId = TenantContext.get();
+    if (!tenantId) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: TenantErrorCodes.TENANT_REQUIRED,
+        message: '[TenantContext] No tenant bound to the current context!',
+      });
+    }
+
+    return tenantId;
+  }
+
+  /**
+   * Run `task` with `tenantId`, keeping the request id of the enclosing scope if any.
+   */
+  static run<T>(opts: { tenantId: string; task: () => T }): T {
+    const { tenantId, task } = opts;
+    const current = RequestContextStorage.get();
+
+    return RequestContextStorage.run({
+      context: {
+        ...current,
+        requestId: current?.requestId ?? C.randomUUID(),
+        extra: { ...current?.extra },
+        tenantId,
+      },
+      task,
+    });
+  }
+}
@@ -0,0 +1,3 @@
+export * from './constants';
+export * from './context';
+export * from './resource-pool';
@@ -0,0 +1,120 @@
+import { HTTP } from '@/common/constants';
+import { ValueOrPromise } from '@/common/types';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { TenantContext } from './context';
+
+export interface ITenantResourcePoolOptions<T> {
+  identifier?: string;
+
+  // Build the resource of a tenant, e.g. a datasource bound to its schema or database
+  factory: (opts: { tenantId: string }) => ValueOrPromise<T>;
+  // Release a resource when it is evicted or the pool is disposed
+  dispose?: (opts: { tenantId: string; resource: T }) => ValueOrPromise<void>;
+  // Least recently used resources are disposed once exceeded
+  maxSize?: number;
+}
+
+// --------------------------------------------------------
+/**
+ * Lazily creates and caches one resource per tenant, the hook point for per tenant
+ * connections or schemas. Concurrent lookups of the same tenant share a single `factory` call.
+ *
+ * @example
+ * ```typescript
+ * const tenantDataSources = new TenantResourcePool({
+ *   factory: ({ tenantId }) => new PostgresDataSource({ schema: `tenant_${tenantId}`, ... }),
+ *   dispose: ({ resource }) => resource.disconnect(),
+ *   maxSize: 50,
+ * });
+ *
+ * // resolved from TenantContext
+ * const dataSource = await tenantDataSources.get();
+ * ```
+ */
+export class TenantResourcePool<T> extends BaseHelper {
+  private resources = new Map<string, Promise<T>>();
+  private factory: ITenantResourcePoolOptions<T>['factory'];
+  private disposer?: ITenantResourcePoolOptions<T>['dispose'];
+  private maxSize: number;
+
+  constructor(opts: ITenantResourcePoolOptions<T>) {
+    super({
+      scope: TenantResourcePool.name,
+      identifier: opts.identifier ?? TenantResourcePool.name,
+    });
+
+    this.factory = opts.factory;
+    this.disposer = opts.dispose;
+    this.maxSize = opts.maxSize ?? Infinity;
+
+    if (!(this.maxSize > 0)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[TenantResourcePool] Invalid maxSize | maxSize: ${opts.maxSize}`,
+      });
+    }
+  }
+
+  // --------------------------------------------------------
+  get(opts: { tenantId?: string } = {}): Promise<T> {
+    const tenantId = opts.tenantId ?? TenantContext.getOrThrow();
+
+    const cached = this.resources.get(tenantId);
+    if (cached) {
+      // Re-insert to mark as most recently used
+      this.resources.delete(tenantId);
+      this.resources.set(tenantId, cached);
+      return cached;
+    }
+
+    const created = Promise.resolve()
+      .then(() => this.factory({ tenantId }))
+      .catch(error => {
+        this.resources.delete(tenantId);
+        throw error;
+      });
+
+    this.resources.set(tenantId, created);
+    this.evict();
+
+    return created;
+  }
+
+  private evict() {
+    while (this.resources.size > this.maxSize) {
+      const [tenantId] = this.resources.keys();
+      void this.evictTenant({ tenantId });
+    }
+  }
+
+  private async evictTenant(opts: { tenantId: string }) {
+    const { tenantId } = opts;
+    const resource = this.resources.get(tenantId);
+    this.resources.delete(tenantId);
+
+    if (!resource || !this.disposer) {
+      return;
+    }
+
+    try {
+      await this.disposer({ tenantId, resource: await resource });
+    } catch (error) {
+      this.logger
+        .for(this.evictTenant.name)
+        .error('Failed to dispose tenant resource | tenantId: %s | error: %s', tenantId, error);
+    }
+  }
+
+  has(opts: { tenantId: string }): boolean {
+    return this.resources.has(opts.tenantId);
+  }
+
+  async delete(opts: { tenantId: string }) {
+    await this.evictTenant(opts);
+  }
+
+  async dispose() {
+    await Promise.all([...this.resources.keys()].map(tenantId => this.evictTenant({ tenantId })));
+  }
+}
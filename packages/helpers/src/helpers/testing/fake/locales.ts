@@ -0,0 +1,98 @@
+export interface IFakeLocale {
+  code: string;
+  // ISO 3166-1 alpha-2, used for the phone calling code
+  region: string;
+  currency: string;
+  firstNames: Array<string>;
+  lastNames: Array<string>;
+  // e.g. `Nguyễn Văn An` instead of `An Nguyễn`
+  isFamilyNameFirst?: boolean;
+  cities: Array<string>;
+  streets: Array<string>;
+  formatStreet: (opts: { number: number; street: string }) => string;
+  // `#` is replaced by a digit, `N` by a non zero digit and `A` by an uppercase letter
+  postalCodePattern: string;
+  // National significant number without the trunk prefix, within ranges reserved for fiction
+  // where the numbering plan has one
+  phonePattern: string;
+}
+
+const words = (value: string) => value.split(' ');
+const phrases = (value: string) => value.split(', ');
+
+// --------------------------------------------------------
+export class FakeLocales {
+  static readonly EN_US: IFakeLocale = {
+    code: 'en-US',
+    region: 'US',
+    currency: 'USD',
+    firstNames: words('James Mary John Patricia Robert Jennifer Michael Linda David Emily Sarah'),
+    lastNames: words('Smith Johnson Williams Brown Jones Garcia Miller Davis Wilson Taylor'),
+    cities: phrases('Springfield, Riverside, Franklin, Greenville, Madison, Georgetown, Salem'),
+    streets: phrases('Main St, Oak Ave, Maple Dr, Cedar Ln, Park Blvd, Elm St, Washington Ave'),
+    formatStreet: ({ number, street }) => `${number} ${street}`,
+    postalCodePattern: 'N####',
+    phonePattern: 'N##55501##',
+  };
+
+  static readonly EN_GB: IFakeLocale = {
+    code: 'en-GB',
+    region: 'GB',
+    currency: 'GBP',
+    firstNames: words('Oliver Amelia George Isla Harry Ava Jack Emily Charlie Sophie Thomas Grace'),
+    lastNames: words('Smith Jones Taylor Brown Williams Wilson Evans Thomas Roberts Walker Wright'),
+    cities: phrases('Oxford, Bristol, Leeds, York, Bath, Cambridge, Norwich, Exeter'),
+    streets: phrases('High Street, Station Road, Church Lane, Victoria Road, Mill Lane, The Green'),
+    formatStreet: ({ number, street }) => `${number} ${street}`,
+    postalCodePattern: 'AN# #AA',
+    phonePattern: '7700900###',
+  };
+
+  static readonly VI_VN: IFakeLocale = {
+    code: 'vi-VN',
+    region: 'VN',
+    currency: 'VND',
+    firstNames: words('An Bình Chi Dũng Giang Hà Hải Hương Khánh Linh Minh Nam Phương Quân Thảo'),
+    lastNames: words('Nguyễn Trần Lê Phạm Hoàng Huỳnh Phan Vũ Võ Đặng Bùi Đỗ Hồ Ngô Dương'),
+    isFamilyNameFirst: true,
+    cities: phrases('Hà Nội, Hồ Chí Minh, Đà Nẵng, Hải Phòng, Cần Thơ, Huế, Nha Trang'),
+    streets: phrases('Lê Lợi, Trần Hưng Đạo, Nguyễn Huệ, Hai Bà Trưng, Lý Thường Kiệt'),
+    formatStreet: ({ number, street }) => `${number} ${street}`,
+    postalCodePattern: 'N#####',
+    phonePattern: '9########',
+  };
+
+  static readonly DE_DE: IFakeLocale = {
+    code: 'de-DE',
+    region: 'DE',
+    currency: 'EUR',
+    firstNames: words('Lukas Mia Leon Emma Finn Hannah Paul Lea Jonas Sophie Felix Marie Elias'),
+    lastNames: words('Müller Schmidt Schneider Fischer Weber Meyer Wagner Becker Hoffmann Koch'),
+    cities: phrases('Berlin, Hamburg, München, Köln, Leipzig, Dresden, Freiburg, Bremen'),
+    streets: phrases('Hauptstraße, Schulstraße, Gartenstraße, Bahnhofstraße, Lindenweg'),
+    formatStreet: ({ number, street }) => `${street} ${number}`,
+    postalCodePattern: 'N####',
+    phonePattern: '15#########',
+  };
+
+  private static readonly LOCALES = new Map<string, IFakeLocale>(
+    [FakeLocales.EN_US, FakeLocales.EN_GB, FakeLocales.VI_VN, FakeLocales.DE_DE].map(
+      locale => [locale.code.toLowerCase(), locale] as const,
+    ),
+  );
+
+  static isValid(code: string): boolean {
+    return this.LOCALES.has(code.toLowerCase());
+  }
+
+  static get(code: string): IFakeLocale | undefined {
+    return this.LOCALES.get(code.toLowerCase());
+  }
+
+  /**
+   * Add or replace a locale, e.g. for a market the built-in data does not cover.
+   */
+  static register(locale: IFakeLocale) {
+    this.LOCALES.set(locale.code.toLowerCase(), locale);
+  }
+}
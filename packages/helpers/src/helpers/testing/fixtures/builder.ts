@@ -0,0 +1,114 @@
+import { AnyObject } from '@/common/types';
+import { ApplicationError, IValidationFieldError, TError, ValidationError } from '@/helpers/error';
+import { INodeFetchRequestOptions } from '@/helpers/network';
+import cloneDeep from 'lodash/cloneDeep';
+import mergeWith from 'lodash/mergeWith';
+
+export type TFixtureOverrides<T> = {
+  [K in keyof T]?: T[K] extends Array<any>
+    ? T[K]
+    : T[K] extends AnyObject
+      ? TFixtureOverrides<T[K]>
+      : T[K];
+};
+
+// Arrays are replaced instead of merged index by index
+const mergeOverrides = <T>(target: T, ...sources: Array<TFixtureOverrides<T> | undefined>): T => {
+  return mergeWith(cloneDeep(target), ...sources, (_: unknown, source: unknown) =>
+    Array.isArray(source) ? [...source] : undefined,
+  );
+};
+
+// --------------------------------------------------------
+/**
+ * Immutable builder producing deep copies of `defaults` with overrides applied on top.
+ *
+ * @example
+ * ```typescript
+ * const configs = new FixtureBuilder<IAppConfigs>({
+ *   defaults: { host: '127.0.0.1', port: 0, path: { base: '/api', isStrict: true } },
+ * });
+ *
+ * const strict = configs.build();
+ * const lenient = configs.with({ path: { isStrict: false } }).build({ port: 3000 });
+ * const apps = configs.buildMany({ count: 3, overrides: index => ({ port: 3000 + index }) });
+ * ```
+ */
+export class FixtureBuilder<T extends AnyObject> {
+  private defaults: T;
+
+  constructor(opts: { defaults: T }) {
+    this.defaults = cloneDeep(opts.defaults);
+  }
+
+  /**
+   * New builder whose defaults include `overrides`, the current builder is left untouched.
+   */
+  with(overrides: TFixtureOverrides<T>) {
+    return new FixtureBuilder<T>({ defaults: mergeOverrides(this.defaults, overrides) });
+  }
+
+  build(overrides?: TFixtureOverrides<T>): T {
+    return mergeOverrides(this.defaults, overrides);
+  }
+
+  buildMany(opts: {
+    count: number;
+    overrides?: (index: number) => TFixtureOverrides<T>;
+  }): Array<T> {
+    const { count, overrides } = opts;
+    return Array.from({ length: count }, (_, index) => this.build(overrides?.(index)));
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * Canned values for the shapes most tests need.
+ */
+export class Fixtures {
+  static readonly DEFAULT_BASE_URL = 'http://127.0.0.1:3000';
+
+  static builder<T extends AnyObject>(defaults: T) {
+    return new FixtureBuilder<T>({ defaults });
+  }
+
+  static requestOptions(
+    overrides?: TFixtureOverrides<INodeFetchRequestOptions>,
+  ): INodeFetchRequestOptions {
+    return mergeOverrides<INodeFetchRequestOptions>(
+      {
+        url: `${Fixtures.DEFAULT_BASE_URL}/api/test`,
+        method: 'get',
+        params: {},
+        headers: { 'content-type': 'application/json' },
+        timeout: 5_000,
+      },
+      overrides,
+    );
+  }
+
+  static applicationError(overrides?: Partial<TError>) {
+    return new ApplicationError({
+      statusCode: 400,
+      messageCode: 'TEST_ERROR',
+      message: 'Test error',
+      ...overrides,
+    });
+  }
+
+  static validationError(
+    opts: { fields?: Array<Partial<IValidationFieldError>>; target?: string } = {},
+  ) {
+    const { fields = [{}], target = 'json' } = opts;
+
+    return new ValidationError({
+      target,
+      fields: fields.map(field => ({
+        path: 'name',
+        code: 'required',
+        message: 'name is required',
+        ...field,
+      })),
+    });
+  }
+}
@@ -0,0 +1,3 @@
+export * from './builder';
+export * from './container';
+export * from './jwt';
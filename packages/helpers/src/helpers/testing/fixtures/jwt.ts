@@ -0,0 +1,129 @@
+import { AnyObject } from '@/common/types';
+import { ISignJWTOptions, JWTAlgorithms, JWTHelper } from '@/helpers/auth';
+import C from 'node:crypto';
+import { MockServer } from '../mock-server';
+
+// --------------------------------------------------------
+/**
+ * RS256 key pair with its JWK set, for tests of code verifying tokens of an identity provider.
+ *
+ * Keys are generated once per instance, `rotate` issues a new `kid` while the JWK set keeps
+ * publishing the previous keys.
+ *
+ * @example
+ * ```typescript
+ * const tokens = new JWTFixtures({ issuer: 'https://auth.test', audience: 'api' });
+ *
+ * const server = await new MockServer().start();
+ * const jwksUrl = tokens.serve({ server });
+ *
+ * const token = tokens.sign({ subject: '42', claims: { roles: ['admin'] } });
+ * const expired = tokens.expired({ subject: '42' });
+ * ```
+ */
+export class JWTFixtures {
+  static readonly DEFAULT_EXPIRES_IN = 5 * 60;
+  static readonly JWKS_PATH = '/.well-known/jwks.json';
+
+  private issuer?: string;
+  private audience?: string | Array<string>;
+  private keys: Array<{ kid: string; privateKey: C.KeyObject; publicKey: C.KeyObject }> = [];
+
+  constructor(opts: { issuer?: string; audience?: string | Array<string> } = {}) {
+    this.issuer = opts.issuer;
+    this.audience = opts.audience;
+    this.rotate();
+  }
+
+  getKeyId() {
+    return this.keys[this.keys.length - 1].kid;
+  }
+
+  /**
+   * Issue a new signing key, tokens signed before keep verifying against the JWK set.
+   */
+  rotate() {
+    const { privateKey, publicKey } = C.generateKeyPairSync('rsa', { modulusLength: 2048 });
+    this.keys.push({ kid: `test-key-${this.keys.length + 1}`, privateKey, publicKey });
+    return this.getKeyId();
+  }
+
+  /**
+   * Helper signing with the current key.
+   */
+  getHelper() {
+    const { kid, privateKey } = this.keys[this.keys.length - 1];
+
+    return new JWTHelper({
+      algorithm: JWTAlgorithms.RS256,
+      privateKey,
+      keyId: kid,
+      issuer: this.issuer,
+      audience: this.audience,
+      expiresIn: JWTFixtures.DEFAULT_EXPIRES_IN,
+    });
+  }
+
+  getJWKS(): { keys: Array<AnyObject> } {
+    return {
+      keys: this.keys.map(({ kid, publicKey }) => ({
+        ...publicKey.export({ format: 'jwk' }),
+        kid,
+        alg: JWTAlgorithms.RS256,
+        use: 'sig',
+      })),
+    };
+  }
+
+  // --------------------------------------------------------
+  sign<TClaims extends AnyObject = AnyObject>(
+    opts: { claims?: TClaims; now?: number } & ISignJWTOptions = {},
+  ) {
+    return this.getHelper().sign(opts);
+  }
+
+  /**
+   * Token which expired `ago` seconds before now.
+   */
+  expired<TClaims extends AnyObject = AnyObject>(
+    opts: { claims?: TClaims; ago?: number } & Omit<ISignJWTOptions, 'expiresIn'> = {},
+  ) {
+    const { ago = 60, ...rest } = opts;
+    const now = Math.floor(Date.now() / 1000) - JWTFixtures.DEFAULT_EXPIRES_IN - ago;
+
+    return this.getHelper().sign({ ...rest, now, expiresIn: JWTFixtures.DEFAULT_EXPIRES_IN });
+  }
+
+  /**
+   * Token with a valid structure signed by a key which is not published in the JWK set.
+   */
+  forged<TClaims extends AnyObject = AnyObject>(opts: { claims?: TClaims } & ISignJWTOptions = {}) {
+    const { privateKey } = C.generateKeyPairSync('rsa', { modulusLength: 2048 });
+
+    return new JWTHelper({
+      algorithm: JWTAlgorithms.RS256,
+      privateKey,
+      keyId: this.getKeyId(),
+      issuer: this.issuer,
+      audience: this.audience,
+      expiresIn: JWTFixtures.DEFAULT_EXPIRES_IN,
+    }).sign(opts);
+  }
+
+  /**
+   * Publish the JWK set on a started mock server.
+   *
+   * @returns url of the JWK set
+   */
+  serve(opts: { server: MockServer; path?: string }) {
+    const { server, path = JWTFixtures.JWKS_PATH } = opts;
+
+    server.when({ method: 'GET', path }).respond(() => ({
+      status: 200,
+      headers: { 'cache-control': 'max-age=60' },
+      json: this.getJWKS(),
+    }));
+
+    return `${server.getBaseUrl()}${path}`;
+  }
+}
@@ -1,6 +1,9 @@
 export * from './base-test-plan';
 export * from './common';
 export * from './describe';
+export * from './fake';
+export * from './fixtures';
+export * from './mock-server';
 export * from './test-case';
 export * from './test-handler';
 export * from './test-plan';
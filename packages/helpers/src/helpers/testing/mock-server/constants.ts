@@ -0,0 +1,22 @@
+import { TConstValue } from '@/common/types';
+
+export class MockServerFaults {
+  // Destroy the connection without a response
+  static readonly CONNECTION_RESET = 'connection_reset';
+  // Never respond, the client times out
+  static readonly NO_RESPONSE = 'no_response';
+  // Send the status line and headers then close mid body
+  static readonly TRUNCATED_BODY = 'truncated_body';
+
+  static readonly SCHEME_SET = new Set([
+    this.CONNECTION_RESET,
+    this.NO_RESPONSE,
+    this.TRUNCATED_BODY,
+  ]);
+
+  static isValid(input: string): boolean {
+    return this.SCHEME_SET.has(input);
+  }
+}
+
+export type TMockServerFault = TConstValue<typeof MockServerFaults>;
@@ -0,0 +1,390 @@
+import { HTTP } from '@/common/constants';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import get from 'lodash/get';
+import isMatch from 'lodash/isMatch';
+import http from 'node:http';
+import { AddressInfo } from 'node:net';
+import { MockServerFaults } from './constants';
+import { IMockRequest, IMockRequestMatcher, IMockResponse, TMockResponder } from './types';
+
+const PLACEHOLDER_PATTERN = /\{\{\s*([\w.-]+)\s*\}\}/g;
+
+const render = (value: unknown, request: IMockRequest): unknown => {
+  if (typeof value === 'string') {
+    return value.replace(PLACEHOLDER_PATTERN, (_, key: string) => String(get(request, key) ?? ''));
+  }
+
+  if (Array.isArray(value)) {
+    return value.map(item => render(item, request));
+  }
+
+  if (value && typeof value === 'object') {
+    return Object.fromEntries(
+      Object.entries(value).map(([key, item]) => [key, render(item, request)]),
+    );
+  }
+
+  return value;
+};
+
+/**
+ * `/orders/:id/*` => `^/orders/(?<id>[^/]+)/.*$`
+ */
+const compilePath = (path: string | RegExp) => {
+  if (path instanceof RegExp) {
+    return path;
+  }
+
+  const source = path
+    .split('/')
+    .map(segment => {
+      if (segment === '*') {
+        return '.*';
+      }
+
+      if (segment.startsWith(':')) {
+        return `(?<${segment.slice(1)}>[^/]+)`;
+      }
+
+      return segment.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+    })
+    .join('/');
+
+  return new RegExp(`^${source}/?$`);
+};
+
+// --------------------------------------------------------
+export class MockExpectation {
+  readonly matcher: IMockRequestMatcher;
+  private pattern: RegExp;
+  private responder: TMockResponder = { status: 200 };
+  private limit?: number;
+  private expected?: number;
+
+  hits = 0;
+
+  constructor(opts: { matcher: IMockRequestMatcher }) {
+    this.matcher = opts.matcher;
+    this.pattern = compilePath(opts.matcher.path);
+  }
+
+  respond(responder: TMockResponder) {
+    this.responder = responder;
+    return this;
+  }
+
+  fault(fault: NonNullable<IMockResponse['fault']>) {
+    this.responder = { fault };
+    return this;
+  }
+
+  /**
+   * Only match the first `count` requests, `verify` fails unless all of them were received.
+   */
+  times(count: number) {
+    this.limit = count;
+    this.expected = count;
+    return this;
+  }
+
+  isExhausted() {
+    return this.limit !== undefined && this.hits >= this.limit;
+  }
+
+  isSatisfied() {
+    return this.expected === undefined || this.hits >= this.expected;
+  }
+
+  match(request: Omit<IMockRequest, 'params'>): Record<string, string> | null {
+    const { method, query, headers, body } = this.matcher;
+
+    if (this.isExhausted()) {
+      return null;
+    }
+
+    if (method && method.toUpperCase() !== request.method) {
+      return null;
+    }
+
+    const matched = this.pattern.exec(request.path);
+    if (!matched) {
+      return null;
+    }
+
+    if (query && !isMatch(request.query, query)) {
+      return null;
+    }
+
+    const lowerHeaders = Object.fromEntries(
+      Object.entries(headers ?? {}).map(([key, value]) => [key.toLowerCase(), value]),
+    );
+    if (headers && !isMatch(request.headers, lowerHeaders)) {
+      return null;
+    }
+
+    if (body !== undefined) {
+      const isBodyMatched =
+        typeof body === 'function'
+          ? body(request.body)
+          : typeof body === 'string'
+            ? body === request.rawBody
+            : !!request.body && typeof request.body === 'object' && isMatch(request.body, body);
+
+      if (!isBodyMatched) {
+        return null;
+      }
+    }
+
+    return { ...matched.groups };
+  }
+
+  async resolve(request: IMockRequest): Promise<IMockResponse> {
+    return typeof this.responder === 'function' ? this.responder(request) : this.responder;
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * Embedded HTTP server for tests, answering with stubbed responses instead of the real
+ * dependency.
+ *
+ * The most recently registered expectation matching a request wins so a test can override the
+ * defaults of its suite. Unmatched requests are answered with a 404 describing the request.
+ * Every request is recorded, `verify` fails when an expectation registered with `times` was
+ * not fully used.
+ *
+ * @example
+ * ```typescript
+ * const server = new MockServer();
+ * await server.start();
+ *
+ * server
+ *   .when({ method: 'GET', path: '/orders/:id' })
+ *   .respond({ status: 200, json: { id: '{{params.id}}', status: 'paid' } });
+ *
+ * server
+ *   .when({ method: 'POST', path: '/payments', body: { currency: 'USD' } })
+ *   .respond({ status: 201, json: { id: 'pay_1' }, delay: { min: 50, max: 200 } })
+ *   .times(1);
+ *
+ * server.when({ path: '/inventory/*' }).fault(MockServerFaults.CONNECTION_RESET);
+ *
+ * const client = new NodeFetchNetworkRequest({
+ *   name: 'OrderRequest',
+ *   networkOptions: { baseUrl: server.getBaseUrl() },
+ * });
+ *
+ * server.verify();
+ * await server.stop();
+ * ```
+ */
+export class MockServer extends BaseHelper {
+  private server?: http.Server;
+  private expectations: Array<MockExpectation> = [];
+  private pendingTimers = new Set<ReturnType<typeof setTimeout>>();
+
+  requests: Array<IMockRequest> = [];
+
+  constructor(opts: { identifier?: string } = {}) {
+    super({ scope: MockServer.name, identifier: opts.identifier ?? MockServer.name });
+  }
+
+  async start(opts: { port?: number; host?: string } = {}) {
+    const { port = 0, host = '127.0.0.1' } = opts;
+
+    this.server = http.createServer((req, res) => {
+      this.handle(req, res).catch(error => {
+        this.logger.for(this.start.name).error('Failed to handle request | Error: %s', error);
+        if (!res.headersSent) {
+          res.writeHead(HTTP.ResultCodes.RS_5.InternalServerError);
+        }
+        res.end();
+      });
+    });
+
+    await new Promise<void>((resolve, reject) => {
+      this.server?.once('error', reject);
+      this.server?.listen(port, host, () => resolve());
+    });
+
+    return this;
+  }
+
+  async stop() {
+    for (const timer of this.pendingTimers) {
+      clearTimeout(timer);
+    }
+    this.pendingTimers.clear();
+
+    const server = this.server;
+    this.server = undefined;
+
+    if (!server) {
+      return;
+    }
+
+    server.closeAllConnections?.();
+    await new Promise<void>(resolve => server.close(() => resolve()));
+  }
+
+  getBaseUrl() {
+    const address = this.server?.address() as AddressInfo | null;
+
+    if (!address) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: '[MockServer] Server is not started',
+      });
+    }
+
+    return `http://${address.address}:${address.port}`;
+  }
+
+  // --------------------------------------------------------
+  when(matcher: IMockRequestMatcher) {
+    const expectation = new MockExpectation({ matcher });
+    this.expectations.push(expectation);
+    return expectation;
+  }
+
+  /**
+   * Drop the expectations and the recorded requests.
+   */
+  reset() {
+    this.expectations = [];
+    this.requests = [];
+  }
+
+  /**
+   * @throws when an expectation registered with `times` did not receive all of its requests
+   */
+  verify() {
+    const unsatisfied = this.expectations.filter(expectation => !expectation.isSatisfied());
+    if (!unsatisfied.length) {
+      return;
+    }
+
+    const details = unsatisfied
+      .map(({ matcher, hits }) => `${matcher.method ?? '*'} ${matcher.path} (hits: ${hits})`)
+      .join(', ');
+
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+      message: `[MockServer] Unsatisfied expectations | ${details}`,
+    });
+  }
+
+  // --------------------------------------------------------
+  private async readRequest(req: http.IncomingMessage): Promise<Omit<IMockRequest, 'params'>> {
+    const chunks: Array<Buffer> = [];
+    for await (const chunk of req) {
+      chunks.push(chunk as Buffer);
+    }
+
+    const url = new URL(req.url ?? '/', 'http://localhost');
+    const rawBody = Buffer.concat(chunks).toString('utf-8');
+    const headers = Object.fromEntries(
+      Object.entries(req.headers).map(([key, value]) => [
+        key,
+        Array.isArray(value) ? value.join(', ') : (value ?? ''),
+      ]),
+    );
+
+    let body: unknown = rawBody;
+    if (rawBody && headers['content-type']?.includes('json')) {
+      try {
+        body = JSON.parse(rawBody);
+      } catch {
+        body = rawBody;
+      }
+    }
+
+    return {
+      method: (req.method ?? 'GET').toUpperCase(),
+      path: url.pathname,
+      query: Object.fromEntries(url.searchParams.entries()),
+      headers,
+      body,
+      rawBody,
+    };
+  }
+
+  private wait(ms: number) {
+    return new Promise<void>(resolve => {
+      const timer = setTimeout(() => {
+        this.pendingTimers.delete(timer);
+        resolve();
+      }, ms);
+      this.pendingTimers.add(timer);
+    });
+  }
+
+  private async handle(req: http.IncomingMessage, res: http.ServerResponse) {
+    const incoming = await this.readRequest(req);
+
+    let request: IMockRequest = { ...incoming, params: {} };
+    let expectation: MockExpectation | undefined;
+
+    for (let i = this.expectations.length - 1; i >= 0; i--) {
+      const params = this.expectations[i].match(incoming);
+      if (params) {
+        expectation = this.expectations[i];
+        request = { ...incoming, params };
+        break;
+      }
+    }
+
+    this.requests.push(request);
+
+    if (!expectation) {
+      res.writeHead(HTTP.ResultCodes.RS_4.NotFound, { 'content-type': 'application/json' });
+      res.end(JSON.stringify({ message: '[MockServer] No expectation matched', request }));
+      return;
+    }
+
+    expectation.hits++;
+    const response = await expectation.resolve(request);
+
+    const { delay } = response;
+    if (delay) {
+      await this.wait(
+        typeof delay === 'number'
+          ? delay
+          : delay.min + Math.floor(Math.random() * (delay.max - delay.min + 1)),
+      );
+    }
+
+    switch (response.fault) {
+      case MockServerFaults.CONNECTION_RESET: {
+        req.socket.destroy();
+        return;
+      }
+      case MockServerFaults.NO_RESPONSE: {
+        // Released by `stop`
+        return;
+      }
+      default: {
+        break;
+      }
+    }
+
+    const isJson = response.json !== undefined;
+    const payload = isJson
+      ? JSON.stringify(render(response.json, request))
+      : String(render(response.body ?? '', request));
+
+    res.writeHead(response.status ?? HTTP.ResultCodes.RS_2.Ok, {
+      ...(isJson ? { 'content-type': 'application/json' } : {}),
+      ...response.headers,
+    });
+
+    if (response.fault === MockServerFaults.TRUNCATED_BODY) {
+      res.write(payload.slice(0, Math.ceil(payload.length / 2)));
+      req.socket.destroy();
+      return;
+    }
+
+    res.end(payload);
+  }
+}
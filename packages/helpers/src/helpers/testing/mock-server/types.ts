@@ -0,0 +1,44 @@
+import { AnyObject, ValueOrPromise } from '@/common/types';
+import { TMockServerFault } from './constants';
+
+export interface IMockRequest {
+  method: string;
+  path: string;
+  // Route parameters of the matched expectation, e.g. `{ id: '42' }` for `/orders/:id`
+  params: Record<string, string>;
+  query: Record<string, string>;
+  // Lower cased names
+  headers: Record<string, string>;
+  // Parsed JSON when the request is JSON, raw text otherwise
+  body: unknown;
+  rawBody: string;
+}
+
+export interface IMockRequestMatcher {
+  // Any method when omitted
+  method?: string;
+  // `/orders/:id`, `/files/*` or a RegExp
+  path: string | RegExp;
+  // Subset of the query the request must contain
+  query?: Record<string, string>;
+  // Subset of the headers the request must contain
+  headers?: Record<string, string>;
+  // Deep subset of a JSON body, exact raw body for strings
+  body?: AnyObject | string | ((body: unknown) => boolean);
+}
+
+export interface IMockResponse {
+  status?: number;
+  headers?: Record<string, string>;
+  // Serialized as JSON, `{{params.id}}` placeholders in strings are rendered from the request
+  json?: unknown;
+  // Sent as is after rendering the placeholders
+  body?: string;
+  // Milliseconds, or a random delay in `[min, max]`
+  delay?: number | { min: number; max: number };
+  fault?: TMockServerFault;
+}
+
+export type TMockResponder =
+  | IMockResponse
+  | ((request: IMockRequest) => ValueOrPromise<IMockResponse>);
@@ -0,0 +1,101 @@
+import { BaseHelper } from '@/helpers/base';
+import path from 'node:path';
+import { Readable } from 'node:stream';
+import { IFileTransfer, IRemoteEntry, IUploadOptions, IUploadResult, TTransferBody } from './types';
+
+// -------------------------------------------------------------------------
+/**
+ * File exchange with a remote server, e.g. the SFTP drop box of a logistics partner.
+ *
+ * Uploads are atomic by default: the body is written to a temporary `.<name>.part` file which
+ * is renamed once complete, so a partner polling the directory never picks up a partial file.
+ * Remote paths are POSIX paths whatever the local platform.
+ */
+export abstract class AbstractFileTransferHelper extends BaseHelper implements IFileTransfer {
+  static readonly TEMP_SUFFIX = '.part';
+
+  constructor(opts: { scope: string; identifier: string }) {
+    super(opts);
+  }
+
+  // -------------------------------------------------------------------------
+  static getTempPath(remotePath: string) {
+    const { dir, base } = path.posix.parse(remotePath);
+    return path.posix.join(dir, `.${base}${AbstractFileTransferHelper.TEMP_SUFFIX}`);
+  }
+
+  protected toReadable(body: TTransferBody): Readable {
+    if (body instanceof Readable) {
+      return body;
+    }
+
+    return Readable.from([typeof body === 'string' ? Buffer.from(body) : body]);
+  }
+
+  protected getBodyLength(body: TTransferBody): number | undefined {
+    if (typeof body === 'string') {
+      return Buffer.byteLength(body);
+    }
+
+    if (body instanceof Readable) {
+      return undefined;
+    }
+
+    return body.byteLength;
+  }
+
+  // -------------------------------------------------------------------------
+  async upload(opts: IUploadOptions): Promise<IUploadResult> {
+    const { path: remotePath, body, atomic = true } = opts;
+    const size = this.getBodyLength(body);
+
+    if (!atomic) {
+      await this.write({ path: remotePath, body });
+      return { path: remotePath, size };
+    }
+
+    const tempPath = opts.tempPath ?? AbstractFileTransferHelper.getTempPath(remotePath);
+    try {
+      await this.write({ path: tempPath, body });
+      await this.rename({ from: tempPath, to: remotePath });
+    } catch (error) {
+      await this.delete({ path: tempPath }).catch(() => {});
+      throw error;
+    }
+
+    this.logger.for(this.upload.name).debug('Uploaded | path: %s | size: %s', remotePath, size);
+    return { path: remotePath, size };
+  }
+
+  async exists(opts: { path: string }) {
+    const entry = await this.stat(opts);
+    return !!entry;
+  }
+
+  /**
+   * Run `fn` on a fresh connection, closed once `fn` settles.
+   */
+  async withConnection<T>(fn: (transfer: this) => Promise<T>): Promise<T> {
+    await this.connect();
+
+    try {
+      return await fn(this);
+    } finally {
+      await this.disconnect();
+    }
+  }
+
+  // Write `body` to `path` as is
+  protected abstract write(opts: { path: string; body: TTransferBody }): Promise<void>;
+
+  abstract connect(): Promise<void>;
+  abstract disconnect(): Promise<void>;
+  abstract list(opts: { path: string }): Promise<Array<IRemoteEntry>>;
+  abstract stat(opts: { path: string }): Promise<IRemoteEntry | null>;
+  abstract download(opts: { path: string }): Promise<Readable>;
+  // Replaces `to` when it exists
+  abstract rename(opts: { from: string; to: string }): Promise<void>;
+  abstract delete(opts: { path: string }): Promise<void>;
+  // Creates the missing parent directories as well
+  abstract mkdir(opts: { path: string }): Promise<void>;
+}
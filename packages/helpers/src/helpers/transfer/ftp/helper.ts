@@ -0,0 +1,132 @@
+import { Client, FileInfo, FileType } from 'basic-ftp';
+import path from 'node:path';
+import { PassThrough, Readable } from 'node:stream';
+import { AbstractFileTransferHelper } from '../base';
+import { IRemoteEntry, RemoteEntryTypes, TRemoteEntryType, TTransferBody } from '../types';
+
+const ENTRY_TYPES: Partial<Record<FileType, TRemoteEntryType>> = {
+  [FileType.File]: RemoteEntryTypes.FILE,
+  [FileType.Directory]: RemoteEntryTypes.DIRECTORY,
+  [FileType.SymbolicLink]: RemoteEntryTypes.SYMLINK,
+};
+
+// ================================================================================
+export interface IFtpTransferOptions {
+  host: string;
+  port?: number;
+  user: string;
+  password: string;
+  /** `true` for explicit FTPS, `implicit` for implicit FTPS (default: false) */
+  secure?: boolean | 'implicit';
+  /** Milliseconds of inactivity before a command fails (default: 30s) */
+  timeout?: number;
+  scope?: string;
+  identifier?: string;
+}
+
+// ================================================================================
+/**
+ * File transfers over FTP or FTPS.
+ *
+ * An FTP connection runs one command at a time, operations are queued and a download holds
+ * the connection until its stream is read to the end.
+ */
+export class FtpTransferHelper extends AbstractFileTransferHelper {
+  client: Client;
+  protected options: IFtpTransferOptions;
+  private queue: Promise<unknown> = Promise.resolve();
+
+  constructor(opts: IFtpTransferOptions) {
+    const { scope, identifier, ...options } = opts;
+    super({
+      scope: scope ?? FtpTransferHelper.name,
+      identifier: identifier ?? `${options.user}@${options.host}`,
+    });
+
+    this.options = options;
+    this.client = new Client(options.timeout ?? 30_000);
+  }
+
+  // -------------------------------------------------------------------------
+  async connect() {
+    const { host, port = 21, user, password, secure = false } = this.options;
+    await this.enqueue(() => this.client.access({ host, port, user, password, secure }));
+  }
+
+  async disconnect() {
+    await this.enqueue(async () => this.client.close());
+  }
+
+  // -------------------------------------------------------------------------
+  async list(opts: { path: string }): Promise<Array<IRemoteEntry>> {
+    const entries = await this.enqueue(() => this.client.list(opts.path));
+    return entries.map(entry => this.toEntry({ dir: opts.path, entry }));
+  }
+
+  async stat(opts: { path: string }): Promise<IRemoteEntry | null> {
+    const { dir, base } = path.posix.parse(opts.path);
+
+    // FTP has no stat command, the entry is read from the listing of its directory
+    const entries = await this.enqueue(() => this.client.list(dir || '/'));
+    const entry = entries.find(item => item.name === base);
+
+    return entry ? this.toEntry({ dir, entry }) : null;
+  }
+
+  async download(opts: { path: string }): Promise<Readable> {
+    const stream = new PassThrough();
+
+    this.enqueue(() => this.client.downloadTo(stream, opts.path))
+      .then(() => {
+        if (!stream.writableEnded) {
+          stream.end();
+        }
+      })
+      .catch(error => stream.destroy(error));
+
+    return stream;
+  }
+
+  async rename(opts: { from: string; to: string }) {
+    await this.enqueue(() => this.client.rename(opts.from, opts.to));
+  }
+
+  async delete(opts: { path: string }) {
+    // Missing files are ignored, like the other backends
+    await this.enqueue(() => this.client.remove(opts.path, true));
+  }
+
+  async mkdir(opts: { path: string }) {
+    await this.enqueue(async () => {
+      // `ensureDir` changes the working directory
+      const cwd = await this.client.pwd();
+      await this.client.ensureDir(opts.path);
+      await this.client.cd(cwd);
+    });
+  }
+
+  // -------------------------------------------------------------------------
+  protected async write(opts: { path: string; body: TTransferBody }) {
+    const { path: remotePath, body } = opts;
+    await this.enqueue(() => this.client.uploadFrom(this.toReadable(body), remotePath));
+  }
+
+  private toEntry(opts: { dir: string; entry: FileInfo }): IRemoteEntry {
+    const { dir, entry } = opts;
+
+    return {
+      name: entry.name,
+      path: path.posix.join(dir, entry.name),
+      type: ENTRY_TYPES[entry.type] ?? RemoteEntryTypes.FILE,
+      size: entry.size,
+      modifiedAt: entry.modifiedAt,
+    };
+  }
+
+  // Commands sent while another one runs are rejected by basic-ftp
+  private enqueue<T>(task: () => Promise<T>): Promise<T> {
+    const rs = this.queue.then(task, task);
+    this.queue = rs.catch(() => {});
+    return rs;
+  }
+}
@@ -0,0 +1,167 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import path from 'node:path';
+import { Readable } from 'node:stream';
+import SftpClient from 'ssh2-sftp-client';
+import { AbstractFileTransferHelper } from '../base';
+import { IRemoteEntry, RemoteEntryTypes, TRemoteEntryType, TTransferBody } from '../types';
+
+const ENTRY_TYPES: Record<string, TRemoteEntryType> = {
+  '-': RemoteEntryTypes.FILE,
+  d: RemoteEntryTypes.DIRECTORY,
+  l: RemoteEntryTypes.SYMLINK,
+};
+
+// ================================================================================
+export interface ISftpTransferOptions {
+  host: string;
+  port?: number;
+  username: string;
+  // Password or private key authentication, one of them is required
+  password?: string;
+  privateKey?: string | Buffer;
+  passphrase?: string;
+  /** Milliseconds to wait for the SSH handshake (default: 20s) */
+  readyTimeout?: number;
+  scope?: string;
+  identifier?: string;
+}
+
+// ================================================================================
+/**
+ * File transfers over SFTP, authenticated with a password or a private key.
+ *
+ * @example
+ * ```typescript
+ * const sftp = new SftpTransferHelper({
+ *   host: 'sftp.partner.example',
+ *   username: 'ignis',
+ *   privateKey: fs.readFileSync('/run/secrets/partner_key'),
+ * });
+ *
+ * await sftp.withConnection(async transfer => {
+ *   await transfer.upload({ path: '/inbound/orders-2024-05-01.csv', body: csv });
+ *
+ *   for (const entry of await transfer.list({ path: '/outbound' })) {
+ *     await pipeline(await transfer.download({ path: entry.path }), createWriteStream(entry.name));
+ *   }
+ * });
+ * ```
+ */
+export class SftpTransferHelper extends AbstractFileTransferHelper {
+  client: SftpClient;
+  protected options: ISftpTransferOptions;
+
+  constructor(opts: ISftpTransferOptions) {
+    const { scope, identifier, ...options } = opts;
+    super({
+      scope: scope ?? SftpTransferHelper.name,
+      identifier: identifier ?? `${options.username}@${options.host}`,
+    });
+
+    if (!options.password && !options.privateKey) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[SftpTransferHelper] Missing password or private key | host: ${options.host}`,
+      });
+    }
+
+    this.options = options;
+    this.client = new SftpClient(this.identifier);
+  }
+
+  // -------------------------------------------------------------------------
+  async connect() {
+    const { host, port = 22, username, password, privateKey, passphrase, readyTimeout } =
+      this.options;
+
+    await this.client.connect({
+      host,
+      port,
+      username,
+      password,
+      privateKey,
+      passphrase,
+      readyTimeout,
+    });
+  }
+
+  async disconnect() {
+    await this.client.end();
+  }
+
+  // -------------------------------------------------------------------------
+  async list(opts: { path: string }): Promise<Array<IRemoteEntry>> {
+    const entries = await this.client.list(opts.path);
+
+    return entries.map(entry => ({
+      name: entry.name,
+      path: path.posix.join(opts.path, entry.name),
+      type: ENTRY_TYPES[entry.type] ?? RemoteEntryTypes.FILE,
+      size: entry.size,
+      modifiedAt: new Date(entry.modifyTime),
+    }));
+  }
+
+  async stat(opts: { path: string }): Promise<IRemoteEntry | null> {
+    const { path: remotePath } = opts;
+
+    const type = await this.client.exists(remotePath);
+    if (!type) {
+      return null;
+    }
+
+    const stats = await this.client.stat(remotePath);
+    return {
+      name: path.posix.basename(remotePath),
+      path: remotePath,
+      type: ENTRY_TYPES[type] ?? RemoteEntryTypes.FILE,
+      size: stats.size,
+      modifiedAt: new Date(stats.modifyTime),
+    };
+  }
+
+  async download(opts: { path: string }): Promise<Readable> {
+    return this.client.createReadStream(opts.path) as unknown as Readable;
+  }
+
+  /**
+   * Uses the `posix-rename@openssh.com` extension, which replaces `to` atomically, when the
+   * server supports it. Plain SFTP renames fail on an existing `to`, which is removed first.
+   */
+  async rename(opts: { from: string; to: string }) {
+    const { from, to } = opts;
+
+    try {
+      await this.client.posixRename(from, to);
+      return;
+    } catch (error) {
+      this.logger
+        .for(this.rename.name)
+        .debug('POSIX rename unavailable | from: %s | to: %s | Error: %s', from, to, error);
+    }
+
+    await this.client.delete(to, true);
+    await this.client.rename(from, to);
+  }
+
+  async delete(opts: { path: string }) {
+    await this.client.delete(opts.path, true);
+  }
+
+  async mkdir(opts: { path: string }) {
+    await this.client.mkdir(opts.path, true);
+  }
+
+  // -------------------------------------------------------------------------
+  protected async write(opts: { path: string; body: TTransferBody }) {
+    const { path: remotePath, body } = opts;
+    if (body instanceof Readable) {
+      await this.client.put(body, remotePath);
+      return;
+    }
+
+    const buffer = typeof body === 'string' ? Buffer.from(body) : Buffer.from(body);
+    await this.client.put(buffer, remotePath);
+  }
+}
@@ -0,0 +1,58 @@
+import { TConstValue } from '@/common/types';
+import { Readable } from 'node:stream';
+
+export type TTransferBody = Buffer | Uint8Array | string | Readable;
+
+export class RemoteEntryTypes {
+  static readonly FILE = 'file';
+  static readonly DIRECTORY = 'directory';
+  static readonly SYMLINK = 'symlink';
+
+  static readonly SCHEME_SET = new Set([this.FILE, this.DIRECTORY, this.SYMLINK]);
+
+  static isValid(input: string): input is TRemoteEntryType {
+    return this.SCHEME_SET.has(input);
+  }
+}
+
+export type TRemoteEntryType = TConstValue<typeof RemoteEntryTypes>;
+
+// -------------------------------------------------------------------------
+export interface IRemoteEntry {
+  name: string;
+  // Absolute remote path
+  path: string;
+  type: TRemoteEntryType;
+  size: number;
+  modifiedAt?: Date;
+}
+
+// -------------------------------------------------------------------------
+export interface IUploadOptions {
+  // Remote path of the file
+  path: string;
+  body: TTransferBody;
+  /** Upload to a temporary file renamed to `path` once complete (default: true) */
+  atomic?: boolean;
+  /** Temporary path of atomic uploads (default: `.<name>.part` next to `path`) */
+  tempPath?: string;
+}
+
+export interface IUploadResult {
+  path: string;
+  size?: number;
+}
+
+// -------------------------------------------------------------------------
+export interface IFileTransfer {
+  connect(): Promise<void>;
+  disconnect(): Promise<void>;
+  list(opts: { path: string }): Promise<Array<IRemoteEntry>>;
+  stat(opts: { path: string }): Promise<IRemoteEntry | null>;
+  exists(opts: { path: string }): Promise<boolean>;
+  upload(opts: IUploadOptions): Promise<IUploadResult>;
+  download(opts: { path: string }): Promise<Readable>;
+  rename(opts: { from: string; to: string }): Promise<void>;
+  delete(opts: { path: string }): Promise<void>;
+  mkdir(opts: { path: string }): Promise<void>;
+}
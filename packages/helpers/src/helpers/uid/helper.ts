@@ -1,6 +1,7 @@
 import { HTTP } from '@/common/constants';
 import { BaseHelper } from '../base';
 import { getError } from '../error/app-error';
+import { resolveWorkerId } from './worker-id';
 
 const BASE62_CHARS = '0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz';
 
@@ -46,7 +47,7 @@ export interface ISnowflakeParsedId {
  *
  * @example
  * ```typescript
- * // Initialize with defaults (workerId: 199, epoch: 2025-01-01 00:00:00 UTC)
+ * // Initialize with defaults (workerId: resolveWorkerId() or 199, epoch: 2025-01-01 00:00:00 UTC)
  * const generator = new SnowflakeUidHelper();
  *
  * // Or with custom values
@@ -82,7 +83,7 @@ export class SnowflakeUidHelper extends BaseHelper {
   constructor(opts?: IIdGeneratorOptions) {
     super({ scope: SnowflakeUidHelper.name });
 
-    const workerId = opts?.workerId ?? 199;
+    const workerId = opts?.workerId ?? resolveWorkerId() ?? 199;
     const epoch = opts?.epoch ?? SnowflakeConfig.DEFAULT_EPOCH;
 
     this.validateWorkerId(workerId);
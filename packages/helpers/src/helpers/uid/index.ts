@@ -1 +1,3 @@
 export * from './helper';
+export * from './ulid';
+export * from './worker-id';
@@ -0,0 +1,159 @@
+import { HTTP } from '@/common/constants';
+import C from 'node:crypto';
+import { BaseHelper } from '../base';
+import { getError } from '../error/app-error';
+
+// Crockford Base32, no I, L, O and U
+const ULID_CHARS = '0123456789ABCDEFGHJKMNPQRSTVWXYZ';
+
+/**
+ * ULID Configuration Constants (48-80)
+ * - 48 bits: timestamp in ms since unix epoch
+ * - 80 bits: randomness
+ */
+export class UlidConfig {
+  static readonly LENGTH = 26;
+  static readonly TIME_LENGTH = 10;
+  static readonly RANDOM_LENGTH = 16;
+  static readonly MAX_TIMESTAMP_MS = 2 ** 48 - 1;
+  static readonly MAX_RANDOM = (BigInt(1) << BigInt(80)) - BigInt(1);
+  static readonly PATTERN = /^[0-7][0-9A-HJKMNP-TV-Z]{25}$/;
+}
+
+export interface IUlidParsedId {
+  raw: string;
+  timestamp: Date;
+  randomness: string;
+}
+
+/**
+ * Monotonic ULID Generator
+ *
+ * Generates 26 chars, lexicographically sortable ids. IDs minted in the same millisecond
+ * increment the randomness of the previous one so they stay strictly ordered within the
+ * process, a backward clock reuses the last timestamp.
+ *
+ * @example
+ * ```typescript
+ * const generator = new UlidHelper();
+ *
+ * const id = generator.nextId(); // e.g., "01JA2Z3X4Y5Z6A7B8C9D0EFGHJ"
+ * generator.parseId(id).timestamp; // Date when the ID was generated
+ *
+ * // Store in uuid columns
+ * const uuid = UlidHelper.toUuid(id); // e.g., "01928ff1-e9de-..."
+ * UlidHelper.fromUuid(uuid) === id; // true
+ * ```
+ */
+export class UlidHelper extends BaseHelper {
+  private lastTimestamp = -1;
+  private lastRandom = BigInt(0);
+
+  constructor() {
+    super({ scope: UlidHelper.name });
+  }
+
+  /**
+   * Generate next ULID
+   * @returns 26 chars Crockford Base32 ULID
+   */
+  nextId(): string {
+    let timestamp = Date.now();
+
+    if (timestamp <= this.lastTimestamp) {
+      timestamp = this.lastTimestamp;
+      this.lastRandom = this.lastRandom + BigInt(1);
+
+      if (this.lastRandom > UlidConfig.MAX_RANDOM) {
+        throw getError({
+          statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+          message: `[UlidHelper][nextId] Randomness overflow within ${timestamp}ms. Refusing to generate ID.`,
+        });
+      }
+    } else {
+      this.lastTimestamp = timestamp;
+      this.lastRandom = BigInt(`0x${C.randomBytes(10).toString('hex')}`);
+    }
+
+    return (
+      UlidHelper.encode(BigInt(timestamp), UlidConfig.TIME_LENGTH) +
+      UlidHelper.encode(this.lastRandom, UlidConfig.RANDOM_LENGTH)
+    );
+  }
+
+  /**
+   * Parse a ULID and extract its components
+   */
+  parseId(id: string): IUlidParsedId {
+    UlidHelper.assert(id);
+
+    return {
+      raw: id,
+      timestamp: new Date(Number(UlidHelper.decode(id.slice(0, UlidConfig.TIME_LENGTH)))),
+      randomness: id.slice(UlidConfig.TIME_LENGTH),
+    };
+  }
+
+  // -----------------------------------------------------------------------------
+  static isValid(id: string): boolean {
+    return typeof id === 'string' && UlidConfig.PATTERN.test(id.toUpperCase());
+  }
+
+  /**
+   * Convert a ULID to its 128 bits UUID representation
+   */
+  static toUuid(id: string): string {
+    UlidHelper.assert(id);
+
+    const hex = UlidHelper.decode(id).toString(16).padStart(32, '0');
+    return `${hex.slice(0, 8)}-${hex.slice(8, 12)}-${hex.slice(12, 16)}-${hex.slice(16, 20)}-${hex.slice(20)}`;
+  }
+
+  /**
+   * Convert a UUID back to its ULID representation
+   */
+  static fromUuid(uuid: string): string {
+    const hex = uuid.replace(/-/g, '');
+
+    if (!/^[0-9a-f]{32}$/i.test(hex)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        message: `[UlidHelper][fromUuid] Invalid UUID: ${uuid}`,
+      });
+    }
+
+    return UlidHelper.encode(BigInt(`0x${hex}`), UlidConfig.LENGTH);
+  }
+
+  // -----------------------------------------------------------------------------
+  private static assert(id: string) {
+    if (!UlidHelper.isValid(id)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        message: `[UlidHelper] Invalid ULID: ${id}`,
+      });
+    }
+  }
+
+  private static encode(value: bigint, length: number): string {
+    let result = '';
+    let remaining = value;
+
+    for (let i = 0; i < length; i++) {
+      result = ULID_CHARS[Number(remaining & BigInt(31))] + result;
+      remaining = remaining >> BigInt(5);
+    }
+
+    return result;
+  }
+
+  private static decode(str: string): bigint {
+    let result = BigInt(0);
+
+    for (const char of str.toUpperCase()) {
+      result = (result << BigInt(5)) | BigInt(ULID_CHARS.indexOf(char));
+    }
+
+    return result;
+  }
+}
@@ -0,0 +1,54 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '../error/app-error';
+
+/**
+ * Resolve the Snowflake worker id of the current process, in order:
+ * 1. `APP_ENV_WORKER_ID`
+ * 2. The ordinal of a StatefulSet pod name (`orders-3` => 3)
+ * 3. A hash of the pod name, taken from `POD_NAME` or `HOSTNAME` inside Kubernetes
+ *
+ * Hashed pod names may collide, prefer StatefulSets or an explicit id when ids must be unique
+ * across more than a handful of replicas.
+ *
+ * @returns The worker id, `undefined` when none could be resolved.
+ */
+export const resolveWorkerId = (
+  opts: { env?: Record<string, string | undefined>; maxWorkerId?: number } = {},
+): number | undefined => {
+  const env = opts.env ?? process.env;
+  // 10 bits worker id of SnowflakeConfig
+  const maxWorkerId = opts.maxWorkerId ?? 1023;
+
+  const explicit = env.APP_ENV_WORKER_ID;
+  if (explicit !== undefined && explicit !== '') {
+    const workerId = Number(explicit);
+
+    if (!Number.isInteger(workerId) || workerId < 0 || workerId > maxWorkerId) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[resolveWorkerId] APP_ENV_WORKER_ID must be between 0 and ${maxWorkerId} | received: ${explicit}`,
+      });
+    }
+
+    return workerId;
+  }
+
+  const podName = env.POD_NAME ?? (env.KUBERNETES_SERVICE_HOST ? env.HOSTNAME : undefined);
+  if (!podName) {
+    return undefined;
+  }
+
+  const ordinal = /-(\d+)$/.exec(podName)?.[1];
+  if (ordinal !== undefined) {
+    return Number(ordinal) % (maxWorkerId + 1);
+  }
+
+  // FNV-1a
+  let hash = 0x811c9dc5;
+  for (let i = 0; i < podName.length; i++) {
+    hash ^= podName.charCodeAt(i);
+    hash = Math.imul(hash, 0x01000193) >>> 0;
+  }
+
+  return hash % (maxWorkerId + 1);
+};
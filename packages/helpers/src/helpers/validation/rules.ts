@@ -0,0 +1,145 @@
+import { AnyObject, ValueOrPromise } from '@/common/types';
+import { EmailNormalizer } from '@/helpers/notification/email';
+import { PhoneNumberNormalizer } from '@/helpers/notification/sms/phone-number';
+import { IValidationRule, IValidationRuleContext } from './types';
+
+const getLength = (value: unknown) => {
+  if (typeof value === 'string' || Array.isArray(value)) {
+    return value.length;
+  }
+
+  return undefined;
+};
+
+// `at least 2 and at most 64`
+const describeBounds = (opts: { min?: number; max?: number; labels: [string, string] }) => {
+  const { min, max, labels } = opts;
+
+  return [min !== undefined && `${labels[0]} ${min}`, max !== undefined && `${labels[1]} ${max}`]
+    .filter(Boolean)
+    .join(' and ');
+};
+
+// --------------------------------------------------------
+/**
+ * Reusable rules for `Validator` schemas. Every rule accepts an optional `message` to override
+ * the default one.
+ *
+ * @example
+ * ```typescript
+ * const rules = {
+ *   email: [ValidationRules.required(), ValidationRules.email()],
+ *   name: [ValidationRules.length({ min: 2, max: 64 })],
+ *   slug: [ValidationRules.regex({ pattern: /^[a-z0-9-]+$/, code: 'invalid_slug' })],
+ * };
+ * ```
+ */
+export class ValidationRules {
+  static required(opts: { message?: string } = {}): IValidationRule {
+    return {
+      code: 'required',
+      message: opts.message ?? 'Value is required',
+      isPresenceRule: true,
+      test: value => value !== undefined && value !== null && value !== '',
+    };
+  }
+
+  static email(opts: { message?: string } = {}): IValidationRule {
+    return {
+      code: 'invalid_email',
+      message: opts.message ?? 'Invalid email address',
+      test: value => typeof value === 'string' && EmailNormalizer.isValid(value.trim()),
+    };
+  }
+
+  /**
+   * Numbers that can be normalized to E.164, national numbers need a default region or code.
+   */
+  static phone(
+    opts: { defaultRegion?: string; defaultCountryCode?: string; message?: string } = {},
+  ): IValidationRule {
+    const { defaultRegion, defaultCountryCode } = opts;
+
+    return {
+      code: 'invalid_phone',
+      message: opts.message ?? 'Invalid phone number',
+      test: value =>
+        typeof value === 'string' &&
+        PhoneNumberNormalizer.isValid({ phone: value, defaultRegion, defaultCountryCode }),
+    };
+  }
+
+  /**
+   * Length of a string or an array.
+   */
+  static length(opts: { min?: number; max?: number; message?: string }): IValidationRule {
+    const { min, max } = opts;
+
+    return {
+      code: 'invalid_length',
+      message:
+        opts.message ??
+        `Length must be ${describeBounds({ min, max, labels: ['at least', 'at most'] })}`,
+      test: value => {
+        const length = getLength(value);
+        if (length === undefined) {
+          return false;
+        }
+
+        return (min === undefined || length >= min) && (max === undefined || length <= max);
+      },
+    };
+  }
+
+  static range(opts: { min?: number; max?: number; message?: string }): IValidationRule {
+    const { min, max } = opts;
+
+    return {
+      code: 'out_of_range',
+      message:
+        opts.message ?? `Value must be ${describeBounds({ min, max, labels: ['>=', '<='] })}`,
+      test: value =>
+        typeof value === 'number' &&
+        !Number.isNaN(value) &&
+        (min === undefined || value >= min) &&
+        (max === undefined || value <= max),
+    };
+  }
+
+  static regex(opts: { pattern: RegExp; code?: string; message?: string }): IValidationRule {
+    return {
+      code: opts.code ?? 'invalid_format',
+      message: opts.message ?? `Value must match ${opts.pattern}`,
+      test: value => typeof value === 'string' && new RegExp(opts.pattern).test(value),
+    };
+  }
+
+  static oneOf<T>(opts: { values: Array<T>; message?: string }): IValidationRule<T> {
+    return {
+      code: 'invalid_value',
+      message: opts.message ?? `Value must be one of ${opts.values.join(', ')}`,
+      test: value => opts.values.includes(value),
+    };
+  }
+
+  /**
+   * Application specific rule, e.g. a uniqueness check against the database.
+   *
+   * @example
+   * ```typescript
+   * ValidationRules.custom({
+   *   code: 'already_taken',
+   *   message: 'Email is already taken',
+   *   test: async email => !(await userRepository.exists({ where: { email } })),
+   * });
+   * ```
+   */
+  static custom<TValue = any, TData extends AnyObject = AnyObject>(opts: {
+    code: string;
+    message: string;
+    isPresenceRule?: boolean;
+    test: (value: TValue, context: IValidationRuleContext<TData>) => ValueOrPromise<boolean>;
+  }): IValidationRule<TValue, TData> {
+    return { ...opts };
+  }
+}
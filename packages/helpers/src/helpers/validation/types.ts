@@ -0,0 +1,24 @@
+import { AnyObject, ValueOrPromise } from '@/common/types';
+
+// --------------------------------------------------------
+export interface IValidationRuleContext<TData extends AnyObject = AnyObject> {
+  // Dot separated path of the validated value, e.g. `items.0.quantity`
+  path: string;
+  // Whole validated object, for rules depending on other fields
+  data: TData;
+}
+
+export interface IValidationRule<TValue = any, TData extends AnyObject = AnyObject> {
+  // Reported as the field error code, e.g. `invalid_email`
+  code: string;
+  message: string;
+  // Rules are skipped for `undefined` and `null` values unless they check the presence itself
+  isPresenceRule?: boolean;
+  test: (value: TValue, context: IValidationRuleContext<TData>) => ValueOrPromise<boolean>;
+}
+
+// Rules of each field path, run in order
+export type TValidationSchema<TData extends AnyObject = AnyObject> = Record<
+  string,
+  Array<IValidationRule<any, TData>>
+>;
@@ -0,0 +1,115 @@
+import { AnyObject } from '@/common/types';
+import { IValidationFieldError, ValidationError } from '@/helpers/error';
+import get from 'lodash/get';
+import { TValidationSchema } from './types';
+
+/**
+ * `items.*.quantity` => `items.0.quantity`, `items.1.quantity`, ...
+ */
+const expandPath = (opts: { data: unknown; path: string }): Array<string> => {
+  const { data, path } = opts;
+  const index = path.indexOf('*');
+  if (index < 0) {
+    return [path];
+  }
+
+  const parentPath = path.slice(0, Math.max(index - 1, 0));
+  const restPath = path.slice(index + 1);
+  const parent = parentPath ? get(data, parentPath) : data;
+
+  if (!parent || typeof parent !== 'object') {
+    return [];
+  }
+
+  return Object.keys(parent).flatMap(key => {
+    const prefix = parentPath ? `${parentPath}.${key}` : key;
+    return expandPath({ data, path: `${prefix}${restPath}` });
+  });
+};
+
+// --------------------------------------------------------
+/**
+ * Validates objects against a schema of reusable rules, collecting the failures of every field
+ * into a single `ValidationError` instead of failing on the first one.
+ *
+ * Rules of a field run in order and stop at the first failure so a missing value is not also
+ * reported as too short, fields are validated concurrently. Paths are dot separated and `*`
+ * matches every item of an array or object.
+ *
+ * @example
+ * ```typescript
+ * const validator = new Validator<ISignUp>({
+ *   schema: {
+ *     email: [
+ *       ValidationRules.required(),
+ *       ValidationRules.email(),
+ *       ValidationRules.custom({
+ *         code: 'already_taken',
+ *         message: 'Email is already taken',
+ *         test: async email => !(await userRepository.exists({ where: { email } })),
+ *       }),
+ *     ],
+ *     phone: [ValidationRules.phone()],
+ *     'addresses.*.city': [ValidationRules.required(), ValidationRules.length({ max: 128 })],
+ *   },
+ * });
+ *
+ * await validator.assert({ data: payload }); // throws a 422 ValidationError with every field error
+ * ```
+ */
+export class Validator<TData extends AnyObject = AnyObject> {
+  private schema: TValidationSchema<TData>;
+
+  constructor(opts: { schema: TValidationSchema<TData> }) {
+    this.schema = opts.schema;
+  }
+
+  async validate(opts: { data: TData }): Promise<Array<IValidationFieldError>> {
+    const { data } = opts;
+
+    const targets = Object.entries(this.schema).flatMap(([path, rules]) =>
+      expandPath({ data, path }).map(expandedPath => ({ path: expandedPath, rules })),
+    );
+
+    const results = await Promise.all(
+      targets.map(async ({ path, rules }) => {
+        const value = get(data, path);
+        const isAbsent = value === undefined || value === null;
+
+        for (const rule of rules) {
+          if (isAbsent && !rule.isPresenceRule) {
+            continue;
+          }
+
+          const isValid = await rule.test(value, { path, data });
+          if (!isValid) {
+            const fieldError: IValidationFieldError = {
+              path,
+              code: rule.code,
+              message: rule.message,
+              received: value,
+            };
+            return fieldError;
+          }
+        }
+
+        return undefined;
+      }),
+    );
+
+    return results.filter((fieldError): fieldError is IValidationFieldError => !!fieldError);
+  }
+
+  /**
+   * @throws ValidationError with every failed field
+   */
+  async assert(opts: { data: TData; target?: string }): Promise<TData> {
+    const fields = await this.validate({ data: opts.data });
+
+    if (fields.length) {
+      throw new ValidationError({ fields, target: opts.target });
+    }
+
+    return opts.data;
+  }
+}
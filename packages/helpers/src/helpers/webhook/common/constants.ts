@@ -0,0 +1,56 @@
+// --------------------------------------------------------
+export class WebhookDeliveryStatuses {
+  static readonly PENDING = '000_PENDING';
+  static readonly PROCESSING = '100_PROCESSING';
+  static readonly RETRYING = '200_RETRYING';
+  static readonly SUCCEEDED = '300_SUCCEEDED';
+  static readonly FAILED = '400_FAILED';
+
+  static readonly SCHEME_SET = new Set([
+    this.PENDING,
+    this.PROCESSING,
+    this.RETRYING,
+    this.SUCCEEDED,
+    this.FAILED,
+  ]);
+
+  static isValid(scheme: string): boolean {
+    return this.SCHEME_SET.has(scheme);
+  }
+
+  static isFinal(scheme: string): boolean {
+    return scheme === this.SUCCEEDED || scheme === this.FAILED;
+  }
+}
+
+// --------------------------------------------------------
+export class WebhookHeaders {
+  static readonly ID = 'x-webhook-id';
+  static readonly EVENT = 'x-webhook-event';
+  static readonly TIMESTAMP = 'x-webhook-timestamp';
+  static readonly SIGNATURE = 'x-webhook-signature';
+  static readonly ATTEMPT = 'x-webhook-attempt';
+}
+
+// --------------------------------------------------------
+export class WebhookDefaults {
+  static readonly SIGNATURE_SCHEME = 'v1';
+  static readonly MAX_ATTEMPTS = 5;
+  static readonly TIMEOUT = 10_000;
+  static readonly BACKOFF_BASE_DELAY = 1_000;
+  static readonly BACKOFF_MAX_DELAY = 60 * 60 * 1_000;
+  static readonly BACKOFF_FACTOR = 2;
+  static readonly SIGNATURE_TOLERANCE = 5 * 60; // seconds
+  static readonly NONCE_BYTES = 16;
+}
+
+// --------------------------------------------------------
+export class WebhookErrorCodes {
+  static readonly MISSING_SIGNATURE = 'WEBHOOK_MISSING_SIGNATURE';
+  static readonly MALFORMED_SIGNATURE = 'WEBHOOK_MALFORMED_SIGNATURE';
+  static readonly TIMESTAMP_OUT_OF_TOLERANCE = 'WEBHOOK_TIMESTAMP_OUT_OF_TOLERANCE';
+  static readonly SIGNATURE_MISMATCH = 'WEBHOOK_SIGNATURE_MISMATCH';
+  static readonly INVALID_PAYLOAD = 'WEBHOOK_INVALID_PAYLOAD';
+  static readonly MISSING_NONCE = 'WEBHOOK_MISSING_NONCE';
+  static readonly REPLAYED_NONCE = 'WEBHOOK_REPLAYED_NONCE';
+}
@@ -0,0 +1,63 @@
+import { AnyObject, TConstValue, ValueOrPromise } from '@/common/types';
+import { WebhookDeliveryStatuses } from './constants';
+
+export type TWebhookDeliveryStatus = TConstValue<typeof WebhookDeliveryStatuses>;
+
+// --------------------------------------------------------
+export interface IWebhookEndpoint {
+  url: string;
+  secret: string;
+  headers?: Record<string, string>;
+  timeout?: number;
+  // Sign every attempt with a fresh nonce, for receivers rejecting replays
+  useNonce?: boolean;
+}
+
+export interface IWebhookRetryOptions {
+  maxAttempts?: number;
+  baseDelay?: number;
+  maxDelay?: number;
+  factor?: number;
+}
+
+// --------------------------------------------------------
+export interface IWebhookDeliveryAttempt {
+  attempt: number;
+  startedAt: string;
+  finishedAt: string;
+  statusCode?: number;
+  responseBody?: string;
+  error?: string;
+  duration: number;
+}
+
+export interface IWebhookDelivery<TPayload extends AnyObject = AnyObject> {
+  id: string;
+  event: string;
+  endpoint: IWebhookEndpoint;
+  payload: TPayload;
+  status: TWebhookDeliveryStatus;
+  attempts: Array<IWebhookDeliveryAttempt>;
+  maxAttempts: number;
+  nextAttemptAt?: string;
+  createdAt: string;
+  updatedAt: string;
+}
+
+// --------------------------------------------------------
+export interface IWebhookDeliveryStore {
+  save(opts: { delivery: IWebhookDelivery }): ValueOrPromise<void>;
+  findById(opts: { id: string }): ValueOrPromise<IWebhookDelivery | null>;
+  find(opts: {
+    status?: TWebhookDeliveryStatus;
+    event?: string;
+    limit?: number;
+  }): ValueOrPromise<Array<IWebhookDelivery>>;
+  remove(opts: { id: string }): ValueOrPromise<void>;
+}
+
+// --------------------------------------------------------
+export interface IWebhookNonceStore {
+  // `false` when the nonce was already claimed within `ttl` milliseconds
+  claim(opts: { nonce: string; ttl: number }): ValueOrPromise<boolean>;
+}
@@ -0,0 +1,387 @@
+import { AnyObject } from '@/common/types';
+import { BaseHelper } from '@/helpers/base';
+import { getError } from '@/helpers/error';
+import { NodeFetchNetworkRequest } from '@/helpers/network';
+import { QueueHelper } from '@/helpers/queue';
+import C from 'node:crypto';
+import {
+  IWebhookDelivery,
+  IWebhookDeliveryAttempt,
+  IWebhookDeliveryStore,
+  IWebhookEndpoint,
+  IWebhookRetryOptions,
+  TWebhookDeliveryStatus,
+  WebhookDefaults,
+  WebhookDeliveryStatuses,
+  WebhookHeaders,
+} from './common';
+import { WebhookSigner } from './signer';
+import { MemoryWebhookDeliveryStore } from './stores';
+
+const MAX_RESPONSE_BODY_LENGTH = 2_048;
+
+export interface IWebhookDispatcherOptions {
+  identifier: string;
+  store?: IWebhookDeliveryStore;
+  retry?: IWebhookRetryOptions;
+  timeout?: number;
+
+  onDelivered?: (opts: { delivery: IWebhookDelivery }) => void | Promise<void>;
+  onFailed?: (opts: { delivery: IWebhookDelivery }) => void | Promise<void>;
+}
+
+// --------------------------------------------------------
+/**
+ * Deliver signed webhook payloads to subscriber endpoints.
+ *
+ * Every delivery is persisted to a {@link IWebhookDeliveryStore} together with its attempts,
+ * and failing deliveries (network error, 408, 429, 5xx) are retried with exponential backoff
+ * until `maxAttempts` is reached.
+ *
+ * Retries wait in timers of the running process. Call {@link start} once at boot, before
+ * dispatching, to pick up the deliveries a previous process left pending or retrying in a
+ * persistent store. An attempt interrupted by the restart is sent again.
+ *
+ * @example
+ * ```typescript
+ * const dispatcher = new WebhookDispatcherHelper({
+ *   identifier: 'order-webhooks',
+ *   store: new RedisWebhookDeliveryStore({ redis }),
+ * });
+ * await dispatcher.start();
+ *
+ * const { id } = await dispatcher.dispatch({
+ *   endpoint: { url: 'https://partner.example.com/hooks', secret: 'whsec_...' },
+ *   event: 'order.created',
+ *   payload: { orderId: 1 },
+ * });
+ *
+ * const delivery = await dispatcher.getDelivery({ id });
+ * ```
+ */
+export class WebhookDispatcherHelper extends BaseHelper {
+  private store: IWebhookDeliveryStore;
+  private network: NodeFetchNetworkRequest;
+  private queue: QueueHelper<{ id: string }>;
+  private retryTimers = new Map<string, NodeJS.Timeout>();
+
+  private retry: Required<IWebhookRetryOptions>;
+  private timeout: number;
+
+  private onDelivered?: IWebhookDispatcherOptions['onDelivered'];
+  private onFailed?: IWebhookDispatcherOptions['onFailed'];
+
+  constructor(opts: IWebhookDispatcherOptions) {
+    super({
+      scope: `${WebhookDispatcherHelper.name}_${opts.identifier}`,
+      identifier: opts.identifier,
+    });
+
+    this.store = opts.store ?? new MemoryWebhookDeliveryStore();
+    this.timeout = opts.timeout ?? WebhookDefaults.TIMEOUT;
+    this.retry = {
+      maxAttempts: opts.retry?.maxAttempts ?? WebhookDefaults.MAX_ATTEMPTS,
+      baseDelay: opts.retry?.baseDelay ?? WebhookDefaults.BACKOFF_BASE_DELAY,
+      maxDelay: opts.retry?.maxDelay ?? WebhookDefaults.BACKOFF_MAX_DELAY,
+      factor: opts.retry?.factor ?? WebhookDefaults.BACKOFF_FACTOR,
+    };
+
+    this.onDelivered = opts.onDelivered;
+    this.onFailed = opts.onFailed;
+
+    this.network = new NodeFetchNetworkRequest({
+      name: `${WebhookDispatcherHelper.name}_${opts.identifier}`,
+      networkOptions: {},
+    });
+
+    this.queue = new QueueHelper<{ id: string }>({
+      identifier: `${WebhookDispatcherHelper.name}_${opts.identifier}`,
+      onMessage: async ({ queueElement }) => {
+        const { id } = queueElement.payload;
+
+        try {
+          await this.deliver({ id });
+        } catch (error) {
+          this.logger
+            .for('onMessage')
+            .error('Failed to process webhook delivery | id: %s | error: %s', id, error);
+        }
+      },
+    });
+  }
+
+  // --------------------------------------------------------
+  /**
+   * Enqueue the unfinished deliveries of the store, the due ones at once and the others when
+   * their `nextAttemptAt` is reached. Returns the number of recovered deliveries.
+   */
+  async start() {
+    const now = Date.now();
+    let count = 0;
+
+    for (const status of [
+      WebhookDeliveryStatuses.PENDING,
+      WebhookDeliveryStatuses.PROCESSING,
+      WebhookDeliveryStatuses.RETRYING,
+    ]) {
+      const deliveries = await this.store.find({ status });
+
+      for (const delivery of deliveries) {
+        const nextAttemptAt = delivery.nextAttemptAt
+          ? new Date(delivery.nextAttemptAt).getTime()
+          : now;
+        this.schedule({ id: delivery.id, delay: nextAttemptAt - now });
+        count++;
+      }
+    }
+
+    this.logger.for(this.start.name).info('Recovered webhook deliveries | count: %d', count);
+    return count;
+  }
+
+  // --------------------------------------------------------
+  async dispatch<TPayload extends AnyObject = AnyObject>(opts: {
+    endpoint: IWebhookEndpoint;
+    event: string;
+    payload: TPayload;
+    id?: string;
+    maxAttempts?: number;
+  }): Promise<IWebhookDelivery<TPayload>> {
+    const { endpoint, event, payload, id = C.randomUUID(), maxAttempts } = opts;
+
+    if (!endpoint?.url || !endpoint?.secret) {
+      throw getError({
+        statusCode: 400,
+        message: '[dispatch] Invalid webhook endpoint | url and secret are required!',
+      });
+    }
+
+    const now = new Date().toISOString();
+    const delivery: IWebhookDelivery<TPayload> = {
+      id,
+      event,
+      endpoint,
+      payload,
+      status: WebhookDeliveryStatuses.PENDING,
+      attempts: [],
+      maxAttempts: maxAttempts ?? this.retry.maxAttempts,
+      createdAt: now,
+      updatedAt: now,
+    };
+
+    await this.store.save({ delivery });
+    await this.queue.enqueue({ id });
+
+    this.logger
+      .for(this.dispatch.name)
+      .info('Enqueued webhook delivery | id: %s | event: %s | url: %s', id, event, endpoint.url);
+    return delivery;
+  }
+
+  // --------------------------------------------------------
+  async redeliver(opts: { id: string }) {
+    const delivery = await this.store.findById(opts);
+    if (!delivery) {
+      throw getError({
+        statusCode: 404,
+        message: `[redeliver] Webhook delivery not found | id: ${opts.id}`,
+      });
+    }
+
+    delivery.status = WebhookDeliveryStatuses.PENDING;
+    delivery.maxAttempts = delivery.attempts.length + this.retry.maxAttempts;
+    delivery.nextAttemptAt = undefined;
+    delivery.updatedAt = new Date().toISOString();
+
+    // The pending retry would send the payload a second time
+    this.clearRetry({ id: delivery.id });
+
+    await this.store.save({ delivery });
+    await this.queue.enqueue({ id: delivery.id });
+    return delivery;
+  }
+
+  // --------------------------------------------------------
+  getDelivery(opts: { id: string }) {
+    return this.store.findById(opts);
+  }
+
+  getDeliveries(opts: { status?: TWebhookDeliveryStatus; event?: string; limit?: number }) {
+    return this.store.find(opts);
+  }
+
+  // --------------------------------------------------------
+  getBackoffDelay(opts: { attempt: number }) {
+    const { baseDelay, maxDelay, factor } = this.retry;
+    const delay = baseDelay * Math.pow(factor, Math.max(opts.attempt - 1, 0));
+
+    // Jitter keeps retries of the same batch from hitting the subscriber at once
+    return Math.min(maxDelay, Math.round(delay / 2 + Math.random() * (delay / 2)));
+  }
+
+  isRetryable(opts: { statusCode?: number }) {
+    const { statusCode } = opts;
+    if (!statusCode) {
+      return true;
+    }
+
+    return statusCode === 408 || statusCode === 429 || statusCode >= 500;
+  }
+
+  // --------------------------------------------------------
+  protected async deliver(opts: { id: string }) {
+    const logger = this.logger.for(this.deliver.name);
+
+    const delivery = await this.store.findById(opts);
+    if (!delivery || WebhookDeliveryStatuses.isFinal(delivery.status)) {
+      logger.warn('Skip delivery | id: %s | status: %s', opts.id, delivery?.status);
+      return;
+    }
+
+    delivery.status = WebhookDeliveryStatuses.PROCESSING;
+    delivery.updatedAt = new Date().toISOString();
+    await this.store.save({ delivery });
+
+    const attempt = await this.attempt({ delivery });
+    delivery.attempts.push(attempt);
+    delivery.updatedAt = attempt.finishedAt;
+
+    const isSucceeded =
+      !!attempt.statusCode && attempt.statusCode >= 200 && attempt.statusCode < 300;
+    if (isSucceeded) {
+      delivery.status = WebhookDeliveryStatuses.SUCCEEDED;
+      delivery.nextAttemptAt = undefined;
+      await this.store.save({ delivery });
+
+      logger.info('Webhook delivered | id: %s | attempt: %d', delivery.id, attempt.attempt);
+      await this.onDelivered?.({ delivery });
+      return;
+    }
+
+    const canRetry =
+      delivery.attempts.length < delivery.maxAttempts &&
+      this.isRetryable({ statusCode: attempt.statusCode });
+
+    if (!canRetry) {
+      delivery.status = WebhookDeliveryStatuses.FAILED;
+      delivery.nextAttemptAt = undefined;
+      await this.store.save({ delivery });
+
+      logger.error(
+        'Webhook delivery FAILED | id: %s | attempts: %d | statusCode: %s | error: %s',
+        delivery.id,
+        delivery.attempts.length,
+        attempt.statusCode,
+        attempt.error,
+      );
+      await this.onFailed?.({ delivery });
+      return;
+    }
+
+    const delay = this.getBackoffDelay({ attempt: attempt.attempt });
+    delivery.status = WebhookDeliveryStatuses.RETRYING;
+    delivery.nextAttemptAt = new Date(Date.now() + delay).toISOString();
+    await this.store.save({ delivery });
+
+    logger.warn(
+      'Retry webhook delivery | id: %s | attempt: %d | delay: %dms | statusCode: %s | error: %s',
+      delivery.id,
+      attempt.attempt,
+      delay,
+      attempt.statusCode,
+      attempt.error,
+    );
+
+    this.schedule({ id: delivery.id, delay });
+  }
+
+  // --------------------------------------------------------
+  protected schedule(opts: { id: string; delay: number }) {
+    const { id, delay } = opts;
+    this.clearRetry({ id });
+
+    if (delay <= 0) {
+      this.queue.enqueue({ id });
+      return;
+    }
+
+    const timer = setTimeout(() => {
+      this.retryTimers.delete(id);
+      this.queue.enqueue({ id });
+    }, delay);
+    this.retryTimers.set(id, timer);
+  }
+
+  protected clearRetry(opts: { id: string }) {
+    const timer = this.retryTimers.get(opts.id);
+    if (!timer) {
+      return;
+    }
+
+    clearTimeout(timer);
+    this.retryTimers.delete(opts.id);
+  }
+
+  // --------------------------------------------------------
+  protected async attempt(opts: { delivery: IWebhookDelivery }): Promise<IWebhookDeliveryAttempt> {
+    const { delivery } = opts;
+    const { endpoint } = delivery;
+
+    const attempt = delivery.attempts.length + 1;
+    const body = JSON.stringify({
+      id: delivery.id,
+      event: delivery.event,
+      createdAt: delivery.createdAt,
+      data: delivery.payload,
+    });
+    const { timestamp, header } = WebhookSigner.sign({
+      secret: endpoint.secret,
+      body,
+      nonce: endpoint.useNonce ? WebhookSigner.generateNonce() : undefined,
+    });
+
+    const startedAt = new Date();
+    const rs: Partial<IWebhookDeliveryAttempt> = { attempt, startedAt: startedAt.toISOString() };
+
+    try {
+      const response = await this.network.getNetworkService().post(
+        {
+          url: endpoint.url,
+          body,
+          timeout: endpoint.timeout ?? this.timeout,
+          headers: {
+            ['content-type']: 'application/json; charset=utf-8',
+            ...endpoint.headers,
+            [WebhookHeaders.ID]: delivery.id,
+            [WebhookHeaders.EVENT]: delivery.event,
+            [WebhookHeaders.TIMESTAMP]: `${timestamp}`,
+            [WebhookHeaders.SIGNATURE]: header,
+            [WebhookHeaders.ATTEMPT]: `${attempt}`,
+          },
+        },
+        this.logger,
+      );
+
+      rs.statusCode = response.status;
+      rs.responseBody = (await response.text()).slice(0, MAX_RESPONSE_BODY_LENGTH);
+    } catch (error) {
+      rs.error = (error as Error)?.message ?? `${error}`;
+    }
+
+    const finishedAt = new Date();
+    rs.finishedAt = finishedAt.toISOString();
+    rs.duration = finishedAt.getTime() - startedAt.getTime();
+
+    return rs as IWebhookDeliveryAttempt;
+  }
+
+  // --------------------------------------------------------
+  close() {
+    for (const timer of this.retryTimers.values()) {
+      clearTimeout(timer);
+    }
+
+    this.retryTimers.clear();
+    this.queue.close();
+  }
+}
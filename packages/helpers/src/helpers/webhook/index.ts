@@ -0,0 +1,5 @@
+export * from './common';
+export * from './dispatcher.helper';
+export * from './signer';
+export * from './stores';
+export * from './verifier';
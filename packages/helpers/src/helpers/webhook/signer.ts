@@ -0,0 +1,38 @@
+import C from 'node:crypto';
+import { WebhookDefaults } from './common';
+
+/**
+ * Build and compute Stripe-style webhook signatures.
+ *
+ * Header format: `t=<unix seconds>,v1=<hex hmac-sha256 of "<t>.<body>">`, with a nonce:
+ * `t=<unix seconds>,n=<nonce>,v1=<hex hmac-sha256 of "<t>.<n>.<body>">`
+ */
+export class WebhookSigner {
+  static generateNonce() {
+    return C.randomBytes(WebhookDefaults.NONCE_BYTES).toString('hex');
+  }
+
+  static computeSignature(opts: {
+    secret: string;
+    timestamp: number;
+    body: string;
+    nonce?: string;
+  }) {
+    const { secret, timestamp, body, nonce } = opts;
+    const payload = nonce ? `${timestamp}.${nonce}.${body}` : `${timestamp}.${body}`;
+    return C.createHmac('sha256', secret).update(payload).digest('hex');
+  }
+
+  static sign(opts: { secret: string; body: string; timestamp?: number; nonce?: string }) {
+    const { secret, body, timestamp = Math.floor(Date.now() / 1000), nonce } = opts;
+    const signature = this.computeSignature({ secret, timestamp, body, nonce });
+    const parts = nonce ? [`t=${timestamp}`, `n=${nonce}`] : [`t=${timestamp}`];
+
+    return {
+      timestamp,
+      nonce,
+      signature,
+      header: [...parts, `${WebhookDefaults.SIGNATURE_SCHEME}=${signature}`].join(','),
+    };
+  }
+}
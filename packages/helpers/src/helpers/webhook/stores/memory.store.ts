@@ -0,0 +1,74 @@
+import {
+  IWebhookDelivery,
+  IWebhookDeliveryStore,
+  IWebhookNonceStore,
+  TWebhookDeliveryStatus,
+} from '../common';
+
+// --------------------------------------------------------
+export class MemoryWebhookDeliveryStore implements IWebhookDeliveryStore {
+  private deliveries = new Map<string, IWebhookDelivery>();
+
+  save(opts: { delivery: IWebhookDelivery }) {
+    const { delivery } = opts;
+    this.deliveries.set(delivery.id, structuredClone(delivery));
+  }
+
+  findById(opts: { id: string }) {
+    const delivery = this.deliveries.get(opts.id);
+    return delivery ? structuredClone(delivery) : null;
+  }
+
+  find(opts: { status?: TWebhookDeliveryStatus; event?: string; limit?: number }) {
+    const { status, event, limit } = opts;
+
+    const rs: Array<IWebhookDelivery> = [];
+    for (const delivery of this.deliveries.values()) {
+      if (status && delivery.status !== status) {
+        continue;
+      }
+
+      if (event && delivery.event !== event) {
+        continue;
+      }
+
+      rs.push(structuredClone(delivery));
+      if (limit && rs.length >= limit) {
+        break;
+      }
+    }
+
+    return rs;
+  }
+
+  remove(opts: { id: string }) {
+    this.deliveries.delete(opts.id);
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * Claimed nonces of a single process, for tests and single instance receivers.
+ */
+export class MemoryWebhookNonceStore implements IWebhookNonceStore {
+  // Expiry in epoch milliseconds by nonce
+  private nonces = new Map<string, number>();
+
+  claim(opts: { nonce: string; ttl: number }) {
+    const { nonce, ttl } = opts;
+    const now = Date.now();
+
+    for (const [key, expiresAt] of this.nonces) {
+      if (expiresAt <= now) {
+        this.nonces.delete(key);
+      }
+    }
+
+    if (this.nonces.has(nonce)) {
+      return false;
+    }
+
+    this.nonces.set(nonce, now + ttl);
+    return true;
+  }
+}
@@ -0,0 +1,128 @@
+import { DefaultRedisHelper } from '@/helpers/redis';
+import {
+  IWebhookDelivery,
+  IWebhookDeliveryStore,
+  IWebhookNonceStore,
+  TWebhookDeliveryStatus,
+} from '../common';
+
+// --------------------------------------------------------
+/**
+ * Persist webhook deliveries in Redis.
+ *
+ * - `<prefix>:delivery:<id>` hold the serialized delivery
+ * - `<prefix>:status:<status>` sets index delivery ids by their current status
+ */
+export class RedisWebhookDeliveryStore implements IWebhookDeliveryStore {
+  private redis: DefaultRedisHelper;
+  private prefix: string;
+  private ttl?: number;
+
+  constructor(opts: { redis: DefaultRedisHelper; prefix?: string; ttl?: number }) {
+    this.redis = opts.redis;
+    this.prefix = opts.prefix ?? 'webhook';
+    this.ttl = opts.ttl;
+  }
+
+  private getDeliveryKey(id: string) {
+    return `${this.prefix}:delivery:${id}`;
+  }
+
+  private getStatusKey(status: TWebhookDeliveryStatus) {
+    return `${this.prefix}:status:${status}`;
+  }
+
+  async save(opts: { delivery: IWebhookDelivery }) {
+    const { delivery } = opts;
+    const client = this.redis.getClient();
+
+    const previous = await this.findById({ id: delivery.id });
+    const key = this.getDeliveryKey(delivery.id);
+    const serialized = JSON.stringify(delivery);
+
+    if (this.ttl) {
+      await client.set(key, serialized, 'PX', this.ttl);
+    } else {
+      await client.set(key, serialized);
+    }
+
+    if (previous && previous.status !== delivery.status) {
+      await client.srem(this.getStatusKey(previous.status), delivery.id);
+    }
+
+    await client.sadd(this.getStatusKey(delivery.status), delivery.id);
+  }
+
+  async findById(opts: { id: string }) {
+    return this.redis.get<IWebhookDelivery>({
+      key: this.getDeliveryKey(opts.id),
+      transform: input => JSON.parse(input),
+    });
+  }
+
+  async find(opts: { status?: TWebhookDeliveryStatus; event?: string; limit?: number }) {
+    const { status, event, limit } = opts;
+    const client = this.redis.getClient();
+
+    let ids: Array<string> = [];
+    if (status) {
+      ids = await client.smembers(this.getStatusKey(status));
+    } else {
+      const keys = await this.redis.keys({ key: this.getDeliveryKey('*') });
+      ids = keys.map(key => key.slice(this.getDeliveryKey('').length));
+    }
+
+    const rs: Array<IWebhookDelivery> = [];
+    for (const id of ids) {
+      const delivery = await this.findById({ id });
+      if (!delivery) {
+        continue;
+      }
+
+      if (event && delivery.event !== event) {
+        continue;
+      }
+
+      rs.push(delivery);
+      if (limit && rs.length >= limit) {
+        break;
+      }
+    }
+
+    return rs;
+  }
+
+  async remove(opts: { id: string }) {
+    const delivery = await this.findById(opts);
+    if (!delivery) {
+      return;
+    }
+
+    const client = this.redis.getClient();
+    await client.srem(this.getStatusKey(delivery.status), delivery.id);
+    await this.redis.del({ keys: [this.getDeliveryKey(delivery.id)] });
+  }
+}
+
+// --------------------------------------------------------
+/**
+ * Claimed nonces shared by every instance of the receiver, `<prefix>:nonce:<nonce>` keys set
+ * with `NX` and expiring with the tolerance window.
+ */
+export class RedisWebhookNonceStore implements IWebhookNonceStore {
+  private redis: DefaultRedisHelper;
+  private prefix: string;
+
+  constructor(opts: { redis: DefaultRedisHelper; prefix?: string }) {
+    this.redis = opts.redis;
+    this.prefix = opts.prefix ?? 'webhook';
+  }
+
+  async claim(opts: { nonce: string; ttl: number }) {
+    const { nonce, ttl } = opts;
+    const client = this.redis.getClient();
+
+    const rs = await client.set(`${this.prefix}:nonce:${nonce}`, '1', 'PX', Math.ceil(ttl), 'NX');
+    return rs === 'OK';
+  }
+}
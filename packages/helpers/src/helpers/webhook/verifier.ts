@@ -0,0 +1,241 @@
+import { HTTP } from '@/common/constants';
+import { AnyObject } from '@/common/types';
+import { getError } from '@/helpers/error';
+import C from 'node:crypto';
+import { IWebhookNonceStore, WebhookDefaults, WebhookErrorCodes } from './common';
+import { WebhookSigner } from './signer';
+
+export interface IParsedWebhookSignature {
+  timestamp: number;
+  nonce?: string;
+  signatures: Array<string>;
+}
+
+// --------------------------------------------------------
+/**
+ * Verify Stripe-style webhook signature headers on the receiver side.
+ *
+ * Several secrets can be active at the same time (e.g. while rotating), the header is accepted
+ * as soon as one `v1` signature matches one of them. `verifyOnce` also rejects replays of signed
+ * requests within the tolerance window, by their nonce.
+ *
+ * @example
+ * ```typescript
+ * const event = WebhookVerifier.verify<{ orderId: number }>({
+ *   body: await context.req.text(),
+ *   header: context.req.header(WebhookHeaders.SIGNATURE),
+ *   secrets: [env.WEBHOOK_SECRET, env.WEBHOOK_PREVIOUS_SECRET],
+ * });
+ * ```
+ */
+export class WebhookVerifier {
+  static parseHeader(opts: { header: string; scheme?: string }): IParsedWebhookSignature {
+    const { header, scheme = WebhookDefaults.SIGNATURE_SCHEME } = opts;
+
+    let timestamp = NaN;
+    let nonce: string | undefined;
+    const signatures: Array<string> = [];
+
+    for (const part of header.split(',')) {
+      const separatorIndex = part.indexOf('=');
+      if (separatorIndex < 0) {
+        continue;
+      }
+
+      const key = part.slice(0, separatorIndex).trim();
+      const value = part.slice(separatorIndex + 1).trim();
+
+      if (key === 't') {
+        timestamp = Number(value);
+        continue;
+      }
+
+      if (key === 'n' && value) {
+        nonce = value;
+        continue;
+      }
+
+      if (key === scheme && value) {
+        signatures.push(value);
+      }
+    }
+
+    if (!Number.isInteger(timestamp) || !signatures.length) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: WebhookErrorCodes.MALFORMED_SIGNATURE,
+        message: '[parseHeader] Malformed webhook signature header!',
+      });
+    }
+
+    return { timestamp, nonce, signatures };
+  }
+
+  // --------------------------------------------------------
+  static verifySignature(opts: {
+    body: string;
+    header?: string | null;
+    secrets: string | Array<string>;
+    tolerance?: number;
+    now?: number;
+  }): IParsedWebhookSignature {
+    const {
+      body,
+      header,
+      secrets,
+      tolerance = WebhookDefaults.SIGNATURE_TOLERANCE,
+      now = Math.floor(Date.now() / 1000),
+    } = opts;
+
+    if (!header) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: WebhookErrorCodes.MISSING_SIGNATURE,
+        message: '[verifySignature] Missing webhook signature header!',
+      });
+    }
+
+    const parsed = this.parseHeader({ header });
+
+    if (tolerance > 0 && Math.abs(now - parsed.timestamp) > tolerance) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: WebhookErrorCodes.TIMESTAMP_OUT_OF_TOLERANCE,
+        message: `[verifySignature] Webhook timestamp is outside of the tolerance window | tolerance: ${tolerance}s`,
+      });
+    }
+
+    const activeSecrets = Array.isArray(secrets) ? secrets : [secrets];
+    for (const secret of activeSecrets) {
+      if (!secret) {
+        continue;
+      }
+
+      const expected = Buffer.from(
+        WebhookSigner.computeSignature({
+          secret,
+          timestamp: parsed.timestamp,
+          nonce: parsed.nonce,
+          body,
+        }),
+      );
+
+      const isMatched = parsed.signatures.some(signature => {
+        const received = Buffer.from(signature);
+        return received.length === expected.length && C.timingSafeEqual(received, expected);
+      });
+
+      if (isMatched) {
+        return parsed;
+      }
+    }
+
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+      messageCode: WebhookErrorCodes.SIGNATURE_MISMATCH,
+      message: '[verifySignature] No webhook signature matches the active secrets!',
+    });
+  }
+
+  // --------------------------------------------------------
+  static verify<TPayload extends AnyObject = AnyObject>(opts: {
+    body: string | Buffer;
+    header?: string | null;
+    secrets: string | Array<string>;
+    tolerance?: number;
+    now?: number;
+    transform?: (input: AnyObject) => TPayload;
+  }): TPayload {
+    const { body, transform, ...rest } = opts;
+    const rawBody = typeof body === 'string' ? body : body.toString('utf-8');
+
+    this.verifySignature({ ...rest, body: rawBody });
+    return this.parsePayload({ body: rawBody, transform });
+  }
+
+  /**
+   * `verify`, then claim the nonce of the signature in `nonceStore` for twice the tolerance, the
+   * timestamp being accepted up to `tolerance` behind or ahead of the clock. A replayed request
+   * fails with `WEBHOOK_REPLAYED_NONCE`, signatures without nonce with `WEBHOOK_MISSING_NONCE`.
+   * Replays can only be rejected within a tolerance window, `tolerance` must be positive.
+   *
+   * @example
+   * ```typescript
+   * const nonceStore = new RedisWebhookNonceStore({ redis, prefix: 'partner-x' });
+   *
+   * const event = await WebhookVerifier.verifyOnce({
+   *   body: await context.req.text(),
+   *   header: context.req.header(WebhookHeaders.SIGNATURE),
+   *   secrets: env.PARTNER_X_SECRET,
+   *   nonceStore,
+   * });
+   * ```
+   */
+  static async verifyOnce<TPayload extends AnyObject = AnyObject>(opts: {
+    body: string | Buffer;
+    header?: string | null;
+    secrets: string | Array<string>;
+    nonceStore: IWebhookNonceStore;
+    tolerance?: number;
+    now?: number;
+    transform?: (input: AnyObject) => TPayload;
+  }): Promise<TPayload> {
+    const { body, transform, nonceStore, ...rest } = opts;
+    const {
+      tolerance = WebhookDefaults.SIGNATURE_TOLERANCE,
+      now = Math.floor(Date.now() / 1000),
+    } = rest;
+    const rawBody = typeof body === 'string' ? body : body.toString('utf-8');
+
+    // Without tolerance window any timestamp is accepted, the nonce could never be released
+    if (!(tolerance > 0)) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_5.InternalServerError,
+        message: `[verifyOnce] Invalid tolerance, replays are rejected within a tolerance window | tolerance: ${tolerance}s`,
+      });
+    }
+
+    const { nonce } = this.verifySignature({ ...rest, tolerance, now, body: rawBody });
+    if (!nonce) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: WebhookErrorCodes.MISSING_NONCE,
+        message: '[verifyOnce] Webhook signature carries no nonce!',
+      });
+    }
+
+    // Kept for the whole window whatever the age of the timestamp
+    const ttl = 2 * tolerance * 1000;
+    const isClaimed = await nonceStore.claim({ nonce, ttl });
+    if (!isClaimed) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.Unauthorized,
+        messageCode: WebhookErrorCodes.REPLAYED_NONCE,
+        message: `[verifyOnce] Webhook nonce was already used | nonce: ${nonce}`,
+      });
+    }
+
+    return this.parsePayload({ body: rawBody, transform });
+  }
+
+  // --------------------------------------------------------
+  private static parsePayload<TPayload extends AnyObject>(opts: {
+    body: string;
+    transform?: (input: AnyObject) => TPayload;
+  }): TPayload {
+    const { body: rawBody, transform } = opts;
+
+    let payload: AnyObject;
+    try {
+      payload = JSON.parse(rawBody);
+    } catch (error) {
+      throw getError({
+        statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+        messageCode: WebhookErrorCodes.INVALID_PAYLOAD,
+        message: `[verify] Invalid webhook payload | error: ${(error as Error).message}`,
+      });
+    }
+
+    return transform ? transform(payload) : (payload as TPayload);
+  }
+}
@@ -5,3 +5,4 @@ export * from './parse.utility';
 export * from './performance.utility';
 export * from './promise.utility';
 export * from './request.utility';
+export * from './time.utility';
@@ -0,0 +1,206 @@
+import { HTTP } from '@/common/constants';
+import { getError } from '@/helpers/error';
+import { dayjs } from './date.utility';
+
+type TDateInput = string | number | Date | dayjs.Dayjs;
+
+// Full RFC 3339 date-time, the offset is mandatory
+const RFC3339_PATTERN = /^\d{4}-\d{2}-\d{2}[Tt ]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})$/;
+
+// ISO weekdays, saturday and sunday
+const DEFAULT_WEEKEND = [6, 7];
+
+// --------------------------------------------------------
+// Timezones
+// --------------------------------------------------------
+export const isValidTimezone = (timezone: string) => {
+  try {
+    new Intl.DateTimeFormat('en-US', { timeZone: timezone });
+    return true;
+  } catch {
+    return false;
+  }
+};
+
+const assertTimezone = (timezone: string) => {
+  if (!isValidTimezone(timezone)) {
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+      message: `[time] Invalid IANA timezone | received: ${timezone}`,
+    });
+  }
+};
+
+/**
+ * Same instant in another IANA timezone, e.g. `Europe/Paris`.
+ */
+export const convertTimezone = (opts: { date: TDateInput; timezone: string }) => {
+  assertTimezone(opts.timezone);
+  return dayjs(opts.date).tz(opts.timezone);
+};
+
+// --------------------------------------------------------
+// RFC 3339
+// --------------------------------------------------------
+export const isRFC3339 = (input: unknown): input is string => {
+  return (
+    typeof input === 'string' && RFC3339_PATTERN.test(input) && !Number.isNaN(Date.parse(input))
+  );
+};
+
+/**
+ * Format as RFC 3339 with milliseconds, in UTC (`Z`) unless a timezone is given.
+ *
+ * @example
+ * ```typescript
+ * toRFC3339({ date }); // 2025-03-01T08:30:00.000Z
+ * toRFC3339({ date, timezone: 'Asia/Ho_Chi_Minh' }); // 2025-03-01T15:30:00.000+07:00
+ * ```
+ */
+export const toRFC3339 = (opts: { date: TDateInput; timezone?: string }) => {
+  const { date, timezone } = opts;
+
+  if (!timezone) {
+    return dayjs(date).toDate().toISOString();
+  }
+
+  return convertTimezone({ date, timezone }).format('YYYY-MM-DDTHH:mm:ss.SSSZ');
+};
+
+/**
+ * Parse a RFC 3339 date-time, date only and offset less values are rejected since their
+ * instant depends on the server timezone.
+ */
+export const parseRFC3339 = (input: string): Date => {
+  if (!isRFC3339(input)) {
+    throw getError({
+      statusCode: HTTP.ResultCodes.RS_4.BadRequest,
+      message: `[time] Invalid RFC 3339 date-time | received: ${input}`,
+    });
+  }
+
+  return new Date(input);
+};
+
+// --------------------------------------------------------
+// Business days
+// --------------------------------------------------------
+export interface IBusinessDayOptions {
+  // `YYYY-MM-DD` days off
+  holidays?: Array<string>;
+  // ISO weekdays off, defaults to saturday and sunday
+  weekend?: Array<number>;
+  // Timezone the calendar days are counted in, defaults to the application timezone
+  timezone?: string;
+}
+
+const toCalendarDay = (opts: { date: TDateInput; timezone?: string }) => {
+  return opts.timezone ? convertTimezone(opts) : dayjs(opts.date).tz();
+};
+
+export const isBusinessDay = (opts: { date: TDateInput } & IBusinessDayOptions) => {
+  const { date, holidays = [], weekend = DEFAULT_WEEKEND, timezone } = opts;
+  const day = toCalendarDay({ date, timezone });
+
+  return !weekend.includes(day.isoWeekday()) && !holidays.includes(day.format('YYYY-MM-DD'));
+};
+
+/**
+ * Move by `days` business days, backward when negative. The time of day is kept.
+ */
+export const addBusinessDays = (opts: { date: TDateInput; days: number } & IBusinessDayOptions) => {
+  const { date, days, ...calendar } = opts;
+  const step = days < 0 ? -1 : 1;
+
+  let rs = toCalendarDay({ date, timezone: calendar.timezone });
+  let remaining = Math.abs(days);
+
+  while (remaining > 0) {
+    rs = rs.add(step, 'day');
+    if (isBusinessDay({ date: rs, ...calendar })) {
+      remaining--;
+    }
+  }
+
+  return rs;
+};
+
+/**
+ * Business days in `[from, to)`, negative when `to` is before `from`.
+ */
+export const countBusinessDays = (
+  opts: { from: TDateInput; to: TDateInput } & IBusinessDayOptions,
+) => {
+  const { from, to, ...calendar } = opts;
+
+  const start = toCalendarDay({ date: from, timezone: calendar.timezone }).startOf('day');
+  const end = toCalendarDay({ date: to, timezone: calendar.timezone }).startOf('day');
+  const sign = end.isBefore(start) ? -1 : 1;
+
+  const [first, last] = sign > 0 ? [start, end] : [end, start];
+
+  let count = 0;
+  for (let day = first; day.isBefore(last); day = day.add(1, 'day')) {
+    if (isBusinessDay({ date: day, ...calendar })) {
+      count++;
+    }
+  }
+
+  return count * sign;
+};
+
+// --------------------------------------------------------
+// Durations
+// --------------------------------------------------------
+const DURATION_UNITS = [
+  { short: 'd', long: 'day', ms: 24 * 60 * 60 * 1000 },
+  { short: 'h', long: 'hour', ms: 60 * 60 * 1000 },
+  { short: 'm', long: 'minute', ms: 60 * 1000 },
+  { short: 's', long: 'second', ms: 1000 },
+  { short: 'ms', long: 'millisecond', ms: 1 },
+];
+
+/**
+ * Human readable duration, keeping the `largest` most significant units.
+ *
+ * @example
+ * ```typescript
+ * humanizeDuration({ ms: 7_530_000 }); // 2h 5m
+ * humanizeDuration({ ms: 90_061_000, largest: 3, style: 'long' }); // 1 day 1 hour 1 minute
+ * humanizeDuration({ ms: 850 }); // 850ms
+ * ```
+ */
+export const humanizeDuration = (opts: {
+  ms: number;
+  largest?: number;
+  style?: 'short' | 'long';
+}) => {
+  const { largest = 2, style = 'short' } = opts;
+  const sign = opts.ms < 0 ? '-' : '';
+
+  let remaining = Math.round(Math.abs(opts.ms));
+  const parts: Array<string> = [];
+
+  for (const unit of DURATION_UNITS) {
+    if (parts.length >= largest) {
+      break;
+    }
+
+    const value = Math.floor(remaining / unit.ms);
+    remaining -= value * unit.ms;
+
+    if (value === 0) {
+      continue;
+    }
+
+    parts.push(
+      style === 'short' ? `${value}${unit.short}` : `${value} ${unit.long}${value > 1 ? 's' : ''}`,
+    );
+  }
+
+  if (!parts.length) {
+    return style === 'short' ? '0ms' : '0 milliseconds';
+  }
+
+  return `${sign}${parts.join(' ')}`;
+};